@@ -1,9 +1,12 @@
-use crate::commands::classified::block::run_block;
+use crate::commands::classified::block::{run_block, run_block_with_vars, set_var};
 use crate::commands::classified::external::{MaybeTextCodec, StringOrBinary};
 use crate::commands::plugin::JsonRpc;
 use crate::commands::plugin::{PluginCommand, PluginSink};
+use crate::commands::source::{resolve_sources, run_file};
 use crate::commands::whole_stream_command;
 use crate::context::Context;
+use crate::data::history::{self, HistoryEntry};
+use crate::data::visits;
 #[cfg(not(feature = "starship-prompt"))]
 use crate::git::current_branch;
 use crate::path::canonicalize;
@@ -12,8 +15,11 @@ use crate::EnvironmentSyncer;
 use futures_codec::FramedRead;
 
 use nu_errors::ShellError;
-use nu_protocol::hir::{ClassifiedCommand, Expression, InternalCommand, Literal, NamedArguments};
-use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+use nu_protocol::hir::{
+    Block, ClassifiedCommand, Expression, InternalCommand, Literal, NamedArguments, NamedValue,
+    SpannedExpression,
+};
+use nu_protocol::{Primitive, ReturnSuccess, Scope, Signature, UntaggedValue, Value};
 
 use log::{debug, trace};
 use rustyline::error::ReadlineError;
@@ -241,6 +247,69 @@ pub fn create_default_context(
     syncer.sync_env_vars(&mut context);
     syncer.sync_path_vars(&mut context);
 
+    add_default_commands(&mut context, interactive);
+
+    Ok(context)
+}
+
+/// A context with every internal command, for embedding nu. Unlike `create_default_context`,
+/// it leaves the user's config, environment, history and plugins alone.
+pub fn create_embedded_context() -> Result<Context, Box<dyn Error>> {
+    let mut context = Context::basic()?;
+    add_default_commands(&mut context, false);
+
+    Ok(context)
+}
+
+/// Runs a pipeline over the given values, giving back the values it produces, or the first error
+/// it runs into.
+pub async fn evaluate_pipeline(
+    pipeline: &str,
+    context: &mut Context,
+    input: Vec<Value>,
+    vars: &IndexMap<String, Value>,
+    env: &IndexMap<String, String>,
+) -> Result<Vec<Value>, ShellError> {
+    let lite_block = nu_parser::lite_parse(pipeline, 0)?;
+    let mut classified_block = nu_parser::classify_block(&lite_block, context.registry());
+
+    if let Some(failure) = classified_block.failed {
+        return Err(failure.into());
+    }
+
+    classified_block.block.expand_it_usage();
+
+    let mut context_env = context.get_env();
+    context_env.extend(env.clone());
+
+    let output = run_block(
+        &classified_block.block,
+        context,
+        InputStream::from(input),
+        &Value::nothing(),
+        vars,
+        &context_env,
+    )
+    .await?
+    .into_vec()
+    .await;
+
+    if let Some(err) = context.get_errors().first() {
+        context.clear_errors();
+        return Err(err.clone());
+    }
+
+    output
+        .into_iter()
+        .map(|value| match value.value {
+            UntaggedValue::Error(err) => Err(err),
+            _ => Ok(value),
+        })
+        .collect()
+}
+
+/// Registers every internal command that ships with nu into the given context.
+pub(crate) fn add_default_commands(context: &mut Context, interactive: bool) {
     {
         use crate::commands::*;
 
@@ -248,17 +317,33 @@ pub fn create_default_context(
             // System/file operations
             whole_stream_command(Pwd),
             whole_stream_command(Ls),
+            whole_stream_command(Link),
             whole_stream_command(Du),
+            whole_stream_command(Glob),
             whole_stream_command(Cd),
+            whole_stream_command(Z),
             whole_stream_command(Remove),
             whole_stream_command(Open),
             whole_stream_command(Config),
+            whole_stream_command(ConfigGet),
+            whole_stream_command(ConfigSet),
+            whole_stream_command(ConfigRemove),
+            whole_stream_command(ConfigEdit),
             whole_stream_command(Help),
             whole_stream_command(History),
             whole_stream_command(Save),
             whole_stream_command(Touch),
+            whole_stream_command(Mktemp),
             whole_stream_command(Cpy),
+            whole_stream_command(Chmod),
             whole_stream_command(Date),
+            whole_stream_command(DateAdd),
+            whole_stream_command(DateFormat),
+            whole_stream_command(DateNextCron),
+            whole_stream_command(DateNow),
+            whole_stream_command(DateRange),
+            whole_stream_command(DateSubtract),
+            whole_stream_command(DateToTimeZone),
             whole_stream_command(Cal),
             whole_stream_command(Calc),
             whole_stream_command(Mkdir),
@@ -267,10 +352,34 @@ pub fn create_default_context(
             whole_stream_command(Version),
             whole_stream_command(Clear),
             whole_stream_command(What),
+            whole_stream_command(Describe),
             whole_stream_command(Which),
             whole_stream_command(Debug),
             whole_stream_command(Alias),
+            whole_stream_command(Def),
+            whole_stream_command(Let),
+            whole_stream_command(Mut),
+            whole_stream_command(For),
+            whole_stream_command(While),
+            whole_stream_command(Loop),
+            whole_stream_command(Break),
+            whole_stream_command(Continue),
+            whole_stream_command(If),
+            whole_stream_command(Match),
+            whole_stream_command(Module),
+            whole_stream_command(Use),
+            whole_stream_command(Source),
             whole_stream_command(WithEnv),
+            whole_stream_command(LoadEnv),
+            whole_stream_command(Env),
+            whole_stream_command(Pathvar),
+            whole_stream_command(PathvarAdd),
+            whole_stream_command(PathvarAppend),
+            whole_stream_command(PathvarRemove),
+            whole_stream_command(Benchmark),
+            whole_stream_command(Poll),
+            whole_stream_command(Try),
+            whole_stream_command(Complete),
             // Statistics
             whole_stream_command(Size),
             whole_stream_command(Count),
@@ -285,6 +394,7 @@ pub fn create_default_context(
             // Viewers
             whole_stream_command(Autoview),
             whole_stream_command(Table),
+            whole_stream_command(Explore),
             // Text manipulation
             whole_stream_command(Split),
             whole_stream_command(SplitColumn),
@@ -292,6 +402,8 @@ pub fn create_default_context(
             whole_stream_command(Lines),
             whole_stream_command(Trim),
             whole_stream_command(Echo),
+            whole_stream_command(Seq),
+            whole_stream_command(SeqDate),
             whole_stream_command(Parse),
             whole_stream_command(Str),
             whole_stream_command(StrToDecimal),
@@ -299,12 +411,37 @@ pub fn create_default_context(
             whole_stream_command(StrDowncase),
             whole_stream_command(StrUpcase),
             whole_stream_command(StrCapitalize),
+            whole_stream_command(StrCamelCase),
+            whole_stream_command(StrPascalCase),
+            whole_stream_command(StrSnakeCase),
+            whole_stream_command(StrKebabCase),
+            whole_stream_command(StrTitleCase),
             whole_stream_command(StrFindReplace),
+            whole_stream_command(StrContains),
+            whole_stream_command(StrStartsWith),
+            whole_stream_command(StrEndsWith),
+            whole_stream_command(StrIndexOf),
+            whole_stream_command(StrLength),
+            whole_stream_command(StrDistance),
+            whole_stream_command(StrSimilarity),
             whole_stream_command(StrSubstring),
             whole_stream_command(StrSet),
+            whole_stream_command(StrPad),
+            whole_stream_command(StrLeftPad),
+            whole_stream_command(StrRightPad),
             whole_stream_command(StrToDatetime),
+            whole_stream_command(IntoCommand),
+            whole_stream_command(IntoInt),
+            whole_stream_command(IntoDecimal),
+            whole_stream_command(IntoDuration),
+            whole_stream_command(IntoString),
+            whole_stream_command(IntoFilesize),
+            whole_stream_command(IntoDate),
             whole_stream_command(StrTrim),
             whole_stream_command(BuildString),
+            whole_stream_command(Ansi),
+            whole_stream_command(AnsiStrip),
+            whole_stream_command(Char),
             // Column manipulation
             whole_stream_command(Reject),
             whole_stream_command(Select),
@@ -319,13 +456,21 @@ pub fn create_default_context(
             whole_stream_command(SortBy),
             whole_stream_command(GroupBy),
             whole_stream_command(GroupByDate),
+            whole_stream_command(Aggregate),
             whole_stream_command(First),
+            whole_stream_command(Window),
+            whole_stream_command(Chunks),
             whole_stream_command(Last),
             whole_stream_command(Every),
             whole_stream_command(Nth),
             whole_stream_command(Drop),
+            whole_stream_command(DropNth),
             whole_stream_command(Format),
+            whole_stream_command(FileSize),
+            whole_stream_command(FormatDate),
+            whole_stream_command(FormatDuration),
             whole_stream_command(Where),
+            whole_stream_command(Find),
             whole_stream_command(Compact),
             whole_stream_command(Default),
             whole_stream_command(Skip),
@@ -336,21 +481,51 @@ pub fn create_default_context(
             whole_stream_command(KeepWhile),
             whole_stream_command(Range),
             whole_stream_command(Rename),
+            whole_stream_command(MoveColumn),
+            whole_stream_command(Rotate),
+            whole_stream_command(Zip),
             whole_stream_command(Uniq),
             whole_stream_command(Each),
+            whole_stream_command(Reduce),
             whole_stream_command(IsEmpty),
+            whole_stream_command(IsEmptyValue),
+            whole_stream_command(IsNull),
             // Table manipulation
             whole_stream_command(Merge),
+            whole_stream_command(Join),
             whole_stream_command(Shuffle),
+            whole_stream_command(RandomCommand),
+            whole_stream_command(RandomInteger),
+            whole_stream_command(RandomDecimal),
+            whole_stream_command(RandomUUID),
+            whole_stream_command(RandomBool),
+            whole_stream_command(RandomChars),
+            whole_stream_command(Sample),
             whole_stream_command(Wrap),
             whole_stream_command(Pivot),
+            whole_stream_command(Unpivot),
+            whole_stream_command(Flatten),
+            whole_stream_command(Tree),
             whole_stream_command(Headers),
             // Data processing
             whole_stream_command(Histogram),
             whole_stream_command(Math),
+            whole_stream_command(MathEval),
             whole_stream_command(Average),
             whole_stream_command(Minimum),
             whole_stream_command(Maximum),
+            whole_stream_command(Median),
+            whole_stream_command(Mode),
+            whole_stream_command(Percentile),
+            whole_stream_command(Quartiles),
+            whole_stream_command(Product),
+            whole_stream_command(StandardDeviation),
+            whole_stream_command(Variance),
+            whole_stream_command(Absolute),
+            whole_stream_command(Ceiling),
+            whole_stream_command(Floor),
+            whole_stream_command(Round),
+            whole_stream_command(SquareRoot),
             whole_stream_command(Sum),
             // File format output
             whole_stream_command(To),
@@ -404,14 +579,89 @@ pub fn create_default_context(
         {
             context.add_commands(vec![whole_stream_command(crate::commands::clip::Clip)]);
         }
+
+        #[cfg(unix)]
+        {
+            context.add_commands(vec![
+                whole_stream_command(crate::commands::chown::Chown),
+                whole_stream_command(crate::commands::chown::Chgrp),
+            ]);
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            context.add_commands(vec![whole_stream_command(
+                crate::commands::netstat::Netstat,
+            )]);
+        }
     }
 
-    Ok(context)
+    // The conversions used to be called `from-json`, `to-csv` and so on
+    for name in context.registry.names() {
+        if name.starts_with("from ") || name.starts_with("to ") {
+            let old = name.replacen(' ', "-", 1);
+            context.registry.deprecate_command(old, name);
+        }
+    }
 }
 
+/// Runs the pipelines given with `-c`. Each can have several lines, which are run in turn like
+/// the lines of a script.
 pub async fn run_vec_of_pipelines(
     pipelines: Vec<String>,
     redirect_stdin: bool,
+) -> Result<(), Box<dyn Error>> {
+    let pipelines = pipelines
+        .iter()
+        .flat_map(|pipeline| split_lines(pipeline))
+        .collect();
+
+    run_pipelines(pipelines, redirect_stdin, None, None).await
+}
+
+/// Runs the lines of a script, finding the files it sources from the directory it is in. The
+/// arguments are given to the script as `$args`, and to its `main` command if it defines one,
+/// which is run after the lines.
+pub async fn run_script_file(
+    file: String,
+    args: Vec<String>,
+    redirect_stdin: bool,
+) -> Result<(), Box<dyn Error>> {
+    let contents = std::fs::read_to_string(&file)?;
+    let pipelines = split_lines(&contents);
+    let script_dir = std::fs::canonicalize(&file)?.parent().map(PathBuf::from);
+
+    run_pipelines(pipelines, redirect_stdin, script_dir, Some(args)).await
+}
+
+/// Splits nu code into the lines to run, the way `source` does: a line that leaves a block or a
+/// string open, or ends with a `|`, goes on with the next ones.
+fn split_lines(source: &str) -> Vec<String> {
+    let mut lines = vec![];
+    let mut continued: Option<String> = None;
+
+    for line in source.lines() {
+        let line = match continued.take() {
+            Some(previous) => format!("{}\n{}", previous, line),
+            None => line.to_string(),
+        };
+
+        if !line.trim_start().starts_with('#') && nu_parser::is_incomplete(&line) {
+            continued = Some(line);
+        } else {
+            lines.push(line);
+        }
+    }
+
+    lines.extend(continued);
+    lines
+}
+
+async fn run_pipelines(
+    pipelines: Vec<String>,
+    redirect_stdin: bool,
+    script_dir: Option<PathBuf>,
+    script_args: Option<Vec<String>>,
 ) -> Result<(), Box<dyn Error>> {
     let mut syncer = crate::EnvironmentSyncer::new();
     let mut context = create_default_context(&mut syncer, false)?;
@@ -456,12 +706,183 @@ pub async fn run_vec_of_pipelines(
         }
     }
 
+    run_startup_scripts(&mut context, false).await;
+
+    context.script_dir = script_dir;
+
+    if let Some(args) = &script_args {
+        let args = args
+            .iter()
+            .map(|arg| UntaggedValue::string(arg).into_untagged_value())
+            .collect();
+        set_var(
+            &mut context.vars,
+            "$args".to_string(),
+            UntaggedValue::Table(args).into_untagged_value(),
+        );
+    }
+
     for pipeline in pipelines {
         run_pipeline_standalone(pipeline, redirect_stdin, &mut context, true).await?;
     }
+
+    if let Some(args) = script_args {
+        if context.registry.has("main") {
+            let main = std::iter::once("main".to_string())
+                .chain(args.iter().map(|arg| script_arg(arg)))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            run_pipeline_standalone(main, redirect_stdin, &mut context, true).await?;
+        }
+    }
+
     Ok(())
 }
 
+/// An argument given to a script, written for the call to its `main` command. Flags and plain
+/// words are left as they are, so they are parsed for the shape of their parameter, and anything
+/// else is quoted so it is given as it was written.
+fn script_arg(arg: &str) -> String {
+    let is_flag = arg.starts_with("--")
+        || (arg.starts_with('-') && arg.chars().nth(1).map_or(false, char::is_alphabetic));
+    let is_plain = !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '/'));
+
+    if is_flag || is_plain {
+        return arg.to_string();
+    }
+
+    match ['"', '\'', '`'].iter().find(|quote| !arg.contains(**quote)) {
+        Some(quote) => format!("{}{}{}", quote, arg, quote),
+        None => format!("\"{}\"", arg.replace('"', "'")),
+    }
+}
+
+/// Runs the startup scripts of the config directory, after the startup commands of the config.
+/// An error in one is shown against the script, and nu starts anyway.
+async fn run_startup_scripts(context: &mut Context, interactive: bool) {
+    for path in crate::data::config::startup_scripts(interactive) {
+        if let Ok(contents) = std::fs::read_to_string(&path) {
+            context.raw_input = contents.clone();
+            let _ = run_file(&path, &contents, &Tag::unknown(), context, &Scope::new()).await;
+        }
+    }
+}
+
+/// Runs nu code of the config, like a prompt or a completer, and gives the values it outputs. The
+/// code sees the variables and the environment of the session, along with the variables it is
+/// given.
+pub(crate) async fn evaluate_config_code(
+    code: &str,
+    context: &mut Context,
+    vars: IndexMap<String, Value>,
+) -> Result<Vec<Value>, ShellError> {
+    let lite_block = nu_parser::lite_parse_lines(code, 0)?;
+    let classified_block = nu_parser::classify_block(&lite_block, context.registry());
+
+    if let Some(failure) = classified_block.failed {
+        return Err(failure.into());
+    }
+
+    let mut block = classified_block.block;
+    block.expand_it_usage();
+
+    // The code can't change the variables of the session
+    let env = context.get_env();
+    let mut scope = context.vars.clone();
+    scope.extend(vars);
+    let input = run_block_with_vars(
+        &block,
+        context,
+        InputStream::empty(),
+        &Value::nothing(),
+        &mut scope,
+        &env,
+    )
+    .await?;
+
+    let mut values = vec![];
+    for value in input.into_vec().await {
+        match value.value {
+            UntaggedValue::Error(err) => return Err(err),
+            UntaggedValue::Primitive(Primitive::Nothing) => {}
+            _ => values.push(value),
+        }
+    }
+
+    if let Some(err) = context.with_errors(|errors| errors.drain(..).next()) {
+        return Err(err);
+    }
+
+    Ok(values)
+}
+
+/// Runs the nu code of a prompt setting, like `prompt` or `right_prompt`, and gives its output
+/// joined as text.
+async fn evaluate_prompt(code: &str, context: &mut Context) -> Result<String, ShellError> {
+    let values = evaluate_config_code(code, context, IndexMap::new()).await?;
+
+    Ok(values.iter().map(Value::convert_to_string).collect())
+}
+
+/// The prompt of a prompt setting of the config, or `None` when it isn't set. When its code fails,
+/// the error is shown and `None` is given, so the built-in prompt is used.
+async fn configured_prompt(
+    config: &IndexMap<String, Value>,
+    setting: &str,
+    context: &mut Context,
+) -> Option<String> {
+    let code = config.get(setting)?.as_string().ok()?;
+
+    match evaluate_prompt(&code, context).await {
+        Ok(prompt) => Some(prompt),
+        Err(err) => {
+            print_err(err, &Text::from(code));
+            None
+        }
+    }
+}
+
+/// Sets how the last line that ran went in the environment, as `LAST_EXIT_CODE` and
+/// `CMD_DURATION_MS`, for the prompt to show.
+fn set_last_line_env(context: &mut Context, exit_code: i32, duration_ms: u128) {
+    context.with_host(|host| {
+        host.env_set(
+            std::ffi::OsString::from("LAST_EXIT_CODE"),
+            std::ffi::OsString::from(exit_code.to_string()),
+        );
+        host.env_set(
+            std::ffi::OsString::from("CMD_DURATION_MS"),
+            std::ffi::OsString::from(duration_ms.to_string()),
+        );
+    });
+}
+
+/// Adds the right prompt to the end of the line the input is written on. The cursor is put back
+/// after the left prompt, and the right prompt is left out when the two don't fit on the line.
+fn with_right_prompt(colored_prompt: String, prompt: &str, right_prompt: &str) -> String {
+    let width = textwrap::termwidth();
+    let right_width = match strip_ansi_escapes::strip(right_prompt) {
+        Ok(bytes) => String::from_utf8_lossy(&bytes).chars().count(),
+        Err(_) => return colored_prompt,
+    };
+    let left_width = prompt.rsplit('\n').next().unwrap_or("").chars().count();
+
+    if right_width == 0 || left_width + right_width >= width {
+        return colored_prompt;
+    }
+
+    format!(
+        "{}\x1b7\x1b[{}G{}\x1b8",
+        colored_prompt,
+        width - right_width + 1,
+        right_prompt
+    )
+}
+
 pub async fn run_pipeline_standalone(
     pipeline: String,
     redirect_stdin: bool,
@@ -477,7 +898,7 @@ pub async fn run_pipeline_standalone(
                 let errors = errors.lock();
 
                 if errors.len() > 0 {
-                    1
+                    errors.iter().find_map(ShellError::exit_code).unwrap_or(1)
                 } else {
                     0
                 }
@@ -490,13 +911,16 @@ pub async fn run_pipeline_standalone(
         }
 
         LineResult::Error(line, err) => {
+            // An external command that failed gives its own exit code
+            let error_code = err.exit_code().unwrap_or(1);
+
             context.with_host(|_host| {
                 print_err(err, &Text::from(line.clone()));
             });
 
             context.maybe_print_errors(Text::from(line));
             if exit_on_error {
-                std::process::exit(1);
+                std::process::exit(error_code);
             }
         }
 
@@ -515,6 +939,7 @@ pub async fn cli(
     const DEFAULT_COMPLETION_MODE: CompletionType = CompletionType::Circular;
     #[cfg(not(windows))]
     const DEFAULT_COMPLETION_MODE: CompletionType = CompletionType::List;
+    const CONTINUATION_PROMPT: &str = "::: ";
 
     let _ = load_plugins(&mut context);
 
@@ -530,14 +955,32 @@ pub async fn cli(
         KeyPress::ControlRight,
         Cmd::Move(Movement::ForwardWord(1, At::AfterEnd, Word::Vi)),
     );
+    // Alt+Enter starts a new line without running what's written so far
+    rl.bind_sequence(KeyPress::Meta('\r'), Cmd::Insert(1, "\n".to_string()));
+    // Ctrl+R marks the line as a fuzzy search of the history, which the helper shows as it goes
+    rl.bind_sequence(
+        KeyPress::Ctrl('R'),
+        Cmd::Insert(1, history::FUZZY_SEARCH.to_string()),
+    );
 
     #[cfg(windows)]
     {
         let _ = ansi_term::enable_ansi_support();
     }
 
-    // we are ok if history does not exist
-    let _ = rl.load_history(&History::path());
+    // The history is compacted as nu starts, as sessions only add to it while they run. We are
+    // ok if it does not exist, or can not be written.
+    let history_size = config::config(Tag::unknown())?
+        .get("history_size")
+        .map(|i| i.value.expect_int())
+        .unwrap_or(100_000);
+    rl.set_max_history_size(history_size as usize);
+
+    let entries = history::compact(history::read(&History::path()), history_size as usize);
+    let _ = history::write(&History::path(), &entries);
+    for entry in entries {
+        rl.add_history_entry(entry.command);
+    }
 
     let cc = context.ctrl_c.clone();
     ctrlc::set_handler(move || {
@@ -573,6 +1016,11 @@ pub async fn cli(
         }
     }
 
+    run_startup_scripts(&mut context, true).await;
+    set_last_line_env(&mut context, 0, 0);
+
+    let mut last_cwd = context.shell_manager.path();
+
     loop {
         if context.ctrl_c.load(Ordering::SeqCst) {
             context.ctrl_c.store(false, Ordering::SeqCst);
@@ -619,7 +1067,13 @@ pub async fn cli(
 
         rl.set_completion_type(completion_mode);
 
-        let colored_prompt = {
+        let config = config::config(Tag::unknown())?;
+        let custom_prompt = configured_prompt(&config, "prompt", &mut context).await;
+        let right_prompt = configured_prompt(&config, "right_prompt", &mut context).await;
+
+        let colored_prompt = if let Some(prompt) = custom_prompt {
+            prompt
+        } else {
             #[cfg(feature = "starship-prompt")]
             {
                 std::env::set_var("STARSHIP_SHELL", "");
@@ -659,15 +1113,47 @@ pub async fn cli(
             }
         };
 
+        let colored_prompt = match right_prompt {
+            Some(right_prompt) => with_right_prompt(colored_prompt, &prompt, &right_prompt),
+            None => colored_prompt,
+        };
+
         rl.helper_mut().expect("No helper").colored_prompt = colored_prompt;
         let mut initial_command = Some(String::new());
         let mut readline = Err(ReadlineError::Eof);
         while let Some(ref cmd) = initial_command {
             readline = rl.readline_with_initial(&prompt, (&cmd, ""));
             initial_command = None;
+
+            // Enter on a search of the history puts the command it found on the line to edit
+            if let Ok(line) = &readline {
+                if line.contains(history::FUZZY_SEARCH) {
+                    initial_command = Some(
+                        history::fuzzy_search(rl.history().iter(), line)
+                            .cloned()
+                            .unwrap_or_else(|| line.replace(history::FUZZY_SEARCH, "")),
+                    );
+                }
+            }
+        }
+
+        // A line that leaves a block open or ends with a `|` goes on with the next ones
+        while let Ok(text) = &readline {
+            if text.trim_start().starts_with('#') || !nu_parser::is_incomplete(text) {
+                break;
+            }
+
+            let text = text.clone();
+            rl.helper_mut().expect("No helper").colored_prompt = CONTINUATION_PROMPT.to_string();
+            readline = rl
+                .readline(CONTINUATION_PROMPT)
+                .map(|next| format!("{}\n{}", text, next));
         }
 
+        let start_time = chrono::Utc::now();
+        let started = std::time::Instant::now();
         let line = process_line(readline, &mut context, false, true).await;
+        let duration = started.elapsed();
 
         // Check the config to see if we need to update the path
         // TODO: make sure config is cached so we don't path this load every call
@@ -676,16 +1162,52 @@ pub async fn cli(
         syncer.sync_env_vars(&mut context);
         syncer.sync_path_vars(&mut context);
 
+        // The prompt can show how the last line that ran went, and the history keeps it
+        let ran = match &line {
+            LineResult::Success(line) if !line.trim().is_empty() => {
+                let exit_code = context.with_errors(|errors| {
+                    if errors.is_empty() {
+                        0
+                    } else {
+                        errors.iter().find_map(ShellError::exit_code).unwrap_or(1)
+                    }
+                });
+                Some((line.clone(), exit_code))
+            }
+            LineResult::Error(line, err) => Some((line.clone(), err.exit_code().unwrap_or(1))),
+            _ => None,
+        };
+
+        if let Some((command, exit_code)) = ran {
+            set_last_line_env(&mut context, exit_code, duration.as_millis());
+
+            // we are ok if we can not save history
+            let _ = history::append(
+                &History::path(),
+                &HistoryEntry {
+                    command,
+                    start_time: Some(start_time),
+                    duration_ms: Some(duration.as_millis() as u64),
+                    exit_status: Some(exit_code),
+                },
+            );
+        }
+
+        // The directories the lines go into are counted, for z to jump to them
+        let cwd = context.shell_manager.path();
+        if cwd != last_cwd && context.shell_manager.is_filesystem() {
+            let _ = visits::record(&cwd);
+        }
+        last_cwd = cwd;
+
         match line {
             LineResult::Success(line) => {
                 rl.add_history_entry(&line);
-                let _ = rl.save_history(&History::path());
                 context.maybe_print_errors(Text::from(line));
             }
 
             LineResult::Error(line, err) => {
                 rl.add_history_entry(&line);
-                let _ = rl.save_history(&History::path());
 
                 context.with_host(|_host| {
                     print_err(err, &Text::from(line.clone()));
@@ -708,7 +1230,6 @@ pub async fn cli(
                 }
 
                 if ctrlcbreak {
-                    let _ = rl.save_history(&History::path());
                     std::process::exit(0);
                 } else {
                     context.with_host(|host| host.stdout("CTRL-C pressed (again to quit)"));
@@ -724,9 +1245,6 @@ pub async fn cli(
         ctrlcbreak = false;
     }
 
-    // we are ok if we can not save history
-    let _ = rl.save_history(&History::path());
-
     Ok(())
 }
 
@@ -745,6 +1263,32 @@ enum LineResult {
     Break,
 }
 
+/// Give the comments written above a def to it as the usage of the command it defines, unless
+/// it was given a usage already.
+pub(crate) fn document_definitions(block: &mut Block, usage: String) {
+    for pipeline in block.block.iter_mut() {
+        for command in pipeline.list.iter_mut() {
+            if let ClassifiedCommand::Internal(internal) = command {
+                if internal.name != "def" {
+                    continue;
+                }
+
+                let span = internal.name_span;
+                let named = internal.args.named.get_or_insert_with(NamedArguments::new);
+                if let None | Some(NamedValue::AbsentValue) = named.named.get("usage") {
+                    named.named.insert(
+                        "usage".to_string(),
+                        NamedValue::Value(
+                            span,
+                            SpannedExpression::new(Expression::string(usage.clone()), span),
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
+
 /// Process the line by parsing the text to turn it into commands, classify those commands so that we understand what is being called in the pipeline, and then run this pipeline
 async fn process_line(
     readline: Result<String, ReadlineError>,
@@ -753,13 +1297,28 @@ async fn process_line(
     cli_mode: bool,
 ) -> LineResult {
     match &readline {
-        Ok(line) if line.trim() == "" => LineResult::Success(line.clone()),
+        Ok(line) if line.trim() == "" => {
+            ctx.doc_comments.clear();
+            LineResult::Success(line.clone())
+        }
+
+        // Comments are kept to document a def on the line right after them
+        Ok(line) if line.trim_start().starts_with('#') => {
+            let comment = chomp_newline(line).trim_start();
+            if !comment.starts_with("#!") {
+                let comment = comment.trim_start_matches('#');
+                let comment = comment.strip_prefix(' ').unwrap_or(comment);
+                ctx.doc_comments.push(comment.to_string());
+            }
+            LineResult::Success(line.clone())
+        }
 
         Ok(line) => {
             let line = chomp_newline(line);
             ctx.raw_input = line.to_string();
+            let doc_comments = std::mem::take(&mut ctx.doc_comments);
 
-            let result = match nu_parser::lite_parse(&line, 0) {
+            let result = match nu_parser::lite_parse_lines(line, 0) {
                 Err(err) => {
                     return LineResult::Error(line.to_string(), err.into());
                 }
@@ -779,6 +1338,14 @@ async fn process_line(
                 return LineResult::Error(line.to_string(), failure.into());
             }
 
+            if !doc_comments.is_empty() {
+                document_definitions(&mut classified_block.block, doc_comments.join("\n"));
+            }
+
+            if let Some(dir) = &ctx.script_dir {
+                resolve_sources(&mut classified_block.block, dir);
+            }
+
             // There's a special case to check before we process the pipeline:
             // If we're giving a path by itself
             // ...and it's not a command in the path
@@ -867,7 +1434,7 @@ async fn process_line(
 
             let input_stream = if redirect_stdin {
                 let file = futures::io::AllowStdIo::new(std::io::stdin());
-                let stream = FramedRead::new(file, MaybeTextCodec).map(|line| {
+                let stream = FramedRead::new(file, MaybeTextCodec::default()).map(|line| {
                     if let Ok(line) = line {
                         match line {
                             StringOrBinary::String(s) => Ok(Value {
@@ -894,16 +1461,19 @@ async fn process_line(
 
             trace!("{:#?}", classified_block);
             let env = ctx.get_env();
-            match run_block(
+            let mut vars = std::mem::take(&mut ctx.vars);
+            let result = run_block_with_vars(
                 &classified_block.block,
                 ctx,
                 input_stream,
                 &Value::nothing(),
-                &IndexMap::new(),
+                &mut vars,
                 &env,
             )
-            .await
-            {
+            .await;
+            ctx.vars = vars;
+
+            match result {
                 Ok(input) => {
                     // Running a pipeline gives us back a stream that we can then
                     // work through. At the top level, we just want to pull on the
@@ -956,10 +1526,15 @@ async fn process_line(
 }
 
 pub fn print_err(err: ShellError, source: &Text) {
+    print_err_in(err, "shell", source)
+}
+
+/// Shows the error against `source`, the text of the file called `name`.
+pub(crate) fn print_err_in(err: ShellError, name: &str, source: &Text) {
     if let Some(diag) = err.into_diagnostic() {
         let source = source.to_string();
         let mut files = codespan_reporting::files::SimpleFiles::new();
-        files.add("shell", source);
+        files.add(name, source);
 
         let writer = codespan_reporting::term::termcolor::StandardStream::stderr(
             codespan_reporting::term::termcolor::ColorChoice::Always,
@@ -4,36 +4,63 @@ pub(crate) mod macros;
 mod from_delimited_data;
 mod to_delimited_data;
 
+pub(crate) mod aggregate;
 pub(crate) mod alias;
+pub(crate) mod ansi;
 pub(crate) mod append;
 pub(crate) mod args;
 pub(crate) mod autoview;
+pub(crate) mod benchmark;
+pub(crate) mod break_;
 pub(crate) mod build_string;
 pub(crate) mod cal;
 pub(crate) mod calc;
 pub(crate) mod cd;
+pub(crate) mod char_;
+pub(crate) mod chmod;
+#[cfg(unix)]
+pub(crate) mod chown;
+pub(crate) mod chunks;
 pub(crate) mod classified;
 #[cfg(feature = "clipboard")]
 pub(crate) mod clip;
 pub(crate) mod command;
 pub(crate) mod compact;
+pub(crate) mod complete;
 pub(crate) mod config;
+pub(crate) mod continue_;
 pub(crate) mod count;
 pub(crate) mod cp;
 pub(crate) mod date;
+pub(crate) mod date_arithmetic;
+pub(crate) mod date_next_cron;
+pub(crate) mod date_now;
+pub(crate) mod date_range;
+pub(crate) mod date_to_timezone;
 pub(crate) mod debug;
+pub(crate) mod def;
 pub(crate) mod default;
+pub(crate) mod describe;
 pub(crate) mod drop;
+pub(crate) mod drop_nth;
 pub(crate) mod du;
 pub(crate) mod each;
 pub(crate) mod echo;
 pub(crate) mod enter;
+pub(crate) mod env;
 #[allow(unused)]
 pub(crate) mod evaluate_by;
 pub(crate) mod every;
 pub(crate) mod exit;
+pub(crate) mod explore;
+pub(crate) mod find;
 pub(crate) mod first;
+pub(crate) mod flatten;
+pub(crate) mod for_;
 pub(crate) mod format;
+pub(crate) mod format_date;
+pub(crate) mod format_duration;
+pub(crate) mod format_filesize;
 pub(crate) mod from;
 pub(crate) mod from_bson;
 pub(crate) mod from_csv;
@@ -52,46 +79,71 @@ pub(crate) mod from_xlsx;
 pub(crate) mod from_xml;
 pub(crate) mod from_yaml;
 pub(crate) mod get;
+pub(crate) mod glob_;
 pub(crate) mod group_by;
 pub(crate) mod group_by_date;
 pub(crate) mod headers;
 pub(crate) mod help;
 pub(crate) mod histogram;
 pub(crate) mod history;
+pub(crate) mod if_;
 pub(crate) mod insert;
+pub(crate) mod into;
 pub(crate) mod is_empty;
+pub(crate) mod join;
 pub(crate) mod keep;
 pub(crate) mod keep_until;
 pub(crate) mod keep_while;
 pub(crate) mod last;
+pub(crate) mod let_;
 pub(crate) mod lines;
+pub(crate) mod ln;
+pub(crate) mod load_env;
+pub(crate) mod loop_;
 pub(crate) mod ls;
 #[allow(unused)]
 pub(crate) mod map_max_by;
+pub(crate) mod match_;
 pub(crate) mod math;
 pub(crate) mod merge;
 pub(crate) mod mkdir;
+pub(crate) mod mktemp;
+pub(crate) mod module;
+pub(crate) mod move_;
+pub(crate) mod mut_;
 pub(crate) mod mv;
+#[cfg(target_os = "linux")]
+pub(crate) mod netstat;
 pub(crate) mod next;
 pub(crate) mod nth;
 pub(crate) mod open;
 pub(crate) mod parse;
+pub(crate) mod pathvar;
 pub(crate) mod pivot;
 pub(crate) mod plugin;
+pub(crate) mod poll;
+pub(crate) mod predicates;
 pub(crate) mod prepend;
 pub(crate) mod prev;
 pub(crate) mod pwd;
+pub(crate) mod random;
 pub(crate) mod range;
 #[allow(unused)]
+pub(crate) mod reduce;
 pub(crate) mod reduce_by;
 pub(crate) mod reject;
 pub(crate) mod rename;
 pub(crate) mod reverse;
 pub(crate) mod rm;
+pub(crate) mod rotate;
 pub(crate) mod run_alias;
+pub(crate) mod run_def;
 pub(crate) mod run_external;
+pub(crate) mod sample;
 pub(crate) mod save;
 pub(crate) mod select;
+pub(crate) mod seq;
+pub(crate) mod seq_date;
 pub(crate) mod shells;
 pub(crate) mod shuffle;
 pub(crate) mod size;
@@ -99,6 +151,7 @@ pub(crate) mod skip;
 pub(crate) mod skip_until;
 pub(crate) mod skip_while;
 pub(crate) mod sort_by;
+pub(crate) mod source;
 pub(crate) mod split;
 pub(crate) mod split_by;
 pub(crate) mod str_;
@@ -118,15 +171,23 @@ pub(crate) mod to_toml;
 pub(crate) mod to_tsv;
 pub(crate) mod to_url;
 pub(crate) mod to_yaml;
+pub(crate) mod tree;
 pub(crate) mod trim;
+pub(crate) mod try_;
 pub(crate) mod uniq;
+pub(crate) mod unpivot;
 pub(crate) mod update;
+pub(crate) mod use_;
 pub(crate) mod version;
 pub(crate) mod what;
 pub(crate) mod where_;
 pub(crate) mod which_;
+pub(crate) mod while_;
+pub(crate) mod window;
 pub(crate) mod with_env;
 pub(crate) mod wrap;
+pub(crate) mod z;
+pub(crate) mod zip;
 
 pub(crate) use autoview::Autoview;
 pub(crate) use cd::Cd;
@@ -134,22 +195,42 @@ pub(crate) use command::{
     whole_stream_command, Command, Example, UnevaluatedCallInfo, WholeStreamCommand,
 };
 
+pub(crate) use aggregate::Aggregate;
 pub(crate) use alias::Alias;
+pub(crate) use ansi::{Ansi, AnsiStrip};
 pub(crate) use append::Append;
+pub(crate) use benchmark::Benchmark;
+pub(crate) use break_::Break;
 pub(crate) use build_string::BuildString;
 pub(crate) use cal::Cal;
 pub(crate) use calc::Calc;
+pub(crate) use char_::Char;
+pub(crate) use chmod::Chmod;
+pub(crate) use chunks::Chunks;
 pub(crate) use compact::Compact;
-pub(crate) use config::Config;
+pub(crate) use complete::Complete;
+pub(crate) use config::{Config, ConfigEdit, ConfigGet, ConfigRemove, ConfigSet};
+pub(crate) use continue_::Continue;
 pub(crate) use count::Count;
 pub(crate) use cp::Cpy;
 pub(crate) use date::Date;
+pub(crate) use date_arithmetic::{DateAdd, DateSubtract};
+pub(crate) use date_next_cron::DateNextCron;
+pub(crate) use date_now::DateNow;
+pub(crate) use date_range::DateRange;
+pub(crate) use date_to_timezone::DateToTimeZone;
 pub(crate) use debug::Debug;
+pub(crate) use def::Def;
 pub(crate) use default::Default;
+pub(crate) use describe::Describe;
 pub(crate) use drop::Drop;
+pub(crate) use drop_nth::DropNth;
 pub(crate) use du::Du;
 pub(crate) use each::Each;
 pub(crate) use echo::Echo;
+pub(crate) use into::{
+    IntoCommand, IntoDate, IntoDecimal, IntoDuration, IntoFilesize, IntoInt, IntoString,
+};
 pub(crate) use is_empty::IsEmpty;
 pub(crate) use math::Math;
 pub(crate) use update::Update;
@@ -159,12 +240,20 @@ pub(crate) mod clear;
 pub(crate) use clear::Clear;
 pub(crate) mod touch;
 pub(crate) use enter::Enter;
+pub(crate) use env::Env;
 #[allow(unused_imports)]
 pub(crate) use evaluate_by::EvaluateBy;
 pub(crate) use every::Every;
 pub(crate) use exit::Exit;
+pub(crate) use explore::Explore;
+pub(crate) use find::Find;
 pub(crate) use first::First;
+pub(crate) use flatten::Flatten;
+pub(crate) use for_::For;
 pub(crate) use format::Format;
+pub(crate) use format_date::{DateFormat, FormatDate};
+pub(crate) use format_duration::FormatDuration;
+pub(crate) use format_filesize::FileSize;
 pub(crate) use from::From;
 pub(crate) use from_bson::FromBSON;
 pub(crate) use from_csv::FromCSV;
@@ -185,43 +274,69 @@ pub(crate) use from_xml::FromXML;
 pub(crate) use from_yaml::FromYAML;
 pub(crate) use from_yaml::FromYML;
 pub(crate) use get::Get;
+pub(crate) use glob_::Glob;
 pub(crate) use group_by::GroupBy;
 pub(crate) use group_by_date::GroupByDate;
 pub(crate) use headers::Headers;
 pub(crate) use help::Help;
 pub(crate) use histogram::Histogram;
 pub(crate) use history::History;
+pub(crate) use if_::If;
 pub(crate) use insert::Insert;
+pub(crate) use join::Join;
 pub(crate) use keep::Keep;
 pub(crate) use keep_until::KeepUntil;
 pub(crate) use keep_while::KeepWhile;
 pub(crate) use last::Last;
+pub(crate) use let_::Let;
 pub(crate) use lines::Lines;
+pub(crate) use ln::Link;
+pub(crate) use load_env::LoadEnv;
+pub(crate) use loop_::Loop;
 pub(crate) use ls::Ls;
 #[allow(unused_imports)]
 pub(crate) use map_max_by::MapMaxBy;
-pub(crate) use math::{Average, Maximum, Minimum};
+pub(crate) use match_::Match;
+pub(crate) use math::{
+    Absolute, Average, Ceiling, Floor, MathEval, Maximum, Median, Minimum, Mode, Percentile,
+    Product, Quartiles, Round, SquareRoot, StandardDeviation, Variance,
+};
 pub(crate) use merge::Merge;
 pub(crate) use mkdir::Mkdir;
+pub(crate) use mktemp::Mktemp;
+pub(crate) use module::Module;
+pub(crate) use move_::MoveColumn;
+pub(crate) use mut_::Mut;
 pub(crate) use mv::Move;
 pub(crate) use next::Next;
 pub(crate) use nth::Nth;
 pub(crate) use open::Open;
 pub(crate) use parse::Parse;
+pub(crate) use pathvar::{Pathvar, PathvarAdd, PathvarAppend, PathvarRemove};
 pub(crate) use pivot::Pivot;
+pub(crate) use poll::Poll;
+pub(crate) use predicates::{IsEmptyValue, IsNull};
 pub(crate) use prepend::Prepend;
 pub(crate) use prev::Previous;
 pub(crate) use pwd::Pwd;
+pub(crate) use random::{
+    RandomBool, RandomChars, RandomCommand, RandomDecimal, RandomInteger, RandomUUID,
+};
 pub(crate) use range::Range;
 #[allow(unused_imports)]
+pub(crate) use reduce::Reduce;
 pub(crate) use reduce_by::ReduceBy;
 pub(crate) use reject::Reject;
 pub(crate) use rename::Rename;
 pub(crate) use reverse::Reverse;
 pub(crate) use rm::Remove;
+pub(crate) use rotate::Rotate;
 pub(crate) use run_external::RunExternalCommand;
+pub(crate) use sample::Sample;
 pub(crate) use save::Save;
 pub(crate) use select::Select;
+pub(crate) use seq::Seq;
+pub(crate) use seq_date::SeqDate;
 pub(crate) use shells::Shells;
 pub(crate) use shuffle::Shuffle;
 pub(crate) use size::Size;
@@ -229,13 +344,16 @@ pub(crate) use skip::Skip;
 pub(crate) use skip_until::SkipUntil;
 pub(crate) use skip_while::SkipWhile;
 pub(crate) use sort_by::SortBy;
+pub(crate) use source::Source;
 pub(crate) use split::Split;
 pub(crate) use split::SplitColumn;
 pub(crate) use split::SplitRow;
 pub(crate) use split_by::SplitBy;
 pub(crate) use str_::{
-    Str, StrCapitalize, StrDowncase, StrFindReplace, StrSet, StrSubstring, StrToDatetime,
-    StrToDecimal, StrToInteger, StrTrim, StrUpcase,
+    Str, StrCamelCase, StrCapitalize, StrContains, StrDistance, StrDowncase, StrEndsWith,
+    StrFindReplace, StrIndexOf, StrKebabCase, StrLeftPad, StrLength, StrPad, StrPascalCase,
+    StrRightPad, StrSet, StrSimilarity, StrSnakeCase, StrStartsWith, StrSubstring, StrTitleCase,
+    StrToDatetime, StrToDecimal, StrToInteger, StrTrim, StrUpcase,
 };
 pub(crate) use sum::Sum;
 #[allow(unused_imports)]
@@ -255,11 +373,19 @@ pub(crate) use to_tsv::ToTSV;
 pub(crate) use to_url::ToURL;
 pub(crate) use to_yaml::ToYAML;
 pub(crate) use touch::Touch;
+pub(crate) use tree::Tree;
 pub(crate) use trim::Trim;
+pub(crate) use try_::Try;
 pub(crate) use uniq::Uniq;
+pub(crate) use unpivot::Unpivot;
+pub(crate) use use_::Use;
 pub(crate) use version::Version;
 pub(crate) use what::What;
 pub(crate) use where_::Where;
 pub(crate) use which_::Which;
+pub(crate) use while_::While;
+pub(crate) use window::Window;
 pub(crate) use with_env::WithEnv;
 pub(crate) use wrap::Wrap;
+pub(crate) use z::Z;
+pub(crate) use zip::Zip;
@@ -0,0 +1,457 @@
+use crate::commands::group_by::suggestions;
+use crate::commands::math::average::average_of_total;
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use crate::utils::data_processing::{max, min, sum};
+use indexmap::{IndexMap, IndexSet};
+use nu_errors::ShellError;
+use nu_protocol::{
+    Primitive, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+use nu_value_ext::as_string;
+
+pub struct Aggregate;
+
+#[derive(Deserialize)]
+pub struct AggregateArgs {
+    agg: Value,
+    by: Option<Value>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Aggregate {
+    fn name(&self) -> &str {
+        "aggregate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("aggregate")
+            .required_named(
+                "agg",
+                SyntaxShape::Any,
+                "the function (or table of functions) to aggregate each column with, like { size: sum, name: count } (sum, avg, min, max, count, first or concat)",
+                Some('a'),
+            )
+            .named(
+                "by",
+                SyntaxShape::Any,
+                "the column (or table of columns) to group the rows by",
+                Some('b'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Computes aggregates of columns, optionally per group of rows, in one pass."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        aggregate(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Total size and number of files per type",
+                example: "ls | aggregate --by type --agg { size: sum, name: count }",
+                result: None,
+            },
+            Example {
+                description: "Largest and average file size",
+                example: "ls | aggregate --agg { size: [max avg] }",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Aggregation {
+    Sum,
+    Average,
+    Minimum,
+    Maximum,
+    Count,
+    First,
+    Concat,
+}
+
+impl Aggregation {
+    pub(crate) fn from_name(name: &str) -> Option<Aggregation> {
+        match name {
+            "sum" => Some(Aggregation::Sum),
+            "avg" | "average" => Some(Aggregation::Average),
+            "min" => Some(Aggregation::Minimum),
+            "max" => Some(Aggregation::Maximum),
+            "count" => Some(Aggregation::Count),
+            "first" => Some(Aggregation::First),
+            "concat" => Some(Aggregation::Concat),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Aggregation::Sum => "sum",
+            Aggregation::Average => "avg",
+            Aggregation::Minimum => "min",
+            Aggregation::Maximum => "max",
+            Aggregation::Count => "count",
+            Aggregation::First => "first",
+            Aggregation::Concat => "concat",
+        }
+    }
+
+    pub(crate) fn compute(self, values: &[Value], tag: &Tag) -> Result<Value, ShellError> {
+        let mut accumulator = self.accumulator();
+        for value in values {
+            accumulator.add(value.clone())?;
+        }
+
+        accumulator.finish(tag)
+    }
+
+    fn accumulator(self) -> Accumulator {
+        match self {
+            Aggregation::Sum => Accumulator::Sum(None),
+            Aggregation::Average => Accumulator::Average(None, 0),
+            Aggregation::Minimum => Accumulator::Minimum(None),
+            Aggregation::Maximum => Accumulator::Maximum(None),
+            Aggregation::Count => Accumulator::Count(0),
+            Aggregation::First => Accumulator::First(None),
+            Aggregation::Concat => Accumulator::Concat(vec![]),
+        }
+    }
+}
+
+/// The running result of an aggregation, so the cells aggregated don't have to be kept around.
+enum Accumulator {
+    Sum(Option<Value>),
+    Average(Option<Value>, usize),
+    Minimum(Option<Value>),
+    Maximum(Option<Value>),
+    Count(usize),
+    First(Option<Value>),
+    Concat(Vec<String>),
+}
+
+impl Accumulator {
+    fn add(&mut self, value: Value) -> Result<(), ShellError> {
+        match self {
+            Accumulator::Sum(total) => *total = Some(combine(total.take(), value, sum)?),
+            Accumulator::Average(total, count) => {
+                *total = Some(combine(total.take(), value, sum)?);
+                *count += 1;
+            }
+            Accumulator::Minimum(smallest) => {
+                *smallest = Some(combine(smallest.take(), value, min)?)
+            }
+            Accumulator::Maximum(biggest) => *biggest = Some(combine(biggest.take(), value, max)?),
+            Accumulator::Count(count) => *count += 1,
+            Accumulator::First(first) => {
+                if first.is_none() {
+                    *first = Some(value);
+                }
+            }
+            Accumulator::Concat(strings) => strings.push(as_string(&value)?),
+        }
+
+        Ok(())
+    }
+
+    fn finish(self, tag: &Tag) -> Result<Value, ShellError> {
+        match self {
+            Accumulator::Average(Some(total), count) => average_of_total(total, count, tag),
+            Accumulator::Sum(Some(value))
+            | Accumulator::Minimum(Some(value))
+            | Accumulator::Maximum(Some(value))
+            | Accumulator::First(Some(value)) => Ok(value),
+            Accumulator::Count(count) => Ok(UntaggedValue::int(count).into_value(tag)),
+            Accumulator::Concat(strings) => {
+                Ok(UntaggedValue::string(strings.join(", ")).into_value(tag))
+            }
+            _ => Ok(UntaggedValue::nothing().into_value(tag)),
+        }
+    }
+}
+
+/// Folds `value` into what's been accumulated so far with `reduce`, like `sum` or `max`.
+fn combine(
+    so_far: Option<Value>,
+    value: Value,
+    reduce: fn(Vec<Value>) -> Result<Value, ShellError>,
+) -> Result<Value, ShellError> {
+    match so_far {
+        Some(so_far) => reduce(vec![so_far, value]),
+        None => reduce(vec![value]),
+    }
+}
+
+struct Spec {
+    column: Tagged<String>,
+    aggregation: Aggregation,
+}
+
+impl Spec {
+    /// The aggregates asked for with `--agg`, a row of a function (or table of functions) for
+    /// each column.
+    fn parse_all(agg: &Value) -> Result<Vec<Spec>, ShellError> {
+        let columns = match &agg.value {
+            UntaggedValue::Row(columns) => columns,
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Expected aggregates",
+                    "expected a row of columns and functions, like { size: sum, name: count }",
+                    &agg.tag,
+                ))
+            }
+        };
+
+        let mut specs = vec![];
+        for (column, functions) in columns.entries.iter() {
+            let functions = match &functions.value {
+                UntaggedValue::Table(functions) => functions.iter().collect(),
+                _ => vec![functions],
+            };
+
+            for function in functions {
+                let aggregation = match Aggregation::from_name(as_string(function)?.trim()) {
+                    Some(aggregation) => aggregation,
+                    None => {
+                        return Err(ShellError::labeled_error(
+                            "Unknown aggregate function",
+                            "expected sum, avg, min, max, count, first or concat",
+                            &function.tag,
+                        ))
+                    }
+                };
+
+                specs.push(Spec {
+                    column: column.clone().tagged(&function.tag),
+                    aggregation,
+                });
+            }
+        }
+
+        if specs.is_empty() {
+            return Err(ShellError::labeled_error(
+                "Expected aggregates",
+                "requires at least one column and function",
+                &agg.tag,
+            ));
+        }
+
+        Ok(specs)
+    }
+}
+
+async fn aggregate(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (AggregateArgs { agg, by }, input) = args.process(&registry).await?;
+
+    let specs = Spec::parse_all(&agg)?;
+
+    let by: Vec<Tagged<String>> = match by {
+        None => vec![],
+        Some(Value {
+            value: UntaggedValue::Table(columns),
+            ..
+        }) => columns
+            .iter()
+            .map(|column| Ok(as_string(column)?.tagged(&column.tag)))
+            .collect::<Result<Vec<_>, ShellError>>()?,
+        Some(column) => vec![as_string(&column)?.tagged(&column.tag)],
+    };
+
+    Ok(futures::stream::iter(
+        aggregate_rows(input, &by, &specs, &name)
+            .await?
+            .into_iter()
+            .map(ReturnSuccess::value),
+    )
+    .to_output_stream())
+}
+
+/// The key cells (as they appear in the group's first row) and what's been accumulated for every
+/// aggregate.
+struct Group {
+    key_values: Vec<Value>,
+    accumulators: Vec<Accumulator>,
+}
+
+impl Group {
+    fn new(key_values: Vec<Value>, specs: &[Spec]) -> Group {
+        Group {
+            key_values,
+            accumulators: specs
+                .iter()
+                .map(|spec| spec.aggregation.accumulator())
+                .collect(),
+        }
+    }
+}
+
+async fn aggregate_rows(
+    mut rows: impl Stream<Item = Value> + Unpin,
+    by: &[Tagged<String>],
+    specs: &[Spec],
+    tag: &Tag,
+) -> Result<Vec<Value>, ShellError> {
+    let mut groups: IndexMap<Vec<String>, Group> = IndexMap::new();
+
+    while let Some(row) = rows.next().await {
+        let mut key = Vec::with_capacity(by.len());
+        let mut key_values = Vec::with_capacity(by.len());
+
+        for column in by {
+            match row.get_data_by_key(column.borrow_spanned()) {
+                Some(value) => {
+                    key.push(as_string(&value)?);
+                    key_values.push(value);
+                }
+                None => return Err(suggestions(column.borrow_tagged(), &row)),
+            }
+        }
+
+        let group = groups
+            .entry(key)
+            .or_insert_with(|| Group::new(key_values, specs));
+
+        for (idx, spec) in specs.iter().enumerate() {
+            match row.get_data_by_key(spec.column.borrow_spanned()) {
+                Some(Value {
+                    value: UntaggedValue::Primitive(Primitive::Nothing),
+                    ..
+                })
+                | None => {}
+                Some(value) => group.accumulators[idx].add(value)?,
+            }
+        }
+    }
+
+    if groups.is_empty() && by.is_empty() {
+        groups.insert(vec![], Group::new(vec![], specs));
+    }
+
+    let mut output = vec![];
+
+    for (
+        _,
+        Group {
+            key_values,
+            accumulators,
+        },
+    ) in groups
+    {
+        let mut out = TaggedDictBuilder::new(tag);
+        let mut columns = IndexSet::new();
+
+        for (column, value) in by.iter().zip(key_values) {
+            columns.insert(column.item.clone());
+            out.insert_value(column.item.clone(), value);
+        }
+
+        for (spec, accumulator) in specs.iter().zip(accumulators) {
+            let column = if columns.contains(&spec.column.item) {
+                format!("{}_{}", spec.column.item, spec.aggregation.name())
+            } else {
+                spec.column.item.clone()
+            };
+
+            columns.insert(column.clone());
+            out.insert_value(column, accumulator.finish(tag)?);
+        }
+
+        output.push(out.into_value());
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{aggregate_rows, Aggregate, Spec};
+    use futures::executor::block_on;
+    use indexmap::IndexMap;
+    use nu_errors::ShellError;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::*;
+
+    fn string(input: impl Into<String>) -> Value {
+        UntaggedValue::string(input.into()).into_untagged_value()
+    }
+
+    fn int(n: i64) -> Value {
+        UntaggedValue::int(n).into_untagged_value()
+    }
+
+    fn row(entries: IndexMap<String, Value>) -> Value {
+        UntaggedValue::row(entries).into_untagged_value()
+    }
+
+    fn table(list: &[Value]) -> Value {
+        UntaggedValue::table(list).into_untagged_value()
+    }
+
+    #[test]
+    fn aggregates_per_group() -> Result<(), ShellError> {
+        let rows = vec![
+            row(
+                indexmap! {"type".into() => string("File"), "name".into() => string("a"), "size".into() => int(10)},
+            ),
+            row(
+                indexmap! {"type".into() => string("Dir"), "name".into() => string("b"), "size".into() => int(1)},
+            ),
+            row(
+                indexmap! {"type".into() => string("File"), "name".into() => string("c"), "size".into() => int(5)},
+            ),
+        ];
+        let by = vec![String::from("type").tagged_unknown()];
+        let specs = Spec::parse_all(&row(
+            indexmap! {"size".into() => string("sum"), "name".into() => table(&[string("count"), string("concat")])},
+        ))?;
+
+        let actual = block_on(aggregate_rows(
+            futures::stream::iter(rows),
+            &by,
+            &specs,
+            &Tag::unknown(),
+        ))?;
+
+        assert_eq!(
+            actual,
+            vec![
+                row(
+                    indexmap! {"type".into() => string("File"), "size".into() => int(15), "name".into() => int(2), "name_concat".into() => string("a, c")}
+                ),
+                row(
+                    indexmap! {"type".into() => string("Dir"), "size".into() => int(1), "name".into() => int(1), "name_concat".into() => string("b")}
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_unknown_functions() {
+        assert!(Spec::parse_all(&row(indexmap! {"size".into() => string("median")})).is_err());
+        assert!(Spec::parse_all(&string("size:sum")).is_err());
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Aggregate {})
+    }
+}
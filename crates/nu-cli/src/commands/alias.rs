@@ -1,3 +1,4 @@
+use crate::commands::def::{parameter_words, Parameters};
 use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::data::config;
@@ -27,17 +28,29 @@ impl WholeStreamCommand for Alias {
     fn signature(&self) -> Signature {
         Signature::build("alias")
             .required("name", SyntaxShape::String, "the name of the alias")
-            .required("args", SyntaxShape::Table, "the arguments to the alias")
+            .required(
+                "args",
+                SyntaxShape::Table,
+                "the parameters of the alias, like those of def: [x, y: int = 1, --flag(-f)]",
+            )
             .required(
                 "block",
                 SyntaxShape::Block,
                 "the block to run as the body of the alias",
             )
-            .switch("save", "save the alias to your config", Some('s'))
+            .switch(
+                "save",
+                "save the alias to the startup commands of your config",
+                Some('s'),
+            )
     }
 
     fn usage(&self) -> &str {
-        "Define a shortcut for another command."
+        r#"Define a shortcut for another command.
+
+The parameters are written like those of def, and are given to the body as variables. The flags
+of the last command of the body can be given to the alias too, and the arguments after its
+parameters, and they are given on to that command."#
     }
 
     async fn run(
@@ -60,6 +73,17 @@ impl WholeStreamCommand for Alias {
                 example: "alias l [x] { ls $x }",
                 result: None,
             },
+            Example {
+                description:
+                    "An alias that gives its flags and arguments to ls, like `ll --all src`",
+                example: "alias ll [] { ls --full }",
+                result: None,
+            },
+            Example {
+                description: "An alias with a flag of its own, saved for the next sessions",
+                example: "alias big [--size: int = 10] { ls | where size > $size } --save",
+                result: None,
+            },
         ]
     }
 }
@@ -69,78 +93,121 @@ pub async fn alias(
     registry: &CommandRegistry,
 ) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
-    let mut raw_input = args.raw_input.clone();
+    let raw_input = args.raw_input.clone();
     let (
         AliasArgs {
             name,
-            args: list,
+            args: params,
             block,
             save,
         },
         _ctx,
     ) = args.process(&registry).await?;
-    let mut processed_args: Vec<String> = vec![];
+
+    // Check the parameters now, so a mistake shows up where the alias is defined
+    let parameters = Parameters::parse(&name.item, &parameter_words(params)?)?;
 
     if let Some(true) = save {
-        let mut result = crate::data::config::read(name.clone().tag, &None)?;
-
-        // process the alias to remove the --save flag
-        let left_brace = raw_input.find('{').unwrap_or(0);
-        let right_brace = raw_input.rfind('}').unwrap_or_else(|| raw_input.len());
-        let left = raw_input[..left_brace]
-            .replace("--save", "")
-            .replace("-s", "");
-        let right = raw_input[right_brace..]
-            .replace("--save", "")
-            .replace("-s", "");
-        raw_input = format!("{}{}{}", left, &raw_input[left_brace..right_brace], right);
-
-        // create a value from raw_input alias
-        let alias: Value = raw_input.trim().to_string().into();
-        let alias_start = raw_input.find('[').unwrap_or(0); // used to check if the same alias already exists
-
-        // add to startup if alias doesn't exist and replce if it does
-        match result.get_mut("startup") {
-            Some(startup) => {
-                if let UntaggedValue::Table(ref mut commands) = startup.value {
-                    if let Some(command) = commands.iter_mut().find(|command| {
-                        let cmd_str = command.as_string().unwrap_or_default();
-                        cmd_str.starts_with(&raw_input[..alias_start])
-                    }) {
-                        *command = alias;
-                    } else {
-                        commands.push(alias);
-                    }
+        save_alias(&name, &raw_input)?;
+    }
+
+    Ok(OutputStream::one(ReturnSuccess::action(
+        CommandAction::AddAlias(
+            name.item,
+            parameters.specs.into_iter().map(|spec| spec.item).collect(),
+            block,
+        ),
+    )))
+}
+
+/// Adds the alias, as it's written in `raw_input` but without `--save`, to the startup commands
+/// of the config, in place of an alias of the same name saved before.
+fn save_alias(name: &Tagged<String>, raw_input: &str) -> Result<(), ShellError> {
+    let alias = match alias_text(&name.item, raw_input) {
+        Some(alias) => alias,
+        None => {
+            return Err(ShellError::labeled_error(
+                "Could not save the alias",
+                "could not find the alias in the line to save it",
+                &name.tag,
+            ))
+        }
+    };
+
+    let mut result = config::read(name.tag.clone(), &None)?;
+    let alias_value: Value = alias.into();
+
+    match result.get_mut("startup") {
+        Some(startup) => {
+            if let UntaggedValue::Table(ref mut commands) = startup.value {
+                let saved = commands.iter_mut().find(|command| {
+                    let text = command.as_string().unwrap_or_default();
+                    alias_name(&text).as_deref() == Some(&name.item[..])
+                });
+
+                match saved {
+                    Some(command) => *command = alias_value,
+                    None => commands.push(alias_value),
                 }
             }
-            None => {
-                let table = UntaggedValue::table(&[alias]);
-                result.insert("startup".to_string(), table.into_value(Tag::default()));
-            }
         }
-        config::write(&result, &None)?;
+        None => {
+            let table = UntaggedValue::table(&[alias_value]);
+            result.insert("startup".to_string(), table.into_value(Tag::default()));
+        }
     }
 
-    for item in list.iter() {
-        if let Ok(string) = item.as_string() {
-            processed_args.push(format!("${}", string));
-        } else {
-            return Err(ShellError::labeled_error(
-                "Expected a string",
-                "expected a string",
-                item.tag(),
-            ));
-        }
+    config::write(&result, &None)
+}
+
+/// The alias named `name` in `text`, as it's written, without its `--save` flag.
+fn alias_text(name: &str, text: &str) -> Option<String> {
+    let lite_block = nu_parser::lite_parse_lines(text, 0).ok()?;
+    let command = lite_block
+        .block
+        .iter()
+        .flat_map(|pipeline| pipeline.commands.iter())
+        .find(|command| {
+            command.name.item == "alias"
+                && command
+                    .args
+                    .iter()
+                    .find(|arg| !arg.item.starts_with('-'))
+                    .map(|arg| &arg.item[..])
+                    == Some(name)
+        })?;
+
+    let mut words = vec![command.name.item.clone()];
+    words.extend(
+        command
+            .args
+            .iter()
+            .filter(|arg| arg.item != "--save" && arg.item != "-s")
+            .map(|arg| arg.item.clone()),
+    );
+
+    Some(words.join(" "))
+}
+
+/// The name of the alias a startup command defines, if it defines one.
+fn alias_name(text: &str) -> Option<String> {
+    let lite_block = nu_parser::lite_parse_lines(text, 0).ok()?;
+    let command = lite_block.block.first()?.commands.first()?;
+
+    if command.name.item != "alias" {
+        return None;
     }
 
-    Ok(OutputStream::one(ReturnSuccess::action(
-        CommandAction::AddAlias(name.to_string(), processed_args, block),
-    )))
+    command
+        .args
+        .iter()
+        .find(|arg| !arg.item.starts_with('-'))
+        .map(|arg| arg.item.clone())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Alias;
+    use super::{alias_name, alias_text, Alias};
 
     #[test]
     fn examples_work_as_expected() {
@@ -148,4 +215,17 @@ mod tests {
 
         test_examples(Alias {})
     }
+
+    #[test]
+    fn saves_the_alias_without_the_save_flag() {
+        assert_eq!(
+            alias_text("ls-s", "alias -s ls-s [x] { ls -s $x } --save; ls").as_deref(),
+            Some("alias ls-s [x] { ls -s $x }")
+        );
+        assert_eq!(
+            alias_name("alias ls-s [x] { ls -s $x }").as_deref(),
+            Some("ls-s")
+        );
+        assert_eq!(alias_name("echo ls-s"), None);
+    }
 }
@@ -0,0 +1,156 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+
+#[derive(Deserialize)]
+struct Arguments {
+    code: Tagged<String>,
+}
+
+pub struct Command;
+
+#[async_trait]
+impl WholeStreamCommand for Command {
+    fn name(&self) -> &str {
+        "ansi"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("ansi").required(
+            "code",
+            SyntaxShape::String,
+            "the name of the code to use, like 'green', 'bold' or 'reset'",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Output ANSI escape codes, for colors, text styles and cursor movement."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let name = args.call_info.name_tag.clone();
+        let (Arguments { code }, _) = args.process(&registry).await?;
+
+        match escape_code(&code.item) {
+            Some(escape) => Ok(OutputStream::one(ReturnSuccess::value(
+                UntaggedValue::string(escape).into_value(name),
+            ))),
+            None => Err(ShellError::labeled_error(
+                "Unknown ansi code",
+                format!("'{}' isn't the name of an ansi code", code.item),
+                &code.tag,
+            )),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Change the color of the text to green",
+                example: "ansi green",
+                result: Some(vec![UntaggedValue::string("\u{1b}[32m").into()]),
+            },
+            Example {
+                description: "Reset the colors and styles",
+                example: "ansi reset",
+                result: Some(vec![UntaggedValue::string("\u{1b}[0m").into()]),
+            },
+            Example {
+                description: "Build a colored string",
+                example: r#"build-string $(ansi bold) $(ansi red) "Hello" $(ansi reset)"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+const COLORS: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "purple", "cyan", "white",
+];
+
+/// The escape sequence for a code name. Colors come as `red`, `light_red`, `bg_red` and
+/// `bg_light_red`, `magenta` being the same as `purple`.
+fn escape_code(name: &str) -> Option<String> {
+    let name = name.to_lowercase().replace('-', "_");
+
+    let sgr = |n: u8| Some(format!("\u{1b}[{}m", n));
+    let csi = |s: &str| Some(format!("\u{1b}[{}", s));
+
+    match name.as_str() {
+        "reset" => sgr(0),
+        "bold" => sgr(1),
+        "dimmed" => sgr(2),
+        "italic" => sgr(3),
+        "underline" => sgr(4),
+        "blink" => sgr(5),
+        "reverse" => sgr(7),
+        "hidden" => sgr(8),
+        "strikethrough" => sgr(9),
+        "cursor_up" => csi("1A"),
+        "cursor_down" => csi("1B"),
+        "cursor_right" => csi("1C"),
+        "cursor_left" => csi("1D"),
+        "cursor_home" => csi("H"),
+        "cursor_save" => csi("s"),
+        "cursor_restore" => csi("u"),
+        "cursor_hide" => csi("?25l"),
+        "cursor_show" => csi("?25h"),
+        "clear_screen" => csi("2J"),
+        "clear_line" => csi("2K"),
+        color => {
+            let (color, background) = match color.strip_prefix("bg_") {
+                Some(color) => (color, true),
+                None => (color, false),
+            };
+            let (color, light) = match color.strip_prefix("light_") {
+                Some(color) => (color, true),
+                None => (color, false),
+            };
+            let color = if color == "magenta" { "purple" } else { color };
+
+            let offset = COLORS.iter().position(|c| *c == color)? as u8;
+            let base = match (background, light) {
+                (false, false) => 30,
+                (true, false) => 40,
+                (false, true) => 90,
+                (true, true) => 100,
+            };
+
+            sgr(base + offset)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape_code, Command};
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Command {})
+    }
+
+    #[test]
+    fn names_colors_and_styles() {
+        assert_eq!(escape_code("red"), Some("\u{1b}[31m".to_string()));
+        assert_eq!(escape_code("light_blue"), Some("\u{1b}[94m".to_string()));
+        assert_eq!(escape_code("bg_magenta"), Some("\u{1b}[45m".to_string()));
+        assert_eq!(
+            escape_code("bg-light-white"),
+            Some("\u{1b}[107m".to_string())
+        );
+        assert_eq!(escape_code("Bold"), Some("\u{1b}[1m".to_string()));
+        assert_eq!(escape_code("cursor_up"), Some("\u{1b}[1A".to_string()));
+        assert_eq!(escape_code("light_reset"), None);
+        assert_eq!(escape_code("orange"), None);
+    }
+}
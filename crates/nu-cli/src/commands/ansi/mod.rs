@@ -0,0 +1,5 @@
+mod command;
+mod strip;
+
+pub use command::Command as Ansi;
+pub use strip::SubCommand as AnsiStrip;
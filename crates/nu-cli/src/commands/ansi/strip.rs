@@ -0,0 +1,148 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tag;
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "ansi strip"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("ansi strip").rest(
+            SyntaxShape::ColumnPath,
+            "optionally remove ansi escape codes by column paths",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "removes ansi escape codes from text"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Remove the colors from text",
+                example: r#"build-string $(ansi green) "nu" $(ansi reset) | ansi strip"#,
+                result: None,
+            },
+            Example {
+                description: "Remove the colors from the output of an external command",
+                example: "^ls --color=always | lines | ansi strip",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn operate(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let (Arguments { rest }, input) = args.process(&registry).await?;
+
+    let column_paths: Vec<_> = rest;
+
+    Ok(input
+        .map(move |v| {
+            if column_paths.is_empty() {
+                match action(&v, v.tag()) {
+                    Ok(out) => ReturnSuccess::value(out),
+                    Err(err) => Err(err),
+                }
+            } else {
+                let mut ret = v;
+
+                for path in &column_paths {
+                    let swapping = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, old.tag())),
+                    );
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            return Err(err);
+                        }
+                    }
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    let tag = tag.into();
+
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => {
+            let stripped = strip_ansi_escapes::strip(s).map_err(|_| {
+                ShellError::labeled_error(
+                    "Could not remove ansi escape codes",
+                    "could not remove ansi escape codes",
+                    &tag,
+                )
+            })?;
+
+            Ok(UntaggedValue::string(String::from_utf8_lossy(&stripped)).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not string",
+                got,
+                tag.span,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, SubCommand};
+    use nu_plugin::test_helpers::value::string;
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn strips_escape_codes() {
+        let colored = string("\u{1b}[1m\u{1b}[31mnu\u{1b}[0m \u{1b}[2Kshell");
+
+        let actual = action(&colored, Tag::unknown()).unwrap();
+        assert_eq!(actual, string("nu shell"));
+    }
+}
@@ -3,10 +3,12 @@ use crate::context::CommandRegistry;
 use crate::prelude::*;
 use nu_errors::ShellError;
 use nu_protocol::{Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
 
 #[derive(Deserialize)]
 struct AppendArgs {
     row: Value,
+    at: Option<Tagged<usize>>,
 }
 
 pub struct Append;
@@ -18,15 +20,22 @@ impl WholeStreamCommand for Append {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("append").required(
-            "row value",
-            SyntaxShape::Any,
-            "the value of the row to append to the table",
-        )
+        Signature::build("append")
+            .required(
+                "row value",
+                SyntaxShape::Any,
+                "the value of the row to append to the table, or a table whose rows to append",
+            )
+            .named(
+                "at",
+                SyntaxShape::Int,
+                "the position to put the rows at, instead of the end",
+                Some('a'),
+            )
     }
 
     fn usage(&self) -> &str {
-        "Append the given row to the table"
+        "Append the given row, or the rows of the given table, to the table"
     }
 
     async fn run(
@@ -34,24 +43,58 @@ impl WholeStreamCommand for Append {
         args: CommandArgs,
         registry: &CommandRegistry,
     ) -> Result<OutputStream, ShellError> {
-        let (AppendArgs { row }, input) = args.process(registry).await?;
+        let (AppendArgs { row, at }, input) = args.process(registry).await?;
 
-        let eos = futures::stream::iter(vec![row]);
+        let rows = match row.value {
+            UntaggedValue::Table(rows) => rows,
+            _ => vec![row],
+        };
 
-        Ok(input.chain(eos).to_output_stream())
+        match at {
+            Some(at) => {
+                let mut table: Vec<Value> = input.collect().await;
+                let at = at.item.min(table.len());
+                table.splice(at..at, rows);
+
+                Ok(futures::stream::iter(table).to_output_stream())
+            }
+            None => Ok(input.chain(futures::stream::iter(rows)).to_output_stream()),
+        }
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Add something to the end of a list or table",
-            example: "echo [1 2 3] | append 4",
-            result: Some(vec![
-                UntaggedValue::int(1).into(),
-                UntaggedValue::int(2).into(),
-                UntaggedValue::int(3).into(),
-                UntaggedValue::int(4).into(),
-            ]),
-        }]
+        vec![
+            Example {
+                description: "Add something to the end of a list or table",
+                example: "echo [1 2 3] | append 4",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                    UntaggedValue::int(4).into(),
+                ]),
+            },
+            Example {
+                description: "Add the rows of another list or table to the end",
+                example: "echo [1 2] | append [3 4]",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                    UntaggedValue::int(4).into(),
+                ]),
+            },
+            Example {
+                description: "Put rows in the middle of a list or table",
+                example: "echo [1 4] | append [2 3] --at 1",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                    UntaggedValue::int(4).into(),
+                ]),
+            },
+        ]
     }
 }
 
@@ -1,10 +1,10 @@
 use crate::commands::UnevaluatedCallInfo;
 use crate::commands::WholeStreamCommand;
-use crate::data::value::format_leaf;
+use crate::data::value::{format_decimal, format_leaf};
 use crate::prelude::*;
 use nu_errors::ShellError;
 use nu_protocol::{hir, hir::Expression, hir::Literal, hir::SpannedExpression};
-use nu_protocol::{Primitive, Scope, Signature, UntaggedValue, Value};
+use nu_protocol::{Primitive, ReturnSuccess, Scope, Signature, UntaggedValue, Value};
 use parking_lot::Mutex;
 use prettytable::format::{FormatBuilder, LinePosition, LineSeparator};
 use prettytable::{color, Attr, Cell, Row, Table};
@@ -88,6 +88,7 @@ pub async fn autoview(context: RunnableContext) -> Result<OutputStream, ShellErr
     let binary = context.get_command("binaryview");
     let text = context.get_command("textview");
     let table = context.get_command("table");
+    let explore = context.get_command("explore");
 
     #[derive(PartialEq)]
     enum AutoPivotMode {
@@ -96,8 +97,8 @@ pub async fn autoview(context: RunnableContext) -> Result<OutputStream, ShellErr
         Never,
     }
 
-    let pivot_mode = crate::data::config::config(Tag::unknown());
-    let pivot_mode = if let Some(v) = pivot_mode?.get("pivot_mode") {
+    let config = crate::data::config::config(Tag::unknown())?;
+    let pivot_mode = if let Some(v) = config.get("pivot_mode") {
         match v.as_string() {
             Ok(m) if m.to_lowercase() == "auto" => AutoPivotMode::Auto,
             Ok(m) if m.to_lowercase() == "always" => AutoPivotMode::Always,
@@ -108,13 +109,58 @@ pub async fn autoview(context: RunnableContext) -> Result<OutputStream, ShellErr
         AutoPivotMode::Always
     };
 
+    let explore_large_output = config
+        .get("explore_large_output")
+        .map_or(false, |x| x.as_bool().unwrap_or(false));
+
     let (mut input_stream, context) = RunnableContextWithoutInput::convert(context);
 
     if let Some(x) = input_stream.next().await {
         match input_stream.next().await {
             Some(y) => {
                 let ctrl_c = context.ctrl_c.clone();
-                let xy = vec![x, y];
+                let mut xy = vec![x, y];
+
+                // A table taller than the terminal is explored instead, when the config asks
+                // for it, and only the rows selected in it are shown
+                if let (true, Some(explore), Some(height)) =
+                    (explore_large_output, &explore, terminal_height())
+                {
+                    while xy.len() <= height {
+                        match input_stream.next().await {
+                            Some(value) => xy.push(value),
+                            None => break,
+                        }
+                    }
+
+                    if xy.len() > height {
+                        xy.extend(input_stream.collect::<Vec<_>>().await);
+
+                        let command_args =
+                            create_default_command_args(&context).with_input(VecDeque::from(xy));
+                        let mut selection = VecDeque::new();
+                        for item in explore
+                            .run(command_args, &context.registry)
+                            .await
+                            .collect::<Vec<_>>()
+                            .await
+                        {
+                            if let ReturnSuccess::Value(value) = item? {
+                                selection.push_back(value);
+                            }
+                        }
+
+                        if let (false, Some(table)) = (selection.is_empty(), table) {
+                            let command_args =
+                                create_default_command_args(&context).with_input(selection);
+                            let result = table.run(command_args, &context.registry).await;
+                            result.collect::<Vec<_>>().await;
+                        }
+
+                        return Ok(OutputStream::empty());
+                    }
+                }
+
                 let xy_stream = futures::stream::iter(xy)
                     .chain(input_stream)
                     .interruptible(ctrl_c);
@@ -193,7 +239,10 @@ pub async fn autoview(context: RunnableContext) -> Result<OutputStream, ShellErr
                     } => {
                         // TODO: normalize decimal to remove trailing zeros.
                         // normalization will be available in next release of bigdecimal crate
-                        let mut output = n.to_string();
+                        let mut output = match crate::data::config::decimal_precision() {
+                            Some(precision) => format_decimal(&n, Some(precision)),
+                            None => n.to_string(),
+                        };
                         if output.contains('.') {
                             output = output.trim_end_matches('0').to_owned();
                         }
@@ -376,6 +425,20 @@ pub async fn autoview(context: RunnableContext) -> Result<OutputStream, ShellErr
     Ok(OutputStream::empty())
 }
 
+/// How many rows of a table fit on the terminal, next to its borders, its headers and the
+/// prompt. Without a terminal there's no room to go over.
+fn terminal_height() -> Option<usize> {
+    use crossterm::tty::IsTty;
+
+    if !std::io::stdout().is_tty() {
+        return None;
+    }
+
+    crossterm::terminal::size()
+        .ok()
+        .map(|(_, height)| (height as usize).saturating_sub(5))
+}
+
 fn create_default_command_args(context: &RunnableContextWithoutInput) -> RawCommandArgs {
     let span = context.name.span;
     RawCommandArgs {
@@ -393,6 +456,7 @@ fn create_default_command_args(context: &RunnableContextWithoutInput) -> RawComm
                 named: None,
                 span,
                 is_last: true,
+                capture_stderr: false,
             },
             name_tag: context.name.clone(),
             scope: Scope::new(),
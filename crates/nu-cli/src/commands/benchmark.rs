@@ -0,0 +1,232 @@
+use crate::commands::classified::block::run_block;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use bigdecimal::BigDecimal;
+use nu_errors::ShellError;
+use nu_protocol::{
+    hir::Block, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue,
+};
+use nu_source::Tagged;
+use num_bigint::BigInt;
+use std::time::{Duration, Instant};
+
+pub struct Benchmark;
+
+#[derive(Deserialize)]
+pub struct BenchmarkArgs {
+    block: Block,
+    runs: Option<Tagged<usize>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Benchmark {
+    fn name(&self) -> &str {
+        "benchmark"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("benchmark")
+            .required("block", SyntaxShape::Block, "the block to time")
+            .named(
+                "runs",
+                SyntaxShape::Int,
+                "the number of times to run the block (default: 10)",
+                Some('n'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Run a block several times and give how long it took: the shortest, mean and longest time, and the standard deviation, in milliseconds. On Linux, also give the peak memory used."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        benchmark(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Time reading a file",
+                example: "benchmark { open Cargo.toml }",
+                result: None,
+            },
+            Example {
+                description: "Time a pipeline over 100 runs",
+                example: "benchmark --runs 100 { ls | where size > 10kb | count }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn benchmark(
+    raw_args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = raw_args.call_info.name_tag.clone();
+    let scope = raw_args.call_info.scope.clone();
+    let mut context = Context::from_raw(&raw_args, &registry);
+    let (BenchmarkArgs { block, runs }, _) = raw_args.process(&registry).await?;
+
+    let runs = match runs {
+        Some(runs) if *runs == 0 => {
+            return Err(ShellError::labeled_error(
+                "Expected a positive number",
+                "the block has to run at least once",
+                runs.tag,
+            ))
+        }
+        Some(runs) => *runs,
+        None => 10,
+    };
+
+    let mut times = Vec::with_capacity(runs);
+    let mut peak = None;
+
+    for _ in 0..runs {
+        let measures_memory = reset_peak_memory();
+        let start = Instant::now();
+
+        run_block(
+            &block,
+            &mut context,
+            InputStream::empty(),
+            &scope.it,
+            &scope.vars,
+            &scope.env,
+        )
+        .await?
+        .drain_vec()
+        .await;
+
+        times.push(start.elapsed());
+
+        if let Some(err) = context.get_errors().first() {
+            return Err(err.clone());
+        }
+
+        if measures_memory {
+            peak = peak.max(peak_memory());
+        }
+    }
+
+    let stats = Stats::of(&times);
+
+    let mut dict = TaggedDictBuilder::new(&name);
+    dict.insert_untagged("runs", UntaggedValue::int(runs));
+    dict.insert_untagged("min ms", milliseconds(stats.min));
+    dict.insert_untagged("mean ms", milliseconds(stats.mean));
+    dict.insert_untagged("max ms", milliseconds(stats.max));
+    dict.insert_untagged("stddev ms", milliseconds(stats.stddev));
+    if let Some(peak) = peak {
+        dict.insert_untagged("peak memory", UntaggedValue::bytes(peak));
+    }
+
+    Ok(OutputStream::one(ReturnSuccess::value(dict.into_value())))
+}
+
+/// The timings of the runs, in microseconds.
+#[derive(Debug, PartialEq)]
+struct Stats {
+    min: u128,
+    mean: u128,
+    max: u128,
+    stddev: u128,
+}
+
+impl Stats {
+    fn of(times: &[Duration]) -> Stats {
+        let micros = times.iter().map(Duration::as_micros).collect::<Vec<_>>();
+        let count = micros.len().max(1) as f64;
+        let mean = micros.iter().sum::<u128>() as f64 / count;
+
+        // The sample standard deviation, as the runs are a sample of every run there could be.
+        let stddev = if micros.len() > 1 {
+            let squares = micros
+                .iter()
+                .map(|time| (*time as f64 - mean).powi(2))
+                .sum::<f64>();
+            (squares / (count - 1.0)).sqrt()
+        } else {
+            0.0
+        };
+
+        Stats {
+            min: micros.iter().copied().min().unwrap_or_default(),
+            mean: mean.round() as u128,
+            max: micros.iter().copied().max().unwrap_or_default(),
+            stddev: stddev.round() as u128,
+        }
+    }
+}
+
+fn milliseconds(micros: u128) -> UntaggedValue {
+    UntaggedValue::decimal(BigDecimal::new(BigInt::from(micros), 3))
+}
+
+/// Starts measuring the peak memory again, giving whether it can be measured. Linux keeps the
+/// peak of the whole process, and lets it be reset to the memory used now.
+fn reset_peak_memory() -> bool {
+    cfg_if::cfg_if! {
+        if #[cfg(target_os = "linux")] {
+            std::fs::write("/proc/self/clear_refs", "5").is_ok()
+        } else {
+            false
+        }
+    }
+}
+
+/// The most memory used since the peak was last reset, in bytes.
+fn peak_memory() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    let kilobytes = line.split_whitespace().nth(1)?.parse::<u64>().ok()?;
+
+    Some(kilobytes * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Benchmark, Stats};
+    use std::time::Duration;
+
+    #[test]
+    fn computes_stats() {
+        let times = [2, 4, 4, 4, 5, 5, 7, 9]
+            .iter()
+            .map(|ms| Duration::from_millis(*ms))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            Stats::of(&times),
+            Stats {
+                min: 2000,
+                mean: 5000,
+                max: 9000,
+                stddev: 2138,
+            }
+        );
+
+        assert_eq!(
+            Stats::of(&[Duration::from_micros(1500)]),
+            Stats {
+                min: 1500,
+                mean: 1500,
+                max: 1500,
+                stddev: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Benchmark {})
+    }
+}
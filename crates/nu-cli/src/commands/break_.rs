@@ -0,0 +1,52 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::{LoopControl, ShellError};
+use nu_protocol::Signature;
+
+pub struct Break;
+
+#[async_trait]
+impl WholeStreamCommand for Break {
+    fn name(&self) -> &str {
+        "break"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("break")
+    }
+
+    fn usage(&self) -> &str {
+        "Stop the loop it is in, going on after it."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        _registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Err(ShellError::loop_control(
+            LoopControl::Break,
+            args.call_info.name_tag.span,
+        ))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Ping hosts in turn, stopping at the first one that doesn't answer",
+            example: "for host in [alpha beta gamma] { try { ^ping -c 1 $host } catch { break } }",
+            result: None,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Break;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Break {})
+    }
+}
@@ -29,6 +29,12 @@ impl WholeStreamCommand for Cal {
                 "Display the month names instead of integers",
                 None,
             )
+            .named(
+                "week-start",
+                SyntaxShape::String,
+                "Display the calendar with the week starting on the given day, like monday (default: sunday)",
+                None,
+            )
     }
 
     fn usage(&self) -> &str {
@@ -55,6 +61,11 @@ impl WholeStreamCommand for Cal {
                 example: "cal --full-year 2012",
                 result: None,
             },
+            Example {
+                description: "This month's calendar with the weeks starting on Monday",
+                example: "cal --week-start monday",
+                result: None,
+            },
         ]
     }
 }
@@ -70,6 +81,11 @@ pub async fn cal(
 
     let (current_year, current_month, current_day) = get_current_date();
 
+    let week_start = match args.get("week-start") {
+        Some(day) => get_week_start(day)?,
+        None => 0,
+    };
+
     let mut selected_year: i32 = current_year;
     let mut current_day_option: Option<u32> = Some(current_day);
 
@@ -99,6 +115,7 @@ pub async fn cal(
         month_range,
         current_month,
         current_day_option,
+        week_start,
     );
 
     match add_months_of_year_to_table_result {
@@ -111,6 +128,32 @@ fn get_invalid_year_shell_error(year_tag: &Tag) -> ShellError {
     ShellError::labeled_error("The year is invalid", "invalid year", year_tag)
 }
 
+const DAYS_OF_THE_WEEK: [&str; 7] = [
+    "sunday",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+];
+
+/// The number of days from Sunday of the day the week starts on. Days can be shortened, like mo.
+fn get_week_start(day: &Value) -> Result<usize, ShellError> {
+    let name = day.as_string()?.to_lowercase();
+
+    DAYS_OF_THE_WEEK
+        .iter()
+        .position(|day| name.len() >= 2 && day.starts_with(&name))
+        .ok_or_else(|| {
+            ShellError::labeled_error(
+                "The day of the week is invalid",
+                "expected a day like sunday or monday",
+                day.tag(),
+            )
+        })
+}
+
 struct MonthHelper {
     selected_year: i32,
     selected_month: u32,
@@ -175,6 +218,7 @@ fn add_months_of_year_to_table(
     (start_month, end_month): (u32, u32),
     current_month: u32,
     current_day_option: Option<u32>,
+    week_start: usize,
 ) -> Result<(), ShellError> {
     for month_number in start_month..=end_month {
         let mut new_current_day_option: Option<u32> = None;
@@ -192,6 +236,7 @@ fn add_months_of_year_to_table(
             selected_year,
             month_number,
             new_current_day_option,
+            week_start,
         );
 
         add_month_to_table_result?
@@ -207,6 +252,7 @@ fn add_month_to_table(
     selected_year: i32,
     current_month: u32,
     current_day_option: Option<u32>,
+    week_start: usize,
 ) -> Result<(), ShellError> {
     let month_helper_result = MonthHelper::new(selected_year, current_month);
 
@@ -226,19 +272,19 @@ fn add_month_to_table(
         },
     };
 
-    let day_limit =
-        month_helper.number_of_days_in_month + month_helper.day_number_of_week_month_starts_on;
+    // The number of days before the first of the month in its first week
+    let day_number_of_week_month_starts_on =
+        (month_helper.day_number_of_week_month_starts_on + 7 - week_start as u32) % 7;
+
+    let day_limit = month_helper.number_of_days_in_month + day_number_of_week_month_starts_on;
     let mut day_count: u32 = 1;
 
-    let days_of_the_week = [
-        "sunday",
-        "monday",
-        "tuesday",
-        "wednesday",
-        "thursday",
-        "friday",
-        "saturday",
-    ];
+    let days_of_the_week = DAYS_OF_THE_WEEK
+        .iter()
+        .cycle()
+        .skip(week_start)
+        .take(7)
+        .collect::<Vec<_>>();
 
     let should_show_year_column = args.has("year");
     let should_show_quarter_column = args.has("quarter");
@@ -273,14 +319,13 @@ fn add_month_to_table(
         }
 
         for day in &days_of_the_week {
-            let should_add_day_number_to_table = (day_count <= day_limit)
-                && (day_count > month_helper.day_number_of_week_month_starts_on);
+            let should_add_day_number_to_table =
+                (day_count <= day_limit) && (day_count > day_number_of_week_month_starts_on);
 
             let mut value = UntaggedValue::nothing().into_value(tag);
 
             if should_add_day_number_to_table {
-                let day_count_with_offset =
-                    day_count - month_helper.day_number_of_week_month_starts_on;
+                let day_count_with_offset = day_count - day_number_of_week_month_starts_on;
 
                 value = UntaggedValue::int(day_count_with_offset).into_value(tag);
 
@@ -0,0 +1,159 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+
+#[derive(Deserialize)]
+struct CharArgs {
+    name: Option<Tagged<String>>,
+    unicode: Option<Tagged<String>>,
+}
+
+pub struct Char;
+
+#[async_trait]
+impl WholeStreamCommand for Char {
+    fn name(&self) -> &str {
+        "char"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("char")
+            .optional(
+                "name",
+                SyntaxShape::String,
+                "the name of the character to output, like 'newline' or 'pipe'",
+            )
+            .named(
+                "unicode",
+                SyntaxShape::String,
+                "the hexadecimal unicode code point of the character to output",
+                Some('u'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Output special characters, like newlines and pipes, that are awkward to write in text."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let name_tag = args.call_info.name_tag.clone();
+        let (CharArgs { name, unicode }, _) = args.process(&registry).await?;
+
+        let character = match (name, unicode) {
+            (Some(name), None) => named_char(&name.item).ok_or_else(|| {
+                ShellError::labeled_error(
+                    "Unknown character name",
+                    format!("'{}' isn't the name of a character", name.item),
+                    &name.tag,
+                )
+            })?,
+            (None, Some(unicode)) => code_point(&unicode.item).ok_or_else(|| {
+                ShellError::labeled_error(
+                    "Invalid unicode code point",
+                    "expected a hexadecimal code point, like 1F600",
+                    &unicode.tag,
+                )
+            })?,
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Expected a character name or a unicode code point",
+                    "give either a character name or --unicode",
+                    &name_tag,
+                ))
+            }
+        };
+
+        Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::string(character.to_string()).into_value(name_tag),
+        )))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Output a newline",
+                example: "char newline",
+                result: Some(vec![UntaggedValue::string("\n").into()]),
+            },
+            Example {
+                description: "Output a character from its unicode code point",
+                example: "char -u 1F600",
+                result: Some(vec![UntaggedValue::string("\u{1F600}").into()]),
+            },
+            Example {
+                description: "Join text with pipes",
+                example: "build-string a $(char pipe) b",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn named_char(name: &str) -> Option<char> {
+    let character = match name.to_lowercase().replace('-', "_").as_str() {
+        "newline" | "enter" | "nl" => '\n',
+        "carriage_return" | "cr" => '\r',
+        "tab" => '\t',
+        "space" | "sp" => ' ',
+        "null" => '\0',
+        "escape" | "esc" => '\u{1b}',
+        "pipe" => '|',
+        "semicolon" => ';',
+        "hash" => '#',
+        "dollar" => '$',
+        "backtick" => '`',
+        "single_quote" => '\'',
+        "double_quote" => '"',
+        "left_paren" | "lparen" => '(',
+        "right_paren" | "rparen" => ')',
+        "left_brace" | "lbrace" => '{',
+        "right_brace" | "rbrace" => '}',
+        "left_bracket" | "lbracket" => '[',
+        "right_bracket" | "rbracket" => ']',
+        _ => return None,
+    };
+
+    Some(character)
+}
+
+fn code_point(hex: &str) -> Option<char> {
+    let hex = hex.trim_start_matches("U+").trim_start_matches("u+");
+
+    u32::from_str_radix(hex, 16)
+        .ok()
+        .and_then(std::char::from_u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{code_point, named_char, Char};
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Char {})
+    }
+
+    #[test]
+    fn finds_characters_by_name() {
+        assert_eq!(named_char("pipe"), Some('|'));
+        assert_eq!(named_char("Left-Brace"), Some('{'));
+        assert_eq!(named_char("unknown"), None);
+    }
+
+    #[test]
+    fn finds_characters_by_code_point() {
+        assert_eq!(code_point("1F600"), Some('😀'));
+        assert_eq!(code_point("U+41"), Some('A'));
+        assert_eq!(code_point("D800"), None);
+        assert_eq!(code_point("nope"), None);
+    }
+}
@@ -0,0 +1,354 @@
+use crate::commands::WholeStreamCommand;
+use crate::path::absolutize;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::{SpannedItem, Tagged, TaggedItem};
+use nu_value_ext::get_data_by_key;
+use std::path::{Path, PathBuf};
+
+pub struct Chmod;
+
+#[derive(Deserialize)]
+pub struct ChmodArgs {
+    mode: Tagged<String>,
+    rest: Vec<Tagged<PathBuf>>,
+    recursive: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Chmod {
+    fn name(&self) -> &str {
+        "chmod"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("chmod")
+            .required(
+                "mode",
+                SyntaxShape::String,
+                "the permissions, in octal like 644 or symbolic like u+x,go-w",
+            )
+            .rest(
+                SyntaxShape::Pattern,
+                "the paths to change (default: the paths in the pipeline)",
+            )
+            .switch(
+                "recursive",
+                "also change everything inside directories",
+                Some('r'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Change the permissions of files and directories. On Windows, only the write permission of the owner is used, to make files read-only."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        chmod(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Make a script executable",
+                example: "chmod +x build.sh",
+                result: None,
+            },
+            Example {
+                description: "Let only the owner read and write the files in a directory",
+                example: "chmod -r 600 secrets",
+                result: None,
+            },
+            Example {
+                description:
+                    "Take away the write permission from everyone but the owner, when anyone has it",
+                example: "ls --long | where octal =~ '[2367]$' | chmod go-w",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// Who a symbolic mode clause is about, as the permission bits of each one.
+const USER: u32 = 0o4700;
+const GROUP: u32 = 0o2070;
+const OTHERS: u32 = 0o1007;
+
+/// A mode, like `755` or `u+x,go-w`.
+#[derive(Debug, PartialEq)]
+enum Mode {
+    Octal(u32),
+    Symbolic(Vec<Clause>),
+}
+
+/// A clause of a symbolic mode, like `go-w`.
+#[derive(Debug, PartialEq)]
+struct Clause {
+    who: u32,
+    op: char,
+    perms: String,
+}
+
+impl Mode {
+    fn parse(mode: &str) -> Option<Mode> {
+        if !mode.is_empty() && mode.chars().all(|c| c.is_digit(8)) {
+            return u32::from_str_radix(mode, 8)
+                .ok()
+                .filter(|mode| *mode <= 0o7777)
+                .map(Mode::Octal);
+        }
+
+        mode.split(',')
+            .map(|clause| {
+                let op_at = clause.find(&['+', '-', '='][..])?;
+                let (who, rest) = clause.split_at(op_at);
+
+                let who = who.chars().try_fold(0, |who, c| match c {
+                    'u' => Some(who | USER),
+                    'g' => Some(who | GROUP),
+                    'o' => Some(who | OTHERS),
+                    'a' => Some(who | USER | GROUP | OTHERS),
+                    _ => None,
+                })?;
+
+                let op = rest.chars().next()?;
+                let perms = &rest[1..];
+                if !perms
+                    .chars()
+                    .all(|c| matches!(c, 'r' | 'w' | 'x' | 'X' | 's' | 't'))
+                {
+                    return None;
+                }
+
+                Some(Clause {
+                    who: if who == 0 { USER | GROUP | OTHERS } else { who },
+                    op,
+                    perms: perms.to_string(),
+                })
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(Mode::Symbolic)
+    }
+
+    /// The permission bits after applying the mode to the current ones.
+    fn apply(&self, current: u32, is_dir: bool) -> u32 {
+        let clauses = match self {
+            Mode::Octal(mode) => return *mode,
+            Mode::Symbolic(clauses) => clauses,
+        };
+
+        clauses.iter().fold(current & 0o7777, |mode, clause| {
+            let bits = clause.perms.chars().fold(0, |bits, c| {
+                bits | match c {
+                    'r' => 0o444,
+                    'w' => 0o222,
+                    'x' => 0o111,
+                    // Execute only for directories, and files someone can execute already.
+                    'X' if is_dir || current & 0o111 != 0 => 0o111,
+                    's' => 0o6000,
+                    't' => 0o1000,
+                    _ => 0,
+                }
+            }) & clause.who;
+
+            match clause.op {
+                '+' => mode | bits,
+                '-' => mode & !bits,
+                _ => (mode & !(clause.who & 0o777)) | bits,
+            }
+        })
+    }
+}
+
+async fn chmod(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let cwd = PathBuf::from(args.shell_manager.path());
+    let (
+        ChmodArgs {
+            mode,
+            rest,
+            recursive,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    let parsed = Mode::parse(&mode.item).ok_or_else(|| {
+        ShellError::labeled_error(
+            "Invalid mode",
+            "expected an octal mode like 644, or a symbolic mode like u+x,go-w",
+            &mode.tag,
+        )
+    })?;
+
+    for path in paths_to_change(rest, input, &cwd).await? {
+        each_path(&path, recursive, &mut |path, metadata| {
+            let mut permissions = metadata.permissions();
+
+            cfg_if::cfg_if! {
+                if #[cfg(unix)] {
+                    use std::os::unix::fs::PermissionsExt;
+
+                    permissions.set_mode(parsed.apply(permissions.mode(), metadata.is_dir()));
+                } else {
+                    let current = if permissions.readonly() { 0o555 } else { 0o777 };
+                    let mode = parsed.apply(current, metadata.is_dir());
+                    permissions.set_readonly(mode & 0o200 == 0);
+                }
+            }
+
+            std::fs::set_permissions(path, permissions)
+        })?;
+    }
+
+    Ok(OutputStream::empty())
+}
+
+/// The paths given, with their patterns expanded, or else the ones in the pipeline: paths, text,
+/// or rows with a `name` column, like the ones from `ls`.
+pub(crate) async fn paths_to_change(
+    rest: Vec<Tagged<PathBuf>>,
+    input: InputStream,
+    cwd: &Path,
+) -> Result<Vec<Tagged<PathBuf>>, ShellError> {
+    let paths = if rest.is_empty() {
+        input
+            .collect::<Vec<Value>>()
+            .await
+            .iter()
+            .map(path_from_value)
+            .collect::<Result<Vec<_>, _>>()?
+    } else {
+        rest
+    };
+
+    let mut expanded = vec![];
+
+    for path in paths {
+        let absolute = absolutize(cwd, &path.item);
+        let matches = glob::glob(&absolute.to_string_lossy())
+            .map(|paths| paths.filter_map(Result::ok).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        if matches.is_empty() {
+            expanded.push(absolute.tagged(path.tag));
+        } else {
+            expanded.extend(matches.into_iter().map(|m| m.tagged(&path.tag)));
+        }
+    }
+
+    Ok(expanded)
+}
+
+pub(crate) fn path_from_value(value: &Value) -> Result<Tagged<PathBuf>, ShellError> {
+    let path = match &value.value {
+        UntaggedValue::Row(_) => {
+            get_data_by_key(value, "name".spanned_unknown()).and_then(|name| name.as_path().ok())
+        }
+        UntaggedValue::Primitive(Primitive::Line(line)) => Some(PathBuf::from(line.trim_end())),
+        _ => value.as_path().ok(),
+    };
+
+    path.map(|path| path.tagged(&value.tag)).ok_or_else(|| {
+        ShellError::labeled_error(
+            "Expected a path",
+            "expected a path, or a row with a name column",
+            &value.tag,
+        )
+    })
+}
+
+/// Runs the change on the path and, when recursive, on everything inside it. Symlinks inside
+/// directories are left alone.
+pub(crate) fn each_path(
+    path: &Tagged<PathBuf>,
+    recursive: bool,
+    change: &mut dyn FnMut(&Path, &std::fs::Metadata) -> std::io::Result<()>,
+) -> Result<(), ShellError> {
+    let fail = |e: std::io::Error, at: &Path| {
+        ShellError::labeled_error(
+            format!("Could not change {:?}. {}", at, e),
+            e.to_string(),
+            &path.tag,
+        )
+    };
+
+    let mut pending = vec![path.item.clone()];
+    let mut first = true;
+
+    while let Some(next) = pending.pop() {
+        let metadata = if first {
+            std::fs::metadata(&next)
+        } else {
+            std::fs::symlink_metadata(&next)
+        }
+        .map_err(|e| fail(e, &next))?;
+        first = false;
+
+        if metadata.file_type().is_symlink() {
+            continue;
+        }
+
+        change(&next, &metadata).map_err(|e| fail(e, &next))?;
+
+        if recursive && metadata.is_dir() {
+            for entry in std::fs::read_dir(&next).map_err(|e| fail(e, &next))? {
+                pending.push(entry.map_err(|e| fail(e, &next))?.path());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Chmod, Mode};
+
+    fn apply(mode: &str, current: u32) -> u32 {
+        Mode::parse(mode)
+            .expect("invalid mode")
+            .apply(current, false)
+    }
+
+    #[test]
+    fn parses_modes() {
+        assert_eq!(Mode::parse("755"), Some(Mode::Octal(0o755)));
+        assert_eq!(Mode::parse("4755"), Some(Mode::Octal(0o4755)));
+        assert_eq!(Mode::parse("17777"), None);
+        assert_eq!(Mode::parse("u+q"), None);
+        assert_eq!(Mode::parse("z+x"), None);
+        assert_eq!(Mode::parse("ux"), None);
+        assert_eq!(Mode::parse(""), None);
+    }
+
+    #[test]
+    fn applies_symbolic_modes() {
+        assert_eq!(apply("u+x", 0o644), 0o744);
+        assert_eq!(apply("+x", 0o644), 0o755);
+        assert_eq!(apply("go-w", 0o666), 0o644);
+        assert_eq!(apply("a=r", 0o755), 0o444);
+        assert_eq!(apply("u=rw,go=", 0o755), 0o600);
+        assert_eq!(apply("g+s", 0o755), 0o2755);
+    }
+
+    #[test]
+    fn gives_execute_to_directories_with_capital_x() {
+        let mode = Mode::parse("a+X").expect("invalid mode");
+
+        assert_eq!(mode.apply(0o644, false), 0o644);
+        assert_eq!(mode.apply(0o744, false), 0o755);
+        assert_eq!(mode.apply(0o644, true), 0o755);
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Chmod {})
+    }
+}
@@ -0,0 +1,202 @@
+use crate::commands::chmod::{each_path, paths_to_change};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape};
+use nu_source::Tagged;
+use std::path::PathBuf;
+
+pub struct Chown;
+
+pub struct Chgrp;
+
+#[derive(Deserialize)]
+pub struct ChownArgs {
+    owner: Tagged<String>,
+    rest: Vec<Tagged<PathBuf>>,
+    recursive: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Chown {
+    fn name(&self) -> &str {
+        "chown"
+    }
+
+    fn signature(&self) -> Signature {
+        signature(
+            "chown",
+            "the user, and optionally the group, like alice or alice:staff",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Change the user and group that own files and directories."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        change_owner(args, registry, Owner::User).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Give a directory and everything in it to a user and their group",
+                example: "chown -r alice:alice /home/alice",
+                result: None,
+            },
+            Example {
+                description: "Give back the files owned by root",
+                example: "ls --long | where uid == root | chown alice",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[async_trait]
+impl WholeStreamCommand for Chgrp {
+    fn name(&self) -> &str {
+        "chgrp"
+    }
+
+    fn signature(&self) -> Signature {
+        signature("chgrp", "the group, like staff")
+    }
+
+    fn usage(&self) -> &str {
+        "Change the group that owns files and directories."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        change_owner(args, registry, Owner::Group).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Share a directory with a group",
+            example: "chgrp -r staff shared",
+            result: None,
+        }]
+    }
+}
+
+fn signature(name: &str, owner: &str) -> Signature {
+    Signature::build(name)
+        .required("owner", SyntaxShape::String, owner)
+        .rest(
+            SyntaxShape::Pattern,
+            "the paths to change (default: the paths in the pipeline)",
+        )
+        .switch(
+            "recursive",
+            "also change everything inside directories",
+            Some('r'),
+        )
+}
+
+/// Which owner the command is about.
+#[derive(Clone, Copy)]
+enum Owner {
+    User,
+    Group,
+}
+
+async fn change_owner(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+    which: Owner,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let cwd = PathBuf::from(args.shell_manager.path());
+    let (
+        ChownArgs {
+            owner,
+            rest,
+            recursive,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    let (uid, gid) = match which {
+        Owner::User => match owner.item.find(':') {
+            Some(idx) => (
+                Some(user_id(&owner.item[..idx], &owner.tag)?),
+                group_id(&owner.item[idx + 1..], &owner.tag)?,
+            ),
+            None => (Some(user_id(&owner.item, &owner.tag)?), None),
+        },
+        Owner::Group => (None, group_id(&owner.item, &owner.tag)?),
+    };
+
+    for path in paths_to_change(rest, input, &cwd).await? {
+        each_path(&path, recursive, &mut |path, _| {
+            std::os::unix::fs::chown(path, uid, gid)
+        })?;
+    }
+
+    Ok(OutputStream::empty())
+}
+
+fn user_id(name: &str, tag: &Tag) -> Result<u32, ShellError> {
+    name.parse()
+        .ok()
+        .or_else(|| users::get_user_by_name(name).map(|user| user.uid()))
+        .ok_or_else(|| {
+            ShellError::labeled_error(
+                format!("Unknown user {}", name),
+                "no user with this name",
+                tag,
+            )
+        })
+}
+
+/// An empty group, like in `alice:`, leaves the group as it is.
+fn group_id(name: &str, tag: &Tag) -> Result<Option<u32>, ShellError> {
+    if name.is_empty() {
+        return Ok(None);
+    }
+
+    name.parse()
+        .ok()
+        .or_else(|| users::get_group_by_name(name).map(|group| group.gid()))
+        .map(Some)
+        .ok_or_else(|| {
+            ShellError::labeled_error(
+                format!("Unknown group {}", name),
+                "no group with this name",
+                tag,
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{group_id, user_id, Chgrp, Chown};
+    use nu_source::Tag;
+
+    #[test]
+    fn reads_names_and_ids() {
+        assert_eq!(user_id("0", &Tag::unknown()).ok(), Some(0));
+        assert_eq!(user_id("root", &Tag::unknown()).ok(), Some(0));
+        assert!(user_id("no-such-user-here", &Tag::unknown()).is_err());
+        assert_eq!(group_id("", &Tag::unknown()).ok(), Some(None));
+        assert_eq!(group_id("0", &Tag::unknown()).ok(), Some(Some(0)));
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Chown {});
+        test_examples(Chgrp {});
+    }
+}
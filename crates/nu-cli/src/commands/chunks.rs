@@ -0,0 +1,104 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+
+pub struct Chunks;
+
+#[derive(Deserialize)]
+pub struct ChunksArgs {
+    size: Tagged<usize>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Chunks {
+    fn name(&self) -> &str {
+        "chunks"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("chunks").required(
+            "size",
+            SyntaxShape::Int,
+            "the number of rows in each chunk",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Splits the rows into tables of a fixed size as they stream by (the last one may be smaller)."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        chunks(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Batch rows by two",
+            example: "echo [1 2 3] | chunks 2",
+            result: Some(vec![
+                UntaggedValue::Table(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                ])
+                .into(),
+                UntaggedValue::Table(vec![UntaggedValue::int(3).into()]).into(),
+            ]),
+        }]
+    }
+}
+
+async fn chunks(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (ChunksArgs { size }, input) = args.process(&registry).await?;
+
+    if *size == 0 {
+        return Err(ShellError::labeled_error(
+            "Expected a positive number",
+            "the size of chunks can't be zero",
+            size.tag,
+        ));
+    }
+
+    // The chunks grow as rows come in, so a size far larger than the input takes no more room
+    // than the input does
+    let size = *size;
+    let chunks = futures::stream::unfold(input, move |mut input| async move {
+        let mut rows = vec![];
+        while rows.len() < size {
+            match input.next().await {
+                Some(row) => rows.push(row),
+                None => break,
+            }
+        }
+
+        if rows.is_empty() {
+            None
+        } else {
+            Some((rows, input))
+        }
+    });
+
+    Ok(chunks
+        .map(move |rows| ReturnSuccess::value(UntaggedValue::table(&rows).into_value(&name)))
+        .to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Chunks;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Chunks {})
+    }
+}
@@ -1,23 +1,148 @@
 use crate::commands::classified::expr::run_expression_block;
 use crate::commands::classified::internal::run_internal_command;
-use crate::context::Context;
+use crate::context::{CommandRegistry, Context};
+use crate::evaluate::evaluate_baseline_expr;
+use crate::evaluate::operator::apply_operator;
 use crate::prelude::*;
 use crate::stream::InputStream;
 use futures::stream::TryStreamExt;
 use nu_errors::ShellError;
-use nu_protocol::hir::{Block, ClassifiedCommand, Commands};
+use nu_protocol::hir::{
+    Block, ClassifiedCommand, Commands, Expression, Literal, Operator, SpannedExpression,
+};
 use nu_protocol::{ReturnSuccess, UntaggedValue, Value};
 use std::sync::atomic::Ordering;
 
 pub(crate) async fn run_block(
     block: &Block,
     ctx: &mut Context,
-    mut input: InputStream,
+    input: InputStream,
+    it: &Value,
+    vars: &IndexMap<String, Value>,
+    env: &IndexMap<String, String>,
+) -> Result<InputStream, ShellError> {
+    run_block_with_params(block, ctx, input, it, vars, &IndexMap::new(), env).await
+}
+
+/// Runs the block like `run_block`, giving it `params`, like the parameters of a `def` or the
+/// `$acc` of `reduce`, on top of the variables it was made with.
+pub(crate) async fn run_block_with_params(
+    block: &Block,
+    ctx: &mut Context,
+    input: InputStream,
+    it: &Value,
+    vars: &IndexMap<String, Value>,
+    params: &IndexMap<String, Value>,
+    env: &IndexMap<String, String>,
+) -> Result<InputStream, ShellError> {
+    // The variables the block was made with take the place of the ones of the same name it is
+    // given, even those made with mut, so it sees them as they were when it was made
+    let mut scope = vars.clone();
+    for name in block.captures.keys() {
+        let variable = name.strip_prefix("mut ").unwrap_or(name);
+        scope.shift_remove(variable);
+        scope.shift_remove(&mutable_key(variable));
+    }
+    scope.extend(block.captures.clone());
+
+    for (variable, value) in params {
+        set_var(&mut scope, variable.clone(), value.clone());
+    }
+
+    // The variables the block makes with mut are gone once it's done, which is once its output
+    // is, as the last pipeline runs while it's read
+    let given = mutable_slots(&scope);
+    let output = run_block_with_vars(block, ctx, input, it, &mut scope, env).await;
+    let made = MadeMutables {
+        registry: ctx.registry().clone(),
+        slots: mutable_slots(&scope)
+            .into_iter()
+            .filter(|slot| !given.contains(slot))
+            .collect(),
+    };
+
+    let output = output?;
+    if made.slots.is_empty() {
+        return Ok(output);
+    }
+
+    Ok(InputStream::from_stream(output.map(move |value| {
+        let _ = &made;
+        value
+    })))
+}
+
+/// The slots of the variables made with `mut` by a block, freed when it's done with them.
+struct MadeMutables {
+    registry: CommandRegistry,
+    slots: Vec<usize>,
+}
+
+impl Drop for MadeMutables {
+    fn drop(&mut self) {
+        self.registry.free_mutables(&self.slots);
+    }
+}
+
+/// The slots of the variables made with `mut` in `vars`.
+fn mutable_slots(vars: &IndexMap<String, Value>) -> Vec<usize> {
+    vars.iter()
+        .filter(|(key, _)| key.starts_with("mut "))
+        .filter_map(|(_, slot)| slot.as_u64().ok().map(|slot| slot as usize))
+        .collect()
+}
+
+/// Runs a condition, like the one of `if` or `while`, giving whether it holds.
+pub(crate) async fn run_condition(
+    condition: &Block,
+    ctx: &mut Context,
     it: &Value,
     vars: &IndexMap<String, Value>,
     env: &IndexMap<String, String>,
+) -> Result<bool, ShellError> {
+    let values: Vec<Value> = run_block(condition, ctx, InputStream::empty(), it, vars, env)
+        .await?
+        .collect()
+        .await;
+
+    if let Some(err) = ctx.get_errors().into_iter().next() {
+        ctx.clear_errors();
+        return Err(err);
+    }
+
+    match &values[..] {
+        [Value {
+            value: UntaggedValue::Error(err),
+            ..
+        }] => Err(err.clone()),
+        [value] => value.as_bool(),
+        _ => Err(ShellError::labeled_error(
+            "Expected a condition",
+            "expected a condition",
+            condition.span,
+        )),
+    }
+}
+
+/// Runs the block like `run_block`, keeping the variables set in it with `let` and `mut` in `vars`.
+pub(crate) async fn run_block_with_vars(
+    block: &Block,
+    ctx: &mut Context,
+    mut input: InputStream,
+    it: &Value,
+    vars: &mut IndexMap<String, Value>,
+    env: &IndexMap<String, String>,
 ) -> Result<InputStream, ShellError> {
     let mut output: Result<InputStream, ShellError> = Ok(InputStream::empty());
+
+    // Variables set in the session by one pipeline, like with load-env, are seen by the next ones
+    let session = if block.block.len() > 1 {
+        Some(ctx.get_env())
+    } else {
+        None
+    };
+    let mut env = env.clone();
+
     for pipeline in &block.block {
         match output {
             Ok(inp) if inp.is_empty() => {}
@@ -54,7 +179,42 @@ pub(crate) async fn run_block(
                 return Err(e);
             }
         }
-        output = run_pipeline(pipeline, ctx, input, it, vars, env).await;
+        if let Some(session) = &session {
+            for (name, value) in ctx.get_env() {
+                if session.get(&name) != Some(&value) {
+                    env.insert(name, value);
+                }
+            }
+        }
+
+        // A block run by the pipeline before, like the body of a loop, may have set variables
+        // made with mut
+        refresh_mutables(ctx.registry(), vars);
+
+        if let Some(binding) = binding(pipeline) {
+            let variable = format!("${}", binding.name);
+            let value =
+                evaluate_baseline_expr(binding.value, ctx.registry(), it, vars, &env).await?;
+
+            match binding.kind {
+                "let" => set_var(vars, variable, value),
+                "mut" => {
+                    let slot = ctx.registry().make_mutable(value.clone());
+                    vars.insert(
+                        mutable_key(&variable),
+                        UntaggedValue::int(slot).into_value(Tag::unknown()),
+                    );
+                    vars.insert(variable, value);
+                }
+                _ => assign(&binding, variable, value, ctx.registry(), vars)?,
+            }
+
+            output = Ok(InputStream::from_stream(futures::stream::iter(vec![])));
+            input = InputStream::empty();
+            continue;
+        }
+
+        output = run_pipeline(pipeline, ctx, input, it, vars, &env).await;
 
         input = InputStream::empty();
     }
@@ -62,6 +222,118 @@ pub(crate) async fn run_block(
     output
 }
 
+/// A pipeline that sets a variable, which is run by the block it is in rather than as a command.
+struct Binding<'a> {
+    /// `let` or `mut`, or the operator of an assignment, like `=` or `+=`.
+    kind: &'a str,
+    name: &'a str,
+    span: Span,
+    value: &'a SpannedExpression,
+}
+
+/// The binding of a pipeline like `let name = value` or `$name += value`.
+fn binding(pipeline: &Commands) -> Option<Binding> {
+    match &pipeline.list[..] {
+        [ClassifiedCommand::Internal(command)] => match command.name.as_str() {
+            "let" | "mut" | "=" | "+=" | "-=" => match command.args.positional.as_deref() {
+                Some(
+                    [SpannedExpression {
+                        expr: Expression::Literal(Literal::String(name)),
+                        span,
+                    }, value],
+                ) => Some(Binding {
+                    kind: &command.name,
+                    name,
+                    span: *span,
+                    value,
+                }),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The key `vars` keeps the slot of a variable made with `mut` under. It can't be written as a
+/// variable, so blocks never see it.
+fn mutable_key(variable: &str) -> String {
+    format!("mut {}", variable)
+}
+
+/// Sets a variable, in place of any variable of the same name made with `mut`.
+pub(crate) fn set_var(vars: &mut IndexMap<String, Value>, variable: String, value: Value) {
+    vars.shift_remove(&mutable_key(&variable));
+    vars.insert(variable, value);
+}
+
+/// Gives the variables made with `mut` the values they were last set to.
+fn refresh_mutables(registry: &CommandRegistry, vars: &mut IndexMap<String, Value>) {
+    let mutables: Vec<(String, usize)> = vars
+        .iter()
+        .filter_map(|(key, slot)| {
+            let variable = key.strip_prefix("mut ")?;
+            Some((variable.to_string(), slot.as_u64().ok()? as usize))
+        })
+        .collect();
+
+    for (variable, slot) in mutables {
+        if let Some(value) = registry.get_mutable(slot) {
+            vars.insert(variable, value);
+        }
+    }
+}
+
+/// Sets a variable made with `mut` to `value`, or adds it to or takes it from the variable.
+fn assign(
+    binding: &Binding,
+    variable: String,
+    value: Value,
+    registry: &CommandRegistry,
+    vars: &mut IndexMap<String, Value>,
+) -> Result<(), ShellError> {
+    let slot = match vars.get(&mutable_key(&variable)) {
+        Some(slot) => slot.as_u64()? as usize,
+        None => {
+            return Err(ShellError::labeled_error(
+                "Could not set the variable",
+                format!("{} was not made with mut", variable),
+                binding.span,
+            ))
+        }
+    };
+
+    let value = match binding.kind {
+        "+=" | "-=" => {
+            let operator = if binding.kind == "+=" {
+                Operator::Plus
+            } else {
+                Operator::Minus
+            };
+            let current = vars
+                .get(&variable)
+                .cloned()
+                .unwrap_or_else(|| UntaggedValue::nothing().into_value(binding.span));
+
+            match apply_operator(operator, &current, &value) {
+                Ok(result) => result.into_value(binding.value.span),
+                Err((left_type, right_type)) => {
+                    return Err(ShellError::coerce_error(
+                        left_type.spanned(binding.span),
+                        right_type.spanned(binding.value.span),
+                    ))
+                }
+            }
+        }
+        _ => value,
+    };
+
+    registry.set_mutable(slot, value.clone());
+    vars.insert(variable, value);
+
+    Ok(())
+}
+
 async fn run_pipeline(
     commands: &Commands,
     ctx: &mut Context,
@@ -75,6 +347,16 @@ async fn run_pipeline(
         let item: Option<ClassifiedCommand> = iter.next();
         let next: Option<&ClassifiedCommand> = iter.peek();
 
+        let in_vars = match &item {
+            Some(command) if command.has_in_usage() => {
+                let (rest, in_vars) = bind_in(input, vars).await;
+                input = rest;
+                Some(in_vars)
+            }
+            _ => None,
+        };
+        let vars = in_vars.as_ref().unwrap_or(vars);
+
         input = match (item, next) {
             (Some(ClassifiedCommand::Dynamic(_)), _) | (_, Some(ClassifiedCommand::Dynamic(_))) => {
                 return Err(ShellError::unimplemented("Dynamic commands"))
@@ -96,3 +378,74 @@ async fn run_pipeline(
 
     Ok(input)
 }
+
+/// The variables for a command that uses `$in`, with the input it is given as `$in`, and the
+/// input to give it, as it is collected for this. Without input, like in the condition of `if`,
+/// `$in` stays the input of the pipeline the block is in.
+async fn bind_in(
+    input: InputStream,
+    vars: &IndexMap<String, Value>,
+) -> (InputStream, IndexMap<String, Value>) {
+    if input.is_empty() {
+        return (input, vars.clone());
+    }
+
+    let values: Vec<Value> = input.collect().await;
+    let value = match &values[..] {
+        [] => UntaggedValue::nothing().into_untagged_value(),
+        [value] => value.clone(),
+        _ => UntaggedValue::Table(values.clone()).into_untagged_value(),
+    };
+
+    let mut vars = vars.clone();
+    set_var(&mut vars, "$in".to_string(), value);
+
+    (InputStream::from(values), vars)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run_block;
+    use crate::commands::{whole_stream_command, Echo, For, Mut};
+    use crate::context::Context;
+    use crate::prelude::*;
+    use futures::executor::block_on;
+    use nu_protocol::Value;
+
+    #[test]
+    fn frees_the_variables_made_with_mut_once_the_block_is_done() {
+        let mut ctx = Context::basic().expect("could not create basic context");
+        ctx.add_commands(vec![
+            whole_stream_command(Echo),
+            whole_stream_command(For),
+            whole_stream_command(Mut),
+        ]);
+
+        let lite_block = nu_parser::lite_parse(
+            "mut total = 0; for x in 1..50 { mut doubled = $x * 2; $total += $doubled }; echo $total",
+            0,
+        )
+        .expect("could not parse");
+        let mut block = nu_parser::classify_block(&lite_block, ctx.registry()).block;
+        block.expand_it_usage();
+
+        let env = ctx.get_env();
+        let output = block_on(async {
+            run_block(
+                &block,
+                &mut ctx,
+                InputStream::empty(),
+                &Value::nothing(),
+                &IndexMap::new(),
+                &env,
+            )
+            .await
+            .expect("could not run")
+            .into_vec()
+            .await
+        });
+
+        assert_eq!(output[0].as_u64().ok(), Some(2550));
+        assert_eq!(ctx.registry().mutables_len(), 0);
+    }
+}
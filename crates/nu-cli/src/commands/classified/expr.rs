@@ -3,10 +3,11 @@ use crate::prelude::*;
 
 use log::{log_enabled, trace};
 
+use crate::data::range::range_values;
 use futures::stream::once;
 use nu_errors::ShellError;
 use nu_protocol::hir::SpannedExpression;
-use nu_protocol::Value;
+use nu_protocol::{Primitive, UntaggedValue, Value};
 
 pub(crate) async fn run_expression_block(
     expr: SpannedExpression,
@@ -23,5 +24,21 @@ pub(crate) async fn run_expression_block(
     let registry = context.registry().clone();
     let output = evaluate_baseline_expr(&expr, &registry, it, vars, env).await?;
 
+    // A range gives its values, like in `1..10 | each { ... }`
+    if let Value {
+        value: UntaggedValue::Primitive(Primitive::Range(range)),
+        tag,
+    } = output
+    {
+        return Ok(InputStream::from_stream(futures::stream::iter(
+            range_values(&range, tag)?,
+        )));
+    }
+
+    // A list or a table gives its rows, like in `[1 2 3] | each { ... }`
+    if let UntaggedValue::Table(rows) = output.value {
+        return Ok(InputStream::from_stream(futures::stream::iter(rows)));
+    }
+
     Ok(once(async { Ok(output) }).to_input_stream())
 }
@@ -2,6 +2,7 @@ use crate::evaluate::evaluate_baseline_expr;
 use crate::futures::ThreadedReceiver;
 use crate::prelude::*;
 
+use std::borrow::Cow;
 use std::io::Write;
 use std::ops::Deref;
 use std::process::{Command, Stdio};
@@ -15,14 +16,19 @@ use log::trace;
 
 use nu_errors::ShellError;
 use nu_protocol::hir::ExternalCommand;
-use nu_protocol::{Primitive, Scope, ShellTypeName, UntaggedValue, Value};
+use nu_protocol::{Primitive, Scope, ShellTypeName, TaggedDictBuilder, UntaggedValue, Value};
 use nu_source::Tag;
 
 pub enum StringOrBinary {
     String(String),
     Binary(Vec<u8>),
 }
-pub struct MaybeTextCodec;
+/// Splits the output of a command into text, or into binary once it's seen not to be text. Once
+/// output is given as binary, the rest of it is too, so it can be put back together byte for byte.
+#[derive(Default)]
+pub struct MaybeTextCodec {
+    binary: bool,
+}
 
 impl futures_codec::Encoder for MaybeTextCodec {
     type Item = StringOrBinary;
@@ -49,35 +55,49 @@ impl futures_codec::Decoder for MaybeTextCodec {
     type Error = std::io::Error;
 
     fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
-        let v: Vec<u8> = src.to_vec();
-        match String::from_utf8(v) {
+        if src.is_empty() {
+            return Ok(None);
+        }
+
+        if self.binary {
+            let buf = src.split_to(src.len());
+            return Ok(Some(StringOrBinary::Binary(buf.to_vec())));
+        }
+
+        match std::str::from_utf8(src) {
             Ok(s) => {
+                let s = s.to_string();
                 src.clear();
-                if s.is_empty() {
-                    Ok(None)
-                } else {
-                    Ok(Some(StringOrBinary::String(s)))
-                }
+                Ok(Some(StringOrBinary::String(s)))
             }
-            Err(err) => {
-                // Note: the longest UTF-8 character per Unicode spec is currently 6 bytes. If we fail somewhere earlier than the last 6 bytes,
-                // we know that we're failing to understand the string encoding and not just seeing a partial character. When this happens, let's
-                // fall back to assuming it's a binary buffer.
-                if src.is_empty() {
+            // The buffer ends partway through a character, so the text up to it is given, and
+            // the rest waits for more bytes
+            Err(err) if err.error_len().is_none() => {
+                if err.valid_up_to() == 0 {
                     Ok(None)
-                } else if src.len() > 6 && (src.len() - err.utf8_error().valid_up_to() > 6) {
-                    // Fall back to assuming binary
-                    let buf = src.to_vec();
-                    src.clear();
-                    Ok(Some(StringOrBinary::Binary(buf)))
                 } else {
-                    // Looks like a utf-8 string, so let's assume that
-                    let buf = src.split_to(err.utf8_error().valid_up_to() + 1);
+                    let buf = src.split_to(err.valid_up_to());
                     String::from_utf8(buf.to_vec())
-                        .map(|x| Some(StringOrBinary::String(x)))
+                        .map(|s| Some(StringOrBinary::String(s)))
                         .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
                 }
             }
+            Err(_) => {
+                self.binary = true;
+                self.decode(src)
+            }
+        }
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self.decode(src)? {
+            Some(item) => Ok(Some(item)),
+            None if src.is_empty() => Ok(None),
+            // The output ended partway through a character, so it wasn't text after all
+            None => {
+                self.binary = true;
+                self.decode(src)
+            }
         }
     }
 }
@@ -88,10 +108,11 @@ pub(crate) async fn run_external_command(
     input: InputStream,
     scope: &Scope,
     is_last: bool,
+    capture_stderr: bool,
 ) -> Result<InputStream, ShellError> {
     trace!(target: "nu::run::external", "-> {}", command.name);
 
-    if !did_find_command(&command.name).await {
+    if !did_find_command(&command.name) {
         return Err(ShellError::labeled_error(
             "Command not found",
             "command not found",
@@ -99,7 +120,7 @@ pub(crate) async fn run_external_command(
         ));
     }
 
-    run_with_stdin(command, context, input, scope, is_last).await
+    run_with_stdin(command, context, input, scope, is_last, capture_stderr).await
 }
 
 async fn run_with_stdin(
@@ -108,6 +129,7 @@ async fn run_with_stdin(
     input: InputStream,
     scope: &Scope,
     is_last: bool,
+    capture_stderr: bool,
 ) -> Result<InputStream, ShellError> {
     let path = context.shell_manager.path();
 
@@ -167,7 +189,15 @@ async fn run_with_stdin(
         })
         .collect::<Vec<String>>();
 
-    spawn(&command, &path, &process_args[..], input, is_last, scope)
+    spawn(
+        &command,
+        &path,
+        &process_args[..],
+        input,
+        is_last,
+        capture_stderr,
+        scope,
+    )
 }
 
 fn spawn(
@@ -176,6 +206,7 @@ fn spawn(
     args: &[String],
     input: InputStream,
     is_last: bool,
+    capture_stderr: bool,
     scope: &Scope,
 ) -> Result<InputStream, ShellError> {
     let command = command.clone();
@@ -216,6 +247,12 @@ fn spawn(
         trace!(target: "nu::run::external", "set up stdout pipe");
     }
 
+    // When piped into `complete`, stderr is kept apart from stdout and given with the exit code
+    if capture_stderr {
+        process.stderr(Stdio::piped());
+        trace!(target: "nu::run::external", "set up stderr pipe");
+    }
+
     // open since we have some contents for stdin
     if !input.is_empty() {
         process.stdin(Stdio::piped());
@@ -242,39 +279,16 @@ fn spawn(
                     .expect("Internal error: could not get stdin pipe for external command");
 
                 for value in block_on_stream(input) {
-                    match &value.value {
+                    let bytes = match &value.value {
                         UntaggedValue::Primitive(Primitive::Nothing) => continue,
-                        UntaggedValue::Primitive(Primitive::String(s))
-                        | UntaggedValue::Primitive(Primitive::Line(s)) => {
-                            if let Err(e) = stdin_write.write(s.as_bytes()) {
-                                let message = format!("Unable to write to stdin (error = {})", e);
-
-                                let _ = stdin_write_tx.send(Ok(Value {
-                                    value: UntaggedValue::Error(ShellError::labeled_error(
-                                        message,
-                                        "application may have closed before completing pipeline",
-                                        &stdin_name_tag,
-                                    )),
-                                    tag: stdin_name_tag,
-                                }));
-                                return Err(());
-                            }
+                        UntaggedValue::Primitive(Primitive::String(s)) => {
+                            Cow::Borrowed(s.as_bytes())
                         }
-                        UntaggedValue::Primitive(Primitive::Binary(b)) => {
-                            if let Err(e) = stdin_write.write(b) {
-                                let message = format!("Unable to write to stdin (error = {})", e);
-
-                                let _ = stdin_write_tx.send(Ok(Value {
-                                    value: UntaggedValue::Error(ShellError::labeled_error(
-                                        message,
-                                        "application may have closed before completing pipeline",
-                                        &stdin_name_tag,
-                                    )),
-                                    tag: stdin_name_tag,
-                                }));
-                                return Err(());
-                            }
+                        // Each line is ended, so the command can read the lines as they come
+                        UntaggedValue::Primitive(Primitive::Line(s)) => {
+                            Cow::Owned(format!("{}\n", s).into_bytes())
                         }
+                        UntaggedValue::Primitive(Primitive::Binary(b)) => Cow::Borrowed(&b[..]),
                         unsupported => {
                             let _ = stdin_write_tx.send(Ok(Value {
                                 value: UntaggedValue::Error(ShellError::labeled_error(
@@ -290,6 +304,20 @@ fn spawn(
                             return Err(());
                         }
                     };
+
+                    if let Err(e) = stdin_write.write_all(&bytes) {
+                        let message = format!("Unable to write to stdin (error = {})", e);
+
+                        let _ = stdin_write_tx.send(Ok(Value {
+                            value: UntaggedValue::Error(ShellError::labeled_error(
+                                message,
+                                "application may have closed before completing pipeline",
+                                &stdin_name_tag,
+                            )),
+                            tag: stdin_name_tag,
+                        }));
+                        return Err(());
+                    }
                 }
             }
 
@@ -297,6 +325,42 @@ fn spawn(
         });
 
         std::thread::spawn(move || {
+            if capture_stderr {
+                let value = match child.wait_with_output() {
+                    Ok(output) => {
+                        let mut dict = TaggedDictBuilder::new(&stdout_name_tag);
+                        let stdout = match String::from_utf8(output.stdout) {
+                            Ok(s) => UntaggedValue::string(s),
+                            Err(err) => UntaggedValue::binary(err.into_bytes()),
+                        };
+                        dict.insert_untagged("stdout", stdout);
+                        dict.insert_untagged(
+                            "stderr",
+                            UntaggedValue::string(String::from_utf8_lossy(&output.stderr)),
+                        );
+                        dict.insert_untagged(
+                            "exit_code",
+                            match output.status.code() {
+                                Some(code) => UntaggedValue::int(code),
+                                None => UntaggedValue::nothing(),
+                            },
+                        );
+                        dict.into_value()
+                    }
+                    Err(e) => Value {
+                        value: UntaggedValue::Error(ShellError::labeled_error(
+                            format!("Unable to read the output of the command ({})", e),
+                            "unable to read output",
+                            &stdout_name_tag,
+                        )),
+                        tag: stdout_name_tag,
+                    },
+                };
+
+                let _ = stdout_read_tx.send(Ok(value));
+                return Ok(());
+            }
+
             if !is_last {
                 let stdout = if let Some(stdout) = child.stdout.take() {
                     stdout
@@ -313,7 +377,7 @@ fn spawn(
                 };
 
                 let file = futures::io::AllowStdIo::new(stdout);
-                let stream = FramedRead::new(file, MaybeTextCodec);
+                let stream = FramedRead::new(file, MaybeTextCodec::default());
 
                 for line in block_on_stream(stream) {
                     match line {
@@ -369,9 +433,9 @@ fn spawn(
 
             // We can give an error when we see a non-zero exit code, but this is different
             // than what other shells will do.
-            let external_failed = match child.wait() {
-                Err(_) => true,
-                Ok(exit_status) => !exit_status.success(),
+            let (external_failed, exit_code) = match child.wait() {
+                Err(_) => (true, None),
+                Ok(exit_status) => (!exit_status.success(), exit_status.code()),
             };
 
             if external_failed {
@@ -389,7 +453,7 @@ fn spawn(
                     }
                 }
                 let _ = stdout_read_tx.send(Ok(Value {
-                    value: UntaggedValue::Error(ShellError::external_non_zero()),
+                    value: UntaggedValue::Error(ShellError::external_non_zero(exit_code)),
                     tag: stdout_name_tag,
                 }));
             }
@@ -408,7 +472,7 @@ fn spawn(
     }
 }
 
-async fn did_find_command(name: &str) -> bool {
+pub(crate) fn did_find_command(name: &str) -> bool {
     #[cfg(not(windows))]
     {
         which::which(name).is_ok()
@@ -484,9 +548,11 @@ fn shell_os_paths() -> Vec<std::path::PathBuf> {
 mod tests {
     use super::{
         add_quotes, argument_contains_whitespace, argument_is_quoted, expand_tilde, remove_quotes,
-        run_external_command, Context, InputStream,
+        run_external_command, Context, InputStream, MaybeTextCodec, StringOrBinary,
     };
+    use bytes::BytesMut;
     use futures::executor::block_on;
+    use futures_codec::Decoder;
     use nu_errors::ShellError;
     use nu_protocol::Scope;
     use nu_test_support::commands::ExternalBuilder;
@@ -511,7 +577,7 @@ mod tests {
         let mut ctx = Context::basic().expect("There was a problem creating a basic context.");
 
         assert!(
-            run_external_command(cmd, &mut ctx, input, &Scope::new(), false)
+            run_external_command(cmd, &mut ctx, input, &Scope::new(), false, false)
                 .await
                 .is_err()
         );
@@ -548,6 +614,45 @@ mod tests {
         block_on(non_existent_run())
     }
 
+    #[test]
+    fn waits_for_the_rest_of_a_character_split_across_reads() -> Result<(), std::io::Error> {
+        let mut codec = MaybeTextCodec::default();
+        let mut buf = BytesMut::from(&"andr\u{e9}s".as_bytes()[..5]);
+
+        match codec.decode(&mut buf)? {
+            Some(StringOrBinary::String(s)) => assert_eq!(s, "andr"),
+            _ => panic!("expected text"),
+        }
+        assert!(codec.decode(&mut buf)?.is_none());
+
+        buf.extend_from_slice(&"andr\u{e9}s".as_bytes()[5..]);
+        match codec.decode(&mut buf)? {
+            Some(StringOrBinary::String(s)) => assert_eq!(s, "\u{e9}s"),
+            _ => panic!("expected text"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn keeps_giving_binary_once_output_is_not_text() -> Result<(), std::io::Error> {
+        let mut codec = MaybeTextCodec::default();
+        let mut buf = BytesMut::from(&[0x89, b'P', b'N', b'G'][..]);
+
+        match codec.decode(&mut buf)? {
+            Some(StringOrBinary::Binary(b)) => assert_eq!(b, vec![0x89, b'P', b'N', b'G']),
+            _ => panic!("expected binary"),
+        }
+
+        buf.extend_from_slice(b"IHDR");
+        match codec.decode(&mut buf)? {
+            Some(StringOrBinary::Binary(b)) => assert_eq!(b, b"IHDR".to_vec()),
+            _ => panic!("expected binary"),
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn checks_contains_whitespace_from_argument_to_be_passed_in() {
         assert_eq!(argument_contains_whitespace("andrés"), false);
@@ -1,5 +1,6 @@
 use crate::commands::command::whole_stream_command;
 use crate::commands::run_alias::AliasCommand;
+use crate::commands::run_def::CustomCommand;
 use crate::commands::UnevaluatedCallInfo;
 use crate::prelude::*;
 use log::{log_enabled, trace};
@@ -8,7 +9,7 @@ use nu_protocol::hir::InternalCommand;
 use nu_protocol::{CommandAction, Primitive, ReturnSuccess, Scope, UntaggedValue, Value};
 
 pub(crate) async fn run_internal_command(
-    command: InternalCommand,
+    mut command: InternalCommand,
     context: &mut Context,
     input: InputStream,
     it: &Value,
@@ -25,6 +26,10 @@ pub(crate) async fn run_internal_command(
         vars: vars.clone(),
         env: env.clone(),
     };
+    for warning in context.registry.forward_deprecated(&mut command) {
+        context.with_host(|host| host.stderr(&warning));
+    }
+
     let objects: InputStream = trace_stream!(target: "nu::trace_stream::internal", "input" = input);
     let internal_command = context.expect_command(&command.name);
 
@@ -84,6 +89,7 @@ pub(crate) async fn run_internal_command(
                                                 named: None,
                                                 span: Span::unknown(),
                                                 is_last: false,
+                                                capture_stderr: false,
                                             },
                                             name_tag: Tag::unknown_anchor(command.name_span),
                                             scope: (&*scope).clone(),
@@ -164,6 +170,63 @@ pub(crate) async fn run_internal_command(
                                     .insert_at_current(Box::new(ValueShell::new(value)));
                                 InputStream::from_stream(futures::stream::iter(vec![]))
                             }
+                            CommandAction::EnterShell(location)
+                                if crate::shell::archive_shell::split_path(
+                                    std::path::Path::new(&location),
+                                )
+                                .is_some() =>
+                            {
+                                context.shell_manager.insert_at_current(Box::new(
+                                    match crate::shell::archive_shell::ArchiveShell::with_location(
+                                        &location,
+                                    ) {
+                                        Ok(v) => v,
+                                        Err(err) => {
+                                            context.error(err.clone());
+                                            return InputStream::one(
+                                                UntaggedValue::Error(err).into_untagged_value(),
+                                            );
+                                        }
+                                    },
+                                ));
+                                InputStream::from_stream(futures::stream::iter(vec![]))
+                            }
+                            CommandAction::EnterShell(location)
+                                if location.starts_with("ssh://")
+                                    || location.starts_with("sftp://") =>
+                            {
+                                context.shell_manager.insert_at_current(Box::new(
+                                    match crate::shell::ssh_shell::SshShell::with_location(
+                                        &location,
+                                    ) {
+                                        Ok(v) => v,
+                                        Err(err) => {
+                                            context.error(err.clone());
+                                            return InputStream::one(
+                                                UntaggedValue::Error(err).into_untagged_value(),
+                                            );
+                                        }
+                                    },
+                                ));
+                                InputStream::from_stream(futures::stream::iter(vec![]))
+                            }
+                            #[cfg(feature = "s3")]
+                            CommandAction::EnterShell(location)
+                                if location.starts_with("s3://") =>
+                            {
+                                context.shell_manager.insert_at_current(Box::new(
+                                    match crate::shell::s3_shell::S3Shell::with_location(&location)
+                                    {
+                                        Ok(v) => v,
+                                        Err(err) => {
+                                            return InputStream::one(
+                                                UntaggedValue::Error(err).into_untagged_value(),
+                                            )
+                                        }
+                                    },
+                                ));
+                                InputStream::from_stream(futures::stream::iter(vec![]))
+                            }
                             CommandAction::EnterShell(location) => {
                                 context.shell_manager.insert_at_current(Box::new(
                                     match FilesystemShell::with_location(
@@ -181,11 +244,27 @@ pub(crate) async fn run_internal_command(
                                 ));
                                 InputStream::from_stream(futures::stream::iter(vec![]))
                             }
-                            CommandAction::AddAlias(name, args, block) => {
-                                context.add_commands(vec![whole_stream_command(
-                                    AliasCommand::new(name, args, block),
-                                )]);
-                                InputStream::from_stream(futures::stream::iter(vec![]))
+                            CommandAction::AddAlias(name, params, block) => {
+                                match AliasCommand::new(name, params, block, context.registry()) {
+                                    Ok(command) => {
+                                        context.add_commands(vec![whole_stream_command(command)]);
+                                        InputStream::from_stream(futures::stream::iter(vec![]))
+                                    }
+                                    Err(err) => InputStream::one(
+                                        UntaggedValue::Error(err).into_untagged_value(),
+                                    ),
+                                }
+                            }
+                            CommandAction::AddCommand(name, params, usage, block) => {
+                                match CustomCommand::new(name, params, &usage, block) {
+                                    Ok(command) => {
+                                        context.add_commands(vec![whole_stream_command(command)]);
+                                        InputStream::from_stream(futures::stream::iter(vec![]))
+                                    }
+                                    Err(err) => InputStream::one(
+                                        UntaggedValue::Error(err).into_untagged_value(),
+                                    ),
+                                }
                             }
                             CommandAction::PreviousShell => {
                                 context.shell_manager.prev();
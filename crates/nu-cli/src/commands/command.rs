@@ -365,6 +365,11 @@ impl Command {
     pub fn stream_command(&self) -> &dyn WholeStreamCommand {
         &*self.0
     }
+
+    /// Whether both are the very same command, and not just two commands with the same name.
+    pub(crate) fn is(&self, other: &Command) -> bool {
+        Arc::as_ptr(&self.0) as *const () == Arc::as_ptr(&other.0) as *const ()
+    }
 }
 
 pub struct FnFilterCommand {
@@ -25,7 +25,7 @@ impl WholeStreamCommand for Compact {
     }
 
     fn usage(&self) -> &str {
-        "Creates a table with non-empty rows"
+        "Creates a table with non-empty rows, or with the rows whose given columns aren't empty"
     }
 
     async fn run(
@@ -78,7 +78,7 @@ pub async fn compact(
                     } => {
                         if columns
                             .iter()
-                            .all(|field| r.get_data(field).borrow().is_some())
+                            .all(|field| !r.get_data(field).borrow().is_empty())
                         {
                             Some(ReturnSuccess::value(item))
                         } else {
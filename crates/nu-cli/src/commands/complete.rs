@@ -0,0 +1,111 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    Dictionary, Primitive, ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue, Value,
+};
+
+pub struct Complete;
+
+#[async_trait]
+impl WholeStreamCommand for Complete {
+    fn name(&self) -> &str {
+        "complete"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("complete")
+    }
+
+    fn usage(&self) -> &str {
+        "Wait for the command before it to finish, and give a row with its stdout, stderr and exit code. A failing external command doesn't stop the pipeline when piped into complete."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        complete(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Give the output and exit code of an external command",
+                example: "^ls no_such_dir | complete",
+                result: None,
+            },
+            Example {
+                description: "Only give the output if the command succeeded",
+                example: "^git status | complete | where exit_code == 0 | get stdout",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn is_completed(row: &Dictionary) -> bool {
+    row.entries.len() == 3
+        && row.contains_key("stdout")
+        && row.contains_key("stderr")
+        && row.contains_key("exit_code")
+}
+
+async fn complete(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let args = args.evaluate_once(&registry).await?;
+    let name = args.call_info.name_tag.clone();
+    let values: Vec<Value> = args.input.collect().await;
+
+    // External commands piped into complete already give the row, with stderr kept apart
+    if let [value] = &values[..] {
+        if let UntaggedValue::Row(row) = &value.value {
+            if is_completed(row) {
+                return Ok(OutputStream::one(ReturnSuccess::value(value.clone())));
+            }
+        }
+    }
+
+    // Anything else is taken as the stdout of a command that succeeded
+    let mut stdout = String::new();
+    for value in values {
+        match value.value {
+            UntaggedValue::Error(err) => return Err(err),
+            UntaggedValue::Primitive(Primitive::String(s))
+            | UntaggedValue::Primitive(Primitive::Line(s)) => stdout.push_str(&s),
+            UntaggedValue::Primitive(Primitive::Nothing) => {}
+            _ => {
+                return Err(ShellError::labeled_error_with_secondary(
+                    "Expected text from the pipeline",
+                    "requires text input",
+                    &name,
+                    "value originates from here",
+                    &value.tag,
+                ))
+            }
+        }
+    }
+
+    let mut dict = TaggedDictBuilder::new(&name);
+    dict.insert_untagged("stdout", UntaggedValue::string(stdout));
+    dict.insert_untagged("stderr", UntaggedValue::string(""));
+    dict.insert_untagged("exit_code", UntaggedValue::int(0));
+
+    Ok(OutputStream::one(ReturnSuccess::value(dict.into_value())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Complete;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Complete {})
+    }
+}
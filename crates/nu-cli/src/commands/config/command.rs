@@ -7,7 +7,7 @@ use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValu
 use nu_source::Tagged;
 use std::path::PathBuf;
 
-pub struct Config;
+pub struct Command;
 
 #[derive(Deserialize)]
 pub struct ConfigArgs {
@@ -21,7 +21,7 @@ pub struct ConfigArgs {
 }
 
 #[async_trait]
-impl WholeStreamCommand for Config {
+impl WholeStreamCommand for Command {
     fn name(&self) -> &str {
         "config"
     }
@@ -249,12 +249,12 @@ pub async fn config(
 
 #[cfg(test)]
 mod tests {
-    use super::Config;
+    use super::Command;
 
     #[test]
     fn examples_work_as_expected() {
         use crate::examples::test as test_examples;
 
-        test_examples(Config {})
+        test_examples(Command {})
     }
 }
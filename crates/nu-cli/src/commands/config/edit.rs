@@ -0,0 +1,95 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::config;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::Signature;
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "config edit"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("config edit")
+    }
+
+    fn usage(&self) -> &str {
+        "Open the config file in the editor of $VISUAL or $EDITOR, and check it once it's saved. The session picks up the changes from the next line on."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        _registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        edit(args)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Edit the config file",
+            example: "config edit",
+            result: None,
+        }]
+    }
+}
+
+fn edit(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name = args.call_info.name_tag;
+    config::check_enabled()?;
+    let path = config::default_path()?;
+
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| {
+            if cfg!(windows) {
+                "notepad".to_string()
+            } else {
+                "vi".to_string()
+            }
+        });
+
+    // The editor can be given with arguments, like `code --wait`
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or("vi");
+
+    let status = std::process::Command::new(program)
+        .args(words)
+        .arg(&path)
+        .status()
+        .map_err(|err| {
+            ShellError::labeled_error(
+                format!("Could not start the editor {}: {}", program, err),
+                "could not start the editor",
+                &name,
+            )
+        })?;
+
+    if !status.success() {
+        return Err(ShellError::labeled_error(
+            "The editor failed",
+            "the editor exited with an error",
+            &name,
+        ));
+    }
+
+    // The error tells what is wrong with the file, if it can't be read anymore
+    config::read(&name, &None)?;
+
+    Ok(OutputStream::empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,91 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::config;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::HasFallibleSpan;
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+pub struct Arguments {
+    key: ColumnPath,
+}
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "config get"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("config get").required(
+            "key",
+            SyntaxShape::ColumnPath,
+            "the key of the setting, which can be nested, like color_config.header",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Get a setting from the config. Tables are given a row at a time."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        get(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the edit mode",
+                example: "config get edit_mode",
+                result: None,
+            },
+            Example {
+                description: "Get a nested setting",
+                example: "config get env.EDITOR",
+                result: None,
+            },
+        ]
+    }
+}
+
+pub async fn get(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+
+    let (Arguments { key }, _) = args.process(&registry).await?;
+
+    let result = config::read(&name, &None)?;
+    let span = key.maybe_span().unwrap_or(name.span);
+
+    let value = config::value_at(&result, &key)
+        .ok_or_else(|| ShellError::labeled_error("Missing key in config", "key", span))?;
+
+    Ok(match value {
+        Value {
+            value: UntaggedValue::Table(list),
+            ..
+        } => futures::stream::iter(list.into_iter().map(ReturnSuccess::value)).to_output_stream(),
+        value => OutputStream::one(ReturnSuccess::value(value)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,11 @@
+mod command;
+mod edit;
+mod get;
+mod remove;
+mod set;
+
+pub use command::Command as Config;
+pub use edit::SubCommand as ConfigEdit;
+pub use get::SubCommand as ConfigGet;
+pub use remove::SubCommand as ConfigRemove;
+pub use set::SubCommand as ConfigSet;
@@ -0,0 +1,92 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::config;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Signature, SyntaxShape};
+use nu_source::HasFallibleSpan;
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+pub struct Arguments {
+    key: ColumnPath,
+}
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "config remove"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("config remove").required(
+            "key",
+            SyntaxShape::ColumnPath,
+            "the key of the setting, which can be nested, like color_config.header",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Remove a setting from the config, so nu goes back to its default. The session picks up the change from the next line on."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        remove(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Remove the startup commands",
+                example: "config remove startup",
+                result: None,
+            },
+            Example {
+                description: "Remove a nested setting",
+                example: "config remove env.EDITOR",
+                result: None,
+            },
+        ]
+    }
+}
+
+pub async fn remove(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+
+    let (Arguments { key }, _) = args.process(&registry).await?;
+
+    let mut result = config::read(&name, &None)?;
+    let span = key.maybe_span().unwrap_or(name.span);
+
+    if config::remove_value_at(&mut result, &key).is_none() {
+        return Err(ShellError::labeled_error(
+            "Key does not exist in config",
+            "key",
+            span,
+        ));
+    }
+
+    config::write(&result, &None)?;
+
+    Ok(OutputStream::empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,230 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::config;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ShellTypeName, Signature, SyntaxShape, UnspannedPathMember,
+    UntaggedValue, Value,
+};
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+pub struct Arguments {
+    key: ColumnPath,
+    value: Option<Value>,
+}
+
+/// The settings nu reads, with the type of value they take, and the values they can be set to
+/// when only some are allowed.
+const SETTINGS: &[(&str, &str, &[&str])] = &[
+    ("path", "table", &[]),
+    ("env", "row", &[]),
+    ("startup", "table", &[]),
+    ("plugin_dirs", "table", &[]),
+    ("nu_env_dirs", "table", &[]),
+    ("ctrlc_exit", "boolean", &[]),
+    ("complete_from_path", "boolean", &[]),
+    ("rm_always_trash", "boolean", &[]),
+    ("disable_table_indexes", "boolean", &[]),
+    ("nonzero_exit_errors", "boolean", &[]),
+    ("key_timeout", "integer", &[]),
+    ("history_size", "integer", &[]),
+    ("decimal_precision", "integer", &[]),
+    ("prompt", "string", &[]),
+    ("right_prompt", "string", &[]),
+    ("completions", "row", &[]),
+    ("edit_mode", "string", &["vi", "emacs"]),
+    ("completion_mode", "string", &["circular", "list"]),
+    ("table_mode", "string", &["light", "normal"]),
+    ("pivot_mode", "string", &["auto", "always", "never"]),
+    (
+        "header_align",
+        "string",
+        &["left", "center", "right", "l", "c", "r"],
+    ),
+    (
+        "header_color",
+        "string",
+        &[
+            "g",
+            "r",
+            "u",
+            "b",
+            "y",
+            "m",
+            "c",
+            "w",
+            "bg",
+            "br",
+            "bu",
+            "by",
+            "bm",
+            "bc",
+            "bw",
+            "green",
+            "red",
+            "blue",
+            "black",
+            "yellow",
+            "magenta",
+            "cyan",
+            "white",
+            "bright green",
+            "bright red",
+            "bright blue",
+            "bright yellow",
+            "bright magenta",
+            "bright cyan",
+            "bright white",
+        ],
+    ),
+    ("s3", "row", &[]),
+];
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "config set"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("config set")
+            .required(
+                "key",
+                SyntaxShape::ColumnPath,
+                "the key of the setting, which can be nested, like color_config.header",
+            )
+            .optional(
+                "value",
+                SyntaxShape::Any,
+                "the value to set, instead of the input",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        r#"Set a setting in the config, making the rows on the way to a nested key.
+
+The value is kept with its type, like a number, a list or a row. The settings nu reads are checked
+for the type of value they take. The session picks up the change from the next line on."#
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        set(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Use vi keys to edit lines",
+                example: "config set edit_mode vi",
+                result: None,
+            },
+            Example {
+                description: "Set a nested setting",
+                example: "config set env.EDITOR vim",
+                result: None,
+            },
+            Example {
+                description: "Set the path from the pipeline",
+                example: "echo ['/usr/bin' '/bin'] | config set path",
+                result: None,
+            },
+        ]
+    }
+}
+
+pub async fn set(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+
+    let (Arguments { key, value }, input) = args.process(&registry).await?;
+
+    let value = match value {
+        Some(value) => value,
+        None => {
+            let mut rows: Vec<Value> = input.collect().await;
+            match rows.len() {
+                0 => {
+                    return Err(ShellError::labeled_error(
+                        "No value to set",
+                        "needs a value, or one from the pipeline",
+                        &name,
+                    ))
+                }
+                1 => rows.remove(0),
+                _ => UntaggedValue::Table(rows).into_value(&name),
+            }
+        }
+    };
+
+    check_setting(&key, &value)?;
+
+    let mut result = config::read(&name, &None)?;
+    config::set_value_at(&mut result, &key, value)?;
+    config::write(&result, &None)?;
+
+    Ok(OutputStream::empty())
+}
+
+/// Checks that the value can be given to a setting nu reads, that is of the right type, and one
+/// of the allowed values if there are some.
+fn check_setting(key: &ColumnPath, value: &Value) -> Result<(), ShellError> {
+    let name = match &key.members()[..] {
+        [member] => match &member.unspanned {
+            UnspannedPathMember::String(name) => name,
+            UnspannedPathMember::Int(_) => return Ok(()),
+        },
+        _ => return Ok(()),
+    };
+
+    let (expected, allowed) = match SETTINGS.iter().find(|(setting, _, _)| *setting == name) {
+        Some((_, expected, allowed)) => (*expected, *allowed),
+        None => return Ok(()),
+    };
+
+    let type_name = match &value.value {
+        UntaggedValue::Primitive(Primitive::Line(_)) => "string",
+        other => other.type_name(),
+    };
+
+    if type_name != expected {
+        return Err(ShellError::labeled_error(
+            format!("{} has to be of type {}", name, expected),
+            format!("expected {}, found {}", expected, type_name),
+            value.tag.span,
+        ));
+    }
+
+    if !allowed.is_empty() {
+        let text = value.as_string()?;
+        if !allowed.contains(&text.to_lowercase().as_str()) {
+            return Err(ShellError::labeled_error(
+                format!("{} can't be set to {}", name, text),
+                format!("expected one of: {}", allowed.join(", ")),
+                value.tag.span,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,52 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::{LoopControl, ShellError};
+use nu_protocol::Signature;
+
+pub struct Continue;
+
+#[async_trait]
+impl WholeStreamCommand for Continue {
+    fn name(&self) -> &str {
+        "continue"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("continue")
+    }
+
+    fn usage(&self) -> &str {
+        "Skip the rest of the pass of the loop it is in, going on with the next one."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        _registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Err(ShellError::loop_control(
+            LoopControl::Continue,
+            args.call_info.name_tag.span,
+        ))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Skip the files that can't be opened",
+            example: "for file in $(ls) { try { open $file.name } catch { continue } }",
+            result: None,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Continue;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Continue {})
+    }
+}
@@ -1,18 +1,38 @@
+use crate::commands::mv::renames;
 use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, SyntaxShape};
+use nu_protocol::{hir::Block, Signature, SyntaxShape};
 use nu_source::Tagged;
 use std::path::PathBuf;
 
 pub struct Cpy;
 
 #[derive(Deserialize)]
+struct Arguments {
+    src: Option<Tagged<PathBuf>>,
+    dst: Option<Tagged<PathBuf>>,
+    using: Option<Block>,
+    recursive: Tagged<bool>,
+    #[serde(rename = "no-dereference")]
+    no_dereference: bool,
+    preserve: bool,
+    interactive: bool,
+    #[serde(rename = "no-clobber")]
+    no_clobber: bool,
+    progress: bool,
+}
+
 pub struct CopyArgs {
     pub src: Tagged<PathBuf>,
     pub dst: Tagged<PathBuf>,
     pub recursive: Tagged<bool>,
+    pub no_dereference: bool,
+    pub preserve: bool,
+    pub interactive: bool,
+    pub no_clobber: bool,
+    pub progress: bool,
 }
 
 #[async_trait]
@@ -23,13 +43,40 @@ impl WholeStreamCommand for Cpy {
 
     fn signature(&self) -> Signature {
         Signature::build("cp")
-            .required("src", SyntaxShape::Pattern, "the place to copy from")
-            .required("dst", SyntaxShape::Path, "the place to copy to")
+            .optional(
+                "src",
+                SyntaxShape::Pattern,
+                "the place to copy from (default: the paths in the pipeline)",
+            )
+            .optional("dst", SyntaxShape::Path, "the place to copy to")
+            .named(
+                "using",
+                SyntaxShape::Block,
+                "a block giving the path to copy each file in the pipeline to",
+                Some('u'),
+            )
             .switch(
                 "recursive",
                 "copy recursively through subdirectories",
                 Some('r'),
             )
+            .switch(
+                "no-dereference",
+                "copy symlinks as symlinks instead of copying the files they point to",
+                None,
+            )
+            .switch(
+                "preserve",
+                "keep the permissions and the access and modification times",
+                Some('p'),
+            )
+            .switch("interactive", "ask before overwriting files", Some('i'))
+            .switch("no-clobber", "don't overwrite files", Some('n'))
+            .switch(
+                "progress",
+                "output a row for every file as it's copied",
+                None,
+            )
     }
 
     fn usage(&self) -> &str {
@@ -41,10 +88,7 @@ impl WholeStreamCommand for Cpy {
         args: CommandArgs,
         registry: &CommandRegistry,
     ) -> Result<OutputStream, ShellError> {
-        let shell_manager = args.shell_manager.clone();
-        let name = args.call_info.name_tag.clone();
-        let (args, _) = args.process(&registry).await?;
-        shell_manager.cp(args, name)
+        cp(args, registry).await
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -59,10 +103,61 @@ impl WholeStreamCommand for Cpy {
                 example: "cp -r dir_a dir_b",
                 result: None,
             },
+            Example {
+                description:
+                    "Copy a directory tree, keeping the file times and showing the copied files",
+                example: "cp -r --preserve --progress dir_a dir_b",
+                result: None,
+            },
+            Example {
+                description: "Make a backup copy of each CSV file",
+                example: "ls *.csv | cp --using { build-string $it.name '.bak' }",
+                result: None,
+            },
         ]
     }
 }
 
+async fn cp(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let shell_manager = args.shell_manager.clone();
+    let name = args.call_info.name_tag.clone();
+    let scope = args.call_info.scope.clone();
+    let mut context = Context::from_raw(&args, &registry);
+    let (
+        Arguments {
+            src,
+            dst,
+            using,
+            recursive,
+            no_dereference,
+            preserve,
+            interactive,
+            no_clobber,
+            progress,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    let mut outputs = vec![];
+    for (src, dst) in renames(src, dst, using, input, &scope, &mut context, &name).await? {
+        let args = CopyArgs {
+            src,
+            dst,
+            recursive: recursive.clone(),
+            no_dereference,
+            preserve,
+            interactive,
+            no_clobber,
+            progress,
+        };
+
+        outputs.push(shell_manager.cp(args, name.clone())?);
+    }
+
+    Ok(futures::stream::iter(outputs).flatten().to_output_stream())
+}
+
 #[cfg(test)]
 mod tests {
     use super::Cpy;
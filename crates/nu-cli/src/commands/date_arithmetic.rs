@@ -0,0 +1,220 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value::duration_of_seconds;
+use crate::prelude::*;
+use chrono::Duration;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_value_ext::ValueExt;
+
+pub struct DateAdd;
+
+pub struct DateSubtract;
+
+#[derive(Deserialize)]
+pub struct DateArithmeticArgs {
+    duration: Value,
+    rest: Vec<ColumnPath>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for DateAdd {
+    fn name(&self) -> &str {
+        "date add"
+    }
+
+    fn signature(&self) -> Signature {
+        signature("date add", "the duration to add, like 3wk or 90sec")
+    }
+
+    fn usage(&self) -> &str {
+        "Adds a duration to dates."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        shift(args, registry, Direction::Forward).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the date three weeks from now",
+                example: "date now | date add 3wk",
+                result: None,
+            },
+            Example {
+                description: "Push the due dates back by a day",
+                example: "open tasks.json | date add 1day due",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[async_trait]
+impl WholeStreamCommand for DateSubtract {
+    fn name(&self) -> &str {
+        "date subtract"
+    }
+
+    fn signature(&self) -> Signature {
+        signature(
+            "date subtract",
+            "the duration to subtract, like 3wk or 90sec",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Subtracts a duration from dates."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        shift(args, registry, Direction::Backward).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the date 90 seconds ago",
+            example: "date now | date subtract 90sec",
+            result: None,
+        }]
+    }
+}
+
+fn signature(name: &str, duration: &str) -> Signature {
+    Signature::build(name)
+        .required("duration", SyntaxShape::Unit, duration)
+        .rest(
+            SyntaxShape::ColumnPath,
+            "optionally shift the dates at the given column paths",
+        )
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+    Forward,
+    Backward,
+}
+
+async fn shift(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+    direction: Direction,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let (
+        DateArithmeticArgs {
+            duration,
+            rest: column_paths,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    let duration = match duration.value {
+        UntaggedValue::Primitive(Primitive::Duration(secs)) => {
+            let secs = match direction {
+                Direction::Forward => Some(secs),
+                Direction::Backward => secs.checked_neg(),
+            };
+
+            match secs.and_then(duration_of_seconds) {
+                Some(duration) => duration,
+                None => {
+                    return Err(ShellError::labeled_error(
+                        "Duration out of range",
+                        "the duration is too long to move a date by",
+                        duration.tag,
+                    ))
+                }
+            }
+        }
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a duration",
+                "expected a duration, like 3wk or 90sec",
+                duration.tag,
+            ))
+        }
+    };
+
+    Ok(input
+        .map(move |value| {
+            if column_paths.is_empty() {
+                ReturnSuccess::value(action(&value, duration)?)
+            } else {
+                let mut ret = value;
+
+                for path in &column_paths {
+                    ret = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, duration)),
+                    )?;
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn action(value: &Value, duration: Duration) -> Result<Value, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Date(date)) => {
+            match date.checked_add_signed(duration) {
+                Some(date) => Ok(UntaggedValue::date(date).into_value(&value.tag)),
+                None => Err(ShellError::labeled_error(
+                    "Date out of range",
+                    "the resulting date is too far in the past or future",
+                    &value.tag,
+                )),
+            }
+        }
+        other => Err(ShellError::labeled_error(
+            "Expected a date",
+            format!("found {}", other.type_name()),
+            &value.tag,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, DateAdd, DateSubtract};
+    use chrono::{Duration, TimeZone, Utc};
+    use nu_plugin::test_helpers::value::int;
+    use nu_protocol::UntaggedValue;
+
+    #[test]
+    fn shifts_dates_by_the_duration() {
+        let date =
+            UntaggedValue::date(Utc.ymd(2020, 2, 28).and_hms(12, 0, 0)).into_untagged_value();
+
+        assert_eq!(
+            action(&date, Duration::days(2)).unwrap().value,
+            UntaggedValue::date(Utc.ymd(2020, 3, 1).and_hms(12, 0, 0))
+        );
+        assert_eq!(
+            action(&date, Duration::seconds(-90)).unwrap().value,
+            UntaggedValue::date(Utc.ymd(2020, 2, 28).and_hms(11, 58, 30))
+        );
+        assert!(action(&int(5), Duration::days(1)).is_err());
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(DateAdd {});
+        test_examples(DateSubtract {})
+    }
+}
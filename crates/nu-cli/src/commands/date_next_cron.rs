@@ -0,0 +1,382 @@
+use crate::commands::date_range::to_date;
+use crate::commands::date_to_timezone::Zone;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, Timelike, Utc};
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+
+pub struct DateNextCron;
+
+#[derive(Deserialize)]
+pub struct DateNextCronArgs {
+    expression: Tagged<String>,
+    count: Option<Tagged<usize>>,
+    from: Option<Value>,
+    timezone: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for DateNextCron {
+    fn name(&self) -> &str {
+        "date next-cron"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("date next-cron")
+            .required(
+                "expression",
+                SyntaxShape::String,
+                "the cron expression, like '0 9 * * mon-fri' or @daily",
+            )
+            .named(
+                "count",
+                SyntaxShape::Int,
+                "the number of times to produce (default: 1)",
+                Some('n'),
+            )
+            .named(
+                "from",
+                SyntaxShape::Any,
+                "look for the times after this date (default: now)",
+                Some('f'),
+            )
+            .named(
+                "timezone",
+                SyntaxShape::String,
+                "the timezone the schedule runs in, like Europe/Paris or UTC (default: local)",
+                Some('z'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Gives the next times a cron expression fires."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        next_cron(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the next five times a weekday morning job runs",
+                example: "date next-cron '0 9 * * mon-fri' --count 5",
+                result: None,
+            },
+            Example {
+                description: "Get the times a job ran in Paris in the first week of June 2020",
+                example: "date next-cron '30 2 * * *' --from 2020-06-01 --timezone Europe/Paris --count 7",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// How many years ahead to look before deciding an expression never fires, like `0 0 30 2 *`.
+const YEARS_TO_SEARCH: i32 = 8;
+
+/// The times a cron expression fires, as sets of allowed values in bit masks.
+#[derive(Debug, PartialEq)]
+struct Schedule {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    days_restricted: bool,
+    weekdays_restricted: bool,
+}
+
+const MONTHS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+const WEEKDAYS: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+impl Schedule {
+    fn parse(expression: &str) -> Result<Schedule, String> {
+        let expression = match expression.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            other => other,
+        };
+
+        let fields = expression.split_whitespace().collect::<Vec<_>>();
+        if fields.len() != 5 {
+            return Err(format!(
+                "expected 5 fields (minute hour day month weekday), found {}",
+                fields.len()
+            ));
+        }
+
+        let field = |idx: usize, name: &str, min: u32, max: u32, names: &[&str]| {
+            parse_field(fields[idx], min, max, names)
+                .ok_or_else(|| format!("the {} field '{}' is invalid", name, fields[idx]))
+        };
+
+        let (minutes, _) = field(0, "minute", 0, 59, &[])?;
+        let (hours, _) = field(1, "hour", 0, 23, &[])?;
+        let (days, days_restricted) = field(2, "day", 1, 31, &[])?;
+        let (months, _) = field(3, "month", 1, 12, &MONTHS)?;
+        let (weekdays, weekdays_restricted) = field(4, "weekday", 0, 7, &WEEKDAYS)?;
+
+        // Both 0 and 7 are Sunday.
+        let weekdays = (weekdays | (weekdays >> 7)) & 0x7f;
+
+        Ok(Schedule {
+            minutes,
+            hours,
+            days,
+            months,
+            weekdays,
+            days_restricted,
+            weekdays_restricted,
+        })
+    }
+
+    /// When both the day and the weekday are given, either one matching is enough, as in cron.
+    fn matches_day(&self, date: &NaiveDate) -> bool {
+        let day = has(self.days, date.day());
+        let weekday = has(self.weekdays, date.weekday().num_days_from_sunday());
+
+        if self.days_restricted && self.weekdays_restricted {
+            day || weekday
+        } else {
+            day && weekday
+        }
+    }
+
+    /// The first wall clock time at or after `start` the expression fires.
+    fn first_from(&self, start: NaiveDateTime) -> Option<NaiveDateTime> {
+        let last_year = start.year() + YEARS_TO_SEARCH;
+        let mut time = start.with_second(0)?.with_nanosecond(0)?;
+
+        while time.year() <= last_year {
+            let date = time.date();
+
+            time = if !has(self.months, date.month()) {
+                let (year, month) = match date.month() {
+                    12 => (date.year() + 1, 1),
+                    month => (date.year(), month + 1),
+                };
+                NaiveDate::from_ymd(year, month, 1).and_hms(0, 0, 0)
+            } else if !self.matches_day(&date) {
+                date.succ().and_hms(0, 0, 0)
+            } else if !has(self.hours, time.hour()) {
+                date.and_hms(time.hour(), 0, 0) + Duration::hours(1)
+            } else if !has(self.minutes, time.minute()) {
+                time + Duration::minutes(1)
+            } else {
+                return Some(time);
+            };
+        }
+
+        None
+    }
+
+    /// The first time after `after` the expression fires in the timezone. Wall clock times
+    /// skipped when the clocks go forward are left out.
+    fn next_after(&self, after: &DateTime<Utc>, zone: Zone) -> Option<DateTime<Utc>> {
+        let mut start = zone.utc_to_local(after).with_second(0)? + Duration::minutes(1);
+
+        loop {
+            let time = self.first_from(start)?;
+
+            match zone.local_to_utc(&time) {
+                Some(date) if date > *after => return Some(date),
+                _ => start = time + Duration::minutes(1),
+            }
+        }
+    }
+}
+
+fn has(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+/// Parses a field like `*`, `5`, `1-5`, `*/15`, `mon-fri` or `0,30`, giving the allowed values
+/// and whether the field was restricted at all.
+fn parse_field(field: &str, min: u32, max: u32, names: &[&str]) -> Option<(u64, bool)> {
+    let value = |text: &str| -> Option<u32> {
+        let text = text.to_lowercase();
+        let value = match names.iter().position(|name| *name == text) {
+            Some(idx) => idx as u32 + min,
+            None => text.parse().ok()?,
+        };
+
+        if value >= min && value <= max {
+            Some(value)
+        } else {
+            None
+        }
+    };
+
+    let mut mask = 0;
+
+    for part in field.split(',') {
+        let (range, step) = match part.find('/') {
+            Some(idx) => (&part[..idx], part[idx + 1..].parse::<u32>().ok()?),
+            None => (part, 1),
+        };
+
+        if step == 0 {
+            return None;
+        }
+
+        let (first, last) = match range {
+            "*" => (min, max),
+            range => match range.find('-') {
+                Some(idx) => (value(&range[..idx])?, value(&range[idx + 1..])?),
+                None if step > 1 => (value(range)?, max),
+                None => (value(range)?, value(range)?),
+            },
+        };
+
+        if first > last {
+            return None;
+        }
+
+        for value in (first..=last).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+
+    Some((mask, field != "*"))
+}
+
+async fn next_cron(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (
+        DateNextCronArgs {
+            expression,
+            count,
+            from,
+            timezone,
+        },
+        _,
+    ) = args.process(&registry).await?;
+
+    let schedule = Schedule::parse(&expression.item).map_err(|label| {
+        ShellError::labeled_error("Invalid cron expression", label, &expression.tag)
+    })?;
+
+    let zone = match timezone {
+        Some(timezone) => Zone::parse(&timezone)?,
+        None => Zone::Local,
+    };
+
+    let from = match from {
+        Some(from) => to_date(&from)?,
+        None => Utc::now(),
+    };
+
+    let count = count.map(|count| count.item).unwrap_or(1);
+
+    let times = std::iter::successors(schedule.next_after(&from, zone), move |after| {
+        schedule.next_after(after, zone)
+    })
+    .take(count)
+    .map(move |date| ReturnSuccess::value(zone.date_value(&date, name.clone())));
+
+    Ok(futures::stream::iter(times).to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_field, DateNextCron, Schedule, Zone};
+    use chrono::{TimeZone, Utc};
+
+    fn next_times(expression: &str, from: &str, count: usize) -> Vec<String> {
+        let schedule = Schedule::parse(expression).expect("invalid expression");
+        let from = Utc.datetime_from_str(from, "%F %R").expect("invalid date");
+
+        std::iter::successors(schedule.next_after(&from, Zone::Utc), |after| {
+            schedule.next_after(after, Zone::Utc)
+        })
+        .take(count)
+        .map(|date| date.format("%F %R").to_string())
+        .collect()
+    }
+
+    #[test]
+    fn parses_fields() {
+        assert_eq!(parse_field("*", 0, 6, &[]), Some((0b111_1111, false)));
+        assert_eq!(parse_field("1-3,5", 0, 6, &[]), Some((0b10_1110, true)));
+        assert_eq!(
+            parse_field("*/20", 0, 59, &[]),
+            Some((1 | 1 << 20 | 1 << 40, true))
+        );
+        assert_eq!(
+            parse_field("10/20", 0, 59, &[]),
+            Some((1 << 10 | 1 << 30 | 1 << 50, true))
+        );
+        assert_eq!(
+            parse_field("feb", 1, 12, &super::MONTHS),
+            Some((1 << 2, true))
+        );
+        assert_eq!(parse_field("5-1", 0, 6, &[]), None);
+        assert_eq!(parse_field("*/0", 0, 6, &[]), None);
+        assert_eq!(parse_field("60", 0, 59, &[]), None);
+    }
+
+    #[test]
+    fn rejects_invalid_expressions() {
+        assert!(Schedule::parse("* * * *").is_err());
+        assert!(Schedule::parse("0 25 * * *").is_err());
+        assert!(Schedule::parse("0 9 * * someday").is_err());
+        assert_eq!(Schedule::parse("@daily"), Schedule::parse("0 0 * * *"));
+    }
+
+    #[test]
+    fn finds_the_next_times() {
+        assert_eq!(
+            next_times("0 9 * * mon-fri", "2020-06-05 09:00", 3),
+            vec!["2020-06-08 09:00", "2020-06-09 09:00", "2020-06-10 09:00"]
+        );
+        assert_eq!(
+            next_times("*/15 * * * *", "2020-12-31 23:40", 2),
+            vec!["2020-12-31 23:45", "2021-01-01 00:00"]
+        );
+        assert_eq!(
+            next_times("0 0 29 feb *", "2020-03-01 00:00", 1),
+            vec!["2024-02-29 00:00"]
+        );
+    }
+
+    #[test]
+    fn fires_on_either_the_day_or_the_weekday() {
+        assert_eq!(
+            next_times("0 12 1 * fri", "2020-11-20 13:00", 3),
+            vec!["2020-11-27 12:00", "2020-12-01 12:00", "2020-12-04 12:00"]
+        );
+        assert_eq!(
+            next_times("0 0 * * 7", "2020-06-01 00:00", 1),
+            vec!["2020-06-07 00:00"]
+        );
+    }
+
+    #[test]
+    fn gives_up_on_expressions_that_never_fire() {
+        assert!(next_times("0 0 30 feb *", "2020-01-01 00:00", 1).is_empty());
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(DateNextCron {})
+    }
+}
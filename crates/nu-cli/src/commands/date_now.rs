@@ -0,0 +1,61 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use chrono::Utc;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, UntaggedValue};
+
+pub struct DateNow;
+
+#[async_trait]
+impl WholeStreamCommand for DateNow {
+    fn name(&self) -> &str {
+        "date now"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("date now")
+    }
+
+    fn usage(&self) -> &str {
+        "Get the current date as a date value, for comparisons and arithmetic."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        _registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let tag = args.call_info.name_tag.clone();
+
+        Ok(OutputStream::one(
+            UntaggedValue::date(Utc::now()).into_value(tag),
+        ))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the current date",
+                example: "date now",
+                result: None,
+            },
+            Example {
+                description: "List the files modified in the last week",
+                example: "ls | where modified > ((date now) - 7day)",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DateNow;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(DateNow {})
+    }
+}
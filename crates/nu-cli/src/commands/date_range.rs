@@ -0,0 +1,239 @@
+use crate::commands::seq_date::parse_date;
+use crate::commands::WholeStreamCommand;
+use crate::data::value::duration_of_seconds;
+use crate::prelude::*;
+use chrono::{DateTime, Datelike, Duration, Utc, Weekday};
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{
+    Dictionary, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::TaggedItem;
+
+pub struct DateRange;
+
+#[derive(Deserialize)]
+pub struct DateRangeArgs {
+    from: Value,
+    to: Value,
+    step: Option<Value>,
+    #[serde(rename(deserialize = "weekdays-only"))]
+    weekdays_only: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for DateRange {
+    fn name(&self) -> &str {
+        "date range"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("date range")
+            .required(
+                "from",
+                SyntaxShape::Any,
+                "the first date, as a date, YYYY-MM-DD or an RFC 3339 date and time",
+            )
+            .required(
+                "to",
+                SyntaxShape::Any,
+                "the last date, as a date, YYYY-MM-DD or an RFC 3339 date and time",
+            )
+            .named(
+                "step",
+                SyntaxShape::Unit,
+                "the time between consecutive dates (default: 1d)",
+                Some('s'),
+            )
+            .switch(
+                "weekdays-only",
+                "leave out Saturdays and Sundays",
+                Some('w'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Produces a row for every date between two dates, with its year, quarter, month, week, day and weekday."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        date_range(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Every working day of the first two weeks of June 2020",
+                example: "date range 2020-06-01 2020-06-14 --weekdays-only",
+                result: None,
+            },
+            Example {
+                description: "Every other week for the next three months",
+                example: "date range (date now) ((date now) + 12wk) --step 2wk",
+                result: None,
+            },
+        ]
+    }
+}
+
+pub(crate) fn to_date(value: &Value) -> Result<DateTime<Utc>, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Date(date)) => Ok(*date),
+        UntaggedValue::Primitive(Primitive::String(text))
+        | UntaggedValue::Primitive(Primitive::Line(text)) => {
+            parse_date(&text.clone().tagged(&value.tag))
+        }
+        _ => Err(ShellError::labeled_error(
+            "Expected a date",
+            "expected a date, YYYY-MM-DD or an RFC 3339 date and time",
+            &value.tag,
+        )),
+    }
+}
+
+async fn date_range(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (
+        DateRangeArgs {
+            from,
+            to,
+            step,
+            weekdays_only,
+        },
+        _,
+    ) = args.process(&registry).await?;
+
+    let from = to_date(&from)?;
+    let to = to_date(&to)?;
+
+    let step = match step {
+        None => Duration::days(1),
+        Some(Value {
+            value: UntaggedValue::Primitive(Primitive::Duration(secs)),
+            tag,
+        }) if secs != 0 => match duration_of_seconds(secs) {
+            Some(step) => step,
+            None => {
+                return Err(ShellError::labeled_error(
+                    "Duration out of range",
+                    "the duration is too long to move a date by",
+                    tag,
+                ))
+            }
+        },
+        Some(step) => {
+            return Err(ShellError::labeled_error(
+                "Expected a duration",
+                "expected a non-zero duration, like 1d",
+                step.tag,
+            ))
+        }
+    };
+
+    if (step > Duration::zero()) != (to >= from) && to != from {
+        return Err(ShellError::labeled_error(
+            "The step goes the wrong way",
+            "the step must move from the first date towards the last one",
+            name,
+        ));
+    }
+
+    let ascending = step > Duration::zero();
+
+    // A step past the last date there can be is past the end of the range too
+    let dates = std::iter::successors(Some(from), move |date| date.checked_add_signed(step))
+        .take_while(move |date| if ascending { *date <= to } else { *date >= to })
+        .filter(move |date| !weekdays_only || !is_weekend(date.weekday()))
+        .map(move |date| ReturnSuccess::value(date_row(date, &name)));
+
+    Ok(futures::stream::iter(dates).to_output_stream())
+}
+
+fn is_weekend(weekday: Weekday) -> bool {
+    matches!(weekday, Weekday::Sat | Weekday::Sun)
+}
+
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "monday",
+        Weekday::Tue => "tuesday",
+        Weekday::Wed => "wednesday",
+        Weekday::Thu => "thursday",
+        Weekday::Fri => "friday",
+        Weekday::Sat => "saturday",
+        Weekday::Sun => "sunday",
+    }
+}
+
+fn date_row(date: DateTime<Utc>, tag: &Tag) -> Value {
+    let mut row = IndexMap::new();
+
+    row.insert(
+        "date".to_string(),
+        UntaggedValue::date(date).into_value(tag),
+    );
+    row.insert(
+        "year".to_string(),
+        UntaggedValue::int(date.year()).into_value(tag),
+    );
+    row.insert(
+        "quarter".to_string(),
+        UntaggedValue::int((date.month() - 1) / 3 + 1).into_value(tag),
+    );
+    row.insert(
+        "month".to_string(),
+        UntaggedValue::int(date.month()).into_value(tag),
+    );
+    row.insert(
+        "week".to_string(),
+        UntaggedValue::int(date.iso_week().week()).into_value(tag),
+    );
+    row.insert(
+        "day".to_string(),
+        UntaggedValue::int(date.day()).into_value(tag),
+    );
+    row.insert(
+        "weekday".to_string(),
+        UntaggedValue::string(weekday_name(date.weekday())).into_value(tag),
+    );
+
+    UntaggedValue::Row(Dictionary::from(row)).into_value(tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{date_row, DateRange};
+    use chrono::{TimeZone, Utc};
+    use nu_protocol::UntaggedValue;
+    use nu_source::{SpannedItem, Tag};
+    use nu_value_ext::get_data_by_key;
+
+    #[test]
+    fn describes_the_date_in_the_row() {
+        let row = date_row(Utc.ymd(2020, 12, 31).and_hms(0, 0, 0), &Tag::unknown());
+        let get = |key: &str| {
+            get_data_by_key(&row, key.spanned_unknown())
+                .map(|value| value.value)
+                .expect("column not found")
+        };
+
+        assert_eq!(get("quarter"), UntaggedValue::int(4));
+        assert_eq!(get("week"), UntaggedValue::int(53));
+        assert_eq!(get("weekday"), UntaggedValue::string("thursday"));
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(DateRange {})
+    }
+}
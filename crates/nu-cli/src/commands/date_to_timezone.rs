@@ -0,0 +1,274 @@
+use crate::commands::date::date_to_value;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::Tagged;
+use nu_value_ext::ValueExt;
+
+pub struct DateToTimeZone;
+
+#[derive(Deserialize)]
+pub struct DateToTimeZoneArgs {
+    timezone: Option<Tagged<String>>,
+    rest: Vec<ColumnPath>,
+    utc: bool,
+    local: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for DateToTimeZone {
+    fn name(&self) -> &str {
+        "date to-timezone"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("date to-timezone")
+            .optional(
+                "timezone",
+                SyntaxShape::String,
+                "the timezone, like Europe/Paris, UTC or +02:00",
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally convert the cells at the given column paths",
+            )
+            .switch("utc", "convert to universal time (UTC)", Some('u'))
+            .switch("local", "convert to the local time", Some('l'))
+    }
+
+    fn usage(&self) -> &str {
+        "Shows dates in the given timezone."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        to_timezone(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show a date in Paris time",
+                example:
+                    "echo '2020-06-01T12:00:00+00:00' | into date | date to-timezone Europe/Paris",
+                result: None,
+            },
+            Example {
+                description: "Show the modification dates of the files in the local time",
+                example: "ls | date to-timezone modified --local",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// A timezone given by name, like `Europe/Paris`, or by offset, like `+02:00`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Zone {
+    Utc,
+    Local,
+    Named(Tz),
+    Offset(FixedOffset),
+}
+
+impl Zone {
+    pub(crate) fn parse(zone: &Tagged<String>) -> Result<Zone, ShellError> {
+        Zone::from_name(&zone.item).ok_or_else(|| {
+            ShellError::labeled_error(
+                "Unknown timezone",
+                "expected a timezone like Europe/Paris, UTC or +02:00",
+                &zone.tag,
+            )
+        })
+    }
+
+    fn from_name(name: &str) -> Option<Zone> {
+        let name = name.trim();
+
+        match name.to_lowercase().as_str() {
+            "utc" | "z" => return Some(Zone::Utc),
+            "local" => return Some(Zone::Local),
+            _ => {}
+        }
+
+        if name.starts_with('+') || name.starts_with('-') {
+            return parse_offset(name).map(Zone::Offset);
+        }
+
+        name.parse::<Tz>().ok().map(Zone::Named)
+    }
+
+    /// Reads the date and time as the wall clock time in this timezone.
+    /// When the clocks go back, the earlier of the two instants is picked.
+    pub(crate) fn local_to_utc(&self, naive: &NaiveDateTime) -> Option<DateTime<Utc>> {
+        match self {
+            Zone::Utc => Some(Utc.from_utc_datetime(naive)),
+            Zone::Local => Local
+                .from_local_datetime(naive)
+                .earliest()
+                .map(|d| d.with_timezone(&Utc)),
+            Zone::Named(tz) => tz
+                .from_local_datetime(naive)
+                .earliest()
+                .map(|d| d.with_timezone(&Utc)),
+            Zone::Offset(offset) => offset
+                .from_local_datetime(naive)
+                .earliest()
+                .map(|d| d.with_timezone(&Utc)),
+        }
+    }
+
+    /// The wall clock time in this timezone.
+    pub(crate) fn utc_to_local(&self, date: &DateTime<Utc>) -> NaiveDateTime {
+        match self {
+            Zone::Utc => date.naive_utc(),
+            Zone::Local => date.with_timezone(&Local).naive_local(),
+            Zone::Named(tz) => date.with_timezone(tz).naive_local(),
+            Zone::Offset(offset) => date.with_timezone(offset).naive_local(),
+        }
+    }
+
+    pub(crate) fn date_value(&self, date: &DateTime<Utc>, tag: Tag) -> Value {
+        match self {
+            Zone::Utc => date_to_value(*date, tag),
+            Zone::Local => date_to_value(date.with_timezone(&Local), tag),
+            Zone::Named(tz) => date_to_value(date.with_timezone(tz), tag),
+            Zone::Offset(offset) => date_to_value(date.with_timezone(offset), tag),
+        }
+    }
+}
+
+/// Parses offsets like `+02`, `+0200` and `-05:30`.
+fn parse_offset(offset: &str) -> Option<FixedOffset> {
+    let sign = if offset.starts_with('-') { -1 } else { 1 };
+    let digits: String = offset[1..].chars().filter(|c| *c != ':').collect();
+
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let (hours, minutes) = match digits.len() {
+        2 => (digits.parse::<i32>().ok()?, 0),
+        4 => (
+            digits[..2].parse::<i32>().ok()?,
+            digits[2..].parse::<i32>().ok()?,
+        ),
+        _ => return None,
+    };
+
+    if minutes >= 60 {
+        return None;
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+async fn to_timezone(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name_tag = args.call_info.name_tag.clone();
+    let (
+        DateToTimeZoneArgs {
+            timezone,
+            rest: column_paths,
+            utc,
+            local,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    let zone = match (timezone, utc, local) {
+        (Some(timezone), false, false) => Zone::parse(&timezone)?,
+        (None, true, false) => Zone::Utc,
+        (None, false, true) => Zone::Local,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a single timezone",
+                "give a timezone, --utc or --local",
+                name_tag,
+            ))
+        }
+    };
+
+    Ok(input
+        .map(move |value| {
+            if column_paths.is_empty() {
+                ReturnSuccess::value(action(&value, zone)?)
+            } else {
+                let mut ret = value;
+
+                for path in &column_paths {
+                    ret =
+                        ret.swap_data_by_column_path(path, Box::new(move |old| action(old, zone)))?;
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn action(value: &Value, zone: Zone) -> Result<Value, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Date(date)) => {
+            Ok(zone.date_value(date, value.tag.clone()))
+        }
+        other => Err(ShellError::labeled_error(
+            "Expected a date",
+            format!("found {}", other.type_name()),
+            &value.tag,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_offset, DateToTimeZone, Zone};
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use chrono_tz::Europe::Paris;
+
+    #[test]
+    fn parses_timezone_names_and_offsets() {
+        assert_eq!(Zone::from_name("UTC"), Some(Zone::Utc));
+        assert_eq!(Zone::from_name("Europe/Paris"), Some(Zone::Named(Paris)));
+        assert_eq!(
+            Zone::from_name("-05:30"),
+            parse_offset("-0530").map(Zone::Offset)
+        );
+        assert_eq!(parse_offset("+02").map(|o| o.local_minus_utc()), Some(7200));
+        assert_eq!(Zone::from_name("Mars/Olympus_Mons"), None);
+        assert_eq!(Zone::from_name("+2:0:0"), None);
+    }
+
+    #[test]
+    fn reads_wall_clock_times_in_the_timezone() {
+        let naive = NaiveDate::from_ymd(2020, 6, 1).and_hms(14, 0, 0);
+
+        assert_eq!(
+            Zone::Named(Paris).local_to_utc(&naive),
+            Some(Utc.ymd(2020, 6, 1).and_hms(12, 0, 0))
+        );
+        assert_eq!(
+            Zone::from_name("-03:00").and_then(|zone| zone.local_to_utc(&naive)),
+            Some(Utc.ymd(2020, 6, 1).and_hms(17, 0, 0))
+        );
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(DateToTimeZone {})
+    }
+}
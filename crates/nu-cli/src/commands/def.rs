@@ -0,0 +1,614 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use bigdecimal::BigDecimal;
+use nu_errors::ShellError;
+use nu_protocol::{
+    hir::Block, CommandAction, EvaluatedArgs, NamedType, ReturnSuccess, Signature, SyntaxShape,
+    UntaggedValue, Value,
+};
+use nu_source::Tagged;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+pub struct Def;
+
+#[derive(Deserialize)]
+pub struct DefArgs {
+    pub name: Tagged<String>,
+    pub params: Vec<Value>,
+    pub block: Block,
+    pub usage: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Def {
+    fn name(&self) -> &str {
+        "def"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("def")
+            .required("name", SyntaxShape::String, "the name of the command")
+            .required(
+                "params",
+                SyntaxShape::Table,
+                "the parameters of the command, like [x, y: int, z = 1, ...rest, --flag(-f)]",
+            )
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the block to run as the body of the command",
+            )
+            .named(
+                "usage",
+                SyntaxShape::String,
+                "the documentation of the command, given by the # comments above def when not set",
+                Some('u'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        r#"Define a command, with parameters that can be typed, optional, given defaults, or flags.
+
+Each parameter is given to the body as a variable of its name, like $x:
+  x             a required parameter
+  x: int        a parameter of a type (any, int, number, string, path, pattern, block, table, range, unit)
+  x?            an optional parameter, $x is nothing when it's not given
+  x = 1         an optional parameter with a default value
+  ...rest       the rest of the arguments, as a list
+  --flag(-f)    a switch, with an optional short name, $flag is true when it's given
+  --flag: int   a flag taking a value, which can have a default too
+
+The # comments right above def document the command for help. A comment line starting with > is
+an example, described by the comment line right above it."#
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        def(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "A command with a parameter",
+                example: "def greet [name] { echo $(build-string 'hello ' $name) }",
+                result: None,
+            },
+            Example {
+                description: "A command with a typed parameter and a default",
+                example: "def repeat [text: string, times: int = 2] { seq $times | each { echo $text } }",
+                result: None,
+            },
+            Example {
+                description: "A command with documentation and an example",
+                example: r#"def greet [name] { build-string 'hello ' $name } --usage "Greet someone.
+
+Say hello to nu
+> greet nu""#,
+                result: None,
+            },
+            Example {
+                description: "A command with the rest of its arguments and a switch",
+                example: "def touch-all [...files: path, --verbose(-v)] { echo $files | each { touch $it } }",
+                result: None,
+            },
+        ]
+    }
+}
+
+pub async fn def(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let (
+        DefArgs {
+            name,
+            params,
+            block,
+            usage,
+        },
+        _,
+    ) = args.process(&registry).await?;
+
+    // Check the parameters now, so a mistake shows up where the command is defined
+    let parameters = Parameters::parse(&name.item, &parameter_words(params)?)?;
+
+    Ok(OutputStream::one(ReturnSuccess::action(
+        CommandAction::AddCommand(
+            name.item,
+            parameters.specs.into_iter().map(|spec| spec.item).collect(),
+            usage.map(|usage| usage.item).unwrap_or_default(),
+            block,
+        ),
+    )))
+}
+
+/// The words of a list of parameters, like `[x: int, --flag]`, for `Parameters::parse`.
+pub(crate) fn parameter_words(params: Vec<Value>) -> Result<Vec<Tagged<String>>, ShellError> {
+    let mut words = vec![];
+    for param in params {
+        match &param.value {
+            UntaggedValue::Primitive(_) => words.push(param.convert_to_string().tagged(param.tag)),
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Expected a parameter",
+                    "expected a parameter like x, x: int or --flag",
+                    param.tag,
+                ))
+            }
+        }
+    }
+
+    Ok(words)
+}
+
+/// The parameters of a command defined with def, and how its arguments are given to its body.
+#[derive(Clone)]
+pub(crate) struct Parameters {
+    pub(crate) signature: Signature,
+    /// The variables the positional arguments are given as, in order.
+    pub(crate) positional: Vec<String>,
+    /// The variable the arguments after the positional ones are given as.
+    pub(crate) rest: Option<String>,
+    pub(crate) defaults: IndexMap<String, Value>,
+    /// Each parameter as written, whole, like `x: int = 1`.
+    pub(crate) specs: Vec<Tagged<String>>,
+}
+
+impl Parameters {
+    /// Parse the parameters from the words they were written as, where a parameter may be split
+    /// across several words, like `x:` `int` `=` `1`.
+    pub(crate) fn parse(name: &str, words: &[Tagged<String>]) -> Result<Parameters, ShellError> {
+        let mut specs: Vec<Tagged<String>> = vec![];
+        let mut continued = false;
+
+        for word in words {
+            let text = word.item.trim_end_matches(',');
+            if text.is_empty() {
+                continue;
+            }
+
+            let continues_last = text.starts_with(':') || text.starts_with('=');
+
+            match specs.last_mut() {
+                Some(last) if continued || continues_last => {
+                    last.item.push_str(text);
+                    last.tag = last.tag.until(&word.tag);
+                }
+                _ => specs.push(text.to_string().tagged(&word.tag)),
+            }
+
+            continued = !word.item.ends_with(',') && (text.ends_with(':') || text.ends_with('='));
+        }
+
+        let mut parameters = Parameters {
+            signature: Signature::build(name),
+            positional: vec![],
+            rest: None,
+            defaults: IndexMap::new(),
+            specs: vec![],
+        };
+
+        let mut optional_seen = false;
+
+        for spec in &specs {
+            let (declaration, default) = match spec.item.find('=') {
+                Some(index) => (
+                    &spec.item[..index],
+                    Some(trim_quotes(&spec.item[index + 1..])),
+                ),
+                None => (&spec.item[..], None),
+            };
+            let (declaration, shape_name) = match declaration.find(':') {
+                Some(index) => (&declaration[..index], Some(&declaration[index + 1..])),
+                None => (declaration, None),
+            };
+
+            let shape = match shape_name {
+                Some(shape_name) => shape(shape_name).ok_or_else(|| {
+                    ShellError::labeled_error(
+                        "Unknown type",
+                        format!(
+                            "'{}' is not a type, expected any, int, number, string, path, pattern, block, table, range or unit",
+                            shape_name
+                        ),
+                        &spec.tag,
+                    )
+                })?,
+                None => SyntaxShape::Any,
+            };
+            let description = match (shape_name, default) {
+                (Some(shape_name), Some(default)) => format!("{}, default {}", shape_name, default),
+                (Some(shape_name), None) => shape_name.to_string(),
+                (None, Some(default)) => format!("default {}", default),
+                (None, None) => String::new(),
+            };
+
+            if let Some(flag) = declaration.strip_prefix("--") {
+                let (long, short) = flag_names(flag, &spec.tag)?;
+                parameters.check_unused(&long, &spec.tag)?;
+
+                if let Some(short) = short {
+                    if parameters.signature.get_shorts().contains(&short) {
+                        return Err(ShellError::labeled_error(
+                            "Short flag already used",
+                            format!("another flag is given as -{} already", short),
+                            &spec.tag,
+                        ));
+                    }
+                }
+
+                parameters.signature = if shape_name.is_none() && default.is_none() {
+                    parameters.signature.switch(&long, description, short)
+                } else {
+                    parameters.signature.named(&long, shape, description, short)
+                };
+
+                if let Some(default) = default {
+                    let value = default_value(default, shape, &spec.tag)?;
+                    parameters.defaults.insert(long, value);
+                }
+            } else if let Some(rest) = declaration.strip_prefix("...") {
+                let variable = variable_name(rest, &spec.tag)?;
+                parameters.check_unused(&variable, &spec.tag)?;
+
+                if parameters.rest.is_some() || default.is_some() {
+                    return Err(ShellError::labeled_error(
+                        "Unexpected rest parameter",
+                        "there can be one rest parameter, without a default",
+                        &spec.tag,
+                    ));
+                }
+
+                parameters.signature = parameters
+                    .signature
+                    .rest(shape, format!("${} {}", variable, description).trim_end());
+                parameters.rest = Some(variable);
+            } else {
+                let optional = declaration.ends_with('?') || default.is_some();
+                let variable = variable_name(declaration.trim_end_matches('?'), &spec.tag)?;
+                parameters.check_unused(&variable, &spec.tag)?;
+
+                if parameters.rest.is_some() {
+                    return Err(ShellError::labeled_error(
+                        "Parameter after the rest parameter",
+                        "the rest parameter needs to come after the other parameters",
+                        &spec.tag,
+                    ));
+                }
+
+                if optional {
+                    optional_seen = true;
+                    parameters.signature =
+                        parameters.signature.optional(&variable, shape, description);
+                } else if optional_seen {
+                    return Err(ShellError::labeled_error(
+                        "Required parameter after an optional one",
+                        "required parameters need to come before the optional ones",
+                        &spec.tag,
+                    ));
+                } else {
+                    parameters.signature =
+                        parameters.signature.required(&variable, shape, description);
+                }
+
+                if let Some(default) = default {
+                    let value = default_value(default, shape, &spec.tag)?;
+                    parameters.defaults.insert(variable.clone(), value);
+                }
+
+                parameters.positional.push(variable);
+            }
+        }
+
+        parameters.specs = specs;
+
+        Ok(parameters)
+    }
+
+    /// Gives the arguments of a call to the variables of the parameters, in `vars`. The positional
+    /// arguments left over, when there is no rest parameter to take them, are given back.
+    pub(crate) fn bind(
+        &self,
+        args: &EvaluatedArgs,
+        tag: &Tag,
+        vars: &mut IndexMap<String, Value>,
+    ) -> Vec<Value> {
+        let mut positional = args.slice_from(0).into_iter();
+
+        for variable in &self.positional {
+            let value = positional
+                .next()
+                .or_else(|| self.defaults.get(variable).cloned())
+                .unwrap_or_else(|| UntaggedValue::nothing().into_value(tag));
+            vars.insert(format!("${}", variable), value);
+        }
+
+        if let Some(rest) = &self.rest {
+            let values: Vec<_> = positional.by_ref().collect();
+            vars.insert(
+                format!("${}", rest),
+                UntaggedValue::table(&values).into_value(tag),
+            );
+        }
+
+        for (flag, (named_type, _)) in &self.signature.named {
+            if flag == "help" {
+                continue;
+            }
+
+            let value = match args.get(flag) {
+                Some(value) => value.clone(),
+                None => match named_type {
+                    NamedType::Switch(_) => UntaggedValue::boolean(false).into_untagged_value(),
+                    _ => self
+                        .defaults
+                        .get(flag)
+                        .cloned()
+                        .unwrap_or_else(|| UntaggedValue::nothing().into_untagged_value()),
+                },
+            };
+            vars.insert(format!("${}", flag), value);
+        }
+
+        positional.collect()
+    }
+
+    fn check_unused(&self, variable: &str, tag: &Tag) -> Result<(), ShellError> {
+        let used = self.positional.iter().any(|name| name == variable)
+            || self.rest.as_deref() == Some(variable)
+            || self.signature.named.contains_key(variable);
+
+        if used {
+            Err(ShellError::labeled_error(
+                "Parameter already defined",
+                format!("there is another parameter named {}", variable),
+                tag,
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The usage and the examples of a command from its documentation, where a line starting with `>`
+/// is an example, described by the line right above it.
+pub(crate) fn documentation(text: &str) -> (String, Vec<(String, String)>) {
+    let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    let is_example = |line: &str| line.trim_start().starts_with('>');
+    let is_description = |index: usize| {
+        index > 0
+            && !lines[index].trim().is_empty()
+            && !is_example(lines[index])
+            && lines.get(index + 1).map_or(false, |line| is_example(line))
+    };
+
+    let mut usage = vec![];
+    let mut examples = vec![];
+
+    for (index, line) in lines.iter().enumerate() {
+        if is_example(line) {
+            let description = if is_description(index.saturating_sub(1)) {
+                lines[index - 1].trim().to_string()
+            } else {
+                String::new()
+            };
+            let example = line.trim_start()[1..].trim().to_string();
+            examples.push((description, example));
+        } else if !is_description(index) {
+            // Blank lines left around examples are kept once
+            let blank =
+                line.is_empty() && usage.last().map_or(false, |last: &&str| last.is_empty());
+            if !blank {
+                usage.push(*line);
+            }
+        }
+    }
+
+    (usage.join("\n").trim().to_string(), examples)
+}
+
+fn shape(name: &str) -> Option<SyntaxShape> {
+    Some(match name {
+        "any" => SyntaxShape::Any,
+        "int" => SyntaxShape::Int,
+        "number" => SyntaxShape::Number,
+        "string" => SyntaxShape::String,
+        "path" => SyntaxShape::Path,
+        "pattern" => SyntaxShape::Pattern,
+        "block" => SyntaxShape::Block,
+        "table" => SyntaxShape::Table,
+        "range" => SyntaxShape::Range,
+        "unit" => SyntaxShape::Unit,
+        _ => return None,
+    })
+}
+
+fn variable_name(name: &str, tag: &Tag) -> Result<String, ShellError> {
+    let name = name.trim_start_matches('$');
+
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+    {
+        Ok(name.to_string())
+    } else {
+        Err(ShellError::labeled_error(
+            "Invalid parameter name",
+            "expected a name of letters, numbers, _ and -",
+            tag,
+        ))
+    }
+}
+
+/// The long and short name of a flag, written like `verbose(-v)`.
+fn flag_names(flag: &str, tag: &Tag) -> Result<(String, Option<char>), ShellError> {
+    let (long, short) = match flag.find('(') {
+        Some(index) if flag.ends_with(')') => {
+            (&flag[..index], Some(&flag[index + 1..flag.len() - 1]))
+        }
+        Some(_) => {
+            return Err(ShellError::labeled_error(
+                "Invalid flag",
+                "expected a short name like --flag(-f)",
+                tag,
+            ))
+        }
+        None => (flag, None),
+    };
+
+    let short = match short.map(|short| short.trim_start_matches('-').chars().collect::<Vec<_>>()) {
+        Some(chars) => match chars[..] {
+            [c] if c.is_alphanumeric() => Some(c),
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Invalid flag",
+                    "expected a short name of one letter, like --flag(-f)",
+                    tag,
+                ))
+            }
+        },
+        None => None,
+    };
+
+    Ok((variable_name(long, tag)?, short))
+}
+
+fn trim_quotes(text: &str) -> &str {
+    let mut chars = text.chars();
+
+    match (chars.next(), chars.next_back()) {
+        (Some('"'), Some('"')) | (Some('\''), Some('\'')) | (Some('`'), Some('`')) => {
+            &text[1..text.len() - 1]
+        }
+        _ => text,
+    }
+}
+
+fn default_value(text: &str, shape: SyntaxShape, tag: &Tag) -> Result<Value, ShellError> {
+    let value = match shape {
+        SyntaxShape::Int => text.parse::<i64>().ok().map(UntaggedValue::int),
+        SyntaxShape::Number => match text.parse::<i64>() {
+            Ok(int) => Some(UntaggedValue::int(int)),
+            Err(_) => BigDecimal::from_str(text).ok().map(UntaggedValue::decimal),
+        },
+        SyntaxShape::Any => match text.parse::<i64>() {
+            Ok(int) => Some(UntaggedValue::int(int)),
+            Err(_) => Some(match BigDecimal::from_str(text) {
+                Ok(decimal) => UntaggedValue::decimal(decimal),
+                Err(_) => UntaggedValue::string(text),
+            }),
+        },
+        SyntaxShape::Path => Some(UntaggedValue::path(PathBuf::from(text))),
+        SyntaxShape::String | SyntaxShape::Pattern => Some(UntaggedValue::string(text)),
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Unsupported default",
+                "only numbers, strings and paths can have defaults",
+                tag,
+            ))
+        }
+    };
+
+    value.map(|value| value.into_value(tag)).ok_or_else(|| {
+        ShellError::labeled_error(
+            "Invalid default",
+            format!("'{}' is not a value of the parameter's type", text),
+            tag,
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{documentation, Def, Parameters};
+    use nu_protocol::{NamedType, PositionalType, SyntaxShape, UntaggedValue};
+    use nu_source::{Tag, Tagged, TaggedItem};
+
+    fn words(words: &[&str]) -> Vec<Tagged<String>> {
+        words
+            .iter()
+            .map(|word| word.to_string().tagged(Tag::unknown()))
+            .collect()
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Def {})
+    }
+
+    #[test]
+    fn parses_parameters_split_across_words() {
+        let parameters = Parameters::parse(
+            "cmd",
+            &words(&[
+                "x:",
+                "int,",
+                "y",
+                "=",
+                "2,",
+                "...rest:string,",
+                "--verbose(-v)",
+            ]),
+        )
+        .expect("couldn't parse parameters");
+
+        assert_eq!(parameters.positional, vec!["x", "y"]);
+        assert_eq!(parameters.rest.as_deref(), Some("rest"));
+        assert!(matches!(
+            &parameters.signature.positional[0].0,
+            PositionalType::Mandatory(name, SyntaxShape::Int) if name == "x"
+        ));
+        assert!(matches!(
+            &parameters.signature.positional[1].0,
+            PositionalType::Optional(name, SyntaxShape::Any) if name == "y"
+        ));
+        assert_eq!(
+            parameters
+                .defaults
+                .get("y")
+                .map(|value| value.value.clone()),
+            Some(UntaggedValue::int(2))
+        );
+        assert!(matches!(
+            parameters.signature.named.get("verbose"),
+            Some((NamedType::Switch(Some('v')), _))
+        ));
+    }
+
+    #[test]
+    fn splits_documentation_into_usage_and_examples() {
+        let (usage, examples) = documentation(
+            "Greet someone.\n\nThey can be greeted by name\n> greet nu\n> greet nu --shout\n\nAnd that's all.",
+        );
+
+        assert_eq!(usage, "Greet someone.\n\nAnd that's all.");
+        assert_eq!(
+            examples,
+            vec![
+                (
+                    "They can be greeted by name".to_string(),
+                    "greet nu".to_string()
+                ),
+                (String::new(), "greet nu --shout".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        assert!(Parameters::parse("cmd", &words(&["x?", "y"])).is_err());
+        assert!(Parameters::parse("cmd", &words(&["x", "x"])).is_err());
+        assert!(Parameters::parse("cmd", &words(&["x: nope"])).is_err());
+        assert!(Parameters::parse("cmd", &words(&["x:int=one"])).is_err());
+    }
+}
@@ -31,7 +31,7 @@ impl WholeStreamCommand for Default {
     }
 
     fn usage(&self) -> &str {
-        "Sets a default row's column if missing."
+        "Sets a default row's column if missing or empty."
     }
 
     async fn run(
@@ -43,11 +43,18 @@ impl WholeStreamCommand for Default {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Give a default 'target' to all file entries",
-            example: "ls -af | default target 'nothing'",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Give a default 'target' to all file entries",
+                example: "ls -af | default target 'nothing'",
+                result: None,
+            },
+            Example {
+                description: "Fill the empty cells of a CSV column before adding them up",
+                example: "open sales.csv | default amount 0 | get amount | sum",
+                result: None,
+            },
+        ]
     }
 }
 
@@ -64,7 +71,7 @@ async fn default(
                 Value {
                     value: UntaggedValue::Row(ref r),
                     ..
-                } => r.get_data(&column.item).borrow().is_none(),
+                } => r.get_data(&column.item).borrow().is_empty(),
                 _ => false,
             };
 
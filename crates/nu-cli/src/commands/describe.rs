@@ -0,0 +1,228 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use crate::utils::data_processing::{max, min};
+use indexmap::{IndexMap, IndexSet};
+use nu_errors::ShellError;
+use nu_protocol::{
+    ReturnSuccess, ShellTypeName, Signature, TaggedDictBuilder, UntaggedValue, Value,
+};
+
+pub struct Describe;
+
+#[async_trait]
+impl WholeStreamCommand for Describe {
+    fn name(&self) -> &str {
+        "describe"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("describe")
+    }
+
+    fn usage(&self) -> &str {
+        "Describes the type of the value, or the columns of the table, given."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        describe(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Describe a single value",
+                example: "echo 42 | describe",
+                result: Some(vec![Value::from("integer")]),
+            },
+            Example {
+                description: "Describe the columns of a table",
+                example: "open unfamiliar.csv | describe",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// The name given to the values of a table that aren't rows.
+const VALUE_COLUMN: &str = "<value>";
+
+async fn describe(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let args = args.evaluate_once(&registry).await?;
+    let name = args.name_tag();
+    let values: Vec<Value> = args.input.collect().await;
+
+    match &values[..] {
+        [value] => Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::string(type_of(value)).into_value(name),
+        ))),
+        _ => Ok(futures::stream::iter(
+            schema(&values, &name).into_iter().map(ReturnSuccess::value),
+        )
+        .to_output_stream()),
+    }
+}
+
+/// The type of a value, including the types of the columns of rows and the items of tables.
+fn type_of(value: &Value) -> String {
+    match &value.value {
+        UntaggedValue::Row(dict) => format!(
+            "row<{}>",
+            dict.entries
+                .iter()
+                .map(|(column, value)| format!("{}: {}", column, type_of(value)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        UntaggedValue::Table(values) => {
+            let types: IndexSet<String> = values.iter().map(type_of).collect();
+            if types.is_empty() {
+                "table".to_string()
+            } else {
+                format!(
+                    "table<{}>",
+                    types.into_iter().collect::<Vec<_>>().join(" | ")
+                )
+            }
+        }
+        other => other.type_name().to_string(),
+    }
+}
+
+/// A row per column of the table, with the type, number of empty cells (nothing or ""), number of distinct
+/// values and the smallest and largest values found in it.
+fn schema(values: &[Value], tag: &Tag) -> Vec<Value> {
+    let mut columns: IndexMap<String, Vec<Value>> = IndexMap::new();
+
+    for value in values {
+        match &value.value {
+            UntaggedValue::Row(dict) => {
+                for (column, cell) in &dict.entries {
+                    columns
+                        .entry(column.clone())
+                        .or_default()
+                        .push(cell.clone());
+                }
+            }
+            _ => columns
+                .entry(VALUE_COLUMN.to_string())
+                .or_default()
+                .push(value.clone()),
+        }
+    }
+
+    columns
+        .into_iter()
+        .map(|(column, cells)| {
+            let present: Vec<Value> = cells.into_iter().filter(|cell| !cell.is_empty()).collect();
+
+            let types: IndexSet<String> = present.iter().map(type_of).collect();
+            let distinct: IndexSet<&UntaggedValue> =
+                present.iter().map(|cell| &cell.value).collect();
+            let comparable = present.iter().all(|cell| cell.is_primitive());
+            let bound = |found: Result<Value, ShellError>| match found {
+                Ok(value) if comparable => value.value.into_value(tag),
+                _ => UntaggedValue::nothing().into_value(tag),
+            };
+
+            let mut row = TaggedDictBuilder::new(tag);
+            row.insert_untagged("column", UntaggedValue::string(column));
+            row.insert_untagged(
+                "type",
+                UntaggedValue::string(if types.is_empty() {
+                    "nothing".to_string()
+                } else {
+                    types.into_iter().collect::<Vec<_>>().join(" | ")
+                }),
+            );
+            row.insert_untagged("nulls", UntaggedValue::int(values.len() - present.len()));
+            row.insert_untagged("distinct", UntaggedValue::int(distinct.len()));
+            row.insert_value("min", bound(min(present.clone())));
+            row.insert_value("max", bound(max(present)));
+            row.into_value()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{schema, type_of, Describe};
+    use indexmap::IndexMap;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::Tag;
+
+    fn string(input: impl Into<String>) -> Value {
+        UntaggedValue::string(input.into()).into_untagged_value()
+    }
+
+    fn int(n: i64) -> Value {
+        UntaggedValue::int(n).into_untagged_value()
+    }
+
+    fn nothing() -> Value {
+        UntaggedValue::nothing().into_untagged_value()
+    }
+
+    fn row(entries: IndexMap<String, Value>) -> Value {
+        UntaggedValue::row(entries).into_untagged_value()
+    }
+
+    #[test]
+    fn describes_nested_types() {
+        let value = row(indexmap! {
+            "name".into() => string("nu"),
+            "sizes".into() => UntaggedValue::table(&[int(1), string("2")]).into_untagged_value(),
+        });
+
+        assert_eq!(
+            type_of(&value),
+            "row<name: string, sizes: table<integer | string>>"
+        );
+    }
+
+    #[test]
+    fn summarizes_every_column() {
+        let rows = vec![
+            row(indexmap! {"name".into() => string("b"), "size".into() => int(3)}),
+            row(indexmap! {"name".into() => string("a"), "size".into() => nothing()}),
+            row(indexmap! {"name".into() => string("b"), "size".into() => int(1)}),
+        ];
+
+        assert_eq!(
+            schema(&rows, &Tag::unknown()),
+            vec![
+                row(indexmap! {
+                    "column".into() => string("name"),
+                    "type".into() => string("string"),
+                    "nulls".into() => int(0),
+                    "distinct".into() => int(2),
+                    "min".into() => string("a"),
+                    "max".into() => string("b"),
+                }),
+                row(indexmap! {
+                    "column".into() => string("size"),
+                    "type".into() => string("integer"),
+                    "nulls".into() => int(1),
+                    "distinct".into() => int(2),
+                    "min".into() => int(1),
+                    "max".into() => int(3),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Describe {})
+    }
+}
@@ -0,0 +1,100 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+
+#[derive(Deserialize)]
+struct DropNthArgs {
+    row_number: Tagged<u64>,
+    rest: Vec<Tagged<u64>>,
+}
+
+pub struct DropNth;
+
+#[async_trait]
+impl WholeStreamCommand for DropNth {
+    fn name(&self) -> &str {
+        "drop nth"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("drop nth")
+            .required(
+                "row number",
+                SyntaxShape::Int,
+                "the number of the row to drop",
+            )
+            .rest(SyntaxShape::Int, "Optionally drop more rows")
+    }
+
+    fn usage(&self) -> &str {
+        "Drop the selected rows"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        drop_nth(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Drop the second row",
+                example: "echo [first second third] | drop nth 1",
+                result: Some(vec![Value::from("first"), Value::from("third")]),
+            },
+            Example {
+                description: "Drop the first and third rows",
+                example: "echo [first second third] | drop nth 0 2",
+                result: Some(vec![Value::from("second")]),
+            },
+        ]
+    }
+}
+
+async fn drop_nth(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let (
+        DropNthArgs {
+            row_number,
+            rest: and_rows,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    let row_numbers = std::iter::once(row_number)
+        .chain(and_rows)
+        .map(|row| row.item)
+        .collect::<Vec<u64>>();
+
+    Ok(input
+        .enumerate()
+        .filter_map(move |(idx, item)| {
+            futures::future::ready(if row_numbers.contains(&(idx as u64)) {
+                None
+            } else {
+                Some(ReturnSuccess::value(item))
+            })
+        })
+        .to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DropNth;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(DropNth {})
+    }
+}
@@ -28,6 +28,7 @@ pub struct DuArgs {
     max_depth: Option<Tagged<u64>>,
     #[serde(rename = "min-size")]
     min_size: Option<Tagged<u64>>,
+    flat: bool,
 }
 
 #[async_trait]
@@ -67,6 +68,11 @@ impl WholeStreamCommand for Du {
                 "Exclude files below this size",
                 Some('m'),
             )
+            .switch(
+                "flat",
+                "List every directory, down to the max depth, in its own row instead of nesting them",
+                Some('f'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -82,11 +88,18 @@ impl WholeStreamCommand for Du {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Disk usage of the current directory",
-            example: "du",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Disk usage of the current directory",
+                example: "du",
+                result: None,
+            },
+            Example {
+                description: "The largest directories, two levels down",
+                example: "du --flat --max-depth 2 | sort-by physical | reverse | first 5",
+                result: None,
+            },
+        ]
     }
 }
 
@@ -129,6 +142,7 @@ async fn du(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStrea
     let deref = args.deref;
     let max_depth = args.max_depth.map(|f| f.item);
     let min_size = args.min_size.map(|f| f.item);
+    let flat = args.flat;
 
     let params = DirBuilder {
         tag: tag.clone(),
@@ -145,9 +159,18 @@ async fn du(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStrea
             Ok(p) => {
                 let mut output = vec![];
                 if p.is_dir() {
-                    output.push(Ok(ReturnSuccess::Value(
-                        DirInfo::new(p, &params, max_depth, ctrl_c.clone()).into(),
-                    )));
+                    let dir = DirInfo::new(p, &params, max_depth, ctrl_c.clone());
+                    if flat {
+                        let mut rows = vec![];
+                        dir.flatten(&mut rows);
+                        output.extend(rows.into_iter().map(ReturnSuccess::value));
+                    } else {
+                        output.push(Ok(ReturnSuccess::Value(dir.into())));
+                    }
+                } else if flat {
+                    for f in FileInfo::new(p, deref, tag.clone()).into_iter() {
+                        output.push(ReturnSuccess::value(f.flat_row()));
+                    }
                 } else {
                     for v in FileInfo::new(p, deref, tag.clone()).into_iter() {
                         output.push(Ok(ReturnSuccess::Value(v.into())));
@@ -272,25 +295,26 @@ impl DirInfo {
         s
     }
 
+    /// Directories below the depth limit still count towards the sizes, but aren't listed.
     fn add_dir(
         mut self,
         path: impl Into<PathBuf>,
-        mut depth: Option<u64>,
+        depth: Option<u64>,
         params: &DirBuilder,
         ctrl_c: Arc<AtomicBool>,
     ) -> Self {
-        if let Some(current) = depth {
-            if let Some(new) = current.checked_sub(1) {
-                depth = Some(new);
-            } else {
-                return self;
-            }
-        }
+        let (depth, listed) = match depth.map(|current| current.checked_sub(1)) {
+            Some(Some(new)) => (Some(new), true),
+            Some(None) => (Some(0), false),
+            None => (None, true),
+        };
 
         let d = DirInfo::new(path, &params, depth, ctrl_c);
         self.size += d.size;
         self.blocks += d.blocks;
-        self.dirs.push(d);
+        if listed {
+            self.dirs.push(d);
+        }
         self
     }
 
@@ -326,6 +350,48 @@ impl DirInfo {
     pub fn get_size(&self) -> u64 {
         self.size
     }
+
+    pub fn get_blocks(&self) -> u64 {
+        self.blocks
+    }
+
+    /// Adds a row for this directory, then for the directories and files in it.
+    fn flatten(self, rows: &mut Vec<Value>) {
+        rows.push(flat_row(self.path, self.size, Some(self.blocks), &self.tag));
+
+        for file in self.files {
+            rows.push(file.flat_row());
+        }
+
+        for dir in self.dirs {
+            dir.flatten(rows);
+        }
+    }
+}
+
+impl FileInfo {
+    fn flat_row(self) -> Value {
+        flat_row(self.path, self.size, self.blocks, &self.tag)
+    }
+}
+
+fn flat_row(path: PathBuf, apparent: u64, physical: Option<u64>, tag: &Tag) -> Value {
+    let mut r: IndexMap<String, Value> = IndexMap::new();
+
+    r.insert("path".to_string(), UntaggedValue::path(path).retag(tag));
+    r.insert(
+        "apparent".to_string(),
+        UntaggedValue::bytes(apparent).retag(tag),
+    );
+    r.insert(
+        "physical".to_string(),
+        physical
+            .map(UntaggedValue::bytes)
+            .unwrap_or_else(UntaggedValue::nothing)
+            .retag(tag),
+    );
+
+    UntaggedValue::row(r).retag(tag)
 }
 
 fn glob_err_into(e: GlobError) -> ShellError {
@@ -3,18 +3,24 @@ use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
 
+use futures::channel::oneshot;
+use futures::executor::block_on;
 use futures::stream::once;
 use nu_errors::ShellError;
 use nu_protocol::{
     hir::Block, hir::Expression, hir::SpannedExpression, hir::Synthetic, Scope, Signature,
     SyntaxShape, UntaggedValue, Value,
 };
+use nu_source::Tagged;
 
 pub struct Each;
 
 #[derive(Deserialize)]
 pub struct EachArgs {
     block: Block,
+    parallel: bool,
+    threads: Option<Tagged<usize>>,
+    unordered: bool,
 }
 
 #[async_trait]
@@ -24,11 +30,24 @@ impl WholeStreamCommand for Each {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("each").required(
-            "block",
-            SyntaxShape::Block,
-            "the block to run on each row",
-        )
+        Signature::build("each")
+            .required("block", SyntaxShape::Block, "the block to run on each row")
+            .switch(
+                "parallel",
+                "run the block on several rows at once, each in its own thread",
+                Some('p'),
+            )
+            .named(
+                "threads",
+                SyntaxShape::Int,
+                "the number of rows to run the block on at once (implies --parallel, default: the number of cores)",
+                Some('t'),
+            )
+            .switch(
+                "unordered",
+                "with --parallel, give each row's output as soon as it's ready instead of in the order of the rows",
+                Some('u'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -62,6 +81,11 @@ impl WholeStreamCommand for Each {
                     UntaggedValue::int(7).into(),
                 ]),
             },
+            Example {
+                description: "Hash the files four at a time",
+                example: "ls | each --threads 4 { ^sha256sum $it.name }",
+                result: None,
+            },
         ]
     }
 }
@@ -101,6 +125,33 @@ async fn process_row(
     .to_output_stream())
 }
 
+/// Runs the block on the row in a thread of its own, collecting the output.
+async fn process_row_in_thread(
+    block: Arc<Block>,
+    scope: Arc<Scope>,
+    head: Arc<Box<SpannedExpression>>,
+    context: Arc<Context>,
+    input: Value,
+) -> OutputStream {
+    let (sender, receiver) = oneshot::channel();
+
+    std::thread::spawn(move || {
+        let output = block_on(async {
+            match process_row(block, scope, head, context, input).await {
+                Ok(s) => s.collect::<Vec<_>>().await,
+                Err(e) => vec![Err(e)],
+            }
+        });
+
+        let _ = sender.send(output);
+    });
+
+    match receiver.await {
+        Ok(output) => futures::stream::iter(output).to_output_stream(),
+        Err(_) => OutputStream::empty(),
+    }
+}
+
 async fn each(
     raw_args: CommandArgs,
     registry: &CommandRegistry,
@@ -109,8 +160,47 @@ async fn each(
     let head = Arc::new(raw_args.call_info.args.head.clone());
     let scope = Arc::new(raw_args.call_info.scope.clone());
     let context = Arc::new(Context::from_raw(&raw_args, &registry));
-    let (each_args, input): (EachArgs, _) = raw_args.process(&registry).await?;
-    let block = Arc::new(each_args.block);
+    let (
+        EachArgs {
+            block,
+            parallel,
+            threads,
+            unordered,
+        },
+        input,
+    ): (EachArgs, _) = raw_args.process(&registry).await?;
+    let block = Arc::new(block);
+
+    if parallel || threads.is_some() {
+        let threads = match threads {
+            Some(threads) if *threads == 0 => {
+                return Err(ShellError::labeled_error(
+                    "Expected a positive number",
+                    "the number of threads can't be zero",
+                    threads.tag,
+                ))
+            }
+            Some(threads) => *threads,
+            None => num_cpus::get(),
+        };
+
+        let rows = input.map(move |input| {
+            process_row_in_thread(
+                block.clone(),
+                scope.clone(),
+                head.clone(),
+                context.clone(),
+                input,
+            )
+        });
+
+        return Ok(if unordered {
+            rows.buffer_unordered(threads).flatten().to_output_stream()
+        } else {
+            rows.buffered(threads).flatten().to_output_stream()
+        });
+    }
+
     Ok(input
         .then(move |input| {
             let block = block.clone();
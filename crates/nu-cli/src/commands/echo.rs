@@ -1,10 +1,8 @@
 use crate::commands::WholeStreamCommand;
+use crate::data::range::range_values;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::hir::Operator;
-use nu_protocol::{
-    Primitive, RangeInclusion, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
-};
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
 
 pub struct Echo;
 
@@ -55,55 +53,27 @@ async fn echo(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStr
     let registry = registry.clone();
     let (args, _): (EchoArgs, _) = args.process(&registry).await?;
 
-    let stream = args.rest.into_iter().map(|i| {
-        match i.as_string() {
-            Ok(s) => {
-                OutputStream::one(Ok(ReturnSuccess::Value(
-                    UntaggedValue::string(s).into_value(i.tag.clone()),
-                )))
-            }
-            _ => match i {
-                Value {
-                    value: UntaggedValue::Table(table),
-                    ..
-                } => {
-                    futures::stream::iter(table.into_iter().map(ReturnSuccess::value)).to_output_stream()
-                }
-                Value {
-                    value: UntaggedValue::Primitive(Primitive::Range(range)),
-                    tag
-                } => {
-                    let mut output_vec = vec![];
-
-                    let mut current = range.from.0.item;
-                    while current != range.to.0.item {
-                        output_vec.push(Ok(ReturnSuccess::Value(UntaggedValue::Primitive(current.clone()).into_value(&tag))));
-                        current = match crate::data::value::compute_values(Operator::Plus, &UntaggedValue::Primitive(current), &UntaggedValue::int(1)) {
-                            Ok(result) => match result {
-                                UntaggedValue::Primitive(p) => p,
-                                _ => {
-                                    return OutputStream::one(Err(ShellError::unimplemented("Internal error: expected a primitive result from increment")));
-                                }
-                            },
-                            Err((left_type, right_type)) => {
-                                return OutputStream::one(Err(ShellError::coerce_error(
-                                    left_type.spanned(tag.span),
-                                    right_type.spanned(tag.span),
-                                )));
-                            }
-                        }
-                    }
-                    if let RangeInclusion::Inclusive = range.to.1 {
-                            output_vec.push(Ok(ReturnSuccess::Value(UntaggedValue::Primitive(current).into_value(&tag))));
-                    }
-
-                    futures::stream::iter(output_vec.into_iter()).to_output_stream()
-                }
-                _ => {
-                    OutputStream::one(Ok(ReturnSuccess::Value(i.clone())))
+    let stream = args.rest.into_iter().map(|i| match i.as_string() {
+        Ok(s) => OutputStream::one(Ok(ReturnSuccess::Value(
+            UntaggedValue::string(s).into_value(i.tag.clone()),
+        ))),
+        _ => match i {
+            Value {
+                value: UntaggedValue::Table(table),
+                ..
+            } => futures::stream::iter(table.into_iter().map(ReturnSuccess::value))
+                .to_output_stream(),
+            Value {
+                value: UntaggedValue::Primitive(Primitive::Range(range)),
+                tag,
+            } => match range_values(&range, tag) {
+                Ok(values) => {
+                    futures::stream::iter(values.map(ReturnSuccess::value)).to_output_stream()
                 }
+                Err(err) => OutputStream::one(Err(err)),
             },
-        }
+            _ => OutputStream::one(Ok(ReturnSuccess::Value(i.clone()))),
+        },
     });
 
     Ok(futures::stream::iter(stream).flatten().to_output_stream())
@@ -2,6 +2,7 @@ use crate::commands::UnevaluatedCallInfo;
 use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
+use crate::shell::archive_shell;
 use nu_errors::ShellError;
 use nu_protocol::{
     CommandAction, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
@@ -74,6 +75,21 @@ documentation link at https://docs.rs/encoding_rs/0.8.23/encoding_rs/#statics"#
                 example: "enter file.csv --encoding iso-8859-1",
                 result: None,
             },
+            Example {
+                description: "Enter a directory inside a zip file, without extracting it",
+                example: "enter logs.zip/2020",
+                result: None,
+            },
+            Example {
+                description: "Enter a prefix of an S3 bucket, when built with the s3 feature",
+                example: "enter s3://my-bucket/logs",
+                result: None,
+            },
+            Example {
+                description: "Enter a directory on another machine, over SSH",
+                example: "enter ssh://andres@example.com/var/log",
+                result: None,
+            },
         ]
     }
 }
@@ -111,20 +127,41 @@ async fn enter(
         Ok(OutputStream::one(ReturnSuccess::action(
             CommandAction::EnterHelpShell(UntaggedValue::nothing().into_value(Tag::unknown())),
         )))
-    } else if location.is_dir() {
+    } else if location_string.starts_with("s3://") {
+        if cfg!(feature = "s3") {
+            Ok(OutputStream::one(ReturnSuccess::action(
+                CommandAction::EnterShell(location_clone),
+            )))
+        } else {
+            Err(ShellError::labeled_error(
+                "Cannot enter S3 locations",
+                "nu was built without the s3 feature",
+                &location.tag,
+            ))
+        }
+    } else if location_string.starts_with("ssh://")
+        || location_string.starts_with("sftp://")
+        || location.is_dir()
+    {
         Ok(OutputStream::one(ReturnSuccess::action(
             CommandAction::EnterShell(location_clone),
         )))
+    } else if let Some((archive, inner)) =
+        archive_shell::split_path(&PathBuf::from(shell_manager.path()).join(&location.item))
+            .filter(|(archive, inner)| archive_shell::is_dir(archive, inner))
+    {
+        Ok(OutputStream::one(ReturnSuccess::action(
+            CommandAction::EnterShell(archive_shell::full_path(&archive, &inner)),
+        )))
     } else {
         // If it's a file, attempt to open the file as a value and enter it
         let cwd = shell_manager.path();
 
         let full_path = std::path::PathBuf::from(cwd);
 
-        let (file_extension, contents, contents_tag) = crate::commands::open::fetch(
+        let (file_extension, contents, contents_tag) = crate::commands::open::fetch_from_shell(
             &full_path,
-            &PathBuf::from(location_clone),
-            tag.span,
+            &location,
             match encoding {
                 Some(e) => e.to_string(),
                 _ => "".to_string(),
@@ -151,6 +188,7 @@ async fn enter(
                                     named: None,
                                     span: Span::unknown(),
                                     is_last: false,
+                                    capture_stderr: false,
                                 },
                                 name_tag: tag.clone(),
                                 scope: scope.clone(),
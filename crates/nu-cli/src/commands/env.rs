@@ -0,0 +1,94 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue};
+
+pub struct Env;
+
+#[async_trait]
+impl WholeStreamCommand for Env {
+    fn name(&self) -> &str {
+        "env"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("env")
+    }
+
+    fn usage(&self) -> &str {
+        "Show the environment variables as a table, with the scope each comes from: the config file, the session, or the block being run (like with with-env)."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        env(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the environment variables",
+                example: "env",
+                result: None,
+            },
+            Example {
+                description: "Show the variables only set for a block",
+                example: "with-env [X 1] { env | where scope == block }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn env(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let context = Context::from_raw(&args, &registry);
+    let env = args.call_info.scope.env.clone();
+    let args = args.evaluate_once(&registry).await?;
+    let name = args.call_info.name_tag.clone();
+
+    let session = context.get_env();
+    let config = crate::data::config::read(&name, &None)?;
+    let configured = config.get("env").map(|env| env.row_entries()).into_iter();
+    let configured: IndexMap<String, String> = configured
+        .flatten()
+        .map(|(name, value)| (name.clone(), value.convert_to_string()))
+        .collect();
+
+    let mut variables: Vec<_> = env.into_iter().collect();
+    variables.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let rows = variables.into_iter().map(move |(variable, value)| {
+        let scope = if session.get(&variable) != Some(&value) {
+            "block"
+        } else if configured.get(&variable) == Some(&value) {
+            "config"
+        } else {
+            "session"
+        };
+
+        let mut dict = TaggedDictBuilder::new(&name);
+        dict.insert_untagged("name", UntaggedValue::string(variable));
+        dict.insert_untagged("value", UntaggedValue::string(value));
+        dict.insert_untagged("scope", UntaggedValue::string(scope));
+
+        ReturnSuccess::value(dict.into_value())
+    });
+
+    Ok(futures::stream::iter(rows).to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Env;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Env {})
+    }
+}
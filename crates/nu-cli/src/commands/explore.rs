@@ -0,0 +1,587 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value::format_leaf;
+use crate::prelude::*;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::style::{Attribute, Color, Print, SetAttribute, SetForegroundColor};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::tty::IsTty;
+use crossterm::{cursor, execute, queue};
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, UntaggedValue, Value};
+use std::collections::BTreeSet;
+use std::io::Write;
+
+/// Cells wider than this are cut short, so that a long one doesn't push the others off screen.
+const MAX_COLUMN_WIDTH: usize = 40;
+
+pub struct Explore;
+
+#[async_trait]
+impl WholeStreamCommand for Explore {
+    fn name(&self) -> &str {
+        "explore"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("explore")
+    }
+
+    fn usage(&self) -> &str {
+        "Scroll, search and expand the contents of the pipeline, giving back the rows selected."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        explore(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Look through the files, giving back the ones selected with space",
+                example: "ls | explore",
+                result: None,
+            },
+            Example {
+                description: "Remove the processes selected",
+                example: "ps | explore | get pid | each { kill $it }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn explore(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let args = args.evaluate_once(&registry).await?;
+    let name = args.call_info.name_tag.clone();
+    let (input, _args) = args.parts();
+
+    let values: Vec<Value> = input.collect().await;
+
+    // Without a terminal to explore them in, the values go on as they are
+    let values = if values.is_empty() || !std::io::stdout().is_tty() {
+        values
+    } else {
+        Explorer::new(values).run().map_err(|e| {
+            ShellError::labeled_error(
+                "Could not explore the values",
+                format!("could not use the terminal: {}", e),
+                &name,
+            )
+        })?
+    };
+
+    Ok(futures::stream::iter(values.into_iter().map(ReturnSuccess::value)).to_output_stream())
+}
+
+/// How a piece of the screen is drawn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Look {
+    Plain,
+    Header,
+    CursorRow,
+    CursorCell,
+    Selected,
+    Status,
+}
+
+type Line = Vec<(String, Look)>;
+
+/// A table being explored, with where the cursor is in it. Expanding a cell opens its value in
+/// another page, on top of this one.
+struct Page {
+    title: String,
+    values: Vec<Value>,
+    headers: Vec<String>,
+    cells: Vec<Vec<String>>,
+    widths: Vec<usize>,
+    row: usize,
+    column: usize,
+    top: usize,
+    left: usize,
+    selected: BTreeSet<usize>,
+}
+
+impl Page {
+    fn new(title: String, values: Vec<Value>) -> Page {
+        let mut headers = nu_protocol::merge_descriptors(&values);
+        if headers.is_empty() {
+            headers.push("".to_string());
+        }
+
+        let cells: Vec<Vec<String>> = values
+            .iter()
+            .map(|value| {
+                headers
+                    .iter()
+                    .map(|header| match cell(value, header) {
+                        Some(cell) => format_leaf(cell).plain_string(100_000),
+                        None => String::new(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let widths = headers
+            .iter()
+            .enumerate()
+            .map(|(column, header)| {
+                cells
+                    .iter()
+                    .map(|row| row[column].chars().count())
+                    .chain(std::iter::once(header.chars().count()))
+                    .max()
+                    .unwrap_or(0)
+                    .min(MAX_COLUMN_WIDTH)
+            })
+            .collect();
+
+        Page {
+            title,
+            values,
+            headers,
+            cells,
+            widths,
+            row: 0,
+            column: 0,
+            top: 0,
+            left: 0,
+            selected: BTreeSet::new(),
+        }
+    }
+
+    fn move_row(&mut self, by: isize) {
+        let last = self.values.len().saturating_sub(1) as isize;
+        self.row = (self.row as isize + by).max(0).min(last) as usize;
+    }
+
+    fn move_column(&mut self, by: isize) {
+        let last = self.headers.len().saturating_sub(1) as isize;
+        self.column = (self.column as isize + by).max(0).min(last) as usize;
+    }
+
+    /// The value under the cursor.
+    fn current(&self) -> Option<&Value> {
+        self.values
+            .get(self.row)
+            .and_then(|value| cell(value, &self.headers[self.column]))
+    }
+
+    fn selection(&self) -> Vec<Value> {
+        self.selected
+            .iter()
+            .map(|row| self.values[*row].clone())
+            .collect()
+    }
+
+    /// Scrolls so that the cursor is on screen, with `rows` rows and `width` columns of room.
+    fn scroll(&mut self, rows: usize, width: usize) {
+        if self.row < self.top {
+            self.top = self.row;
+        } else if self.row >= self.top + rows {
+            self.top = self.row + 1 - rows;
+        }
+
+        if self.column < self.left {
+            self.left = self.column;
+        }
+        let gutter = self.gutter_width();
+        while self.left < self.column
+            && gutter
+                + (self.left..=self.column)
+                    .map(|c| self.widths[c] + 3)
+                    .sum::<usize>()
+                > width
+        {
+            self.left += 1;
+        }
+    }
+
+    /// The room taken by the selection marker and the row numbers, before the cells.
+    fn gutter_width(&self) -> usize {
+        2 + self.values.len().saturating_sub(1).to_string().len() + 3
+    }
+
+    fn render(&self, rows: usize) -> Vec<Line> {
+        let number_width = self.gutter_width() - 5;
+        let columns = self.left..self.headers.len();
+
+        let mut header: Line = vec![(format!("  {:>1$} │ ", "#", number_width), Look::Header)];
+        for column in columns.clone() {
+            let look = if column == self.column {
+                Look::CursorCell
+            } else {
+                Look::Header
+            };
+            header.push((fit(&self.headers[column], self.widths[column]), look));
+            header.push((" │ ".to_string(), Look::Header));
+        }
+
+        let mut separator = "─".repeat(self.gutter_width() - 2);
+        separator.push('┼');
+        for column in columns.clone() {
+            separator.push_str(&"─".repeat(self.widths[column] + 2));
+            separator.push('┼');
+        }
+
+        let mut lines = vec![header, vec![(separator, Look::Plain)]];
+
+        for row in self.top..(self.top + rows).min(self.values.len()) {
+            let selected = self.selected.contains(&row);
+            let look = match (row == self.row, selected) {
+                (true, _) => Look::CursorRow,
+                (false, true) => Look::Selected,
+                (false, false) => Look::Plain,
+            };
+            let marker = if selected { "*" } else { " " };
+
+            let mut line: Line = vec![(format!("{} {:>2$} │ ", marker, row, number_width), look)];
+            for column in columns.clone() {
+                let look = if row == self.row && column == self.column {
+                    Look::CursorCell
+                } else {
+                    look
+                };
+                line.push((fit(&self.cells[row][column], self.widths[column]), look));
+                line.push((" │ ".to_string(), Look::Plain));
+            }
+            lines.push(line);
+        }
+
+        lines
+    }
+}
+
+/// The value of the cell of `value` in the column `header`, where the column without a name
+/// holds the values that aren't rows.
+fn cell<'a>(value: &'a Value, header: &str) -> Option<&'a Value> {
+    match &value.value {
+        UntaggedValue::Row(row) => row.entries.get(header),
+        _ if header.is_empty() => Some(value),
+        _ => None,
+    }
+}
+
+/// The text padded or cut short to `width` characters.
+fn fit(text: &str, width: usize) -> String {
+    let text = text.replace('\n', " ");
+    if text.chars().count() > width {
+        let mut cut: String = text.chars().take(width.saturating_sub(1)).collect();
+        cut.push('…');
+        cut
+    } else {
+        format!("{:1$}", text, width)
+    }
+}
+
+/// The pages being explored, the last one shown, and the search being typed or last made.
+struct Explorer {
+    pages: Vec<Page>,
+    typing: Option<String>,
+    search: Option<String>,
+    message: Option<String>,
+}
+
+impl Explorer {
+    fn new(values: Vec<Value>) -> Explorer {
+        Explorer {
+            pages: vec![Page::new("explore".to_string(), values)],
+            typing: None,
+            search: None,
+            message: None,
+        }
+    }
+
+    fn page(&self) -> &Page {
+        &self.pages[self.pages.len() - 1]
+    }
+
+    fn page_mut(&mut self) -> &mut Page {
+        let last = self.pages.len() - 1;
+        &mut self.pages[last]
+    }
+
+    /// Explores the values on the whole terminal, until quitting gives back the rows selected.
+    fn run(mut self) -> crossterm::Result<Vec<Value>> {
+        let mut stdout = std::io::stdout();
+
+        terminal::enable_raw_mode()?;
+        execute!(stdout, EnterAlternateScreen, cursor::Hide)?;
+
+        let selection = self.interact(&mut stdout);
+
+        execute!(stdout, cursor::Show, LeaveAlternateScreen)?;
+        terminal::disable_raw_mode()?;
+
+        selection
+    }
+
+    fn interact(&mut self, stdout: &mut std::io::Stdout) -> crossterm::Result<Vec<Value>> {
+        loop {
+            let (width, height) = terminal::size()?;
+            let (width, height) = (width as usize, height as usize);
+            let rows = height.saturating_sub(3).max(1);
+
+            self.page_mut().scroll(rows, width);
+
+            queue!(stdout, Clear(ClearType::All))?;
+            for (y, line) in self.page().render(rows).into_iter().enumerate() {
+                queue!(stdout, cursor::MoveTo(0, y as u16))?;
+                draw(stdout, line, width)?;
+            }
+            queue!(stdout, cursor::MoveTo(0, height.saturating_sub(1) as u16))?;
+            draw(stdout, self.status(), width)?;
+            stdout.flush()?;
+
+            if let Event::Key(key) = event::read()? {
+                if let Some(selection) = self.press(key, rows) {
+                    return Ok(selection);
+                }
+            }
+        }
+    }
+
+    /// Acts on a key, giving back the rows to leave with once it quits.
+    fn press(&mut self, key: KeyEvent, rows: usize) -> Option<Vec<Value>> {
+        self.message = None;
+
+        if let Some(typing) = &mut self.typing {
+            match key.code {
+                KeyCode::Char(c) => typing.push(c),
+                KeyCode::Backspace => {
+                    typing.pop();
+                }
+                KeyCode::Enter => {
+                    self.search = self.typing.take().filter(|search| !search.is_empty());
+                    self.find(true);
+                }
+                KeyCode::Esc => self.typing = None,
+                _ => {}
+            }
+            return None;
+        }
+
+        let page = self.page_mut();
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Some(vec![])
+            }
+            KeyCode::Char('q') => return Some(page.selection()),
+            KeyCode::Up | KeyCode::Char('k') => page.move_row(-1),
+            KeyCode::Down | KeyCode::Char('j') => page.move_row(1),
+            KeyCode::PageUp => page.move_row(-(rows as isize)),
+            KeyCode::PageDown => page.move_row(rows as isize),
+            KeyCode::Home | KeyCode::Char('g') => page.row = 0,
+            KeyCode::End | KeyCode::Char('G') => page.move_row(page.values.len() as isize),
+            KeyCode::Left | KeyCode::Char('h') => page.move_column(-1),
+            KeyCode::Right | KeyCode::Char('l') => page.move_column(1),
+            KeyCode::Char(' ') => {
+                let row = page.row;
+                if !page.selected.remove(&row) {
+                    page.selected.insert(row);
+                }
+                page.move_row(1);
+            }
+            KeyCode::Enter => self.expand(),
+            KeyCode::Esc | KeyCode::Backspace if self.pages.len() > 1 => {
+                self.pages.pop();
+            }
+            KeyCode::Char('/') => self.typing = Some(String::new()),
+            KeyCode::Char('n') => self.find(true),
+            KeyCode::Char('N') => self.find(false),
+            _ => {}
+        }
+
+        None
+    }
+
+    /// Opens the table or row under the cursor in a page of its own.
+    fn expand(&mut self) {
+        let page = self.page();
+        let values = match page.current() {
+            Some(Value {
+                value: UntaggedValue::Table(values),
+                ..
+            }) => values.clone(),
+            Some(
+                value @ Value {
+                    value: UntaggedValue::Row(_),
+                    ..
+                },
+            ) if !page.headers[page.column].is_empty() => vec![value.clone()],
+            _ => {
+                self.message = Some("only tables and rows in a cell can be expanded".to_string());
+                return;
+            }
+        };
+
+        let mut title = format!("{} › {}", page.title, page.row);
+        if !page.headers[page.column].is_empty() {
+            title.push_str(&format!(" › {}", page.headers[page.column]));
+        }
+
+        self.pages.push(Page::new(title, values));
+    }
+
+    /// Moves the cursor to the next row, or the previous one, with a cell that has the search.
+    fn find(&mut self, forward: bool) {
+        let search = match &self.search {
+            Some(search) => search.to_lowercase(),
+            None => return,
+        };
+
+        let page = self.page_mut();
+        let count = page.values.len();
+        let found = (1..=count)
+            .map(|offset| {
+                if forward {
+                    (page.row + offset) % count
+                } else {
+                    (page.row + count * offset - offset) % count
+                }
+            })
+            .find_map(|row| {
+                page.cells[row]
+                    .iter()
+                    .position(|cell| cell.to_lowercase().contains(&search))
+                    .map(|column| (row, column))
+            });
+
+        match found {
+            Some((row, column)) => {
+                page.row = row;
+                page.column = column;
+            }
+            None => self.message = Some(format!("no row has \"{}\"", search)),
+        }
+    }
+
+    fn status(&self) -> Line {
+        let page = self.page();
+
+        let text = if let Some(typing) = &self.typing {
+            format!("/{}", typing)
+        } else if let Some(message) = &self.message {
+            message.clone()
+        } else {
+            format!(
+                "{} │ row {} of {} │ {} selected │ arrows move, enter expands, esc goes back, space selects, / searches, q quits",
+                page.title,
+                page.row + 1,
+                page.values.len(),
+                page.selected.len()
+            )
+        };
+
+        vec![(text, Look::Status)]
+    }
+}
+
+/// Draws a line from where the cursor is, cut short at the end of the terminal.
+fn draw(stdout: &mut std::io::Stdout, line: Line, width: usize) -> crossterm::Result<()> {
+    let mut room = width;
+
+    for (text, look) in line {
+        let text: String = text.chars().take(room).collect();
+        room -= text.chars().count();
+
+        match look {
+            Look::Plain => {}
+            Look::Header => queue!(stdout, SetForegroundColor(Color::Green))?,
+            Look::CursorRow => queue!(stdout, SetAttribute(Attribute::Bold))?,
+            Look::CursorCell => queue!(stdout, SetAttribute(Attribute::Reverse))?,
+            Look::Selected => queue!(stdout, SetForegroundColor(Color::Cyan))?,
+            Look::Status => queue!(stdout, SetAttribute(Attribute::Reverse))?,
+        }
+        queue!(stdout, Print(text), SetAttribute(Attribute::Reset))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Explore, Explorer};
+    use crossterm::event::{KeyCode, KeyEvent};
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::Tag;
+
+    fn row(name: &str, size: i64) -> Value {
+        let mut dict = nu_protocol::TaggedDictBuilder::new(Tag::unknown());
+        dict.insert_untagged("name", UntaggedValue::string(name));
+        dict.insert_untagged("size", UntaggedValue::int(size));
+        dict.into_value()
+    }
+
+    fn press(explorer: &mut Explorer, keys: &[KeyCode]) -> Option<Vec<Value>> {
+        keys.iter()
+            .map(|key| explorer.press(KeyEvent::from(*key), 10))
+            .last()
+            .unwrap_or(None)
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Explore {})
+    }
+
+    #[test]
+    fn gives_back_the_rows_selected() {
+        let mut explorer = Explorer::new(vec![row("a", 1), row("b", 2), row("c", 3)]);
+
+        let selection = press(
+            &mut explorer,
+            &[
+                KeyCode::Char(' '),
+                KeyCode::Down,
+                KeyCode::Char(' '),
+                KeyCode::Char('q'),
+            ],
+        );
+
+        assert_eq!(selection, Some(vec![row("a", 1), row("c", 3)]));
+    }
+
+    #[test]
+    fn searches_the_cells() {
+        let mut explorer = Explorer::new(vec![row("apple", 1), row("pear", 2), row("plum", 3)]);
+
+        press(
+            &mut explorer,
+            &[
+                KeyCode::Char('/'),
+                KeyCode::Char('P'),
+                KeyCode::Char('l'),
+                KeyCode::Enter,
+            ],
+        );
+        assert_eq!(explorer.page().row, 2);
+
+        press(&mut explorer, &[KeyCode::Char('n')]);
+        assert_eq!(explorer.page().row, 0);
+    }
+
+    #[test]
+    fn expands_nested_tables_and_goes_back() {
+        let nested = UntaggedValue::table(&[row("inner", 7)]).into_untagged_value();
+        let mut dict = nu_protocol::TaggedDictBuilder::new(Tag::unknown());
+        dict.insert_value("files", nested);
+        let mut explorer = Explorer::new(vec![dict.into_value()]);
+
+        press(&mut explorer, &[KeyCode::Enter]);
+        assert_eq!(explorer.page().values, vec![row("inner", 7)]);
+        assert_eq!(explorer.page().title, "explore › 0 › files");
+
+        press(&mut explorer, &[KeyCode::Esc]);
+        assert_eq!(explorer.pages.len(), 1);
+    }
+}
@@ -0,0 +1,197 @@
+use crate::commands::group_by::suggestions;
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use nu_value_ext::as_string;
+use regex::{Regex, RegexBuilder};
+
+pub struct Find;
+
+#[derive(Deserialize)]
+pub struct FindArgs {
+    rest: Vec<Tagged<String>>,
+    columns: Option<Value>,
+    invert: bool,
+    regex: bool,
+    insensitive: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Find {
+    fn name(&self) -> &str {
+        "find"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("find")
+            .rest(SyntaxShape::String, "the text(s) to search for")
+            .named(
+                "columns",
+                SyntaxShape::Any,
+                "the column (or table of columns) to search in (default: every column)",
+                Some('c'),
+            )
+            .switch(
+                "invert",
+                "keep the rows that don't match instead",
+                Some('v'),
+            )
+            .switch(
+                "regex",
+                "treat the search terms as regular expressions",
+                Some('r'),
+            )
+            .switch("insensitive", "ignore case when matching", Some('i'))
+    }
+
+    fn usage(&self) -> &str {
+        "Keep the rows where any cell contains one of the given search terms."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        find(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Find the words containing an 'a'",
+                example: "echo [cat dog bat] | find a",
+                result: Some(vec![Value::from("cat"), Value::from("bat")]),
+            },
+            Example {
+                description: "Find the words not ending with a 't'",
+                example: "echo [cat dog bat] | find --invert --regex 't$'",
+                result: Some(vec![Value::from("dog")]),
+            },
+            Example {
+                description: "Find the files mentioning 'toml' in their name",
+                example: "ls | find --columns name toml",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn find(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (
+        FindArgs {
+            rest: terms,
+            columns,
+            invert,
+            regex,
+            insensitive,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    if terms.is_empty() {
+        return Err(ShellError::labeled_error(
+            "Expected search terms",
+            "requires at least one text to find",
+            name,
+        ));
+    }
+
+    let patterns = terms
+        .iter()
+        .map(|term| {
+            let pattern = if regex {
+                term.item.clone()
+            } else {
+                regex::escape(&term.item)
+            };
+
+            RegexBuilder::new(&pattern)
+                .case_insensitive(insensitive)
+                .build()
+                .map_err(|err| {
+                    ShellError::labeled_error(
+                        "Invalid regular expression",
+                        err.to_string(),
+                        &term.tag,
+                    )
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let columns: Vec<Tagged<String>> = match columns {
+        None => vec![],
+        Some(Value {
+            value: UntaggedValue::Table(columns),
+            ..
+        }) => columns
+            .iter()
+            .map(|column| Ok(as_string(column)?.tagged(&column.tag)))
+            .collect::<Result<Vec<_>, ShellError>>()?,
+        Some(column) => vec![as_string(&column)?.tagged(&column.tag)],
+    };
+
+    Ok(input
+        .filter_map(move |item| {
+            let result = match row_matches(&item, &columns, &patterns) {
+                Ok(found) if found != invert => Some(ReturnSuccess::value(item)),
+                Ok(_) => None,
+                Err(err) => Some(Err(err)),
+            };
+
+            futures::future::ready(result)
+        })
+        .to_output_stream())
+}
+
+fn row_matches(
+    row: &Value,
+    columns: &[Tagged<String>],
+    patterns: &[Regex],
+) -> Result<bool, ShellError> {
+    if columns.is_empty() {
+        return Ok(cell_matches(row, patterns));
+    }
+
+    for column in columns {
+        match row.get_data_by_key(column.borrow_spanned()) {
+            Some(cell) if cell_matches(&cell, patterns) => return Ok(true),
+            Some(_) => {}
+            None => return Err(suggestions(column.borrow_tagged(), row)),
+        }
+    }
+
+    Ok(false)
+}
+
+/// Whether any of the patterns is found in the cell, searching nested rows and tables too.
+fn cell_matches(cell: &Value, patterns: &[Regex]) -> bool {
+    match &cell.value {
+        UntaggedValue::Row(dict) => dict
+            .entries
+            .values()
+            .any(|value| cell_matches(value, patterns)),
+        UntaggedValue::Table(values) => values.iter().any(|value| cell_matches(value, patterns)),
+        _ => match as_string(cell) {
+            Ok(text) => patterns.iter().any(|pattern| pattern.is_match(&text)),
+            Err(_) => false,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Find;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Find {})
+    }
+}
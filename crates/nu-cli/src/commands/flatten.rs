@@ -0,0 +1,270 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct Flatten;
+
+#[derive(Deserialize)]
+pub struct FlattenArgs {
+    rest: Vec<Tagged<String>>,
+    depth: Option<Tagged<usize>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Flatten {
+    fn name(&self) -> &str {
+        "flatten"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("flatten")
+            .rest(
+                SyntaxShape::String,
+                "the column(s) to flatten (default: all of them)",
+            )
+            .named(
+                "depth",
+                SyntaxShape::Int,
+                "the number of nesting levels to flatten (default: all of them)",
+                Some('d'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Expands nested rows into dotted columns and lists in cells into one row per item."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        flatten(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Flatten a list of lists",
+                example: "echo [[1 2] [3]] | flatten",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                ]),
+            },
+            Example {
+                description: "Turn nested fields of a JSON document into columns",
+                example: r#"echo '{"name": "nu", "meta": {"stars": 9000}}' | from json | flatten"#,
+                result: None,
+            },
+            Example {
+                description: "Get a row per tag, only expanding the tags column",
+                example: "open posts.json | flatten tags",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn flatten(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let (
+        FlattenArgs {
+            rest: columns,
+            depth,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    let columns: Vec<String> = columns.into_iter().map(|c| c.item).collect();
+    let depth = depth.map(|d| *d);
+
+    Ok(input
+        .map(move |item| {
+            futures::stream::iter(
+                flatten_value(item, &columns, depth)
+                    .into_iter()
+                    .map(ReturnSuccess::value),
+            )
+        })
+        .flatten()
+        .to_output_stream())
+}
+
+/// The cells of one output row, as (dotted column name, value) pairs.
+type Cells = Vec<(String, Value)>;
+
+fn flatten_value(value: Value, columns: &[String], depth: Option<usize>) -> Vec<Value> {
+    if depth == Some(0) {
+        return vec![value];
+    }
+
+    let tag = value.tag;
+
+    match value.value {
+        UntaggedValue::Table(items) => {
+            let depth = depth.map(|d| d - 1);
+            items
+                .into_iter()
+                .flat_map(|item| flatten_value(item, columns, depth))
+                .collect()
+        }
+        UntaggedValue::Row(dict) => {
+            let mut rows: Vec<Cells> = vec![vec![]];
+
+            for (column, cell) in dict.entries {
+                let expanded = if columns.is_empty() || columns.contains(&column) {
+                    expand(column, cell, depth)
+                } else {
+                    vec![vec![(column, cell)]]
+                };
+
+                rows = cross(rows, &expanded);
+            }
+
+            rows.into_iter()
+                .map(|cells| {
+                    let mut row = TaggedDictBuilder::new(&tag);
+                    for (column, cell) in cells {
+                        row.insert_value(column, cell);
+                    }
+                    row.into_value()
+                })
+                .collect()
+        }
+        other => vec![other.into_value(tag)],
+    }
+}
+
+/// Expands a cell into the alternative sets of cells it flattens to: a nested row gives one set
+/// of dotted columns, a list gives one set per item.
+fn expand(column: String, cell: Value, depth: Option<usize>) -> Vec<Cells> {
+    if depth == Some(0) {
+        return vec![vec![(column, cell)]];
+    }
+
+    let depth = depth.map(|d| d - 1);
+
+    match cell.value {
+        UntaggedValue::Row(ref dict) if !dict.entries.is_empty() => {
+            let mut alternatives: Vec<Cells> = vec![vec![]];
+
+            for (key, nested) in dict.entries.iter() {
+                let expanded = expand(format!("{}.{}", column, key), nested.clone(), depth);
+                alternatives = cross(alternatives, &expanded);
+            }
+
+            alternatives
+        }
+        UntaggedValue::Table(items) => {
+            if items.is_empty() {
+                return vec![vec![(
+                    column,
+                    UntaggedValue::nothing().into_value(cell.tag),
+                )]];
+            }
+
+            items
+                .into_iter()
+                .flat_map(|item| expand(column.clone(), item, depth))
+                .collect()
+        }
+        _ => vec![vec![(column, cell)]],
+    }
+}
+
+fn cross(rows: Vec<Cells>, alternatives: &[Cells]) -> Vec<Cells> {
+    rows.into_iter()
+        .flat_map(|row| {
+            alternatives.iter().map(move |cells| {
+                let mut row = row.clone();
+                row.extend(cells.iter().cloned());
+                row
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{flatten_value, Flatten};
+    use indexmap::IndexMap;
+    use nu_protocol::{UntaggedValue, Value};
+
+    fn string(input: impl Into<String>) -> Value {
+        UntaggedValue::string(input.into()).into_untagged_value()
+    }
+
+    fn int(n: i64) -> Value {
+        UntaggedValue::int(n).into_untagged_value()
+    }
+
+    fn row(entries: IndexMap<String, Value>) -> Value {
+        UntaggedValue::row(entries).into_untagged_value()
+    }
+
+    fn table(list: &[Value]) -> Value {
+        UntaggedValue::table(list).into_untagged_value()
+    }
+
+    fn post() -> Value {
+        row(indexmap! {
+            "title".into() => string("nu"),
+            "meta".into() => row(indexmap! {
+                "stars".into() => int(9),
+                "author".into() => row(indexmap! { "name".into() => string("jt") }),
+            }),
+            "tags".into() => table(&[string("shell"), string("rust")]),
+        })
+    }
+
+    #[test]
+    fn flattens_nested_rows_and_explodes_lists() {
+        assert_eq!(
+            flatten_value(post(), &[], None),
+            vec![
+                row(indexmap! {
+                    "title".into() => string("nu"),
+                    "meta.stars".into() => int(9),
+                    "meta.author.name".into() => string("jt"),
+                    "tags".into() => string("shell"),
+                }),
+                row(indexmap! {
+                    "title".into() => string("nu"),
+                    "meta.stars".into() => int(9),
+                    "meta.author.name".into() => string("jt"),
+                    "tags".into() => string("rust"),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn stops_at_the_given_depth() {
+        let flattened = flatten_value(post(), &["meta".to_string()], Some(1));
+
+        assert_eq!(
+            flattened,
+            vec![row(indexmap! {
+                "title".into() => string("nu"),
+                "meta.stars".into() => int(9),
+                "meta.author".into() => row(indexmap! { "name".into() => string("jt") }),
+                "tags".into() => table(&[string("shell"), string("rust")]),
+            })]
+        );
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Flatten {})
+    }
+}
@@ -0,0 +1,148 @@
+use crate::commands::loop_::{output, Body};
+use crate::commands::WholeStreamCommand;
+use crate::data::range::range_values;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{hir::Block, Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct For;
+
+#[derive(Deserialize)]
+pub struct ForArgs {
+    name: Tagged<String>,
+    keyword: Tagged<String>,
+    values: Value,
+    block: Block,
+}
+
+#[async_trait]
+impl WholeStreamCommand for For {
+    fn name(&self) -> &str {
+        "for"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("for")
+            .required("name", SyntaxShape::String, "the name of the variable")
+            .required("in", SyntaxShape::String, "the word in")
+            .required(
+                "values",
+                SyntaxShape::Any,
+                "the values to loop over, like a range, a list or a variable",
+            )
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the block to run with each value",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Run a block once for each value, with the value in a variable. The output of each pass is given in turn."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        for_(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Echo the square of each number of a range",
+                example: "for x in 1..3 { echo $(= $x * $x) }",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(4).into(),
+                    UntaggedValue::int(9).into(),
+                ]),
+            },
+            Example {
+                description: "Loop over the rows of a table",
+                example: "for file in $(ls) { echo $file.name }",
+                result: None,
+            },
+            Example {
+                description: "Open the JSON files, skipping the ones that can't be read",
+                example: "for file in $(ls *.json) { try { open $file.name } catch { continue } }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn for_(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let context = Context::from_args(&args, &registry);
+    let scope = args.call_info.scope.clone();
+
+    let (
+        ForArgs {
+            name,
+            keyword,
+            values,
+            block,
+        },
+        _,
+    ) = args.process(&registry).await?;
+
+    if keyword.item != "in" {
+        return Err(ShellError::labeled_error(
+            "Expected in",
+            "expected the word in, then the values to loop over",
+            keyword.tag,
+        ));
+    }
+
+    // The values of a range are counted as the loop goes, so it can be as long as it takes
+    let values: Box<dyn Iterator<Item = Value> + Send> = match values {
+        Value {
+            value: UntaggedValue::Table(rows),
+            ..
+        } => Box::new(rows.into_iter()),
+        Value {
+            value: UntaggedValue::Primitive(Primitive::Range(range)),
+            tag,
+        } => Box::new(range_values(&range, tag)?),
+        Value {
+            value: UntaggedValue::Primitive(Primitive::Nothing),
+            ..
+        } => Box::new(std::iter::empty()),
+        value => Box::new(std::iter::once(value)),
+    };
+
+    let variable = format!("${}", name.item);
+    let body = Body::new(block, context, scope);
+    let passes = futures::stream::unfold(Some((body, values)), move |state| {
+        let variable = variable.clone();
+
+        async move {
+            let (body, mut values) = state?;
+            let value = values.next()?;
+
+            let mut vars = IndexMap::new();
+            vars.insert(variable, value);
+
+            let (output, body) = body.pass(vars).await;
+            Some((output, body.map(|body| (body, values))))
+        }
+    });
+
+    Ok(output(passes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::For;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(For {})
+    }
+}
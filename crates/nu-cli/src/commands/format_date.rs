@@ -0,0 +1,540 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Datelike, Local, TimeZone};
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Dictionary, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape,
+    UntaggedValue, Value,
+};
+use nu_source::Tagged;
+use nu_value_ext::ValueExt;
+use std::fmt::Display;
+
+pub struct FormatDate;
+
+pub struct DateFormat;
+
+#[derive(Deserialize)]
+pub struct FormatDateArgs {
+    pattern: Tagged<String>,
+    rest: Vec<ColumnPath>,
+    locale: Option<Tagged<String>>,
+    utc: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for FormatDate {
+    fn name(&self) -> &str {
+        "format date"
+    }
+
+    fn signature(&self) -> Signature {
+        signature("format date")
+    }
+
+    fn usage(&self) -> &str {
+        "Formats dates as text using strftime patterns."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        format_date(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Format a date as year, month and day",
+                example:
+                    "echo '2020-06-01 14:30:00' | into date | format date '%Y-%m-%d %H:%M' --utc",
+                result: None,
+            },
+            Example {
+                description: "Write the names of the month and the day in German",
+                example:
+                    "echo '2020-06-01' | into date | format date '%A, %e. %B %Y' --locale de --utc",
+                result: None,
+            },
+            Example {
+                description: "Format the modification dates of the files",
+                example: "ls | format date '%d/%m/%Y' modified",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[async_trait]
+impl WholeStreamCommand for DateFormat {
+    fn name(&self) -> &str {
+        "date format"
+    }
+
+    fn signature(&self) -> Signature {
+        signature("date format")
+    }
+
+    fn usage(&self) -> &str {
+        "Formats dates as text using strftime patterns (same as `format date`)."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        format_date(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Format the current date",
+            example: "date | into date | date format '%Y-%m-%d'",
+            result: None,
+        }]
+    }
+}
+
+fn signature(name: &str) -> Signature {
+    Signature::build(name)
+        .required(
+            "pattern",
+            SyntaxShape::String,
+            "the strftime pattern, like '%Y-%m-%d %H:%M'",
+        )
+        .rest(
+            SyntaxShape::ColumnPath,
+            "optionally format the cells at the given column paths (default: every date)",
+        )
+        .named(
+            "locale",
+            SyntaxShape::String,
+            "the language of the month and day names, like de or fr (default: en)",
+            Some('l'),
+        )
+        .switch(
+            "utc",
+            "use universal time (UTC) instead of the local time",
+            Some('u'),
+        )
+}
+
+/// How to format dates.
+#[derive(Clone)]
+struct Format {
+    pattern: String,
+    names: &'static Names,
+    utc: bool,
+}
+
+/// The names of the months and the days of the week in a language.
+struct Names {
+    language: &'static str,
+    months: [&'static str; 12],
+    short_months: [&'static str; 12],
+    days: [&'static str; 7],
+    short_days: [&'static str; 7],
+}
+
+const NAMES: &[Names] = &[
+    Names {
+        language: "en",
+        months: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        short_months: [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ],
+        days: [
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+            "Sunday",
+        ],
+        short_days: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+    },
+    Names {
+        language: "de",
+        months: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        short_months: [
+            "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+        ],
+        days: [
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+            "Sonntag",
+        ],
+        short_days: ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+    },
+    Names {
+        language: "es",
+        months: [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        short_months: [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sep", "oct", "nov", "dic",
+        ],
+        days: [
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+            "domingo",
+        ],
+        short_days: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+    },
+    Names {
+        language: "fr",
+        months: [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        short_months: [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+        days: [
+            "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+        ],
+        short_days: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+    },
+    Names {
+        language: "it",
+        months: [
+            "gennaio",
+            "febbraio",
+            "marzo",
+            "aprile",
+            "maggio",
+            "giugno",
+            "luglio",
+            "agosto",
+            "settembre",
+            "ottobre",
+            "novembre",
+            "dicembre",
+        ],
+        short_months: [
+            "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+        ],
+        days: [
+            "lunedì",
+            "martedì",
+            "mercoledì",
+            "giovedì",
+            "venerdì",
+            "sabato",
+            "domenica",
+        ],
+        short_days: ["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+    },
+    Names {
+        language: "nl",
+        months: [
+            "januari",
+            "februari",
+            "maart",
+            "april",
+            "mei",
+            "juni",
+            "juli",
+            "augustus",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        short_months: [
+            "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+        ],
+        days: [
+            "maandag",
+            "dinsdag",
+            "woensdag",
+            "donderdag",
+            "vrijdag",
+            "zaterdag",
+            "zondag",
+        ],
+        short_days: ["ma", "di", "wo", "do", "vr", "za", "zo"],
+    },
+    Names {
+        language: "pt",
+        months: [
+            "janeiro",
+            "fevereiro",
+            "março",
+            "abril",
+            "maio",
+            "junho",
+            "julho",
+            "agosto",
+            "setembro",
+            "outubro",
+            "novembro",
+            "dezembro",
+        ],
+        short_months: [
+            "jan", "fev", "mar", "abr", "mai", "jun", "jul", "ago", "set", "out", "nov", "dez",
+        ],
+        days: [
+            "segunda-feira",
+            "terça-feira",
+            "quarta-feira",
+            "quinta-feira",
+            "sexta-feira",
+            "sábado",
+            "domingo",
+        ],
+        short_days: ["seg", "ter", "qua", "qui", "sex", "sáb", "dom"],
+    },
+];
+
+/// Finds the names for a locale like `de`, `de_AT` or `de-AT`.
+fn names_for(locale: &str) -> Option<&'static Names> {
+    let language = locale
+        .split(&['_', '-', '.'][..])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+
+    NAMES.iter().find(|names| names.language == language)
+}
+
+async fn format_date(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let (
+        FormatDateArgs {
+            pattern,
+            rest: column_paths,
+            locale,
+            utc,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    let names = match locale {
+        Some(locale) => names_for(&locale.item).ok_or_else(|| {
+            ShellError::labeled_error(
+                "Unknown locale",
+                "expected one of de, en, es, fr, it, nl or pt",
+                &locale.tag,
+            )
+        })?,
+        None => &NAMES[0],
+    };
+
+    if StrftimeItems::new(&pattern.item).any(|item| matches!(item, Item::Error)) {
+        return Err(ShellError::labeled_error(
+            "Invalid date pattern",
+            "expected a strftime pattern, like '%Y-%m-%d %H:%M'",
+            &pattern.tag,
+        ));
+    }
+
+    let format = Format {
+        pattern: pattern.item,
+        names,
+        utc,
+    };
+
+    Ok(input
+        .map(move |value| match &value.value {
+            UntaggedValue::Row(row) if column_paths.is_empty() => {
+                let mut entries = IndexMap::new();
+                for (column, cell) in &row.entries {
+                    let cell = match &cell.value {
+                        UntaggedValue::Primitive(Primitive::Date(_)) => action(cell, &format)?,
+                        _ => cell.clone(),
+                    };
+                    entries.insert(column.clone(), cell);
+                }
+
+                ReturnSuccess::value(
+                    UntaggedValue::Row(Dictionary { entries }).into_value(&value.tag),
+                )
+            }
+            _ if column_paths.is_empty() => ReturnSuccess::value(action(&value, &format)?),
+            _ => {
+                let mut ret = value;
+
+                for path in &column_paths {
+                    let format = format.clone();
+                    ret = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, &format)),
+                    )?;
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn action(value: &Value, format: &Format) -> Result<Value, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Date(date)) => {
+            let text = if format.utc {
+                render(date, format)
+            } else {
+                render(&date.with_timezone(&Local), format)
+            };
+
+            Ok(UntaggedValue::string(text).into_value(&value.tag))
+        }
+        other => Err(ShellError::labeled_error(
+            "Expected a date",
+            format!("found {}", other.type_name()),
+            &value.tag,
+        )),
+    }
+}
+
+/// Renders the date, writing the names of the months and days (`%B`, `%b`,
+/// `%h`, `%A` and `%a`) in the language of the format.
+fn render<T: TimeZone>(date: &DateTime<T>, format: &Format) -> String
+where
+    T::Offset: Display,
+{
+    let month = date.month0() as usize;
+    let day = date.weekday().num_days_from_monday() as usize;
+
+    let mut pattern = String::with_capacity(format.pattern.len());
+    let mut chars = format.pattern.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            pattern.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('B') => pattern.push_str(format.names.months[month]),
+            Some('b') | Some('h') => pattern.push_str(format.names.short_months[month]),
+            Some('A') => pattern.push_str(format.names.days[day]),
+            Some('a') => pattern.push_str(format.names.short_days[day]),
+            Some(other) => {
+                pattern.push('%');
+                pattern.push(other);
+            }
+            None => pattern.push('%'),
+        }
+    }
+
+    date.format(&pattern).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{names_for, render, DateFormat, Format, FormatDate, NAMES};
+    use chrono::{TimeZone, Utc};
+
+    fn format(pattern: &str, locale: &str) -> Format {
+        Format {
+            pattern: pattern.to_string(),
+            names: names_for(locale).expect("locale not found"),
+            utc: true,
+        }
+    }
+
+    #[test]
+    fn renders_strftime_patterns() {
+        let date = Utc.ymd(2020, 2, 29).and_hms(18, 5, 9);
+
+        assert_eq!(
+            render(&date, &format("%Y-%m-%d %H:%M:%S", "en")),
+            "2020-02-29 18:05:09"
+        );
+        assert_eq!(
+            render(&date, &format("%a %d %b, %B %A (100%%)", "en")),
+            "Sat 29 Feb, February Saturday (100%)"
+        );
+    }
+
+    #[test]
+    fn writes_names_in_the_language_of_the_locale() {
+        let date = Utc.ymd(2020, 3, 4).and_hms(0, 0, 0);
+
+        assert_eq!(
+            render(&date, &format("%A %e %B (%a, %b)", "fr_FR")),
+            "mercredi  4 mars (mer., mars)"
+        );
+        assert_eq!(
+            render(&date, &format("%A, %d. %B", "de-AT")),
+            "Mittwoch, 04. März"
+        );
+        assert!(names_for("tlh").is_none());
+        assert_eq!(names_for("EN").map(|n| n.language), Some(NAMES[0].language));
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(FormatDate {});
+        test_examples(DateFormat {})
+    }
+}
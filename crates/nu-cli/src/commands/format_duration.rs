@@ -0,0 +1,203 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{
+    format_duration, ColumnPath, Dictionary, Primitive, ReturnSuccess, ShellTypeName, Signature,
+    SyntaxShape, UntaggedValue, Value,
+};
+use nu_value_ext::ValueExt;
+
+pub struct FormatDuration;
+
+#[derive(Deserialize)]
+pub struct FormatDurationArgs {
+    rest: Vec<ColumnPath>,
+    humanize: bool,
+    ago: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for FormatDuration {
+    fn name(&self) -> &str {
+        "format duration"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("format duration")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally format the cells at the given column paths (default: every duration)",
+            )
+            .switch(
+                "humanize",
+                "write the duration in its largest unit, like 2 hours",
+                None,
+            )
+            .switch(
+                "ago",
+                "write the duration as a time in the past, like 2 hours ago",
+                Some('a'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Formats durations as text, as a clock time or in words."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        format_duration_command(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Format a duration as a clock time",
+                example: "echo 5400sec | format duration",
+                result: Some(vec![Value::from("1:30:00")]),
+            },
+            Example {
+                description: "Format a duration in words",
+                example: "echo 7300sec | format duration --humanize",
+                result: Some(vec![Value::from("2 hours")]),
+            },
+            Example {
+                description: "Format a duration as a time in the past",
+                example: "echo 3day | format duration --ago",
+                result: Some(vec![Value::from("3 days ago")]),
+            },
+        ]
+    }
+}
+
+/// How to format durations.
+#[derive(Clone, Copy)]
+enum Style {
+    Clock,
+    Humanized,
+    Ago,
+}
+
+async fn format_duration_command(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let (
+        FormatDurationArgs {
+            rest: column_paths,
+            humanize,
+            ago,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    let style = match (humanize, ago) {
+        (_, true) => Style::Ago,
+        (true, false) => Style::Humanized,
+        (false, false) => Style::Clock,
+    };
+
+    Ok(input
+        .map(move |value| match &value.value {
+            UntaggedValue::Row(row) if column_paths.is_empty() => {
+                let mut entries = IndexMap::new();
+                for (column, cell) in &row.entries {
+                    let cell = match &cell.value {
+                        UntaggedValue::Primitive(Primitive::Duration(_)) => action(cell, style)?,
+                        _ => cell.clone(),
+                    };
+                    entries.insert(column.clone(), cell);
+                }
+
+                ReturnSuccess::value(
+                    UntaggedValue::Row(Dictionary { entries }).into_value(&value.tag),
+                )
+            }
+            _ if column_paths.is_empty() => ReturnSuccess::value(action(&value, style)?),
+            _ => {
+                let mut ret = value;
+
+                for path in &column_paths {
+                    ret = ret
+                        .swap_data_by_column_path(path, Box::new(move |old| action(old, style)))?;
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn action(value: &Value, style: Style) -> Result<Value, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Duration(secs)) => {
+            let text = match style {
+                Style::Clock if *secs < 0 => format!("-{}", format_duration(-secs)),
+                Style::Clock => format_duration(*secs),
+                Style::Humanized => humanize(*secs),
+                Style::Ago if *secs < 0 => format!("{} from now", humanize(-secs)),
+                Style::Ago => format!("{} ago", humanize(*secs)),
+            };
+
+            Ok(UntaggedValue::string(text).into_value(&value.tag))
+        }
+        other => Err(ShellError::labeled_error(
+            "Expected a duration",
+            format!("found {}", other.type_name()),
+            &value.tag,
+        )),
+    }
+}
+
+/// Writes the duration in its largest whole unit, with the same units as humanized dates.
+fn humanize(secs: i64) -> String {
+    let units = [
+        ("year", 52 * 7 * 24 * 60 * 60),
+        ("month", 4 * 7 * 24 * 60 * 60),
+        ("week", 7 * 24 * 60 * 60),
+        ("day", 24 * 60 * 60),
+        ("hour", 60 * 60),
+        ("min", 60),
+    ];
+
+    let (unit, count) = units
+        .iter()
+        .find(|(_, size)| secs.abs() >= *size)
+        .map(|(unit, size)| (*unit, secs / size))
+        .unwrap_or(("sec", secs));
+
+    format!(
+        "{} {}{}",
+        count,
+        unit,
+        if count.abs() == 1 { "" } else { "s" }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{humanize, FormatDuration};
+
+    #[test]
+    fn humanizes_in_the_largest_unit() {
+        assert_eq!(humanize(0), "0 secs");
+        assert_eq!(humanize(1), "1 sec");
+        assert_eq!(humanize(119), "1 min");
+        assert_eq!(humanize(2 * 60 * 60 + 59 * 60), "2 hours");
+        assert_eq!(humanize(-3 * 24 * 60 * 60), "-3 days");
+        assert_eq!(humanize(9 * 7 * 24 * 60 * 60), "2 months");
+        assert_eq!(humanize(400 * 24 * 60 * 60), "1 year");
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(FormatDuration {})
+    }
+}
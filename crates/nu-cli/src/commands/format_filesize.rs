@@ -0,0 +1,224 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use byte_unit::{Byte, ByteUnit};
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Dictionary, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape,
+    UntaggedValue, Value,
+};
+use nu_source::Tagged;
+use nu_value_ext::ValueExt;
+
+pub struct FileSize;
+
+#[derive(Deserialize)]
+pub struct FileSizeArgs {
+    rest: Vec<ColumnPath>,
+    unit: Option<Tagged<String>>,
+    precision: Option<Tagged<u64>>,
+    binary: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for FileSize {
+    fn name(&self) -> &str {
+        "format filesize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("format filesize")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally format the cells at the given column paths (default: every filesize)",
+            )
+            .named(
+                "unit",
+                SyntaxShape::String,
+                "the unit to use, like B, KB, KiB, MB or MiB (default: the most appropriate one)",
+                Some('u'),
+            )
+            .named(
+                "precision",
+                SyntaxShape::Int,
+                "the number of decimal places (default: 1)",
+                Some('p'),
+            )
+            .switch(
+                "binary",
+                "pick the most appropriate of the units based on 1024 bytes (KiB, MiB, ...)",
+                Some('b'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Formats filesizes as text in the given unit and precision."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        format_filesize(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Format a filesize in kibibytes",
+                example: "echo 1536b | format filesize --unit KiB",
+                result: Some(vec![Value::from("1.5 KiB")]),
+            },
+            Example {
+                description:
+                    "Format a filesize in the most appropriate unit, with two decimal places",
+                example: "echo 2500000b | format filesize --precision 2",
+                result: Some(vec![Value::from("2.50 MB")]),
+            },
+            Example {
+                description: "Format the sizes of the files in megabytes",
+                example: "ls | format filesize size --unit MB",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// How to format filesizes.
+#[derive(Clone)]
+struct Format {
+    unit: Option<ByteUnit>,
+    precision: usize,
+    binary: bool,
+}
+
+async fn format_filesize(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let (
+        FileSizeArgs {
+            rest: column_paths,
+            unit,
+            precision,
+            binary,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    let unit = match unit {
+        Some(unit) => Some(ByteUnit::from_str(&unit.item).map_err(|_| {
+            ShellError::labeled_error(
+                "Unknown unit",
+                "expected a unit like B, KB, KiB, MB or MiB",
+                &unit.tag,
+            )
+        })?),
+        None => None,
+    };
+
+    let format = Format {
+        unit,
+        precision: precision.map(|p| p.item as usize).unwrap_or(1),
+        binary,
+    };
+
+    Ok(input
+        .map(move |value| match &value.value {
+            UntaggedValue::Row(row) if column_paths.is_empty() => {
+                let mut entries = IndexMap::new();
+                for (column, cell) in &row.entries {
+                    let cell = match &cell.value {
+                        UntaggedValue::Primitive(Primitive::Bytes(_)) => action(cell, &format)?,
+                        _ => cell.clone(),
+                    };
+                    entries.insert(column.clone(), cell);
+                }
+
+                ReturnSuccess::value(
+                    UntaggedValue::Row(Dictionary { entries }).into_value(&value.tag),
+                )
+            }
+            _ if column_paths.is_empty() => ReturnSuccess::value(action(&value, &format)?),
+            _ => {
+                let mut ret = value;
+
+                for path in &column_paths {
+                    let format = format.clone();
+                    ret = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, &format)),
+                    )?;
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn action(value: &Value, format: &Format) -> Result<Value, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Bytes(bytes)) => {
+            Ok(UntaggedValue::string(format_bytes(*bytes, format)).into_value(&value.tag))
+        }
+        other => Err(ShellError::labeled_error(
+            "Expected a filesize",
+            format!("found {}", other.type_name()),
+            &value.tag,
+        )),
+    }
+}
+
+fn format_bytes(bytes: u64, format: &Format) -> String {
+    let byte = Byte::from_bytes(bytes as u128);
+
+    let adjusted = match format.unit {
+        Some(unit) => byte.get_adjusted_unit(unit),
+        None => byte.get_appropriate_unit(format.binary),
+    };
+
+    adjusted.format(format.precision)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_bytes, FileSize, Format};
+    use byte_unit::ByteUnit;
+
+    #[test]
+    fn formats_in_the_given_unit() {
+        let format = |unit, precision| Format {
+            unit: Some(unit),
+            precision,
+            binary: false,
+        };
+
+        assert_eq!(format_bytes(1_500_000, &format(ByteUnit::KB, 0)), "1500 KB");
+        assert_eq!(
+            format_bytes(1_500_000, &format(ByteUnit::MiB, 3)),
+            "1.431 MiB"
+        );
+        assert_eq!(format_bytes(1_500, &format(ByteUnit::B, 2)), "1500 B");
+    }
+
+    #[test]
+    fn picks_binary_units() {
+        let format = Format {
+            unit: None,
+            precision: 1,
+            binary: true,
+        };
+
+        assert_eq!(format_bytes(3 * 1024 * 1024, &format), "3.0 MiB");
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(FileSize {})
+    }
+}
@@ -0,0 +1,359 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use glob::{MatchOptions, Pattern};
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+
+pub struct Glob;
+
+#[derive(Deserialize)]
+pub struct GlobArgs {
+    pattern: Tagged<String>,
+    depth: Option<Tagged<u64>>,
+    #[serde(rename(deserialize = "follow-symlinks"))]
+    follow_symlinks: bool,
+    #[serde(rename(deserialize = "no-dir"))]
+    no_dir: bool,
+    #[serde(rename(deserialize = "no-file"))]
+    no_file: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Glob {
+    fn name(&self) -> &str {
+        "glob"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("glob")
+            .required(
+                "pattern",
+                SyntaxShape::String,
+                "the pattern to match, with *, ?, **, [abc] and {a,b}",
+            )
+            .named(
+                "depth",
+                SyntaxShape::Int,
+                "how many directory levels to look into (default: as deep as the pattern goes)",
+                Some('d'),
+            )
+            .switch(
+                "follow-symlinks",
+                "look inside the directories that symlinks point to",
+                Some('l'),
+            )
+            .switch("no-dir", "leave out directories", None)
+            .switch("no-file", "leave out files, keeping only directories", None)
+    }
+
+    fn usage(&self) -> &str {
+        "Gives the paths that match a glob pattern."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        glob(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Find the Rust and TOML files in every subdirectory",
+                example: "glob '**/*.{rs,toml}'",
+                result: None,
+            },
+            Example {
+                description: "Find the directories up to two levels down",
+                example: "glob '**' --depth 2 --no-file",
+                result: None,
+            },
+            Example {
+                description: "Count the lines in the markdown files of the docs",
+                example: "glob 'docs/**/*.md' | each { open $it | lines | count }",
+                result: None,
+            },
+        ]
+    }
+}
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// What to look for, and where.
+struct Search {
+    /// The directory the pattern starts from: the part of it without any wildcards.
+    base: PathBuf,
+    /// The rest of the pattern, matched against the paths relative to the base.
+    pattern: Pattern,
+    /// How many directory levels below the base to look into, if limited.
+    depth: Option<usize>,
+}
+
+struct Filter {
+    follow_symlinks: bool,
+    no_dir: bool,
+    no_file: bool,
+}
+
+async fn glob(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let ctrl_c = args.ctrl_c.clone();
+    let cwd = PathBuf::from(args.shell_manager.path());
+    let (
+        GlobArgs {
+            pattern,
+            depth,
+            follow_symlinks,
+            no_dir,
+            no_file,
+        },
+        _,
+    ) = args.process(&registry).await?;
+
+    let filter = Filter {
+        follow_symlinks,
+        no_dir,
+        no_file,
+    };
+
+    let mut found = BTreeSet::new();
+
+    for expanded in expand_braces(&pattern.item) {
+        let search = Search::new(&expanded, depth.as_ref().map(|d| d.item as usize))
+            .map_err(|e| ShellError::labeled_error("Invalid glob pattern", e, &pattern.tag))?;
+
+        let start = cwd.join(&search.base);
+
+        // A pattern without wildcards names a single path.
+        if search.pattern.as_str().is_empty() {
+            let wanted = if start.is_dir() { !no_dir } else { !no_file };
+            if start.exists() && wanted {
+                found.insert(search.base);
+            }
+            continue;
+        }
+
+        let mut visited = HashSet::new();
+        walk(
+            &start,
+            &search.base,
+            0,
+            &search,
+            &filter,
+            &mut visited,
+            &ctrl_c,
+            &mut found,
+        );
+    }
+
+    let tag = pattern.tag;
+
+    Ok(futures::stream::iter(
+        found
+            .into_iter()
+            .map(move |path| ReturnSuccess::value(UntaggedValue::path(path).into_value(&tag))),
+    )
+    .to_output_stream())
+}
+
+impl Search {
+    fn new(pattern: &str, depth: Option<usize>) -> Result<Search, String> {
+        let mut base = PathBuf::new();
+        let mut rest = vec![];
+
+        for component in Path::new(pattern).components() {
+            let text = component.as_os_str().to_string_lossy();
+
+            if rest.is_empty() && !text.contains(&['*', '?', '['][..]) {
+                base.push(component);
+            } else {
+                rest.push(text.to_string());
+            }
+        }
+
+        // Without `**`, there's no point in looking deeper than the pattern goes.
+        let pattern_depth = if rest.iter().any(|part| part.contains("**")) {
+            None
+        } else {
+            Some(rest.len())
+        };
+
+        let depth = match (depth, pattern_depth) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+
+        let pattern = Pattern::new(&rest.join("/")).map_err(|e| e.msg.to_string())?;
+
+        Ok(Search {
+            base,
+            pattern,
+            depth,
+        })
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn walk(
+    dir: &Path,
+    relative_dir: &Path,
+    level: usize,
+    search: &Search,
+    filter: &Filter,
+    visited: &mut HashSet<PathBuf>,
+    ctrl_c: &Arc<AtomicBool>,
+    found: &mut BTreeSet<PathBuf>,
+) {
+    if search.depth.map_or(false, |depth| level >= depth) {
+        return;
+    }
+
+    // Symlinks can point back up the tree, so each directory is only looked into once.
+    if let Ok(canonical) = dir.canonicalize() {
+        if !visited.insert(canonical) {
+            return;
+        }
+    }
+
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.filter_map(Result::ok) {
+        if ctrl_c.load(Ordering::SeqCst) {
+            return;
+        }
+
+        let path = entry.path();
+        let relative = relative_dir.join(entry.file_name());
+
+        let is_dir = path.is_dir();
+        let descend = is_dir
+            && (filter.follow_symlinks || !matches!(entry.file_type(), Ok(t) if t.is_symlink()));
+
+        let matched = relative.strip_prefix(&search.base).map_or(false, |rest| {
+            search.pattern.matches_path_with(rest, MATCH_OPTIONS)
+        });
+
+        let wanted = if is_dir {
+            !filter.no_dir
+        } else {
+            !filter.no_file
+        };
+
+        if matched && wanted {
+            found.insert(relative.clone());
+        }
+
+        if descend {
+            walk(
+                &path,
+                &relative,
+                level + 1,
+                search,
+                filter,
+                visited,
+                ctrl_c,
+                found,
+            );
+        }
+    }
+}
+
+/// Expands braces the way shells do, so `*.{rs,toml}` gives `*.rs` and `*.toml`. Braces without
+/// a comma in them, or without a match, are left alone.
+fn expand_braces(pattern: &str) -> Vec<String> {
+    let chars: Vec<char> = pattern.chars().collect();
+
+    let mut depth = 0;
+    let mut open = None;
+    let mut commas = vec![];
+
+    for (idx, c) in chars.iter().enumerate() {
+        match c {
+            '{' => {
+                if depth == 0 {
+                    open = Some(idx);
+                    commas.clear();
+                }
+                depth += 1;
+            }
+            ',' if depth == 1 => commas.push(idx),
+            '}' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    if let (Some(start), false) = (open, commas.is_empty()) {
+                        let prefix: String = chars[..start].iter().collect();
+                        let suffix: String = chars[idx + 1..].iter().collect();
+
+                        let mut bounds = vec![start];
+                        bounds.extend(&commas);
+                        bounds.push(idx);
+
+                        return bounds
+                            .windows(2)
+                            .flat_map(|window| {
+                                let choice: String =
+                                    chars[window[0] + 1..window[1]].iter().collect();
+                                expand_braces(&format!("{}{}{}", prefix, choice, suffix))
+                            })
+                            .collect();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    vec![pattern.to_string()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expand_braces, Glob, Search};
+    use std::path::PathBuf;
+
+    #[test]
+    fn expands_braces() {
+        assert_eq!(expand_braces("*.{rs,toml}"), vec!["*.rs", "*.toml"]);
+        assert_eq!(
+            expand_braces("{src,tests}/{a,b}.rs"),
+            vec!["src/a.rs", "src/b.rs", "tests/a.rs", "tests/b.rs"]
+        );
+        assert_eq!(expand_braces("{a,{b,c}}"), vec!["a", "b", "c"]);
+        assert_eq!(expand_braces("{a}"), vec!["{a}"]);
+        assert_eq!(expand_braces("{a,b"), vec!["{a,b"]);
+    }
+
+    #[test]
+    fn starts_from_the_part_without_wildcards() {
+        let search = Search::new("docs/commands/*.md", None).expect("invalid pattern");
+        assert_eq!(search.base, PathBuf::from("docs/commands"));
+        assert_eq!(search.pattern.as_str(), "*.md");
+        assert_eq!(search.depth, Some(1));
+
+        let search = Search::new("src/**/*.rs", Some(3)).expect("invalid pattern");
+        assert_eq!(search.base, PathBuf::from("src"));
+        assert_eq!(search.depth, Some(3));
+
+        assert!(Search::new("src/[", None).is_err());
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Glob {})
+    }
+}
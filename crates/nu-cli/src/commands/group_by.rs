@@ -1,8 +1,13 @@
+use crate::commands::classified::block::run_block;
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
+use futures::stream::once;
 use indexmap::indexmap;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{
+    hir::Block, ReturnSuccess, Scope, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue,
+    Value,
+};
 use nu_source::Tagged;
 use nu_value_ext::as_string;
 
@@ -10,7 +15,7 @@ pub struct GroupBy;
 
 #[derive(Deserialize)]
 pub struct GroupByArgs {
-    column_name: Option<Tagged<String>>,
+    rest: Vec<Value>,
 }
 
 #[async_trait]
@@ -20,15 +25,14 @@ impl WholeStreamCommand for GroupBy {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("group-by").optional(
-            "column_name",
-            SyntaxShape::String,
-            "the name of the column to group by",
+        Signature::build("group-by").rest(
+            SyntaxShape::Any,
+            "the name(s) of the column(s) or block(s) to group by (nested in the order given)",
         )
     }
 
     fn usage(&self) -> &str {
-        "Creates a new table with the data from the table rows grouped by the column given."
+        "Creates a new table with the data from the table rows grouped by the columns or blocks given."
     }
 
     async fn run(
@@ -46,6 +50,11 @@ impl WholeStreamCommand for GroupBy {
                 example: r#"ls | group-by type"#,
                 result: None,
             },
+            Example {
+                description: "Group items by type, then by the result of a block",
+                example: r#"ls | group-by type { = $it.size > 1kb }"#,
+                result: None,
+            },
             Example {
                 description: "Group items by their value",
                 example: "echo [1 3 1 3 2 1 1] | group-by",
@@ -68,12 +77,97 @@ impl WholeStreamCommand for GroupBy {
                 })
                 .into()]),
             },
+            Example {
+                description: "Group items by whether they are even",
+                example: "echo [1 2 3 4] | group-by { = $it mod 2 == 0 }",
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                    "false".to_string() => UntaggedValue::Table(vec![
+                        UntaggedValue::int(1).into(),
+                        UntaggedValue::int(3).into(),
+                    ]).into(),
+
+                    "true".to_string() => UntaggedValue::Table(vec![
+                        UntaggedValue::int(2).into(),
+                        UntaggedValue::int(4).into(),
+                    ]).into(),
+                })
+                .into()]),
+            },
         ]
     }
 }
 
-enum Grouper {
+pub(crate) enum Grouper {
     ByColumn(Option<Tagged<String>>),
+    ByBlock(Box<Block>, Tag),
+}
+
+impl Grouper {
+    pub(crate) fn from_arg(arg: Value) -> Result<Grouper, ShellError> {
+        match arg {
+            Value {
+                value: UntaggedValue::Block(block),
+                tag,
+            } => Ok(Grouper::ByBlock(Box::new(block), tag)),
+            other => {
+                let tag = other.tag.clone();
+                Ok(Grouper::ByColumn(Some(as_string(&other)?.tagged(tag))))
+            }
+        }
+    }
+
+    async fn key_for(
+        &self,
+        row: &Value,
+        context: &mut Context,
+        scope: &Scope,
+    ) -> Result<String, ShellError> {
+        as_string(&self.value_for(row, context, scope).await?)
+    }
+
+    /// The value of the column, or the value produced by the block, for the given row.
+    pub(crate) async fn value_for(
+        &self,
+        row: &Value,
+        context: &mut Context,
+        scope: &Scope,
+    ) -> Result<Value, ShellError> {
+        match self {
+            Grouper::ByColumn(Some(column_name)) => {
+                match row.get_data_by_key(column_name.borrow_spanned()) {
+                    Some(group_key) => Ok(group_key),
+                    None => Err(suggestions(column_name.borrow_tagged(), row)),
+                }
+            }
+            Grouper::ByColumn(None) => Ok(row.clone()),
+            Grouper::ByBlock(block, tag) => {
+                let for_block = row.clone();
+                let input_stream = once(async { Ok(for_block) }).to_input_stream();
+
+                let result = run_block(block, context, input_stream, row, &scope.vars, &scope.env)
+                    .await?
+                    .into_vec()
+                    .await;
+
+                if let Some(error) = context.get_errors().first() {
+                    return Err(error.clone());
+                }
+
+                match result.into_iter().next() {
+                    Some(Value {
+                        value: UntaggedValue::Error(e),
+                        ..
+                    }) => Err(e),
+                    Some(group_key) => Ok(group_key),
+                    None => Err(ShellError::labeled_error(
+                        "Expected a value to group by",
+                        "block produced no value",
+                        tag,
+                    )),
+                }
+            }
+        }
+    }
 }
 
 pub async fn group_by(
@@ -82,7 +176,9 @@ pub async fn group_by(
 ) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
     let name = args.call_info.name_tag.clone();
-    let (GroupByArgs { column_name }, input) = args.process(&registry).await?;
+    let scope = args.call_info.scope.clone();
+    let mut context = Context::from_raw(&args, &registry);
+    let (GroupByArgs { rest }, input) = args.process(&registry).await?;
     let values: Vec<Value> = input.collect().await;
 
     if values.is_empty() {
@@ -93,12 +189,54 @@ pub async fn group_by(
         ));
     }
 
-    let values = UntaggedValue::table(&values).into_value(&name);
+    let groupers = if rest.is_empty() {
+        vec![Grouper::ByColumn(None)]
+    } else {
+        rest.into_iter()
+            .map(Grouper::from_arg)
+            .collect::<Result<Vec<_>, _>>()?
+    };
+
+    let mut keyed = Vec::with_capacity(values.len());
 
-    match group(&column_name, &values, name) {
-        Ok(grouped) => Ok(OutputStream::one(ReturnSuccess::value(grouped))),
-        Err(reason) => Err(reason),
+    for row in values {
+        let mut keys = Vec::with_capacity(groupers.len());
+
+        for grouper in &groupers {
+            keys.push(grouper.key_for(&row, &mut context, &scope).await?);
+        }
+
+        keyed.push((keys, row));
     }
+
+    Ok(OutputStream::one(ReturnSuccess::value(nest(
+        keyed, 0, &name,
+    ))))
+}
+
+/// Groups rows by the key at `depth`, nesting the groups of each following key inside.
+fn nest(keyed: Vec<(Vec<String>, Value)>, depth: usize, tag: &Tag) -> Value {
+    let mut groups: IndexMap<String, Vec<(Vec<String>, Value)>> = IndexMap::new();
+    let mut leaves = vec![];
+
+    for (keys, row) in keyed {
+        match keys.get(depth) {
+            Some(key) => groups.entry(key.clone()).or_default().push((keys, row)),
+            None => leaves.push(row),
+        }
+    }
+
+    if groups.is_empty() {
+        return UntaggedValue::table(&leaves).into_value(tag);
+    }
+
+    let mut out = TaggedDictBuilder::new(tag);
+
+    for (key, group) in groups {
+        out.insert_value(key, nest(group, depth + 1, tag));
+    }
+
+    out.into_value()
 }
 
 pub fn suggestions(tried: Tagged<&str>, for_value: &Value) -> ShellError {
@@ -133,14 +271,9 @@ pub fn group(
 ) -> Result<Value, ShellError> {
     let name = tag.into();
 
-    let grouper = if let Some(column_name) = column_name {
-        Grouper::ByColumn(Some(column_name.clone()))
-    } else {
-        Grouper::ByColumn(None)
-    };
-
-    match grouper {
-        Grouper::ByColumn(Some(column_name)) => {
+    match column_name {
+        Some(column_name) => {
+            let column_name = column_name.clone();
             let block = Box::new(move |row: &Value| {
                 match row.get_data_by_key(column_name.borrow_spanned()) {
                     Some(group_key) => Ok(as_string(&group_key)?),
@@ -150,7 +283,7 @@ pub fn group(
 
             crate::utils::data::group(&values, &Some(block), &name)
         }
-        Grouper::ByColumn(None) => {
+        None => {
             let block = Box::new(move |row: &Value| match as_string(row) {
                 Ok(group_key) => Ok(group_key),
                 Err(reason) => Err(reason),
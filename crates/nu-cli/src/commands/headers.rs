@@ -5,10 +5,17 @@ use futures::stream::StreamExt;
 use indexmap::IndexMap;
 use nu_errors::ShellError;
 use nu_protocol::Dictionary;
-use nu_protocol::{ReturnSuccess, Signature, UntaggedValue, Value};
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
 
 pub struct Headers;
 
+#[derive(Deserialize)]
+pub struct HeadersArgs {
+    row: Option<Tagged<u64>>,
+    keep: bool,
+}
+
 #[async_trait]
 impl WholeStreamCommand for Headers {
     fn name(&self) -> &str {
@@ -17,6 +24,17 @@ impl WholeStreamCommand for Headers {
 
     fn signature(&self) -> Signature {
         Signature::build("headers")
+            .named(
+                "row",
+                SyntaxShape::Int,
+                "the number of the row to use as column names, dropping the rows above it (default: 0)",
+                Some('r'),
+            )
+            .switch(
+                "keep",
+                "keep the row used as column names in the table",
+                Some('k'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -32,60 +50,73 @@ impl WholeStreamCommand for Headers {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Create headers for a raw string",
-            example: r#"echo "a b c|1 2 3" | split row "|" | split column " " | headers"#,
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Create headers for a raw string",
+                example: r#"echo "a b c|1 2 3" | split row "|" | split column " " | headers"#,
+                result: None,
+            },
+            Example {
+                description: "Use the third row of a spreadsheet as column names",
+                example: "open sample_data.ods | get SalesOrders | headers --row 2",
+                result: None,
+            },
+        ]
     }
 }
 
 pub async fn headers(
     args: CommandArgs,
-    _registry: &CommandRegistry,
+    registry: &CommandRegistry,
 ) -> Result<OutputStream, ShellError> {
-    let input = args.input;
-    let rows: Vec<Value> = input.collect().await;
+    let registry = registry.clone();
+    let (HeadersArgs { row, keep }, input) = args.process(&registry).await?;
+    let mut rows: Vec<Value> = input.collect().await;
+
+    let header_row = match &row {
+        Some(row) => row.item as usize,
+        None => 0,
+    };
 
-    if rows.is_empty() {
-        return Err(ShellError::untagged_runtime_error(
-            "Couldn't find headers, was the input a properly formatted, non-empty table?",
-        ));
+    if rows.len() <= header_row {
+        return Err(match row {
+            Some(row) => ShellError::labeled_error(
+                "Couldn't find headers",
+                format!("the table has {} rows", rows.len()),
+                row.tag,
+            ),
+            None => ShellError::untagged_runtime_error(
+                "Couldn't find headers, was the input a properly formatted, non-empty table?",
+            ),
+        });
     }
 
-    //the headers are the first row in the table
-    let headers: Vec<String> = match &rows[0].value {
-        UntaggedValue::Row(d) => {
-            Ok(d.entries
-                .iter()
-                .map(|(k, v)| {
-                    match v.as_string() {
-                        Ok(s) => s,
-                        Err(_) => {
-                            //If a cell that should contain a header name is empty, we name the column Column[index]
-                            match d.entries.get_full(k) {
-                                Some((index, _, _)) => format!("Column{}", index),
-                                None => "unknownColumn".to_string(),
-                            }
-                        }
-                    }
-                })
-                .collect())
+    let rows = rows.split_off(header_row);
+
+    let headers = match &rows[0].value {
+        UntaggedValue::Row(d) => column_names(d),
+        _ => {
+            return Err(ShellError::unexpected_eof(
+                "Could not get headers, is the table empty?",
+                rows[0].tag.span,
+            ))
         }
-        _ => Err(ShellError::unexpected_eof(
-            "Could not get headers, is the table empty?",
-            rows[0].tag.span,
-        )),
-    }?;
+    };
+
+    let skip = if keep { 0 } else { 1 };
 
     Ok(
-        futures::stream::iter(rows.into_iter().skip(1).map(move |r| {
+        futures::stream::iter(rows.into_iter().skip(skip).map(move |r| {
             //Each row is a dictionary with the headers as keys
             match &r.value {
                 UntaggedValue::Row(d) => {
                     let mut entries = IndexMap::new();
                     for (i, (_, v)) in d.entries.iter().enumerate() {
-                        entries.insert(headers[i].clone(), v.clone());
+                        let header = match headers.get(i) {
+                            Some(header) => header.clone(),
+                            None => format!("Column{}", i),
+                        };
+                        entries.insert(header, v.clone());
                     }
                     Ok(ReturnSuccess::Value(
                         UntaggedValue::Row(Dictionary { entries }).into_value(r.tag.clone()),
@@ -101,9 +132,56 @@ pub async fn headers(
     )
 }
 
+/// The column names given by the cells of a row. Empty cells are named Column[index] and repeated
+/// names get a numeric suffix (name_2, name_3, ...), so every name is unique.
+fn column_names(row: &Dictionary) -> Vec<String> {
+    let mut names: Vec<String> = vec![];
+
+    for (index, value) in row.entries.values().enumerate() {
+        let name = match value.as_string() {
+            Ok(s) if !s.trim().is_empty() => s.trim().to_string(),
+            _ => format!("Column{}", index),
+        };
+
+        let mut unique = name.clone();
+        let mut count = 1;
+        while names.contains(&unique) {
+            count += 1;
+            unique = format!("{}_{}", name, count);
+        }
+
+        names.push(unique);
+    }
+
+    names
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Headers;
+    use super::{column_names, Headers};
+    use nu_protocol::{Dictionary, UntaggedValue, Value};
+
+    fn string(input: impl Into<String>) -> Value {
+        UntaggedValue::string(input.into()).into_untagged_value()
+    }
+
+    #[test]
+    fn names_empty_and_repeated_columns_uniquely() {
+        let row = Dictionary {
+            entries: indexmap! {
+                "Column0".into() => string("name"),
+                "Column1".into() => string(""),
+                "Column2".into() => string("name"),
+                "Column3".into() => UntaggedValue::nothing().into_untagged_value(),
+                "Column4".into() => string("name"),
+            },
+        };
+
+        assert_eq!(
+            column_names(&row),
+            vec!["name", "Column1", "name_2", "Column3", "name_3"]
+        );
+    }
 
     #[test]
     fn examples_work_as_expected() {
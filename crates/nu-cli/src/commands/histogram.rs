@@ -1,20 +1,27 @@
-use crate::commands::group_by::group;
+use crate::commands::group_by::Grouper;
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
-use crate::utils::data_processing::{columns_sorted, evaluate, map_max, reduce, t_sort};
+use crate::utils::data_processing::columns_sorted;
+use bigdecimal::BigDecimal;
+use indexmap::IndexMap;
 use nu_errors::ShellError;
 use nu_protocol::{
-    Primitive, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, TaggedDictBuilder,
+    UntaggedValue, Value,
 };
 use nu_source::Tagged;
-use num_traits::{ToPrimitive, Zero};
+use nu_value_ext::as_string;
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
 
 pub struct Histogram;
 
 #[derive(Deserialize)]
 pub struct HistogramArgs {
-    column_name: Tagged<String>,
+    column_name: Option<Value>,
     rest: Vec<Tagged<String>>,
+    bins: Option<Tagged<u64>>,
+    edges: Option<Value>,
 }
 
 #[async_trait]
@@ -25,15 +32,27 @@ impl WholeStreamCommand for Histogram {
 
     fn signature(&self) -> Signature {
         Signature::build("histogram")
-            .required(
+            .optional(
                 "column_name",
-                SyntaxShape::String,
-                "the name of the column to graph by",
+                SyntaxShape::Any,
+                "the name of the column, or a block giving the value, to graph by",
             )
             .rest(
                 SyntaxShape::String,
                 "column name to give the histogram's frequency column",
             )
+            .named(
+                "bins",
+                SyntaxShape::Int,
+                "count numbers in the given number of equally wide bins",
+                Some('b'),
+            )
+            .named(
+                "edges",
+                SyntaxShape::Table,
+                "count numbers in the bins between the given edges, like [0 10 100]",
+                Some('e'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -66,6 +85,16 @@ impl WholeStreamCommand for Histogram {
                 example: "echo [1 2 3 1 1 1 2 2 1 1] | histogram",
                 result: None,
             },
+            Example {
+                description: "Get a histogram for the sizes of files, in 10 bins",
+                example: "ls | histogram size --bins 10",
+                result: None,
+            },
+            Example {
+                description: "Get a histogram for whether files are larger than a megabyte",
+                example: "ls | histogram { = $it.size > 1mb }",
+                result: None,
+            },
         ]
     }
 }
@@ -76,186 +105,297 @@ pub async fn histogram(
 ) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
     let name = args.call_info.name_tag.clone();
+    let scope = args.call_info.scope.clone();
+    let mut context = Context::from_raw(&args, &registry);
 
-    let (HistogramArgs { column_name, rest }, input) = args.process(&registry).await?;
-    let values: Vec<Value> = input.collect().await;
-    let values = UntaggedValue::table(&values).into_value(&name);
-
-    let groups = group(&Some(column_name.clone()), &values, &name)?;
-    let group_labels = columns_sorted(Some(column_name.clone()), &groups, &name);
-    let sorted = t_sort(Some(column_name.clone()), None, &groups, &name)?;
-    let evaled = evaluate(&sorted, None, &name)?;
-    let reduced = reduce(&evaled, None, &name)?;
-    let maxima = map_max(&reduced, None, &name)?;
-    let percents = percentages(&reduced, maxima, &name)?;
-
-    match percents {
-        Value {
-            value: UntaggedValue::Table(datasets),
-            ..
-        } => {
-            let mut idx = 0;
-
-            let column_names_supplied: Vec<_> = rest.iter().map(|f| f.item.clone()).collect();
-
-            let frequency_column_name = if column_names_supplied.is_empty() {
-                "frequency".to_string()
-            } else {
-                column_names_supplied[0].clone()
-            };
+    let (
+        HistogramArgs {
+            column_name,
+            rest,
+            bins,
+            edges,
+        },
+        input,
+    ) = args.process(&registry).await?;
+    let rows: Vec<Value> = input.collect().await;
 
-            let column = (*column_name).clone();
+    let grouper = match column_name {
+        Some(arg) => Grouper::from_arg(arg)?,
+        None => Grouper::ByColumn(None),
+    };
 
-            let count_column_name = "count".to_string();
-            let count_shell_error = ShellError::labeled_error(
-                "Unable to load group count",
-                "unabled to load group count",
-                &name,
-            );
-            let mut count_values: Vec<u64> = Vec::new();
-
-            for table_entry in reduced.table_entries() {
-                match table_entry {
-                    Value {
-                        value: UntaggedValue::Table(list),
-                        ..
-                    } => {
-                        for i in list {
-                            if let Ok(count) = i.value.clone().into_value(&name).as_u64() {
-                                count_values.push(count);
-                            } else {
-                                return Err(count_shell_error);
-                            }
-                        }
-                    }
-                    _ => {
-                        return Err(count_shell_error);
-                    }
-                }
-            }
-
-            if let Value {
-                value: UntaggedValue::Table(start),
-                ..
-            } = datasets.get(0).ok_or_else(|| {
+    let mut values = Vec::with_capacity(rows.len());
+    for row in &rows {
+        values.push(grouper.value_for(row, &mut context, &scope).await?);
+    }
+
+    let buckets = match (bins, edges) {
+        (None, None) => categories(&values, &name)?,
+        (Some(bins), None) => {
+            let numbers = numbers(&values)?;
+            let edges = even_edges(&numbers, &bins)?;
+            binned(&numbers, &edges)
+        }
+        (None, Some(edges)) => binned(&numbers(&values)?, &given_edges(&edges)?),
+        (Some(_), Some(edges)) => {
+            return Err(ShellError::labeled_error(
+                "Expected either --bins or --edges",
+                "can't be combined with --bins",
+                edges.tag,
+            ))
+        }
+    };
+
+    let column = match &grouper {
+        Grouper::ByColumn(Some(column_name)) => column_name.item.clone(),
+        _ => "value".to_string(),
+    };
+
+    let frequency_column_name = match rest.first() {
+        Some(frequency) => frequency.item.clone(),
+        None => "frequency".to_string(),
+    };
+
+    let total: u64 = buckets.iter().map(|(_, count)| count).sum();
+    let maximum = buckets.iter().map(|(_, count)| *count).max().unwrap_or(0);
+
+    let rows = buckets.into_iter().map(move |(label, count)| {
+        let mut fact = TaggedDictBuilder::new(&name);
+        fact.insert_value(&column, label);
+        fact.insert_untagged("count", UntaggedValue::int(count));
+        fact.insert_untagged("percentage", UntaggedValue::decimal(percent(count, total)));
+        fact.insert_untagged(
+            &frequency_column_name,
+            UntaggedValue::string(bar(count, maximum)),
+        );
+
+        ReturnSuccess::value(fact.into_value())
+    });
+
+    Ok(futures::stream::iter(rows).to_output_stream())
+}
+
+/// A bar of up to 100 `*`s, as long next to the longest bar as `count` is next to `maximum`. When
+/// nothing was counted at all, every bar is empty.
+fn bar(count: u64, maximum: u64) -> String {
+    if maximum == 0 {
+        return String::new();
+    }
+
+    "*".repeat((count * 100 / maximum) as usize)
+}
+
+/// The number of times each distinct value appears, in sorted order.
+fn categories(values: &[Value], tag: &Tag) -> Result<Vec<(Value, u64)>, ShellError> {
+    let mut counts: IndexMap<String, (Tag, u64)> = IndexMap::new();
+
+    for value in values {
+        let entry = counts
+            .entry(as_string(value)?)
+            .or_insert_with(|| (value.tag.clone(), 0));
+        entry.1 += 1;
+    }
+
+    let keys = UntaggedValue::row(
+        counts
+            .keys()
+            .map(|key| (key.clone(), UntaggedValue::nothing().into_value(tag)))
+            .collect(),
+    )
+    .into_value(tag);
+
+    Ok(columns_sorted(None, &keys, tag)
+        .into_iter()
+        .filter_map(|key| {
+            let (key_tag, count) = counts.get(&key.item)?;
+            Some((UntaggedValue::string(key.item).into_value(key_tag), *count))
+        })
+        .collect())
+}
+
+fn numbers(values: &[Value]) -> Result<Vec<f64>, ShellError> {
+    values
+        .iter()
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            let number = match &value.value {
+                UntaggedValue::Primitive(Primitive::Int(n)) => n.to_f64(),
+                UntaggedValue::Primitive(Primitive::Decimal(d)) => d.to_f64(),
+                UntaggedValue::Primitive(Primitive::Bytes(b)) => b.to_f64(),
+                _ => None,
+            };
+
+            number.ok_or_else(|| {
                 ShellError::labeled_error(
-                    "Unable to load dataset",
-                    "unabled to load dataset",
-                    &name,
-                )
-            })? {
-                let start = start.clone();
-                Ok(
-                    futures::stream::iter(start.into_iter().map(move |percentage| {
-                        let mut fact = TaggedDictBuilder::new(&name);
-                        let value: Tagged<String> = group_labels
-                            .get(idx)
-                            .ok_or_else(|| {
-                                ShellError::labeled_error(
-                                    "Unable to load group labels",
-                                    "unabled to load group labels",
-                                    &name,
-                                )
-                            })?
-                            .clone();
-                        fact.insert_value(
-                            &column,
-                            UntaggedValue::string(value.item).into_value(value.tag),
-                        );
-
-                        fact.insert_untagged(
-                            &count_column_name,
-                            UntaggedValue::int(count_values[idx]),
-                        );
-
-                        if let Value {
-                            value: UntaggedValue::Primitive(Primitive::Int(ref num)),
-                            ref tag,
-                        } = percentage
-                        {
-                            let string = std::iter::repeat("*")
-                                .take(num.to_i32().ok_or_else(|| {
-                                    ShellError::labeled_error(
-                                        "Expected a number",
-                                        "expected a number",
-                                        tag,
-                                    )
-                                })? as usize)
-                                .collect::<String>();
-                            fact.insert_untagged(
-                                &frequency_column_name,
-                                UntaggedValue::string(string),
-                            );
-                        }
-
-                        idx += 1;
-
-                        ReturnSuccess::value(fact.into_value())
-                    }))
-                    .to_output_stream(),
+                    "Expected numbers to put in bins",
+                    format!("found {}", value.type_name()),
+                    &value.tag,
                 )
-            } else {
-                Ok(OutputStream::empty())
-            }
-        }
-        _ => Ok(OutputStream::empty()),
+            })
+        })
+        .collect()
+}
+
+/// The most bins `--bins` can make.
+const MAX_BINS: u64 = 10_000;
+
+/// The edges of `bins` equally wide bins, from the smallest to the largest number.
+fn even_edges(numbers: &[f64], bins: &Tagged<u64>) -> Result<Vec<f64>, ShellError> {
+    if bins.item == 0 {
+        return Err(ShellError::labeled_error(
+            "Expected at least one bin",
+            "requires at least one bin",
+            &bins.tag,
+        ));
     }
+
+    if bins.item > MAX_BINS {
+        return Err(ShellError::labeled_error(
+            "Too many bins",
+            format!("can make at most {} bins", MAX_BINS),
+            &bins.tag,
+        ));
+    }
+
+    if numbers.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let min = numbers.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = numbers.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+    let count = bins.item as usize;
+    let width = if max > min {
+        (max - min) / count as f64
+    } else {
+        1.0
+    };
+
+    let mut edges: Vec<f64> = (0..=count).map(|idx| min + width * idx as f64).collect();
+    if max > min {
+        edges[count] = max;
+    }
+
+    Ok(edges)
 }
 
-fn percentages(values: &Value, max: Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
-    let tag = tag.into();
-
-    let results: Value = match values {
-        Value {
-            value: UntaggedValue::Table(datasets),
-            ..
-        } => {
-            let datasets: Vec<_> = datasets
-                .iter()
-                .map(|subsets| match subsets {
-                    Value {
-                        value: UntaggedValue::Table(data),
-                        ..
-                    } => {
-                        let data = data
-                            .iter()
-                            .map(|d| match d {
-                                Value {
-                                    value: UntaggedValue::Primitive(Primitive::Int(n)),
-                                    ..
-                                } => {
-                                    let max = match &max {
-                                        Value {
-                                            value: UntaggedValue::Primitive(Primitive::Int(maxima)),
-                                            ..
-                                        } => maxima.clone(),
-                                        _ => Zero::zero(),
-                                    };
-
-                                    let n = (n * 100) / max;
-
-                                    UntaggedValue::int(n).into_value(&tag)
-                                }
-                                _ => UntaggedValue::int(0).into_value(&tag),
-                            })
-                            .collect::<Vec<_>>();
-                        UntaggedValue::Table(data).into_value(&tag)
-                    }
-                    _ => UntaggedValue::Table(vec![]).into_value(&tag),
-                })
-                .collect();
-
-            UntaggedValue::Table(datasets).into_value(&tag)
-        }
-        other => other.clone(),
+fn given_edges(edges: &Value) -> Result<Vec<f64>, ShellError> {
+    let numbers = match &edges.value {
+        UntaggedValue::Table(values) => numbers(values)?,
+        _ => numbers(std::slice::from_ref(edges))?,
     };
 
-    Ok(results)
+    if numbers.len() < 2 || numbers.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return Err(ShellError::labeled_error(
+            "Expected increasing edges",
+            "requires at least two edges, in increasing order",
+            &edges.tag,
+        ));
+    }
+
+    Ok(numbers)
+}
+
+/// The number of numbers in each bin between the edges. Every bin includes its lower edge, and the
+/// last one its upper edge too. Numbers outside of the edges aren't counted.
+fn binned(numbers: &[f64], edges: &[f64]) -> Vec<(Value, u64)> {
+    let bins = edges.len().saturating_sub(1);
+    let mut counts = vec![0; bins];
+
+    for number in numbers {
+        let bin = edges.windows(2).enumerate().position(|(idx, pair)| {
+            pair[0] <= *number && (*number < pair[1] || (idx == bins - 1 && *number <= pair[1]))
+        });
+
+        if let Some(bin) = bin {
+            counts[bin] += 1;
+        }
+    }
+
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(idx, count)| {
+            let label = format!(
+                "{} - {}",
+                format_edge(edges[idx]),
+                format_edge(edges[idx + 1])
+            );
+            (UntaggedValue::string(label).into_untagged_value(), count)
+        })
+        .collect()
+}
+
+fn format_edge(edge: f64) -> String {
+    format!("{:.2}", edge)
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+/// The percentage `count` is of `total`, rounded to two decimal places.
+fn percent(count: u64, total: u64) -> BigDecimal {
+    if total == 0 {
+        return BigDecimal::from(0);
+    }
+
+    let hundredths = (count * 10_000 + total / 2) / total;
+    BigDecimal::new(BigInt::from(hundredths), 2)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Histogram;
+    use super::{bar, binned, even_edges, percent, Histogram, MAX_BINS};
+    use bigdecimal::BigDecimal;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::TaggedItem;
+    use std::str::FromStr;
+
+    fn string(input: impl Into<String>) -> Value {
+        UntaggedValue::string(input.into()).into_untagged_value()
+    }
+
+    #[test]
+    fn counts_numbers_in_even_bins() {
+        let numbers = vec![1.0, 2.0, 2.5, 4.0, 5.0];
+        let edges = even_edges(&numbers, &2.tagged_unknown()).expect("edges");
+
+        assert_eq!(
+            binned(&numbers, &edges),
+            vec![(string("1 - 3"), 3), (string("3 - 5"), 2)]
+        );
+    }
+
+    #[test]
+    fn ignores_numbers_outside_of_the_edges() {
+        assert_eq!(
+            binned(&[-1.0, 0.0, 9.5, 10.0, 100.0, 101.0], &[0.0, 10.0, 100.0]),
+            vec![(string("0 - 10"), 2), (string("10 - 100"), 2)]
+        );
+    }
+
+    #[test]
+    fn refuses_to_make_too_many_bins() {
+        assert!(even_edges(&[1.0, 2.0], &(MAX_BINS + 1).tagged_unknown()).is_err());
+    }
+
+    #[test]
+    fn draws_empty_bars_when_nothing_was_counted() {
+        assert_eq!(bar(0, 0), "");
+        assert_eq!(bar(1, 4), "*".repeat(25));
+    }
+
+    #[test]
+    fn rounds_percentages() {
+        assert_eq!(
+            percent(1, 3),
+            BigDecimal::from_str("33.33").expect("decimal")
+        );
+        assert_eq!(
+            percent(2, 3),
+            BigDecimal::from_str("66.67").expect("decimal")
+        );
+    }
 
     #[test]
     fn examples_work_as_expected() {
@@ -1,13 +1,19 @@
 use crate::cli::History as HistoryFile;
 use crate::commands::WholeStreamCommand;
+use crate::data::config;
+use crate::data::history::{self, fuzzy_match, HistoryEntry};
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tagged;
 
 pub struct History;
 
+#[derive(Deserialize)]
+pub struct HistoryArgs {
+    search: Option<Tagged<String>>,
+}
+
 #[async_trait]
 impl WholeStreamCommand for History {
     fn name(&self) -> &str {
@@ -15,11 +21,16 @@ impl WholeStreamCommand for History {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("history")
+        Signature::build("history").named(
+            "search",
+            SyntaxShape::String,
+            "only give the commands that have the letters of the text in order, not minding case",
+            Some('s'),
+        )
     }
 
     fn usage(&self) -> &str {
-        "Display command history."
+        "Display command history, with when each command last ran, how long it took and its exit status."
     }
 
     async fn run(
@@ -27,33 +38,82 @@ impl WholeStreamCommand for History {
         args: CommandArgs,
         registry: &CommandRegistry,
     ) -> Result<OutputStream, ShellError> {
-        history(args, registry)
+        history(args, registry).await
     }
-}
 
-fn history(args: CommandArgs, _registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
-    let tag = args.call_info.name_tag;
-    let history_path = HistoryFile::path();
-    let file = File::open(history_path);
-    if let Ok(file) = file {
-        let reader = BufReader::new(file);
-        let output = reader.lines().filter_map(move |line| match line {
-            Ok(line) => Some(ReturnSuccess::value(
-                UntaggedValue::string(line).into_value(tag.clone()),
-            )),
-            Err(_) => None,
-        });
-
-        Ok(futures::stream::iter(output).to_output_stream())
-    } else {
-        Err(ShellError::labeled_error(
-            "Could not open history",
-            "history file could not be opened",
-            tag,
-        ))
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the commands that failed",
+                example: "history | where exit_status != 0",
+                result: None,
+            },
+            Example {
+                description: "Find the commands that opened a csv file, like `open data.csv`",
+                example: "history --search ocsv",
+                result: None,
+            },
+        ]
     }
 }
 
+async fn history(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let tag = args.call_info.name_tag.clone();
+    let (HistoryArgs { search }, _) = args.process(&registry).await?;
+
+    let history_size = config::config(tag.clone())?
+        .get("history_size")
+        .map(|i| i.value.expect_int())
+        .unwrap_or(100_000);
+    let entries = history::compact(history::read(&HistoryFile::path()), history_size as usize);
+
+    let rows: Vec<Value> = entries
+        .into_iter()
+        .enumerate()
+        .filter(|(_, entry)| match &search {
+            Some(search) => fuzzy_match(&entry.command, &search.item),
+            None => true,
+        })
+        .map(|(index, entry)| entry_to_value(index, entry, &tag))
+        .collect();
+
+    Ok(futures::stream::iter(rows.into_iter().map(ReturnSuccess::value)).to_output_stream())
+}
+
+fn entry_to_value(index: usize, entry: HistoryEntry, tag: &Tag) -> Value {
+    let mut dict = TaggedDictBuilder::new(tag);
+
+    dict.insert_untagged("index", UntaggedValue::int(index));
+    dict.insert_untagged("command", UntaggedValue::string(entry.command));
+    dict.insert_untagged(
+        "start_time",
+        entry
+            .start_time
+            .map(UntaggedValue::date)
+            .unwrap_or_else(UntaggedValue::nothing),
+    );
+    dict.insert_untagged(
+        "duration_ms",
+        entry
+            .duration_ms
+            .map(UntaggedValue::int)
+            .unwrap_or_else(UntaggedValue::nothing),
+    );
+    dict.insert_untagged(
+        "exit_status",
+        entry
+            .exit_status
+            .map(UntaggedValue::int)
+            .unwrap_or_else(UntaggedValue::nothing),
+    );
+
+    dict.into_value()
+}
+
 #[cfg(test)]
 mod tests {
     use super::History;
@@ -0,0 +1,137 @@
+use crate::commands::classified::block::{run_block, run_condition};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{hir::Block, Signature, SyntaxShape, UntaggedValue, Value};
+
+pub struct If;
+
+#[derive(Deserialize)]
+pub struct IfArgs {
+    condition: Block,
+    then: Block,
+    rest: Vec<Value>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for If {
+    fn name(&self) -> &str {
+        "if"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("if")
+            .required(
+                "condition",
+                SyntaxShape::Math,
+                "the condition that must hold for the block to run",
+            )
+            .required("then", SyntaxShape::Block, "the block to run if it holds")
+            .rest(
+                SyntaxShape::Any,
+                "else if, with another condition and block, or else with the block to run if none hold",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Run the block of the first condition that holds, or the else block if none of them do. The input is given to the block that is run."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        if_(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Run the block of the condition that holds",
+                example: "if 2 > 1 { echo yes } else { echo no }",
+                result: Some(vec![Value::from("yes")]),
+            },
+            Example {
+                description: "Check conditions in turn with else if",
+                example: "let n = 15; if $n < 10 { echo small } else if $n < 100 { echo medium } else { echo large }",
+                result: Some(vec![Value::from("medium")]),
+            },
+            Example {
+                description: "Give the input to the block that is run",
+                example: "ls | if $(ls | count) > 10 { first 10 } else { sort-by name }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn if_(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let mut context = Context::from_args(&args, &registry);
+    let scope = args.call_info.scope.clone();
+
+    let (
+        IfArgs {
+            condition,
+            then,
+            rest,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    let mut blocks = vec![condition, then];
+    for value in rest {
+        match value.value {
+            UntaggedValue::Block(block) => blocks.push(block),
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Expected a block",
+                    "expected a block",
+                    value.tag,
+                ))
+            }
+        }
+    }
+
+    // The blocks go in pairs of a condition and the block it runs, with the else block last
+    for arm in blocks.chunks(2) {
+        let block = match arm {
+            [condition, block] => {
+                if !run_condition(condition, &mut context, &scope.it, &scope.vars, &scope.env)
+                    .await?
+                {
+                    continue;
+                }
+                block
+            }
+            [otherwise] => otherwise,
+            _ => continue,
+        };
+
+        return Ok(run_block(
+            block,
+            &mut context,
+            input,
+            &scope.it,
+            &scope.vars,
+            &scope.env,
+        )
+        .await?
+        .to_output_stream());
+    }
+
+    Ok(OutputStream::empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::If;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(If {})
+    }
+}
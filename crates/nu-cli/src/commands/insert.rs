@@ -1,10 +1,12 @@
+use crate::commands::classified::block::run_block;
 use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{ColumnPath, ReturnSuccess, Scope, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_value_ext::ValueExt;
 
+use futures::stream::once;
 pub struct Insert;
 
 #[derive(Deserialize)]
@@ -28,8 +30,8 @@ impl WholeStreamCommand for Insert {
             )
             .required(
                 "value",
-                SyntaxShape::String,
-                "the value to give the cell(s)",
+                SyntaxShape::Any,
+                "the value to give the cell(s), or a block run for each row to compute it",
             )
     }
 
@@ -44,29 +46,106 @@ impl WholeStreamCommand for Insert {
     ) -> Result<OutputStream, ShellError> {
         insert(args, registry).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Insert a column with the same value in every row",
+                example: "ls | insert is_on_a_computer yes_obviously",
+                result: None,
+            },
+            Example {
+                description: "Insert a column computed from each row",
+                example: "ls | insert double_size { = $it.size * 2 }",
+                result: None,
+            },
+        ]
+    }
 }
 
-async fn insert(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
-    let registry = registry.clone();
+async fn process_row(
+    scope: Arc<Scope>,
+    mut context: Arc<Context>,
+    input: Value,
+    mut value: Arc<Value>,
+    column: Arc<ColumnPath>,
+) -> Result<OutputStream, ShellError> {
+    let value = Arc::make_mut(&mut value);
+
+    let value = match value {
+        Value {
+            value: UntaggedValue::Block(block),
+            tag,
+        } => {
+            let for_block = input.clone();
+            let input_stream = once(async { Ok(for_block) }).to_input_stream();
+
+            let mut stream = run_block(
+                block,
+                Arc::make_mut(&mut context),
+                input_stream,
+                &input,
+                &scope.vars,
+                &scope.env,
+            )
+            .await?;
 
-    let (InsertArgs { column, value }, input) = args.process(&registry).await?;
+            let errors = context.get_errors();
+            if let Some(error) = errors.first() {
+                return Err(error.clone());
+            }
+
+            match stream.next().await {
+                Some(result) => result,
+                None => UntaggedValue::nothing().into_value(tag.clone()),
+            }
+        }
+        _ => value.clone(),
+    };
+
+    Ok(match input {
+        obj @ Value {
+            value: UntaggedValue::Row(_),
+            ..
+        } => match obj.insert_data_at_column_path(&column, value) {
+            Ok(v) => OutputStream::one(ReturnSuccess::value(v)),
+            Err(err) => OutputStream::one(Err(err)),
+        },
+
+        Value { tag, .. } => OutputStream::one(Err(ShellError::labeled_error(
+            "Unrecognized type in stream",
+            "original value",
+            tag,
+        ))),
+    })
+}
+
+async fn insert(
+    raw_args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let scope = Arc::new(raw_args.call_info.scope.clone());
+    let context = Arc::new(Context::from_raw(&raw_args, &registry));
+    let (InsertArgs { column, value }, input) = raw_args.process(&registry).await?;
+    let value = Arc::new(value);
+    let column = Arc::new(column);
 
     Ok(input
-        .map(move |row| match row {
-            Value {
-                value: UntaggedValue::Row(_),
-                ..
-            } => match row.insert_data_at_column_path(&column, value.clone()) {
-                Ok(v) => Ok(ReturnSuccess::Value(v)),
-                Err(err) => Err(err),
-            },
+        .then(move |input| {
+            let value = value.clone();
+            let scope = scope.clone();
+            let context = context.clone();
+            let column = column.clone();
 
-            Value { tag, .. } => Err(ShellError::labeled_error(
-                "Unrecognized type in stream",
-                "original value",
-                tag,
-            )),
+            async {
+                match process_row(scope, context, input, value, column).await {
+                    Ok(s) => s,
+                    Err(e) => OutputStream::one(Err(e)),
+                }
+            }
         })
+        .flatten()
         .to_output_stream())
 }
 
@@ -0,0 +1,166 @@
+use crate::commands::into::utils::{cant_convert, convert_values};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use num_traits::ToPrimitive;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    format: Option<Tagged<String>>,
+    #[serde(rename(deserialize = "ignore-errors"))]
+    ignore_errors: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "into date"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into date")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally convert the cells at the given column paths",
+            )
+            .named(
+                "format",
+                SyntaxShape::String,
+                "the strftime format of the text (default: RFC 3339, RFC 2822, YYYY-MM-DD HH:MM:SS or YYYY-MM-DD)",
+                Some('f'),
+            )
+            .switch(
+                "ignore-errors",
+                "turn the values that can't be converted into nothing",
+                Some('i'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert text, or seconds since the Unix epoch, into dates."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let (
+            Arguments {
+                rest,
+                format,
+                ignore_errors,
+            },
+            input,
+        ) = args.process(&registry).await?;
+
+        let format = format.map(|f| f.item);
+
+        Ok(convert_values(input, rest, ignore_errors, move |value| {
+            action(value, format.as_deref())
+        }))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert text into a date",
+                example: "echo '2020-06-01' | into date",
+                result: Some(vec![UntaggedValue::date(
+                    Utc.ymd(2020, 6, 1).and_hms(0, 0, 0),
+                )
+                .into()]),
+            },
+            Example {
+                description: "Convert a column of dates written the European way",
+                example: "open events.csv | into date when --format '%d.%m.%Y %H:%M'",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn parse_date(text: &str, format: Option<&str>) -> Option<DateTime<Utc>> {
+    let text = text.trim();
+
+    if let Some(format) = format {
+        return DateTime::parse_from_str(text, format)
+            .map(|d| d.with_timezone(&Utc))
+            .or_else(|_| {
+                NaiveDateTime::parse_from_str(text, format).map(|d| Utc.from_utc_datetime(&d))
+            })
+            .or_else(|_| {
+                NaiveDate::parse_from_str(text, format)
+                    .map(|d| Utc.from_utc_datetime(&d.and_hms(0, 0, 0)))
+            })
+            .ok();
+    }
+
+    DateTime::parse_from_rfc3339(text)
+        .or_else(|_| DateTime::parse_from_rfc2822(text))
+        .map(|d| d.with_timezone(&Utc))
+        .or_else(|_| {
+            NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S")
+                .map(|d| Utc.from_utc_datetime(&d))
+        })
+        .or_else(|_| {
+            NaiveDate::parse_from_str(text, "%Y-%m-%d")
+                .map(|d| Utc.from_utc_datetime(&d.and_hms(0, 0, 0)))
+        })
+        .ok()
+}
+
+fn action(input: &Value, format: Option<&str>) -> Result<UntaggedValue, ShellError> {
+    let date = match &input.value {
+        UntaggedValue::Primitive(Primitive::Date(d)) => Some(*d),
+        UntaggedValue::Primitive(Primitive::Int(secs)) => {
+            secs.to_i64().map(|secs| Utc.timestamp(secs, 0))
+        }
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => parse_date(s, format),
+        _ => None,
+    };
+
+    match date {
+        Some(date) => Ok(UntaggedValue::date(date)),
+        None => Err(cant_convert(input, "date")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, SubCommand};
+    use chrono::{TimeZone, Utc};
+    use nu_plugin::test_helpers::value::{int, string};
+    use nu_protocol::UntaggedValue;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn converts_text_in_known_and_given_formats() {
+        let expected = UntaggedValue::date(Utc.ymd(1984, 11, 16).and_hms(8, 0, 0));
+
+        assert_eq!(
+            action(&string("1984-11-16T08:00:00+00:00"), None).unwrap(),
+            expected
+        );
+        assert_eq!(
+            action(&string("16.11.1984 08:00"), Some("%d.%m.%Y %H:%M")).unwrap(),
+            expected
+        );
+        assert_eq!(action(&int(469_440_000), None).unwrap(), expected);
+        assert!(action(&string("someday"), None).is_err());
+    }
+}
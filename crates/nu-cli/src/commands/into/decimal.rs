@@ -0,0 +1,114 @@
+use crate::commands::into::utils::{cant_convert, convert_values};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use bigdecimal::BigDecimal;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+use std::str::FromStr;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    #[serde(rename(deserialize = "ignore-errors"))]
+    ignore_errors: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "into decimal"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into decimal")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally convert the cells at the given column paths",
+            )
+            .switch(
+                "ignore-errors",
+                "turn the values that can't be converted into nothing",
+                Some('i'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert values into decimals."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let (
+            Arguments {
+                rest,
+                ignore_errors,
+            },
+            input,
+        ) = args.process(&registry).await?;
+
+        Ok(convert_values(input, rest, ignore_errors, action))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert text into a decimal",
+                example: "echo '1.5' | into decimal",
+                result: Some(vec![UntaggedValue::decimal(1.5).into()]),
+            },
+            Example {
+                description: "Convert the price column of a table, leaving unreadable prices empty",
+                example: "open stock.csv | into decimal price --ignore-errors",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn action(input: &Value) -> Result<UntaggedValue, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => Ok(UntaggedValue::decimal(d.clone())),
+        UntaggedValue::Primitive(Primitive::Int(i)) => {
+            Ok(UntaggedValue::decimal(BigDecimal::from(i.clone())))
+        }
+        UntaggedValue::Primitive(Primitive::Bytes(b)) => {
+            Ok(UntaggedValue::decimal(BigDecimal::from(*b)))
+        }
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => match BigDecimal::from_str(s.trim()) {
+            Ok(d) => Ok(UntaggedValue::decimal(d)),
+            Err(_) => Err(cant_convert(input, "decimal")),
+        },
+        _ => Err(cant_convert(input, "decimal")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, SubCommand};
+    use nu_plugin::test_helpers::value::{int, string};
+    use nu_protocol::UntaggedValue;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn converts_text_and_integers() {
+        assert_eq!(
+            action(&string("0.25")).unwrap(),
+            UntaggedValue::decimal(0.25)
+        );
+        assert_eq!(action(&int(3)).unwrap(), UntaggedValue::decimal(3));
+        assert!(action(&string("a quarter")).is_err());
+    }
+}
@@ -0,0 +1,219 @@
+use crate::commands::into::utils::{cant_convert, convert_values};
+use crate::commands::math::round::round;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use bigdecimal::{BigDecimal, ToPrimitive};
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+use std::str::FromStr;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    #[serde(rename(deserialize = "ignore-errors"))]
+    ignore_errors: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "into duration"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into duration")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally convert the cells at the given column paths",
+            )
+            .switch(
+                "ignore-errors",
+                "turn the values that can't be converted into nothing",
+                Some('i'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert numbers of seconds, or text like 1h30m or 1:30:00, into durations."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let (
+            Arguments {
+                rest,
+                ignore_errors,
+            },
+            input,
+        ) = args.process(&registry).await?;
+
+        Ok(convert_values(input, rest, ignore_errors, action))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert text into a duration",
+                example: "echo '1h30m' | into duration",
+                result: Some(vec![UntaggedValue::duration(5400).into()]),
+            },
+            Example {
+                description: "Convert a number of seconds into a duration",
+                example: "echo 90 | into duration",
+                result: Some(vec![UntaggedValue::duration(90).into()]),
+            },
+            Example {
+                description: "Convert a column of elapsed times into durations",
+                example: "open jobs.csv | into duration elapsed",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// The number of seconds in a unit, like `h`, `hr` or `hours`.
+fn unit_seconds(unit: &str) -> Option<i64> {
+    match unit {
+        "w" | "wk" | "week" | "weeks" => Some(7 * 24 * 60 * 60),
+        "d" | "day" | "days" => Some(24 * 60 * 60),
+        "h" | "hr" | "hrs" | "hour" | "hours" => Some(60 * 60),
+        "m" | "min" | "mins" | "minute" | "minutes" => Some(60),
+        "s" | "sec" | "secs" | "second" | "seconds" => Some(1),
+        _ => None,
+    }
+}
+
+/// Parses durations written as numbers with units, like `1h30m`, `90s` or `1.5 days`, or as
+/// clock times, like `1:30:00`. Bare numbers are seconds.
+fn parse_duration(text: &str) -> Option<i64> {
+    let text = text.trim().to_lowercase();
+    let (sign, text) = match text.strip_prefix('-') {
+        Some(rest) => (-1, rest.trim_start()),
+        None => (1, &text[..]),
+    };
+
+    if text.is_empty() {
+        return None;
+    }
+
+    let seconds = if text.contains(':') {
+        parse_clock(text)?
+    } else {
+        parse_with_units(text)?
+    };
+
+    Some(sign * seconds)
+}
+
+/// Parses `m:ss`, `h:mm:ss` and `d:hh:mm:ss`, the way durations are shown.
+fn parse_clock(text: &str) -> Option<i64> {
+    let parts = text
+        .split(':')
+        .map(|part| part.trim().parse::<i64>().ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    if parts.len() > 4 || parts.iter().any(|part| *part < 0) {
+        return None;
+    }
+
+    let scales = [1, 60, 60 * 60, 24 * 60 * 60];
+
+    Some(
+        parts
+            .iter()
+            .rev()
+            .zip(scales.iter())
+            .map(|(part, scale)| part * scale)
+            .sum(),
+    )
+}
+
+fn parse_with_units(text: &str) -> Option<i64> {
+    let mut total = BigDecimal::from(0);
+    let mut rest = text.trim_start();
+
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let number = BigDecimal::from_str(&rest[..number_len]).ok()?;
+        rest = rest[number_len..].trim_start();
+
+        let unit_len = rest
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(rest.len());
+        let multiplier = match &rest[..unit_len] {
+            "" if total == BigDecimal::from(0) && unit_len == rest.len() => 1,
+            unit => unit_seconds(unit)?,
+        };
+        rest = rest[unit_len..].trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+
+        total += number * BigDecimal::from(multiplier);
+    }
+
+    round(&total, 0).to_i64()
+}
+
+fn action(input: &Value) -> Result<UntaggedValue, ShellError> {
+    let seconds = match &input.value {
+        UntaggedValue::Primitive(Primitive::Duration(secs)) => Some(*secs),
+        UntaggedValue::Primitive(Primitive::Int(i)) => i.to_i64(),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => round(d, 0).to_i64(),
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => parse_duration(s),
+        _ => None,
+    };
+
+    match seconds {
+        Some(seconds) => Ok(UntaggedValue::duration(seconds)),
+        None => Err(cant_convert(input, "duration")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, parse_duration, SubCommand};
+    use nu_plugin::test_helpers::value::{decimal, int, string};
+    use nu_protocol::UntaggedValue;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn parses_durations_with_units() {
+        assert_eq!(parse_duration("1h30m"), Some(5400));
+        assert_eq!(parse_duration("90s"), Some(90));
+        assert_eq!(parse_duration("2 days, 4 hours"), Some(187_200));
+        assert_eq!(parse_duration("1.5wk"), Some(907_200));
+        assert_eq!(parse_duration("-5min"), Some(-300));
+        assert_eq!(parse_duration("42"), Some(42));
+        assert_eq!(parse_duration("1h30"), None);
+        assert_eq!(parse_duration("3 fortnights"), None);
+        assert_eq!(parse_duration(""), None);
+    }
+
+    #[test]
+    fn parses_clock_times() {
+        assert_eq!(parse_duration("1:30"), Some(90));
+        assert_eq!(parse_duration("1:30:00"), Some(5400));
+        assert_eq!(parse_duration("2:01:00:05"), Some(176_405));
+        assert_eq!(parse_duration("1::30"), None);
+    }
+
+    #[test]
+    fn converts_numbers_of_seconds() {
+        assert_eq!(action(&int(90)).unwrap(), UntaggedValue::duration(90));
+        assert_eq!(action(&decimal(1.6)).unwrap(), UntaggedValue::duration(2));
+        assert!(action(&string("soon")).is_err());
+    }
+}
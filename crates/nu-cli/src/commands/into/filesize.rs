@@ -0,0 +1,135 @@
+use crate::commands::into::utils::{cant_convert, convert_values};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use bigdecimal::{BigDecimal, ToPrimitive};
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+use std::str::FromStr;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    #[serde(rename(deserialize = "ignore-errors"))]
+    ignore_errors: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "into filesize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into filesize")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally convert the cells at the given column paths",
+            )
+            .switch(
+                "ignore-errors",
+                "turn the values that can't be converted into nothing",
+                Some('i'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert numbers of bytes, or text like 1.5mb, into file sizes."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let (
+            Arguments {
+                rest,
+                ignore_errors,
+            },
+            input,
+        ) = args.process(&registry).await?;
+
+        Ok(convert_values(input, rest, ignore_errors, action))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert text into a file size",
+                example: "echo '2kb' | into filesize",
+                result: Some(vec![UntaggedValue::bytes(2048u64).into()]),
+            },
+            Example {
+                description: "Convert a column of byte counts into file sizes",
+                example: "open downloads.csv | into filesize bytes",
+                result: None,
+            },
+        ]
+    }
+}
+
+const UNITS: [(&str, u64); 6] = [
+    ("pb", 1 << 50),
+    ("tb", 1 << 40),
+    ("gb", 1 << 30),
+    ("mb", 1 << 20),
+    ("kb", 1 << 10),
+    ("b", 1),
+];
+
+fn parse_filesize(text: &str) -> Option<u64> {
+    let text = text.trim().to_lowercase();
+    let (number, multiplier) = UNITS
+        .iter()
+        .find(|(unit, _)| text.ends_with(unit))
+        .map(|(unit, multiplier)| (text[..text.len() - unit.len()].trim(), *multiplier))
+        .unwrap_or((&text[..], 1));
+
+    let size = BigDecimal::from_str(number).ok()? * BigDecimal::from(multiplier);
+    size.with_scale(0).to_u64()
+}
+
+fn action(input: &Value) -> Result<UntaggedValue, ShellError> {
+    let bytes = match &input.value {
+        UntaggedValue::Primitive(Primitive::Bytes(b)) => Some(*b),
+        UntaggedValue::Primitive(Primitive::Int(i)) => i.to_u64(),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => d.with_scale(0).to_u64(),
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => parse_filesize(s),
+        _ => None,
+    };
+
+    match bytes {
+        Some(bytes) => Ok(UntaggedValue::bytes(bytes)),
+        None => Err(cant_convert(input, "filesize")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, SubCommand};
+    use nu_plugin::test_helpers::value::{int, string};
+    use nu_protocol::UntaggedValue;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn converts_text_with_units_and_byte_counts() {
+        assert_eq!(
+            action(&string("1.5 MB")).unwrap(),
+            UntaggedValue::bytes(1_572_864u64)
+        );
+        assert_eq!(action(&string("12")).unwrap(), UntaggedValue::bytes(12u64));
+        assert_eq!(action(&int(100)).unwrap(), UntaggedValue::bytes(100u64));
+        assert!(action(&int(-1)).is_err());
+        assert!(action(&string("big")).is_err());
+    }
+}
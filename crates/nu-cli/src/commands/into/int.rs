@@ -0,0 +1,123 @@
+use crate::commands::into::utils::{cant_convert, convert_values};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use bigdecimal::{BigDecimal, ToPrimitive};
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+use num_bigint::{BigInt, ToBigInt};
+use std::str::FromStr;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    #[serde(rename(deserialize = "ignore-errors"))]
+    ignore_errors: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "into int"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into int")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally convert the cells at the given column paths",
+            )
+            .switch(
+                "ignore-errors",
+                "turn the values that can't be converted into nothing",
+                Some('i'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert values into integers, truncating decimals."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let (
+            Arguments {
+                rest,
+                ignore_errors,
+            },
+            input,
+        ) = args.process(&registry).await?;
+
+        Ok(convert_values(input, rest, ignore_errors, action))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert text into an integer",
+                example: "echo '255' | into int",
+                result: Some(vec![UntaggedValue::int(255).into()]),
+            },
+            Example {
+                description: "Convert the count column of a table",
+                example: "open stock.csv | into int count",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn action(input: &Value) -> Result<UntaggedValue, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) => Ok(UntaggedValue::int(i.clone())),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => match d.with_scale(0).to_bigint() {
+            Some(i) => Ok(UntaggedValue::int(i)),
+            None => Err(cant_convert(input, "int")),
+        },
+        UntaggedValue::Primitive(Primitive::Bytes(b)) => Ok(UntaggedValue::int(*b)),
+        UntaggedValue::Primitive(Primitive::Duration(secs)) => Ok(UntaggedValue::int(*secs)),
+        UntaggedValue::Primitive(Primitive::Boolean(b)) => {
+            Ok(UntaggedValue::int(if *b { 1 } else { 0 }))
+        }
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => {
+            let s = s.trim();
+            if let Ok(i) = BigInt::from_str(s) {
+                return Ok(UntaggedValue::int(i));
+            }
+
+            match BigDecimal::from_str(s).ok().and_then(|d| d.to_i64()) {
+                Some(i) => Ok(UntaggedValue::int(i)),
+                None => Err(cant_convert(input, "int")),
+            }
+        }
+        _ => Err(cant_convert(input, "int")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, SubCommand};
+    use nu_plugin::test_helpers::value::{decimal, string};
+    use nu_protocol::UntaggedValue;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn converts_text_and_truncates_decimals() {
+        assert_eq!(action(&string(" 42 ")).unwrap(), UntaggedValue::int(42));
+        assert_eq!(action(&string("2.9")).unwrap(), UntaggedValue::int(2));
+        assert_eq!(action(&decimal(-2.5)).unwrap(), UntaggedValue::int(-2));
+        assert!(action(&string("forty-two")).is_err());
+    }
+}
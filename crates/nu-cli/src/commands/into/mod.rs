@@ -0,0 +1,16 @@
+mod command;
+mod date;
+mod decimal;
+mod duration;
+mod filesize;
+mod int;
+mod string;
+mod utils;
+
+pub use command::Command as IntoCommand;
+pub use date::SubCommand as IntoDate;
+pub use decimal::SubCommand as IntoDecimal;
+pub use duration::SubCommand as IntoDuration;
+pub use filesize::SubCommand as IntoFilesize;
+pub use int::SubCommand as IntoInt;
+pub use string::SubCommand as IntoString;
@@ -0,0 +1,114 @@
+use crate::commands::into::utils::{cant_convert, convert_values};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    format_primitive, ColumnPath, Primitive, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_value_ext::as_string;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    #[serde(rename(deserialize = "ignore-errors"))]
+    ignore_errors: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "into string"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into string")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally convert the cells at the given column paths",
+            )
+            .switch(
+                "ignore-errors",
+                "turn the values that can't be converted into nothing",
+                Some('i'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert values into text."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let (
+            Arguments {
+                rest,
+                ignore_errors,
+            },
+            input,
+        ) = args.process(&registry).await?;
+
+        Ok(convert_values(input, rest, ignore_errors, action))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert an integer into text",
+                example: "echo 42 | into string",
+                result: Some(vec![Value::from("42")]),
+            },
+            Example {
+                description:
+                    "Convert the size column of a listing into the number of bytes as text",
+                example: "ls | into string size",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn action(input: &Value) -> Result<UntaggedValue, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => Ok(UntaggedValue::string(s)),
+        UntaggedValue::Primitive(Primitive::Date(d)) => Ok(UntaggedValue::string(d.to_rfc3339())),
+        UntaggedValue::Primitive(Primitive::Nothing) => Ok(UntaggedValue::string("")),
+        UntaggedValue::Primitive(primitive) => match as_string(input) {
+            Ok(s) => Ok(UntaggedValue::string(s)),
+            Err(_) => Ok(UntaggedValue::string(format_primitive(primitive, None))),
+        },
+        _ => Err(cant_convert(input, "string")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, SubCommand};
+    use bigdecimal::BigDecimal;
+    use nu_plugin::test_helpers::value::{decimal, int};
+    use nu_protocol::UntaggedValue;
+    use std::str::FromStr;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn converts_numbers_but_not_rows() {
+        assert_eq!(action(&int(7)).unwrap(), UntaggedValue::string("7"));
+        assert_eq!(
+            action(&decimal(BigDecimal::from_str("1.5").unwrap())).unwrap(),
+            UntaggedValue::string("1.5")
+        );
+        assert!(action(&UntaggedValue::row(indexmap! {}).into_untagged_value()).is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, ReturnSuccess, ShellTypeName, UntaggedValue, Value};
+use nu_value_ext::ValueExt;
+
+/// Converts every input value, or the cells at the given column paths of every row, with
+/// `convert`. Cells that can't be converted are errors, or nothing when ignoring errors.
+pub fn convert_values<F>(
+    input: InputStream,
+    column_paths: Vec<ColumnPath>,
+    ignore_errors: bool,
+    convert: F,
+) -> OutputStream
+where
+    F: Fn(&Value) -> Result<UntaggedValue, ShellError> + Clone + Send + Sync + 'static,
+{
+    let action = move |value: &Value| match convert(value) {
+        Ok(converted) => Ok(converted.into_value(&value.tag)),
+        Err(_) if ignore_errors => Ok(UntaggedValue::nothing().into_value(&value.tag)),
+        Err(err) => Err(err),
+    };
+
+    input
+        .map(move |v| {
+            if column_paths.is_empty() {
+                ReturnSuccess::value(action(&v)?)
+            } else {
+                let mut ret = v;
+
+                for path in &column_paths {
+                    let action = action.clone();
+                    ret = ret.swap_data_by_column_path(path, Box::new(move |old| action(old)))?;
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream()
+}
+
+/// The error for a value that can't be converted into the given type.
+pub fn cant_convert(value: &Value, into: &str) -> ShellError {
+    let label = match &value.value {
+        UntaggedValue::Primitive(_) => match nu_value_ext::as_string(value) {
+            Ok(text) => format!("can't convert '{}' into {}", text, into),
+            Err(_) => format!("can't convert {} into {}", value.type_name(), into),
+        },
+        other => format!("can't convert {} into {}", other.type_name(), into),
+    };
+
+    ShellError::labeled_error(
+        format!("Could not convert into {}", into),
+        label,
+        &value.tag,
+    )
+}
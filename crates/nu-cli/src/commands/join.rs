@@ -0,0 +1,357 @@
+use crate::commands::classified::block::run_block;
+use crate::commands::group_by::suggestions;
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use indexmap::{IndexMap, IndexSet};
+use nu_errors::ShellError;
+use nu_protocol::{
+    hir::Block, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+use nu_value_ext::as_string;
+
+pub struct Join;
+
+#[derive(Deserialize)]
+pub struct JoinArgs {
+    block: Block,
+    rest: Vec<Tagged<String>>,
+    inner: bool,
+    left: bool,
+    right: bool,
+    outer: bool,
+    suffix: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Join {
+    fn name(&self) -> &str {
+        "join"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("join")
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the block to run to get the table to join with",
+            )
+            .rest(SyntaxShape::String, "the key column(s) to join on")
+            .switch("inner", "only keep rows with a match in both tables (default)", Some('i'))
+            .switch("left", "keep every row of the input table", Some('l'))
+            .switch("right", "keep every row of the joined table", Some('r'))
+            .switch("outer", "keep every row of both tables", Some('o'))
+            .named(
+                "suffix",
+                SyntaxShape::String,
+                "the suffix for joined columns whose names collide with the input's (default: _right)",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Join the table with another one on the given key columns."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        join(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Attach the owner of each file listed in another table",
+                example: "ls | join { open owners.csv } name",
+                result: None,
+            },
+            Example {
+                description: "Keep files without an owner too, suffixing colliding columns",
+                example: "ls | join --left --suffix _owner { open owners.csv } name",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum JoinKind {
+    Inner,
+    Left,
+    Right,
+    Outer,
+}
+
+async fn join(
+    raw_args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let scope = raw_args.call_info.scope.clone();
+    let mut context = Context::from_raw(&raw_args, &registry);
+    let name = raw_args.call_info.name_tag.clone();
+    let (
+        JoinArgs {
+            block,
+            rest: keys,
+            inner,
+            left,
+            right,
+            outer,
+            suffix,
+        },
+        input,
+    ) = raw_args.process(&registry).await?;
+
+    let kind = match (inner, left, right, outer) {
+        (_, false, false, false) => JoinKind::Inner,
+        (false, true, false, false) => JoinKind::Left,
+        (false, false, true, false) => JoinKind::Right,
+        (false, false, false, true) => JoinKind::Outer,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Conflicting join kinds",
+                "only one of --inner, --left, --right or --outer can be given",
+                name,
+            ))
+        }
+    };
+
+    if keys.is_empty() {
+        return Err(ShellError::labeled_error(
+            "Expected key columns",
+            "requires at least one column to join on",
+            name,
+        ));
+    }
+
+    let suffix = suffix
+        .map(|s| s.item)
+        .unwrap_or_else(|| "_right".to_string());
+
+    let left_rows: Vec<Value> = input.collect().await;
+    let right_rows: Vec<Value> = run_block(
+        &block,
+        &mut context,
+        InputStream::empty(),
+        &scope.it,
+        &scope.vars,
+        &scope.env,
+    )
+    .await?
+    .drain_vec()
+    .await;
+
+    if let Some(err) = context.get_errors().first() {
+        return Err(err.clone());
+    }
+
+    let joined = join_tables(&left_rows, &right_rows, &keys, kind, &suffix, &name)?;
+
+    Ok(futures::stream::iter(joined.into_iter().map(ReturnSuccess::value)).to_output_stream())
+}
+
+fn columns_of(rows: &[Value]) -> IndexSet<String> {
+    rows.iter().flat_map(|row| row.data_descriptors()).collect()
+}
+
+fn key_of(row: &Value, keys: &[Tagged<String>]) -> Result<Vec<String>, ShellError> {
+    keys.iter()
+        .map(|key| match row.get_data_by_key(key.borrow_spanned()) {
+            Some(value) => as_string(&value),
+            None => Err(suggestions(key.borrow_tagged(), row)),
+        })
+        .collect()
+}
+
+fn join_tables(
+    left_rows: &[Value],
+    right_rows: &[Value],
+    keys: &[Tagged<String>],
+    kind: JoinKind,
+    suffix: &str,
+    tag: &Tag,
+) -> Result<Vec<Value>, ShellError> {
+    let key_names: IndexSet<String> = keys.iter().map(|key| key.item.clone()).collect();
+    let left_columns = columns_of(left_rows);
+    let right_columns: Vec<(String, String)> = columns_of(right_rows)
+        .into_iter()
+        .filter(|column| !key_names.contains(column))
+        .map(|column| {
+            let joined_name = if left_columns.contains(&column) {
+                format!("{}{}", column, suffix)
+            } else {
+                column.clone()
+            };
+            (column, joined_name)
+        })
+        .collect();
+
+    let mut right_index: IndexMap<Vec<String>, Vec<usize>> = IndexMap::new();
+    for (idx, row) in right_rows.iter().enumerate() {
+        right_index.entry(key_of(row, keys)?).or_default().push(idx);
+    }
+
+    let combine = |left: Option<&Value>, right: Option<&Value>| -> Value {
+        let mut out = TaggedDictBuilder::new(tag);
+
+        for column in &left_columns {
+            let value = match (left, right) {
+                (Some(left), _) => left.get_data_by_key(column[..].spanned_unknown()),
+                (None, Some(right)) if key_names.contains(column) => {
+                    right.get_data_by_key(column[..].spanned_unknown())
+                }
+                _ => None,
+            };
+            out.insert_value(
+                column.clone(),
+                value.unwrap_or_else(|| UntaggedValue::nothing().into_value(tag)),
+            );
+        }
+
+        for (column, joined_name) in &right_columns {
+            let value = right.and_then(|right| right.get_data_by_key(column[..].spanned_unknown()));
+            out.insert_value(
+                joined_name.clone(),
+                value.unwrap_or_else(|| UntaggedValue::nothing().into_value(tag)),
+            );
+        }
+
+        out.into_value()
+    };
+
+    let mut output = vec![];
+    let mut matched_right = vec![false; right_rows.len()];
+
+    for left in left_rows {
+        match right_index.get(&key_of(left, keys)?) {
+            Some(matches) => {
+                for idx in matches {
+                    matched_right[*idx] = true;
+                    output.push(combine(Some(left), Some(&right_rows[*idx])));
+                }
+            }
+            None if kind == JoinKind::Left || kind == JoinKind::Outer => {
+                output.push(combine(Some(left), None));
+            }
+            None => {}
+        }
+    }
+
+    if kind == JoinKind::Right || kind == JoinKind::Outer {
+        for (idx, right) in right_rows.iter().enumerate() {
+            if !matched_right[idx] {
+                output.push(combine(None, Some(right)));
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{join_tables, Join, JoinKind};
+    use indexmap::IndexMap;
+    use nu_errors::ShellError;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::*;
+
+    fn string(input: impl Into<String>) -> Value {
+        UntaggedValue::string(input.into()).into_untagged_value()
+    }
+
+    fn row(entries: IndexMap<String, Value>) -> Value {
+        UntaggedValue::row(entries).into_untagged_value()
+    }
+
+    fn nothing() -> Value {
+        UntaggedValue::nothing().into_untagged_value()
+    }
+
+    fn people() -> Vec<Value> {
+        vec![
+            row(indexmap! {"name".into() => string("AR"), "country".into() => string("EC")}),
+            row(indexmap! {"name".into() => string("JT"), "country".into() => string("NZ")}),
+        ]
+    }
+
+    fn countries() -> Vec<Value> {
+        vec![
+            row(indexmap! {"country".into() => string("EC"), "name".into() => string("Ecuador")}),
+            row(
+                indexmap! {"country".into() => string("US"), "name".into() => string("United States")},
+            ),
+        ]
+    }
+
+    fn keys() -> Vec<Tagged<String>> {
+        vec![String::from("country").tagged_unknown()]
+    }
+
+    #[test]
+    fn inner_join_keeps_matching_rows_and_suffixes_collisions() -> Result<(), ShellError> {
+        let joined = join_tables(
+            &people(),
+            &countries(),
+            &keys(),
+            JoinKind::Inner,
+            "_right",
+            &Tag::unknown(),
+        )?;
+
+        assert_eq!(
+            joined,
+            vec![row(indexmap! {
+                "name".into() => string("AR"),
+                "country".into() => string("EC"),
+                "name_right".into() => string("Ecuador"),
+            })]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn outer_join_keeps_unmatched_rows_of_both_tables() -> Result<(), ShellError> {
+        let joined = join_tables(
+            &people(),
+            &countries(),
+            &keys(),
+            JoinKind::Outer,
+            "_c",
+            &Tag::unknown(),
+        )?;
+
+        assert_eq!(joined.len(), 3);
+        assert_eq!(
+            joined[1],
+            row(indexmap! {
+                "name".into() => string("JT"),
+                "country".into() => string("NZ"),
+                "name_c".into() => nothing(),
+            })
+        );
+        assert_eq!(
+            joined[2],
+            row(indexmap! {
+                "name".into() => nothing(),
+                "country".into() => string("US"),
+                "name_c".into() => string("United States"),
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Join {})
+    }
+}
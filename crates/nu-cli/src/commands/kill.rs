@@ -2,18 +2,19 @@ use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, SyntaxShape};
-use nu_source::Tagged;
+use nu_protocol::{Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::{SpannedItem, Tagged, TaggedItem};
+use nu_value_ext::get_data_by_key;
 use std::process::{Command, Stdio};
 
 pub struct Kill;
 
 #[derive(Deserialize)]
 pub struct KillArgs {
-    pub pid: Tagged<u64>,
     pub rest: Vec<Tagged<u64>>,
     pub force: Tagged<bool>,
     pub quiet: Tagged<bool>,
+    pub signal: Option<Value>,
 }
 
 #[async_trait]
@@ -24,14 +25,18 @@ impl WholeStreamCommand for Kill {
 
     fn signature(&self) -> Signature {
         Signature::build("kill")
-            .required(
-                "pid",
+            .rest(
                 SyntaxShape::Int,
-                "process id of process that is to be killed",
+                "the process ids to kill (default: the pids in the pipeline)",
             )
-            .rest(SyntaxShape::Int, "rest of processes to kill")
             .switch("force", "forcefully kill the process", Some('f'))
             .switch("quiet", "won't print anything to the console", Some('q'))
+            .named(
+                "signal",
+                SyntaxShape::Any,
+                "the signal to send, by name like HUP or number like 1 (Unix only, default: TERM)",
+                Some('s'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -58,35 +63,83 @@ impl WholeStreamCommand for Kill {
                 example: "kill --force 12345",
                 result: None,
             },
+            Example {
+                description: "Kill every process with a given name",
+                example: "ps | where name == sleep | kill",
+                result: None,
+            },
+            Example {
+                description: "Ask a process to reload its configuration, on Unix",
+                example: "kill --signal HUP 12345",
+                result: None,
+            },
         ]
     }
 }
 
 async fn kill(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
 
     let (
         KillArgs {
-            pid,
             rest,
             force,
             quiet,
+            signal,
         },
-        ..,
+        input,
     ) = args.process(&registry).await?;
+
+    let pids = if rest.is_empty() {
+        input
+            .collect::<Vec<Value>>()
+            .await
+            .iter()
+            .map(pid_from_value)
+            .collect::<Result<Vec<_>, _>>()?
+    } else {
+        rest
+    };
+
+    if pids.is_empty() {
+        return Err(ShellError::labeled_error(
+            "kill requires process ids",
+            "needs pids, or ps rows in the pipeline",
+            name,
+        ));
+    }
+
+    let signal = match signal {
+        Some(signal) if *force => {
+            return Err(ShellError::labeled_error(
+                "Can't use --force with --signal",
+                "--force already sends KILL",
+                signal.tag,
+            ))
+        }
+        Some(signal) => Some(signal_arg(&signal)?),
+        None => None,
+    };
+
     let mut cmd = if cfg!(windows) {
+        if let Some((_, tag)) = signal {
+            return Err(ShellError::labeled_error(
+                "Signals are not supported on Windows",
+                "use --force to end the process",
+                tag,
+            ));
+        }
+
         let mut cmd = Command::new("taskkill");
 
         if *force {
             cmd.arg("/F");
         }
 
-        cmd.arg("/PID");
-        cmd.arg(pid.item().to_string());
-
         // each pid must written as `/PID 0` otherwise
         // taskkill will act as `killall` unix command
-        for id in &rest {
+        for id in &pids {
             cmd.arg("/PID");
             cmd.arg(id.item().to_string());
         }
@@ -99,9 +152,12 @@ async fn kill(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStr
             cmd.arg("-9");
         }
 
-        cmd.arg(pid.item().to_string());
+        if let Some((signal, _)) = signal {
+            cmd.arg("-s");
+            cmd.arg(signal);
+        }
 
-        cmd.args(rest.iter().map(move |id| id.item().to_string()));
+        cmd.args(pids.iter().map(move |id| id.item().to_string()));
 
         cmd
     };
@@ -113,14 +169,101 @@ async fn kill(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStr
             .stderr(Stdio::null());
     }
 
-    cmd.status().expect("failed to execute shell command");
+    let status = cmd.status().map_err(|e| {
+        ShellError::labeled_error(
+            format!("Could not run the command to kill processes. {}", e),
+            e.to_string(),
+            &name,
+        )
+    })?;
+
+    if !status.success() && !*quiet {
+        return Err(ShellError::labeled_error(
+            "Could not kill every process",
+            "some processes could not be killed",
+            name,
+        ));
+    }
 
     Ok(OutputStream::empty())
 }
 
+/// The pid of a value in the pipeline: a number, or a row with a `pid` column, like the ones from
+/// `ps`.
+fn pid_from_value(value: &Value) -> Result<Tagged<u64>, ShellError> {
+    let pid = match &value.value {
+        UntaggedValue::Row(_) => {
+            get_data_by_key(value, "pid".spanned_unknown()).and_then(|pid| pid.as_u64().ok())
+        }
+        _ => value.as_u64().ok(),
+    };
+
+    pid.map(|pid| pid.tagged(&value.tag)).ok_or_else(|| {
+        ShellError::labeled_error(
+            "Expected a pid",
+            "expected a process id, or a row with a pid column",
+            &value.tag,
+        )
+    })
+}
+
+/// The signal to give `kill -s`, from a name like `hup` or `SIGHUP`, or a number.
+fn signal_arg(signal: &Value) -> Result<(String, Tag), ShellError> {
+    let invalid = || {
+        ShellError::labeled_error(
+            "Invalid signal",
+            "expected a signal name like HUP, or a number",
+            &signal.tag,
+        )
+    };
+
+    let text = match &signal.value {
+        UntaggedValue::Primitive(Primitive::Int(_)) => {
+            signal.as_u64().map_err(|_| invalid())?.to_string()
+        }
+        UntaggedValue::Primitive(Primitive::String(name)) => {
+            let name = name.to_uppercase();
+            let name = name.trim_start_matches("SIG");
+
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return Err(invalid());
+            }
+
+            name.to_string()
+        }
+        _ => return Err(invalid()),
+    };
+
+    Ok((text, signal.tag.clone()))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Kill;
+    use super::{signal_arg, Kill};
+    use nu_protocol::UntaggedValue;
+    use nu_source::Tag;
+
+    fn signal(value: UntaggedValue) -> Option<String> {
+        signal_arg(&value.into_value(Tag::unknown()))
+            .ok()
+            .map(|(signal, _)| signal)
+    }
+
+    #[test]
+    fn reads_signals() {
+        assert_eq!(
+            signal(UntaggedValue::string("hup")),
+            Some("HUP".to_string())
+        );
+        assert_eq!(
+            signal(UntaggedValue::string("SIGUSR1")),
+            Some("USR1".to_string())
+        );
+        assert_eq!(signal(UntaggedValue::int(9)), Some("9".to_string()));
+        assert_eq!(signal(UntaggedValue::int(-1)), None);
+        assert_eq!(signal(UntaggedValue::string("SIG")), None);
+        assert_eq!(signal(UntaggedValue::string("-9; rm")), None);
+    }
 
     #[test]
     fn examples_work_as_expected() {
@@ -0,0 +1,73 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape};
+
+pub struct Let;
+
+#[async_trait]
+impl WholeStreamCommand for Let {
+    fn name(&self) -> &str {
+        "let"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("let")
+            .required("name", SyntaxShape::String, "the name of the variable")
+            .required("=", SyntaxShape::String, "the equals sign")
+            .required(
+                "value",
+                SyntaxShape::Math,
+                "the value of the variable, which can be an expression",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Set a variable for the rest of the block, or of the session at the top level. Blocks made after it keep its value, even when they are run later on."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        _registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        // A let on its own is run by the block it is in, which is where its variable goes
+        Err(ShellError::labeled_error(
+            "Could not set the variable",
+            "let has to be on its own, not part of a pipeline",
+            args.call_info.name_tag,
+        ))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Set a variable and use it in a block",
+                example: "let threshold = 10; echo [1 20 300] | where $it > $threshold",
+                result: None,
+            },
+            Example {
+                description: "Set a variable from the output of a pipeline",
+                example: "let files = (ls | count); echo $files",
+                result: None,
+            },
+            Example {
+                description: "Define a command that keeps the value the variable has now",
+                example: "let greeting = hello; def greet [name] { echo $greeting $name }",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Let;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Let {})
+    }
+}
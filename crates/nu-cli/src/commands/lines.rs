@@ -2,6 +2,7 @@ use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use nu_errors::ShellError;
 use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+use parking_lot::Mutex;
 
 pub struct Lines;
 
@@ -36,19 +37,10 @@ impl WholeStreamCommand for Lines {
     }
 }
 
-fn ends_with_line_ending(st: &str) -> bool {
-    let mut temp = st.to_string();
-    let last = temp.pop();
-    if let Some(c) = last {
-        c == '\n'
-    } else {
-        false
-    }
-}
-
 async fn lines(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
-    let leftover = Arc::new(vec![]);
-    let leftover_string = Arc::new(String::new());
+    // The end of a line can come in a later string, as external commands give their output as
+    // it's written, so what's after the last line ending waits for the rest of its line.
+    let leftover_string = Arc::new(Mutex::new(String::new()));
     let registry = registry.clone();
     let args = args.evaluate_once(&registry).await?;
     let tag = args.name_tag();
@@ -62,88 +54,44 @@ async fn lines(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputSt
         .input
         .chain(eos)
         .map(move |item| {
-            let mut leftover = leftover.clone();
-            let mut leftover_string = leftover_string.clone();
+            let mut leftover_string = leftover_string.lock();
 
             match item {
                 Value {
                     value: UntaggedValue::Primitive(Primitive::String(st)),
                     ..
-                } => {
-                    let st = (&*leftover_string).clone() + &st;
-                    if let Some(leftover) = Arc::get_mut(&mut leftover) {
-                        leftover.clear();
-                    }
-
-                    let mut lines: Vec<String> = st.lines().map(|x| x.to_string()).collect();
-
-                    if !ends_with_line_ending(&st) {
-                        if let Some(last) = lines.pop() {
-                            if let Some(leftover_string) = Arc::get_mut(&mut leftover_string) {
-                                leftover_string.clear();
-                                leftover_string.push_str(&last);
-                            }
-                        } else if let Some(leftover_string) = Arc::get_mut(&mut leftover_string) {
-                            leftover_string.clear();
-                        }
-                    } else if let Some(leftover_string) = Arc::get_mut(&mut leftover_string) {
-                        leftover_string.clear();
-                    }
-
-                    let success_lines: Vec<_> = lines
-                        .iter()
-                        .map(|x| ReturnSuccess::value(UntaggedValue::line(x).into_untagged_value()))
-                        .collect();
-
-                    futures::stream::iter(success_lines)
                 }
-                Value {
+                | Value {
                     value: UntaggedValue::Primitive(Primitive::Line(st)),
                     ..
                 } => {
-                    let st = (&*leftover_string).clone() + &st;
-                    if let Some(leftover) = Arc::get_mut(&mut leftover) {
-                        leftover.clear();
-                    }
+                    leftover_string.push_str(&st);
+                    let st = std::mem::take(&mut *leftover_string);
+
                     let mut lines: Vec<String> = st.lines().map(|x| x.to_string()).collect();
-                    if !ends_with_line_ending(&st) {
+
+                    if !st.ends_with('\n') {
                         if let Some(last) = lines.pop() {
-                            if let Some(leftover_string) = Arc::get_mut(&mut leftover_string) {
-                                leftover_string.clear();
-                                leftover_string.push_str(&last);
-                            }
-                        } else if let Some(leftover_string) = Arc::get_mut(&mut leftover_string) {
-                            leftover_string.clear();
+                            leftover_string.push_str(&last);
                         }
-                    } else if let Some(leftover_string) = Arc::get_mut(&mut leftover_string) {
-                        leftover_string.clear();
                     }
 
                     let success_lines: Vec<_> = lines
                         .iter()
                         .map(|x| ReturnSuccess::value(UntaggedValue::line(x).into_untagged_value()))
                         .collect();
+
                     futures::stream::iter(success_lines)
                 }
                 Value {
                     value: UntaggedValue::Primitive(Primitive::EndOfStream),
                     ..
                 } => {
-                    if !leftover.is_empty() {
-                        let mut st = (&*leftover_string).clone();
-                        if let Ok(extra) = String::from_utf8((&*leftover).clone()) {
-                            st.push_str(&extra);
-                        }
-                        // futures::stream::iter(vec![ReturnSuccess::value(
-                        //     UntaggedValue::string(st).into_untagged_value(),
-                        // )])
-                        if !st.is_empty() {
-                            futures::stream::iter(vec![ReturnSuccess::value(
-                                UntaggedValue::string(&*leftover_string).into_untagged_value(),
-                            )])
-                        } else {
-                            futures::stream::iter(vec![])
-                        }
+                    let st = std::mem::take(&mut *leftover_string);
+                    if !st.is_empty() {
+                        futures::stream::iter(vec![ReturnSuccess::value(
+                            UntaggedValue::line(st).into_untagged_value(),
+                        )])
                     } else {
                         futures::stream::iter(vec![])
                     }
@@ -0,0 +1,95 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape};
+use nu_source::Tagged;
+use std::path::PathBuf;
+
+pub struct Link;
+
+#[derive(Deserialize)]
+pub struct LinkArgs {
+    pub target: Tagged<PathBuf>,
+    pub link: Option<Tagged<PathBuf>>,
+    pub hard: bool,
+    pub relative: bool,
+    pub force: bool,
+    pub backup: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Link {
+    fn name(&self) -> &str {
+        "ln"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("ln")
+            .required("target", SyntaxShape::Path, "the path the link points to")
+            .optional(
+                "link",
+                SyntaxShape::Path,
+                "the link to create, or the directory to create it in (default: the current directory)",
+            )
+            .switch("hard", "create a hard link instead of a symbolic link", None)
+            .switch(
+                "relative",
+                "point the symbolic link to the target relative to the link's directory",
+                Some('r'),
+            )
+            .switch("force", "replace the file at the link's path", Some('f'))
+            .switch(
+                "backup",
+                "rename the file at the link's path, adding a ~, instead of replacing it",
+                Some('b'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Create symbolic or hard links."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let shell_manager = args.shell_manager.clone();
+        let name = args.call_info.name_tag.clone();
+        let (args, _) = args.process(registry).await?;
+        shell_manager.ln(args, name)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Link to a file from the current directory",
+                example: "ln ../shared/config.toml",
+                result: None,
+            },
+            Example {
+                description: "Link with a relative path, so the link keeps working when both are moved together",
+                example: "ln --relative build/release/nu bin/nu",
+                result: None,
+            },
+            Example {
+                description: "Create a hard link, replacing the file that's already there",
+                example: "ln --hard --force data.csv backup/data.csv",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Link;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Link {})
+    }
+}
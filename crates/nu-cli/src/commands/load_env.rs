@@ -0,0 +1,90 @@
+use crate::commands::with_env::env_variables;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+use std::ffi::OsString;
+
+pub struct LoadEnv;
+
+#[derive(Deserialize, Debug)]
+struct LoadEnvArgs {
+    variables: Option<Value>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for LoadEnv {
+    fn name(&self) -> &str {
+        "load-env"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("load-env").optional(
+            "variables",
+            SyntaxShape::Any,
+            "the environment variables to set, as a row or as pairs of names and values (else taken from the input)",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Set several environment variables in the session at once, from the rows of the input or from the row given."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        load_env(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Set environment variables for the rest of the session",
+                example: r#"load-env [LOAD_ENV_EXAMPLE "loaded"]; echo $nu.env.LOAD_ENV_EXAMPLE"#,
+                result: Some(vec![Value::from("loaded")]),
+            },
+            Example {
+                description: "Set the environment variables in a .env file",
+                example: "open .env | from toml | load-env",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn load_env(
+    raw_args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let mut context = Context::from_raw(&raw_args, &registry);
+    let (LoadEnvArgs { variables }, input) = raw_args.process(&registry).await?;
+
+    let values: Vec<Value> = match variables {
+        Some(variables) => vec![variables],
+        None => input.collect().await,
+    };
+
+    for value in values {
+        for (name, value) in env_variables(&value)? {
+            context.with_host(|host| host.env_set(OsString::from(name), OsString::from(value)));
+        }
+    }
+
+    Ok(OutputStream::empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LoadEnv;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(LoadEnv {})
+    }
+}
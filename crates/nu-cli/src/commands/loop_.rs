@@ -0,0 +1,208 @@
+use crate::commands::classified::block::{run_block_with_params, run_condition};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::{LoopControl, ShellError};
+use nu_protocol::{
+    hir::Block, ReturnSuccess, ReturnValue, Scope, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use std::sync::atomic::Ordering;
+
+pub struct Loop;
+
+#[derive(Deserialize)]
+pub struct LoopArgs {
+    block: Block,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Loop {
+    fn name(&self) -> &str {
+        "loop"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("loop").required(
+            "block",
+            SyntaxShape::Block,
+            "the block to run until it breaks",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Run a block over and over, until `break` is run in it. The output of each pass is given in turn."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        loop_(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Print a message until ctrl-c is pressed",
+                example: "loop { echo \"still here\" }",
+                result: None,
+            },
+            Example {
+                description: "Ping a host until it stops answering",
+                example: "loop { try { ^ping -c 1 example.com } catch { break } }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn loop_(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let context = Context::from_args(&args, &registry);
+    let scope = args.call_info.scope.clone();
+
+    let (LoopArgs { block }, _) = args.process(&registry).await?;
+
+    let body = Body::new(block, context, scope);
+    let passes = futures::stream::unfold(Some(body), |body| async move {
+        let (output, body) = body?.pass(IndexMap::new()).await;
+        Some((output, body))
+    });
+
+    Ok(output(passes))
+}
+
+/// The block of a loop, with what it needs to be run again on each pass.
+pub(crate) struct Body {
+    block: Block,
+    context: Context,
+    scope: Scope,
+    session: IndexMap<String, String>,
+}
+
+impl Body {
+    pub(crate) fn new(block: Block, context: Context, scope: Scope) -> Body {
+        let session = context.get_env();
+
+        Body {
+            block,
+            context,
+            scope,
+            session,
+        }
+    }
+
+    /// Runs the body once, with `vars` on top of the variables of the loop. The body is given
+    /// back if the loop goes on, that is unless it ran into `break`, an error or ctrl-c.
+    pub(crate) async fn pass(
+        mut self,
+        vars: IndexMap<String, Value>,
+    ) -> (Result<Vec<Value>, ShellError>, Option<Body>) {
+        if self.context.ctrl_c.load(Ordering::SeqCst) {
+            return (Ok(vec![]), None);
+        }
+
+        let block = self.block.clone();
+        match self.run(&block, vars).await {
+            Ok(output) => (Ok(output), Some(self)),
+            Err(err) => match err.as_loop_control() {
+                Some(LoopControl::Continue) => (Ok(vec![]), Some(self)),
+                Some(LoopControl::Break) => (Ok(vec![]), None),
+                None => (Err(err), None),
+            },
+        }
+    }
+
+    /// Whether the condition of a loop holds for its next pass.
+    pub(crate) async fn test(&mut self, condition: &Block) -> Result<bool, ShellError> {
+        let env = self.env();
+
+        run_condition(
+            condition,
+            &mut self.context,
+            &self.scope.it,
+            &self.scope.vars,
+            &env,
+        )
+        .await
+    }
+
+    /// Runs `block` in the scope of the loop, giving what it outputs, or the first error in it.
+    async fn run(
+        &mut self,
+        block: &Block,
+        vars: IndexMap<String, Value>,
+    ) -> Result<Vec<Value>, ShellError> {
+        let env = self.env();
+
+        let values: Vec<Value> = run_block_with_params(
+            block,
+            &mut self.context,
+            InputStream::empty(),
+            &self.scope.it,
+            &self.scope.vars,
+            &vars,
+            &env,
+        )
+        .await?
+        .collect()
+        .await;
+
+        if let Some(err) = self.context.get_errors().into_iter().next() {
+            self.context.clear_errors();
+            return Err(err);
+        }
+
+        let mut output = vec![];
+        for value in values {
+            match value.value {
+                UntaggedValue::Error(err) => return Err(err),
+                _ => output.push(value),
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// The environment of the loop, with the variables set by the passes so far, so each pass
+    /// sees the ones set before it.
+    fn env(&self) -> IndexMap<String, String> {
+        let mut env = self.scope.env.clone();
+        for (name, value) in self.context.get_env() {
+            if self.session.get(&name) != Some(&value) {
+                env.insert(name, value);
+            }
+        }
+
+        env
+    }
+}
+
+/// The output of a loop, from the output of its passes, up to the first error.
+pub(crate) fn output(
+    passes: impl Stream<Item = Result<Vec<Value>, ShellError>> + Send + 'static,
+) -> OutputStream {
+    passes
+        .map(|pass| {
+            let values: Vec<ReturnValue> = match pass {
+                Ok(output) => output.into_iter().map(ReturnSuccess::value).collect(),
+                Err(err) => vec![Err(err)],
+            };
+
+            futures::stream::iter(values)
+        })
+        .flatten()
+        .to_output_stream()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Loop;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Loop {})
+    }
+}
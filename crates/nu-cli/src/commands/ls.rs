@@ -18,6 +18,7 @@ pub struct LsArgs {
     pub with_symlink_targets: bool,
     #[serde(rename = "du")]
     pub du: bool,
+    pub long: bool,
 }
 
 #[async_trait]
@@ -49,9 +50,14 @@ impl WholeStreamCommand for Ls {
                 "display the paths to the target files that symlinks point to",
                 Some('w'),
             )
+            .switch(
+                "long",
+                "list all available columns, with the octal permissions, inode and link count",
+                Some('l'),
+            )
             .switch(
                 "du",
-                "display the apparent directory size in place of the directory metadata size",
+                "display the total size of directories, and the space each entry takes on disk (physical)",
                 Some('d'),
             )
     }
@@ -89,6 +95,11 @@ impl WholeStreamCommand for Ls {
                 example: "ls *.rs",
                 result: None,
             },
+            Example {
+                description: "List the files anyone can write to",
+                example: "ls --long | where octal =~ '[2367]$'",
+                result: None,
+            },
         ]
     }
 }
@@ -0,0 +1,265 @@
+use crate::commands::classified::block::run_block;
+use crate::commands::WholeStreamCommand;
+use crate::data::range::range_contains;
+use crate::data::value::compare_values;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::hir::{Block, Expression, Literal, Operator};
+use nu_protocol::{
+    Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{SpannedItem, Tagged};
+use regex::Regex;
+
+pub struct Match;
+
+#[derive(Deserialize)]
+pub struct MatchArgs {
+    value: Value,
+    rest: Vec<Value>,
+}
+
+/// What the pattern of an arm matches.
+enum Pattern {
+    Anything,
+    Type(Tagged<String>),
+    Value(Value),
+}
+
+impl Pattern {
+    fn matches(&self, value: &Value) -> bool {
+        match self {
+            Pattern::Anything => true,
+            Pattern::Type(name) => type_matches(&name.item, value).unwrap_or(false),
+            Pattern::Value(Value {
+                value: UntaggedValue::Primitive(Primitive::Range(range)),
+                ..
+            }) => range_contains(range, &value.value).unwrap_or(false),
+            Pattern::Value(pattern) => {
+                compare_values(Operator::Equal, &pattern.value, &value.value).unwrap_or(false)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl WholeStreamCommand for Match {
+    fn name(&self) -> &str {
+        "match"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("match")
+            .required("value", SyntaxShape::Any, "the value to match")
+            .required(
+                "arms",
+                SyntaxShape::Any,
+                "the arms, like { <pattern> => <body>, ... }",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        r#"Run the body of the first arm whose pattern matches the value.
+
+A pattern is a value, a range, a variable, the name of a type, like int or string, or _ to match
+anything. Strings have to be quoted, as bare words are names of types. A body is an expression,
+or a block for a pipeline. The input is given to the body that is run, and it is an error if no arm
+matches.
+
+The older form, `match <column> <regex>`, still keeps the rows whose column matches the regex, but
+is deprecated in favor of `where <column> =~ <regex>`."#
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        match_(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Match a number against values and ranges",
+                example: r#"match 3 { 1 => "one", 2..5 => "a few", _ => "many" }"#,
+                result: Some(vec![Value::from("a few")]),
+            },
+            Example {
+                description: "Match the type of a value",
+                example: r#"match 2.5 { int => "whole", decimal => "fraction" }"#,
+                result: Some(vec![Value::from("fraction")]),
+            },
+            Example {
+                description: "Run a block for the arm that matches",
+                example: r#"match $nu.env.SHELL { "/bin/bash" => { echo bash }, _ => { echo other } }"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn match_(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let mut context = Context::from_args(&args, &registry);
+    let scope = args.call_info.scope.clone();
+
+    // Bare words name types, which is only known before they're evaluated into strings
+    let types: Vec<Option<Tagged<String>>> = args
+        .call_info
+        .args
+        .positional
+        .iter()
+        .flatten()
+        .skip(1)
+        .step_by(2)
+        .map(|pattern| match &pattern.expr {
+            Expression::Literal(Literal::Bare(name)) => Some(name.clone().tagged(pattern.span)),
+            _ => None,
+        })
+        .collect();
+
+    let (MatchArgs { value, rest }, input) = args.process(&registry).await?;
+
+    // `match <column> <regex>` was the match plugin, before match had arms
+    if let [regex] = &rest[..] {
+        if let UntaggedValue::Primitive(Primitive::String(_)) = &regex.value {
+            let warning = "warning: `match <column> <regex>` is deprecated, use `where <column> =~ <regex>` instead".to_string();
+            if let Some(warning) = registry.warn_deprecated("match <column> <regex>", warning) {
+                context.with_host(|host| host.stderr(&warning));
+            }
+
+            return filter_by_regex(&value, regex, input);
+        }
+    }
+
+    let mut arms = vec![];
+    for (arm, name) in rest.chunks(2).zip(types) {
+        let (pattern, body) = match arm {
+            [pattern, body] => (pattern, body),
+            _ => continue,
+        };
+
+        let pattern = match name {
+            Some(name) if name.item == "_" => Pattern::Anything,
+            Some(name) => {
+                if type_matches(&name.item, &value).is_none() {
+                    return Err(ShellError::labeled_error(
+                        "Unknown type",
+                        "unknown type (strings in patterns have to be quoted)",
+                        name.tag,
+                    ));
+                }
+                Pattern::Type(name)
+            }
+            None => Pattern::Value(pattern.clone()),
+        };
+
+        match &body.value {
+            UntaggedValue::Block(block) => arms.push((pattern, block.clone())),
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Expected a block",
+                    "expected a block",
+                    &body.tag,
+                ))
+            }
+        }
+    }
+
+    let block: Block = match arms
+        .into_iter()
+        .find(|(pattern, _)| pattern.matches(&value))
+    {
+        Some((_, block)) => block,
+        None => {
+            return Err(ShellError::labeled_error(
+                "No arm matches",
+                "no arm matches this value (add a _ arm to match anything else)",
+                &value.tag,
+            ))
+        }
+    };
+
+    Ok(run_block(
+        &block,
+        &mut context,
+        input,
+        &scope.it,
+        &scope.vars,
+        &scope.env,
+    )
+    .await?
+    .to_output_stream())
+}
+
+/// Keeps the rows of the input whose `column` matches the regex, as the match plugin did.
+fn filter_by_regex(
+    column: &Value,
+    regex: &Value,
+    input: InputStream,
+) -> Result<OutputStream, ShellError> {
+    let column = column.as_string()?;
+    let regex = Regex::new(&regex.as_string()?).map_err(|e| {
+        ShellError::labeled_error(
+            "Could not parse regex",
+            format!("could not parse regex: {}", e),
+            &regex.tag,
+        )
+    })?;
+
+    Ok(input
+        .filter_map(move |row| {
+            let matches = match row.get_data_by_key(column.as_str().spanned_unknown()) {
+                Some(cell) => match cell.as_string() {
+                    Ok(text) => Ok(regex.is_match(&text)),
+                    Err(_) => Err(ShellError::labeled_error(
+                        "Expected a string",
+                        "expected a string",
+                        cell.tag(),
+                    )),
+                },
+                None => Err(ShellError::labeled_error(
+                    "Unknown column",
+                    format!("the row has no column {}", column),
+                    row.tag(),
+                )),
+            };
+
+            futures::future::ready(match matches {
+                Ok(true) => Some(ReturnSuccess::value(row)),
+                Ok(false) => None,
+                Err(e) => Some(Err(e)),
+            })
+        })
+        .to_output_stream())
+}
+
+/// Whether the value is of the type named `name`, or `None` if there is no type with this name.
+fn type_matches(name: &str, value: &Value) -> Option<bool> {
+    let type_name = value.value.type_name();
+
+    Some(match name {
+        "int" | "integer" => type_name == "integer",
+        "number" => type_name == "integer" || type_name == "decimal",
+        "string" => type_name == "string" || type_name == "line",
+        "bool" | "boolean" => type_name == "boolean",
+        "filesize" | "bytes" => type_name == "bytes",
+        "path" => type_name == "file path",
+        "decimal" | "nothing" | "date" | "duration" | "range" | "table" | "row" | "block"
+        | "binary" | "pattern" => type_name == name,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Match;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Match {})
+    }
+}
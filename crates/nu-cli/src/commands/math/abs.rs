@@ -0,0 +1,78 @@
+use crate::commands::math::utils::{expected_number, map_numbers};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+use num_traits::Signed;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math abs"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math abs").rest(
+            SyntaxShape::ColumnPath,
+            "optionally apply to the cells at the given column paths (default: every number)",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Gets the absolute value of each number"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let (Arguments { rest }, input) = args.process(&registry).await?;
+
+        Ok(map_numbers(input, rest, action))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the absolute values of a list of numbers",
+            example: "echo [-50 -100.0 25] | math abs",
+            result: Some(vec![
+                UntaggedValue::int(50).into(),
+                UntaggedValue::decimal(100).into(),
+                UntaggedValue::int(25).into(),
+            ]),
+        }]
+    }
+}
+
+fn action(value: &Value) -> Result<UntaggedValue, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(n)) => Ok(UntaggedValue::int(n.abs())),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => Ok(UntaggedValue::decimal(d.abs())),
+        UntaggedValue::Primitive(Primitive::Duration(secs)) => {
+            Ok(UntaggedValue::duration(secs.abs()))
+        }
+        UntaggedValue::Primitive(Primitive::Bytes(_)) => Ok(value.value.clone()),
+        _ => Err(expected_number(value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
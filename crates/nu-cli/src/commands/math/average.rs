@@ -57,11 +57,16 @@ impl WholeStreamCommand for SubCommand {
 
 pub fn average(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
     let sum = reducer_for(Reduce::Sum);
+    let total = sum(Value::zero(), values.to_vec())?;
+
+    average_of_total(total, values.len(), name)
+}
 
-    let number = BigDecimal::from_usize(values.len()).expect("expected a usize-sized bigdecimal");
+/// The average of `count` values that add up to `total`.
+pub fn average_of_total(total: Value, count: usize, name: &Tag) -> Result<Value, ShellError> {
+    let number = BigDecimal::from_usize(count).expect("expected a usize-sized bigdecimal");
 
     let total_rows = UntaggedValue::decimal(number);
-    let total = sum(Value::zero(), values.to_vec())?;
 
     match total {
         Value {
@@ -35,7 +35,8 @@ impl WholeStreamCommand for Command {
 mod tests {
     use super::*;
     use crate::commands::math::{
-        average::average, max::maximum, min::minimum, utils::MathFunction,
+        average::average, max::maximum, median::median, min::minimum, product::product,
+        utils::MathFunction,
     };
     use nu_plugin::test_helpers::value::{decimal, int};
     use nu_protocol::Value;
@@ -53,7 +54,7 @@ mod tests {
             description: &'static str,
             values: Vec<Value>,
             expected_err: Option<ShellError>,
-            // Order is: avg, min, max
+            // Order is: avg, min, max, median, product
             expected_res: Vec<Result<Value, ShellError>>,
         }
         let tt: Vec<TestCase> = vec![
@@ -67,31 +68,61 @@ mod tests {
                 description: "Single value",
                 values: vec![int(10)],
                 expected_err: None,
-                expected_res: vec![Ok(decimal(10)), Ok(int(10)), Ok(int(10))],
+                expected_res: vec![
+                    Ok(decimal(10)),
+                    Ok(int(10)),
+                    Ok(int(10)),
+                    Ok(int(10)),
+                    Ok(int(10)),
+                ],
             },
             TestCase {
                 description: "Multiple Values",
                 values: vec![int(10), int(30), int(20)],
                 expected_err: None,
-                expected_res: vec![Ok(decimal(20)), Ok(int(10)), Ok(int(30))],
+                expected_res: vec![
+                    Ok(decimal(20)),
+                    Ok(int(10)),
+                    Ok(int(30)),
+                    Ok(int(20)),
+                    Ok(int(6000)),
+                ],
             },
             TestCase {
                 description: "Mixed Values",
                 values: vec![int(10), decimal(26.5), decimal(26.5)],
                 expected_err: None,
-                expected_res: vec![Ok(decimal(21)), Ok(int(10)), Ok(decimal(26.5))],
+                expected_res: vec![
+                    Ok(decimal(21)),
+                    Ok(int(10)),
+                    Ok(decimal(26.5)),
+                    Ok(decimal(26.5)),
+                    Ok(decimal(7022.5)),
+                ],
             },
             TestCase {
                 description: "Negative Values",
                 values: vec![int(10), int(-11), int(-14)],
                 expected_err: None,
-                expected_res: vec![Ok(decimal(-5)), Ok(int(-14)), Ok(int(10))],
+                expected_res: vec![
+                    Ok(decimal(-5)),
+                    Ok(int(-14)),
+                    Ok(int(10)),
+                    Ok(int(-11)),
+                    Ok(int(1540)),
+                ],
             },
             TestCase {
                 description: "Mixed Negative Values",
                 values: vec![int(10), decimal(-11.5), decimal(-13.5)],
                 expected_err: None,
-                expected_res: vec![Ok(decimal(-5)), Ok(decimal(-13.5)), Ok(int(10))],
+                expected_res: vec![
+                    Ok(decimal(-5)),
+                    Ok(decimal(-13.5)),
+                    Ok(int(10)),
+                    Ok(decimal(-11.5)),
+                    Ok(decimal(1552.5)),
+                ],
             },
             // TODO-Uncomment once I figure out how to structure tables
             // TestCase {
@@ -116,7 +147,8 @@ mod tests {
 
         for tc in tt.iter() {
             let tc: &TestCase = tc; // Just for type annotations
-            let math_functions: Vec<MathFunction> = vec![average, minimum, maximum];
+            let math_functions: Vec<MathFunction> =
+                vec![average, minimum, maximum, median, product];
             let results = math_functions
                 .iter()
                 .map(|mf| mf(&tc.values, &test_tag))
@@ -0,0 +1,226 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use num_traits::ToPrimitive;
+use std::str::FromStr;
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+pub struct SubCommandArgs {
+    expression: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math eval"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math eval").optional(
+            "expression",
+            SyntaxShape::String,
+            "the expression to evaluate for each value (default: the values are the expressions)",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Evaluates a math expression, like \"sqrt(2) * 3\", into a number"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        eval(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Evaluate an expression",
+                example: "math eval \"floor(10 / 4)\"",
+                result: Some(vec![UntaggedValue::decimal(2).into()]),
+            },
+            Example {
+                description: "Evaluate the expressions in the pipeline",
+                example: "echo \"2 ^ 6\" | math eval",
+                result: Some(vec![UntaggedValue::decimal(64).into()]),
+            },
+            Example {
+                description: "Evaluate an expression for each number in the pipeline",
+                example: "echo [1 4 9] | math eval \"sqrt($it)\"",
+                result: Some(vec![
+                    UntaggedValue::decimal(1).into(),
+                    UntaggedValue::decimal(2).into(),
+                    UntaggedValue::decimal(3).into(),
+                ]),
+            },
+            Example {
+                description: "Evaluate an expression using the columns of each row",
+                example: "open triangles.csv | math eval \"sqrt(a^2 + b^2)\"",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn eval(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (SubCommandArgs { expression }, input) = args.process(&registry).await?;
+
+    let expression = match expression {
+        Some(expression) => expression,
+        None => {
+            return Ok(input
+                .map(move |value| match value.as_string() {
+                    Ok(expression) => {
+                        let expression = parse(&expression, &value.tag)?;
+                        ReturnSuccess::value(evaluate(&expression, &[], &value.tag)?)
+                    }
+                    Err(_) => Err(ShellError::labeled_error(
+                        "Expected a string from pipeline",
+                        "requires string input",
+                        &name,
+                    )),
+                })
+                .to_output_stream())
+        }
+    };
+
+    let tag = expression.tag.clone();
+    let expression = parse(&expression.item, &tag)?;
+    let values: Vec<Value> = input.collect().await;
+
+    if values.iter().all(|value| value.value.is_none()) {
+        return Ok(OutputStream::one(ReturnSuccess::value(evaluate(
+            &expression,
+            &[],
+            &tag,
+        )?)));
+    }
+
+    Ok(futures::stream::iter(values.into_iter().map(move |value| {
+        ReturnSuccess::value(evaluate(&expression, &variables(&value), &value.tag)?)
+    }))
+    .to_output_stream())
+}
+
+/// Parses the expression. Variables can be written like `$it`, as in the rest of the shell.
+fn parse(expression: &str, tag: &Tag) -> Result<meval::Expr, ShellError> {
+    expression
+        .replace('$', "")
+        .parse()
+        .map_err(|err: meval::Error| {
+            ShellError::labeled_error("Could not parse the expression", err.to_string(), tag)
+        })
+}
+
+/// The variables available to the expression: the value itself as `it`, and the columns of a row
+/// that are numbers by their names.
+fn variables(value: &Value) -> Vec<(String, f64)> {
+    match &value.value {
+        UntaggedValue::Row(dict) => dict
+            .entries
+            .iter()
+            .filter_map(|(column, cell)| Some((column.clone(), as_number(cell)?)))
+            .collect(),
+        _ => as_number(value)
+            .map(|number| vec![("it".to_string(), number)])
+            .unwrap_or_default(),
+    }
+}
+
+fn as_number(value: &Value) -> Option<f64> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(n)) => n.to_f64(),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => d.to_f64(),
+        UntaggedValue::Primitive(Primitive::Bytes(b)) => b.to_f64(),
+        UntaggedValue::Primitive(Primitive::String(s)) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn evaluate(
+    expression: &meval::Expr,
+    variables: &[(String, f64)],
+    tag: &Tag,
+) -> Result<Value, ShellError> {
+    let mut context = meval::Context::new();
+    for (variable, number) in variables {
+        context.var(variable.as_str(), *number);
+    }
+
+    match expression.eval_with_context(context) {
+        Ok(number) if number.is_finite() => match BigDecimal::from_str(&number.to_string()) {
+            Ok(number) => Ok(UntaggedValue::decimal(number).into_value(tag)),
+            Err(_) => Err(ShellError::labeled_error(
+                "Calculation error",
+                "cannot represent result",
+                tag,
+            )),
+        },
+        Ok(_) => Err(ShellError::labeled_error(
+            "Calculation error",
+            "cannot represent result",
+            tag,
+        )),
+        Err(err) => Err(ShellError::labeled_error(
+            "Calculation error",
+            err.to_string(),
+            tag,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{evaluate, parse, variables, SubCommand};
+    use nu_errors::ShellError;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::Tag;
+
+    fn int(n: i64) -> Value {
+        UntaggedValue::int(n).into_untagged_value()
+    }
+
+    #[test]
+    fn evaluates_with_the_columns_of_a_row() -> Result<(), ShellError> {
+        let row = UntaggedValue::row(indexmap! {
+            "a".into() => int(3),
+            "b".into() => int(4),
+            "name".into() => UntaggedValue::string("triangle").into_untagged_value(),
+        })
+        .into_untagged_value();
+
+        let expression = parse("sqrt($a^2 + b^2)", &Tag::unknown())?;
+
+        assert_eq!(
+            evaluate(&expression, &variables(&row), &Tag::unknown())?,
+            UntaggedValue::decimal(5).into_untagged_value()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reports_unknown_variables() -> Result<(), ShellError> {
+        let expression = parse("it + x", &Tag::unknown())?;
+
+        assert!(evaluate(&expression, &variables(&int(1)), &Tag::unknown()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,83 @@
+use crate::commands::math::utils::{expected_number, map_numbers};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math floor"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math floor").rest(
+            SyntaxShape::ColumnPath,
+            "optionally apply to the cells at the given column paths (default: every number)",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Rounds each number down to the nearest integer"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let (Arguments { rest }, input) = args.process(&registry).await?;
+
+        Ok(map_numbers(input, rest, action))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Round a list of numbers down",
+            example: "echo [1.5 2.3 -3.1] | math floor",
+            result: Some(vec![
+                UntaggedValue::int(1).into(),
+                UntaggedValue::int(2).into(),
+                UntaggedValue::int(-4).into(),
+            ]),
+        }]
+    }
+}
+
+fn action(value: &Value) -> Result<UntaggedValue, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => {
+            let truncated = d.with_scale(0);
+            let floor = if *d < truncated {
+                truncated - BigDecimal::from(1)
+            } else {
+                truncated
+            };
+            Ok(UntaggedValue::int(floor.as_bigint_and_exponent().0))
+        }
+        UntaggedValue::Primitive(Primitive::Int(_))
+        | UntaggedValue::Primitive(Primitive::Bytes(_))
+        | UntaggedValue::Primitive(Primitive::Duration(_)) => Ok(value.value.clone()),
+        _ => Err(expected_number(value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -1,10 +1,36 @@
+pub mod abs;
 pub mod average;
+pub mod ceil;
 pub mod command;
+pub mod eval;
+pub mod floor;
 pub mod max;
+pub mod median;
 pub mod min;
+pub mod mode;
+pub mod percentile;
+pub mod product;
+pub mod quartiles;
+pub mod round;
+pub mod sqrt;
+pub mod stddev;
 pub mod utils;
+pub mod variance;
 
+pub use abs::SubCommand as Absolute;
 pub use average::SubCommand as Average;
+pub use ceil::SubCommand as Ceiling;
 pub use command::Command as Math;
+pub use eval::SubCommand as MathEval;
+pub use floor::SubCommand as Floor;
 pub use max::SubCommand as Maximum;
+pub use median::SubCommand as Median;
 pub use min::SubCommand as Minimum;
+pub use mode::SubCommand as Mode;
+pub use percentile::SubCommand as Percentile;
+pub use product::SubCommand as Product;
+pub use quartiles::SubCommand as Quartiles;
+pub use round::SubCommand as Round;
+pub use sqrt::SubCommand as SquareRoot;
+pub use stddev::SubCommand as StandardDeviation;
+pub use variance::SubCommand as Variance;
@@ -0,0 +1,91 @@
+use crate::commands::math::utils::calculate;
+use crate::commands::math::utils::sorted;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use crate::utils::data_processing::ERR_EMPTY_DATA;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, UntaggedValue, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math mode"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math mode")
+    }
+
+    fn usage(&self) -> &str {
+        "Gets the most frequent value(s) of a list of values or tables"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        calculate(
+            RunnableContext {
+                input: args.input,
+                registry: registry.clone(),
+                shell_manager: args.shell_manager,
+                host: args.host,
+                ctrl_c: args.ctrl_c,
+                current_errors: args.current_errors,
+                name: args.call_info.name_tag,
+                raw_input: args.raw_input,
+            },
+            mode,
+        )
+        .await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the most frequent values of a list of numbers",
+            example: "echo [3 3 9 12 12 15] | math mode",
+            result: Some(vec![UntaggedValue::table(&[
+                UntaggedValue::int(3).into(),
+                UntaggedValue::int(12).into(),
+            ])
+            .into()]),
+        }]
+    }
+}
+
+/// The values that appear the most, in ascending order.
+pub fn mode(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
+    if values.is_empty() {
+        return Err(ShellError::unexpected(ERR_EMPTY_DATA));
+    }
+
+    let mut counts: IndexMap<&UntaggedValue, usize> = IndexMap::new();
+    for value in values {
+        *counts.entry(&value.value).or_insert(0) += 1;
+    }
+
+    let most = counts.values().max().cloned().unwrap_or(0);
+    let modes: Vec<Value> = counts
+        .into_iter()
+        .filter(|(_, count)| *count == most)
+        .map(|(value, _)| value.clone().into_value(name))
+        .collect();
+
+    Ok(UntaggedValue::table(&sorted(&modes, name)?).into_value(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
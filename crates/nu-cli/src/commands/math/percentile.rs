@@ -0,0 +1,135 @@
+use crate::commands::math::utils::{calculate_with, sorted, to_decimals};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+use num_traits::ToPrimitive;
+
+#[derive(Deserialize)]
+struct Arguments {
+    nth: Value,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math percentile"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math percentile").required_named(
+            "nth",
+            SyntaxShape::Number,
+            "the percentile to get, from 0 to 100",
+            Some('n'),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Gets the nth percentile of a list of numbers or tables, interpolating between the closest values"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let name = args.call_info.name_tag.clone();
+        let (Arguments { nth }, input) = args.process(&registry).await?;
+
+        let nth_tag = nth.tag.clone();
+        let nth = match &nth.value {
+            UntaggedValue::Primitive(Primitive::Int(n)) => BigDecimal::from(n.clone()),
+            UntaggedValue::Primitive(Primitive::Decimal(d)) => d.clone(),
+            _ => BigDecimal::from(-1),
+        };
+        if nth < BigDecimal::from(0) || nth > BigDecimal::from(100) {
+            return Err(ShellError::labeled_error(
+                "Expected a percentile from 0 to 100",
+                "expected a number from 0 to 100",
+                nth_tag,
+            ));
+        }
+
+        calculate_with(input, name, move |values, tag| {
+            percentile(values, &nth, tag)
+        })
+        .await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the 95th percentile of a list of numbers",
+                example: "echo [10 20 30 40 50] | math percentile --nth 95",
+                result: Some(vec![UntaggedValue::decimal(48).into()]),
+            },
+            Example {
+                description: "Get the 99th percentile of the latencies in a log",
+                example: "open requests.csv | get latency | math percentile -n 99",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// The nth percentile of the numbers, interpolating linearly between the closest ranks.
+pub fn percentile(values: &[Value], nth: &BigDecimal, name: &Tag) -> Result<Value, ShellError> {
+    let sorted = sorted(values, name)?;
+    let numbers = to_decimals(&sorted)?;
+
+    let rank = nth * BigDecimal::from((numbers.len() - 1) as u64) / BigDecimal::from(100);
+    let lower = rank.with_scale(0);
+    let fraction = &rank - &lower;
+    let index = lower.as_bigint_and_exponent().0.to_usize().unwrap_or(0);
+
+    if fraction == BigDecimal::from(0) || index + 1 >= numbers.len() {
+        return Ok(sorted[index].value.clone().into_value(name));
+    }
+
+    let interpolated = &numbers[index] + (&numbers[index + 1] - &numbers[index]) * fraction;
+    Ok(UntaggedValue::decimal(interpolated).into_value(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{percentile, SubCommand};
+    use bigdecimal::BigDecimal;
+    use nu_errors::ShellError;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::Tag;
+    use std::str::FromStr;
+
+    fn int(n: i64) -> Value {
+        UntaggedValue::int(n).into_untagged_value()
+    }
+
+    #[test]
+    fn interpolates_between_the_closest_values() -> Result<(), ShellError> {
+        let values = vec![int(40), int(10), int(30), int(20)];
+        let nth = |n: &str| BigDecimal::from_str(n).expect("decimal");
+
+        assert_eq!(percentile(&values, &nth("0"), &Tag::unknown())?, int(10));
+        assert_eq!(percentile(&values, &nth("100"), &Tag::unknown())?, int(40));
+        assert_eq!(
+            percentile(&values, &nth("50"), &Tag::unknown())?,
+            UntaggedValue::decimal(25).into_untagged_value()
+        );
+        assert_eq!(
+            percentile(&values, &nth("90"), &Tag::unknown())?,
+            UntaggedValue::decimal(37).into_untagged_value()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
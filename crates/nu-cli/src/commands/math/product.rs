@@ -0,0 +1,85 @@
+use crate::commands::math::utils::calculate;
+use crate::commands::WholeStreamCommand;
+use crate::data::value::compute_values;
+use crate::prelude::*;
+use crate::utils::data_processing::ERR_EMPTY_DATA;
+use nu_errors::ShellError;
+use nu_protocol::{hir::Operator, Signature, UntaggedValue, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math product"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math product")
+    }
+
+    fn usage(&self) -> &str {
+        "Gets the product of a list of numbers or tables"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        calculate(
+            RunnableContext {
+                input: args.input,
+                registry: registry.clone(),
+                shell_manager: args.shell_manager,
+                host: args.host,
+                ctrl_c: args.ctrl_c,
+                current_errors: args.current_errors,
+                name: args.call_info.name_tag,
+                raw_input: args.raw_input,
+            },
+            product,
+        )
+        .await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the product of a list of numbers",
+            example: "echo [2 3 4] | math product",
+            result: Some(vec![UntaggedValue::int(24).into()]),
+        }]
+    }
+}
+
+pub fn product(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
+    let (first, rest) = values
+        .split_first()
+        .ok_or_else(|| ShellError::unexpected(ERR_EMPTY_DATA))?;
+
+    let mut acc = first.value.clone();
+    for value in rest {
+        acc = compute_values(Operator::Multiply, &acc, &value.value).map_err(
+            |(left_type, right_type)| {
+                ShellError::coerce_error(
+                    left_type.spanned(name.span),
+                    right_type.spanned(name.span),
+                )
+            },
+        )?;
+    }
+
+    Ok(acc.into_value(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
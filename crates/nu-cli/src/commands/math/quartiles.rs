@@ -0,0 +1,78 @@
+use crate::commands::math::percentile::percentile;
+use crate::commands::math::utils::calculate;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use indexmap::indexmap;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, TaggedDictBuilder, UntaggedValue, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math quartiles"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math quartiles")
+    }
+
+    fn usage(&self) -> &str {
+        "Gets the quartiles (the 25th, 50th and 75th percentiles) of a list of numbers or tables"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        calculate(
+            RunnableContext {
+                input: args.input,
+                registry: registry.clone(),
+                shell_manager: args.shell_manager,
+                host: args.host,
+                ctrl_c: args.ctrl_c,
+                current_errors: args.current_errors,
+                name: args.call_info.name_tag,
+                raw_input: args.raw_input,
+            },
+            quartiles,
+        )
+        .await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the quartiles of a list of numbers",
+            example: "echo [1 2 3 4 5] | math quartiles",
+            result: Some(vec![UntaggedValue::row(indexmap! {
+                "q1".to_string() => UntaggedValue::int(2).into(),
+                "median".to_string() => UntaggedValue::int(3).into(),
+                "q3".to_string() => UntaggedValue::int(4).into(),
+            })
+            .into()]),
+        }]
+    }
+}
+
+pub fn quartiles(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
+    let mut row = TaggedDictBuilder::new(name);
+    row.insert_value("q1", percentile(values, &BigDecimal::from(25), name)?);
+    row.insert_value("median", percentile(values, &BigDecimal::from(50), name)?);
+    row.insert_value("q3", percentile(values, &BigDecimal::from(75), name)?);
+    Ok(row.into_value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,129 @@
+use crate::commands::math::utils::{expected_number, map_numbers};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use num_bigint::BigInt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    precision: Option<Tagged<u64>>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math round"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math round")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally apply to the cells at the given column paths (default: every number)",
+            )
+            .named(
+                "precision",
+                SyntaxShape::Int,
+                "the number of decimal places to keep (default: round to integers)",
+                Some('p'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Rounds each number to the nearest integer, or to the given number of decimal places"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let (Arguments { rest, precision }, input) = args.process(&registry).await?;
+        let precision = precision.map(|p| p.item as i64);
+
+        Ok(map_numbers(input, rest, move |value| {
+            action(value, precision)
+        }))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Round a list of numbers",
+                example: "echo [1.5 2.3 -3.5] | math round",
+                result: Some(vec![
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(-4).into(),
+                ]),
+            },
+            Example {
+                description: "Round a list of numbers to two decimal places",
+                example: "echo [3.14159 2.71828] | math round --precision 2",
+                result: Some(vec![
+                    UntaggedValue::decimal(BigDecimal::new(BigInt::from(314), 2)).into(),
+                    UntaggedValue::decimal(BigDecimal::new(BigInt::from(272), 2)).into(),
+                ]),
+            },
+        ]
+    }
+}
+
+fn action(value: &Value, precision: Option<i64>) -> Result<UntaggedValue, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => {
+            let rounded = round(d, precision.unwrap_or(0));
+            match precision {
+                Some(_) => Ok(UntaggedValue::decimal(rounded)),
+                None => Ok(UntaggedValue::int(rounded.as_bigint_and_exponent().0)),
+            }
+        }
+        UntaggedValue::Primitive(Primitive::Int(_))
+        | UntaggedValue::Primitive(Primitive::Bytes(_))
+        | UntaggedValue::Primitive(Primitive::Duration(_)) => Ok(value.value.clone()),
+        _ => Err(expected_number(value)),
+    }
+}
+
+/// Rounds to the given number of decimal places, with halves rounded away from zero.
+pub(crate) fn round(number: &BigDecimal, precision: i64) -> BigDecimal {
+    let half = BigDecimal::new(BigInt::from(5), precision + 1);
+
+    if *number < BigDecimal::from(0) {
+        (number - half).with_scale(precision)
+    } else {
+        (number + half).with_scale(precision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{round, SubCommand};
+    use bigdecimal::BigDecimal;
+    use std::str::FromStr;
+
+    fn decimal(number: &str) -> BigDecimal {
+        BigDecimal::from_str(number).expect("decimal")
+    }
+
+    #[test]
+    fn rounds_halves_away_from_zero() {
+        assert_eq!(round(&decimal("2.5"), 0), decimal("3"));
+        assert_eq!(round(&decimal("-2.5"), 0), decimal("-3"));
+        assert_eq!(round(&decimal("1.005"), 2), decimal("1.01"));
+        assert_eq!(round(&decimal("1.004"), 2), decimal("1.00"));
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,90 @@
+use crate::commands::math::utils::{expected_number, map_numbers};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Primitive, Signature, SyntaxShape, UntaggedValue, Value};
+use num_traits::ToPrimitive;
+use std::str::FromStr;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math sqrt"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math sqrt").rest(
+            SyntaxShape::ColumnPath,
+            "optionally apply to the cells at the given column paths (default: every number)",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Gets the square root of each number"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let (Arguments { rest }, input) = args.process(&registry).await?;
+
+        Ok(map_numbers(input, rest, action))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the square roots of a list of numbers",
+            example: "echo [9 16] | math sqrt",
+            result: Some(vec![
+                UntaggedValue::decimal(3).into(),
+                UntaggedValue::decimal(4).into(),
+            ]),
+        }]
+    }
+}
+
+fn action(value: &Value) -> Result<UntaggedValue, ShellError> {
+    let number = match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(n)) => n.to_f64(),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => d.to_f64(),
+        _ => return Err(expected_number(value)),
+    };
+
+    match number {
+        Some(number) if number >= 0.0 => {
+            // Going through the shortest text representation keeps roots like 1.5 exact
+            match BigDecimal::from_str(&number.sqrt().to_string()) {
+                Ok(root) => Ok(UntaggedValue::decimal(root)),
+                Err(_) => Err(expected_number(value)),
+            }
+        }
+        Some(_) => Err(ShellError::labeled_error(
+            "Can't get the square root of a negative number",
+            "negative number",
+            &value.tag,
+        )),
+        None => Err(expected_number(value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
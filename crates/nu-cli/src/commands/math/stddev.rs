@@ -0,0 +1,98 @@
+use crate::commands::math::utils::calculate;
+use crate::commands::math::variance::compute_variance;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, UntaggedValue, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math stddev"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math stddev").switch(
+            "sample",
+            "calculate the sample standard deviation, dividing by one less than the number of values",
+            Some('s'),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Finds the standard deviation of a list of numbers or tables"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let sample = args.call_info.switch_present("sample");
+
+        calculate(
+            RunnableContext {
+                input: args.input,
+                registry: registry.clone(),
+                shell_manager: args.shell_manager,
+                host: args.host,
+                ctrl_c: args.ctrl_c,
+                current_errors: args.current_errors,
+                name: args.call_info.name_tag,
+                raw_input: args.raw_input,
+            },
+            if sample { sample_stddev } else { stddev },
+        )
+        .await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the standard deviation of a list of numbers",
+                example: "echo [2 4 4 4 5 5 7 9] | math stddev",
+                result: Some(vec![UntaggedValue::decimal(2).into()]),
+            },
+            Example {
+                description: "Get the sample standard deviation of a list of numbers",
+                example: "echo [1 2 3 4 5] | math stddev --sample",
+                result: None,
+            },
+        ]
+    }
+}
+
+pub fn stddev(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
+    compute_stddev(values, false, name)
+}
+
+pub fn sample_stddev(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
+    compute_stddev(values, true, name)
+}
+
+fn compute_stddev(values: &[Value], sample: bool, name: &Tag) -> Result<Value, ShellError> {
+    let variance = compute_variance(values, sample, name)?;
+
+    match variance.sqrt() {
+        Some(stddev) => Ok(UntaggedValue::decimal(stddev).into_value(name)),
+        None => Err(ShellError::labeled_error(
+            "Could not calculate the standard deviation",
+            "could not take the square root of the variance",
+            name,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -1,17 +1,34 @@
+use crate::data::value::compare_values;
 use crate::prelude::*;
+use crate::utils::data_processing::ERR_EMPTY_DATA;
 use nu_errors::ShellError;
-use nu_protocol::{Dictionary, ReturnSuccess, UntaggedValue, Value};
+use nu_protocol::{
+    hir::Operator, ColumnPath, Dictionary, Primitive, ReturnSuccess, ShellTypeName, UntaggedValue,
+    Value,
+};
+use nu_value_ext::ValueExt;
+use std::cmp::Ordering;
 
 use indexmap::map::IndexMap;
 
 pub type MathFunction = fn(values: &[Value], tag: &Tag) -> Result<Value, ShellError>;
 
 pub async fn calculate(
-    RunnableContext {
-        mut input, name, ..
-    }: RunnableContext,
+    RunnableContext { input, name, .. }: RunnableContext,
     mf: MathFunction,
 ) -> Result<OutputStream, ShellError> {
+    calculate_with(input, name, mf).await
+}
+
+/// Like `calculate`, for math functions that depend on the arguments they were given.
+pub async fn calculate_with<F>(
+    mut input: InputStream,
+    name: Tag,
+    mf: F,
+) -> Result<OutputStream, ShellError>
+where
+    F: Fn(&[Value], &Tag) -> Result<Value, ShellError>,
+{
     let values: Vec<Value> = input.drain_vec().await;
 
     if values.iter().all(|v| v.is_primitive()) {
@@ -52,3 +69,108 @@ pub async fn calculate(
         )))
     }
 }
+
+/// The values as decimals, for the functions that are only defined on numbers.
+pub fn to_decimals(values: &[Value]) -> Result<Vec<BigDecimal>, ShellError> {
+    if values.is_empty() {
+        return Err(ShellError::unexpected(ERR_EMPTY_DATA));
+    }
+
+    values
+        .iter()
+        .map(|value| match &value.value {
+            UntaggedValue::Primitive(Primitive::Int(n)) => Ok(BigDecimal::from(n.clone())),
+            UntaggedValue::Primitive(Primitive::Decimal(d)) => Ok(d.clone()),
+            _ => Err(ShellError::labeled_error(
+                "Attempted to compute with a value that is not a number.",
+                "value appears here",
+                value.tag.span,
+            )),
+        })
+        .collect()
+}
+
+/// The values in ascending order.
+pub fn sorted(values: &[Value], name: &Tag) -> Result<Vec<Value>, ShellError> {
+    let mut sorted = values.to_vec();
+    let mut failed = None;
+
+    sorted.sort_by(|left, right| {
+        match compare_values(Operator::LessThan, &left.value, &right.value) {
+            Ok(true) => Ordering::Less,
+            Ok(false) if left.value == right.value => Ordering::Equal,
+            Ok(false) => Ordering::Greater,
+            Err(types) => {
+                failed.get_or_insert(types);
+                Ordering::Equal
+            }
+        }
+    });
+
+    match failed {
+        Some((left_type, right_type)) => Err(ShellError::coerce_error(
+            left_type.spanned(name.span),
+            right_type.spanned(name.span),
+        )),
+        None => Ok(sorted),
+    }
+}
+
+/// Applies `action` to every input value. For rows, it's applied to the cells at the given column
+/// paths, or to every cell that is a number when no column paths are given.
+pub fn map_numbers<F>(input: InputStream, column_paths: Vec<ColumnPath>, action: F) -> OutputStream
+where
+    F: Fn(&Value) -> Result<UntaggedValue, ShellError> + Clone + Send + Sync + 'static,
+{
+    let action = move |value: &Value| Ok(action(value)?.into_value(&value.tag));
+
+    input
+        .map(move |value| match &value.value {
+            UntaggedValue::Row(row) if column_paths.is_empty() => {
+                let mut entries = IndexMap::new();
+                for (column, cell) in &row.entries {
+                    let cell = if is_number(cell) {
+                        action(cell)?
+                    } else {
+                        cell.clone()
+                    };
+                    entries.insert(column.clone(), cell);
+                }
+
+                ReturnSuccess::value(
+                    UntaggedValue::Row(Dictionary { entries }).into_value(&value.tag),
+                )
+            }
+            _ if column_paths.is_empty() => ReturnSuccess::value(action(&value)?),
+            _ => {
+                let mut ret = value;
+
+                for path in &column_paths {
+                    let action = action.clone();
+                    ret = ret.swap_data_by_column_path(path, Box::new(move |old| action(old)))?;
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream()
+}
+
+fn is_number(value: &Value) -> bool {
+    matches!(
+        value.value,
+        UntaggedValue::Primitive(Primitive::Int(_))
+            | UntaggedValue::Primitive(Primitive::Decimal(_))
+            | UntaggedValue::Primitive(Primitive::Bytes(_))
+            | UntaggedValue::Primitive(Primitive::Duration(_))
+    )
+}
+
+/// The error for a value the math function isn't defined on.
+pub fn expected_number(value: &Value) -> ShellError {
+    ShellError::labeled_error(
+        "Expected a number",
+        format!("found {}", value.type_name()),
+        &value.tag,
+    )
+}
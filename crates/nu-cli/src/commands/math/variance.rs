@@ -0,0 +1,119 @@
+use crate::commands::math::utils::{calculate, to_decimals};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, UntaggedValue, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math variance"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math variance").switch(
+            "sample",
+            "calculate the sample variance, dividing by one less than the number of values",
+            Some('s'),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Finds the variance of a list of numbers or tables"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let sample = args.call_info.switch_present("sample");
+
+        calculate(
+            RunnableContext {
+                input: args.input,
+                registry: registry.clone(),
+                shell_manager: args.shell_manager,
+                host: args.host,
+                ctrl_c: args.ctrl_c,
+                current_errors: args.current_errors,
+                name: args.call_info.name_tag,
+                raw_input: args.raw_input,
+            },
+            if sample { sample_variance } else { variance },
+        )
+        .await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the variance of a list of numbers",
+                example: "echo [1 2 3 4 5] | math variance",
+                result: Some(vec![UntaggedValue::decimal(2).into()]),
+            },
+            Example {
+                description: "Get the sample variance of a list of numbers",
+                example: "echo [1 2 3 4 5] | math variance --sample",
+                result: Some(vec![UntaggedValue::decimal(2.5).into()]),
+            },
+        ]
+    }
+}
+
+pub fn variance(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
+    Ok(UntaggedValue::decimal(compute_variance(values, false, name)?).into_value(name))
+}
+
+pub fn sample_variance(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
+    Ok(UntaggedValue::decimal(compute_variance(values, true, name)?).into_value(name))
+}
+
+/// The mean of the squared differences from the mean. The sum of the squares is divided by one
+/// less than the number of values for the sample variance.
+pub fn compute_variance(
+    values: &[Value],
+    sample: bool,
+    name: &Tag,
+) -> Result<BigDecimal, ShellError> {
+    let numbers = to_decimals(values)?;
+    let count = BigDecimal::from(numbers.len() as u64);
+
+    let divisor = if sample {
+        if numbers.len() < 2 {
+            return Err(ShellError::labeled_error(
+                "Sample variance needs at least two values",
+                "requires at least two values",
+                name,
+            ));
+        }
+        &count - BigDecimal::from(1)
+    } else {
+        count.clone()
+    };
+
+    let mean = numbers.iter().sum::<BigDecimal>() / &count;
+    let squares: BigDecimal = numbers
+        .iter()
+        .map(|number| {
+            let difference = number - &mean;
+            &difference * &difference
+        })
+        .sum();
+
+    Ok(squares / divisor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
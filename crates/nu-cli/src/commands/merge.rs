@@ -4,14 +4,13 @@ use crate::context::CommandRegistry;
 use crate::data::value::merge_values;
 use crate::prelude::*;
 
-use indexmap::IndexMap;
 use nu_errors::ShellError;
-use nu_protocol::{hir::Block, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
 pub struct Merge;
 
 #[derive(Deserialize)]
 pub struct MergeArgs {
-    block: Block,
+    other: Value,
 }
 
 #[async_trait]
@@ -22,14 +21,14 @@ impl WholeStreamCommand for Merge {
 
     fn signature(&self) -> Signature {
         Signature::build("merge").required(
-            "block",
-            SyntaxShape::Block,
-            "the block to run and merge into the table",
+            "other",
+            SyntaxShape::Any,
+            "the table (or the block to run to get the table) to merge into the table",
         )
     }
 
     fn usage(&self) -> &str {
-        "Merge a table."
+        "Merge the columns of another table into the table, row by row."
     }
 
     async fn run(
@@ -41,11 +40,18 @@ impl WholeStreamCommand for Merge {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Merge a 1-based index column with some ls output",
-            example: "ls | select name | keep 3 | merge { echo [1 2 3] | wrap index }",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Merge a 1-based index column with some ls output",
+                example: "ls | select name | keep 3 | merge { echo [1 2 3] | wrap index }",
+                result: None,
+            },
+            Example {
+                description: "Merge a table kept in a variable",
+                example: "ls | merge $owners",
+                result: None,
+            },
+        ]
     }
 }
 
@@ -56,33 +62,39 @@ async fn merge(
     let registry = registry.clone();
     let scope = raw_args.call_info.scope.clone();
     let mut context = Context::from_raw(&raw_args, &registry);
-    let name_tag = raw_args.call_info.name_tag.clone();
-    let (merge_args, input): (MergeArgs, _) = raw_args.process(&registry).await?;
-    let block = merge_args.block;
-
-    let table: Option<Vec<Value>> = match run_block(
-        &block,
-        &mut context,
-        InputStream::empty(),
-        &scope.it,
-        &scope.vars,
-        &scope.env,
-    )
-    .await
-    {
-        Ok(mut stream) => Some(stream.drain_vec().await),
-        Err(err) => {
-            return Err(err);
+    let (MergeArgs { other }, input) = raw_args.process(&registry).await?;
+
+    let table: Vec<Value> = match other.value {
+        UntaggedValue::Block(block) => {
+            let table = run_block(
+                &block,
+                &mut context,
+                InputStream::empty(),
+                &scope.it,
+                &scope.vars,
+                &scope.env,
+            )
+            .await?
+            .drain_vec()
+            .await;
+
+            if let Some(err) = context.get_errors().first() {
+                return Err(err.clone());
+            }
+
+            table
+        }
+        UntaggedValue::Table(rows) => rows,
+        UntaggedValue::Row(_) => vec![other],
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a table or a block",
+                "requires a table to merge",
+                &other.tag,
+            ))
         }
     };
 
-    let table = table.unwrap_or_else(|| {
-        vec![Value {
-            value: UntaggedValue::row(IndexMap::default()),
-            tag: name_tag,
-        }]
-    });
-
     Ok(input
         .enumerate()
         .map(move |(idx, value)| {
@@ -0,0 +1,178 @@
+use crate::commands::WholeStreamCommand;
+use crate::path::absolutize;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+use std::path::PathBuf;
+
+pub struct Mktemp;
+
+#[derive(Deserialize)]
+pub struct MktempArgs {
+    template: Option<Tagged<String>>,
+    directory: bool,
+    suffix: Option<Tagged<String>>,
+    tmpdir: Option<Tagged<PathBuf>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Mktemp {
+    fn name(&self) -> &str {
+        "mktemp"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("mktemp")
+            .optional(
+                "template",
+                SyntaxShape::String,
+                "the name to give, ending in at least three Xs to replace with random characters (default: tmp.XXXXXXXXXX)",
+            )
+            .switch("directory", "create a directory instead of a file", Some('d'))
+            .named(
+                "suffix",
+                SyntaxShape::String,
+                "the text to add after the random characters",
+                None,
+            )
+            .named(
+                "tmpdir",
+                SyntaxShape::Path,
+                "the directory to create it in (default: the system's temporary directory, or the current one when a template is given)",
+                Some('p'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Creates a file or directory with a unique name and gives its path."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        mktemp(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Create a temporary file",
+                example: "mktemp",
+                result: None,
+            },
+            Example {
+                description: "Create a directory to build in, under the current one",
+                example: "mktemp -d build.XXXXXX",
+                result: None,
+            },
+            Example {
+                description: "Save a report to a new temporary file",
+                example: "ls | to json | save (mktemp --suffix .json)",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// How many random characters the default template has.
+const DEFAULT_RANDOM_CHARS: usize = 10;
+
+async fn mktemp(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let cwd = PathBuf::from(args.shell_manager.path());
+    let (
+        MktempArgs {
+            template,
+            directory,
+            suffix,
+            tmpdir,
+        },
+        _,
+    ) = args.process(&registry).await?;
+
+    let (prefix, random_chars) = match &template {
+        Some(template) => split_template(&template.item).ok_or_else(|| {
+            ShellError::labeled_error(
+                "Invalid template",
+                "expected a name ending in at least three Xs, like build.XXXXXX",
+                &template.tag,
+            )
+        })?,
+        None => ("tmp.", DEFAULT_RANDOM_CHARS),
+    };
+
+    let dir = match (&tmpdir, &template) {
+        (Some(tmpdir), _) => absolutize(&cwd, &tmpdir.item),
+        (None, Some(_)) => cwd,
+        (None, None) => std::env::temp_dir(),
+    };
+
+    let suffix = suffix.map(|suffix| suffix.item).unwrap_or_default();
+
+    let mut builder = tempfile::Builder::new();
+    builder
+        .prefix(prefix)
+        .suffix(&suffix)
+        .rand_bytes(random_chars);
+
+    let created = if directory {
+        builder.tempdir_in(&dir).map(|dir| dir.into_path())
+    } else {
+        builder
+            .tempfile_in(&dir)
+            .and_then(|file| file.keep().map_err(|e| e.error))
+            .map(|(_, path)| path)
+    };
+
+    let path = created.map_err(|e| {
+        ShellError::labeled_error(
+            format!("Could not create a temporary path in {:?}. {}", dir, e),
+            e.to_string(),
+            tmpdir
+                .map(|tmpdir| tmpdir.tag)
+                .unwrap_or_else(|| name.clone()),
+        )
+    })?;
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::path(path).into_value(name),
+    )))
+}
+
+/// Splits a template like `build.XXXXXX` into the text to keep and how many random characters
+/// replace the Xs at its end.
+fn split_template(template: &str) -> Option<(&str, usize)> {
+    let prefix = template.trim_end_matches('X');
+    let random_chars = template.len() - prefix.len();
+
+    if random_chars >= 3 && !prefix.contains(std::path::is_separator) {
+        Some((prefix, random_chars))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{split_template, Mktemp};
+
+    #[test]
+    fn splits_templates() {
+        assert_eq!(split_template("build.XXXXXX"), Some(("build.", 6)));
+        assert_eq!(split_template("XXX"), Some(("", 3)));
+        assert_eq!(split_template("build.XX"), None);
+        assert_eq!(split_template("XXXX.log"), None);
+        assert_eq!(split_template("dir/XXXXXX"), None);
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Mktemp {})
+    }
+}
@@ -0,0 +1,295 @@
+use crate::commands::help::get_help;
+use crate::commands::source::run_lines;
+use crate::commands::{whole_stream_command, Command, WholeStreamCommand};
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{hir::Block, ReturnSuccess, Scope, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+use std::ffi::OsString;
+use std::path::Path;
+
+pub struct Module;
+
+#[derive(Deserialize)]
+pub struct ModuleArgs {
+    name: Tagged<String>,
+    #[allow(unused)]
+    block: Block,
+}
+
+/// What a module gives to the places it is used in: the commands and aliases it defines, the
+/// environment variables it sets, and the registry its commands run with.
+#[derive(Debug, Clone)]
+pub struct Exports {
+    pub(crate) commands: IndexMap<String, Command>,
+    pub(crate) env: IndexMap<String, String>,
+    pub(crate) registry: CommandRegistry,
+}
+
+impl Exports {
+    /// Registers the commands of the module `name` under their qualified names, like
+    /// `name command`, along with a `name` command that lists them.
+    pub(crate) fn register(&self, name: &str, registry: &CommandRegistry) {
+        let mut registry = registry.clone();
+
+        registry.insert(
+            name,
+            whole_stream_command(Namespace {
+                name: name.to_string(),
+                usage: format!("Commands from the {} module.", name),
+            }),
+        );
+
+        for command in self.commands.keys() {
+            let qualified = format!("{} {}", name, command);
+            if let Some(exported) = self.export(command, &qualified) {
+                registry.insert(qualified, exported);
+            }
+        }
+    }
+
+    /// The command of the module called `command`, to be registered as `name`. It keeps running
+    /// with the commands of the module, so it can call them without their qualified names.
+    pub(crate) fn export(&self, command: &str, name: &str) -> Option<Command> {
+        let command = self.commands.get(command)?.clone();
+
+        Some(whole_stream_command(Exported {
+            name: name.to_string(),
+            command,
+            registry: self.registry.clone(),
+        }))
+    }
+}
+
+#[async_trait]
+impl WholeStreamCommand for Module {
+    fn name(&self) -> &str {
+        "module"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("module")
+            .required("name", SyntaxShape::String, "the name of the module")
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the commands, aliases and environment variables of the module",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Define a module of commands, aliases and environment variables, to be used with `use`. Its commands can also be called with the name of the module in front."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        module(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Define a module and call one of its commands by its qualified name",
+                example: "module greetings { def hello [name] { echo $name }; alias hi [] { hello nu } }; greetings hi",
+                result: None,
+            },
+            Example {
+                description: "Define a module that also sets an environment variable",
+                example: "module editor { load-env [EDITOR vim]; alias e [file] { ^vim $file } }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn module(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let scoped = registry.scoped();
+    let mut context = Context::from_args(&args, &scoped);
+    let scope = args.call_info.scope.clone();
+    let raw_input = args.raw_input.clone();
+    let span = args
+        .call_info
+        .args
+        .positional
+        .as_ref()
+        .and_then(|positional| positional.get(1))
+        .map(|block| block.span);
+
+    let (ModuleArgs { name, .. }, _) = args.process(&registry).await?;
+
+    if registry.has(&name.item) && registry.get_module(&name.item).is_none() {
+        return Err(ShellError::labeled_error(
+            "Could not define the module",
+            format!("there is already a command named {}", name.item),
+            &name.tag,
+        ));
+    }
+
+    // The body is parsed again one pipeline at a time, so the commands of the module are known
+    // when the ones after them are parsed
+    let body = span
+        .and_then(|span| raw_input.get(span.start()..span.end()))
+        .and_then(|body| body.strip_prefix('{'))
+        .and_then(|body| body.strip_suffix('}'));
+
+    let (body, offset) = match (body, span) {
+        (Some(body), Some(span)) => (body, span.start() + 1),
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Could not define the module",
+                "modules can only be defined on a line of their own",
+                &name.tag,
+            ))
+        }
+    };
+
+    context.raw_input = raw_input.clone();
+    let exports = evaluate(body, offset, None, &mut context, &scope).await?;
+
+    exports.register(&name.item, &registry);
+    registry.clone().add_module(name.item, exports);
+
+    Ok(OutputStream::empty())
+}
+
+/// Runs the source of a module, giving the commands and aliases it defines and the environment
+/// variables it sets. The environment is left as it was, until the module is used.
+pub(crate) async fn evaluate(
+    source: &str,
+    offset: usize,
+    dir: Option<&Path>,
+    context: &mut Context,
+    scope: &Scope,
+) -> Result<Exports, ShellError> {
+    let registry = context.registry().clone();
+    let before = context.get_env();
+    let names = registry.names();
+    let commands: Vec<_> = names
+        .iter()
+        .filter_map(|name| registry.get_command(name))
+        .collect();
+
+    let result = run_lines(source, offset, dir, context, scope).await;
+
+    let mut env = IndexMap::new();
+    for (name, value) in context.get_env() {
+        if before.get(&name) != Some(&value) {
+            env.insert(name, value);
+        }
+    }
+
+    context.with_host(|host| {
+        for name in env.keys() {
+            match before.get(name) {
+                Some(value) => host.env_set(OsString::from(name), OsString::from(value)),
+                None => host.env_rm(OsString::from(name)),
+            }
+        }
+    });
+
+    result?;
+
+    let mut exported = IndexMap::new();
+    for name in registry.names() {
+        let command = match registry.get_command(&name) {
+            Some(command) => command,
+            None => continue,
+        };
+
+        if !commands.iter().any(|existing| existing.is(&command)) {
+            exported.insert(name, command);
+        }
+    }
+
+    Ok(Exports {
+        commands: exported,
+        env,
+        registry,
+    })
+}
+
+/// A command of a module, under the name it was registered with.
+struct Exported {
+    name: String,
+    command: Command,
+    registry: CommandRegistry,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Exported {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> Signature {
+        let mut signature = self.command.signature();
+        signature.name = self.name.clone();
+        signature
+    }
+
+    fn usage(&self) -> &str {
+        self.command.usage()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        self.command.stream_command().examples()
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        _registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        self.command
+            .stream_command()
+            .run(args, &self.registry)
+            .await
+    }
+}
+
+/// The name of a module as a command, listing the commands of the module.
+struct Namespace {
+    name: String,
+    usage: String,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Namespace {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(&self.name)
+    }
+
+    fn usage(&self) -> &str {
+        &self.usage
+    }
+
+    async fn run(
+        &self,
+        _args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::string(get_help(self, registry)).into_value(Tag::unknown()),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Module;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Module {})
+    }
+}
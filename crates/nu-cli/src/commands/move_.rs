@@ -0,0 +1,262 @@
+use crate::commands::group_by::suggestions;
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct MoveColumn;
+
+#[derive(Deserialize)]
+pub struct MoveArgs {
+    rest: Vec<Tagged<String>>,
+    before: Option<Tagged<String>>,
+    after: Option<Tagged<String>>,
+    first: bool,
+    last: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for MoveColumn {
+    fn name(&self) -> &str {
+        "move"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("move")
+            .rest(SyntaxShape::String, "the column(s) to move")
+            .named(
+                "before",
+                SyntaxShape::String,
+                "the column to put the moved columns before",
+                Some('b'),
+            )
+            .named(
+                "after",
+                SyntaxShape::String,
+                "the column to put the moved columns after",
+                Some('a'),
+            )
+            .switch("first", "put the moved columns first", Some('f'))
+            .switch("last", "put the moved columns last", Some('l'))
+    }
+
+    fn usage(&self) -> &str {
+        "Moves columns of the table, keeping the order they are given in."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        move_columns(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the size of the files before their names",
+                example: "ls | move size --before name",
+                result: None,
+            },
+            Example {
+                description: "Make the modification date the first column",
+                example: "ls | move modified --first",
+                result: None,
+            },
+        ]
+    }
+}
+
+enum Destination {
+    Before(Tagged<String>),
+    After(Tagged<String>),
+    First,
+    Last,
+}
+
+async fn move_columns(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (
+        MoveArgs {
+            rest: columns,
+            before,
+            after,
+            first,
+            last,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    let destination = match (before, after, first, last) {
+        (Some(column), None, false, false) => Destination::Before(column),
+        (None, Some(column), false, false) => Destination::After(column),
+        (None, None, true, false) => Destination::First,
+        (None, None, false, true) => Destination::Last,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected one destination",
+                "requires exactly one of --before, --after, --first or --last",
+                name,
+            ))
+        }
+    };
+
+    if columns.is_empty() {
+        return Err(ShellError::labeled_error(
+            "Expected columns",
+            "requires at least one column to move",
+            name,
+        ));
+    }
+
+    if let Destination::Before(anchor) | Destination::After(anchor) = &destination {
+        if columns.iter().any(|column| column.item == anchor.item) {
+            return Err(ShellError::labeled_error(
+                "Can't move a column next to itself",
+                "this column is also being moved",
+                &anchor.tag,
+            ));
+        }
+    }
+
+    Ok(input
+        .map(move |item| match move_row(&item, &columns, &destination) {
+            Ok(moved) => ReturnSuccess::value(moved),
+            Err(err) => Err(err),
+        })
+        .to_output_stream())
+}
+
+fn move_row(
+    row: &Value,
+    columns: &[Tagged<String>],
+    destination: &Destination,
+) -> Result<Value, ShellError> {
+    let dict = match &row.value {
+        UntaggedValue::Row(dict) => dict,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected table rows",
+                "requires table input",
+                &row.tag,
+            ))
+        }
+    };
+
+    let mut moved = vec![];
+    for column in columns {
+        match dict.entries.get(&column.item) {
+            Some(value) => moved.push((column.item.clone(), value.clone())),
+            None => return Err(suggestions(column.borrow_tagged(), row)),
+        }
+    }
+
+    let rest: Vec<(String, Value)> = dict
+        .entries
+        .iter()
+        .filter(|(key, _)| !columns.iter().any(|column| &column.item == *key))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+
+    let position = match destination {
+        Destination::First => 0,
+        Destination::Last => rest.len(),
+        Destination::Before(anchor) | Destination::After(anchor) => {
+            match rest.iter().position(|(key, _)| key == &anchor.item) {
+                Some(idx) if matches!(destination, Destination::After(_)) => idx + 1,
+                Some(idx) => idx,
+                None => return Err(suggestions(anchor.borrow_tagged(), row)),
+            }
+        }
+    };
+
+    let mut entries: IndexMap<String, Value> = IndexMap::new();
+    let (head, tail) = rest.split_at(position);
+    entries.extend(head.iter().cloned());
+    entries.extend(moved);
+    entries.extend(tail.iter().cloned());
+
+    Ok(UntaggedValue::Row(entries.into()).into_value(&row.tag))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{move_row, Destination, MoveColumn};
+    use indexmap::IndexMap;
+    use nu_errors::ShellError;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::*;
+
+    fn int(n: i64) -> Value {
+        UntaggedValue::int(n).into_untagged_value()
+    }
+
+    fn row(entries: IndexMap<String, Value>) -> Value {
+        UntaggedValue::row(entries).into_untagged_value()
+    }
+
+    fn columns_of(value: &Value) -> Vec<String> {
+        value.data_descriptors()
+    }
+
+    fn abcd() -> Value {
+        row(indexmap! {
+            "a".into() => int(1),
+            "b".into() => int(2),
+            "c".into() => int(3),
+            "d".into() => int(4),
+        })
+    }
+
+    fn tagged(names: &[&str]) -> Vec<Tagged<String>> {
+        names
+            .iter()
+            .map(|n| n.to_string().tagged_unknown())
+            .collect()
+    }
+
+    #[test]
+    fn moves_columns_relative_to_another() -> Result<(), ShellError> {
+        let before = move_row(
+            &abcd(),
+            &tagged(&["d", "c"]),
+            &Destination::Before("b".to_string().tagged_unknown()),
+        )?;
+        let after = move_row(
+            &abcd(),
+            &tagged(&["a"]),
+            &Destination::After("c".to_string().tagged_unknown()),
+        )?;
+
+        assert_eq!(columns_of(&before), vec!["a", "d", "c", "b"]);
+        assert_eq!(columns_of(&after), vec!["b", "c", "a", "d"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn moves_columns_to_the_ends() -> Result<(), ShellError> {
+        let first = move_row(&abcd(), &tagged(&["c"]), &Destination::First)?;
+        let last = move_row(&abcd(), &tagged(&["a", "b"]), &Destination::Last)?;
+
+        assert_eq!(columns_of(&first), vec!["c", "a", "b", "d"]);
+        assert_eq!(columns_of(&last), vec!["c", "d", "a", "b"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(MoveColumn {})
+    }
+}
@@ -0,0 +1,77 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, UntaggedValue, Value};
+
+pub struct Mut;
+
+#[async_trait]
+impl WholeStreamCommand for Mut {
+    fn name(&self) -> &str {
+        "mut"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("mut")
+            .required("name", SyntaxShape::String, "the name of the variable")
+            .required("=", SyntaxShape::String, "the equals sign")
+            .required(
+                "value",
+                SyntaxShape::Math,
+                "the value of the variable, which can be an expression",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        r#"Set a variable that can be set again, for the rest of the block, or of the session at the top level.
+
+The variable is set again with `$name = <value>`, or added to or taken from with `+=` and `-=`.
+This can be done in the blocks run by commands, like the body of a loop, and is seen by the
+block the variable was made in."#
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        _registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        // Like a let, a mut on its own is run by the block it is in
+        Err(ShellError::labeled_error(
+            "Could not set the variable",
+            "mut has to be on its own, not part of a pipeline",
+            args.call_info.name_tag,
+        ))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Add up numbers in a loop",
+                example: "mut total = 0; for x in 1..4 { $total += $x }; echo $total",
+                result: None,
+            },
+            Example {
+                description: "Set a variable again",
+                example: "mut greeting = hello; $greeting = hi; echo $greeting",
+                result: Some(vec![Value::from("hi")]),
+            },
+            Example {
+                description: "Take from a variable and add to it",
+                example: "mut n = 10; $n -= 3; $n += 1; echo $n",
+                result: Some(vec![UntaggedValue::int(8).into()]),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mut;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Mut {})
+    }
+}
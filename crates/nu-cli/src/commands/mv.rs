@@ -1,14 +1,24 @@
+use crate::commands::chmod::path_from_value;
+use crate::commands::classified::block::run_block;
 use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
+use futures::stream::once;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, SyntaxShape};
-use nu_source::Tagged;
+use nu_protocol::{hir::Block, Scope, Signature, SyntaxShape, Value};
+use nu_source::{SpannedItem, Tagged, TaggedItem};
+use nu_value_ext::get_data_by_key;
 use std::path::PathBuf;
 
 pub struct Move;
 
 #[derive(Deserialize)]
+struct Arguments {
+    src: Option<Tagged<PathBuf>>,
+    dst: Option<Tagged<PathBuf>>,
+    using: Option<Block>,
+}
+
 pub struct MoveArgs {
     pub src: Tagged<PathBuf>,
     pub dst: Tagged<PathBuf>,
@@ -22,16 +32,22 @@ impl WholeStreamCommand for Move {
 
     fn signature(&self) -> Signature {
         Signature::build("mv")
-            .required(
+            .optional(
                 "source",
                 SyntaxShape::Pattern,
-                "the location to move files/directories from",
+                "the location to move files/directories from (default: the paths in the pipeline)",
             )
-            .required(
+            .optional(
                 "destination",
                 SyntaxShape::Path,
                 "the location to move files/directories to",
             )
+            .named(
+                "using",
+                SyntaxShape::Block,
+                "a block giving the new path of each file in the pipeline",
+                Some('u'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -63,6 +79,16 @@ impl WholeStreamCommand for Move {
                 example: "mv *.txt my/subdirectory",
                 result: None,
             },
+            Example {
+                description: "Change the extension of the JPEG files",
+                example: "ls *.jpeg | mv --using { echo $it.name | str find-replace jpeg jpg }",
+                result: None,
+            },
+            Example {
+                description: "Move the files named in a table of from and to columns",
+                example: "open renames.csv | mv",
+                result: None,
+            },
         ]
     }
 }
@@ -71,9 +97,102 @@ async fn mv(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStrea
     let registry = registry.clone();
     let name = args.call_info.name_tag.clone();
     let shell_manager = args.shell_manager.clone();
-    let (args, _) = args.process(&registry).await?;
+    let scope = args.call_info.scope.clone();
+    let mut context = Context::from_raw(&args, &registry);
+    let (Arguments { src, dst, using }, input) = args.process(&registry).await?;
+
+    let mut outputs = vec![];
+    for (src, dst) in renames(src, dst, using, input, &scope, &mut context, &name).await? {
+        outputs.push(shell_manager.mv(MoveArgs { src, dst }, name.clone())?);
+    }
 
-    shell_manager.mv(args, name)
+    Ok(futures::stream::iter(outputs).flatten().to_output_stream())
+}
+
+/// The sources and destinations to move or copy: the ones given, the paths in the pipeline with
+/// the destinations the block gives for them, or the `from` and `to` columns of the rows in the
+/// pipeline.
+pub(crate) async fn renames(
+    src: Option<Tagged<PathBuf>>,
+    dst: Option<Tagged<PathBuf>>,
+    using: Option<Block>,
+    input: InputStream,
+    scope: &Scope,
+    context: &mut Context,
+    name: &Tag,
+) -> Result<Vec<(Tagged<PathBuf>, Tagged<PathBuf>)>, ShellError> {
+    match (src, dst, using) {
+        (Some(src), Some(dst), None) => Ok(vec![(src, dst)]),
+        (Some(src), None, None) => Err(ShellError::labeled_error(
+            "Expected a destination",
+            "needs a place to go to",
+            &src.tag,
+        )),
+        (None, None, Some(block)) => {
+            let mut pairs = vec![];
+
+            for row in input.collect::<Vec<Value>>().await {
+                let src = path_from_value(&row)?;
+                let for_block = row.clone();
+
+                let output = run_block(
+                    &block,
+                    context,
+                    once(async { Ok(for_block) }).to_input_stream(),
+                    &row,
+                    &scope.vars,
+                    &scope.env,
+                )
+                .await?
+                .drain_vec()
+                .await;
+
+                if let Some(err) = context.get_errors().first() {
+                    return Err(err.clone());
+                }
+
+                match &output[..] {
+                    [dst] => pairs.push((src, path_from_value(dst)?)),
+                    _ => {
+                        return Err(ShellError::labeled_error(
+                            "Expected a path",
+                            "the block should give a single path",
+                            block.span,
+                        ))
+                    }
+                }
+            }
+
+            Ok(pairs)
+        }
+        (None, None, None) => {
+            let mut pairs = vec![];
+
+            for row in input.collect::<Vec<Value>>().await {
+                let column = |column: &str| {
+                    get_data_by_key(&row, column.spanned_unknown())
+                        .and_then(|value| value.as_path().ok())
+                        .map(|path| path.tagged(&row.tag))
+                        .ok_or_else(|| {
+                            ShellError::labeled_error(
+                                "Expected from and to columns",
+                                format!("row has no {} path", column),
+                                &row.tag,
+                            )
+                        })
+                };
+
+                pairs.push((column("from")?, column("to")?));
+            }
+
+            Ok(pairs)
+        }
+        _ => Err(ShellError::labeled_error(
+            "Can't give paths along with --using",
+            "--using renames the paths in the pipeline",
+            name,
+        )),
+    }
 }
 
 #[cfg(test)]
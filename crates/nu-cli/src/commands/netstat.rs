@@ -0,0 +1,332 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue};
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+pub struct Netstat;
+
+#[derive(Deserialize)]
+pub struct NetstatArgs {
+    tcp: bool,
+    udp: bool,
+    listening: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Netstat {
+    fn name(&self) -> &str {
+        "netstat"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("netstat")
+            .switch("tcp", "only list TCP sockets", Some('t'))
+            .switch("udp", "only list UDP sockets", Some('u'))
+            .switch(
+                "listening",
+                "only list the sockets waiting for connections",
+                Some('l'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "List the open network sockets, with their addresses, state and the process that owns them."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        netstat(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Find what is listening on port 8080",
+                example: "netstat --listening | where \"local port\" == 8080",
+                result: None,
+            },
+            Example {
+                description: "Count the established connections of each process",
+                example: "netstat --tcp | where state == ESTABLISHED | group-by process",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// The socket tables the kernel gives, and the protocol of each.
+const TABLES: [(&str, &str); 4] = [
+    ("tcp", "/proc/net/tcp"),
+    ("tcp6", "/proc/net/tcp6"),
+    ("udp", "/proc/net/udp"),
+    ("udp6", "/proc/net/udp6"),
+];
+
+/// A line of a socket table.
+#[derive(Debug, PartialEq)]
+struct Socket {
+    local: (IpAddr, u16),
+    remote: (IpAddr, u16),
+    state: &'static str,
+    inode: u64,
+}
+
+async fn netstat(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (
+        NetstatArgs {
+            tcp,
+            udp,
+            listening,
+        },
+        _,
+    ) = args.process(&registry).await?;
+
+    let owners = socket_owners();
+    let mut rows = vec![];
+
+    for (proto, table) in TABLES.iter() {
+        let is_tcp = proto.starts_with("tcp");
+        if (tcp || udp) && !(tcp && is_tcp || udp && !is_tcp) {
+            continue;
+        }
+
+        // A kernel without IPv6 has no tables for it.
+        let contents = match std::fs::read_to_string(table) {
+            Ok(contents) => contents,
+            Err(_) => continue,
+        };
+
+        for socket in contents
+            .lines()
+            .skip(1)
+            .filter_map(|line| parse_line(line, is_tcp))
+        {
+            if listening && socket.state != "LISTEN" && socket.state != "UNCONN" {
+                continue;
+            }
+
+            let mut dict = TaggedDictBuilder::new(&name);
+            dict.insert_untagged("proto", UntaggedValue::string(*proto));
+            dict.insert_untagged(
+                "local address",
+                UntaggedValue::string(socket.local.0.to_string()),
+            );
+            dict.insert_untagged("local port", UntaggedValue::int(socket.local.1));
+            dict.insert_untagged(
+                "remote address",
+                UntaggedValue::string(socket.remote.0.to_string()),
+            );
+            dict.insert_untagged("remote port", UntaggedValue::int(socket.remote.1));
+            dict.insert_untagged("state", UntaggedValue::string(socket.state));
+
+            match owners.get(&socket.inode) {
+                Some((pid, process)) => {
+                    dict.insert_untagged("pid", UntaggedValue::int(*pid));
+                    dict.insert_untagged("process", UntaggedValue::string(process));
+                }
+                None => {
+                    dict.insert_untagged("pid", UntaggedValue::nothing());
+                    dict.insert_untagged("process", UntaggedValue::nothing());
+                }
+            }
+
+            rows.push(ReturnSuccess::value(dict.into_value()));
+        }
+    }
+
+    Ok(futures::stream::iter(rows).to_output_stream())
+}
+
+/// Reads a line of a socket table, like
+/// `0: 0100007F:1F90 00000000:0000 0A 00000000:00000000 00:00000000 00000000 1000 0 4242 ...`.
+fn parse_line(line: &str, is_tcp: bool) -> Option<Socket> {
+    let fields = line.split_whitespace().collect::<Vec<_>>();
+    if fields.len() < 10 {
+        return None;
+    }
+
+    let state = u8::from_str_radix(fields[3], 16).ok()?;
+
+    Some(Socket {
+        local: parse_address(fields[1])?,
+        remote: parse_address(fields[2])?,
+        state: if is_tcp {
+            tcp_state(state)
+        } else if state == 1 {
+            "ESTABLISHED"
+        } else {
+            // UDP sockets without a peer show as closed, though they're receiving.
+            "UNCONN"
+        },
+        inode: fields[9].parse().ok()?,
+    })
+}
+
+/// Reads an address like `0100007F:1F90`. The address is written as the words the kernel keeps
+/// it in, so their bytes are in the machine's order.
+fn parse_address(text: &str) -> Option<(IpAddr, u16)> {
+    let mut parts = text.split(':');
+    let address = parts.next()?;
+    let port = u16::from_str_radix(parts.next()?, 16).ok()?;
+
+    let mut bytes = vec![];
+    for idx in (0..address.len()).step_by(8) {
+        let word = u32::from_str_radix(address.get(idx..idx + 8)?, 16).ok()?;
+        bytes.extend_from_slice(&word.to_ne_bytes());
+    }
+
+    let address = match bytes.len() {
+        4 => IpAddr::V4(Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3])),
+        16 => {
+            let mut octets = [0; 16];
+            octets.copy_from_slice(&bytes);
+            IpAddr::V6(Ipv6Addr::from(octets))
+        }
+        _ => return None,
+    };
+
+    Some((address, port))
+}
+
+fn tcp_state(state: u8) -> &'static str {
+    match state {
+        0x01 => "ESTABLISHED",
+        0x02 => "SYN_SENT",
+        0x03 => "SYN_RECV",
+        0x04 => "FIN_WAIT1",
+        0x05 => "FIN_WAIT2",
+        0x06 => "TIME_WAIT",
+        0x07 => "CLOSE",
+        0x08 => "CLOSE_WAIT",
+        0x09 => "LAST_ACK",
+        0x0A => "LISTEN",
+        0x0B => "CLOSING",
+        _ => "UNKNOWN",
+    }
+}
+
+/// The pid and name of the process owning each socket, by inode. Only the processes we're allowed
+/// to look into are found, so sockets of other users' processes have no owner unless run as root.
+fn socket_owners() -> HashMap<u64, (u64, String)> {
+    let mut owners = HashMap::new();
+
+    let procs = match std::fs::read_dir("/proc") {
+        Ok(procs) => procs,
+        Err(_) => return owners,
+    };
+
+    for proc in procs.filter_map(Result::ok) {
+        let pid = match proc.file_name().to_string_lossy().parse::<u64>() {
+            Ok(pid) => pid,
+            Err(_) => continue,
+        };
+
+        let fds = match std::fs::read_dir(proc.path().join("fd")) {
+            Ok(fds) => fds,
+            Err(_) => continue,
+        };
+
+        let name = std::fs::read_to_string(proc.path().join("comm"))
+            .map(|comm| comm.trim_end().to_string())
+            .unwrap_or_default();
+
+        for fd in fds.filter_map(Result::ok) {
+            let target = match std::fs::read_link(fd.path()) {
+                Ok(target) => target,
+                Err(_) => continue,
+            };
+
+            let inode = target
+                .to_string_lossy()
+                .strip_prefix("socket:[")
+                .and_then(|rest| rest.strip_suffix(']'))
+                .and_then(|inode| inode.parse::<u64>().ok());
+
+            if let Some(inode) = inode {
+                owners.insert(inode, (pid, name.clone()));
+            }
+        }
+    }
+
+    owners
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_address, parse_line, Netstat, Socket};
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    fn hex_v4(address: Ipv4Addr) -> String {
+        format!("{:08X}", u32::from_ne_bytes(address.octets()))
+    }
+
+    #[test]
+    fn reads_addresses() {
+        let localhost = format!("{}:1F90", hex_v4(Ipv4Addr::LOCALHOST));
+        assert_eq!(
+            parse_address(&localhost),
+            Some((IpAddr::V4(Ipv4Addr::LOCALHOST), 8080))
+        );
+
+        let octets = Ipv6Addr::LOCALHOST.octets();
+        let words = octets
+            .chunks(4)
+            .map(|word| {
+                format!(
+                    "{:08X}",
+                    u32::from_ne_bytes([word[0], word[1], word[2], word[3]])
+                )
+            })
+            .collect::<String>();
+        assert_eq!(
+            parse_address(&format!("{}:0016", words)),
+            Some((IpAddr::V6(Ipv6Addr::LOCALHOST), 22))
+        );
+
+        assert_eq!(parse_address("0100007F"), None);
+        assert_eq!(parse_address("7F:0016"), None);
+    }
+
+    #[test]
+    fn reads_socket_lines() {
+        let line = format!(
+            "   0: {}:1F90 00000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 4242 1 0000000000000000 100 0 0 10 0",
+            hex_v4(Ipv4Addr::LOCALHOST)
+        );
+
+        assert_eq!(
+            parse_line(&line, true),
+            Some(Socket {
+                local: (IpAddr::V4(Ipv4Addr::LOCALHOST), 8080),
+                remote: (IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0),
+                state: "LISTEN",
+                inode: 4242,
+            })
+        );
+        assert_eq!(
+            parse_line(&line.replace(" 0A ", " 07 "), false).map(|socket| socket.state),
+            Some("UNCONN")
+        );
+        assert_eq!(
+            parse_line("  sl  local_address rem_address   st", true),
+            None
+        );
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Netstat {})
+    }
+}
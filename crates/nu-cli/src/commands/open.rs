@@ -1,7 +1,7 @@
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{CommandAction, ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_protocol::{CommandAction, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
 use nu_source::{AnchorLocation, Span, Tagged};
 use std::path::{Path, PathBuf};
 extern crate encoding_rs;
@@ -17,6 +17,7 @@ pub struct Open;
 pub struct OpenArgs {
     path: Tagged<PathBuf>,
     raw: Tagged<bool>,
+    binary: Tagged<bool>,
     encoding: Option<Tagged<String>>,
 }
 
@@ -38,6 +39,11 @@ impl WholeStreamCommand for Open {
                 "load content as a string instead of a table",
                 Some('r'),
             )
+            .switch(
+                "binary",
+                "load content as binary, as-is, without decoding it as text",
+                Some('b'),
+            )
             .named(
                 "encoding",
                 SyntaxShape::String,
@@ -77,6 +83,11 @@ documentation link at https://docs.rs/encoding_rs/0.8.23/encoding_rs/#statics"#
                 example: "open file.csv --encoding iso-8859-1 | from csv",
                 result: None,
             },
+            Example {
+                description: "Opens an image and pipes its bytes to an external command",
+                example: "open image.png --binary | ^convert - out.jpg",
+                result: None,
+            },
         ]
     }
 }
@@ -104,17 +115,85 @@ async fn open(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStr
         OpenArgs {
             path,
             raw,
+            binary,
             encoding,
         },
         _,
     ) = args.process(&registry).await?;
+
+    // Objects on S3 are given as they arrive, unless they're converted into a table, which needs
+    // all of them
+    #[cfg(feature = "s3")]
+    {
+        if let Some(location) = crate::shell::s3_shell::S3Location::resolve(&full_path, &path.item)
+        {
+            if binary.item || raw.item || path.extension().is_none() {
+                let as_binary = binary.item;
+                let (_, chunks, _) =
+                    crate::shell::s3_shell::stream(&location, path.tag.span).await?;
+
+                return Ok(chunks
+                    .map(move |chunk| match chunk.value {
+                        UntaggedValue::Primitive(Primitive::String(s)) if as_binary => {
+                            ReturnSuccess::value(
+                                UntaggedValue::binary(s.into_bytes()).into_value(chunk.tag),
+                            )
+                        }
+                        UntaggedValue::Error(e) => Err(e),
+                        _ => ReturnSuccess::value(chunk),
+                    })
+                    .to_output_stream());
+            }
+        }
+    }
+
+    if binary.item {
+        // Files on this machine are read as-is, as decoding them as text (like from UTF-16) can
+        // change their bytes
+        let local_path = full_path.join(&path.item);
+        let (contents, contents_tag) = if local_path.is_file() {
+            match std::fs::read(&local_path) {
+                Ok(bytes) => (
+                    UntaggedValue::binary(bytes),
+                    Tag {
+                        span: path.tag.span,
+                        anchor: Some(AnchorLocation::File(
+                            dunce::canonicalize(&local_path)
+                                .unwrap_or(local_path)
+                                .to_string_lossy()
+                                .to_string(),
+                        )),
+                    },
+                ),
+                Err(_) => {
+                    return Err(ShellError::labeled_error(
+                        format!("Cannot open {:?} for reading.", &local_path),
+                        "file not found",
+                        &path.tag,
+                    ))
+                }
+            }
+        } else {
+            let (_, contents, contents_tag) =
+                fetch_from_shell(&full_path, &path, "".to_string()).await?;
+            match contents {
+                UntaggedValue::Primitive(Primitive::String(s)) => {
+                    (UntaggedValue::binary(s.into_bytes()), contents_tag)
+                }
+                contents => (contents, contents_tag),
+            }
+        };
+
+        return Ok(OutputStream::one(ReturnSuccess::value(
+            contents.into_value(&contents_tag),
+        )));
+    }
+
     let enc = match encoding {
         Some(e) => e.to_string(),
         _ => "".to_string(),
     };
-    let result = fetch(&full_path, &path.item, path.tag.span, enc).await;
-
-    let (file_extension, contents, contents_tag) = result?;
+    let (file_extension, contents, contents_tag) = fetch_from_shell(&full_path, &path, enc).await?;
 
     let file_extension = if raw.item {
         None
@@ -135,6 +214,34 @@ async fn open(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStr
     }
 }
 
+/// Reads the file from wherever the current shell is, over SSH, inside an archive, from S3, or on
+/// this machine.
+pub(crate) async fn fetch_from_shell(
+    cwd: &PathBuf,
+    path: &Tagged<PathBuf>,
+    encoding: String,
+) -> Result<(Option<String>, UntaggedValue, Tag), ShellError> {
+    if let Some(location) = crate::shell::ssh_shell::SshLocation::resolve(cwd, &path.item) {
+        return location.fetch(path.tag.span);
+    }
+
+    let full_path = cwd.join(&path.item);
+    if !full_path.exists() {
+        if let Some((archive, inner)) = crate::shell::archive_shell::split_path(&full_path) {
+            return crate::shell::archive_shell::fetch(&archive, &inner, path.tag.span);
+        }
+    }
+
+    #[cfg(feature = "s3")]
+    {
+        if let Some(location) = crate::shell::s3_shell::S3Location::resolve(cwd, &path.item) {
+            return crate::shell::s3_shell::fetch(&location, path.tag.span).await;
+        }
+    }
+
+    fetch(cwd, &path.item, path.tag.span, encoding).await
+}
+
 pub async fn fetch(
     cwd: &PathBuf,
     location: &PathBuf,
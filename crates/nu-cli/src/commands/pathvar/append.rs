@@ -0,0 +1,84 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape};
+use nu_source::Tagged;
+use std::path::PathBuf;
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+pub struct Arguments {
+    path: Tagged<PathBuf>,
+    force: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "pathvar append"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("pathvar append")
+            .required(
+                "path",
+                SyntaxShape::Path,
+                "the directory to put at the end of the PATH",
+            )
+            .switch(
+                "force",
+                "add the directory even if it doesn't exist",
+                Some('f'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Put a directory at the end of the PATH, moving it there if the PATH has it already."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        append(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Look for commands in ~/.local/bin after everywhere else",
+            example: "pathvar append ~/.local/bin",
+            result: None,
+        }]
+    }
+}
+
+async fn append(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let mut context = Context::from_raw(&args, &registry);
+    let cwd = PathBuf::from(args.shell_manager.path());
+    let name = args.call_info.name_tag.clone();
+    let (Arguments { path, force }, _) = args.process(&registry).await?;
+
+    let directory = super::directory(&path, &cwd, force)?;
+    let mut paths = super::paths(&mut context);
+    paths.retain(|path| *path != directory);
+    paths.push(directory);
+
+    super::set_paths(&mut context, paths, &name)?;
+
+    Ok(OutputStream::empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
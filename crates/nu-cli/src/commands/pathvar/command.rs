@@ -0,0 +1,69 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue};
+
+pub struct Command;
+
+#[async_trait]
+impl WholeStreamCommand for Command {
+    fn name(&self) -> &str {
+        "pathvar"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("pathvar")
+    }
+
+    fn usage(&self) -> &str {
+        "Show the entries of the PATH, and whether each exists. Use the subcommands to change them."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let mut context = Context::from_raw(&args, &registry);
+        let name = args.call_info.name_tag.clone();
+
+        let rows = super::paths(&mut context).into_iter().map(move |path| {
+            let mut dict = TaggedDictBuilder::new(&name);
+            let exists = path.is_dir();
+            dict.insert_untagged("path", UntaggedValue::path(path));
+            dict.insert_untagged("exists", UntaggedValue::boolean(exists));
+
+            ReturnSuccess::value(dict.into_value())
+        });
+
+        Ok(futures::stream::iter(rows).to_output_stream())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the entries of the PATH",
+                example: "pathvar",
+                result: None,
+            },
+            Example {
+                description: "Find the entries of the PATH that don't exist",
+                example: "pathvar | where exists == $false | get path",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Command;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Command {})
+    }
+}
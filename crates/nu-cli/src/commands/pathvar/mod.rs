@@ -0,0 +1,50 @@
+mod add;
+mod append;
+mod command;
+mod remove;
+
+pub use add::SubCommand as PathvarAdd;
+pub use append::SubCommand as PathvarAppend;
+pub use command::Command as Pathvar;
+pub use remove::SubCommand as PathvarRemove;
+
+use crate::prelude::*;
+use indexmap::IndexSet;
+use nu_errors::ShellError;
+use nu_source::Tagged;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// The entries of the PATH of the session, in order.
+fn paths(context: &mut Context) -> Vec<PathBuf> {
+    context
+        .with_host(|host| host.env_get(OsString::from("PATH")))
+        .map(|paths| std::env::split_paths(&paths).collect())
+        .unwrap_or_default()
+}
+
+/// Set the PATH of the session, keeping only the first of any entries given more than once.
+fn set_paths(context: &mut Context, paths: Vec<PathBuf>, tag: &Tag) -> Result<(), ShellError> {
+    let paths: IndexSet<PathBuf> = paths.into_iter().collect();
+    let joined = std::env::join_paths(paths)
+        .map_err(|err| ShellError::labeled_error("Could not set the PATH", err.to_string(), tag))?;
+
+    context.with_host(|host| host.env_set(OsString::from("PATH"), joined));
+
+    Ok(())
+}
+
+/// The absolute path to put in the PATH, which needs to be an existing directory unless forced.
+fn directory(path: &Tagged<PathBuf>, cwd: &Path, force: bool) -> Result<PathBuf, ShellError> {
+    let absolute = crate::path::absolutize(cwd, &path.item);
+
+    if !force && !absolute.is_dir() {
+        return Err(ShellError::labeled_error(
+            "Not a directory",
+            "this directory doesn't exist (use --force to add it anyway)",
+            &path.tag,
+        ));
+    }
+
+    Ok(absolute)
+}
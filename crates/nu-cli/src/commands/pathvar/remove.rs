@@ -0,0 +1,86 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape};
+use nu_source::Tagged;
+use std::path::PathBuf;
+
+pub struct SubCommand;
+
+#[derive(Deserialize)]
+pub struct Arguments {
+    path: Tagged<PathBuf>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "pathvar remove"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("pathvar remove").required(
+            "path",
+            SyntaxShape::Path,
+            "the directory to take out of the PATH",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Take a directory out of the PATH."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        remove(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Stop looking for commands in /usr/games",
+            example: "pathvar remove /usr/games",
+            result: None,
+        }]
+    }
+}
+
+async fn remove(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let mut context = Context::from_raw(&args, &registry);
+    let cwd = PathBuf::from(args.shell_manager.path());
+    let name = args.call_info.name_tag.clone();
+    let (Arguments { path }, _) = args.process(&registry).await?;
+
+    // The directory may be gone already, so it's taken out whether it exists or not
+    let directory = super::directory(&path, &cwd, true)?;
+    let mut paths = super::paths(&mut context);
+    let count = paths.len();
+    paths.retain(|path| *path != directory);
+
+    if paths.len() == count {
+        return Err(ShellError::labeled_error(
+            "Not in the PATH",
+            "the PATH doesn't have this directory",
+            &path.tag,
+        ));
+    }
+
+    super::set_paths(&mut context, paths, &name)?;
+
+    Ok(OutputStream::empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -1,11 +1,15 @@
+use crate::commands::aggregate::Aggregation;
+use crate::commands::group_by::suggestions;
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
+use indexmap::IndexMap;
 use nu_errors::ShellError;
 use nu_protocol::{
     merge_descriptors, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue,
+    Value,
 };
 use nu_source::{SpannedItem, Tagged};
-use nu_value_ext::get_data_by_key;
+use nu_value_ext::{as_string, get_data_by_key};
 
 pub struct Pivot;
 
@@ -16,6 +20,10 @@ pub struct PivotArgs {
     header_row: bool,
     #[serde(rename(deserialize = "ignore-titles"))]
     ignore_titles: bool,
+    index: Option<Tagged<String>>,
+    columns: Option<Tagged<String>>,
+    values: Option<Tagged<String>>,
+    agg: Option<Tagged<String>>,
 }
 
 #[async_trait]
@@ -36,6 +44,30 @@ impl WholeStreamCommand for Pivot {
                 "don't pivot the column names into values",
                 Some('i'),
             )
+            .named(
+                "index",
+                SyntaxShape::String,
+                "reshape long to wide: the column whose values become the rows",
+                None,
+            )
+            .named(
+                "columns",
+                SyntaxShape::String,
+                "reshape long to wide: the column whose values become the column names",
+                None,
+            )
+            .named(
+                "values",
+                SyntaxShape::String,
+                "reshape long to wide: the column whose values fill the cells",
+                None,
+            )
+            .named(
+                "agg",
+                SyntaxShape::String,
+                "how to combine several values landing in one cell (sum, avg, min, max, count, first or concat)",
+                None,
+            )
             .rest(
                 SyntaxShape::String,
                 "the names to give columns once pivoted",
@@ -53,6 +85,22 @@ impl WholeStreamCommand for Pivot {
     ) -> Result<OutputStream, ShellError> {
         pivot(args, registry).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Turn the columns of a table into rows",
+                example: "ls | pivot",
+                result: None,
+            },
+            Example {
+                description:
+                    "Reshape one row per measurement into one row per date and a column per host",
+                example: "open cpu.csv | pivot --index date --columns host --values cpu --agg avg",
+                result: None,
+            },
+        ]
+    }
 }
 
 pub async fn pivot(
@@ -64,6 +112,10 @@ pub async fn pivot(
     let (args, input): (PivotArgs, _) = args.process(&registry).await?;
     let input = input.into_vec().await;
 
+    if args.index.is_some() || args.columns.is_some() || args.values.is_some() {
+        return long_to_wide(args, &input, &name);
+    }
+
     let descs = merge_descriptors(&input);
 
     let mut headers: Vec<String> = vec![];
@@ -152,6 +204,85 @@ pub async fn pivot(
     .to_output_stream())
 }
 
+fn long_to_wide(args: PivotArgs, input: &[Value], name: &Tag) -> Result<OutputStream, ShellError> {
+    let (index, columns, values) = match (args.index, args.columns, args.values) {
+        (Some(index), Some(columns), Some(values)) => (index, columns, values),
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Missing pivot columns",
+                "--index, --columns and --values have to be given together",
+                name,
+            ))
+        }
+    };
+
+    let aggregation = match &args.agg {
+        Some(agg) => Some(Aggregation::from_name(&agg.item).ok_or_else(|| {
+            ShellError::labeled_error(
+                "Unknown aggregate function",
+                "expected sum, avg, min, max, count, first or concat",
+                &agg.tag,
+            )
+        })?),
+        None => None,
+    };
+
+    let cell = |row: &Value, column: &Tagged<String>| -> Result<Value, ShellError> {
+        row.get_data_by_key(column.borrow_spanned())
+            .ok_or_else(|| suggestions(column.borrow_tagged(), row))
+    };
+
+    let mut column_names: Vec<String> = vec![];
+    let mut rows: IndexMap<String, (Value, IndexMap<String, Vec<Value>>)> = IndexMap::new();
+
+    for row in input {
+        let index_value = cell(row, &index)?;
+        let column_name = as_string(&cell(row, &columns)?)?;
+        let value = cell(row, &values)?;
+
+        if !column_names.contains(&column_name) {
+            column_names.push(column_name.clone());
+        }
+
+        let (_, cells) = rows
+            .entry(as_string(&index_value)?)
+            .or_insert_with(|| (index_value, IndexMap::new()));
+        cells.entry(column_name).or_default().push(value);
+    }
+
+    let mut output = VecDeque::new();
+
+    for (_, (index_value, mut cells)) in rows {
+        let mut dict = TaggedDictBuilder::new(name);
+        dict.insert_value(index.item.clone(), index_value);
+
+        for column_name in &column_names {
+            let cell = match (cells.swap_remove(column_name), aggregation) {
+                (None, Some(Aggregation::Count)) => UntaggedValue::int(0).into_value(name),
+                (None, _) => UntaggedValue::nothing().into_value(name),
+                (Some(values), Some(aggregation)) => aggregation.compute(&values, name)?,
+                (Some(mut values), None) if values.len() == 1 => values.remove(0),
+                (Some(_), None) => {
+                    return Err(ShellError::labeled_error(
+                        "Several values for one cell",
+                        format!(
+                            "more than one value for {} in the same row, use --agg to combine them",
+                            column_name
+                        ),
+                        name,
+                    ))
+                }
+            };
+
+            dict.insert_value(column_name.clone(), cell);
+        }
+
+        output.push_back(ReturnSuccess::value(dict.into_value()));
+    }
+
+    Ok(futures::stream::iter(output).to_output_stream())
+}
+
 #[cfg(test)]
 mod tests {
     use super::Pivot;
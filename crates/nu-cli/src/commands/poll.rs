@@ -0,0 +1,252 @@
+use crate::commands::classified::block::run_block;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use chrono::Utc;
+use futures::channel::oneshot;
+use nu_errors::ShellError;
+use nu_protocol::{
+    Primitive, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
+
+pub struct Poll;
+
+#[derive(Deserialize)]
+pub struct PollArgs {
+    rest: Vec<Value>,
+    interval: Option<Value>,
+    count: Option<Tagged<usize>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Poll {
+    fn name(&self) -> &str {
+        "poll"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("poll")
+            .rest(
+                SyntaxShape::Any,
+                "the block to run, optionally after the time between runs, like 5sec",
+            )
+            .named(
+                "interval",
+                SyntaxShape::Unit,
+                "the time between runs (default: 2sec)",
+                Some('i'),
+            )
+            .named(
+                "count",
+                SyntaxShape::Int,
+                "stop after running the block this many times",
+                Some('c'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Run a block again and again, waiting between runs, and give the output of each run with the time it ran at, until stopped with ctrl-c."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        poll(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Watch the processes using the most CPU",
+                example: "poll 2sec { ps | sort-by cpu | reverse | first 5 }",
+                result: None,
+            },
+            Example {
+                description: "Check the size of a log file three times, a minute apart",
+                example: "poll --interval 1min --count 3 { ls app.log | get size }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn poll(
+    raw_args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = raw_args.call_info.name_tag.clone();
+    let scope = raw_args.call_info.scope.clone();
+    let ctrl_c = raw_args.ctrl_c.clone();
+    let context = Context::from_raw(&raw_args, &registry);
+    let (
+        PollArgs {
+            rest,
+            interval,
+            count,
+        },
+        _,
+    ) = raw_args.process(&registry).await?;
+
+    let (interval, block) = match (rest.as_slice(), interval) {
+        (
+            [Value {
+                value: UntaggedValue::Block(block),
+                ..
+            }],
+            interval,
+        ) => (interval, block.clone()),
+        (
+            [interval, Value {
+                value: UntaggedValue::Block(block),
+                ..
+            }],
+            None,
+        ) => (Some(interval.clone()), block.clone()),
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a block",
+                "expected a block, optionally after the time between runs",
+                name,
+            ))
+        }
+    };
+
+    let interval = match interval {
+        None => Duration::from_secs(2),
+        Some(Value {
+            value: UntaggedValue::Primitive(Primitive::Duration(secs)),
+            ..
+        }) if secs > 0 => Duration::from_secs(secs as u64),
+        Some(interval) => {
+            return Err(ShellError::labeled_error(
+                "Expected a duration",
+                "expected a positive duration, like 5sec",
+                interval.tag,
+            ))
+        }
+    };
+
+    let count = match count {
+        Some(count) if *count == 0 => {
+            return Err(ShellError::labeled_error(
+                "Expected a positive number",
+                "the block has to run at least once",
+                count.tag,
+            ))
+        }
+        Some(count) => Some(*count),
+        None => None,
+    };
+
+    let block = Arc::new(block);
+    let scope = Arc::new(scope);
+    let stop = ctrl_c.clone();
+
+    let runs = futures::stream::unfold(Some((context, 0)), move |state| {
+        let block = block.clone();
+        let scope = scope.clone();
+        let name = name.clone();
+        let ctrl_c = ctrl_c.clone();
+
+        async move {
+            let (mut context, run) = state?;
+            if matches!(count, Some(count) if run >= count) {
+                return None;
+            }
+
+            if run > 0 && !sleep(interval, ctrl_c).await {
+                return None;
+            }
+
+            let time = Utc::now();
+            let output = match run_block(
+                &block,
+                &mut context,
+                InputStream::empty(),
+                &scope.it,
+                &scope.vars,
+                &scope.env,
+            )
+            .await
+            {
+                Ok(mut output) => output.drain_vec().await,
+                Err(err) => return Some((vec![Err(err)], None)),
+            };
+
+            // Stop at the first run that fails, instead of giving the same error over and over.
+            if let Some(err) = context.get_errors().first() {
+                return Some((vec![Err(err.clone())], None));
+            }
+
+            let rows = output
+                .into_iter()
+                .map(|value| ReturnSuccess::value(with_time(value, time, &name)))
+                .collect::<Vec<_>>();
+
+            Some((rows, Some((context, run + 1))))
+        }
+    });
+
+    Ok(runs
+        .flat_map(futures::stream::iter)
+        .interruptible(stop)
+        .to_output_stream())
+}
+
+/// The value as a row with the time of the run first: rows get the column added, and other
+/// values go in a `value` column.
+fn with_time(value: Value, time: chrono::DateTime<Utc>, tag: &Tag) -> Value {
+    let mut dict = TaggedDictBuilder::new(&value.tag);
+    dict.insert_value("time", UntaggedValue::date(time).into_value(tag));
+
+    match value.value {
+        UntaggedValue::Row(row) => {
+            for (column, value) in row.entries {
+                dict.insert_value(column, value);
+            }
+        }
+        _ => dict.insert_value("value", value),
+    }
+
+    dict.into_value()
+}
+
+/// Waits for the duration in a thread of its own, giving false if ctrl-c was pressed meanwhile.
+async fn sleep(duration: Duration, ctrl_c: Arc<AtomicBool>) -> bool {
+    let (sender, receiver) = oneshot::channel();
+
+    std::thread::spawn(move || {
+        let end = Instant::now() + duration;
+
+        while !ctrl_c.load(Ordering::SeqCst) {
+            let now = Instant::now();
+            if now >= end {
+                let _ = sender.send(true);
+                return;
+            }
+
+            std::thread::sleep((end - now).min(Duration::from_millis(100)));
+        }
+
+        let _ = sender.send(false);
+    });
+
+    receiver.await.unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Poll;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Poll {})
+    }
+}
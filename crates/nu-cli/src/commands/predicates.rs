@@ -0,0 +1,138 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+
+pub struct IsEmptyValue;
+pub struct IsNull;
+
+#[derive(Deserialize)]
+pub struct PredicateArgs {
+    value: Option<Value>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for IsEmptyValue {
+    fn name(&self) -> &str {
+        "is-empty"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("is-empty").optional(
+            "value",
+            SyntaxShape::Any,
+            "the value to check, instead of the input",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Whether the value, or the input if no value is given, is empty, like nothing, an empty string or a table without rows."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        predicate(args, registry, Value::is_empty).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Check whether a string is empty",
+                example: r#"is-empty """#,
+                result: Some(vec![UntaggedValue::boolean(true).into()]),
+            },
+            Example {
+                description: "Check whether a command gave anything",
+                example: "echo [] | is-empty",
+                result: Some(vec![UntaggedValue::boolean(true).into()]),
+            },
+            Example {
+                description: "Keep the rows whose name is empty",
+                example: "open contacts.csv | where (is-empty $it.name)",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[async_trait]
+impl WholeStreamCommand for IsNull {
+    fn name(&self) -> &str {
+        "is-null"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("is-null").optional(
+            "value",
+            SyntaxShape::Any,
+            "the value to check, instead of the input",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Whether the value, or the input if no value is given, is nothing, like a column missing with `?`."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        predicate(args, registry, |value| value.value.is_none()).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Check whether a row is missing a column",
+                example: "is-null $nu.env.NOT_SET?",
+                result: None,
+            },
+            Example {
+                description: "Keep the rows without a size",
+                example: "open files.json | where (is-null $it.size?)",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// Whether `holds` for the value given, or else for all of the input.
+async fn predicate(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+    holds: fn(&Value) -> bool,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (PredicateArgs { value }, input) = args.process(&registry).await?;
+
+    let result = match value {
+        Some(value) => holds(&value),
+        None => {
+            let values: Vec<Value> = input.collect().await;
+            values.iter().all(holds)
+        }
+    };
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::boolean(result).into_value(name),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IsEmptyValue, IsNull};
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(IsEmptyValue {});
+        test_examples(IsNull {});
+    }
+}
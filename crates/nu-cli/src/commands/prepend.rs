@@ -21,12 +21,12 @@ impl WholeStreamCommand for Prepend {
         Signature::build("prepend").required(
             "row value",
             SyntaxShape::Any,
-            "the value of the row to prepend to the table",
+            "the value of the row to prepend to the table, or a table whose rows to prepend",
         )
     }
 
     fn usage(&self) -> &str {
-        "Prepend the given row to the front of the table"
+        "Prepend the given row, or the rows of the given table, to the front of the table"
     }
 
     async fn run(
@@ -38,16 +38,28 @@ impl WholeStreamCommand for Prepend {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Add something to the beginning of a list or table",
-            example: "echo [2 3 4] | prepend 1",
-            result: Some(vec![
-                UntaggedValue::int(1).into(),
-                UntaggedValue::int(2).into(),
-                UntaggedValue::int(3).into(),
-                UntaggedValue::int(4).into(),
-            ]),
-        }]
+        vec![
+            Example {
+                description: "Add something to the beginning of a list or table",
+                example: "echo [2 3 4] | prepend 1",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                    UntaggedValue::int(4).into(),
+                ]),
+            },
+            Example {
+                description: "Add the rows of another list or table to the beginning",
+                example: "echo [3 4] | prepend [1 2]",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                    UntaggedValue::int(4).into(),
+                ]),
+            },
+        ]
     }
 }
 
@@ -59,7 +71,11 @@ async fn prepend(
 
     let (PrependArgs { row }, input) = args.process(&registry).await?;
 
-    let bos = futures::stream::iter(vec![row]);
+    let rows = match row.value {
+        UntaggedValue::Table(rows) => rows,
+        _ => vec![row],
+    };
+    let bos = futures::stream::iter(rows);
 
     Ok(bos.chain(input).to_output_stream())
 }
@@ -0,0 +1,101 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use num_traits::ToPrimitive;
+use rand::{thread_rng, Rng};
+
+#[derive(Deserialize)]
+struct Arguments {
+    bias: Option<Value>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "random bool"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("random bool").named(
+            "bias",
+            SyntaxShape::Number,
+            "the probability of getting true, from 0 to 1 (default: 0.5)",
+            Some('b'),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Generate a random boolean value"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let name = args.call_info.name_tag.clone();
+        let (Arguments { bias }, _) = args.process(&registry).await?;
+
+        let probability = match &bias {
+            None => 0.5,
+            Some(bias) => {
+                let probability = match &bias.value {
+                    UntaggedValue::Primitive(Primitive::Int(n)) => n.to_f64(),
+                    UntaggedValue::Primitive(Primitive::Decimal(d)) => d.to_f64(),
+                    _ => None,
+                };
+
+                match probability {
+                    Some(p) if (0.0..=1.0).contains(&p) => p,
+                    _ => {
+                        return Err(ShellError::labeled_error(
+                            "Expected a bias from 0 to 1",
+                            "expected a number from 0 to 1",
+                            &bias.tag,
+                        ))
+                    }
+                }
+            }
+        };
+
+        Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::boolean(thread_rng().gen_bool(probability)).into_value(name),
+        )))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Generate a random boolean value",
+                example: "random bool",
+                result: None,
+            },
+            Example {
+                description: "Generate a random boolean value that's true three times out of four",
+                example: "random bool --bias 0.75",
+                result: None,
+            },
+            Example {
+                description: "Always get true",
+                example: "random bool --bias 1",
+                result: Some(vec![UntaggedValue::boolean(true).into()]),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
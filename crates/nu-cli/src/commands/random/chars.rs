@@ -0,0 +1,88 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+use rand::distributions::Alphanumeric;
+use rand::{thread_rng, Rng};
+
+const DEFAULT_CHARS_LENGTH: u64 = 25;
+
+#[derive(Deserialize)]
+struct Arguments {
+    length: Option<Tagged<u64>>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "random chars"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("random chars").named(
+            "length",
+            SyntaxShape::Int,
+            "the number of characters to generate (default: 25)",
+            Some('l'),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Generate a random string of letters and digits"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let name = args.call_info.name_tag.clone();
+        let (Arguments { length }, _) = args.process(&registry).await?;
+
+        let length = length.map_or(DEFAULT_CHARS_LENGTH, |l| l.item) as usize;
+        let chars: String = thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(length)
+            .collect();
+
+        Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::string(chars).into_value(name),
+        )))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Generate a random string of 25 characters",
+                example: "random chars",
+                result: None,
+            },
+            Example {
+                description: "Generate a random string of 8 characters",
+                example: "random chars --length 8",
+                result: None,
+            },
+            Example {
+                description: "Generate an empty string",
+                example: "random chars --length 0",
+                result: Some(vec![UntaggedValue::string("").into()]),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,102 @@
+use crate::commands::WholeStreamCommand;
+use crate::deserializer::NumericRange;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+use rand::{thread_rng, Rng};
+use std::str::FromStr;
+
+#[derive(Deserialize)]
+struct Arguments {
+    range: Option<Tagged<NumericRange>>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "random decimal"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("random decimal").optional(
+            "range",
+            SyntaxShape::Range,
+            "the range to pick a decimal from, excluding its end (default: 0..1)",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Generate a random decimal"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let name = args.call_info.name_tag.clone();
+        let (Arguments { range }, _) = args.process(&registry).await?;
+
+        let (from, to) = match &range {
+            Some(range) => (*range.item.from.0 as f64, *range.item.to.0 as f64),
+            None => (0.0, 1.0),
+        };
+
+        if from > to {
+            return Err(ShellError::labeled_error(
+                "Expected a range from a smaller to a larger number",
+                "the start of the range is larger than its end",
+                range.map(|range| range.tag).unwrap_or_else(Tag::unknown),
+            ));
+        }
+
+        let number = if from < to {
+            thread_rng().gen_range(from, to)
+        } else {
+            from
+        };
+
+        let decimal = BigDecimal::from_str(&number.to_string()).map_err(|_| {
+            ShellError::labeled_error(
+                "Could not generate a random decimal",
+                "could not generate a random decimal",
+                &name,
+            )
+        })?;
+
+        Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::decimal(decimal).into_value(name),
+        )))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Generate a random decimal from 0 to 1",
+                example: "random decimal",
+                result: None,
+            },
+            Example {
+                description: "Generate a random decimal from 1 to 10",
+                example: "random decimal 1..10",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,102 @@
+use crate::commands::WholeStreamCommand;
+use crate::deserializer::NumericRange;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{RangeInclusion, ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+use rand::distributions::Uniform;
+use rand::{thread_rng, Rng};
+
+#[derive(Deserialize)]
+struct Arguments {
+    range: Option<Tagged<NumericRange>>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "random integer"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("random integer").optional(
+            "range",
+            SyntaxShape::Range,
+            "the range of integers to pick from, including both ends",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Generate a random integer"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let name = args.call_info.name_tag.clone();
+        let (Arguments { range }, _) = args.process(&registry).await?;
+
+        let (from, to) = match &range {
+            Some(range) => match range.item.to {
+                (to, RangeInclusion::Inclusive) => (*range.item.from.0, *to),
+                // A range like 1..<7 leaves out its end, so it may have nothing in it
+                (to, RangeInclusion::Exclusive) => match to.checked_sub(1) {
+                    Some(to) => (*range.item.from.0, to),
+                    None => (1, 0),
+                },
+            },
+            None => (0, u64::MAX),
+        };
+
+        if from > to {
+            return Err(ShellError::labeled_error(
+                "Expected a range from a smaller to a larger integer",
+                "the start of the range is larger than its end",
+                range.map(|range| range.tag).unwrap_or_else(Tag::unknown),
+            ));
+        }
+
+        let integer = thread_rng().sample(Uniform::new_inclusive(from, to));
+
+        Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::int(integer).into_value(name),
+        )))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Generate a random integer",
+                example: "random integer",
+                result: None,
+            },
+            Example {
+                description: "Roll a six-sided die",
+                example: "random integer 1..6",
+                result: None,
+            },
+            Example {
+                description: "Pick from a range with a single integer",
+                example: "random integer 7..7",
+                result: Some(vec![UntaggedValue::int(7).into()]),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,13 @@
+mod bool;
+mod chars;
+mod command;
+mod decimal;
+mod integer;
+mod uuid;
+
+pub use self::bool::SubCommand as RandomBool;
+pub use self::uuid::SubCommand as RandomUUID;
+pub use chars::SubCommand as RandomChars;
+pub use command::Command as RandomCommand;
+pub use decimal::SubCommand as RandomDecimal;
+pub use integer::SubCommand as RandomInteger;
@@ -3,7 +3,7 @@ use crate::context::CommandRegistry;
 use crate::deserializer::NumericRange;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape};
+use nu_protocol::{RangeInclusion, ReturnSuccess, Signature, SyntaxShape, Value};
 use nu_source::Tagged;
 
 #[derive(Deserialize)]
@@ -23,7 +23,7 @@ impl WholeStreamCommand for Range {
         Signature::build("range").required(
             "rows ",
             SyntaxShape::Range,
-            "range of rows to return: Eg) 4..7 (=> from 4 to 7)",
+            "range of rows to return: Eg) 4..7 (=> from 4 to 7), 4..<7 (=> from 4 to 6), 0..2..8 (=> every other row from 0 to 8)",
         )
     }
 
@@ -38,6 +38,26 @@ impl WholeStreamCommand for Range {
     ) -> Result<OutputStream, ShellError> {
         range(args, registry).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Return the rows from 1 to 3",
+                example: "echo [a b c d e] | range 1..3",
+                result: Some(vec![Value::from("b"), Value::from("c"), Value::from("d")]),
+            },
+            Example {
+                description: "Return the rows before 3",
+                example: "echo [a b c d e] | range 0..<3",
+                result: Some(vec![Value::from("a"), Value::from("b"), Value::from("c")]),
+            },
+            Example {
+                description: "Return every other row",
+                example: "echo [a b c d e] | range 0..2..4",
+                result: Some(vec![Value::from("a"), Value::from("c"), Value::from("e")]),
+            },
+        ]
+    }
 }
 
 async fn range(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
@@ -45,15 +65,33 @@ async fn range(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputSt
     let (RangeArgs { area }, input) = args.process(&registry).await?;
     let range = area.item;
     let (from, _) = range.from;
-    let (to, _) = range.to;
+    let (to, to_inclusion) = range.to;
 
     let from = *from as usize;
-    let to = *to as usize;
+    let end = match to_inclusion {
+        RangeInclusion::Inclusive => *to as usize + 1,
+        RangeInclusion::Exclusive => *to as usize,
+    };
+
+    // Every step-th row, for ranges like 0..2..10
+    let step = match range.next {
+        Some(next) if *next as usize > from => *next as usize - from,
+        Some(next) => {
+            return Err(ShellError::labeled_error(
+                "Expected a range that counts up",
+                "the second value has to be larger than the first",
+                next.span,
+            ))
+        }
+        None => 1,
+    };
 
     Ok(input
         .skip(from)
-        .take(to - from + 1)
-        .map(ReturnSuccess::value)
+        .take(end.saturating_sub(from))
+        .enumerate()
+        .filter(move |(idx, _)| futures::future::ready(idx % step == 0))
+        .map(|(_, row)| ReturnSuccess::value(row))
         .to_output_stream())
 }
 
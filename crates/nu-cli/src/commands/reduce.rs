@@ -0,0 +1,153 @@
+use crate::commands::classified::block::run_block_with_params;
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use futures::stream::once;
+use nu_errors::ShellError;
+use nu_protocol::{hir::Block, ReturnSuccess, Scope, Signature, SyntaxShape, UntaggedValue, Value};
+
+pub struct Reduce;
+
+#[derive(Deserialize)]
+pub struct ReduceArgs {
+    block: Block,
+    fold: Option<Value>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Reduce {
+    fn name(&self) -> &str {
+        "reduce"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("reduce")
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the block to run for each row, given the accumulated value as $acc and the row as $it",
+            )
+            .named(
+                "fold",
+                SyntaxShape::Any,
+                "the initial value of $acc (default: the first row)",
+                Some('f'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Folds the rows into a single value, replacing $acc with the result of the block for each row."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        reduce(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Multiply the numbers together",
+                example: "echo [1 2 3 4] | reduce { = $acc * $it }",
+                result: Some(vec![UntaggedValue::int(24).into()]),
+            },
+            Example {
+                description: "Add up the numbers, starting from 10",
+                example: "echo [1 2 3 4] | reduce --fold 10 { = $acc + $it }",
+                result: Some(vec![UntaggedValue::int(20).into()]),
+            },
+            Example {
+                description: "Count the words of a file",
+                example: r#"open notes.txt | lines | reduce --fold 0 { = $acc + $(echo $it | split row " " | count) }"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn reduce(
+    raw_args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let scope = raw_args.call_info.scope.clone();
+    let mut context = Context::from_raw(&raw_args, &registry);
+    let name = raw_args.call_info.name_tag.clone();
+    let (ReduceArgs { block, fold }, mut input) = raw_args.process(&registry).await?;
+
+    let mut acc = match fold {
+        Some(initial) => initial,
+        None => match input.next().await {
+            Some(first) => first,
+            None => {
+                return Err(ShellError::labeled_error(
+                    "Expected rows to reduce",
+                    "no rows given and no initial value (use --fold)",
+                    name,
+                ))
+            }
+        },
+    };
+
+    while let Some(row) = input.next().await {
+        acc = reduce_step(&block, acc, row, &mut context, &scope, &name).await?;
+    }
+
+    Ok(OutputStream::one(ReturnSuccess::value(acc)))
+}
+
+async fn reduce_step(
+    block: &Block,
+    acc: Value,
+    row: Value,
+    context: &mut Context,
+    scope: &Scope,
+    tag: &Tag,
+) -> Result<Value, ShellError> {
+    let mut params = IndexMap::new();
+    params.insert("$acc".to_string(), acc);
+
+    let for_block = row.clone();
+    let input_stream = once(async { Ok(for_block) }).to_input_stream();
+
+    let result = run_block_with_params(
+        block,
+        context,
+        input_stream,
+        &row,
+        &scope.vars,
+        &params,
+        &scope.env,
+    )
+    .await?
+    .into_vec()
+    .await;
+
+    if let Some(err) = context.get_errors().first() {
+        return Err(err.clone());
+    }
+
+    match result.into_iter().next() {
+        Some(Value {
+            value: UntaggedValue::Error(err),
+            ..
+        }) => Err(err),
+        Some(value) => Ok(value),
+        None => Ok(UntaggedValue::nothing().into_value(tag)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Reduce;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Reduce {})
+    }
+}
@@ -1,15 +1,18 @@
+use crate::commands::classified::block::run_block;
+use crate::commands::group_by::suggestions;
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use indexmap::IndexMap;
 use nu_errors::ShellError;
 use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::Tagged;
+use nu_value_ext::as_string;
 
 pub struct Rename;
 
 #[derive(Deserialize)]
 pub struct Arguments {
-    column_name: Tagged<String>,
+    column_name: Value,
     rest: Vec<Tagged<String>>,
 }
 
@@ -23,8 +26,8 @@ impl WholeStreamCommand for Rename {
         Signature::build("rename")
             .required(
                 "column_name",
-                SyntaxShape::String,
-                "the new name for the first column",
+                SyntaxShape::Any,
+                "the new name for the first column, or a row (or block giving one) mapping old names to new ones",
             )
             .rest(SyntaxShape::String, "the new name for additional columns")
     }
@@ -53,39 +56,134 @@ impl WholeStreamCommand for Rename {
                 example: r#"echo "{a: 1, b: 2, c: 3}" | from json | rename spam eggs cars"#,
                 result: None,
             },
+            Example {
+                description: "Rename columns by name",
+                example: r#"echo "{a: 1, b: 2, c: 3}" | from json | rename { echo "{c: spam, a: eggs}" | from json }"#,
+                result: None,
+            },
         ]
     }
 }
 
+enum Renaming {
+    Positional(Vec<Tagged<String>>),
+    Mapping(Vec<(Tagged<String>, String)>),
+}
+
 pub async fn rename(
-    args: CommandArgs,
+    raw_args: CommandArgs,
     registry: &CommandRegistry,
 ) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
-    let name = args.call_info.name_tag.clone();
-    let (Arguments { column_name, rest }, input) = args.process(&registry).await?;
-    let mut new_column_names = vec![vec![column_name]];
-    new_column_names.push(rest);
+    let scope = raw_args.call_info.scope.clone();
+    let mut context = Context::from_raw(&raw_args, &registry);
+    let name = raw_args.call_info.name_tag.clone();
+    let (Arguments { column_name, rest }, input) = raw_args.process(&registry).await?;
+
+    let mapping = match column_name.value {
+        UntaggedValue::Block(ref block) => {
+            let output = run_block(
+                block,
+                &mut context,
+                InputStream::empty(),
+                &scope.it,
+                &scope.vars,
+                &scope.env,
+            )
+            .await?
+            .drain_vec()
+            .await;
 
-    let new_column_names = new_column_names.into_iter().flatten().collect::<Vec<_>>();
+            if let Some(err) = context.get_errors().first() {
+                return Err(err.clone());
+            }
+
+            match &output[..] {
+                [row] => Some(row.clone()),
+                _ => {
+                    return Err(ShellError::labeled_error(
+                        "Expected a row mapping old names to new ones",
+                        "the block should give a single row",
+                        &column_name.tag,
+                    ))
+                }
+            }
+        }
+        UntaggedValue::Row(_) => Some(column_name.clone()),
+        _ => None,
+    };
+
+    let renaming = match mapping {
+        Some(mapping) => {
+            if let Some(extra) = rest.first() {
+                return Err(ShellError::labeled_error(
+                    "Unexpected column name",
+                    "can't give new names along with a mapping",
+                    &extra.tag,
+                ));
+            }
+
+            let mut pairs = vec![];
+            for (old, new) in mapping.row_entries() {
+                pairs.push((old.clone().tagged(&column_name.tag), as_string(new)?));
+            }
+            Renaming::Mapping(pairs)
+        }
+        None => {
+            let first = as_string(&column_name)?.tagged(&column_name.tag);
+            Renaming::Positional(std::iter::once(first).chain(rest).collect())
+        }
+    };
 
     Ok(input
         .map(move |item| {
             if let Value {
                 value: UntaggedValue::Row(row),
                 tag,
-            } = item
+            } = &item
             {
                 let mut renamed_row = IndexMap::new();
 
-                for (idx, (key, value)) in row.entries.iter().enumerate() {
-                    let key = if idx < new_column_names.len() {
-                        &new_column_names[idx].item
-                    } else {
-                        key
-                    };
-
-                    renamed_row.insert(key.clone(), value.clone());
+                match &renaming {
+                    Renaming::Positional(new_column_names) => {
+                        for (idx, (key, value)) in row.entries.iter().enumerate() {
+                            let key = if idx < new_column_names.len() {
+                                &new_column_names[idx].item
+                            } else {
+                                key
+                            };
+
+                            if renamed_row.insert(key.clone(), value.clone()).is_some() {
+                                // One of the new names must be the one taken twice
+                                let taken = new_column_names
+                                    .iter()
+                                    .find(|new| &new.item == key)
+                                    .map(|new| new.tag.clone())
+                                    .unwrap_or_else(|| name.clone());
+
+                                return Err(duplicate_column(key, taken));
+                            }
+                        }
+                    }
+                    Renaming::Mapping(pairs) => {
+                        if let Some((missing, _)) = pairs
+                            .iter()
+                            .find(|(old, _)| !row.entries.contains_key(&old.item))
+                        {
+                            return Err(suggestions(missing.borrow_tagged(), &item));
+                        }
+
+                        for (key, value) in row.entries.iter() {
+                            let key = match pairs.iter().find(|(old, _)| &old.item == key) {
+                                Some((_, new)) => new,
+                                None => key,
+                            };
+
+                            if renamed_row.insert(key.clone(), value.clone()).is_some() {
+                                return Err(duplicate_column(key, column_name.tag.clone()));
+                            }
+                        }
+                    }
                 }
 
                 let out = UntaggedValue::Row(renamed_row.into()).into_value(tag);
@@ -105,6 +203,14 @@ pub async fn rename(
         .to_output_stream())
 }
 
+fn duplicate_column(key: &str, tag: Tag) -> ShellError {
+    ShellError::labeled_error(
+        "Duplicate column name",
+        format!("the table would have two columns named {}", key),
+        tag,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::Rename;
@@ -1,5 +1,6 @@
 use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
+use crate::data::config;
 use crate::prelude::*;
 use nu_errors::ShellError;
 use nu_protocol::{Signature, SyntaxShape};
@@ -12,9 +13,7 @@ pub struct Remove;
 pub struct RemoveArgs {
     pub rest: Vec<Tagged<PathBuf>>,
     pub recursive: Tagged<bool>,
-    #[allow(unused)]
     pub trash: Tagged<bool>,
-    #[allow(unused)]
     pub permanent: Tagged<bool>,
 }
 
@@ -77,6 +76,7 @@ async fn rm(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStrea
     let registry = registry.clone();
     let name = args.call_info.name_tag.clone();
     let shell_manager = args.shell_manager.clone();
+    let host = args.host.clone();
     let (args, _): (RemoveArgs, _) = args.process(&registry).await?;
 
     if args.trash.item && args.permanent.item {
@@ -87,9 +87,29 @@ async fn rm(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStrea
         ))));
     }
 
+    if cfg!(not(feature = "trash-support"))
+        && !args.trash.item
+        && !args.permanent.item
+        && always_trash()
+    {
+        let warning = "warning: rm_always_trash is ignored, as this nu is built without the trash-support feature".to_string();
+        if let Some(warning) = registry.warn_once("rm_always_trash", warning) {
+            host.lock().stderr(&warning);
+        }
+    }
+
     shell_manager.rm(args, name)
 }
 
+/// Whether `rm` moves files to the trash unless told otherwise. A config that can't be read
+/// doesn't stop files from being removed.
+pub(crate) fn always_trash() -> bool {
+    config::config(Tag::unknown())
+        .ok()
+        .and_then(|config| config.get("rm_always_trash").map(|val| val.is_true()))
+        .unwrap_or(false)
+}
+
 #[cfg(test)]
 mod tests {
     use super::Remove;
@@ -0,0 +1,194 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use indexmap::IndexSet;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::SpannedItem;
+
+pub struct Rotate;
+
+#[derive(Deserialize)]
+pub struct RotateArgs {
+    ccw: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Rotate {
+    fn name(&self) -> &str {
+        "rotate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("rotate").switch(
+            "ccw",
+            "rotate counter-clockwise (the default is clockwise)",
+            Some('c'),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Rotates the table by 90 degrees, turning its columns into rows."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        rotate(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show a wide record vertically, with its column names last",
+                example: "sys | get host | rotate",
+                result: None,
+            },
+            Example {
+                description: "Rotate counter-clockwise, with the column names first",
+                example: "ls | first 2 | rotate --ccw",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn rotate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (RotateArgs { ccw }, input) = args.process(&registry).await?;
+
+    let rows: Vec<Value> = input.collect().await;
+    let rotated = rotate_rows(&rows, ccw, &name)?;
+
+    Ok(futures::stream::iter(rotated.into_iter().map(ReturnSuccess::value)).to_output_stream())
+}
+
+/// Rotating clockwise turns the last row into the first column and puts the column names in the
+/// last column. Rotating counter-clockwise turns the last column into the first row and puts the
+/// column names in the first column.
+fn rotate_rows(rows: &[Value], ccw: bool, tag: &Tag) -> Result<Vec<Value>, ShellError> {
+    for row in rows {
+        if !matches!(row.value, UntaggedValue::Row(_)) {
+            return Err(ShellError::labeled_error(
+                "Expected table rows",
+                "requires table input",
+                &row.tag,
+            ));
+        }
+    }
+
+    let columns: IndexSet<String> = rows.iter().flat_map(|row| row.data_descriptors()).collect();
+
+    let cells = |column: &str| -> Vec<Value> {
+        rows.iter()
+            .map(|row| {
+                row.get_data_by_key(column.spanned_unknown())
+                    .unwrap_or_else(|| UntaggedValue::nothing().into_value(tag))
+            })
+            .collect()
+    };
+
+    let rotated: Vec<Vec<Value>> = if ccw {
+        columns
+            .iter()
+            .rev()
+            .map(|column| {
+                let mut line = vec![UntaggedValue::string(column).into_value(tag)];
+                line.extend(cells(column));
+                line
+            })
+            .collect()
+    } else {
+        columns
+            .iter()
+            .map(|column| {
+                let mut line: Vec<Value> = cells(column).into_iter().rev().collect();
+                line.push(UntaggedValue::string(column).into_value(tag));
+                line
+            })
+            .collect()
+    };
+
+    Ok(rotated
+        .into_iter()
+        .map(|line| {
+            let mut out = TaggedDictBuilder::new(tag);
+            for (idx, cell) in line.into_iter().enumerate() {
+                out.insert_value(format!("Column{}", idx + 1), cell);
+            }
+            out.into_value()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rotate_rows, Rotate};
+    use indexmap::IndexMap;
+    use nu_errors::ShellError;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::*;
+
+    fn string(input: impl Into<String>) -> Value {
+        UntaggedValue::string(input.into()).into_untagged_value()
+    }
+
+    fn int(n: i64) -> Value {
+        UntaggedValue::int(n).into_untagged_value()
+    }
+
+    fn row(entries: IndexMap<String, Value>) -> Value {
+        UntaggedValue::row(entries).into_untagged_value()
+    }
+
+    fn table() -> Vec<Value> {
+        vec![
+            row(indexmap! {"a".into() => int(1), "b".into() => int(2)}),
+            row(indexmap! {"a".into() => int(3), "b".into() => int(4)}),
+        ]
+    }
+
+    #[test]
+    fn rotates_clockwise() -> Result<(), ShellError> {
+        assert_eq!(
+            rotate_rows(&table(), false, &Tag::unknown())?,
+            vec![
+                row(
+                    indexmap! {"Column1".into() => int(3), "Column2".into() => int(1), "Column3".into() => string("a")}
+                ),
+                row(
+                    indexmap! {"Column1".into() => int(4), "Column2".into() => int(2), "Column3".into() => string("b")}
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn rotates_counter_clockwise() -> Result<(), ShellError> {
+        assert_eq!(
+            rotate_rows(&table(), true, &Tag::unknown())?,
+            vec![
+                row(
+                    indexmap! {"Column1".into() => string("b"), "Column2".into() => int(2), "Column3".into() => int(4)}
+                ),
+                row(
+                    indexmap! {"Column1".into() => string("a"), "Column2".into() => int(1), "Column3".into() => int(3)}
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Rotate {})
+    }
+}
@@ -1,36 +1,149 @@
-use crate::commands::classified::block::run_block;
+use crate::commands::classified::block::run_block_with_params;
+use crate::commands::def::Parameters;
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 
-use derive_new::new;
 use nu_errors::ShellError;
-use nu_protocol::{hir::Block, Signature, SyntaxShape};
+use nu_protocol::hir::{
+    Block, ClassifiedCommand, Expression, InternalCommand, Literal, NamedArguments, NamedValue,
+    SpannedExpression, Variable,
+};
+use nu_protocol::{NamedType, Signature, SyntaxShape, Value};
 
-#[derive(new, Clone)]
+#[derive(Clone)]
 pub struct AliasCommand {
-    name: String,
-    args: Vec<String>,
+    parameters: Parameters,
+    /// The signature of the alias, with the flags of the last command of its body added to its
+    /// parameters.
+    signature: Signature,
+    /// The flags given to the alias that are given on to the last command of its body.
+    forwarded_flags: Vec<String>,
+    /// Whether the arguments after the parameters are given on to the last command of the body.
+    forwards_rest: bool,
     block: Block,
 }
 
+impl AliasCommand {
+    pub(crate) fn new(
+        name: String,
+        params: Vec<String>,
+        block: Block,
+        registry: &CommandRegistry,
+    ) -> Result<AliasCommand, ShellError> {
+        let params: Vec<_> = params
+            .into_iter()
+            .map(|param| param.tagged_unknown())
+            .collect();
+
+        let parameters = Parameters::parse(&name, &params)?;
+        let mut signature = parameters.signature.clone();
+        let mut forwarded_flags = vec![];
+        let mut forwards_rest = false;
+
+        if let Some(command) = last_command(&block) {
+            let target = target_name(command);
+
+            if command.name == "run_external" {
+                signature = signature.allow_unknown_flags();
+            } else if let Some(target_command) = registry.get_command(&command.name) {
+                for (flag, (named_type, description)) in target_command.signature().named {
+                    if flag == "help" || signature.named.contains_key(&flag) {
+                        continue;
+                    }
+
+                    let short = named_type
+                        .get_short()
+                        .filter(|short| !signature.get_shorts().contains(short));
+                    let named_type = match named_type {
+                        NamedType::Switch(_) => NamedType::Switch(short),
+                        NamedType::Mandatory(_, shape) | NamedType::Optional(_, shape) => {
+                            NamedType::Optional(short, shape)
+                        }
+                    };
+
+                    signature
+                        .named
+                        .insert(flag.clone(), (named_type, description));
+                    forwarded_flags.push(flag);
+                }
+            }
+
+            if parameters.rest.is_none() {
+                signature = signature.rest(SyntaxShape::Any, format!("given on to {}", target));
+                forwards_rest = true;
+            }
+
+            signature.usage = format!("Alias for {}.", target);
+        }
+
+        Ok(AliasCommand {
+            parameters,
+            signature,
+            forwarded_flags,
+            forwards_rest,
+            block,
+        })
+    }
+}
+
+/// The command at the end of the body, which is given the arguments the alias passes on.
+fn last_command(block: &Block) -> Option<&InternalCommand> {
+    match block.block.last()?.list.last()? {
+        ClassifiedCommand::Internal(command) => Some(command),
+        _ => None,
+    }
+}
+
+fn last_command_mut(block: &mut Block) -> Option<&mut InternalCommand> {
+    match block.block.last_mut()?.list.last_mut()? {
+        ClassifiedCommand::Internal(command) => Some(command),
+        _ => None,
+    }
+}
+
+/// The name of the command, which for an external command is the name it is run by.
+fn target_name(command: &InternalCommand) -> String {
+    if command.name != "run_external" {
+        return command.name.clone();
+    }
+
+    match command
+        .args
+        .positional
+        .as_ref()
+        .and_then(|args| args.first())
+    {
+        Some(SpannedExpression {
+            expr: Expression::Literal(Literal::String(name)),
+            ..
+        }) => name.clone(),
+        _ => command.name.clone(),
+    }
+}
+
+/// Gives `value` to the body as a variable, giving back the expression that reads it.
+fn forward(
+    vars: &mut IndexMap<String, Value>,
+    variable: String,
+    value: Value,
+    span: Span,
+) -> SpannedExpression {
+    vars.insert(variable.clone(), value);
+    SpannedExpression::new(Expression::Variable(Variable::Other(variable, span)), span)
+}
+
 #[async_trait]
 impl WholeStreamCommand for AliasCommand {
     fn name(&self) -> &str {
-        &self.name
+        &self.signature.name
     }
 
     fn signature(&self) -> Signature {
-        let mut alias = Signature::build(&self.name);
-
-        for arg in &self.args {
-            alias = alias.optional(arg, SyntaxShape::Any, "");
-        }
-
-        alias
+        self.signature.clone()
     }
 
     fn usage(&self) -> &str {
-        ""
+        &self.signature.usage
     }
 
     async fn run(
@@ -40,27 +153,71 @@ impl WholeStreamCommand for AliasCommand {
     ) -> Result<OutputStream, ShellError> {
         let call_info = args.call_info.clone();
         let registry = registry.clone();
-        let block = self.block.clone();
-        let alias_command = self.clone();
+        let mut block = self.block.clone();
         let mut context = Context::from_args(&args, &registry);
         let input = args.input;
 
+        let name = call_info.name_tag.clone();
+        // The body sees the variables from where it was defined, not the ones of the caller
         let mut scope = call_info.scope.clone();
+        scope.vars.clear();
+
         let evaluated = call_info.evaluate(&registry).await?;
-        if let Some(positional) = &evaluated.args.positional {
-            for (pos, arg) in positional.iter().enumerate() {
-                scope
-                    .vars
-                    .insert(alias_command.args[pos].to_string(), arg.clone());
+        let rest = self
+            .parameters
+            .bind(&evaluated.args, &name, &mut scope.vars);
+
+        // The arguments the alias passes on are given to the last command of the body, after the
+        // ones it's written with
+        if let Some(command) = last_command_mut(&mut block) {
+            let span = command.args.span;
+
+            if self.forwards_rest {
+                for (index, value) in rest.into_iter().enumerate() {
+                    let expr = forward(
+                        &mut scope.vars,
+                        format!("$alias-argument-{}", index),
+                        value,
+                        span,
+                    );
+                    command
+                        .args
+                        .positional
+                        .get_or_insert_with(Vec::new)
+                        .push(expr);
+                }
+            }
+
+            for flag in &self.forwarded_flags {
+                let value = match evaluated.args.get(flag) {
+                    Some(value) => value.clone(),
+                    None => continue,
+                };
+
+                let named_value = match self.signature.named.get(flag) {
+                    Some((NamedType::Switch(_), _)) => NamedValue::PresentSwitch(span),
+                    _ => NamedValue::Value(
+                        span,
+                        forward(&mut scope.vars, format!("$alias-{}", flag), value, span),
+                    ),
+                };
+
+                command
+                    .args
+                    .named
+                    .get_or_insert_with(NamedArguments::new)
+                    .named
+                    .insert(flag.clone(), named_value);
             }
         }
 
         // FIXME: we need to patch up the spans to point at the top-level error
-        Ok(run_block(
+        Ok(run_block_with_params(
             &block,
             &mut context,
             input,
             &scope.it,
+            &IndexMap::new(),
             &scope.vars,
             &scope.env,
         )
@@ -0,0 +1,110 @@
+use crate::commands::classified::block::run_block_with_params;
+use crate::commands::def::{self, Parameters};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+
+use nu_errors::ShellError;
+use nu_protocol::{hir::Block, Signature};
+
+#[derive(Clone)]
+pub struct CustomCommand {
+    parameters: Parameters,
+    /// The examples from the documentation, as descriptions and examples, kept for as long as Nu
+    /// runs since help needs them to last.
+    examples: Vec<(&'static str, &'static str)>,
+    block: Block,
+}
+
+impl CustomCommand {
+    pub(crate) fn new(
+        name: String,
+        params: Vec<String>,
+        documentation: &str,
+        block: Block,
+    ) -> Result<CustomCommand, ShellError> {
+        let params: Vec<_> = params
+            .into_iter()
+            .map(|param| param.tagged_unknown())
+            .collect();
+
+        let mut parameters = Parameters::parse(&name, &params)?;
+        let (usage, examples) = def::documentation(documentation);
+        parameters.signature.usage = usage;
+
+        let examples = examples
+            .into_iter()
+            .map(|(description, example)| {
+                (
+                    &*Box::leak(description.into_boxed_str()),
+                    &*Box::leak(example.into_boxed_str()),
+                )
+            })
+            .collect();
+
+        Ok(CustomCommand {
+            parameters,
+            examples,
+            block,
+        })
+    }
+}
+
+#[async_trait]
+impl WholeStreamCommand for CustomCommand {
+    fn name(&self) -> &str {
+        &self.parameters.signature.name
+    }
+
+    fn signature(&self) -> Signature {
+        self.parameters.signature.clone()
+    }
+
+    fn usage(&self) -> &str {
+        &self.parameters.signature.usage
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        self.examples
+            .iter()
+            .map(|(description, example)| Example {
+                description,
+                example,
+                result: None,
+            })
+            .collect()
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let call_info = args.call_info.clone();
+        let registry = registry.clone();
+        let block = self.block.clone();
+        let parameters = &self.parameters;
+        let mut context = Context::from_args(&args, &registry);
+        let input = args.input;
+
+        let name = call_info.name_tag.clone();
+        // The body sees the variables from where it was defined, not the ones of the caller
+        let mut scope = call_info.scope.clone();
+        scope.vars.clear();
+
+        let evaluated = call_info.evaluate(&registry).await?;
+        parameters.bind(&evaluated.args, &name, &mut scope.vars);
+
+        // FIXME: we need to patch up the spans to point at the top-level error
+        Ok(run_block_with_params(
+            &block,
+            &mut context,
+            input,
+            &scope.it,
+            &IndexMap::new(),
+            &scope.vars,
+            &scope.env,
+        )
+        .await?
+        .to_output_stream())
+    }
+}
@@ -82,6 +82,9 @@ impl WholeStreamCommand for RunExternalCommand {
                         std::collections::HashMap::new(),
                     )),
                     raw_input: String::default(),
+                    doc_comments: vec![],
+                    script_dir: None,
+                    vars: IndexMap::new(),
                 }
             }
             #[cfg(not(windows))]
@@ -93,6 +96,9 @@ impl WholeStreamCommand for RunExternalCommand {
                     ctrl_c: args.ctrl_c.clone(),
                     current_errors: Arc::new(Mutex::new(vec![])),
                     raw_input: String::default(),
+                    doc_comments: vec![],
+                    script_dir: None,
+                    vars: IndexMap::new(),
                 }
             }
         };
@@ -133,10 +139,17 @@ impl WholeStreamCommand for RunExternalCommand {
 
         let scope = args.call_info.scope.clone();
         let is_last = args.call_info.args.is_last;
+        let capture_stderr = args.call_info.args.capture_stderr;
         let input = args.input;
-        let result =
-            external::run_external_command(command, &mut external_context, input, &scope, is_last)
-                .await;
+        let result = external::run_external_command(
+            command,
+            &mut external_context,
+            input,
+            &scope,
+            is_last,
+            capture_stderr,
+        )
+        .await;
 
         match result {
             Ok(stream) => Ok(stream.to_output_stream()),
@@ -0,0 +1,189 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use num_traits::ToPrimitive;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+pub struct Sample;
+
+#[derive(Deserialize)]
+pub struct SampleArgs {
+    rows: Option<Tagged<usize>>,
+    fraction: Option<Value>,
+    seed: Option<Tagged<u64>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Sample {
+    fn name(&self) -> &str {
+        "sample"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sample")
+            .optional("rows", SyntaxShape::Int, "the number of rows to pick")
+            .named(
+                "fraction",
+                SyntaxShape::Number,
+                "the probability of picking each row, between 0 and 1",
+                Some('f'),
+            )
+            .named(
+                "seed",
+                SyntaxShape::Int,
+                "the seed of the random generator, to pick the same rows every time",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Pick rows at random, keeping their order."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        sample(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Pick 100 rows",
+                example: "open big.csv | sample 100",
+                result: None,
+            },
+            Example {
+                description: "Pick about a tenth of the rows, the same ones every time",
+                example: "open big.csv | sample --fraction 0.1 --seed 42",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn sample(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (
+        SampleArgs {
+            rows,
+            fraction,
+            seed,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(*seed),
+        None => StdRng::from_entropy(),
+    };
+
+    match (rows, fraction) {
+        (Some(rows), None) => {
+            let picked = reservoir(input.collect().await, *rows, &mut rng);
+            Ok(
+                futures::stream::iter(picked.into_iter().map(ReturnSuccess::value))
+                    .to_output_stream(),
+            )
+        }
+        (None, Some(fraction)) => {
+            let probability = probability_of(&fraction)?;
+
+            Ok(input
+                .filter(move |_| futures::future::ready(rng.gen::<f64>() < probability))
+                .map(ReturnSuccess::value)
+                .to_output_stream())
+        }
+        _ => Err(ShellError::labeled_error(
+            "Expected a number of rows or a fraction",
+            "requires either a number of rows or --fraction",
+            name,
+        )),
+    }
+}
+
+fn probability_of(fraction: &Value) -> Result<f64, ShellError> {
+    let probability = match &fraction.value {
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => d.to_f64(),
+        UntaggedValue::Primitive(Primitive::Int(i)) => i.to_f64(),
+        _ => None,
+    };
+
+    match probability {
+        Some(p) if (0.0..=1.0).contains(&p) => Ok(p),
+        _ => Err(ShellError::labeled_error(
+            "Expected a fraction",
+            "requires a number between 0 and 1",
+            &fraction.tag,
+        )),
+    }
+}
+
+/// Picks `amount` of the values with reservoir sampling, giving back the picked values in the
+/// order they came in.
+fn reservoir(values: Vec<Value>, amount: usize, rng: &mut StdRng) -> Vec<Value> {
+    let mut picked: Vec<(usize, Value)> = Vec::with_capacity(amount.min(values.len()));
+
+    for (idx, value) in values.into_iter().enumerate() {
+        if picked.len() < amount {
+            picked.push((idx, value));
+        } else {
+            let slot = rng.gen_range(0, idx + 1);
+            if slot < amount {
+                picked[slot] = (idx, value);
+            }
+        }
+    }
+
+    picked.sort_by_key(|(idx, _)| *idx);
+    picked.into_iter().map(|(_, value)| value).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{reservoir, Sample};
+    use nu_protocol::{UntaggedValue, Value};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn ints(range: std::ops::Range<i64>) -> Vec<Value> {
+        range
+            .map(|n| UntaggedValue::int(n).into_untagged_value())
+            .collect()
+    }
+
+    #[test]
+    fn picks_the_amount_asked_in_order() {
+        let picked = reservoir(ints(0..100), 10, &mut StdRng::seed_from_u64(42));
+
+        assert_eq!(picked.len(), 10);
+        assert!(picked.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(
+            picked,
+            reservoir(ints(0..100), 10, &mut StdRng::seed_from_u64(42))
+        );
+    }
+
+    #[test]
+    fn picks_everything_when_asked_for_more() {
+        assert_eq!(
+            reservoir(ints(0..5), 10, &mut StdRng::seed_from_u64(1)),
+            ints(0..5)
+        );
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Sample {})
+    }
+}
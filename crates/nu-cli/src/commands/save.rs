@@ -1,5 +1,6 @@
 use crate::commands::{UnevaluatedCallInfo, WholeStreamCommand};
 use crate::prelude::*;
+use crate::shell::ssh_shell::SshLocation;
 use nu_errors::ShellError;
 use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::Tagged;
@@ -9,14 +10,12 @@ pub struct Save;
 
 macro_rules! process_unknown {
     ($scope:tt, $input:ident, $name_tag:ident) => {{
-        if $input.len() > 0 {
-            match $input[0] {
-                Value {
-                    value: UntaggedValue::Primitive(Primitive::Binary(_)),
-                    ..
-                } => process_binary!($scope, $input, $name_tag),
-                _ => process_string!($scope, $input, $name_tag),
-            }
+        // Output of an external command can start as text and turn out to be binary
+        if $input
+            .iter()
+            .any(|value| matches!(value.value, UntaggedValue::Primitive(Primitive::Binary(_))))
+        {
+            process_binary!($scope, $input, $name_tag)
         } else {
             process_string!($scope, $input, $name_tag)
         }
@@ -60,6 +59,12 @@ macro_rules! process_binary {
                         result_binary.push(u);
                     }
                 }
+                Value {
+                    value: UntaggedValue::Primitive(Primitive::String(s)),
+                    ..
+                } => {
+                    result_binary.extend_from_slice(s.as_bytes());
+                }
                 _ => {
                     break $scope Err(ShellError::labeled_error(
                         "Save could not successfully save",
@@ -181,6 +186,7 @@ async fn save(
         input,
     ) = raw_args.process(&registry).await?;
     let input: Vec<Value> = input.collect().await;
+    let mut remote = None;
     if path.is_none() {
         let mut should_return_file_path_error = true;
 
@@ -188,11 +194,19 @@ async fn save(
         if !input.is_empty() {
             let anchor = input[0].tag.anchor();
 
-            if let Some(path) = anchor {
-                if let AnchorLocation::File(file) = path {
+            match anchor {
+                Some(AnchorLocation::File(file)) => {
                     should_return_file_path_error = false;
+                    remote = SshLocation::resolve(&full_path, Path::new(&file));
                     full_path.push(Path::new(&file));
                 }
+                // Files opened over SSH can be saved back to where they came from.
+                Some(AnchorLocation::Url(url)) if SshLocation::parse(&url).is_some() => {
+                    should_return_file_path_error = false;
+                    remote = SshLocation::parse(&url);
+                    full_path = PathBuf::from(url);
+                }
+                _ => {}
             }
         }
 
@@ -204,6 +218,7 @@ async fn save(
             ));
         }
     } else if let Some(file) = path {
+        remote = SshLocation::resolve(&full_path, file.item());
         full_path.push(file.item());
     }
 
@@ -227,6 +242,7 @@ async fn save(
                                 named: None,
                                 span: Span::unknown(),
                                 is_last: false,
+                                capture_stderr: false,
                             },
                             name_tag: name_tag.clone(),
                             scope,
@@ -251,8 +267,12 @@ async fn save(
         };
     };
 
-    match content {
-        Ok(save_data) => match std::fs::write(full_path, save_data) {
+    match (content, remote) {
+        (Ok(save_data), Some(location)) => {
+            location.write(&save_data, &name)?;
+            Ok(OutputStream::empty())
+        }
+        (Ok(save_data), None) => match std::fs::write(full_path, save_data) {
             Ok(_) => Ok(OutputStream::empty()),
             Err(e) => Err(ShellError::labeled_error(
                 e.to_string(),
@@ -260,7 +280,7 @@ async fn save(
                 name,
             )),
         },
-        Err(e) => Err(e),
+        (Err(e), _) => Err(e),
     }
 }
 
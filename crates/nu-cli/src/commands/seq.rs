@@ -0,0 +1,163 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use bigdecimal::{BigDecimal, Zero};
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use num_bigint::ToBigInt;
+
+pub struct Seq;
+
+#[derive(Deserialize)]
+pub struct SeqArgs {
+    rest: Vec<Value>,
+    step: Option<Value>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Seq {
+    fn name(&self) -> &str {
+        "seq"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("seq")
+            .rest(
+                SyntaxShape::Number,
+                "the last number, or the first and the last number of the sequence",
+            )
+            .named(
+                "step",
+                SyntaxShape::Number,
+                "the difference between consecutive numbers (default: 1, or -1 when counting down)",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Produces a sequence of numbers."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        seq(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Count from 1 to 3",
+                example: "seq 3",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                ]),
+            },
+            Example {
+                description: "Count down from 10 to 0 in steps of 5",
+                example: "seq 10 0 --step -5",
+                result: Some(vec![
+                    UntaggedValue::int(10).into(),
+                    UntaggedValue::int(5).into(),
+                    UntaggedValue::int(0).into(),
+                ]),
+            },
+            Example {
+                description: "Count from 0 to 1 in steps of a quarter",
+                example: "seq 0 1 --step 0.25",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn as_number(value: &Value) -> Result<(BigDecimal, bool), ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) => Ok((BigDecimal::from(i.clone()), true)),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => Ok((d.clone(), false)),
+        _ => Err(ShellError::labeled_error(
+            "Expected a number",
+            "expected a number",
+            &value.tag,
+        )),
+    }
+}
+
+async fn seq(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (SeqArgs { rest, step }, _) = args.process(&registry).await?;
+
+    let (first, last, mut all_ints) = match &rest[..] {
+        [last] => {
+            let (last, is_int) = as_number(last)?;
+            (BigDecimal::from(1), last, is_int)
+        }
+        [first, last] => {
+            let (first, first_is_int) = as_number(first)?;
+            let (last, last_is_int) = as_number(last)?;
+            (first, last, first_is_int && last_is_int)
+        }
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected one or two numbers",
+                "requires the last number, or the first and the last number",
+                name,
+            ))
+        }
+    };
+
+    let step = match step {
+        Some(step) => {
+            let (value, is_int) = as_number(&step)?;
+            if value.is_zero() {
+                return Err(ShellError::labeled_error(
+                    "Step can't be zero",
+                    "step can't be zero",
+                    step.tag,
+                ));
+            }
+            all_ints = all_ints && is_int;
+            value
+        }
+        None if first > last => BigDecimal::from(-1),
+        None => BigDecimal::from(1),
+    };
+
+    let ascending = step > BigDecimal::zero();
+
+    let numbers = (0..)
+        .map(move |idx: i64| &first + &step * BigDecimal::from(idx))
+        .take_while(move |number| {
+            if ascending {
+                *number <= last
+            } else {
+                *number >= last
+            }
+        })
+        .map(move |number| {
+            let value = match number.to_bigint() {
+                Some(int) if all_ints => UntaggedValue::int(int),
+                _ => UntaggedValue::decimal(number),
+            };
+            ReturnSuccess::value(value.into_value(&name))
+        });
+
+    Ok(futures::stream::iter(numbers).to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Seq;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Seq {})
+    }
+}
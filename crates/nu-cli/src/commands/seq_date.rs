@@ -0,0 +1,181 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::data::value::duration_of_seconds;
+use crate::prelude::*;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct SeqDate;
+
+#[derive(Deserialize)]
+pub struct SeqDateArgs {
+    from: Tagged<String>,
+    to: Option<Tagged<String>>,
+    step: Option<Value>,
+    count: Option<Tagged<u64>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for SeqDate {
+    fn name(&self) -> &str {
+        "seq date"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("seq date")
+            .required_named(
+                "from",
+                SyntaxShape::String,
+                "the first date, as YYYY-MM-DD or an RFC 3339 date and time",
+                Some('f'),
+            )
+            .named(
+                "to",
+                SyntaxShape::String,
+                "the last date, as YYYY-MM-DD or an RFC 3339 date and time",
+                Some('t'),
+            )
+            .named(
+                "step",
+                SyntaxShape::Unit,
+                "the time between consecutive dates (default: 1d)",
+                Some('s'),
+            )
+            .named(
+                "count",
+                SyntaxShape::Int,
+                "the number of dates to produce",
+                Some('n'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Produces a sequence of dates."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        seq_date(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Every day of the first week of June 2020",
+                example: "seq date --from 2020-06-01 --to 2020-06-07",
+                result: None,
+            },
+            Example {
+                description: "The next four Mondays, starting on a Monday",
+                example: "seq date --from 2020-06-01 --step 1w --count 4",
+                result: None,
+            },
+        ]
+    }
+}
+
+pub(crate) fn parse_date(date: &Tagged<String>) -> Result<DateTime<Utc>, ShellError> {
+    if let Ok(date) = DateTime::parse_from_rfc3339(&date.item) {
+        return Ok(date.with_timezone(&Utc));
+    }
+
+    NaiveDate::parse_from_str(&date.item, "%Y-%m-%d")
+        .map(|day| DateTime::from_utc(day.and_hms(0, 0, 0), Utc))
+        .map_err(|_| {
+            ShellError::labeled_error(
+                "Could not parse date",
+                "expected YYYY-MM-DD or an RFC 3339 date and time",
+                &date.tag,
+            )
+        })
+}
+
+async fn seq_date(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (
+        SeqDateArgs {
+            from,
+            to,
+            step,
+            count,
+        },
+        _,
+    ) = args.process(&registry).await?;
+
+    let from = parse_date(&from)?;
+    let to = match &to {
+        Some(to) => Some(parse_date(to)?),
+        None => None,
+    };
+
+    let step = match step {
+        None => Duration::days(1),
+        Some(Value {
+            value: UntaggedValue::Primitive(Primitive::Duration(secs)),
+            tag,
+        }) if secs != 0 => match duration_of_seconds(secs) {
+            Some(step) => step,
+            None => {
+                return Err(ShellError::labeled_error(
+                    "Duration out of range",
+                    "the duration is too long to move a date by",
+                    tag,
+                ))
+            }
+        },
+        Some(step) => {
+            return Err(ShellError::labeled_error(
+                "Expected a duration",
+                "expected a non-zero duration, like 1d",
+                step.tag,
+            ))
+        }
+    };
+
+    let count = match (to, count) {
+        (None, None) => {
+            return Err(ShellError::labeled_error(
+                "Expected an end for the sequence",
+                "requires --to or --count",
+                name,
+            ))
+        }
+        (_, Some(count)) => Some(count.item as usize),
+        (Some(_), None) => None,
+    };
+
+    let ascending = step > Duration::zero();
+
+    // The sequence ends early at the last date there can be
+    let dates = std::iter::successors(Some(from), move |date| date.checked_add_signed(step))
+        .take_while(move |date| match to {
+            Some(to) if ascending => *date <= to,
+            Some(to) => *date >= to,
+            None => true,
+        })
+        .take(count.unwrap_or(usize::MAX))
+        .map(move |date| ReturnSuccess::value(UntaggedValue::date(date).into_value(&name)));
+
+    Ok(futures::stream::iter(dates).to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SeqDate;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SeqDate {})
+    }
+}
@@ -1,15 +1,21 @@
+use crate::commands::classified::block::run_block_with_params;
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{hir::Block, Primitive, Scope, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::Tagged;
-use nu_value_ext::get_data_by_key;
+use nu_value_ext::{as_string, get_data_by_key};
+use num_traits::Zero;
+use std::cmp::Ordering;
 
 pub struct SortBy;
 
 #[derive(Deserialize)]
 pub struct SortByArgs {
-    rest: Vec<Tagged<String>>,
+    rest: Vec<Value>,
+    reverse: bool,
+    insensitive: bool,
+    natural: bool,
 }
 
 #[async_trait]
@@ -19,7 +25,22 @@ impl WholeStreamCommand for SortBy {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("sort-by").rest(SyntaxShape::String, "the column(s) to sort by")
+        Signature::build("sort-by")
+            .rest(
+                SyntaxShape::Any,
+                "the column(s) to sort by (column:desc sorts that column in decreasing order), or a comparator block",
+            )
+            .switch("reverse", "sort in decreasing order", Some('r'))
+            .switch(
+                "insensitive",
+                "sort strings case-insensitively",
+                Some('i'),
+            )
+            .switch(
+                "natural",
+                "sort the numbers inside strings by their value (file9 before file10)",
+                Some('n'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -56,19 +77,80 @@ impl WholeStreamCommand for SortBy {
                 example: "ls | sort-by type size",
                 result: None,
             },
+            Example {
+                description: "Sort output by type, and then by decreasing file size for each type",
+                example: "ls | sort-by type size:desc",
+                result: None,
+            },
+            Example {
+                description: "Sort file names with numbers in them naturally",
+                example: "echo [file10 File9 file1] | sort-by --natural --insensitive",
+                result: Some(vec![
+                    UntaggedValue::string("file1").into(),
+                    UntaggedValue::string("File9").into(),
+                    UntaggedValue::string("file10").into(),
+                ]),
+            },
+            Example {
+                description: "Sort with a comparator block, given the rows to compare as $a and $b",
+                example: "echo [3 1 2] | sort-by { = $a > $b }",
+                result: Some(vec![
+                    UntaggedValue::int(3).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(1).into(),
+                ]),
+            },
         ]
     }
 }
 
+struct SortKey {
+    column: Tagged<String>,
+    descending: bool,
+}
+
+impl SortKey {
+    fn parse(arg: &Value) -> Result<SortKey, ShellError> {
+        let name = as_string(arg)?;
+
+        let (column, descending) = match name.rfind(':') {
+            Some(idx) if &name[idx + 1..] == "desc" => (&name[..idx], true),
+            Some(idx) if &name[idx + 1..] == "asc" => (&name[..idx], false),
+            _ => (&name[..], false),
+        };
+
+        Ok(SortKey {
+            column: column.to_string().tagged(&arg.tag),
+            descending,
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+struct SortOptions {
+    insensitive: bool,
+    natural: bool,
+}
+
 async fn sort_by(
-    args: CommandArgs,
+    raw_args: CommandArgs,
     registry: &CommandRegistry,
 ) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
-    let tag = args.call_info.name_tag.clone();
+    let scope = raw_args.call_info.scope.clone();
+    let mut context = Context::from_raw(&raw_args, &registry);
+    let tag = raw_args.call_info.name_tag.clone();
 
-    let (SortByArgs { rest }, mut input) = args.process(&registry).await?;
-    let mut vec = input.drain_vec().await;
+    let (
+        SortByArgs {
+            rest,
+            reverse,
+            insensitive,
+            natural,
+        },
+        mut input,
+    ) = raw_args.process(&registry).await?;
+    let vec = input.drain_vec().await;
 
     if vec.is_empty() {
         return Err(ShellError::labeled_error(
@@ -78,46 +160,271 @@ async fn sort_by(
         ));
     }
 
-    for sort_arg in rest.iter() {
-        let match_test = get_data_by_key(&vec[0], sort_arg.borrow_spanned());
-        if match_test == None {
-            return Err(ShellError::labeled_error(
-                "Can not find column to sort by",
-                "invalid column",
-                sort_arg.borrow_spanned().span,
-            ));
+    let sorted = match &rest[..] {
+        [Value {
+            value: UntaggedValue::Block(block),
+            tag,
+        }] => sort_with_block(vec, block, tag, reverse, &mut context, &scope).await?,
+        _ => {
+            let mut keys = vec![];
+
+            for arg in rest.iter() {
+                if let UntaggedValue::Block(_) = arg.value {
+                    return Err(ShellError::labeled_error(
+                        "Expected columns or a single block",
+                        "a comparator block can't be combined with other sort keys",
+                        &arg.tag,
+                    ));
+                }
+
+                let key = SortKey::parse(arg)?;
+
+                if get_data_by_key(&vec[0], key.column.borrow_spanned()).is_none() {
+                    return Err(ShellError::labeled_error(
+                        "Can not find column to sort by",
+                        "invalid column",
+                        key.column.borrow_spanned().span,
+                    ));
+                }
+
+                keys.push(key);
+            }
+
+            sort_by_keys(
+                vec,
+                &keys,
+                reverse,
+                SortOptions {
+                    insensitive,
+                    natural,
+                },
+            )
+        }
+    };
+
+    Ok(futures::stream::iter(sorted).to_output_stream())
+}
+
+fn sort_by_keys(
+    values: Vec<Value>,
+    keys: &[SortKey],
+    reverse: bool,
+    options: SortOptions,
+) -> Vec<Value> {
+    let mut keyed: Vec<(Vec<Option<Value>>, Value)> = values
+        .into_iter()
+        .map(|item| {
+            let cells = if keys.is_empty() {
+                vec![Some(item.clone())]
+            } else {
+                keys.iter()
+                    .map(|key| get_data_by_key(&item, key.column.borrow_spanned()))
+                    .collect()
+            };
+            (cells, item)
+        })
+        .collect();
+
+    keyed.sort_by(|(left, _), (right, _)| {
+        for (idx, (left, right)) in left.iter().zip(right.iter()).enumerate() {
+            let descending = keys.get(idx).map(|key| key.descending).unwrap_or(false);
+
+            let ordering = match (left, right) {
+                (Some(left), Some(right)) => compare_values(left, right, options),
+                (left, right) => left.is_some().cmp(&right.is_some()),
+            };
+
+            let ordering = if descending != reverse {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
         }
+
+        Ordering::Equal
+    });
+
+    keyed.into_iter().map(|(_, item)| item).collect()
+}
+
+fn compare_values(left: &Value, right: &Value, options: SortOptions) -> Ordering {
+    match (&left.value, &right.value) {
+        (
+            UntaggedValue::Primitive(Primitive::String(left)),
+            UntaggedValue::Primitive(Primitive::String(right)),
+        ) => compare_strings(left, right, options),
+        (left, right) => left.cmp(right),
     }
+}
+
+fn compare_strings(left: &str, right: &str, options: SortOptions) -> Ordering {
+    let (left, right) = if options.insensitive {
+        (left.to_lowercase(), right.to_lowercase())
+    } else {
+        (left.to_string(), right.to_string())
+    };
+
+    if options.natural {
+        natural_cmp(&left, &right)
+    } else {
+        left.cmp(&right)
+    }
+}
+
+/// Compares strings so that runs of digits are ordered by their numeric value, eg. "file9"
+/// comes before "file10".
+fn natural_cmp(left: &str, right: &str) -> Ordering {
+    let mut left = left.chars().peekable();
+    let mut right = right.chars().peekable();
 
-    match &vec[0] {
-        Value {
-            value: UntaggedValue::Primitive(_),
-            ..
-        } => {
-            vec.sort();
+    loop {
+        match (left.peek(), right.peek()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(l), Some(r)) if l.is_ascii_digit() && r.is_ascii_digit() => {
+                let left_digits = take_digits(&mut left);
+                let right_digits = take_digits(&mut right);
+
+                let left_number = left_digits.trim_start_matches('0');
+                let right_number = right_digits.trim_start_matches('0');
+
+                let ordering = left_number
+                    .len()
+                    .cmp(&right_number.len())
+                    .then_with(|| left_number.cmp(right_number))
+                    .then_with(|| left_digits.len().cmp(&right_digits.len()));
+
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(l), Some(r)) => {
+                let ordering = l.cmp(r);
+
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+
+                left.next();
+                right.next();
+            }
         }
-        _ => {
-            let calc_key = |item: &Value| {
-                rest.iter()
-                    .map(|f| get_data_by_key(item, f.borrow_spanned()))
-                    .collect::<Vec<Option<Value>>>()
+    }
+}
+
+fn take_digits(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut digits = String::new();
+
+    while let Some(c) = chars.peek() {
+        if !c.is_ascii_digit() {
+            break;
+        }
+        digits.push(*c);
+        chars.next();
+    }
+
+    digits
+}
+
+/// Sorts the values with a comparator block, which gets the values to compare as `$a` and `$b`
+/// and returns whether `$a` goes first (or a number, negative when `$a` goes first).
+///
+/// The block can't be called from a synchronous comparator, so this is a binary insertion sort,
+/// which keeps the number of times the block runs down to O(n log n).
+async fn sort_with_block(
+    values: Vec<Value>,
+    block: &Block,
+    tag: &Tag,
+    reverse: bool,
+    context: &mut Context,
+    scope: &Scope,
+) -> Result<Vec<Value>, ShellError> {
+    let mut sorted: Vec<Value> = Vec::with_capacity(values.len());
+
+    for item in values {
+        let (mut low, mut high) = (0, sorted.len());
+
+        while low < high {
+            let middle = (low + high) / 2;
+
+            let goes_first = if reverse {
+                block_says_first(&sorted[middle], &item, block, tag, context, scope).await?
+            } else {
+                block_says_first(&item, &sorted[middle], block, tag, context, scope).await?
             };
-            vec.sort_by_cached_key(calc_key);
+
+            if goes_first {
+                high = middle;
+            } else {
+                low = middle + 1;
+            }
         }
-    };
 
-    let mut values_vec_deque: VecDeque<Value> = VecDeque::new();
+        sorted.insert(low, item);
+    }
+
+    Ok(sorted)
+}
+
+async fn block_says_first(
+    a: &Value,
+    b: &Value,
+    block: &Block,
+    tag: &Tag,
+    context: &mut Context,
+    scope: &Scope,
+) -> Result<bool, ShellError> {
+    let mut params = IndexMap::new();
+    params.insert("$a".to_string(), a.clone());
+    params.insert("$b".to_string(), b.clone());
+
+    let result = run_block_with_params(
+        block,
+        context,
+        InputStream::empty(),
+        &scope.it,
+        &scope.vars,
+        &params,
+        &scope.env,
+    )
+    .await?
+    .into_vec()
+    .await;
 
-    for item in vec {
-        values_vec_deque.push_back(item);
+    if let Some(err) = context.get_errors().first() {
+        return Err(err.clone());
     }
 
-    Ok(futures::stream::iter(values_vec_deque).to_output_stream())
+    match result.first().map(|value| &value.value) {
+        Some(UntaggedValue::Primitive(Primitive::Boolean(goes_first))) => Ok(*goes_first),
+        Some(UntaggedValue::Primitive(Primitive::Int(n))) => Ok(*n < Zero::zero()),
+        Some(UntaggedValue::Error(err)) => Err(err.clone()),
+        _ => Err(ShellError::labeled_error(
+            "Expected a boolean or a number from the comparator block",
+            "comparator block",
+            tag,
+        )),
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::SortBy;
+    use super::{natural_cmp, SortBy};
+    use std::cmp::Ordering;
+
+    #[test]
+    fn natural_order_compares_numbers_by_value() {
+        assert_eq!(natural_cmp("file9", "file10"), Ordering::Less);
+        assert_eq!(natural_cmp("file10", "file10"), Ordering::Equal);
+        assert_eq!(natural_cmp("file010", "file9"), Ordering::Greater);
+        assert_eq!(natural_cmp("file1a", "file1b"), Ordering::Less);
+        assert_eq!(natural_cmp("file", "file1"), Ordering::Less);
+    }
 
     #[test]
     fn examples_work_as_expected() {
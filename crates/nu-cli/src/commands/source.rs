@@ -0,0 +1,240 @@
+use crate::cli::{document_definitions, print_err_in};
+use crate::commands::classified::block::run_block_with_vars;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::hir::{Block, ClassifiedCommand, Expression};
+use nu_protocol::{Scope, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::{Tagged, Text};
+use std::path::{Path, PathBuf};
+
+pub struct Source;
+
+#[derive(Deserialize)]
+pub struct SourceArgs {
+    filename: Tagged<PathBuf>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Source {
+    fn name(&self) -> &str {
+        "source"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("source").required(
+            "filename",
+            SyntaxShape::Path,
+            "the file of commands to run",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Run the commands of a file in the current session, keeping the commands, aliases and environment variables it defines. Files sourced by the file are found from its directory."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        source(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Run the commands of a file",
+                example: "source aliases.nu",
+                result: None,
+            },
+            Example {
+                description: "Split a config over several files, sourced from the startup commands",
+                example: "config --set [startup ['source ~/.config/nu/init.nu']]",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn source(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let mut context = Context::from_args(&args, &registry);
+    let scope = args.call_info.scope.clone();
+    let cwd = PathBuf::from(args.shell_manager.path());
+
+    let (SourceArgs { filename }, _) = args.process(&registry).await?;
+
+    let path = cwd.join(&filename.item);
+    let contents = std::fs::read_to_string(&path).map_err(|_| {
+        ShellError::labeled_error(
+            "Could not source the file",
+            "could not read this file",
+            &filename.tag,
+        )
+    })?;
+
+    context.raw_input = contents.clone();
+    let values = run_file(&path, &contents, &filename.tag, &mut context, &scope).await?;
+
+    Ok(futures::stream::iter(values).to_output_stream())
+}
+
+/// Runs the lines of the file at `path`, named by `tag`. The first error in it is shown against
+/// the file, and given back as an error of `tag`.
+pub(crate) async fn run_file(
+    path: &Path,
+    contents: &str,
+    tag: &Tag,
+    context: &mut Context,
+    scope: &Scope,
+) -> Result<Vec<Value>, ShellError> {
+    match run_lines(contents, 0, path.parent(), context, scope).await {
+        Ok(values) => Ok(values),
+        Err(err) => {
+            print_err_in(err, &path.display().to_string(), &Text::from(contents));
+
+            Err(ShellError::labeled_error(
+                "Could not run the file",
+                "there is an error in this file",
+                tag,
+            ))
+        }
+    }
+}
+
+/// Runs `source` one line at a time, the way lines typed in are run, giving what they output.
+/// Its spans start at `offset` in the raw input of the context, and the relative paths given to
+/// `source` in it are from `dir`.
+pub(crate) async fn run_lines(
+    source: &str,
+    offset: usize,
+    dir: Option<&Path>,
+    context: &mut Context,
+    scope: &Scope,
+) -> Result<Vec<Value>, ShellError> {
+    let mut output = vec![];
+    let mut vars = scope.vars.clone();
+    let mut doc_comments: Vec<String> = vec![];
+    let mut pos = 0;
+    let mut continued = None;
+
+    for line in source.split('\n') {
+        // A line that leaves a block open or ends with a `|` goes on with the next ones
+        let line_pos = continued.take().unwrap_or(pos);
+        pos += line.len() + 1;
+        let line = &source[line_pos..pos - 1];
+
+        if pos <= source.len()
+            && !line.trim_start().starts_with('#')
+            && nu_parser::is_incomplete(line)
+        {
+            continued = Some(line_pos);
+            continue;
+        }
+
+        let text = line.trim_end();
+        let line_start = offset + line_pos + text.len() - text.trim_start().len();
+        let text = text.trim_start();
+
+        if text.is_empty() {
+            doc_comments.clear();
+            continue;
+        }
+
+        if let Some(comment) = text.strip_prefix('#') {
+            if !comment.starts_with('!') {
+                doc_comments.push(comment.strip_prefix(' ').unwrap_or(comment).to_string());
+            }
+            continue;
+        }
+
+        let lite_block = nu_parser::lite_parse(text, line_start)?;
+        let usage = std::mem::take(&mut doc_comments).join("\n");
+
+        // Each pipeline is parsed once the ones before it have run, so it knows about the
+        // commands they define
+        for pipeline in lite_block.block {
+            let lite_block = nu_parser::LiteBlock {
+                block: vec![pipeline],
+            };
+
+            let mut classified_block = nu_parser::classify_block(&lite_block, context.registry());
+            if let Some(failure) = classified_block.failed {
+                return Err(failure.into());
+            }
+
+            if !usage.is_empty() {
+                document_definitions(&mut classified_block.block, usage.clone());
+            }
+
+            if let Some(dir) = dir {
+                resolve_sources(&mut classified_block.block, dir);
+            }
+
+            classified_block.block.expand_it_usage();
+
+            let env = context.get_env();
+            let values: Vec<Value> = run_block_with_vars(
+                &classified_block.block,
+                context,
+                InputStream::empty(),
+                &scope.it,
+                &mut vars,
+                &env,
+            )
+            .await?
+            .collect()
+            .await;
+
+            for value in values {
+                match value.value {
+                    UntaggedValue::Error(err) => return Err(err),
+                    _ => output.push(value),
+                }
+            }
+
+            if let Some(err) = context.get_errors().into_iter().next() {
+                context.clear_errors();
+                return Err(err);
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+/// Makes the relative paths given to `source` start from `dir`, the directory of the file
+/// they're written in.
+pub(crate) fn resolve_sources(block: &mut Block, dir: &Path) {
+    for pipeline in block.block.iter_mut() {
+        for command in pipeline.list.iter_mut() {
+            if let ClassifiedCommand::Internal(internal) = command {
+                if internal.name != "source" {
+                    continue;
+                }
+
+                let positional = internal.args.positional.iter_mut().flatten();
+                for argument in positional.take(1) {
+                    if let Expression::FilePath(path) = &mut argument.expr {
+                        if path.is_relative() {
+                            *path = dir.join(&path);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Source;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Source {})
+    }
+}
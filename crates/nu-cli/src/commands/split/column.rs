@@ -1,5 +1,6 @@
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
+use indexmap::indexmap;
 use log::trace;
 use nu_errors::ShellError;
 use nu_protocol::{
@@ -7,12 +8,17 @@ use nu_protocol::{
 };
 use nu_source::Tagged;
 
+use super::separator::Separator;
+
 #[derive(Deserialize)]
 struct SplitColumnArgs {
     separator: Tagged<String>,
     rest: Vec<Tagged<String>>,
     #[serde(rename(deserialize = "collapse-empty"))]
     collapse_empty: bool,
+    regex: bool,
+    #[serde(rename(deserialize = "max-splits"))]
+    max_splits: Option<Tagged<u64>>,
 }
 
 pub struct SubCommand;
@@ -31,6 +37,17 @@ impl WholeStreamCommand for SubCommand {
                 "the character that denotes what separates columns",
             )
             .switch("collapse-empty", "remove empty columns", Some('c'))
+            .switch(
+                "regex",
+                "treat the separator as a regular expression",
+                Some('r'),
+            )
+            .named(
+                "max-splits",
+                SyntaxShape::Int,
+                "split at most this many times, leaving the rest in the last column",
+                Some('m'),
+            )
             .rest(SyntaxShape::String, "column names to give the new columns")
     }
 
@@ -45,6 +62,30 @@ impl WholeStreamCommand for SubCommand {
     ) -> Result<OutputStream, ShellError> {
         split_column(args, registry).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Split text into columns named after the given names",
+                example: "echo 'a,b,c' | split column ',' x y z",
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                    "x".to_string() => UntaggedValue::string("a").into(),
+                    "y".to_string() => UntaggedValue::string("b").into(),
+                    "z".to_string() => UntaggedValue::string("c").into(),
+                })
+                .into()]),
+            },
+            Example {
+                description: "Split text on a regular expression, at most once",
+                example: "echo 'a , b;c' | split column -r '\\s*[,;]\\s*' --max-splits 1",
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                    "Column1".to_string() => UntaggedValue::string("a").into(),
+                    "Column2".to_string() => UntaggedValue::string("b;c").into(),
+                })
+                .into()]),
+            },
+        ]
+    }
 }
 
 async fn split_column(
@@ -58,49 +99,45 @@ async fn split_column(
             separator,
             rest,
             collapse_empty,
+            regex,
+            max_splits,
         },
         input,
     ) = args.process(&registry).await?;
 
+    let splitter = Separator::new(&separator, regex)?;
+    let limit = max_splits.map(|max| max.item as usize + 1);
+    let positional: Vec<_> = rest.iter().map(|f| f.item.clone()).collect();
+
     Ok(input
         .map(move |v| {
             if let Ok(s) = v.as_string() {
-                let splitter = separator.replace("\\n", "\n");
-                trace!("splitting with {:?}", splitter);
+                trace!("splitting with {:?}", separator.item);
 
                 let split_result: Vec<_> = if collapse_empty {
-                    s.split(&splitter).filter(|s| !s.is_empty()).collect()
+                    splitter
+                        .split(&s, limit)
+                        .into_iter()
+                        .filter(|s| !s.is_empty())
+                        .collect()
                 } else {
-                    s.split(&splitter).collect()
+                    splitter.split(&s, limit)
                 };
 
                 trace!("split result = {:?}", split_result);
 
-                let positional: Vec<_> = rest.iter().map(|f| f.item.clone()).collect();
-
-                // If they didn't provide column names, make up our own
-                if positional.is_empty() {
-                    let mut gen_columns = vec![];
-                    for i in 0..split_result.len() {
-                        gen_columns.push(format!("Column{}", i + 1));
-                    }
-
-                    let mut dict = TaggedDictBuilder::new(&v.tag);
-                    for (&k, v) in split_result.iter().zip(gen_columns.iter()) {
-                        dict.insert_untagged(v.clone(), Primitive::String(k.into()));
-                    }
+                // Columns without a name given are named after their position
+                let mut dict = TaggedDictBuilder::new(&v.tag);
+                for (i, k) in split_result.into_iter().enumerate() {
+                    let column = match positional.get(i) {
+                        Some(name) => name.clone(),
+                        None => format!("Column{}", i + 1),
+                    };
 
-                    ReturnSuccess::value(dict.into_value())
-                } else {
-                    let mut dict = TaggedDictBuilder::new(&v.tag);
-                    for (&k, v) in split_result.iter().zip(positional.iter()) {
-                        dict.insert_untagged(
-                            v,
-                            UntaggedValue::Primitive(Primitive::String(k.into())),
-                        );
-                    }
-                    ReturnSuccess::value(dict.into_value())
+                    dict.insert_untagged(column, Primitive::String(k.into()));
                 }
+
+                ReturnSuccess::value(dict.into_value())
             } else {
                 Err(ShellError::labeled_error_with_secondary(
                     "Expected a string from pipeline",
@@ -1,6 +1,7 @@
 pub mod column;
 pub mod command;
 pub mod row;
+mod separator;
 
 pub use column::SubCommand as SplitColumn;
 pub use command::Command as Split;
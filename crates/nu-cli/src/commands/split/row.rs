@@ -5,9 +5,12 @@ use nu_errors::ShellError;
 use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
 use nu_source::Tagged;
 
+use super::separator::Separator;
+
 #[derive(Deserialize)]
 struct SplitRowArgs {
     separator: Tagged<String>,
+    regex: bool,
 }
 
 pub struct SubCommand;
@@ -19,11 +22,17 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("split row").required(
-            "separator",
-            SyntaxShape::Any,
-            "the character that denotes what separates rows",
-        )
+        Signature::build("split row")
+            .required(
+                "separator",
+                SyntaxShape::Any,
+                "the character that denotes what separates rows",
+            )
+            .switch(
+                "regex",
+                "treat the separator as a regular expression",
+                Some('r'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -37,6 +46,18 @@ impl WholeStreamCommand for SubCommand {
     ) -> Result<OutputStream, ShellError> {
         split_row(args, registry).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Split text into rows on a regular expression",
+            example: "echo 'a, b;c' | split row -r '[,;] ?'",
+            result: Some(vec![
+                UntaggedValue::string("a").into(),
+                UntaggedValue::string("b").into(),
+                UntaggedValue::string("c").into(),
+            ]),
+        }]
+    }
 }
 
 async fn split_row(
@@ -45,14 +66,15 @@ async fn split_row(
 ) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
     let name = args.call_info.name_tag.clone();
-    let (SplitRowArgs { separator }, input) = args.process(&registry).await?;
+    let (SplitRowArgs { separator, regex }, input) = args.process(&registry).await?;
+    let splitter = Separator::new(&separator, regex)?;
     Ok(input
         .flat_map(move |v| {
             if let Ok(s) = v.as_string() {
-                let splitter = separator.item.replace("\\n", "\n");
-                trace!("splitting with {:?}", splitter);
-                let split_result: Vec<String> = s
-                    .split(&splitter)
+                trace!("splitting with {:?}", separator.item);
+                let split_result: Vec<String> = splitter
+                    .split(&s, None)
+                    .into_iter()
                     .filter_map(|s| {
                         if s.trim() != "" {
                             Some(s.to_string())
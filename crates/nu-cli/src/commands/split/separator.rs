@@ -0,0 +1,56 @@
+use nu_errors::ShellError;
+use nu_source::Tagged;
+use regex::Regex;
+
+/// What separates the pieces of text `split column` and `split row` split.
+pub enum Separator {
+    Text(String),
+    Pattern(Regex),
+}
+
+impl Separator {
+    pub fn new(separator: &Tagged<String>, regex: bool) -> Result<Separator, ShellError> {
+        if regex {
+            Regex::new(&separator.item)
+                .map(Separator::Pattern)
+                .map_err(|_| {
+                    ShellError::labeled_error(
+                        "Invalid regular expression",
+                        "could not parse this regular expression",
+                        &separator.tag,
+                    )
+                })
+        } else {
+            Ok(Separator::Text(separator.item.replace("\\n", "\n")))
+        }
+    }
+
+    /// Splits the text into at most `limit` pieces, the last one holding the rest of the text.
+    pub fn split<'a>(&self, text: &'a str, limit: Option<usize>) -> Vec<&'a str> {
+        match (self, limit) {
+            (Separator::Text(separator), None) => text.split(separator.as_str()).collect(),
+            (Separator::Text(separator), Some(limit)) => {
+                text.splitn(limit, separator.as_str()).collect()
+            }
+            (Separator::Pattern(regex), None) => regex.split(text).collect(),
+            (Separator::Pattern(regex), Some(limit)) => regex.splitn(text, limit).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Separator;
+    use regex::Regex;
+
+    #[test]
+    fn splits_on_text_or_patterns() {
+        let text = Separator::Text(",".to_string());
+        let pattern = Separator::Pattern(Regex::new(r"\s*[,;]\s*").unwrap());
+
+        assert_eq!(text.split("a,b,c", None), vec!["a", "b", "c"]);
+        assert_eq!(text.split("a,b,c", Some(2)), vec!["a", "b,c"]);
+        assert_eq!(pattern.split("a , b;c", None), vec!["a", "b", "c"]);
+        assert_eq!(pattern.split("a , b;c", Some(2)), vec!["a", "b;c"]);
+    }
+}
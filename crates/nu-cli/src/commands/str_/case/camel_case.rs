@@ -0,0 +1,76 @@
+use super::{capitalize, operate, words};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str camel-case"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str camel-case").rest(
+            SyntaxShape::ColumnPath,
+            "optionally convert text to camelCase by column paths",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "converts text to camelCase"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry, &to_camel_case).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "convert a string to camelCase",
+            example: "echo 'this-is-the-first-case' | str camel-case",
+            result: Some(vec![Value::from("thisIsTheFirstCase")]),
+        }]
+    }
+}
+
+pub fn to_camel_case(text: &str) -> String {
+    words(text)
+        .iter()
+        .enumerate()
+        .map(|(idx, word)| {
+            if idx == 0 {
+                word.to_lowercase()
+            } else {
+                capitalize(word)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_camel_case, SubCommand};
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn converts_to_camel_case() {
+        assert_eq!(to_camel_case("NuShell"), "nuShell");
+        assert_eq!(
+            to_camel_case("this_is_the_second_case"),
+            "thisIsTheSecondCase"
+        );
+    }
+}
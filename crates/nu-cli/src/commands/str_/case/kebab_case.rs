@@ -0,0 +1,70 @@
+use super::{operate, words};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str kebab-case"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str kebab-case").rest(
+            SyntaxShape::ColumnPath,
+            "optionally convert text to kebab-case by column paths",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "converts text to kebab-case"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry, &to_kebab_case).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "convert a string to kebab-case",
+            example: "echo 'NuShell' | str kebab-case",
+            result: Some(vec![Value::from("nu-shell")]),
+        }]
+    }
+}
+
+pub fn to_kebab_case(text: &str) -> String {
+    words(text)
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_kebab_case, SubCommand};
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn converts_to_kebab_case() {
+        assert_eq!(
+            to_kebab_case("this_is_the_second_case"),
+            "this-is-the-second-case"
+        );
+        assert_eq!(to_kebab_case("HTTPServer"), "http-server");
+    }
+}
@@ -0,0 +1,157 @@
+pub mod camel_case;
+pub mod kebab_case;
+pub mod pascal_case;
+pub mod snake_case;
+pub mod title_case;
+
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{ColumnPath, Primitive, ReturnSuccess, UntaggedValue, Value};
+use nu_source::Tag;
+use nu_value_ext::ValueExt;
+
+pub use camel_case::SubCommand as CamelCase;
+pub use kebab_case::SubCommand as KebabCase;
+pub use pascal_case::SubCommand as PascalCase;
+pub use snake_case::SubCommand as SnakeCase;
+pub use title_case::SubCommand as TitleCase;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+}
+
+pub async fn operate<F>(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+    case_operation: &'static F,
+) -> Result<OutputStream, ShellError>
+where
+    F: Fn(&str) -> String + Send + Sync + 'static,
+{
+    let registry = registry.clone();
+
+    let (Arguments { rest }, input) = args.process(&registry).await?;
+
+    let column_paths: Vec<_> = rest;
+
+    Ok(input
+        .map(move |v| {
+            if column_paths.is_empty() {
+                match action(&v, v.tag(), case_operation) {
+                    Ok(out) => ReturnSuccess::value(out),
+                    Err(err) => Err(err),
+                }
+            } else {
+                let mut ret = v;
+
+                for path in &column_paths {
+                    let swapping = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, old.tag(), case_operation)),
+                    );
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            return Err(err);
+                        }
+                    }
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+pub fn action<F>(
+    input: &Value,
+    tag: impl Into<Tag>,
+    case_operation: &F,
+) -> Result<Value, ShellError>
+where
+    F: Fn(&str) -> String,
+{
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => {
+            Ok(UntaggedValue::string(case_operation(s)).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not string",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+/// Splits text into its words. Words are separated by anything that isn't a letter or a digit,
+/// and by a change of case, like in `camelCase` or `HTTPServer`.
+pub fn words(text: &str) -> Vec<String> {
+    let mut words = vec![];
+    let mut word = String::new();
+    let chars: Vec<char> = text.chars().collect();
+
+    for (idx, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && !word.is_empty() {
+            let previous = chars[idx - 1];
+            let next_is_lowercase = matches!(chars.get(idx + 1), Some(n) if n.is_lowercase());
+
+            if previous.is_lowercase()
+                || previous.is_numeric()
+                || (previous.is_uppercase() && next_is_lowercase)
+            {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+
+        word.push(c);
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+/// Uppercases the first letter of a word and lowercases the rest.
+pub fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::words;
+
+    #[test]
+    fn splits_words_on_separators_and_case_changes() {
+        assert_eq!(words("nu_shell-is fun"), vec!["nu", "shell", "is", "fun"]);
+        assert_eq!(words("camelCaseWord"), vec!["camel", "Case", "Word"]);
+        assert_eq!(words("HTTPServer"), vec!["HTTP", "Server"]);
+        assert_eq!(words("version2Beta"), vec!["version2", "Beta"]);
+        assert_eq!(words("  --  "), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,63 @@
+use super::{capitalize, operate, words};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str pascal-case"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str pascal-case").rest(
+            SyntaxShape::ColumnPath,
+            "optionally convert text to PascalCase by column paths",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "converts text to PascalCase"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry, &to_pascal_case).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "convert a string to PascalCase",
+            example: "echo 'this-is-the-first-case' | str pascal-case",
+            result: Some(vec![Value::from("ThisIsTheFirstCase")]),
+        }]
+    }
+}
+
+pub fn to_pascal_case(text: &str) -> String {
+    words(text).iter().map(|word| capitalize(word)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_pascal_case, SubCommand};
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn converts_to_pascal_case() {
+        assert_eq!(to_pascal_case("nu_shell"), "NuShell");
+        assert_eq!(to_pascal_case("camelCase"), "CamelCase");
+    }
+}
@@ -0,0 +1,70 @@
+use super::{operate, words};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str snake-case"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str snake-case").rest(
+            SyntaxShape::ColumnPath,
+            "optionally convert text to snake_case by column paths",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "converts text to snake_case"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry, &to_snake_case).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "convert a string to snake_case",
+            example: "echo 'NuShell' | str snake-case",
+            result: Some(vec![Value::from("nu_shell")]),
+        }]
+    }
+}
+
+pub fn to_snake_case(text: &str) -> String {
+    words(text)
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_snake_case, SubCommand};
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn converts_to_snake_case() {
+        assert_eq!(
+            to_snake_case("this-is-the-second-case"),
+            "this_is_the_second_case"
+        );
+        assert_eq!(to_snake_case("userID 42"), "user_id_42");
+    }
+}
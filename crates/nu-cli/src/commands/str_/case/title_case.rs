@@ -0,0 +1,70 @@
+use super::{capitalize, operate, words};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str title-case"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str title-case").rest(
+            SyntaxShape::ColumnPath,
+            "optionally convert text to Title Case by column paths",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "converts text to Title Case"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry, &to_title_case).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "convert a string to Title Case",
+            example: "echo 'nu_shell' | str title-case",
+            result: Some(vec![Value::from("Nu Shell")]),
+        }]
+    }
+}
+
+pub fn to_title_case(text: &str) -> String {
+    words(text)
+        .iter()
+        .map(|word| capitalize(word))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_title_case, SubCommand};
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn converts_to_title_case() {
+        assert_eq!(
+            to_title_case("this-is-the-second-case"),
+            "This Is The Second Case"
+        );
+        assert_eq!(to_title_case("camelCase"), "Camel Case");
+    }
+}
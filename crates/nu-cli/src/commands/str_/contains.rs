@@ -0,0 +1,185 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    pattern: Tagged<String>,
+    rest: Vec<ColumnPath>,
+    insensitive: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str contains"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str contains")
+            .required("pattern", SyntaxShape::String, "the pattern to find")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally check if text contains a pattern by column paths",
+            )
+            .switch(
+                "insensitive",
+                "search the text case-insensitively",
+                Some('i'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "checks if text contains a pattern"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Check if text contains a pattern",
+                example: "echo 'my_library.rb' | str contains '.rb'",
+                result: Some(vec![UntaggedValue::boolean(true).into()]),
+            },
+            Example {
+                description: "Check if text contains a pattern, ignoring case",
+                example: "echo 'my_library.rb' | str contains -i 'LIB'",
+                result: Some(vec![UntaggedValue::boolean(true).into()]),
+            },
+        ]
+    }
+}
+
+#[derive(Clone)]
+struct Contains {
+    pattern: String,
+    insensitive: bool,
+}
+
+async fn operate(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let (
+        Arguments {
+            pattern,
+            rest,
+            insensitive,
+        },
+        input,
+    ) = args.process(&registry).await?;
+    let options = Contains {
+        pattern: pattern.item,
+        insensitive,
+    };
+
+    let column_paths: Vec<_> = rest;
+
+    Ok(input
+        .map(move |v| {
+            if column_paths.is_empty() {
+                match action(&v, &options, v.tag()) {
+                    Ok(out) => ReturnSuccess::value(out),
+                    Err(err) => Err(err),
+                }
+            } else {
+                let mut ret = v;
+
+                for path in &column_paths {
+                    let options = options.clone();
+
+                    let swapping = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, &options, old.tag())),
+                    );
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            return Err(err);
+                        }
+                    }
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn action(input: &Value, options: &Contains, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => {
+            let contains = if options.insensitive {
+                s.to_lowercase().contains(&options.pattern.to_lowercase())
+            } else {
+                s.contains(&options.pattern)
+            };
+
+            Ok(UntaggedValue::boolean(contains).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not string",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, Contains, SubCommand};
+    use nu_plugin::test_helpers::value::string;
+    use nu_protocol::UntaggedValue;
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn checks_if_text_contains_a_pattern() {
+        let word = string("Cargo.toml");
+        let options = Contains {
+            pattern: "TOML".to_string(),
+            insensitive: false,
+        };
+
+        let actual = action(&word, &options, Tag::unknown()).unwrap();
+        assert_eq!(actual, UntaggedValue::boolean(false).into_untagged_value());
+
+        let options = Contains {
+            insensitive: true,
+            ..options
+        };
+
+        let actual = action(&word, &options, Tag::unknown()).unwrap();
+        assert_eq!(actual, UntaggedValue::boolean(true).into_untagged_value());
+    }
+}
@@ -0,0 +1,191 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    compare_to: Tagged<String>,
+    rest: Vec<ColumnPath>,
+    insensitive: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str distance"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str distance")
+            .required("compare-to", SyntaxShape::String, "the text to compare to")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally get the distance by column paths",
+            )
+            .switch(
+                "insensitive",
+                "compare the text case-insensitively",
+                Some('i'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "gets the edit (Levenshtein) distance between text and another string"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the edit distance between two strings",
+                example: "echo 'nushell' | str distance 'nutshell'",
+                result: Some(vec![UntaggedValue::int(1).into()]),
+            },
+            Example {
+                description: "Get the edit distance ignoring case",
+                example: "echo 'NuShell' | str distance -i 'nushell'",
+                result: Some(vec![UntaggedValue::int(0).into()]),
+            },
+        ]
+    }
+}
+
+#[derive(Clone)]
+struct Compare {
+    compare_to: String,
+    insensitive: bool,
+}
+
+async fn operate(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let (
+        Arguments {
+            compare_to,
+            rest,
+            insensitive,
+        },
+        input,
+    ) = args.process(&registry).await?;
+    let options = Compare {
+        compare_to: compare_to.item,
+        insensitive,
+    };
+
+    let column_paths: Vec<_> = rest;
+
+    Ok(input
+        .map(move |v| {
+            if column_paths.is_empty() {
+                match action(&v, &options, v.tag()) {
+                    Ok(out) => ReturnSuccess::value(out),
+                    Err(err) => Err(err),
+                }
+            } else {
+                let mut ret = v;
+
+                for path in &column_paths {
+                    let options = options.clone();
+
+                    let swapping = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, &options, old.tag())),
+                    );
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            return Err(err);
+                        }
+                    }
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn action(input: &Value, options: &Compare, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => {
+            let distance = if options.insensitive {
+                levenshtein(&s.to_lowercase(), &options.compare_to.to_lowercase())
+            } else {
+                levenshtein(s, &options.compare_to)
+            };
+
+            Ok(UntaggedValue::int(distance).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not string",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+/// The Levenshtein distance between two strings: the number of characters to insert, delete
+/// or substitute to turn one into the other.
+pub fn levenshtein(left: &str, right: &str) -> usize {
+    let right: Vec<char> = right.chars().collect();
+    let mut previous: Vec<usize> = (0..=right.len()).collect();
+
+    for (i, l) in left.chars().enumerate() {
+        let mut current = vec![i + 1; right.len() + 1];
+
+        for (j, r) in right.iter().enumerate() {
+            let substitution = previous[j] + if l == *r { 0 } else { 1 };
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        previous = current;
+    }
+
+    previous[right.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{levenshtein, SubCommand};
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn counts_the_edits_between_strings() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "nu"), 2);
+        assert_eq!(levenshtein("nu", ""), 2);
+        assert_eq!(levenshtein("ñu", "nu"), 1);
+        assert_eq!(levenshtein("same", "same"), 0);
+    }
+}
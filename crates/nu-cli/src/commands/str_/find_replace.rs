@@ -8,13 +8,15 @@ use nu_protocol::{
 use nu_source::{Tag, Tagged};
 use nu_value_ext::ValueExt;
 
-use regex::Regex;
+use regex::{Regex, RegexBuilder};
 
 #[derive(Deserialize)]
 struct Arguments {
     find: Tagged<String>,
     replace: Tagged<String>,
     rest: Vec<ColumnPath>,
+    all: bool,
+    insensitive: bool,
 }
 
 pub struct SubCommand;
@@ -33,6 +35,16 @@ impl WholeStreamCommand for SubCommand {
                 SyntaxShape::ColumnPath,
                 "optionally find and replace text by column paths",
             )
+            .switch(
+                "all",
+                "replace all matches instead of only the first",
+                Some('a'),
+            )
+            .switch(
+                "insensitive",
+                "match the pattern case-insensitively",
+                Some('i'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -48,16 +60,37 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Find and replace contents with capture group",
-            example: "echo 'my_library.rb' | str find-replace '(.+).rb' '$1.nu'",
-            result: Some(vec![Value::from("my_library.nu")]),
-        }]
+        vec![
+            Example {
+                description: "Find and replace contents with capture group",
+                example: "echo 'my_library.rb' | str find-replace '(.+).rb' '$1.nu'",
+                result: Some(vec![Value::from("my_library.nu")]),
+            },
+            Example {
+                description: "Find and replace contents with named capture groups",
+                example: "echo '2020-08-31' | str find-replace '(?P<y>\\d+)-(?P<m>\\d+)-(?P<d>\\d+)' '$d/$m/$y'",
+                result: Some(vec![Value::from("31/08/2020")]),
+            },
+            Example {
+                description: "Find and replace all occurrences of a pattern",
+                example: "echo 'abc abc abc' | str find-replace --all 'b' 'z'",
+                result: Some(vec![Value::from("azc azc azc")]),
+            },
+            Example {
+                description: "Find and replace ignoring case",
+                example: "echo 'Nu nu NU' | str find-replace --all --insensitive 'nu' 'shell'",
+                result: Some(vec![Value::from("shell shell shell")]),
+            },
+        ]
     }
 }
 
 #[derive(Clone)]
-struct FindReplace(String, String);
+struct FindReplace {
+    regex: Regex,
+    replacement: String,
+    all: bool,
+}
 
 async fn operate(
     args: CommandArgs,
@@ -70,10 +103,32 @@ async fn operate(
             find,
             replace,
             rest,
+            all,
+            insensitive,
         },
         input,
     ) = args.process(&registry).await?;
-    let options = FindReplace(find.item, replace.item);
+
+    let regex = RegexBuilder::new(&find.item)
+        .case_insensitive(insensitive)
+        .build()
+        .map_err(|err| {
+            // The parse error spans several lines pointing into the pattern, its last one
+            // says what's wrong.
+            let err = err.to_string();
+            let reason = err.lines().last().unwrap_or_default();
+
+            ShellError::labeled_error(
+                "Invalid regular expression",
+                reason.trim_start_matches("error: "),
+                &find.tag,
+            )
+        })?;
+    let options = FindReplace {
+        regex,
+        replacement: replace.item,
+        all,
+    };
 
     let column_paths: Vec<_> = rest;
 
@@ -115,17 +170,15 @@ fn action(input: &Value, options: &FindReplace, tag: impl Into<Tag>) -> Result<V
     match &input.value {
         UntaggedValue::Primitive(Primitive::Line(s))
         | UntaggedValue::Primitive(Primitive::String(s)) => {
-            let find = &options.0;
-            let replacement = &options.1;
+            let replacement = options.replacement.as_str();
 
-            let regex = Regex::new(find.as_str());
-
-            let out = match regex {
-                Ok(re) => UntaggedValue::string(re.replace(s, replacement.as_str()).to_owned()),
-                Err(_) => UntaggedValue::string(s),
+            let out = if options.all {
+                options.regex.replace_all(s, replacement)
+            } else {
+                options.regex.replace(s, replacement)
             };
 
-            Ok(out.into_value(tag))
+            Ok(UntaggedValue::string(out).into_value(tag))
         }
         other => {
             let got = format!("got {}", other.type_name());
@@ -143,6 +196,7 @@ mod tests {
     use super::{action, FindReplace, SubCommand};
     use nu_plugin::test_helpers::value::string;
     use nu_source::Tag;
+    use regex::Regex;
 
     #[test]
     fn examples_work_as_expected() {
@@ -156,9 +210,30 @@ mod tests {
         let word = string("Cargo.toml");
         let expected = string("Carga.toml");
 
-        let find_replace_options = FindReplace("Cargo.(.+)".to_string(), "Carga.$1".to_string());
+        let find_replace_options = FindReplace {
+            regex: Regex::new("Cargo.(.+)").unwrap(),
+            replacement: "Carga.$1".to_string(),
+            all: false,
+        };
 
         let actual = action(&word, &find_replace_options, Tag::unknown()).unwrap();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn replaces_only_the_first_match_unless_all() {
+        let word = string("a-b-c");
+        let mut options = FindReplace {
+            regex: Regex::new("-").unwrap(),
+            replacement: "+".to_string(),
+            all: false,
+        };
+
+        let actual = action(&word, &options, Tag::unknown()).unwrap();
+        assert_eq!(actual, string("a+b-c"));
+
+        options.all = true;
+        let actual = action(&word, &options, Tag::unknown()).unwrap();
+        assert_eq!(actual, string("a+b+c"));
+    }
 }
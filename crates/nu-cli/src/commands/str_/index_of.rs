@@ -0,0 +1,158 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    pattern: Tagged<String>,
+    rest: Vec<ColumnPath>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str index-of"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str index-of")
+            .required(
+                "pattern",
+                SyntaxShape::String,
+                "the pattern to find the index of",
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally find the index of a pattern in text by column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "returns the index of the first occurrence of a pattern in text, or -1"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Find the index of a pattern",
+                example: "echo 'my_library.rb' | str index-of '.rb'",
+                result: Some(vec![UntaggedValue::int(10).into()]),
+            },
+            Example {
+                description: "Get -1 when the pattern isn't found",
+                example: "echo 'my_library.rb' | str index-of '.nu'",
+                result: Some(vec![UntaggedValue::int(-1).into()]),
+            },
+        ]
+    }
+}
+
+async fn operate(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let (Arguments { pattern, rest }, input) = args.process(&registry).await?;
+    let options = pattern.item;
+
+    let column_paths: Vec<_> = rest;
+
+    Ok(input
+        .map(move |v| {
+            if column_paths.is_empty() {
+                match action(&v, &options, v.tag()) {
+                    Ok(out) => ReturnSuccess::value(out),
+                    Err(err) => Err(err),
+                }
+            } else {
+                let mut ret = v;
+
+                for path in &column_paths {
+                    let options = options.clone();
+
+                    let swapping = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, &options, old.tag())),
+                    );
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            return Err(err);
+                        }
+                    }
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn action(input: &Value, pattern: &str, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => {
+            // Indexes count characters, not bytes
+            let index = match s.find(pattern) {
+                Some(byte_index) => s[..byte_index].chars().count() as i64,
+                None => -1,
+            };
+
+            Ok(UntaggedValue::int(index).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not string",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, SubCommand};
+    use nu_plugin::test_helpers::value::{int, string};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn finds_the_character_index_of_a_pattern() {
+        let actual = action(&string("Cargo.toml"), ".", Tag::unknown()).unwrap();
+        assert_eq!(actual, int(5));
+
+        let actual = action(&string("ñu.nu"), "nu", Tag::unknown()).unwrap();
+        assert_eq!(actual, int(3));
+
+        let actual = action(&string("Cargo.toml"), "lock", Tag::unknown()).unwrap();
+        assert_eq!(actual, int(-1));
+    }
+}
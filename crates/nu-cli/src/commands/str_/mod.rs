@@ -1,8 +1,17 @@
 mod capitalize;
+mod case;
 mod command;
+mod contains;
+mod distance;
 mod downcase;
+mod ends_with;
 mod find_replace;
+mod index_of;
+mod length;
+mod padding;
 mod set;
+mod similarity;
+mod starts_with;
 mod substring;
 mod to_datetime;
 mod to_decimal;
@@ -11,10 +20,22 @@ mod trim;
 mod upcase;
 
 pub use capitalize::SubCommand as StrCapitalize;
+pub use case::{
+    CamelCase as StrCamelCase, KebabCase as StrKebabCase, PascalCase as StrPascalCase,
+    SnakeCase as StrSnakeCase, TitleCase as StrTitleCase,
+};
 pub use command::Command as Str;
+pub use contains::SubCommand as StrContains;
+pub use distance::SubCommand as StrDistance;
 pub use downcase::SubCommand as StrDowncase;
+pub use ends_with::SubCommand as StrEndsWith;
 pub use find_replace::SubCommand as StrFindReplace;
+pub use index_of::SubCommand as StrIndexOf;
+pub use length::SubCommand as StrLength;
+pub use padding::{LeftPad as StrLeftPad, Pad as StrPad, RightPad as StrRightPad};
 pub use set::SubCommand as StrSet;
+pub use similarity::SubCommand as StrSimilarity;
+pub use starts_with::SubCommand as StrStartsWith;
 pub use substring::SubCommand as StrSubstring;
 pub use to_datetime::SubCommand as StrToDatetime;
 pub use to_decimal::SubCommand as StrToDecimal;
@@ -0,0 +1,73 @@
+use super::{operate, Side};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str lpad"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str lpad")
+            .required_named(
+                "length",
+                SyntaxShape::Int,
+                "the length to pad the text to",
+                Some('l'),
+            )
+            .named(
+                "character",
+                SyntaxShape::String,
+                "the character to pad with (default: a space)",
+                Some('c'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally pad text by column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "pads text on the left to a given length"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry, Side::Left).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Pad a number with zeros",
+                example: "echo 42 | str lpad --length 5 --character 0",
+                result: Some(vec![Value::from("00042")]),
+            },
+            Example {
+                description: "Right-align text in 8 characters",
+                example: "echo 'nu' | str lpad -l 8",
+                result: Some(vec![Value::from("      nu")]),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
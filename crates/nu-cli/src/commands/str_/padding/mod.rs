@@ -0,0 +1,173 @@
+pub mod lpad;
+pub mod pad;
+pub mod rpad;
+
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{ColumnPath, Primitive, ReturnSuccess, UntaggedValue, Value};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+
+pub use lpad::SubCommand as LeftPad;
+pub use pad::SubCommand as Pad;
+pub use rpad::SubCommand as RightPad;
+
+#[derive(Deserialize)]
+struct Arguments {
+    length: Tagged<u64>,
+    character: Option<Tagged<String>>,
+    rest: Vec<ColumnPath>,
+}
+
+/// Where the fill characters go.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Side {
+    Left,
+    Right,
+    Both,
+}
+
+#[derive(Clone)]
+struct Padding {
+    length: usize,
+    character: char,
+    side: Side,
+}
+
+pub async fn operate(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+    side: Side,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let (
+        Arguments {
+            length,
+            character,
+            rest,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    let character = match character {
+        None => ' ',
+        Some(character) => {
+            let mut chars = character.item.chars();
+
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => c,
+                _ => {
+                    return Err(ShellError::labeled_error(
+                        "Expected a single fill character",
+                        "expected a single character",
+                        &character.tag,
+                    ))
+                }
+            }
+        }
+    };
+
+    let options = Padding {
+        length: length.item as usize,
+        character,
+        side,
+    };
+
+    let column_paths: Vec<_> = rest;
+
+    Ok(input
+        .map(move |v| {
+            if column_paths.is_empty() {
+                match action(&v, &options, v.tag()) {
+                    Ok(out) => ReturnSuccess::value(out),
+                    Err(err) => Err(err),
+                }
+            } else {
+                let mut ret = v;
+
+                for path in &column_paths {
+                    let options = options.clone();
+
+                    let swapping = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, &options, old.tag())),
+                    );
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            return Err(err);
+                        }
+                    }
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn action(input: &Value, options: &Padding, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => {
+            Ok(
+                UntaggedValue::string(pad(s, options.length, options.character, options.side))
+                    .into_value(tag),
+            )
+        }
+        UntaggedValue::Primitive(Primitive::Int(i)) => Ok(UntaggedValue::string(pad(
+            &i.to_string(),
+            options.length,
+            options.character,
+            options.side,
+        ))
+        .into_value(tag)),
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not string",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+/// Pads text with the character until it's `length` characters long. Text that's already as
+/// long is left as it is. When padding both sides, the extra character goes on the right.
+pub fn pad(text: &str, length: usize, character: char, side: Side) -> String {
+    let missing = length.saturating_sub(text.chars().count());
+    let (left, right) = match side {
+        Side::Left => (missing, 0),
+        Side::Right => (0, missing),
+        Side::Both => (missing / 2, missing - missing / 2),
+    };
+
+    let fill = |count| character.to_string().repeat(count);
+
+    format!("{}{}{}", fill(left), text, fill(right))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pad, Side};
+
+    #[test]
+    fn pads_to_the_length() {
+        assert_eq!(pad("42", 5, '0', Side::Left), "00042");
+        assert_eq!(pad("nu", 5, '.', Side::Right), "nu...");
+        assert_eq!(pad("nu", 5, '*', Side::Both), "*nu**");
+        assert_eq!(pad("ñu", 3, ' ', Side::Left), " ñu");
+    }
+
+    #[test]
+    fn leaves_longer_text_as_it_is() {
+        assert_eq!(pad("nushell", 3, '0', Side::Left), "nushell");
+        assert_eq!(pad("nushell", 7, '0', Side::Both), "nushell");
+    }
+}
@@ -0,0 +1,73 @@
+use super::{operate, Side};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str pad"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str pad")
+            .required_named(
+                "length",
+                SyntaxShape::Int,
+                "the length to pad the text to",
+                Some('l'),
+            )
+            .named(
+                "character",
+                SyntaxShape::String,
+                "the character to pad with (default: a space)",
+                Some('c'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally pad text by column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "pads text on both sides to a given length"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry, Side::Both).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Center text with stars",
+                example: "echo 'nu' | str pad --length 6 --character '*'",
+                result: Some(vec![Value::from("**nu**")]),
+            },
+            Example {
+                description: "Center text in 5 characters, putting the extra space on the right",
+                example: "echo 'nu' | str pad -l 5",
+                result: Some(vec![Value::from(" nu  ")]),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
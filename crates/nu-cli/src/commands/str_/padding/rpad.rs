@@ -0,0 +1,73 @@
+use super::{operate, Side};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str rpad"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str rpad")
+            .required_named(
+                "length",
+                SyntaxShape::Int,
+                "the length to pad the text to",
+                Some('l'),
+            )
+            .named(
+                "character",
+                SyntaxShape::String,
+                "the character to pad with (default: a space)",
+                Some('c'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally pad text by column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "pads text on the right to a given length"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry, Side::Right).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Pad text with dots",
+                example: "echo 'nu' | str rpad --length 5 --character .",
+                result: Some(vec![Value::from("nu...")]),
+            },
+            Example {
+                description: "Left-align text in 8 characters",
+                example: "echo 'nu' | str rpad -l 8",
+                result: Some(vec![Value::from("nu      ")]),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,193 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+
+use super::distance::levenshtein;
+
+#[derive(Deserialize)]
+struct Arguments {
+    compare_to: Tagged<String>,
+    rest: Vec<ColumnPath>,
+    insensitive: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str similarity"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str similarity")
+            .required("compare-to", SyntaxShape::String, "the text to compare to")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally score the similarity by column paths",
+            )
+            .switch(
+                "insensitive",
+                "compare the text case-insensitively",
+                Some('i'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "scores how similar text is to another string, from 0 to 1"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get how similar two strings are",
+                example: "echo 'nushell' | str similarity 'nutshell'",
+                result: Some(vec![UntaggedValue::decimal(
+                    BigDecimal::from(7) / BigDecimal::from(8),
+                )
+                .into()]),
+            },
+            Example {
+                description: "Get how similar two strings are ignoring case",
+                example: "echo 'NuShell' | str similarity -i 'nushell'",
+                result: Some(vec![UntaggedValue::decimal(1).into()]),
+            },
+        ]
+    }
+}
+
+#[derive(Clone)]
+struct Compare {
+    compare_to: String,
+    insensitive: bool,
+}
+
+async fn operate(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let (
+        Arguments {
+            compare_to,
+            rest,
+            insensitive,
+        },
+        input,
+    ) = args.process(&registry).await?;
+    let options = Compare {
+        compare_to: compare_to.item,
+        insensitive,
+    };
+
+    let column_paths: Vec<_> = rest;
+
+    Ok(input
+        .map(move |v| {
+            if column_paths.is_empty() {
+                match action(&v, &options, v.tag()) {
+                    Ok(out) => ReturnSuccess::value(out),
+                    Err(err) => Err(err),
+                }
+            } else {
+                let mut ret = v;
+
+                for path in &column_paths {
+                    let options = options.clone();
+
+                    let swapping = ret.swap_data_by_column_path(
+                        path,
+                        Box::new(move |old| action(old, &options, old.tag())),
+                    );
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            return Err(err);
+                        }
+                    }
+                }
+
+                ReturnSuccess::value(ret)
+            }
+        })
+        .to_output_stream())
+}
+
+fn action(input: &Value, options: &Compare, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => {
+            let similarity = if options.insensitive {
+                similarity(&s.to_lowercase(), &options.compare_to.to_lowercase())
+            } else {
+                similarity(s, &options.compare_to)
+            };
+
+            Ok(UntaggedValue::decimal(similarity).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not string",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+/// How similar two strings are, from 0 (nothing in common) to 1 (the same): one minus their
+/// edit distance over the length of the longest one.
+pub fn similarity(left: &str, right: &str) -> BigDecimal {
+    let longest = left.chars().count().max(right.chars().count());
+
+    if longest == 0 {
+        return BigDecimal::from(1);
+    }
+
+    let distance = levenshtein(left, right);
+
+    BigDecimal::from((longest - distance) as u64) / BigDecimal::from(longest as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{similarity, SubCommand};
+    use bigdecimal::BigDecimal;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn scores_similarity_from_zero_to_one() {
+        assert_eq!(similarity("nu", "nu"), BigDecimal::from(1));
+        assert_eq!(similarity("", ""), BigDecimal::from(1));
+        assert_eq!(similarity("ab", "cd"), BigDecimal::from(0));
+        assert_eq!(
+            similarity("nush", "nash"),
+            BigDecimal::from(3) / BigDecimal::from(4)
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::commands::date_to_timezone::Zone;
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use nu_errors::ShellError;
@@ -8,11 +9,12 @@ use nu_protocol::{
 use nu_source::{Tag, Tagged};
 use nu_value_ext::ValueExt;
 
-use chrono::DateTime;
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 
 #[derive(Deserialize)]
 struct Arguments {
     format: Option<Tagged<String>>,
+    timezone: Option<Tagged<String>>,
     rest: Vec<ColumnPath>,
 }
 
@@ -32,6 +34,12 @@ impl WholeStreamCommand for SubCommand {
                 "Specify date and time formatting",
                 Some('f'),
             )
+            .named(
+                "timezone",
+                SyntaxShape::String,
+                "the timezone of text without an offset, like Europe/Paris, UTC or +02:00",
+                Some('z'),
+            )
             .rest(
                 SyntaxShape::ColumnPath,
                 "optionally convert text into datetime by column paths",
@@ -51,16 +59,30 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Convert to datetime",
-            example: "echo '16.11.1984 8:00 am +0000' | str to-datetime",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Convert to datetime",
+                example: "echo '16.11.1984 8:00 am +0000' | str to-datetime",
+                result: None,
+            },
+            Example {
+                description: "Convert local times in Paris to datetime",
+                example:
+                    "echo '2020-06-01 14:00' | str to-datetime -f '%Y-%m-%d %H:%M' -z Europe/Paris",
+                result: Some(vec![UntaggedValue::date(
+                    Utc.ymd(2020, 6, 1).and_hms(12, 0, 0),
+                )
+                .into()]),
+            },
+        ]
     }
 }
 
 #[derive(Clone)]
-struct DatetimeFormat(String);
+struct DatetimeFormat {
+    format: String,
+    zone: Option<Zone>,
+}
 
 async fn operate(
     args: CommandArgs,
@@ -68,14 +90,29 @@ async fn operate(
 ) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
 
-    let (Arguments { format, rest }, input) = args.process(&registry).await?;
+    let (
+        Arguments {
+            format,
+            timezone,
+            rest,
+        },
+        input,
+    ) = args.process(&registry).await?;
 
     let column_paths: Vec<_> = rest;
 
+    let zone = match timezone {
+        Some(timezone) => Some(Zone::parse(&timezone)?),
+        None => None,
+    };
+
     let options = if let Some(Tagged { item: fmt, .. }) = format {
-        DatetimeFormat(fmt)
+        DatetimeFormat { format: fmt, zone }
     } else {
-        DatetimeFormat(String::from("%d.%m.%Y %H:%M %P %z"))
+        DatetimeFormat {
+            format: String::from("%d.%m.%Y %H:%M %P %z"),
+            zone,
+        }
     };
 
     Ok(input
@@ -119,11 +156,20 @@ fn action(
     match &input.value {
         UntaggedValue::Primitive(Primitive::Line(s))
         | UntaggedValue::Primitive(Primitive::String(s)) => {
-            let dt = &options.0;
-
-            let out = match DateTime::parse_from_str(s, dt) {
-                Ok(d) => UntaggedValue::date(d),
-                Err(_) => UntaggedValue::string(s),
+            let dt = &options.format;
+
+            let date = DateTime::parse_from_str(s, dt)
+                .map(|d| d.into())
+                .ok()
+                .or_else(|| {
+                    let zone = options.zone?;
+                    let naive = NaiveDateTime::parse_from_str(s, dt).ok()?;
+                    zone.local_to_utc(&naive)
+                });
+
+            let out = match date {
+                Some(d) => UntaggedValue::date(d),
+                None => UntaggedValue::string(s),
             };
 
             Ok(out.into_value(tag))
@@ -141,10 +187,11 @@ fn action(
 
 #[cfg(test)]
 mod tests {
-    use super::{action, DatetimeFormat, SubCommand};
+    use super::{action, DatetimeFormat, SubCommand, Zone};
+    use chrono::{TimeZone, Utc};
     use nu_plugin::test_helpers::value::string;
     use nu_protocol::{Primitive, UntaggedValue};
-    use nu_source::Tag;
+    use nu_source::{Tag, TaggedItem};
 
     #[test]
     fn examples_work_as_expected() {
@@ -157,7 +204,10 @@ mod tests {
     fn takes_a_date_format() {
         let date_str = string("16.11.1984 8:00 am +0000");
 
-        let fmt_options = DatetimeFormat("%d.%m.%Y %H:%M %P %z".to_string());
+        let fmt_options = DatetimeFormat {
+            format: "%d.%m.%Y %H:%M %P %z".to_string(),
+            zone: None,
+        };
 
         let actual = action(&date_str, &fmt_options, Tag::unknown()).unwrap();
 
@@ -166,4 +216,20 @@ mod tests {
             _ => panic!("Didn't convert to date"),
         }
     }
+    #[test]
+    fn reads_text_without_an_offset_in_the_given_timezone() {
+        let date_str = string("2020-01-15 09:30");
+
+        let fmt_options = DatetimeFormat {
+            format: "%Y-%m-%d %H:%M".to_string(),
+            zone: Zone::parse(&"-05:00".to_string().tagged_unknown()).ok(),
+        };
+
+        let actual = action(&date_str, &fmt_options, Tag::unknown()).unwrap();
+
+        assert_eq!(
+            actual.value,
+            UntaggedValue::date(Utc.ymd(2020, 1, 15).and_hms(14, 30, 0))
+        );
+    }
 }
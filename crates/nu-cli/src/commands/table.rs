@@ -1,12 +1,14 @@
 use crate::commands::WholeStreamCommand;
 use crate::format::TableView;
 use crate::prelude::*;
+use futures::future::{self, Either, FutureExt};
 use nu_errors::ShellError;
 use nu_protocol::{Primitive, Signature, SyntaxShape, UntaggedValue, Value};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 const STREAM_PAGE_SIZE: usize = 1000;
 const STREAM_TIMEOUT_CHECK_INTERVAL: usize = 100;
+const STREAM_TIMEOUT: Duration = Duration::from_secs(1);
 
 pub struct Table;
 
@@ -73,7 +75,23 @@ async fn table(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputSt
                 new_input.push_back(val);
                 delay_slot = None;
             } else {
-                match args.input.next().await {
+                let next = match args.input.next().now_or_never() {
+                    Some(next) => next,
+                    // Nothing is ready yet, as when an external command is still running, so
+                    // what's buffered is shown if the wait takes the page over a second
+                    None if !new_input.is_empty() => {
+                        let wait = STREAM_TIMEOUT
+                            .checked_sub(start_time.elapsed())
+                            .unwrap_or_default();
+                        match future::select(args.input.next(), crate::futures::delay(wait)).await {
+                            Either::Left((next, _)) => next,
+                            Either::Right(_) => break,
+                        }
+                    }
+                    None => args.input.next().await,
+                };
+
+                match next {
                     Some(a) => {
                         if !new_input.is_empty() {
                             if let Some(descs) = new_input.get(0) {
@@ -103,7 +121,7 @@ async fn table(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputSt
                     let end_time = Instant::now();
 
                     // If we've been buffering over a second, go ahead and send out what we have so far
-                    if (end_time - start_time).as_secs() >= 1 {
+                    if end_time - start_time >= STREAM_TIMEOUT {
                         break;
                     }
                 }
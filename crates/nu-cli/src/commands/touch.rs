@@ -1,16 +1,20 @@
+use crate::commands::date_range::to_date;
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, SyntaxShape};
+use nu_protocol::{Signature, SyntaxShape, Value};
 use nu_source::Tagged;
-use std::fs::OpenOptions;
+use std::fs::{FileTimes, OpenOptions};
 use std::path::PathBuf;
+use std::time::SystemTime;
 
 pub struct Touch;
 
 #[derive(Deserialize)]
 pub struct TouchArgs {
     pub target: Tagged<PathBuf>,
+    pub modified: Option<Value>,
+    pub accessed: Option<Value>,
 }
 
 #[async_trait]
@@ -19,14 +23,27 @@ impl WholeStreamCommand for Touch {
         "touch"
     }
     fn signature(&self) -> Signature {
-        Signature::build("touch").required(
-            "filename",
-            SyntaxShape::Path,
-            "the path of the file you want to create",
-        )
+        Signature::build("touch")
+            .required(
+                "filename",
+                SyntaxShape::Path,
+                "the path of the file you want to create",
+            )
+            .named(
+                "modified",
+                SyntaxShape::Any,
+                "the date to set as the last modification time",
+                Some('m'),
+            )
+            .named(
+                "accessed",
+                SyntaxShape::Any,
+                "the date to set as the last access time",
+                Some('a'),
+            )
     }
     fn usage(&self) -> &str {
-        "creates a file"
+        "creates a file, or sets the times of an existing one"
     }
     async fn run(
         &self,
@@ -37,26 +54,75 @@ impl WholeStreamCommand for Touch {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Creates \"fixture.json\"",
-            example: "touch fixture.json",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Creates \"fixture.json\"",
+                example: "touch fixture.json",
+                result: None,
+            },
+            Example {
+                description: "Sets the modification time of \"fixture.json\" to the start of 2020",
+                example: "touch fixture.json --modified 2020-01-01",
+                result: None,
+            },
+            Example {
+                description: "Gives \"copy.json\" the modification time of \"fixture.json\"",
+                example: "touch copy.json --modified (ls fixture.json | get modified)",
+                result: None,
+            },
+        ]
     }
 }
 
 async fn touch(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
-    let (TouchArgs { target }, _) = args.process(&registry).await?;
+    let (
+        TouchArgs {
+            target,
+            modified,
+            accessed,
+        },
+        _,
+    ) = args.process(&registry).await?;
 
-    match OpenOptions::new().write(true).create(true).open(&target) {
-        Ok(_) => Ok(OutputStream::empty()),
-        Err(err) => Err(ShellError::labeled_error(
-            "File Error",
-            err.to_string(),
-            &target.tag,
-        )),
-    }
+    let to_time = |value: Option<Value>| -> Result<Option<SystemTime>, ShellError> {
+        match value {
+            Some(value) => Ok(Some(to_date(&value)?.into())),
+            None => Ok(None),
+        }
+    };
+
+    let modified = to_time(modified)?;
+    let accessed = to_time(accessed)?;
+
+    // Without any dates, both times are set to now, like `touch` does elsewhere.
+    let times = match (modified, accessed) {
+        (None, None) => {
+            let now = SystemTime::now();
+            FileTimes::new().set_modified(now).set_accessed(now)
+        }
+        (modified, accessed) => {
+            let times = FileTimes::new();
+            let times = match modified {
+                Some(modified) => times.set_modified(modified),
+                None => times,
+            };
+            match accessed {
+                Some(accessed) => times.set_accessed(accessed),
+                None => times,
+            }
+        }
+    };
+
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(&target)
+        .and_then(|file| file.set_times(times))
+        .map_err(|err| ShellError::labeled_error("File Error", err.to_string(), &target.tag))?;
+
+    Ok(OutputStream::empty())
 }
 
 #[cfg(test)]
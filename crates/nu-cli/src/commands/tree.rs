@@ -0,0 +1,310 @@
+use crate::commands::group_by::suggestions;
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::data::value::format_leaf;
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use nu_value_ext::as_string;
+
+pub struct Tree;
+
+#[derive(Deserialize)]
+pub struct TreeArgs {
+    path: Option<Tagged<String>>,
+    depth: Option<Tagged<u64>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Tree {
+    fn name(&self) -> &str {
+        "tree"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("tree")
+            .named(
+                "path",
+                SyntaxShape::String,
+                "build the tree from the paths in the given column instead",
+                Some('p'),
+            )
+            .named(
+                "depth",
+                SyntaxShape::Int,
+                "the number of levels to show (default: all of them)",
+                Some('d'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Renders nested data, or the paths of a column, as an indented tree."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        tree(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the structure of a JSON file",
+                example: "open package.json | tree",
+                result: None,
+            },
+            Example {
+                description: "Show the files below the current directory as a tree",
+                example: "ls **/* | tree --path name",
+                result: None,
+            },
+            Example {
+                description: "Show a list as a tree",
+                example: "echo [a b] | tree",
+                result: Some(vec![Value::from("├── a\n└── b\n")]),
+            },
+        ]
+    }
+}
+
+/// A line of the tree and the lines nested under it.
+#[derive(Debug, Default, PartialEq)]
+struct Node {
+    label: String,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn leaf(label: impl Into<String>) -> Node {
+        Node {
+            label: label.into(),
+            children: vec![],
+        }
+    }
+}
+
+async fn tree(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (TreeArgs { path, depth }, input) = args.process(&registry).await?;
+
+    let values: Vec<Value> = input.collect().await;
+
+    let nodes = match &path {
+        Some(column) => nodes_from_paths(&values, column)?,
+        None if values.len() == 1 => nodes_from_value(&values[0]),
+        None => nodes_from_table(&values),
+    };
+
+    let mut lines = vec![];
+    render(
+        &nodes,
+        "",
+        depth.map(|d| d.item as usize).unwrap_or(usize::MAX),
+        &mut lines,
+    );
+
+    let output: String = lines.iter().map(|line| format!("{}\n", line)).collect();
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::string(output).into_value(name),
+    )))
+}
+
+fn is_leaf(value: &Value) -> bool {
+    match &value.value {
+        UntaggedValue::Row(_) => false,
+        UntaggedValue::Table(values) => values.is_empty(),
+        _ => true,
+    }
+}
+
+fn leaf_label(value: &Value) -> String {
+    format_leaf(&value.value).plain_string(100_000)
+}
+
+fn nodes_from_value(value: &Value) -> Vec<Node> {
+    match &value.value {
+        UntaggedValue::Row(dict) => dict
+            .entries
+            .iter()
+            .map(|(key, value)| {
+                if is_leaf(value) {
+                    Node::leaf(format!("{}: {}", key, leaf_label(value)))
+                } else {
+                    Node {
+                        label: key.clone(),
+                        children: nodes_from_value(value),
+                    }
+                }
+            })
+            .collect(),
+        UntaggedValue::Table(values) => nodes_from_table(values),
+        _ => vec![Node::leaf(leaf_label(value))],
+    }
+}
+
+fn nodes_from_table(values: &[Value]) -> Vec<Node> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(idx, value)| {
+            if is_leaf(value) {
+                Node::leaf(leaf_label(value))
+            } else {
+                Node {
+                    label: idx.to_string(),
+                    children: nodes_from_value(value),
+                }
+            }
+        })
+        .collect()
+}
+
+fn nodes_from_paths(rows: &[Value], column: &Tagged<String>) -> Result<Vec<Node>, ShellError> {
+    #[derive(Default)]
+    struct Trie(IndexMap<String, Trie>);
+
+    fn into_nodes(trie: Trie) -> Vec<Node> {
+        trie.0
+            .into_iter()
+            .map(|(label, children)| Node {
+                label,
+                children: into_nodes(children),
+            })
+            .collect()
+    }
+
+    let mut root = Trie::default();
+
+    for row in rows {
+        let path = match row.get_data_by_key(column.borrow_spanned()) {
+            Some(path) => as_string(&path)?,
+            None => return Err(suggestions(column.borrow_tagged(), row)),
+        };
+
+        let mut current = &mut root;
+        for component in path.split(&['/', '\\'][..]) {
+            if component.is_empty() || component == "." {
+                continue;
+            }
+            current = current.0.entry(component.to_string()).or_default();
+        }
+    }
+
+    Ok(into_nodes(root))
+}
+
+fn render(nodes: &[Node], prefix: &str, depth: usize, lines: &mut Vec<String>) {
+    if depth == 0 {
+        return;
+    }
+
+    for (idx, node) in nodes.iter().enumerate() {
+        let last = idx == nodes.len() - 1;
+        let (branch, indent) = if last {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+
+        lines.push(format!("{}{}{}", prefix, branch, node.label));
+        render(
+            &node.children,
+            &format!("{}{}", prefix, indent),
+            depth - 1,
+            lines,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{nodes_from_paths, nodes_from_value, render, Tree};
+    use indexmap::IndexMap;
+    use nu_errors::ShellError;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::*;
+
+    fn string(input: impl Into<String>) -> Value {
+        UntaggedValue::string(input.into()).into_untagged_value()
+    }
+
+    fn int(n: i64) -> Value {
+        UntaggedValue::int(n).into_untagged_value()
+    }
+
+    fn row(entries: IndexMap<String, Value>) -> Value {
+        UntaggedValue::row(entries).into_untagged_value()
+    }
+
+    fn table(list: &[Value]) -> Value {
+        UntaggedValue::table(list).into_untagged_value()
+    }
+
+    #[test]
+    fn renders_nested_rows_and_tables() {
+        let value = row(indexmap! {
+            "name".into() => string("nu"),
+            "pkg".into() => row(indexmap! {
+                "version".into() => string("0.16.0"),
+                "authors".into() => table(&[string("AR"), string("JT")]),
+            }),
+            "size".into() => int(3),
+        });
+
+        let mut lines = vec![];
+        render(&nodes_from_value(&value), "", usize::MAX, &mut lines);
+
+        assert_eq!(
+            lines,
+            vec![
+                "├── name: nu",
+                "├── pkg",
+                "│   ├── version: 0.16.0",
+                "│   └── authors",
+                "│       ├── AR",
+                "│       └── JT",
+                "└── size: 3",
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_paths_up_to_the_given_depth() -> Result<(), ShellError> {
+        let rows = vec![
+            row(indexmap! {"name".into() => string("src/commands/tree.rs")}),
+            row(indexmap! {"name".into() => string("src/cli.rs")}),
+            row(indexmap! {"name".into() => string("Cargo.toml")}),
+        ];
+
+        let nodes = nodes_from_paths(&rows, &String::from("name").tagged_unknown())?;
+
+        let mut lines = vec![];
+        render(&nodes, "", 2, &mut lines);
+
+        assert_eq!(
+            lines,
+            vec![
+                "├── src",
+                "│   ├── commands",
+                "│   └── cli.rs",
+                "└── Cargo.toml"
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Tree {})
+    }
+}
@@ -0,0 +1,269 @@
+use crate::commands::classified::block::{run_block, run_block_with_params};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    hir::Block, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
+};
+use nu_source::{HasFallibleSpan, Span, Tagged};
+
+pub struct Try;
+
+#[derive(Deserialize)]
+pub struct TryArgs {
+    block: Block,
+    catch: Option<Tagged<String>>,
+    handler: Option<Block>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Try {
+    fn name(&self) -> &str {
+        "try"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("try")
+            .required("block", SyntaxShape::Block, "the block to run")
+            .optional(
+                "catch",
+                SyntaxShape::String,
+                "the word catch, before the block to run if the first one fails",
+            )
+            .optional(
+                "handler",
+                SyntaxShape::Block,
+                "the block to run with the error, as $err, if the first one fails",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        r#"Run a block, and if it fails, run another with the error instead of stopping. The error is given to the catch block as $err, a row with the message, label, span and exit code of the error. Without a catch block, the error is ignored."#
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        try_(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Give a default when a file can't be opened",
+                example: "try { open settings.toml } catch { echo [] }",
+                result: None,
+            },
+            Example {
+                description: "Give the message of the error",
+                example: "try { open no_such_file.txt } catch { echo $err.message }",
+                result: None,
+            },
+            Example {
+                description: "Ignore the error of an external command",
+                example: "try { ^grep needle haystack.txt }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn try_(
+    raw_args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = raw_args.call_info.name_tag.clone();
+    let scope = raw_args.call_info.scope.clone();
+    let mut context = Context::from_raw(&raw_args, &registry);
+    let (
+        TryArgs {
+            block,
+            catch,
+            handler,
+        },
+        input,
+    ) = raw_args.process(&registry).await?;
+
+    let handler = match (catch, handler) {
+        (Some(catch), Some(handler)) if catch.item == "catch" => Some(handler),
+        (None, None) => None,
+        (catch, _) => {
+            return Err(ShellError::labeled_error(
+                "Expected catch and a block",
+                "expected the word catch, then the block to run if the first one fails",
+                catch.map_or(name, |catch| catch.tag),
+            ))
+        }
+    };
+
+    // The output is held until the block ends, as it can still fail after giving some values.
+    let result = match run_block(
+        &block,
+        &mut context,
+        input,
+        &scope.it,
+        &scope.vars,
+        &scope.env,
+    )
+    .await
+    {
+        Ok(mut output) => {
+            let values = output.drain_vec().await;
+            let error = values.iter().find_map(|value| match &value.value {
+                UntaggedValue::Error(err) => Some(err.clone()),
+                _ => None,
+            });
+
+            match error.or_else(|| context.get_errors().first().cloned()) {
+                Some(err) => Err(err),
+                None => Ok(values),
+            }
+        }
+        Err(err) => Err(err),
+    };
+
+    let err = match result {
+        Ok(values) => {
+            return Ok(
+                futures::stream::iter(values.into_iter().map(ReturnSuccess::value))
+                    .to_output_stream(),
+            )
+        }
+        Err(err) => err,
+    };
+
+    context.clear_errors();
+
+    // A break or continue is on its way to the loop it is in, it isn't a failure to handle
+    if err.as_loop_control().is_some() {
+        return Err(err);
+    }
+
+    let handler = match handler {
+        Some(handler) => handler,
+        None => return Ok(OutputStream::empty()),
+    };
+
+    let err = error_to_value(&err, &name);
+    let mut params = IndexMap::new();
+    params.insert("$err".to_string(), err.clone());
+
+    let for_block = err.clone();
+    let input = futures::stream::once(async { Ok(for_block) }).to_input_stream();
+
+    Ok(run_block_with_params(
+        &handler,
+        &mut context,
+        input,
+        &err,
+        &scope.vars,
+        &params,
+        &scope.env,
+    )
+    .await?
+    .to_output_stream())
+}
+
+/// The error as a row, with its message, label, span and the exit code of the external command
+/// that failed, if it was one.
+fn error_to_value(err: &ShellError, tag: &Tag) -> Value {
+    let (message, label, span) = match err.clone().into_diagnostic() {
+        Some(diagnostic) => {
+            let label = diagnostic.labels.into_iter().next();
+            let span = label
+                .as_ref()
+                .map(|label| Span::new(label.range.start, label.range.end))
+                .or_else(|| err.maybe_span());
+
+            (diagnostic.message, label.map(|label| label.message), span)
+        }
+        None if err.exit_code().is_some() => (
+            "External command failed".to_string(),
+            Some("command failed".to_string()),
+            None,
+        ),
+        None => ("Error".to_string(), None, err.maybe_span()),
+    };
+
+    let mut dict = TaggedDictBuilder::new(tag);
+    dict.insert_untagged("message", UntaggedValue::string(message));
+    dict.insert_untagged(
+        "label",
+        label.map_or_else(UntaggedValue::nothing, UntaggedValue::string),
+    );
+
+    match span {
+        Some(span) => {
+            let mut span_dict = TaggedDictBuilder::new(tag);
+            span_dict.insert_untagged("start", UntaggedValue::int(span.start()));
+            span_dict.insert_untagged("end", UntaggedValue::int(span.end()));
+            dict.insert_value("span", span_dict.into_value());
+        }
+        None => dict.insert_untagged("span", UntaggedValue::nothing()),
+    }
+
+    dict.insert_untagged(
+        "exit_code",
+        err.exit_code()
+            .map_or_else(UntaggedValue::nothing, UntaggedValue::int),
+    );
+
+    dict.into_value()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{error_to_value, Span, Try};
+    use nu_errors::ShellError;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::{SpannedItem, Tag};
+    use nu_value_ext::get_data_by_key;
+
+    fn column(row: &Value, name: &str) -> UntaggedValue {
+        get_data_by_key(row, name.spanned_unknown())
+            .expect("missing column")
+            .value
+    }
+
+    #[test]
+    fn gives_errors_as_rows() {
+        let err = ShellError::labeled_error("Cannot open", "file not found", Span::new(5, 9));
+        let row = error_to_value(&err, &Tag::unknown());
+
+        assert_eq!(
+            column(&row, "message"),
+            UntaggedValue::string("Cannot open")
+        );
+        assert_eq!(
+            column(&row, "label"),
+            UntaggedValue::string("file not found")
+        );
+
+        let span = get_data_by_key(&row, "span".spanned_unknown()).expect("missing span");
+        assert_eq!(column(&span, "start"), UntaggedValue::int(5));
+        assert_eq!(column(&span, "end"), UntaggedValue::int(9));
+        assert_eq!(column(&row, "exit_code"), UntaggedValue::nothing());
+    }
+
+    #[test]
+    fn gives_the_exit_code_of_externals() {
+        let row = error_to_value(&ShellError::external_non_zero(Some(2)), &Tag::unknown());
+
+        assert_eq!(
+            column(&row, "message"),
+            UntaggedValue::string("External command failed")
+        );
+        assert_eq!(column(&row, "exit_code"), UntaggedValue::int(2));
+        assert_eq!(column(&row, "span"), UntaggedValue::nothing());
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Try {})
+    }
+}
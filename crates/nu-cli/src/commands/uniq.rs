@@ -1,12 +1,24 @@
+use crate::commands::group_by::suggestions;
 use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
-use indexmap::set::IndexSet;
+use indexmap::map::IndexMap;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature};
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tagged;
+use nu_value_ext::as_string;
 
 pub struct Uniq;
 
+#[derive(Deserialize)]
+pub struct UniqArgs {
+    count: bool,
+    by: Option<Value>,
+    repeated: bool,
+    #[serde(rename(deserialize = "unique-only"))]
+    unique_only: bool,
+}
+
 #[async_trait]
 impl WholeStreamCommand for Uniq {
     fn name(&self) -> &str {
@@ -15,6 +27,27 @@ impl WholeStreamCommand for Uniq {
 
     fn signature(&self) -> Signature {
         Signature::build("uniq")
+            .switch(
+                "count",
+                "add a column with the number of occurrences of each row",
+                Some('c'),
+            )
+            .named(
+                "by",
+                SyntaxShape::Any,
+                "the column (or table of columns) that make a row unique",
+                Some('b'),
+            )
+            .switch(
+                "repeated",
+                "only return the rows that occur more than once",
+                Some('d'),
+            )
+            .switch(
+                "unique-only",
+                "only return the rows that occur exactly once",
+                Some('u'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -28,22 +61,136 @@ impl WholeStreamCommand for Uniq {
     ) -> Result<OutputStream, ShellError> {
         uniq(args, registry).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Remove duplicate values",
+                example: "echo [1 2 2 3] | uniq",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                ]),
+            },
+            Example {
+                description: "Only keep the values that appear more than once",
+                example: "echo [1 2 2 3] | uniq --repeated",
+                result: Some(vec![UntaggedValue::int(2).into()]),
+            },
+            Example {
+                description: "Count the files of each type",
+                example: "ls | uniq --by type --count",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn uniq(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (
+        UniqArgs {
+            count,
+            by,
+            repeated,
+            unique_only,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    if repeated && unique_only {
+        return Err(ShellError::labeled_error(
+            "Conflicting filters",
+            "only one of --repeated or --unique-only can be given",
+            name,
+        ));
+    }
+
+    let by: Vec<Tagged<String>> = match by {
+        None => vec![],
+        Some(Value {
+            value: UntaggedValue::Table(columns),
+            ..
+        }) => columns
+            .iter()
+            .map(|column| Ok(as_string(column)?.tagged(&column.tag)))
+            .collect::<Result<Vec<_>, ShellError>>()?,
+        Some(column) => vec![as_string(&column)?.tagged(&column.tag)],
+    };
+
+    let values: Vec<Value> = input.collect().await;
+    let occurrences = count_occurrences(values, &by)?;
+
+    let uniq_values = occurrences
+        .into_iter()
+        .filter(|(_, (_, n))| (!repeated || *n > 1) && (!unique_only || *n == 1))
+        .map(|(_, (value, n))| {
+            if count {
+                with_count(value, n, &name).map(ReturnSuccess::Value)
+            } else {
+                ReturnSuccess::value(value)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(futures::stream::iter(uniq_values).to_output_stream())
 }
 
-async fn uniq(args: CommandArgs, _registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
-    let input = args.input;
-    let uniq_values: IndexSet<_> = input.collect().await;
+/// Groups the values by the given columns (or by the whole value when no columns are given),
+/// keeping the first value of each group along with the size of the group. Tags are ignored
+/// when comparing values.
+fn count_occurrences(
+    values: Vec<Value>,
+    by: &[Tagged<String>],
+) -> Result<IndexMap<Vec<UntaggedValue>, (Value, usize)>, ShellError> {
+    let mut occurrences: IndexMap<Vec<UntaggedValue>, (Value, usize)> = IndexMap::new();
 
-    let mut values_vec_deque = VecDeque::new();
+    for value in values {
+        let key = if by.is_empty() {
+            vec![value.value.clone()]
+        } else {
+            by.iter()
+                .map(
+                    |column| match value.get_data_by_key(column.borrow_spanned()) {
+                        Some(cell) => Ok(cell.value),
+                        None => Err(suggestions(column.borrow_tagged(), &value)),
+                    },
+                )
+                .collect::<Result<Vec<_>, _>>()?
+        };
 
-    for item in uniq_values
-        .iter()
-        .map(|row| ReturnSuccess::value(row.clone()))
-    {
-        values_vec_deque.push_back(item);
+        occurrences.entry(key).or_insert((value, 0)).1 += 1;
     }
 
-    Ok(futures::stream::iter(values_vec_deque).to_output_stream())
+    Ok(occurrences)
+}
+
+fn with_count(value: Value, count: usize, tag: &Tag) -> Result<Value, ShellError> {
+    match value.value {
+        UntaggedValue::Row(mut dict) => {
+            if dict.entries.contains_key("count") {
+                return Err(ShellError::labeled_error(
+                    "Column count already exists",
+                    "the rows already have a count column, rename it before counting",
+                    tag,
+                ));
+            }
+
+            dict.entries.insert(
+                "count".to_string(),
+                UntaggedValue::int(count).into_value(tag),
+            );
+            Ok(UntaggedValue::Row(dict).into_value(value.tag))
+        }
+        _ => {
+            let mut out = TaggedDictBuilder::new(tag);
+            out.insert_value("value", value);
+            out.insert_untagged("count", UntaggedValue::int(count));
+            Ok(out.into_value())
+        }
+    }
 }
 
 #[cfg(test)]
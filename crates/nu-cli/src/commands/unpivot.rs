@@ -0,0 +1,201 @@
+use crate::commands::group_by::suggestions;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct Unpivot;
+
+#[derive(Deserialize)]
+pub struct UnpivotArgs {
+    rest: Vec<Tagged<String>>,
+    #[serde(rename(deserialize = "key-name"))]
+    key_name: Option<Tagged<String>>,
+    #[serde(rename(deserialize = "value-name"))]
+    value_name: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Unpivot {
+    fn name(&self) -> &str {
+        "unpivot"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("unpivot")
+            .rest(
+                SyntaxShape::String,
+                "the column(s) to keep as they are in every produced row",
+            )
+            .named(
+                "key-name",
+                SyntaxShape::String,
+                "the name of the column holding the former column names (default: column)",
+                Some('k'),
+            )
+            .named(
+                "value-name",
+                SyntaxShape::String,
+                "the name of the column holding the former cells (default: value)",
+                Some('v'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Reshapes a wide table into a long one, with a row per cell of the columns not kept."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        unpivot(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Turn a column per host back into a row per date and host",
+            example: "open cpu_by_host.csv | unpivot date --key-name host --value-name cpu",
+            result: None,
+        }]
+    }
+}
+
+pub async fn unpivot(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (
+        UnpivotArgs {
+            rest: keep,
+            key_name,
+            value_name,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    let key_name = key_name
+        .map(|k| k.item)
+        .unwrap_or_else(|| "column".to_string());
+    let value_name = value_name
+        .map(|v| v.item)
+        .unwrap_or_else(|| "value".to_string());
+
+    Ok(input
+        .map(move |row| {
+            let rows = unpivot_row(&row, &keep, &key_name, &value_name, &name);
+            futures::stream::iter(match rows {
+                Ok(rows) => rows.into_iter().map(ReturnSuccess::value).collect(),
+                Err(err) => vec![Err(err)],
+            })
+        })
+        .flatten()
+        .to_output_stream())
+}
+
+fn unpivot_row(
+    row: &Value,
+    keep: &[Tagged<String>],
+    key_name: &str,
+    value_name: &str,
+    tag: &Tag,
+) -> Result<Vec<Value>, ShellError> {
+    let entries = match &row.value {
+        UntaggedValue::Row(dict) => &dict.entries,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected table rows",
+                "requires table input",
+                &row.tag,
+            ))
+        }
+    };
+
+    let kept = keep
+        .iter()
+        .map(|column| {
+            row.get_data_by_key(column.borrow_spanned())
+                .map(|value| (column.item.clone(), value))
+                .ok_or_else(|| suggestions(column.borrow_tagged(), row))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(entries
+        .iter()
+        .filter(|(column, _)| !keep.iter().any(|k| &k.item == *column))
+        .map(|(column, value)| {
+            let mut dict = TaggedDictBuilder::new(tag);
+
+            for (kept_column, kept_value) in &kept {
+                dict.insert_value(kept_column.clone(), kept_value.clone());
+            }
+
+            dict.insert_untagged(key_name, UntaggedValue::string(column));
+            dict.insert_value(value_name, value.clone());
+            dict.into_value()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{unpivot_row, Unpivot};
+    use indexmap::IndexMap;
+    use nu_errors::ShellError;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::*;
+
+    fn string(input: impl Into<String>) -> Value {
+        UntaggedValue::string(input.into()).into_untagged_value()
+    }
+
+    fn int(n: i64) -> Value {
+        UntaggedValue::int(n).into_untagged_value()
+    }
+
+    fn row(entries: IndexMap<String, Value>) -> Value {
+        UntaggedValue::row(entries).into_untagged_value()
+    }
+
+    #[test]
+    fn turns_columns_into_rows() -> Result<(), ShellError> {
+        let wide = row(indexmap! {
+            "date".into() => string("2020-06-01"),
+            "web".into() => int(10),
+            "db".into() => int(50),
+        });
+
+        let actual = unpivot_row(
+            &wide,
+            &[String::from("date").tagged_unknown()],
+            "host",
+            "cpu",
+            &Tag::unknown(),
+        )?;
+
+        assert_eq!(
+            actual,
+            vec![
+                row(
+                    indexmap! {"date".into() => string("2020-06-01"), "host".into() => string("web"), "cpu".into() => int(10)}
+                ),
+                row(
+                    indexmap! {"date".into() => string("2020-06-01"), "host".into() => string("db"), "cpu".into() => int(50)}
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Unpivot {})
+    }
+}
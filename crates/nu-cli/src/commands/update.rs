@@ -31,7 +31,7 @@ impl WholeStreamCommand for Update {
             .required(
                 "replacement value",
                 SyntaxShape::Any,
-                "the new value to give the cell(s)",
+                "the new value to give the cell(s), or a block run for each row to compute it",
             )
     }
 
@@ -46,6 +46,21 @@ impl WholeStreamCommand for Update {
     ) -> Result<OutputStream, ShellError> {
         update(args, registry).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Set the same value in every row",
+                example: "ls | update modified neverrrr",
+                result: None,
+            },
+            Example {
+                description: "Compute the new value from each row",
+                example: "ls | update size { = $it.size * 2 }",
+                result: None,
+            },
+        ]
+    }
 }
 
 async fn process_row(
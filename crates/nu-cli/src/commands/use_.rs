@@ -0,0 +1,175 @@
+use crate::cli::print_err_in;
+use crate::commands::module::{self, Exports};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Scope, Signature, SyntaxShape};
+use nu_source::{Tagged, Text};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+pub struct Use;
+
+#[derive(Deserialize)]
+pub struct UseArgs {
+    module: Tagged<String>,
+    rest: Vec<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Use {
+    fn name(&self) -> &str {
+        "use"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("use")
+            .required(
+                "module",
+                SyntaxShape::String,
+                "the name of the module, or the path to a file to use as one",
+            )
+            .rest(
+                SyntaxShape::String,
+                "the commands to use from the module (all of them if none are given)",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Use the commands of a module without their module name in front, and set its environment variables."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        use_(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Use all the commands of a module",
+                example: "use greetings",
+                result: None,
+            },
+            Example {
+                description: "Use one command of a module",
+                example: "use greetings hello",
+                result: None,
+            },
+            Example {
+                description: "Use a file as a module, named after the file",
+                example: "use greetings.nu",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn use_(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let mut context = Context::from_args(&args, &registry.scoped());
+    let scope = args.call_info.scope.clone();
+    let cwd = PathBuf::from(args.shell_manager.path());
+
+    let (UseArgs { module, rest }, _) = args.process(&registry).await?;
+
+    let exports = match registry.get_module(&module.item) {
+        Some(exports) => exports,
+        None => load(&module, &cwd, &mut context, &scope, &registry).await?,
+    };
+
+    let mut registry = registry.clone();
+    let names: Vec<Tagged<String>> = if rest.is_empty() {
+        exports
+            .commands
+            .keys()
+            .map(|name| name.clone().tagged(&module.tag))
+            .collect()
+    } else {
+        rest
+    };
+
+    for name in names {
+        match exports.export(&name.item, &name.item) {
+            Some(command) => registry.insert(name.item, command),
+            None => {
+                return Err(ShellError::labeled_error(
+                    "Could not use the command",
+                    format!("{} has no command named {}", module.item, name.item),
+                    &name.tag,
+                ))
+            }
+        }
+    }
+
+    context.with_host(|host| {
+        for (name, value) in &exports.env {
+            host.env_set(OsString::from(name), OsString::from(value));
+        }
+    });
+
+    Ok(OutputStream::empty())
+}
+
+/// Uses the file at `path` as a module, named after the file without its extension.
+async fn load(
+    path: &Tagged<String>,
+    cwd: &Path,
+    context: &mut Context,
+    scope: &Scope,
+    registry: &CommandRegistry,
+) -> Result<Exports, ShellError> {
+    let file = cwd.join(&path.item);
+    let file = if file.is_file() {
+        file
+    } else {
+        file.with_extension("nu")
+    };
+
+    let source = std::fs::read_to_string(&file).map_err(|_| {
+        ShellError::labeled_error(
+            "Could not use the module",
+            "no module or file with this name",
+            &path.tag,
+        )
+    })?;
+
+    let name = file
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.item.clone());
+
+    context.raw_input = source.clone();
+    let exports = match module::evaluate(&source, 0, file.parent(), context, scope).await {
+        Ok(exports) => exports,
+        Err(err) => {
+            print_err_in(err, &file.display().to_string(), &Text::from(source));
+
+            return Err(ShellError::labeled_error(
+                "Could not use the module",
+                "there is an error in this file",
+                &path.tag,
+            ));
+        }
+    };
+
+    exports.register(&name, registry);
+    registry.clone().add_module(name, exports.clone());
+
+    Ok(exports)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Use;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Use {})
+    }
+}
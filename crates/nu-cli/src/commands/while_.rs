@@ -0,0 +1,100 @@
+use crate::commands::loop_::{output, Body};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{hir::Block, Signature, SyntaxShape};
+
+pub struct While;
+
+#[derive(Deserialize)]
+pub struct WhileArgs {
+    condition: Block,
+    block: Block,
+}
+
+#[async_trait]
+impl WholeStreamCommand for While {
+    fn name(&self) -> &str {
+        "while"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("while")
+            .required(
+                "condition",
+                SyntaxShape::Math,
+                "the condition that must hold for the block to run again",
+            )
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the block to run while the condition holds",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Run a block for as long as a condition holds, checking it before each pass. The output of each pass is given in turn."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        while_(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Skip the block, as the condition doesn't hold",
+                example: "let n = 0; while $n > 0 { echo never }",
+                result: Some(vec![]),
+            },
+            Example {
+                description: "Wait for a file to be made",
+                example: "while $(ls | where name == ready.txt | count) == 0 { ^sleep 1 }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn while_(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let context = Context::from_args(&args, &registry);
+    let scope = args.call_info.scope.clone();
+
+    let (WhileArgs { condition, block }, _) = args.process(&registry).await?;
+
+    let body = Body::new(block, context, scope);
+    let passes = futures::stream::unfold(Some(body), move |body| {
+        let condition = condition.clone();
+
+        async move {
+            let mut body = body?;
+            match body.test(&condition).await {
+                Ok(true) => {}
+                Ok(false) => return None,
+                Err(err) => return Some((Err(err), None)),
+            }
+
+            let (output, body) = body.pass(IndexMap::new()).await;
+            Some((output, body))
+        }
+    });
+
+    Ok(output(passes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::While;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(While {})
+    }
+}
@@ -0,0 +1,127 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct Window;
+
+#[derive(Deserialize)]
+pub struct WindowArgs {
+    size: Tagged<usize>,
+    stride: Option<Tagged<usize>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Window {
+    fn name(&self) -> &str {
+        "window"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("window")
+            .required(
+                "size",
+                SyntaxShape::Int,
+                "the number of rows in each window",
+            )
+            .named(
+                "stride",
+                SyntaxShape::Int,
+                "the number of rows to move forward between windows (default: 1)",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Creates a table of each (possibly overlapping) window of rows as they stream by."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        window(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Sliding windows of two rows",
+                example: "echo [1 2 3] | window 2",
+                result: Some(vec![
+                    UntaggedValue::Table(vec![
+                        UntaggedValue::int(1).into(),
+                        UntaggedValue::int(2).into(),
+                    ])
+                    .into(),
+                    UntaggedValue::Table(vec![
+                        UntaggedValue::int(2).into(),
+                        UntaggedValue::int(3).into(),
+                    ])
+                    .into(),
+                ]),
+            },
+            Example {
+                description: "Moving average of the last three values",
+                example: "echo [1 2 3 4 5] | window 3 | each { echo $it | math average }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn window(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (WindowArgs { size, stride }, input) = args.process(&registry).await?;
+
+    let stride = stride.map(|s| *s).unwrap_or(1);
+
+    if *size == 0 || stride == 0 {
+        return Err(ShellError::labeled_error(
+            "Expected a positive number",
+            "the size and stride of windows can't be zero",
+            name,
+        ));
+    }
+
+    let size = *size;
+    let mut seen = 0;
+    let mut buffer: VecDeque<Value> = VecDeque::new();
+
+    Ok(input
+        .filter_map(move |item| {
+            buffer.push_back(item);
+            if buffer.len() > size {
+                buffer.pop_front();
+            }
+            seen += 1;
+
+            let window = if seen >= size && (seen - size) % stride == 0 {
+                let rows: Vec<Value> = buffer.iter().cloned().collect();
+                Some(ReturnSuccess::value(
+                    UntaggedValue::table(&rows).into_value(&name),
+                ))
+            } else {
+                None
+            };
+
+            futures::future::ready(window)
+        })
+        .to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Window;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Window {})
+    }
+}
@@ -2,14 +2,13 @@ use crate::commands::classified::block::run_block;
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{hir::Block, Signature, SyntaxShape, Value};
-use nu_source::Tagged;
+use nu_protocol::{hir::Block, Signature, SyntaxShape, UntaggedValue, Value};
 
 pub struct WithEnv;
 
 #[derive(Deserialize, Debug)]
 struct WithEnvArgs {
-    variable: (Tagged<String>, Tagged<String>),
+    variable: Value,
     block: Block,
 }
 
@@ -24,7 +23,7 @@ impl WholeStreamCommand for WithEnv {
             .required(
                 "variable",
                 SyntaxShape::Any,
-                "the environment variable to temporarily set",
+                "the environment variables to temporarily set, as a row or as pairs of names and values",
             )
             .required(
                 "block",
@@ -34,7 +33,7 @@ impl WholeStreamCommand for WithEnv {
     }
 
     fn usage(&self) -> &str {
-        "Runs a block with an environment set. Eg) with-env [NAME 'foo'] { echo $nu.env.NAME }"
+        "Runs a block with an environment set. Eg) with-env [NAME 'foo'] { echo $nu.env.NAME }. The variables are only set for the block, and can also be set for a single command with NAME=foo before it."
     }
 
     async fn run(
@@ -46,11 +45,28 @@ impl WholeStreamCommand for WithEnv {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Set the MYENV environment variable",
-            example: r#"with-env [MYENV "my env value"] { echo $nu.env.MYENV }"#,
-            result: Some(vec![Value::from("my env value")]),
-        }]
+        vec![
+            Example {
+                description: "Set the MYENV environment variable",
+                example: r#"with-env [MYENV "my env value"] { echo $nu.env.MYENV }"#,
+                result: Some(vec![Value::from("my env value")]),
+            },
+            Example {
+                description: "Set several environment variables",
+                example: r#"with-env [X 1 Y 2] { echo $nu.env.X $nu.env.Y }"#,
+                result: Some(vec![Value::from("1"), Value::from("2")]),
+            },
+            Example {
+                description: "Set the environment variables from a row",
+                example: r#"with-env $(echo '{"X": "Y"}' | from json) { echo $nu.env.X }"#,
+                result: Some(vec![Value::from("Y")]),
+            },
+            Example {
+                description: "Set an environment variable for a single command",
+                example: r#"GREETING=hello ^sh -c 'echo $GREETING'"#,
+                result: None,
+            },
+        ]
     }
 }
 
@@ -64,7 +80,9 @@ async fn with_env(
     let mut scope = raw_args.call_info.scope.clone();
     let (WithEnvArgs { variable, block }, input) = raw_args.process(&registry).await?;
 
-    scope.env.insert(variable.0.item, variable.1.item);
+    for (name, value) in env_variables(&variable)? {
+        scope.env.insert(name, value);
+    }
 
     let result = run_block(
         &block,
@@ -79,6 +97,38 @@ async fn with_env(
     result.map(|x| x.to_output_stream())
 }
 
+/// The names and values to set, from a row of them, or from a list of names each followed by its
+/// value.
+pub(crate) fn env_variables(variable: &Value) -> Result<Vec<(String, String)>, ShellError> {
+    match &variable.value {
+        UntaggedValue::Row(row) => Ok(row
+            .entries
+            .iter()
+            .map(|(name, value)| (name.clone(), value.convert_to_string()))
+            .collect()),
+        UntaggedValue::Table(values) => match &values[..] {
+            [row @ Value {
+                value: UntaggedValue::Row(_),
+                ..
+            }] => env_variables(row),
+            _ if values.len() % 2 == 0 => values
+                .chunks(2)
+                .map(|pair| Ok((pair[0].as_string()?, pair[1].convert_to_string())))
+                .collect(),
+            _ => Err(ShellError::labeled_error(
+                "Expected pairs of names and values",
+                "the last name has no value",
+                &variable.tag,
+            )),
+        },
+        _ => Err(ShellError::labeled_error(
+            "Expected the variables to set",
+            "expected a row, or pairs of names and values",
+            &variable.tag,
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::WithEnv;
@@ -0,0 +1,142 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::visits::{self, Visit};
+use crate::prelude::*;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use nu_errors::ShellError;
+use nu_protocol::{
+    CommandAction, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
+};
+use nu_source::Tagged;
+use std::str::FromStr;
+
+pub struct Z;
+
+#[derive(Deserialize)]
+pub struct ZArgs {
+    rest: Vec<Tagged<String>>,
+    list: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Z {
+    fn name(&self) -> &str {
+        "z"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("z")
+            .rest(
+                SyntaxShape::String,
+                "words the directory has in its path, in order",
+            )
+            .switch(
+                "list",
+                "give the matching directories as a table, instead of changing to one",
+                Some('l'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Change to the directory most often and most recently visited that matches the words. Nu counts the directories the lines at the prompt go into."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        z(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Change to the directory visited the most that has proj in its path",
+                example: "z proj",
+                result: None,
+            },
+            Example {
+                description:
+                    "Change to the directory visited the most that has src after nu in its path",
+                example: "z nu src",
+                result: None,
+            },
+            Example {
+                description: "Show the visited directories, the ones z prefers first",
+                example: "z --list",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn z(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (ZArgs { rest, list }, _) = args.process(&registry).await?;
+
+    let words: Vec<String> = rest.iter().map(|word| word.item.clone()).collect();
+    let now = Utc::now();
+    let ranked = visits::ranked(visits::read(&visits::path()), &words, now);
+
+    if list || words.is_empty() {
+        let rows: Vec<Value> = ranked
+            .into_iter()
+            .map(|visit| visit_to_value(visit, now, &name))
+            .collect();
+
+        return Ok(
+            futures::stream::iter(rows.into_iter().map(ReturnSuccess::value)).to_output_stream(),
+        );
+    }
+
+    match ranked.into_iter().next() {
+        Some(visit) => Ok(OutputStream::one(ReturnSuccess::action(
+            CommandAction::ChangePath(visit.path),
+        ))),
+        None => {
+            let span = rest
+                .iter()
+                .map(|word| word.tag.span)
+                .fold(rest[0].tag.span, |span, word| span.until(word));
+
+            Err(ShellError::labeled_error(
+                "No directory to change to",
+                "no visited directory matches these words",
+                span,
+            ))
+        }
+    }
+}
+
+fn visit_to_value(visit: Visit, now: DateTime<Utc>, tag: &Tag) -> Value {
+    let mut dict = TaggedDictBuilder::new(tag);
+
+    let frecency = visit.frecency(now);
+    dict.insert_untagged("path", UntaggedValue::path(visit.path));
+    dict.insert_untagged("visits", decimal(visit.visits));
+    dict.insert_untagged("last_visit", UntaggedValue::date(visit.last_visit));
+    dict.insert_untagged("frecency", decimal(frecency));
+
+    dict.into_value()
+}
+
+/// The number to two decimal places, as the visits are aged by fractions of them.
+fn decimal(number: f64) -> UntaggedValue {
+    BigDecimal::from_str(&format!("{:.2}", number))
+        .map(UntaggedValue::decimal)
+        .unwrap_or_else(|_| UntaggedValue::nothing())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Z;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Z {})
+    }
+}
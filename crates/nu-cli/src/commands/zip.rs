@@ -0,0 +1,113 @@
+use crate::commands::classified::block::run_block;
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+
+pub struct Zip;
+
+#[derive(Deserialize)]
+pub struct ZipArgs {
+    other: Value,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Zip {
+    fn name(&self) -> &str {
+        "zip"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("zip").required(
+            "other",
+            SyntaxShape::Any,
+            "the table (or the block to run to get the table) to pair the rows with",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Pairs each row with the row at the same position of another table, stopping at the end of the shortest."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        zip(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Pair the values of two lists",
+                example: "echo [1 2 3] | zip { echo [a b c] }",
+                result: None,
+            },
+            Example {
+                description: "Add up the values of two lists",
+                example:
+                    "echo [1 2 3] | zip { echo [10 20 30] } | each { = $it.Column1 + $it.Column2 }",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn zip(
+    raw_args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let scope = raw_args.call_info.scope.clone();
+    let mut context = Context::from_raw(&raw_args, &registry);
+    let name = raw_args.call_info.name_tag.clone();
+    let (ZipArgs { other }, input) = raw_args.process(&registry).await?;
+
+    let other: Vec<Value> = match other.value {
+        UntaggedValue::Block(block) => {
+            let rows = run_block(
+                &block,
+                &mut context,
+                InputStream::empty(),
+                &scope.it,
+                &scope.vars,
+                &scope.env,
+            )
+            .await?
+            .drain_vec()
+            .await;
+
+            if let Some(err) = context.get_errors().first() {
+                return Err(err.clone());
+            }
+
+            rows
+        }
+        UntaggedValue::Table(rows) => rows,
+        _ => vec![other],
+    };
+
+    Ok(input
+        .zip(futures::stream::iter(other))
+        .map(move |(left, right)| {
+            let mut pair = TaggedDictBuilder::new(&name);
+            pair.insert_value("Column1", left);
+            pair.insert_value("Column2", right);
+            ReturnSuccess::value(pair.into_value())
+        })
+        .to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Zip;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Zip {})
+    }
+}
@@ -1,3 +1,4 @@
+use crate::commands::module::Exports;
 use crate::commands::{command::CommandArgs, Command, UnevaluatedCallInfo};
 use crate::env::host::Host;
 use crate::shell::shell_manager::ShellManager;
@@ -5,26 +6,78 @@ use crate::stream::{InputStream, OutputStream};
 use indexmap::IndexMap;
 use nu_errors::ShellError;
 use nu_parser::SignatureRegistry;
-use nu_protocol::{hir, Scope, Signature};
+use nu_protocol::{hir, Scope, Signature, Value};
 use nu_source::{Tag, Text};
 use parking_lot::Mutex;
+use std::collections::HashSet;
 use std::error::Error;
+use std::path::PathBuf;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
+/// Names that are still accepted, but forwarded to the commands and flags that replaced them.
+#[derive(Debug, Default)]
+struct Deprecations {
+    commands: IndexMap<String, String>,
+    flags: IndexMap<String, IndexMap<String, String>>,
+    warned: HashSet<String>,
+}
+
+impl Deprecations {
+    /// Returns the warning for `key`, but only the first time it is asked for.
+    fn warn_once(&mut self, key: String, warning: String) -> Option<String> {
+        if self.warned.insert(key) {
+            Some(warning)
+        } else {
+            None
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct CommandRegistry {
     registry: Arc<Mutex<IndexMap<String, Command>>>,
+    deprecations: Arc<Mutex<Deprecations>>,
+    modules: Arc<Mutex<IndexMap<String, Exports>>>,
+    /// The values of the variables made with `mut`, shared so that a block run by a command,
+    /// like the body of a loop, can set them for the block they were made in.
+    mutables: Arc<Mutex<Mutables>>,
+}
+
+/// The values of the variables made with `mut`, by slot. Slots are freed once the block that
+/// made them is done, and never given out again, so a stale slot can't see another variable.
+#[derive(Debug, Default)]
+struct Mutables {
+    next: usize,
+    values: IndexMap<usize, Value>,
 }
 
 impl SignatureRegistry for CommandRegistry {
     fn has(&self, name: &str) -> bool {
+        let name = self.resolve(name);
         let registry = self.registry.lock();
-        registry.contains_key(name)
+        registry.contains_key(&name)
     }
     fn get(&self, name: &str) -> Option<Signature> {
+        let name = self.resolve(name);
         let registry = self.registry.lock();
-        registry.get(name).map(|command| command.signature())
+        let mut signature = registry.get(&name).map(|command| command.signature())?;
+
+        if let Some(flags) = self.deprecations.lock().flags.get(&name) {
+            for (old, replacement) in flags {
+                if let Some((named_type, _)) = signature.named.get(replacement).cloned() {
+                    signature.named.insert(
+                        old.clone(),
+                        (
+                            named_type,
+                            format!("deprecated, use --{} instead", replacement),
+                        ),
+                    );
+                }
+            }
+        }
+
+        Some(signature)
     }
     fn clone_box(&self) -> Box<dyn SignatureRegistry> {
         Box::new(self.clone())
@@ -35,15 +88,19 @@ impl CommandRegistry {
     pub fn new() -> CommandRegistry {
         CommandRegistry {
             registry: Arc::new(Mutex::new(IndexMap::default())),
+            deprecations: Arc::new(Mutex::new(Deprecations::default())),
+            modules: Arc::new(Mutex::new(IndexMap::default())),
+            mutables: Arc::new(Mutex::new(Mutables::default())),
         }
     }
 }
 
 impl CommandRegistry {
     pub(crate) fn get_command(&self, name: &str) -> Option<Command> {
+        let name = self.resolve(name);
         let registry = self.registry.lock();
 
-        registry.get(name).cloned()
+        registry.get(&name).cloned()
     }
 
     pub(crate) fn expect_command(&self, name: &str) -> Result<Command, ShellError> {
@@ -53,9 +110,10 @@ impl CommandRegistry {
     }
 
     pub(crate) fn has(&self, name: &str) -> bool {
+        let name = self.resolve(name);
         let registry = self.registry.lock();
 
-        registry.contains_key(name)
+        registry.contains_key(&name)
     }
 
     pub(crate) fn insert(&mut self, name: impl Into<String>, command: Command) {
@@ -63,6 +121,164 @@ impl CommandRegistry {
         registry.insert(name.into(), command);
     }
 
+    /// A copy of the registry that commands can be added to without changing this one. It still
+    /// shares the deprecations, modules and variables made with `mut`.
+    pub(crate) fn scoped(&self) -> CommandRegistry {
+        CommandRegistry {
+            registry: Arc::new(Mutex::new(self.registry.lock().clone())),
+            deprecations: self.deprecations.clone(),
+            modules: self.modules.clone(),
+            mutables: self.mutables.clone(),
+        }
+    }
+
+    /// Keeps the value of a variable made with `mut`, giving the slot it is kept in.
+    pub(crate) fn make_mutable(&self, value: Value) -> usize {
+        let mut mutables = self.mutables.lock();
+        let slot = mutables.next;
+        mutables.next += 1;
+        mutables.values.insert(slot, value);
+        slot
+    }
+
+    pub(crate) fn get_mutable(&self, slot: usize) -> Option<Value> {
+        let mutables = self.mutables.lock();
+        mutables.values.get(&slot).cloned()
+    }
+
+    pub(crate) fn set_mutable(&self, slot: usize, value: Value) {
+        let mut mutables = self.mutables.lock();
+        if let Some(mutable) = mutables.values.get_mut(&slot) {
+            *mutable = value;
+        }
+    }
+
+    /// Forgets the values of the variables made with `mut` in a block that is done.
+    pub(crate) fn free_mutables(&self, slots: &[usize]) {
+        let mut mutables = self.mutables.lock();
+        for slot in slots {
+            mutables.values.shift_remove(slot);
+        }
+    }
+
+    /// How many variables made with `mut` are kept.
+    #[cfg(test)]
+    pub(crate) fn mutables_len(&self) -> usize {
+        self.mutables.lock().values.len()
+    }
+
+    pub(crate) fn add_module(&mut self, name: impl Into<String>, module: Exports) {
+        let mut modules = self.modules.lock();
+        modules.insert(name.into(), module);
+    }
+
+    pub(crate) fn get_module(&self, name: &str) -> Option<Exports> {
+        let modules = self.modules.lock();
+        modules.get(name).cloned()
+    }
+
+    /// Marks `old` as a deprecated name for the `replacement` command. Calls to `old` keep
+    /// working (unless a command is registered under that name again) but warn once.
+    pub(crate) fn deprecate_command(
+        &mut self,
+        old: impl Into<String>,
+        replacement: impl Into<String>,
+    ) {
+        let mut deprecations = self.deprecations.lock();
+        deprecations.commands.insert(old.into(), replacement.into());
+    }
+
+    /// Marks the `--old` flag of `command` as a deprecated name for its `--replacement` flag.
+    #[allow(unused)]
+    pub(crate) fn deprecate_flag(
+        &mut self,
+        command: impl Into<String>,
+        old: impl Into<String>,
+        replacement: impl Into<String>,
+    ) {
+        let mut deprecations = self.deprecations.lock();
+        deprecations
+            .flags
+            .entry(command.into())
+            .or_default()
+            .insert(old.into(), replacement.into());
+    }
+
+    /// Returns the warning for a deprecated use of a command, but only the first time `key` is
+    /// warned about.
+    pub(crate) fn warn_deprecated(
+        &self,
+        key: impl Into<String>,
+        warning: String,
+    ) -> Option<String> {
+        self.warn_once(key, warning)
+    }
+
+    /// Returns `warning`, but only the first time `key` is warned about.
+    pub(crate) fn warn_once(&self, key: impl Into<String>, warning: String) -> Option<String> {
+        self.deprecations.lock().warn_once(key.into(), warning)
+    }
+
+    /// The name of the command that is actually run when `name` is called.
+    pub(crate) fn resolve(&self, name: &str) -> String {
+        if self.registry.lock().contains_key(name) {
+            return name.to_string();
+        }
+
+        match self.deprecations.lock().commands.get(name) {
+            Some(replacement) => replacement.clone(),
+            None => name.to_string(),
+        }
+    }
+
+    /// Rewrites a call using deprecated command or flag names to use their replacements,
+    /// returning the warnings that have not been shown yet.
+    pub(crate) fn forward_deprecated(&self, command: &mut hir::InternalCommand) -> Vec<String> {
+        let mut warnings = vec![];
+        let name = self.resolve(&command.name);
+        let mut deprecations = self.deprecations.lock();
+
+        if name != command.name {
+            let warning = format!(
+                "warning: `{}` is deprecated, use `{}` instead",
+                command.name, name
+            );
+
+            warnings.extend(deprecations.warn_once(command.name.clone(), warning));
+            command.name = name.clone();
+        }
+
+        let flags = match deprecations.flags.get(&name) {
+            Some(flags) => flags.clone(),
+            None => return warnings,
+        };
+
+        if let Some(named) = &mut command.args.named {
+            for (old, replacement) in flags {
+                let value = match named.named.shift_remove(&old) {
+                    Some(value) => value,
+                    None => continue,
+                };
+
+                match value {
+                    hir::NamedValue::AbsentSwitch | hir::NamedValue::AbsentValue => {}
+                    value => {
+                        let warning = format!(
+                            "warning: `{} --{}` is deprecated, use `{} --{}` instead",
+                            name, old, name, replacement
+                        );
+
+                        warnings
+                            .extend(deprecations.warn_once(format!("{} --{}", name, old), warning));
+                        named.named.insert(replacement, value);
+                    }
+                }
+            }
+        }
+
+        warnings
+    }
+
     pub(crate) fn names(&self) -> Vec<String> {
         let registry = self.registry.lock();
         registry.keys().cloned().collect()
@@ -76,6 +292,13 @@ pub struct Context {
     pub current_errors: Arc<Mutex<Vec<ShellError>>>,
     pub ctrl_c: Arc<AtomicBool>,
     pub raw_input: String,
+    /// The comment lines given just before the current one, which document a def on it.
+    pub(crate) doc_comments: Vec<String>,
+    /// The directory of the script being run, which the relative paths given to `source` in it
+    /// start from.
+    pub(crate) script_dir: Option<PathBuf>,
+    /// The variables set with `let` by the lines run so far.
+    pub(crate) vars: IndexMap<String, Value>,
     pub(crate) shell_manager: ShellManager,
 
     #[cfg(windows)]
@@ -98,6 +321,9 @@ impl Context {
                 shell_manager: raw_args.shell_manager.clone(),
                 windows_drives_previous_cwd: Arc::new(Mutex::new(std::collections::HashMap::new())),
                 raw_input: String::default(),
+                doc_comments: vec![],
+                script_dir: None,
+                vars: IndexMap::new(),
             }
         }
         #[cfg(not(windows))]
@@ -109,6 +335,9 @@ impl Context {
                 ctrl_c: raw_args.ctrl_c.clone(),
                 shell_manager: raw_args.shell_manager.clone(),
                 raw_input: String::default(),
+                doc_comments: vec![],
+                script_dir: None,
+                vars: IndexMap::new(),
             }
         }
     }
@@ -124,6 +353,9 @@ impl Context {
                 shell_manager: args.shell_manager.clone(),
                 windows_drives_previous_cwd: Arc::new(Mutex::new(std::collections::HashMap::new())),
                 raw_input: String::default(),
+                doc_comments: vec![],
+                script_dir: None,
+                vars: IndexMap::new(),
             }
         }
         #[cfg(not(windows))]
@@ -135,6 +367,9 @@ impl Context {
                 ctrl_c: args.ctrl_c.clone(),
                 shell_manager: args.shell_manager.clone(),
                 raw_input: String::default(),
+                doc_comments: vec![],
+                script_dir: None,
+                vars: IndexMap::new(),
             }
         }
     }
@@ -154,6 +389,9 @@ impl Context {
                 shell_manager: ShellManager::basic(registry)?,
                 windows_drives_previous_cwd: Arc::new(Mutex::new(std::collections::HashMap::new())),
                 raw_input: String::default(),
+                doc_comments: vec![],
+                script_dir: None,
+                vars: IndexMap::new(),
             })
         }
 
@@ -168,6 +406,9 @@ impl Context {
                 ctrl_c: Arc::new(AtomicBool::new(false)),
                 shell_manager: ShellManager::basic(registry)?,
                 raw_input: String::default(),
+                doc_comments: vec![],
+                script_dir: None,
+                vars: IndexMap::new(),
             })
         }
     }
@@ -271,3 +512,79 @@ impl Context {
         output
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::CommandRegistry;
+    use crate::commands::{whole_stream_command, First, Ls};
+    use nu_parser::SignatureRegistry;
+    use nu_protocol::hir::{InternalCommand, NamedArguments, NamedValue};
+    use nu_source::Span;
+
+    fn registry() -> CommandRegistry {
+        let mut registry = CommandRegistry::new();
+        registry.insert("first", whole_stream_command(First));
+        registry.insert("ls", whole_stream_command(Ls));
+        registry
+    }
+
+    #[test]
+    fn forwards_deprecated_command_names_and_warns_once() {
+        let mut registry = registry();
+        registry.deprecate_command("head", "first");
+
+        assert!(SignatureRegistry::has(&registry, "head"));
+        assert_eq!(
+            registry.get_command("head").map(|c| c.name().to_string()),
+            Some("first".to_string())
+        );
+
+        let mut call = InternalCommand::new("head".to_string(), Span::unknown(), Span::unknown());
+        let warnings = registry.forward_deprecated(&mut call);
+
+        assert_eq!(call.name, "first");
+        assert_eq!(warnings.len(), 1);
+
+        let mut call = InternalCommand::new("head".to_string(), Span::unknown(), Span::unknown());
+        assert!(registry.forward_deprecated(&mut call).is_empty());
+    }
+
+    #[test]
+    fn registered_commands_win_over_deprecated_names() {
+        let mut registry = registry();
+        registry.deprecate_command("ls", "first");
+
+        assert_eq!(registry.resolve("ls"), "ls");
+    }
+
+    #[test]
+    fn forwards_deprecated_flags() {
+        let mut registry = registry();
+        registry.deprecate_flag("ls", "long", "full");
+
+        let signature = SignatureRegistry::get(&registry, "ls").expect("ls is registered");
+        assert!(signature.named.contains_key("long"));
+
+        let mut named = NamedArguments::new();
+        named.named.insert(
+            "long".to_string(),
+            NamedValue::PresentSwitch(Span::unknown()),
+        );
+        named
+            .named
+            .insert("full".to_string(), NamedValue::AbsentSwitch);
+
+        let mut call = InternalCommand::new("ls".to_string(), Span::unknown(), Span::unknown());
+        call.args.named = Some(named);
+
+        let warnings = registry.forward_deprecated(&mut call);
+        let named = call.args.named.expect("named arguments");
+
+        assert_eq!(warnings.len(), 1);
+        assert!(named.get("long").is_none());
+        assert!(matches!(
+            named.get("full"),
+            Some(NamedValue::PresentSwitch(_))
+        ));
+    }
+}
@@ -3,9 +3,12 @@ pub(crate) mod command;
 pub(crate) mod config;
 pub(crate) mod dict;
 pub(crate) mod files;
+pub(crate) mod history;
 pub mod primitive;
+pub(crate) mod range;
 pub(crate) mod types;
 pub mod value;
+pub(crate) mod visits;
 
 pub(crate) use command::command_dict;
 pub(crate) use dict::TaggedListBuilder;
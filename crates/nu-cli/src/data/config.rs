@@ -14,11 +14,23 @@ use directories::ProjectDirs;
 use indexmap::IndexMap;
 use log::trace;
 use nu_errors::ShellError;
-use nu_protocol::{Dictionary, ShellTypeName, UntaggedValue, Value};
+use nu_protocol::{
+    ColumnPath, Dictionary, PathMember, ShellTypeName, UnspannedPathMember, UntaggedValue, Value,
+};
 use nu_source::Tag;
 use std::fs::{self, OpenOptions};
 use std::io;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether the config file is left alone, as set by `--no-config`.
+static CONFIG_DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// Leaves the config file alone for the rest of the run: it reads as empty, so no settings or
+/// startup commands are taken from it, and it can't be written to.
+pub fn disable_config() {
+    CONFIG_DISABLED.store(true, Ordering::SeqCst);
+}
 
 pub fn config_path() -> Result<PathBuf, ShellError> {
     app_path("config", ProjectDirs::config_dir)
@@ -28,6 +40,17 @@ pub fn default_path() -> Result<PathBuf, ShellError> {
     default_path_for(&None)
 }
 
+/// Fails if the config file is left alone, so it isn't changed.
+pub(crate) fn check_enabled() -> Result<(), ShellError> {
+    if CONFIG_DISABLED.load(Ordering::SeqCst) {
+        return Err(ShellError::untagged_runtime_error(
+            "The config can't be changed when nu is run with --no-config",
+        ));
+    }
+
+    Ok(())
+}
+
 pub fn default_path_for(file: &Option<PathBuf>) -> Result<PathBuf, ShellError> {
     let mut filename = config_path()?;
     let file: &Path = file
@@ -39,6 +62,30 @@ pub fn default_path_for(file: &Option<PathBuf>) -> Result<PathBuf, ShellError> {
     Ok(filename)
 }
 
+/// The startup scripts in the config directory, in the order they're run: `env.nu` for every
+/// run of nu, then `login.nu` for interactive sessions. Only the ones that exist are given.
+pub(crate) fn startup_scripts(interactive: bool) -> Vec<PathBuf> {
+    if CONFIG_DISABLED.load(Ordering::SeqCst) {
+        return vec![];
+    }
+
+    let dir = match config_path() {
+        Ok(dir) => dir,
+        Err(_) => return vec![],
+    };
+    let names: &[&str] = if interactive {
+        &["env.nu", "login.nu"]
+    } else {
+        &["env.nu"]
+    };
+
+    names
+        .iter()
+        .map(|name| dir.join(name))
+        .filter(|path| path.is_file())
+        .collect()
+}
+
 pub fn user_data() -> Result<PathBuf, ShellError> {
     app_path("user data", ProjectDirs::data_local_dir)
 }
@@ -58,10 +105,9 @@ pub fn read(
     tag: impl Into<Tag>,
     at: &Option<PathBuf>,
 ) -> Result<IndexMap<String, Value>, ShellError> {
-    let filename = default_path()?;
-
     let filename = match at {
-        None => filename,
+        None if CONFIG_DISABLED.load(Ordering::SeqCst) => return Ok(IndexMap::new()),
+        None => default_path()?,
         Some(ref file) => file.clone(),
     };
 
@@ -107,7 +153,89 @@ pub(crate) fn config(tag: impl Into<Tag>) -> Result<IndexMap<String, Value>, She
     read(tag, &None)
 }
 
+/// The number of decimal places to display decimals with, if `decimal_precision` is set.
+pub(crate) fn decimal_precision() -> Option<u64> {
+    let config = config(Tag::unknown()).ok()?;
+    config.get("decimal_precision")?.as_u64().ok()
+}
+
+/// The value at `path` in the config, like `color_config.header`.
+pub(crate) fn value_at(config: &IndexMap<String, Value>, path: &ColumnPath) -> Option<Value> {
+    let (first, rest) = path.members().split_first()?;
+    let mut current = config.get(&key(first))?.clone();
+
+    for member in rest {
+        current = current.get_data_by_member(member).ok()?;
+    }
+
+    Some(current)
+}
+
+/// Sets the value at `path` in the config, making the rows on the way to it that aren't there.
+pub(crate) fn set_value_at(
+    config: &mut IndexMap<String, Value>,
+    path: &ColumnPath,
+    value: Value,
+) -> Result<(), ShellError> {
+    let (last, front) = match path.split_last() {
+        Some(parts) => parts,
+        None => return Err(ShellError::untagged_runtime_error("Expected a config key")),
+    };
+
+    let mut entries = config;
+    for member in front {
+        let tag = Tag::from(member.span);
+        let entry = entries
+            .entry(key(member))
+            .or_insert_with(|| UntaggedValue::Row(Dictionary::default()).into_value(&tag));
+
+        entries = match &mut entry.value {
+            UntaggedValue::Row(row) => &mut row.entries,
+            other => {
+                return Err(ShellError::type_error(
+                    "row",
+                    other.type_name().spanned(member.span),
+                ))
+            }
+        };
+    }
+
+    entries.insert(key(last), value);
+    Ok(())
+}
+
+/// Removes the value at `path` from the config, giving it back if it was there.
+pub(crate) fn remove_value_at(
+    config: &mut IndexMap<String, Value>,
+    path: &ColumnPath,
+) -> Option<Value> {
+    let (last, front) = path.split_last()?;
+
+    let mut entries = config;
+    for member in front {
+        entries = match &mut entries.get_mut(&key(member))?.value {
+            UntaggedValue::Row(row) => &mut row.entries,
+            _ => return None,
+        };
+    }
+
+    entries.shift_remove(&key(last))
+}
+
+/// The name of the config key for a member of a path. Numbers are names too, as the config only
+/// has rows of keys on the way to a value.
+fn key(member: &PathMember) -> String {
+    match &member.unspanned {
+        UnspannedPathMember::String(name) => name.clone(),
+        UnspannedPathMember::Int(number) => number.to_string(),
+    }
+}
+
 pub fn write(config: &IndexMap<String, Value>, at: &Option<PathBuf>) -> Result<(), ShellError> {
+    if at.is_none() {
+        check_enabled()?;
+    }
+
     let filename = &mut default_path()?;
     let filename = match at {
         None => filename,
@@ -27,8 +27,9 @@ impl Conf for NuConfig {
     fn reload(&self) {
         let mut vars = self.vars.lock();
 
+        // Replaced rather than extended, so the settings removed from the file are gone too
         if let Ok(variables) = read(Tag::unknown(), &None) {
-            vars.extend(variables);
+            *vars = variables;
         }
     }
 }
@@ -1,5 +1,6 @@
 use crate::commands::du::{DirBuilder, DirInfo};
 use crate::prelude::*;
+use filesize::file_real_size_fast;
 use nu_errors::ShellError;
 use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
 
@@ -38,6 +39,7 @@ pub(crate) fn dir_entry_dict(
     metadata: Option<&std::fs::Metadata>,
     tag: impl Into<Tag>,
     full: bool,
+    long: bool,
     short_name: bool,
     with_symlink_targets: bool,
     du: bool,
@@ -102,6 +104,13 @@ pub(crate) fn dir_entry_dict(
                     UntaggedValue::string(umask::Mode::from(mode).to_string()),
                 );
 
+                if long {
+                    dict.insert_untagged(
+                        "octal",
+                        UntaggedValue::string(format!("{:04o}", mode & 0o7777)),
+                    );
+                }
+
                 if let Some(user) = users::get_user_by_uid(md.uid()) {
                     dict.insert_untagged(
                         "uid",
@@ -115,6 +124,11 @@ pub(crate) fn dir_entry_dict(
                         UntaggedValue::string(group.name().to_string_lossy()),
                     );
                 }
+
+                if long {
+                    dict.insert_untagged("inode", UntaggedValue::int(md.ino()));
+                    dict.insert_untagged("links", UntaggedValue::int(md.nlink()));
+                }
             }
         } else {
             dict.insert_untagged("readonly", UntaggedValue::nothing());
@@ -122,6 +136,12 @@ pub(crate) fn dir_entry_dict(
             #[cfg(unix)]
             {
                 dict.insert_untagged("mode", UntaggedValue::nothing());
+
+                if long {
+                    dict.insert_untagged("octal", UntaggedValue::nothing());
+                    dict.insert_untagged("inode", UntaggedValue::nothing());
+                    dict.insert_untagged("links", UntaggedValue::nothing());
+                }
             }
         }
     }
@@ -129,6 +149,8 @@ pub(crate) fn dir_entry_dict(
     if let Some(md) = metadata {
         let mut size_untagged_value: UntaggedValue = UntaggedValue::nothing();
 
+        let mut physical_untagged_value: UntaggedValue = UntaggedValue::nothing();
+
         if md.is_dir() {
             let dir_size: u64 = if du {
                 let params = DirBuilder::new(
@@ -142,7 +164,9 @@ pub(crate) fn dir_entry_dict(
                     false,
                 );
 
-                DirInfo::new(filename, &params, None, ctrl_c).get_size()
+                let dir = DirInfo::new(filename, &params, None, ctrl_c);
+                physical_untagged_value = UntaggedValue::bytes(dir.get_blocks());
+                dir.get_size()
             } else {
                 md.len()
             };
@@ -150,6 +174,11 @@ pub(crate) fn dir_entry_dict(
             size_untagged_value = UntaggedValue::bytes(dir_size);
         } else if md.is_file() {
             size_untagged_value = UntaggedValue::bytes(md.len());
+            if du {
+                if let Ok(blocks) = file_real_size_fast(filename, md) {
+                    physical_untagged_value = UntaggedValue::bytes(blocks);
+                }
+            }
         } else if md.file_type().is_symlink() {
             if let Ok(symlink_md) = filename.symlink_metadata() {
                 size_untagged_value = UntaggedValue::bytes(symlink_md.len() as u64);
@@ -157,8 +186,16 @@ pub(crate) fn dir_entry_dict(
         }
 
         dict.insert_untagged("size", size_untagged_value);
+
+        if du {
+            dict.insert_untagged("physical", physical_untagged_value);
+        }
     } else {
         dict.insert_untagged("size", UntaggedValue::nothing());
+
+        if du {
+            dict.insert_untagged("physical", UntaggedValue::nothing());
+        }
     }
 
     if let Some(md) = metadata {
@@ -0,0 +1,186 @@
+use chrono::{DateTime, Utc};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+/// A line of the history, with when it ran, how long it took and how it went. Lines kept before
+/// nu wrote these down only have their command.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct HistoryEntry {
+    pub command: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit_status: Option<i32>,
+}
+
+impl HistoryEntry {
+    pub(crate) fn new(command: impl Into<String>) -> HistoryEntry {
+        HistoryEntry {
+            command: command.into(),
+            start_time: None,
+            duration_ms: None,
+            exit_status: None,
+        }
+    }
+}
+
+/// Reads an entry from a line of the history file. The entries are written as JSON, and any
+/// other line is a command written by an older nu.
+fn parse_line(line: &str) -> HistoryEntry {
+    if line.starts_with("{\"command\":") {
+        if let Ok(entry) = serde_json::from_str(line) {
+            return entry;
+        }
+    }
+
+    HistoryEntry::new(line)
+}
+
+/// Reads the entries of the history file, oldest first. A missing file is an empty history.
+pub(crate) fn read(path: &Path) -> Vec<HistoryEntry> {
+    match fs::read_to_string(path) {
+        Ok(contents) => contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(parse_line)
+            .collect(),
+        Err(_) => vec![],
+    }
+}
+
+/// Adds an entry to the end of the history file. Sessions only ever add to the file while they
+/// run, so the lines of all of them are kept.
+pub(crate) fn append(path: &Path, entry: &HistoryEntry) -> io::Result<()> {
+    let line = serde_json::to_string(entry)?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", line)
+}
+
+/// Writes the history file with the given entries in place of the ones it has.
+pub(crate) fn write(path: &Path, entries: &[HistoryEntry]) -> io::Result<()> {
+    let mut contents = String::new();
+    for entry in entries {
+        contents.push_str(&serde_json::to_string(entry)?);
+        contents.push('\n');
+    }
+
+    fs::write(path, contents)
+}
+
+/// Keeps the last run of each command, in the order they last ran, and at most `max_size` of
+/// them, the most recent ones.
+pub(crate) fn compact(entries: Vec<HistoryEntry>, max_size: usize) -> Vec<HistoryEntry> {
+    let mut latest: IndexMap<String, HistoryEntry> = IndexMap::new();
+    for entry in entries {
+        latest.shift_remove(&entry.command);
+        latest.insert(entry.command.clone(), entry);
+    }
+
+    let skip = latest.len().saturating_sub(max_size);
+    latest
+        .into_iter()
+        .skip(skip)
+        .map(|(_, entry)| entry)
+        .collect()
+}
+
+/// Marks a line as a fuzzy search of the history, rather than a command. `Ctrl-R` puts it in the
+/// line being written.
+pub(crate) const FUZZY_SEARCH: char = '\u{12}';
+
+/// Whether the characters of the search are all in the command, in the same order, not minding
+/// case.
+pub(crate) fn fuzzy_match(command: &str, search: &str) -> bool {
+    let mut characters = command.chars().flat_map(char::to_lowercase);
+
+    search
+        .chars()
+        .flat_map(char::to_lowercase)
+        .filter(|c| !c.is_whitespace())
+        .all(|wanted| characters.any(|c| c == wanted))
+}
+
+/// Finds the command a line marked as a fuzzy search stands for. The rest of the line is
+/// searched for from the most recent command back, and each mark after the first skips a match.
+pub(crate) fn fuzzy_search<'a>(
+    commands: impl DoubleEndedIterator<Item = &'a String>,
+    line: &str,
+) -> Option<&'a String> {
+    let skip = line.matches(FUZZY_SEARCH).count().saturating_sub(1);
+    let search = line.replace(FUZZY_SEARCH, "");
+
+    commands
+        .rev()
+        .filter(|command| fuzzy_match(command, &search))
+        .nth(skip)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compact, fuzzy_match, fuzzy_search, parse_line, HistoryEntry, FUZZY_SEARCH};
+
+    #[test]
+    fn reads_entries_and_lines_of_older_versions() {
+        let entry = HistoryEntry {
+            command: "ls".to_string(),
+            start_time: None,
+            duration_ms: Some(12),
+            exit_status: Some(0),
+        };
+
+        assert_eq!(
+            parse_line(r#"{"command":"ls","duration_ms":12,"exit_status":0}"#),
+            entry
+        );
+        assert_eq!(parse_line("echo 1"), HistoryEntry::new("echo 1"));
+    }
+
+    #[test]
+    fn compacting_keeps_the_last_run_of_recent_commands() {
+        let entries = vec!["ls", "cd", "ls", "pwd", "echo"]
+            .into_iter()
+            .map(HistoryEntry::new)
+            .collect();
+
+        let commands: Vec<String> = compact(entries, 3)
+            .into_iter()
+            .map(|entry| entry.command)
+            .collect();
+
+        assert_eq!(commands, vec!["ls", "pwd", "echo"]);
+    }
+
+    #[test]
+    fn matches_the_letters_of_the_search_in_order() {
+        assert!(fuzzy_match("open data.csv", "ocsv"));
+        assert!(fuzzy_match("git Checkout main", "gco"));
+        assert!(!fuzzy_match("ls | sort-by size", "sl"));
+    }
+
+    #[test]
+    fn searches_back_from_the_most_recent_command() {
+        let commands: Vec<String> = vec!["open data.csv", "ls", "open notes.csv"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        let once = format!("{}ocsv", FUZZY_SEARCH);
+        let twice = format!("{}ocsv{}", FUZZY_SEARCH, FUZZY_SEARCH);
+        let thrice = format!("{}{}ocsv{}", FUZZY_SEARCH, FUZZY_SEARCH, FUZZY_SEARCH);
+
+        assert_eq!(
+            fuzzy_search(commands.iter(), &once).map(String::as_str),
+            Some("open notes.csv")
+        );
+        assert_eq!(
+            fuzzy_search(commands.iter(), &twice).map(String::as_str),
+            Some("open data.csv")
+        );
+        assert_eq!(fuzzy_search(commands.iter(), &thrice), None);
+    }
+}
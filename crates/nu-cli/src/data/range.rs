@@ -0,0 +1,150 @@
+use crate::data::value::{compare_values, compute_values};
+use nu_errors::ShellError;
+use nu_protocol::hir::Operator;
+use nu_protocol::{Primitive, Range, RangeInclusion, UntaggedValue, Value};
+use nu_source::{Span, SpannedItem, Tag};
+
+/// The values of a range, counted from its start up or down to its end.
+pub(crate) struct RangeValues {
+    current: Option<UntaggedValue>,
+    step: UntaggedValue,
+    end: UntaggedValue,
+    /// How the values are compared to the end, which depends on the way the range counts and on
+    /// whether its end is in it.
+    within: Operator,
+    tag: Tag,
+}
+
+impl Iterator for RangeValues {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        let current = self.current.take()?;
+        if !compare_values(self.within, &current, &self.end).unwrap_or(false) {
+            return None;
+        }
+
+        self.current = compute_values(Operator::Plus, &current, &self.step).ok();
+        Some(current.into_value(&self.tag))
+    }
+}
+
+/// The values of the range. It counts by one, up or down to its end, or by the difference
+/// between its first two values if it has them, like `1..3..9`.
+pub(crate) fn range_values(range: &Range, tag: impl Into<Tag>) -> Result<RangeValues, ShellError> {
+    let (from, from_inclusion) = &range.from;
+    let (to, to_inclusion) = &range.to;
+    let start = UntaggedValue::Primitive(from.item.clone());
+    let end = UntaggedValue::Primitive(to.item.clone());
+
+    let coerce_error = |left: Span, right: Span| {
+        move |(left_type, right_type): (&'static str, &'static str)| {
+            ShellError::coerce_error(left_type.spanned(left), right_type.spanned(right))
+        }
+    };
+
+    let (step, step_span) = match &range.next {
+        Some(next) => {
+            let next_value = UntaggedValue::Primitive(next.item.clone());
+            let step = compute_values(Operator::Minus, &next_value, &start)
+                .map_err(coerce_error(next.span, from.span))?;
+
+            (step, next.span)
+        }
+        None => {
+            let ascending = compare_values(Operator::LessThanOrEqual, &start, &end)
+                .map_err(coerce_error(from.span, to.span))?;
+
+            (UntaggedValue::int(if ascending { 1 } else { -1 }), to.span)
+        }
+    };
+
+    let zero = UntaggedValue::int(0);
+    let ascending = compare_values(Operator::GreaterThan, &step, &zero)
+        .map_err(coerce_error(step_span, step_span))?;
+    let descending = compare_values(Operator::LessThan, &step, &zero)
+        .map_err(coerce_error(step_span, step_span))?;
+
+    if !ascending && !descending {
+        return Err(ShellError::labeled_error(
+            "Could not count through the range",
+            "the second value has to be different from the first",
+            step_span,
+        ));
+    }
+
+    let within = match (ascending, to_inclusion) {
+        (true, RangeInclusion::Inclusive) => Operator::LessThanOrEqual,
+        (true, RangeInclusion::Exclusive) => Operator::LessThan,
+        (false, RangeInclusion::Inclusive) => Operator::GreaterThanOrEqual,
+        (false, RangeInclusion::Exclusive) => Operator::GreaterThan,
+    };
+
+    let current = match from_inclusion {
+        RangeInclusion::Inclusive => Some(start),
+        RangeInclusion::Exclusive => compute_values(Operator::Plus, &start, &step).ok(),
+    };
+
+    Ok(RangeValues {
+        current,
+        step,
+        end,
+        within,
+        tag: tag.into(),
+    })
+}
+
+/// Whether the value is in the range, that is between its ends, and one of the values counted
+/// for ranges that count by more than one.
+pub(crate) fn range_contains(
+    range: &Range,
+    value: &UntaggedValue,
+) -> Result<bool, (&'static str, &'static str)> {
+    let (from, from_inclusion) = &range.from;
+    let (to, to_inclusion) = &range.to;
+    let start = UntaggedValue::Primitive(from.item.clone());
+    let end = UntaggedValue::Primitive(to.item.clone());
+
+    // Ranges can count down, so the start isn't always the lowest end
+    let ((low, low_inclusion), (high, high_inclusion)) =
+        if compare_values(Operator::LessThanOrEqual, &start, &end)? {
+            ((&start, from_inclusion), (&end, to_inclusion))
+        } else {
+            ((&end, to_inclusion), (&start, from_inclusion))
+        };
+
+    let above = match low_inclusion {
+        RangeInclusion::Inclusive => Operator::GreaterThanOrEqual,
+        RangeInclusion::Exclusive => Operator::GreaterThan,
+    };
+    let below = match high_inclusion {
+        RangeInclusion::Inclusive => Operator::LessThanOrEqual,
+        RangeInclusion::Exclusive => Operator::LessThan,
+    };
+
+    if !compare_values(above, value, low)? || !compare_values(below, value, high)? {
+        return Ok(false);
+    }
+
+    let next = match &range.next {
+        Some(next) => UntaggedValue::Primitive(next.item.clone()),
+        None => return Ok(true),
+    };
+
+    // The value has to be a whole number of steps from the start
+    let step = compute_values(Operator::Minus, &next, &start)?;
+    if compare_values(Operator::Equal, &step, &UntaggedValue::int(0))? {
+        return compare_values(Operator::Equal, value, &start);
+    }
+
+    let offset = compute_values(Operator::Minus, value, &start)?;
+    let steps = compute_values(Operator::Divide, &offset, &step)?;
+
+    let whole = match &steps {
+        UntaggedValue::Primitive(Primitive::Int(_)) => true,
+        UntaggedValue::Primitive(Primitive::Decimal(steps)) => steps.with_scale(0) == *steps,
+        _ => false,
+    };
+
+    Ok(whole && compare_values(Operator::GreaterThanOrEqual, &steps, &UntaggedValue::int(0))?)
+}
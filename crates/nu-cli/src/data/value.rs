@@ -1,11 +1,13 @@
+use crate::commands::math::round::round;
 use crate::data::base::coerce_compare;
 use crate::data::base::shape::{Column, InlineShape};
 use crate::data::primitive::style_primitive;
+use bigdecimal::BigDecimal;
 use chrono::DateTime;
 use nu_errors::ShellError;
 use nu_protocol::hir::Operator;
 use nu_protocol::ShellTypeName;
-use nu_protocol::{Primitive, Type, UntaggedValue};
+use nu_protocol::{format_primitive, Primitive, Type, UntaggedValue};
 use nu_source::{DebugDocBuilder, PrettyDebug, Tagged};
 
 pub fn date_from_str(s: Tagged<&str>) -> Result<UntaggedValue, ShellError> {
@@ -41,13 +43,37 @@ pub fn compute_values(
 ) -> Result<UntaggedValue, (&'static str, &'static str)> {
     match (left, right) {
         (UntaggedValue::Primitive(lhs), UntaggedValue::Primitive(rhs)) => match (lhs, rhs) {
-            (Primitive::Bytes(x), Primitive::Bytes(y)) => {
-                let result = match operator {
-                    Operator::Plus => Ok(x + y),
-                    Operator::Minus => Ok(x - y),
-                    _ => Err((left.type_name(), right.type_name())),
-                }?;
-                Ok(UntaggedValue::Primitive(Primitive::Bytes(result)))
+            (Primitive::Bytes(x), Primitive::Bytes(y)) => match operator {
+                Operator::Plus => Ok(UntaggedValue::Primitive(Primitive::Bytes(x + y))),
+                Operator::Minus => match x.checked_sub(*y) {
+                    Some(result) => Ok(UntaggedValue::Primitive(Primitive::Bytes(result))),
+                    None => Err((left.type_name(), right.type_name())),
+                },
+                // Dividing sizes gives their ratio
+                Operator::Divide if *y != 0 => {
+                    if x % y == 0 {
+                        Ok(UntaggedValue::int(x / y))
+                    } else {
+                        Ok(UntaggedValue::decimal(
+                            BigDecimal::from(*x) / BigDecimal::from(*y),
+                        ))
+                    }
+                }
+                _ => Err((left.type_name(), right.type_name())),
+            },
+            (Primitive::Bytes(x), Primitive::Int(y)) => {
+                let y = bigdecimal::BigDecimal::from(y.clone());
+                scale_bytes(operator, *x, &y).ok_or((left.type_name(), right.type_name()))
+            }
+            (Primitive::Bytes(x), Primitive::Decimal(y)) => {
+                scale_bytes(operator, *x, y).ok_or((left.type_name(), right.type_name()))
+            }
+            (Primitive::Int(x), Primitive::Bytes(y)) if operator == Operator::Multiply => {
+                let x = bigdecimal::BigDecimal::from(x.clone());
+                scale_bytes(operator, *y, &x).ok_or((left.type_name(), right.type_name()))
+            }
+            (Primitive::Decimal(x), Primitive::Bytes(y)) if operator == Operator::Multiply => {
+                scale_bytes(operator, *y, x).ok_or((left.type_name(), right.type_name()))
             }
             (Primitive::Int(x), Primitive::Int(y)) => match operator {
                 Operator::Plus => Ok(UntaggedValue::Primitive(Primitive::Int(x + y))),
@@ -104,11 +130,21 @@ pub fn compute_values(
             }
             (Primitive::Date(x), Primitive::Duration(y)) => {
                 let result = match operator {
-                    Operator::Plus => Ok(x
-                        .checked_add_signed(chrono::Duration::seconds(*y as i64))
-                        .expect("Overflowing add of duration")),
-                    _ => Err((left.type_name(), right.type_name())),
-                }?;
+                    Operator::Plus => duration_of_seconds(*y).and_then(|y| x.checked_add_signed(y)),
+                    Operator::Minus => {
+                        duration_of_seconds(*y).and_then(|y| x.checked_sub_signed(y))
+                    }
+                    _ => None,
+                }
+                .ok_or((left.type_name(), right.type_name()))?;
+                Ok(UntaggedValue::Primitive(Primitive::Date(result)))
+            }
+            (Primitive::Duration(x), Primitive::Date(y)) => {
+                let result = match operator {
+                    Operator::Plus => duration_of_seconds(*x).and_then(|x| y.checked_add_signed(x)),
+                    _ => None,
+                }
+                .ok_or((left.type_name(), right.type_name()))?;
                 Ok(UntaggedValue::Primitive(Primitive::Date(result)))
             }
             (Primitive::Duration(x), Primitive::Duration(y)) => {
@@ -126,6 +162,38 @@ pub fn compute_values(
     }
 }
 
+/// A duration of whole seconds, if it's small enough for dates to be moved by it.
+pub fn duration_of_seconds(seconds: i64) -> Option<chrono::Duration> {
+    let max = chrono::Duration::max_value().num_seconds();
+
+    if (-max..=max).contains(&seconds) {
+        Some(chrono::Duration::seconds(seconds))
+    } else {
+        None
+    }
+}
+
+/// Multiplies or divides a size by a number, truncated to whole bytes. Sizes can't be negative.
+fn scale_bytes(
+    operator: Operator,
+    bytes: u64,
+    by: &bigdecimal::BigDecimal,
+) -> Option<UntaggedValue> {
+    use num_traits::{ToPrimitive, Zero};
+
+    let bytes = bigdecimal::BigDecimal::from(bytes);
+    let result = match operator {
+        Operator::Multiply => bytes * by,
+        Operator::Divide if !by.is_zero() => bytes / by,
+        _ => return None,
+    };
+
+    result
+        .with_scale(0)
+        .to_u64()
+        .map(|result| UntaggedValue::Primitive(Primitive::Bytes(result)))
+}
+
 /// If left is {{ Operator }} right
 pub fn compare_values(
     operator: Operator,
@@ -156,6 +224,15 @@ pub fn format_type<'a>(value: impl Into<&'a UntaggedValue>, width: usize) -> Str
     Type::from_value(value.into()).colored_string(width)
 }
 
+/// Formats a decimal for display. Only the displayed digits are rounded to `precision`
+/// places, the value itself keeps its full precision.
+pub fn format_decimal(decimal: &BigDecimal, precision: Option<u64>) -> String {
+    match precision {
+        Some(precision) => round(decimal, precision as i64).to_string(),
+        None => format_primitive(&Primitive::Decimal(decimal.clone()), None),
+    }
+}
+
 pub fn format_leaf<'a>(value: impl Into<&'a UntaggedValue>) -> DebugDocBuilder {
     InlineShape::from_value(value.into()).format().pretty()
 }
@@ -181,7 +258,10 @@ mod tests {
     use super::UntaggedValue as v;
     use indexmap::indexmap;
 
-    use super::merge_values;
+    use super::{compute_values, format_decimal, merge_values};
+    use bigdecimal::BigDecimal;
+    use nu_protocol::hir::Operator;
+    use std::str::FromStr;
 
     #[test]
     fn merges_tables() {
@@ -202,4 +282,26 @@ mod tests {
             merge_values(&table_author_row, &other_table_author_row).unwrap()
         );
     }
+
+    #[test]
+    fn formats_decimals_to_the_display_precision() {
+        let decimal = BigDecimal::from_str("101.500000000000000004").unwrap();
+
+        assert_eq!(format_decimal(&decimal, Some(2)), "101.50");
+        assert_eq!(format_decimal(&decimal, Some(0)), "102");
+        assert_eq!(format_decimal(&decimal, None), "101.5000");
+    }
+
+    #[test]
+    fn errors_instead_of_moving_dates_out_of_range() {
+        let now = v::system_date(std::time::SystemTime::now());
+
+        for seconds in &[i64::MAX, i64::MIN, 400_000 * 365 * 24 * 60 * 60] {
+            let duration = v::duration(*seconds);
+
+            assert!(compute_values(Operator::Plus, &now, &duration).is_err());
+            assert!(compute_values(Operator::Minus, &now, &duration).is_err());
+            assert!(compute_values(Operator::Plus, &duration, &now).is_err());
+        }
+    }
 }
@@ -0,0 +1,177 @@
+use crate::data::config;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Once the visits of all the directories add up to more than this, they are all aged, so the
+/// directories that aren't visited anymore fade away.
+const MAX_TOTAL_VISITS: f64 = 9000.0;
+
+/// A directory that was visited, with how often and when it was visited last.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Visit {
+    pub path: String,
+    pub visits: f64,
+    pub last_visit: DateTime<Utc>,
+}
+
+impl Visit {
+    /// How often the directory is visited, counting the recent visits more.
+    pub(crate) fn frecency(&self, now: DateTime<Utc>) -> f64 {
+        let age = now - self.last_visit;
+
+        let weight = if age < Duration::hours(1) {
+            4.0
+        } else if age < Duration::days(1) {
+            2.0
+        } else if age < Duration::weeks(1) {
+            0.5
+        } else {
+            0.25
+        };
+
+        self.visits * weight
+    }
+
+    /// Whether the path has each of the words, in order, not minding case.
+    pub(crate) fn matches(&self, words: &[String]) -> bool {
+        let path = self.path.to_lowercase();
+        let mut rest = &path[..];
+
+        for word in words {
+            let word = word.to_lowercase();
+            match rest.find(&word) {
+                Some(index) => rest = &rest[index + word.len()..],
+                None => return false,
+            }
+        }
+
+        true
+    }
+}
+
+/// The file the visits are kept in.
+pub(crate) fn path() -> PathBuf {
+    const FNAME: &str = "visits.txt";
+    config::user_data()
+        .map(|mut p| {
+            p.push(FNAME);
+            p
+        })
+        .unwrap_or_else(|_| PathBuf::from(FNAME))
+}
+
+/// Reads the visits from the file, one per line. A missing file has no visits.
+pub(crate) fn read(path: &Path) -> Vec<Visit> {
+    match fs::read_to_string(path) {
+        Ok(contents) => contents
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect(),
+        Err(_) => vec![],
+    }
+}
+
+pub(crate) fn write(path: &Path, visits: &[Visit]) -> io::Result<()> {
+    let mut contents = String::new();
+    for visit in visits {
+        contents.push_str(&serde_json::to_string(visit)?);
+        contents.push('\n');
+    }
+
+    fs::write(path, contents)
+}
+
+/// Counts a visit to `directory`, aging the others when there are too many visits.
+pub(crate) fn visit(visits: &mut Vec<Visit>, directory: &str, now: DateTime<Utc>) {
+    match visits.iter_mut().find(|visit| visit.path == directory) {
+        Some(visit) => {
+            visit.visits += 1.0;
+            visit.last_visit = now;
+        }
+        None => visits.push(Visit {
+            path: directory.to_string(),
+            visits: 1.0,
+            last_visit: now,
+        }),
+    }
+
+    if visits.iter().map(|visit| visit.visits).sum::<f64>() > MAX_TOTAL_VISITS {
+        for visit in visits.iter_mut() {
+            visit.visits *= 0.99;
+        }
+        visits.retain(|visit| visit.visits >= 1.0);
+    }
+}
+
+/// Counts a visit to `directory` in the visits file.
+pub(crate) fn record(directory: &str) -> io::Result<()> {
+    let path = path();
+    let mut visits = read(&path);
+    visit(&mut visits, directory, Utc::now());
+    write(&path, &visits)
+}
+
+/// The visited directories that still exist and have the words, by how often and recently
+/// they were visited, the most first.
+pub(crate) fn ranked(visits: Vec<Visit>, words: &[String], now: DateTime<Utc>) -> Vec<Visit> {
+    let mut matching: Vec<Visit> = visits
+        .into_iter()
+        .filter(|visit| visit.matches(words) && Path::new(&visit.path).is_dir())
+        .collect();
+
+    matching.sort_by(|a, b| {
+        b.frecency(now)
+            .partial_cmp(&a.frecency(now))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    matching
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{visit, Visit};
+    use chrono::{Duration, Utc};
+
+    fn visited(path: &str, visits: f64, hours_ago: i64) -> Visit {
+        Visit {
+            path: path.to_string(),
+            visits,
+            last_visit: Utc::now() - Duration::hours(hours_ago),
+        }
+    }
+
+    #[test]
+    fn counts_recent_visits_more() {
+        let now = Utc::now();
+
+        assert!(visited("/a", 3.0, 0).frecency(now) > visited("/b", 5.0, 24 * 30).frecency(now));
+        assert!(visited("/a", 3.0, 0).frecency(now) < visited("/b", 7.0, 2).frecency(now));
+    }
+
+    #[test]
+    fn matches_the_words_in_order() {
+        let words =
+            |words: &[&str]| -> Vec<String> { words.iter().map(|word| word.to_string()).collect() };
+        let visit = visited("/home/me/Projects/nushell", 1.0, 0);
+
+        assert!(visit.matches(&words(&["proj"])));
+        assert!(visit.matches(&words(&["me", "nu"])));
+        assert!(!visit.matches(&words(&["nu", "proj"])));
+    }
+
+    #[test]
+    fn ages_the_visits_once_there_are_too_many() {
+        let now = Utc::now();
+        let mut visits = vec![visited("/a", 8999.5, 0), visited("/b", 1.0, 0)];
+
+        visit(&mut visits, "/a", now);
+
+        assert_eq!(visits.len(), 1);
+        assert_eq!(visits[0].path, "/a");
+        assert!(visits[0].visits < 9000.0);
+    }
+}
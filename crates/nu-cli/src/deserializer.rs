@@ -14,6 +14,8 @@ use std::path::PathBuf;
 pub struct NumericRange {
     pub from: (Spanned<u64>, RangeInclusion),
     pub to: (Spanned<u64>, RangeInclusion),
+    #[serde(default)]
+    pub next: Option<Spanned<u64>>,
 }
 
 #[derive(Debug)]
@@ -427,10 +429,15 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut ConfigDeserializer<'de> {
 
                 let left = left.as_u64(left_span)?;
                 let right = right.as_u64(right_span)?;
+                let next = match range.next {
+                    Some(next) => Some(next.item.as_u64(next.span)?.spanned(next.span)),
+                    None => None,
+                };
 
                 let numeric_range = NumericRange {
                     from: (left.spanned(left_span), left_inclusion),
                     to: (right.spanned(right_span), right_inclusion),
+                    next,
                 };
 
                 visit::<Tagged<NumericRange>, _>(numeric_range.tagged(tag), name, fields, visitor)
@@ -2,6 +2,7 @@ use crate::data::config::Conf;
 use crate::env::directory_specific_environment::*;
 use indexmap::{indexmap, IndexSet};
 use nu_protocol::{UntaggedValue, Value};
+use nu_source::Tag;
 use std::ffi::OsString;
 use std::fmt::Debug;
 
@@ -11,6 +12,7 @@ pub trait Env: Debug + Send {
 
     fn add_env(&mut self, key: &str, value: &str, overwrite_existing: bool);
     fn add_path(&mut self, new_path: OsString);
+    fn set_path(&mut self, paths: OsString);
 }
 
 impl Env for Box<dyn Env> {
@@ -29,6 +31,10 @@ impl Env for Box<dyn Env> {
     fn add_path(&mut self, new_path: OsString) {
         (**self).add_path(new_path);
     }
+
+    fn set_path(&mut self, paths: OsString) {
+        (**self).set_path(paths);
+    }
 }
 
 #[derive(Debug, Default)]
@@ -167,6 +173,19 @@ impl Env for Environment {
 
         self.path_vars = Some(new_paths);
     }
+
+    fn set_path(&mut self, paths: std::ffi::OsString) {
+        let tag = match &self.path_vars {
+            Some(current_paths) => current_paths.tag.clone(),
+            None => Tag::unknown(),
+        };
+
+        let paths: IndexSet<Value> = std::env::split_paths(&paths)
+            .map(|path| UntaggedValue::string(path.to_string_lossy()).into_value(tag.clone()))
+            .collect();
+
+        self.path_vars = Some(UntaggedValue::Table(paths.into_iter().collect()).into_value(tag));
+    }
 }
 
 #[cfg(test)]
@@ -1,12 +1,17 @@
 use crate::context::Context;
 use crate::data::config::{Conf, NuConfig};
 use crate::env::environment::{Env, Environment};
+use indexmap::IndexMap;
 use parking_lot::Mutex;
 use std::sync::Arc;
 
 pub struct EnvironmentSyncer {
     pub env: Arc<Mutex<Box<Environment>>>,
     pub config: Arc<Box<dyn Conf>>,
+    /// The variables as they were last given to the session, to tell which the session changed.
+    synced: IndexMap<String, String>,
+    /// The PATH as it was last given to the session.
+    synced_path: Option<std::ffi::OsString>,
 }
 
 impl Default for EnvironmentSyncer {
@@ -20,6 +25,8 @@ impl EnvironmentSyncer {
         EnvironmentSyncer {
             env: Arc::new(Mutex::new(Box::new(Environment::new()))),
             config: Arc::new(Box::new(NuConfig::new())),
+            synced: IndexMap::new(),
+            synced_path: None,
         }
     }
 
@@ -48,8 +55,13 @@ impl EnvironmentSyncer {
             for (name, value) in ctx.with_host(|host| host.vars()) {
                 if name != "path" && name != "PATH" {
                     // account for new env vars present in the current session
-                    // that aren't loaded from config.
-                    environment.add_env(&name, &value, false);
+                    // that aren't loaded from config. The ones changed in the session since
+                    // the last sync, like with load-env, take the place of those from config.
+                    let changed = self
+                        .synced
+                        .get(&name)
+                        .map_or(false, |synced| *synced != value);
+                    environment.add_env(&name, &value, changed);
 
                     environment.maintain_directory_environment().ok();
 
@@ -60,14 +72,17 @@ impl EnvironmentSyncer {
             }
 
             if let Some(variables) = environment.env() {
+                self.synced.clear();
+
                 for var in variables.row_entries() {
                     if let Ok(string) = var.1.as_string() {
                         ctx.with_host(|host| {
                             host.env_set(
                                 std::ffi::OsString::from(var.0),
-                                std::ffi::OsString::from(string),
+                                std::ffi::OsString::from(&string),
                             )
                         });
+                        self.synced.insert(var.0.clone(), string);
                     }
                 }
             }
@@ -81,7 +96,17 @@ impl EnvironmentSyncer {
             let native_paths = ctx.with_host(|host| host.env_get(std::ffi::OsString::from("PATH")));
 
             if let Some(native_paths) = native_paths {
-                environment.add_path(native_paths);
+                // A PATH changed in the session since the last sync, like with pathvar, takes the
+                // place of the one from config, else new paths in the session are added to it.
+                if self
+                    .synced_path
+                    .as_ref()
+                    .map_or(false, |synced| *synced != native_paths)
+                {
+                    environment.set_path(native_paths);
+                } else {
+                    environment.add_path(native_paths);
+                }
 
                 ctx.with_host(|host| {
                     host.env_rm(std::ffi::OsString::from("PATH"));
@@ -97,6 +122,8 @@ impl EnvironmentSyncer {
                 );
 
                 if let Ok(paths_ready) = prepared {
+                    self.synced_path = Some(paths_ready.clone());
+
                     ctx.with_host(|host| {
                         host.env_set(std::ffi::OsString::from("PATH"), paths_ready);
                     });
@@ -303,6 +330,57 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn envs_changed_in_the_session_take_the_place_of_the_configured_ones() -> Result<(), ShellError>
+    {
+        let mut ctx = Context::basic()?;
+        ctx.host = Arc::new(Mutex::new(Box::new(crate::env::host::FakeHost::new())));
+
+        Playground::setup("syncs_env_test_3", |dirs, sandbox| {
+            sandbox.with_files(vec![FileWithContent(
+                "configuration.toml",
+                r#"
+                    [env]
+                    SHELL = "/usr/bin/you_already_made_the_nu_choice"
+                "#,
+            )]);
+
+            let mut file = dirs.test().clone();
+            file.push("configuration.toml");
+
+            let fake_config = FakeConfig::new(&file);
+            let mut actual = EnvironmentSyncer::new();
+            actual.set_config(Box::new(fake_config));
+
+            actual.clear_env_vars(&mut ctx);
+            actual.load_environment();
+            actual.sync_env_vars(&mut ctx);
+
+            // The session changes the variable after it was synced, like load-env does.
+            ctx.with_host(|test_host| {
+                test_host.env_set(
+                    std::ffi::OsString::from("SHELL"),
+                    std::ffi::OsString::from("/usr/bin/sh"),
+                )
+            });
+
+            actual.reload();
+            actual.sync_env_vars(&mut ctx);
+
+            ctx.with_host(|test_host| {
+                let var_shell = test_host
+                    .env_get(std::ffi::OsString::from("SHELL"))
+                    .expect("Couldn't get SHELL var from host.")
+                    .into_string()
+                    .expect("Couldn't convert to string.");
+
+                assert_eq!(var_shell, "/usr/bin/sh");
+            });
+        });
+
+        Ok(())
+    }
+
     #[test]
     fn syncs_path_if_new_path_entry_in_session_is_not_in_configuration_file(
     ) -> Result<(), ShellError> {
@@ -463,4 +541,63 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn paths_changed_in_the_session_take_the_place_of_the_configured_ones() -> Result<(), ShellError>
+    {
+        let mut ctx = Context::basic()?;
+        ctx.host = Arc::new(Mutex::new(Box::new(crate::env::host::FakeHost::new())));
+
+        let expected = std::env::join_paths(vec![
+            PathBuf::from("/path/to/be/added"),
+            PathBuf::from("/Users/mosqueteros/bin"),
+        ])
+        .expect("Couldn't join paths.")
+        .into_string()
+        .expect("Couldn't convert to string.");
+
+        Playground::setup("syncs_path_test_3", |dirs, sandbox| {
+            sandbox.with_files(vec![FileWithContent(
+                "configuration.toml",
+                r#"
+                    path = ["/Users/andresrobalino/.volta/bin", "/Users/mosqueteros/bin"]
+                "#,
+            )]);
+
+            let mut file = dirs.test().clone();
+            file.push("configuration.toml");
+
+            let fake_config = FakeConfig::new(&file);
+            let mut actual = EnvironmentSyncer::new();
+            actual.set_config(Box::new(fake_config));
+
+            actual.clear_path_var(&mut ctx);
+            actual.load_environment();
+            actual.sync_path_vars(&mut ctx);
+
+            // The session puts a path first and takes one out after the PATH was synced, like
+            // pathvar does.
+            ctx.with_host(|test_host| {
+                test_host.env_set(
+                    std::ffi::OsString::from("PATH"),
+                    std::ffi::OsString::from(&expected),
+                )
+            });
+
+            actual.reload();
+            actual.sync_path_vars(&mut ctx);
+
+            ctx.with_host(|test_host| {
+                let actual = test_host
+                    .env_get(std::ffi::OsString::from("PATH"))
+                    .expect("Couldn't get PATH var from host.")
+                    .into_string()
+                    .expect("Couldn't convert to string.");
+
+                assert_eq!(actual, expected);
+            });
+        });
+
+        Ok(())
+    }
 }
@@ -1,13 +1,15 @@
 use crate::commands::classified::block::run_block;
 use crate::context::CommandRegistry;
-use crate::evaluate::operator::apply_operator;
+use crate::data::range::range_values;
+use crate::evaluate::operator::{apply_operator, regex};
 use crate::prelude::*;
 use async_recursion::async_recursion;
 use log::trace;
 use nu_errors::{ArgumentError, ShellError};
 use nu_protocol::hir::{self, Expression, SpannedExpression};
 use nu_protocol::{
-    ColumnPath, Primitive, RangeInclusion, UnspannedPathMember, UntaggedValue, Value,
+    ColumnPath, Primitive, Range, RangeInclusion, TaggedDictBuilder, UnspannedPathMember,
+    UntaggedValue, Value,
 };
 
 #[async_recursion]
@@ -40,12 +42,35 @@ pub(crate) async fn evaluate_baseline_expr(
         Expression::Binary(binary) => {
             // TODO: If we want to add short-circuiting, we'll need to move these down
             let left = evaluate_baseline_expr(&binary.left, registry, it, vars, env).await?;
+
+            // The right side is only needed if there's nothing on the left
+            if let Expression::Literal(hir::Literal::Operator(hir::Operator::Coalesce)) =
+                binary.op.expr
+            {
+                if left.value.is_some() {
+                    return Ok(left.value.into_value(tag));
+                }
+            }
+
             let right = evaluate_baseline_expr(&binary.right, registry, it, vars, env).await?;
 
             trace!("left={:?} right={:?}", left.value, right.value);
 
             match binary.op.expr {
                 Expression::Literal(hir::Literal::Operator(op)) => {
+                    // A pattern that isn't a regex is an error of its own, not one of the types
+                    if let (hir::Operator::Contains, Ok(pattern))
+                    | (hir::Operator::NotContains, Ok(pattern)) = (op, right.as_string())
+                    {
+                        if let Err(e) = regex(&pattern) {
+                            return Err(ShellError::labeled_error(
+                                "Invalid regular expression",
+                                format!("invalid regular expression: {}", e),
+                                binary.right.span,
+                            ));
+                        }
+                    }
+
                     match apply_operator(op, &left, &right) {
                         Ok(result) => Ok(result.into_value(tag)),
                         Err((left_type, right_type)) => Err(ShellError::coerce_error(
@@ -58,11 +83,8 @@ pub(crate) async fn evaluate_baseline_expr(
             }
         }
         Expression::Range(range) => {
-            let left = &range.left;
-            let right = &range.right;
-
-            let left = evaluate_baseline_expr(&left, registry, it, vars, env).await?;
-            let right = evaluate_baseline_expr(&right, registry, it, vars, env).await?;
+            let left = evaluate_baseline_expr(&range.left, registry, it, vars, env).await?;
+            let right = evaluate_baseline_expr(&range.right, registry, it, vars, env).await?;
             let left_span = left.tag.span;
             let right_span = right.tag.span;
 
@@ -70,32 +92,106 @@ pub(crate) async fn evaluate_baseline_expr(
                 left.as_primitive()?.spanned(left_span),
                 RangeInclusion::Inclusive,
             );
-            let right = (
-                right.as_primitive()?.spanned(right_span),
-                RangeInclusion::Inclusive,
-            );
+            let right = (right.as_primitive()?.spanned(right_span), range.inclusion);
 
-            Ok(UntaggedValue::range(left, right).into_value(tag))
+            let mut value = Range::new(left, right);
+            if let Some(next) = &range.next {
+                let next = evaluate_baseline_expr(next, registry, it, vars, env).await?;
+                let next_span = next.tag.span;
+                value.next = Some(next.as_primitive()?.spanned(next_span));
+            }
+
+            Ok(UntaggedValue::Primitive(Primitive::Range(Box::new(value))).into_value(tag))
         }
         Expression::List(list) => {
             let mut exprs = vec![];
 
             for expr in list {
-                let expr = evaluate_baseline_expr(&expr, registry, it, vars, env).await?;
-                exprs.push(expr);
+                match &expr.expr {
+                    Expression::Spread(value) => {
+                        let value = evaluate_baseline_expr(value, registry, it, vars, env).await?;
+                        exprs.extend(spread_rows(value)?);
+                    }
+                    _ => {
+                        let expr = evaluate_baseline_expr(expr, registry, it, vars, env).await?;
+                        exprs.push(expr);
+                    }
+                }
             }
 
             Ok(UntaggedValue::Table(exprs).into_value(tag))
         }
-        Expression::Block(block) => Ok(UntaggedValue::Block(block.clone()).into_value(&tag)),
+        Expression::Table(headers, rows) => {
+            let mut columns = vec![];
+            for header in headers {
+                let header = evaluate_baseline_expr(header, registry, it, vars, env).await?;
+                columns.push(header.as_string()?);
+            }
+
+            let mut output = vec![];
+            for row in rows {
+                let mut dict = TaggedDictBuilder::new(&tag);
+                for (column, cell) in columns.iter().zip(row) {
+                    let cell = evaluate_baseline_expr(cell, registry, it, vars, env).await?;
+                    dict.insert_value(column.clone(), cell);
+                }
+                output.push(dict.into_value());
+            }
+
+            Ok(UntaggedValue::Table(output).into_value(tag))
+        }
+        Expression::Row(entries) => {
+            let mut dict = TaggedDictBuilder::new(&tag);
+            for (column, value) in entries {
+                let value = evaluate_baseline_expr(value, registry, it, vars, env).await?;
+                match column {
+                    Some(column) => {
+                        let column =
+                            evaluate_baseline_expr(column, registry, it, vars, env).await?;
+                        dict.insert_value(column.as_string()?, value);
+                    }
+                    None => match value.value {
+                        UntaggedValue::Row(row) => {
+                            for (column, value) in row.entries {
+                                dict.insert_value(column, value);
+                            }
+                        }
+                        _ => {
+                            return Err(ShellError::labeled_error(
+                                "Expected a row",
+                                "only rows can be spread into a row",
+                                value.tag,
+                            ))
+                        }
+                    },
+                }
+            }
+
+            Ok(dict.into_value())
+        }
+        Expression::Block(block) => {
+            // The block keeps the variables as they are now, for when it is run later on
+            let mut block = block.clone();
+            block.captures.extend(
+                vars.iter()
+                    .map(|(name, value)| (name.clone(), value.clone())),
+            );
+
+            Ok(UntaggedValue::Block(block).into_value(&tag))
+        }
+        Expression::Spread(value) => evaluate_baseline_expr(value, registry, it, vars, env).await,
         Expression::Path(path) => {
             let value = evaluate_baseline_expr(&path.head, registry, it, vars, env).await?;
             let mut item = value;
 
-            for member in &path.tail {
+            for (idx, member) in path.tail.iter().enumerate() {
                 let next = item.get_data_by_member(member);
 
                 match next {
+                    // An optional member that's missing gives nothing, whatever comes after it
+                    Err(_) if path.optional.contains(&idx) => {
+                        return Ok(UntaggedValue::nothing().into_value(tag));
+                    }
                     Err(err) => {
                         let possibilities = item.data_descriptors();
 
@@ -131,6 +227,22 @@ pub(crate) async fn evaluate_baseline_expr(
     }
 }
 
+/// The rows of a value spread into a list, like `...$rows` in `[...$rows 4]`.
+fn spread_rows(value: Value) -> Result<Vec<Value>, ShellError> {
+    match value.value {
+        UntaggedValue::Table(rows) => Ok(rows),
+        UntaggedValue::Primitive(Primitive::Range(range)) => {
+            Ok(range_values(&range, value.tag)?.collect())
+        }
+        UntaggedValue::Primitive(Primitive::Nothing) => Ok(vec![]),
+        _ => Err(ShellError::labeled_error(
+            "Expected a list or a table",
+            "only lists, tables and ranges can be spread into a list",
+            value.tag,
+        )),
+    }
+}
+
 fn evaluate_literal(literal: &hir::Literal, span: Span) -> Value {
     match &literal {
         hir::Literal::ColumnPath(path) => {
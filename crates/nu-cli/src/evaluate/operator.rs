@@ -1,8 +1,21 @@
+use crate::data::range::range_contains;
 use crate::data::value;
+use indexmap::IndexMap;
 use nu_protocol::hir::Operator;
 use nu_protocol::{Primitive, ShellTypeName, UntaggedValue, Value};
+use regex::Regex;
+use std::cell::RefCell;
 use std::ops::Not;
 
+/// How many of the regexes last matched with are kept compiled.
+const REGEX_CACHE_SIZE: usize = 16;
+
+thread_local! {
+    /// The regexes last matched with, so a pattern is compiled once rather than for every row a
+    /// condition is checked on.
+    static REGEXES: RefCell<IndexMap<String, Regex>> = RefCell::new(IndexMap::new());
+}
+
 pub fn apply_operator(
     op: Operator,
     left: &Value,
@@ -35,32 +48,60 @@ pub fn apply_operator(
             (Ok(left), Ok(right)) => Ok(UntaggedValue::boolean(left || right)),
             _ => Err((left.type_name(), right.type_name())),
         },
+        Operator::Coalesce => match left.value {
+            UntaggedValue::Primitive(Primitive::Nothing) => Ok(right.value.clone()),
+            _ => Ok(left.value.clone()),
+        },
     }
 }
 
+/// The pattern compiled as a regex, or the one compiled for it before.
+pub(crate) fn regex(pattern: &str) -> Result<Regex, regex::Error> {
+    REGEXES.with(|regexes| {
+        let mut regexes = regexes.borrow_mut();
+        if let Some(regex) = regexes.get(pattern) {
+            return Ok(regex.clone());
+        }
+
+        let regex = Regex::new(pattern)?;
+        if regexes.len() >= REGEX_CACHE_SIZE {
+            regexes.shift_remove_index(0);
+        }
+        regexes.insert(pattern.to_string(), regex.clone());
+
+        Ok(regex)
+    })
+}
+
+/// Matches the left string against the right one as a regular expression. Invalid patterns are
+/// reported by the evaluator before it gets here.
 fn string_contains(
     left: &UntaggedValue,
     right: &UntaggedValue,
 ) -> Result<bool, (&'static str, &'static str)> {
-    match (left, right) {
+    let (haystack, pattern) = match (left, right) {
         (
             UntaggedValue::Primitive(Primitive::String(l)),
             UntaggedValue::Primitive(Primitive::String(r)),
-        ) => Ok(l.contains(r)),
-        (
+        )
+        | (
             UntaggedValue::Primitive(Primitive::Line(l)),
             UntaggedValue::Primitive(Primitive::String(r)),
-        ) => Ok(l.contains(r)),
-        (
+        )
+        | (
             UntaggedValue::Primitive(Primitive::String(l)),
             UntaggedValue::Primitive(Primitive::Line(r)),
-        ) => Ok(l.contains(r)),
-        (
+        )
+        | (
             UntaggedValue::Primitive(Primitive::Line(l)),
             UntaggedValue::Primitive(Primitive::Line(r)),
-        ) => Ok(l.contains(r)),
-        _ => Err((left.type_name(), right.type_name())),
-    }
+        ) => (l, r),
+        _ => return Err((left.type_name(), right.type_name())),
+    };
+
+    regex(pattern)
+        .map(|regex| regex.is_match(haystack))
+        .map_err(|_| (left.type_name(), right.type_name()))
 }
 
 fn table_contains(
@@ -70,6 +111,7 @@ fn table_contains(
     let left = left.clone();
     match right {
         UntaggedValue::Table(values) => Ok(values.iter().any(|x| x.value == left)),
+        UntaggedValue::Primitive(Primitive::Range(range)) => range_contains(range, &left),
         _ => Err((left.type_name(), right.type_name())),
     }
 }
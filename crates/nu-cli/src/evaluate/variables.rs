@@ -42,6 +42,10 @@ pub fn nu(env: &IndexMap<String, String>, tag: impl Into<Tag>) -> Result<Value,
     let config = crate::data::config::default_path()?;
     nu_dict.insert_value("config-path", UntaggedValue::path(config).into_value(&tag));
 
+    // Empty outside of a git repository, so the prompt can always use it
+    let branch = crate::git::current_branch().unwrap_or_default();
+    nu_dict.insert_value("git-branch", UntaggedValue::string(branch).into_value(&tag));
+
     let history = History::path();
     nu_dict.insert_value(
         "history-path",
@@ -1,9 +1,9 @@
-use crate::data::value::{format_leaf, style_leaf};
+use crate::data::value::{format_decimal, format_leaf, style_leaf};
 use crate::format::RenderView;
 use crate::prelude::*;
 use derive_new::new;
 use nu_errors::ShellError;
-use nu_protocol::{UntaggedValue, Value};
+use nu_protocol::{Primitive, UntaggedValue, Value};
 use textwrap::fill;
 
 use prettytable::format::{Alignment, FormatBuilder, LinePosition, LineSeparator};
@@ -84,8 +84,18 @@ fn are_table_indexes_disabled() -> bool {
     }
 }
 
+fn format_cell(value: &UntaggedValue, decimal_precision: Option<u64>) -> String {
+    match value {
+        UntaggedValue::Primitive(Primitive::Decimal(decimal)) if decimal_precision.is_some() => {
+            format_decimal(decimal, decimal_precision)
+        }
+        _ => format_leaf(value).plain_string(100_000),
+    }
+}
+
 fn values_to_entries(values: &[Value], headers: &mut Vec<String>, starting_idx: usize) -> Entries {
     let disable_indexes = are_table_indexes_disabled();
+    let decimal_precision = crate::data::config::decimal_precision();
     let mut entries = vec![];
 
     if headers.is_empty() {
@@ -105,7 +115,7 @@ fn values_to_entries(values: &[Value], headers: &mut Vec<String>, starting_idx:
                             format_leaf(&UntaggedValue::nothing()).plain_string(100_000),
                             style_leaf(&UntaggedValue::nothing()),
                         ),
-                        _ => (format_leaf(value).plain_string(100_000), style_leaf(value)),
+                        _ => (format_cell(value, decimal_precision), style_leaf(value)),
                     }
                 } else {
                     match value {
@@ -115,7 +125,7 @@ fn values_to_entries(values: &[Value], headers: &mut Vec<String>, starting_idx:
                         } => {
                             let data = value.get_data(d);
                             (
-                                format_leaf(data.borrow()).plain_string(100_000),
+                                format_cell(data.borrow(), decimal_precision),
                                 style_leaf(data.borrow()),
                             )
                         }
@@ -1,8 +1,10 @@
+use futures::channel::oneshot;
 use futures::stream::Stream;
 use std::pin::Pin;
 use std::sync::{mpsc, Arc, Mutex};
 use std::task::{self, Poll, Waker};
 use std::thread;
+use std::time::Duration;
 
 #[allow(clippy::option_option)]
 struct SharedState<T: Send + 'static> {
@@ -106,6 +108,18 @@ impl<T: Send + 'static> Drop for ThreadedReceiver<T> {
     }
 }
 
+/// Resolves once the duration has passed, waiting it out in a thread of its own.
+pub fn delay(duration: Duration) -> oneshot::Receiver<()> {
+    let (sender, receiver) = oneshot::channel();
+
+    thread::spawn(move || {
+        thread::sleep(duration);
+        let _ = sender.send(());
+    });
+
+    receiver
+}
+
 #[cfg(test)]
 mod tests {
     mod threaded_receiver {
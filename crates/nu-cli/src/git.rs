@@ -1,5 +1,3 @@
-#![cfg(not(feature = "starship-prompt"))]
-
 use git2::{Repository, RepositoryOpenFlags};
 use std::ffi::OsString;
 
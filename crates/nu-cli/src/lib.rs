@@ -32,13 +32,15 @@ pub mod utils;
 mod examples;
 
 pub use crate::cli::{
-    cli, create_default_context, load_plugins, run_pipeline_standalone, run_vec_of_pipelines,
+    cli, create_default_context, create_embedded_context, evaluate_pipeline, load_plugins,
+    run_pipeline_standalone, run_script_file, run_vec_of_pipelines,
 };
 pub use crate::commands::command::{
     whole_stream_command, CommandArgs, EvaluatedWholeStreamCommandArgs, WholeStreamCommand,
 };
 pub use crate::commands::help::get_help;
-pub use crate::context::CommandRegistry;
+pub use crate::context::{CommandRegistry, Context};
+pub use crate::data::config::disable_config;
 pub use crate::data::dict::TaggedListBuilder;
 pub use crate::data::primitive;
 pub use crate::data::value;
@@ -68,6 +68,37 @@ where
     Ok(dunce::simplified(&path).to_path_buf())
 }
 
+/// The path that leads from the directory to the path, like `../b/c` from `/a/d` to `/a/b/c`.
+/// Both need to be absolute.
+pub fn relative<P, Q>(from_dir: P, path: Q) -> PathBuf
+where
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+{
+    let from: Vec<_> = from_dir.as_ref().components().collect();
+    let to: Vec<_> = path.as_ref().components().collect();
+
+    let common = from
+        .iter()
+        .zip(to.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut result = PathBuf::new();
+    for _ in common..from.len() {
+        result.push(Component::ParentDir);
+    }
+    for component in &to[common..] {
+        result.push(component);
+    }
+
+    if result.as_os_str().is_empty() {
+        result.push(Component::CurDir);
+    }
+
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,6 +128,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn relative_goes_up_to_the_common_directory() {
+        assert_eq!(
+            PathBuf::from("../b/c"),
+            relative(Path::new("/a/d"), Path::new("/a/b/c"))
+        );
+        assert_eq!(
+            PathBuf::from("c"),
+            relative(Path::new("/a/b"), Path::new("/a/b/c"))
+        );
+        assert_eq!(
+            PathBuf::from("."),
+            relative(Path::new("/a/b"), Path::new("/a/b"))
+        );
+    }
+
     #[test]
     fn canonicalize_should_fail() {
         let relative_to = Path::new("/foo/bar/baz"); // '/foo' is missing
@@ -1,12 +1,16 @@
 #![allow(clippy::module_inception)]
 
+pub(crate) mod archive_shell;
 pub(crate) mod completer;
 pub(crate) mod filesystem_shell;
 pub(crate) mod help_shell;
 pub(crate) mod helper;
 pub(crate) mod palette;
+#[cfg(feature = "s3")]
+pub(crate) mod s3_shell;
 pub(crate) mod shell;
 pub(crate) mod shell_manager;
+pub(crate) mod ssh_shell;
 pub(crate) mod value_shell;
 
 pub(crate) use helper::Helper;
@@ -0,0 +1,590 @@
+use crate::commands::cd::CdArgs;
+use crate::commands::command::EvaluatedWholeStreamCommandArgs;
+use crate::commands::cp::CopyArgs;
+use crate::commands::ln::LinkArgs;
+use crate::commands::ls::LsArgs;
+use crate::commands::mkdir::MkdirArgs;
+use crate::commands::mv::MoveArgs;
+use crate::commands::rm::RemoveArgs;
+use crate::prelude::*;
+use crate::shell::shell::Shell;
+use chrono::{DateTime, TimeZone, Utc};
+use glob::Pattern;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, TaggedDictBuilder, UntaggedValue};
+use nu_source::{AnchorLocation, Span};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// The kinds of archives that can be entered, told apart by their extension.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Zip,
+    Tar,
+    TarGz,
+}
+
+impl Format {
+    fn of(path: &Path) -> Option<Format> {
+        let name = path.file_name()?.to_string_lossy().to_lowercase();
+
+        if name.ends_with(".zip") {
+            Some(Format::Zip)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(Format::TarGz)
+        } else if name.ends_with(".tar") {
+            Some(Format::Tar)
+        } else {
+            None
+        }
+    }
+}
+
+/// A file or directory inside an archive.
+#[derive(Debug, Clone)]
+struct Entry {
+    file_type: &'static str,
+    size: u64,
+    modified: Option<DateTime<Utc>>,
+}
+
+impl Entry {
+    fn dir() -> Entry {
+        Entry {
+            file_type: "Dir",
+            size: 0,
+            modified: None,
+        }
+    }
+
+    fn is_dir(&self) -> bool {
+        self.file_type == "Dir"
+    }
+}
+
+/// Splits a path that goes into an archive, like `logs.tar.gz/2020/today.json`, into the archive
+/// and the path inside it, which is `/` for the archive itself.
+pub(crate) fn split_path(path: &Path) -> Option<(PathBuf, String)> {
+    path.ancestors().find_map(|ancestor| {
+        Format::of(ancestor)?;
+        if !ancestor.is_file() {
+            return None;
+        }
+
+        let inner = path.strip_prefix(ancestor).ok()?;
+        let inner = normalize(&inner.to_string_lossy().replace('\\', "/"))?;
+
+        Some((ancestor.to_path_buf(), inner))
+    })
+}
+
+/// Resolves `.` and `..` in a path inside an archive, giving it from the root of the archive, or
+/// nothing when it leads out of the archive.
+fn normalize(path: &str) -> Option<String> {
+    let mut parts = vec![];
+
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop()?;
+            }
+            part => parts.push(part),
+        }
+    }
+
+    Some(format!("/{}", parts.join("/")))
+}
+
+/// The path of something inside an archive, as seen from outside, like `logs.zip/2020`.
+pub(crate) fn full_path(archive: &Path, inner: &str) -> String {
+    if inner == "/" {
+        archive.display().to_string()
+    } else {
+        format!("{}{}", archive.display(), inner)
+    }
+}
+
+fn join(dir: &str, path: &str) -> Option<String> {
+    if path.starts_with('/') {
+        normalize(path)
+    } else {
+        normalize(&format!("{}/{}", dir, path))
+    }
+}
+
+fn parent(path: &str) -> &str {
+    match path.rfind('/') {
+        Some(0) | None => "/",
+        Some(idx) => &path[..idx],
+    }
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or_default()
+}
+
+fn read_error(archive: &Path, e: impl std::fmt::Display, tag: &Tag) -> ShellError {
+    ShellError::labeled_error(
+        format!("Could not read the archive {}. {}", archive.display(), e),
+        e.to_string(),
+        tag,
+    )
+}
+
+fn open_tar(archive: &Path, format: Format) -> std::io::Result<tar::Archive<Box<dyn Read>>> {
+    let file = File::open(archive)?;
+
+    Ok(tar::Archive::new(if format == Format::TarGz {
+        Box::new(flate2::read::GzDecoder::new(file))
+    } else {
+        Box::new(file)
+    }))
+}
+
+/// Reads what's in an archive, by its path from the root of the archive. Directories that are
+/// only there as parts of paths are added too.
+fn read_entries(archive: &Path, tag: &Tag) -> Result<BTreeMap<String, Entry>, ShellError> {
+    let format = Format::of(archive).ok_or_else(|| read_error(archive, "Unknown format", tag))?;
+    let mut entries = BTreeMap::new();
+
+    if format == Format::Zip {
+        let file = File::open(archive).map_err(|e| read_error(archive, e, tag))?;
+        let mut zip = zip::ZipArchive::new(file).map_err(|e| read_error(archive, e, tag))?;
+
+        for idx in 0..zip.len() {
+            let file = zip.by_index(idx).map_err(|e| read_error(archive, e, tag))?;
+            let modified = file.last_modified();
+
+            if let Some(path) = normalize(file.name()) {
+                entries.insert(
+                    path,
+                    Entry {
+                        file_type: if file.is_dir() { "Dir" } else { "File" },
+                        size: file.size(),
+                        modified: Utc
+                            .ymd_opt(
+                                modified.year().into(),
+                                modified.month().into(),
+                                modified.day().into(),
+                            )
+                            .single()
+                            .and_then(|date| {
+                                date.and_hms_opt(
+                                    modified.hour().into(),
+                                    modified.minute().into(),
+                                    modified.second().into(),
+                                )
+                            }),
+                    },
+                );
+            }
+        }
+    } else {
+        let mut tar = open_tar(archive, format).map_err(|e| read_error(archive, e, tag))?;
+
+        for file in tar.entries().map_err(|e| read_error(archive, e, tag))? {
+            let file = file.map_err(|e| read_error(archive, e, tag))?;
+            let header = file.header();
+            let path = file.path().map_err(|e| read_error(archive, e, tag))?;
+
+            if let Some(path) = normalize(&path.to_string_lossy()) {
+                let entry_type = header.entry_type();
+
+                entries.insert(
+                    path,
+                    Entry {
+                        file_type: if entry_type.is_dir() {
+                            "Dir"
+                        } else if entry_type.is_symlink() {
+                            "Symlink"
+                        } else {
+                            "File"
+                        },
+                        size: header.size().unwrap_or_default(),
+                        modified: header
+                            .mtime()
+                            .ok()
+                            .map(|secs| Utc.timestamp(secs as i64, 0)),
+                    },
+                );
+            }
+        }
+    }
+
+    let implied = entries
+        .keys()
+        .flat_map(|path| {
+            let mut dirs = vec![];
+            let mut dir = parent(path);
+            while dir != "/" {
+                dirs.push(dir.to_string());
+                dir = parent(dir);
+            }
+            dirs
+        })
+        .collect::<Vec<_>>();
+
+    for dir in implied {
+        entries.entry(dir).or_insert_with(Entry::dir);
+    }
+    entries.remove("/");
+
+    Ok(entries)
+}
+
+/// Whether a path inside an archive is a directory, so it can be entered.
+pub(crate) fn is_dir(archive: &Path, inner: &str) -> bool {
+    inner == "/"
+        || read_entries(archive, &Tag::unknown())
+            .map(|entries| entries.get(inner).map(Entry::is_dir).unwrap_or(false))
+            .unwrap_or(false)
+}
+
+/// Reads a file from an archive.
+fn read_file(archive: &Path, inner: &str, tag: &Tag) -> Result<Vec<u8>, ShellError> {
+    let format = Format::of(archive).ok_or_else(|| read_error(archive, "Unknown format", tag))?;
+    let mut contents = vec![];
+
+    let found = if format == Format::Zip {
+        let file = File::open(archive).map_err(|e| read_error(archive, e, tag))?;
+        let mut zip = zip::ZipArchive::new(file).map_err(|e| read_error(archive, e, tag))?;
+
+        let idx = (0..zip.len()).find(|idx| {
+            zip.by_index(*idx)
+                .map(|file| !file.is_dir() && normalize(file.name()).as_deref() == Some(inner))
+                .unwrap_or(false)
+        });
+
+        match idx {
+            Some(idx) => {
+                zip.by_index(idx)
+                    .map_err(|e| read_error(archive, e, tag))?
+                    .read_to_end(&mut contents)
+                    .map_err(|e| read_error(archive, e, tag))?;
+                true
+            }
+            None => false,
+        }
+    } else {
+        let mut tar = open_tar(archive, format).map_err(|e| read_error(archive, e, tag))?;
+        let mut found = false;
+
+        for file in tar.entries().map_err(|e| read_error(archive, e, tag))? {
+            let mut file = file.map_err(|e| read_error(archive, e, tag))?;
+            let path = file.path().map_err(|e| read_error(archive, e, tag))?;
+
+            if file.header().entry_type().is_file()
+                && normalize(&path.to_string_lossy()).as_deref() == Some(inner)
+            {
+                file.read_to_end(&mut contents)
+                    .map_err(|e| read_error(archive, e, tag))?;
+                found = true;
+                break;
+            }
+        }
+
+        found
+    };
+
+    if found {
+        Ok(contents)
+    } else {
+        Err(ShellError::labeled_error(
+            format!("Could not find {} in {}", inner, archive.display()),
+            "file not found in the archive",
+            tag,
+        ))
+    }
+}
+
+/// Reads a file inside an archive for `open`.
+pub(crate) fn fetch(
+    archive: &Path,
+    inner: &str,
+    span: Span,
+) -> Result<(Option<String>, UntaggedValue, Tag), ShellError> {
+    let tag = Tag {
+        span,
+        anchor: Some(AnchorLocation::File(full_path(archive, inner))),
+    };
+
+    let bytes = read_file(archive, inner, &tag)?;
+
+    let extension = Path::new(file_name(inner))
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_string());
+
+    let contents = match String::from_utf8(bytes) {
+        Ok(text) => UntaggedValue::string(text),
+        Err(e) => UntaggedValue::binary(e.into_bytes()),
+    };
+
+    Ok((extension, contents, tag))
+}
+
+#[derive(Clone, Debug)]
+pub struct ArchiveShell {
+    archive: PathBuf,
+    path: String,
+    last_path: String,
+    entries: BTreeMap<String, Entry>,
+}
+
+impl ArchiveShell {
+    pub fn with_location(location: &str) -> Result<ArchiveShell, ShellError> {
+        let tag = Tag::unknown();
+        let (archive, path) = split_path(Path::new(location)).ok_or_else(|| {
+            ShellError::labeled_error(
+                "Not an archive",
+                "expected a .zip, .tar or .tar.gz file",
+                &tag,
+            )
+        })?;
+
+        let entries = read_entries(&archive, &tag)?;
+
+        if path != "/" && !entries.get(&path).map(Entry::is_dir).unwrap_or(false) {
+            return Err(ShellError::labeled_error(
+                format!(
+                    "Could not find the directory {} in {}",
+                    path,
+                    archive.display()
+                ),
+                "directory not found in the archive",
+                &tag,
+            ));
+        }
+
+        Ok(ArchiveShell {
+            archive,
+            last_path: path.clone(),
+            path,
+            entries,
+        })
+    }
+
+    fn full_path(&self, path: &str) -> String {
+        full_path(&self.archive, path)
+    }
+
+    fn is_dir(&self, path: &str) -> bool {
+        path == "/" || self.entries.get(path).map(Entry::is_dir).unwrap_or(false)
+    }
+
+    fn read_only(&self, command: &str, name: Tag) -> Result<OutputStream, ShellError> {
+        Err(ShellError::labeled_error(
+            format!("{} not currently supported in archives", command),
+            "archives are read-only",
+            name,
+        ))
+    }
+}
+
+impl Shell for ArchiveShell {
+    fn name(&self) -> String {
+        format!("archive {}", self.archive.display())
+    }
+
+    fn homedir(&self) -> Option<PathBuf> {
+        Some(PathBuf::from("/"))
+    }
+
+    fn ls(
+        &self,
+        LsArgs { path, all, .. }: LsArgs,
+        name_tag: Tag,
+        _ctrl_c: Arc<AtomicBool>,
+    ) -> Result<OutputStream, ShellError> {
+        let tag = path
+            .as_ref()
+            .map(|path| path.tag.clone())
+            .unwrap_or(name_tag);
+
+        let target = match &path {
+            Some(path) => join(&self.path, &path.item.to_string_lossy().replace('\\', "/"))
+                .ok_or_else(|| {
+                    ShellError::labeled_error(
+                        "Can not list outside of the archive",
+                        "use exit to leave the archive",
+                        &tag,
+                    )
+                })?,
+            None => self.path.clone(),
+        };
+
+        let (dir, pattern) = if file_name(&target).contains(&['*', '?', '['][..]) {
+            let pattern = Pattern::new(file_name(&target))
+                .map_err(|e| ShellError::labeled_error("Invalid glob pattern", e.msg, &tag))?;
+            (parent(&target).to_string(), Some(pattern))
+        } else {
+            (target, None)
+        };
+
+        let listed = if self.is_dir(&dir) {
+            self.entries
+                .iter()
+                .filter(|(path, _)| parent(path) == dir)
+                .filter(|(path, _)| match &pattern {
+                    Some(pattern) => pattern.matches(file_name(path)),
+                    None => all || !file_name(path).starts_with('.'),
+                })
+                .collect::<Vec<_>>()
+        } else {
+            match self.entries.get_key_value(&dir) {
+                Some(entry) => vec![entry],
+                None => {
+                    return Err(ShellError::labeled_error(
+                        "No matches found",
+                        "no matches found in the archive",
+                        &tag,
+                    ))
+                }
+            }
+        };
+
+        let rows = listed
+            .into_iter()
+            .map(|(path, entry)| {
+                let mut dict = TaggedDictBuilder::new(&tag);
+                dict.insert_untagged("name", UntaggedValue::string(file_name(path)));
+                dict.insert_untagged("type", UntaggedValue::string(entry.file_type));
+                dict.insert_untagged(
+                    "size",
+                    if entry.is_dir() {
+                        UntaggedValue::nothing()
+                    } else {
+                        UntaggedValue::bytes(entry.size)
+                    },
+                );
+                dict.insert_untagged(
+                    "modified",
+                    entry
+                        .modified
+                        .map(UntaggedValue::date)
+                        .unwrap_or_else(UntaggedValue::nothing),
+                );
+
+                ReturnSuccess::value(dict.into_value())
+            })
+            .collect::<Vec<_>>();
+
+        Ok(futures::stream::iter(rows).to_output_stream())
+    }
+
+    fn cd(&self, args: CdArgs, name: Tag) -> Result<OutputStream, ShellError> {
+        let tag = args
+            .path
+            .as_ref()
+            .map(|path| path.tag.clone())
+            .unwrap_or(name);
+
+        let target = match &args.path {
+            None => Some("/".to_string()),
+            Some(path) if path.item == Path::new("-") => Some(self.last_path.clone()),
+            Some(path) => join(&self.path, &path.item.to_string_lossy().replace('\\', "/")),
+        };
+
+        match target {
+            Some(target) if self.is_dir(&target) => {
+                let mut stream = VecDeque::new();
+                stream.push_back(ReturnSuccess::change_cwd(self.full_path(&target)));
+                Ok(stream.into())
+            }
+            Some(_) => Err(ShellError::labeled_error(
+                "Can not change to directory",
+                "directory not found in the archive",
+                &tag,
+            )),
+            None => Err(ShellError::labeled_error(
+                "Can not change to directory",
+                "use exit to leave the archive",
+                &tag,
+            )),
+        }
+    }
+
+    fn cp(&self, _args: CopyArgs, name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
+        self.read_only("cp", name)
+    }
+
+    fn mv(&self, _args: MoveArgs, name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
+        self.read_only("mv", name)
+    }
+
+    fn ln(&self, _args: LinkArgs, name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
+        self.read_only("ln", name)
+    }
+
+    fn mkdir(&self, _args: MkdirArgs, name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
+        self.read_only("mkdir", name)
+    }
+
+    fn rm(&self, _args: RemoveArgs, name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
+        self.read_only("rm", name)
+    }
+
+    fn path(&self) -> String {
+        self.full_path(&self.path)
+    }
+
+    fn pwd(&self, args: EvaluatedWholeStreamCommandArgs) -> Result<OutputStream, ShellError> {
+        let mut stream = VecDeque::new();
+        stream.push_back(ReturnSuccess::value(
+            UntaggedValue::string(self.path()).into_value(&args.call_info.name_tag),
+        ));
+        Ok(stream.into())
+    }
+
+    fn set_path(&mut self, path: String) {
+        if let Some((archive, inner)) = split_path(Path::new(&path)) {
+            if archive == self.archive {
+                self.last_path = std::mem::replace(&mut self.path, inner);
+            }
+        }
+    }
+
+    fn complete(
+        &self,
+        _line: &str,
+        pos: usize,
+        _ctx: &rustyline::Context<'_>,
+    ) -> Result<(usize, Vec<rustyline::completion::Pair>), rustyline::error::ReadlineError> {
+        Ok((pos, vec![]))
+    }
+
+    fn hint(&self, _line: &str, _pos: usize, _ctx: &rustyline::Context<'_>) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{join, normalize, parent, Format};
+    use std::path::Path;
+
+    #[test]
+    fn knows_archives_by_their_extension() {
+        assert_eq!(Format::of(Path::new("logs.zip")), Some(Format::Zip));
+        assert_eq!(Format::of(Path::new("src.TAR.GZ")), Some(Format::TarGz));
+        assert_eq!(Format::of(Path::new("src.tgz")), Some(Format::TarGz));
+        assert_eq!(Format::of(Path::new("src.tar")), Some(Format::Tar));
+        assert_eq!(Format::of(Path::new("notes.txt")), None);
+    }
+
+    #[test]
+    fn moves_around_inside_archives() {
+        assert_eq!(normalize("./2020/logs/"), Some("/2020/logs".to_string()));
+        assert_eq!(normalize(""), Some("/".to_string()));
+        assert_eq!(normalize("2020/../.."), None);
+        assert_eq!(
+            join("/2020", "../2021/today.json"),
+            Some("/2021/today.json".to_string())
+        );
+        assert_eq!(join("/2020", "/docs"), Some("/docs".to_string()));
+        assert_eq!(parent("/2020/logs"), "/2020");
+        assert_eq!(parent("/2020"), "/");
+    }
+}
@@ -7,7 +7,8 @@ use derive_new::new;
 use ichwh::IchwhError;
 use ichwh::IchwhResult;
 use indexmap::set::IndexSet;
-use rustyline::completion::{Completer, FilenameCompleter};
+use nu_protocol::{UntaggedValue, Value};
+use rustyline::completion::{Completer, FilenameCompleter, Pair};
 use std::fs::{read_dir, DirEntry};
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
@@ -276,3 +277,283 @@ impl NuCompleter {
         Some(executables)
     }
 }
+
+/// The characters, besides whitespace, that a word being completed starts after.
+const WORD_BOUNDARIES: &[char] = &['|', ';', '(', '[', '{'];
+
+/// Completions that don't depend on the shell the line is written in: the variables, the
+/// subcommands of a command, and what the custom completers of the config give for its arguments.
+/// `None` leaves the line to the completer of the shell, for command names, flags and paths.
+pub(crate) fn complete_in_context(
+    context: &Context,
+    line: &str,
+    pos: usize,
+) -> Option<(usize, Vec<Pair>)> {
+    let before = line.get(..pos)?;
+    let start = before
+        .char_indices()
+        .rev()
+        .find(|(_, c)| c.is_whitespace() || WORD_BOUNDARIES.contains(c))
+        .map_or(0, |(index, c)| index + c.len_utf8());
+    let word = &before[start..];
+
+    if word.starts_with('$') {
+        let completions = complete_variable(context, word);
+        return if completions.is_empty() {
+            None
+        } else {
+            Some((start, completions))
+        };
+    }
+
+    if word.starts_with('-') || before.trim_end().ends_with(WORD_BOUNDARIES) {
+        return None;
+    }
+
+    // The words of the command the cursor is in, up to the one being completed
+    let lite_block = nu_parser::lite_parse_lines(before, 0).ok()?;
+    let command = lite_block.block.last()?.commands.last()?;
+    let mut words: Vec<&str> = std::iter::once(&command.name)
+        .chain(command.args.iter())
+        .map(|word| word.item.as_str())
+        .collect();
+    let start = if word.is_empty() {
+        pos
+    } else {
+        let last = command.args.last()?;
+        words.pop();
+        last.span.start()
+    };
+    let word = &before[start..];
+
+    let subcommands = complete_subcommand(context, &words, word);
+    if !subcommands.is_empty() {
+        return Some((start, subcommands));
+    }
+
+    let completions = complete_with_config(context, &words, before, word);
+    if completions.is_empty() {
+        None
+    } else {
+        Some((start, completions))
+    }
+}
+
+/// Completes the names of variables, and the columns of the rows they have, like
+/// `$nu.env.HOME`.
+fn complete_variable(context: &Context, word: &str) -> Vec<Pair> {
+    let mut members: Vec<&str> = word.split('.').collect();
+    let partial = members.pop().unwrap_or("");
+
+    if members.is_empty() {
+        let mut names: IndexSet<String> = ["$it", "$nu", "$true", "$false"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        names.extend(
+            context
+                .vars
+                .keys()
+                .filter(|name| name.starts_with('$'))
+                .cloned(),
+        );
+
+        return names
+            .into_iter()
+            .filter(|name| name.starts_with(partial))
+            .map(|name| Pair {
+                display: name.clone(),
+                replacement: name,
+            })
+            .collect();
+    }
+
+    let mut value = match members[0] {
+        "$nu" => crate::evaluate::variables::nu(&context.get_env(), Tag::unknown()).ok(),
+        name => context.vars.get(name).cloned(),
+    };
+    for member in &members[1..] {
+        value = value.and_then(|value| column(value, member));
+    }
+
+    match value {
+        Some(Value {
+            value: UntaggedValue::Row(row),
+            ..
+        }) => row
+            .entries
+            .keys()
+            .filter(|key| key.starts_with(partial))
+            .map(|key| Pair {
+                display: key.clone(),
+                replacement: format!("{}.{}", members.join("."), key),
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn column(value: Value, name: &str) -> Option<Value> {
+    match value.value {
+        UntaggedValue::Row(mut row) => row.entries.shift_remove(name),
+        _ => None,
+    }
+}
+
+/// Completes the subcommands of a command, like `str length`.
+fn complete_subcommand(context: &Context, words: &[&str], word: &str) -> Vec<Pair> {
+    let prefix = format!("{} ", words.join(" "));
+
+    let mut subcommands: Vec<String> = context
+        .registry
+        .names()
+        .iter()
+        .filter_map(|name| name.strip_prefix(&prefix))
+        .filter(|name| !name.contains(' ') && name.starts_with(word))
+        .map(|name| name.to_string())
+        .collect();
+    subcommands.sort();
+
+    subcommands
+        .into_iter()
+        .map(|name| Pair {
+            display: name.clone(),
+            replacement: name,
+        })
+        .collect()
+}
+
+/// Completes the arguments of a command with the custom completer of the config for it, if there
+/// is one. The completer is nu code in the `completions` setting, under the name of the command
+/// with the words it starts with, like `git checkout`. It is given the line up to the cursor as
+/// `$line`, and the values it outputs that start with the word being completed are the completions.
+fn complete_with_config(context: &Context, words: &[&str], line: &str, word: &str) -> Vec<Pair> {
+    let completers = match config::config(Tag::unknown())
+        .ok()
+        .and_then(|config| config.get("completions").cloned())
+    {
+        Some(Value {
+            value: UntaggedValue::Row(row),
+            ..
+        }) => row.entries,
+        _ => return vec![],
+    };
+
+    // The completer for the most words of the command is used
+    let code = completers
+        .iter()
+        .filter(|(name, _)| {
+            let names: Vec<&str> = name.split_whitespace().collect();
+            !names.is_empty() && words.starts_with(&names)
+        })
+        .max_by_key(|(name, _)| name.split_whitespace().count())
+        .and_then(|(_, code)| code.as_string().ok());
+    let code = match code {
+        Some(code) => code,
+        None => return vec![],
+    };
+
+    let mut vars = IndexMap::new();
+    vars.insert(
+        "$line".to_string(),
+        UntaggedValue::string(line).into_untagged_value(),
+    );
+
+    // The line editor is run by the executor of the whole session, so the completer gets one of
+    // its own
+    let mut context = context.clone();
+    let values = std::thread::spawn(move || {
+        futures::executor::block_on(crate::cli::evaluate_config_code(&code, &mut context, vars))
+    })
+    .join();
+
+    let values = match values {
+        Ok(Ok(values)) => values,
+        _ => return vec![],
+    };
+
+    let mut completions: IndexSet<String> = IndexSet::new();
+    for value in values {
+        if let Ok(text) = value.as_string() {
+            let text = text.trim().to_string();
+            if !text.is_empty() && text.starts_with(word) {
+                completions.insert(text);
+            }
+        }
+    }
+
+    completions
+        .into_iter()
+        .map(|text| Pair {
+            display: text.clone(),
+            replacement: if text.contains(' ') {
+                format!("\"{}\"", text)
+            } else {
+                text
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::complete_in_context;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::Tag;
+
+    fn complete(line: &str) -> Option<(usize, Vec<String>)> {
+        let mut context =
+            crate::cli::create_default_context(&mut crate::EnvironmentSyncer::new(), false)
+                .expect("could not make a context");
+        let mut row = indexmap::IndexMap::new();
+        row.insert("name".to_string(), Value::from("nu"));
+        row.insert(
+            "size".to_string(),
+            UntaggedValue::int(3).into_untagged_value(),
+        );
+        context.vars.insert(
+            "$package".to_string(),
+            UntaggedValue::row(row).into_value(Tag::unknown()),
+        );
+
+        complete_in_context(&context, line, line.len()).map(|(start, completions)| {
+            (
+                start,
+                completions
+                    .into_iter()
+                    .map(|completion| completion.replacement)
+                    .collect(),
+            )
+        })
+    }
+
+    #[test]
+    fn completes_variable_names() {
+        assert_eq!(
+            complete("echo $pa"),
+            Some((5, vec!["$package".to_string()]))
+        );
+    }
+
+    #[test]
+    fn completes_the_columns_of_variables() {
+        assert_eq!(
+            complete("echo $package.n"),
+            Some((5, vec!["$package.name".to_string()]))
+        );
+    }
+
+    #[test]
+    fn completes_subcommands() {
+        assert_eq!(
+            complete("ls | str up"),
+            Some((9, vec!["upcase".to_string()]))
+        );
+    }
+
+    #[test]
+    fn leaves_flags_and_paths_to_the_shell() {
+        assert_eq!(complete("ls --a"), None);
+        assert_eq!(complete("open Carg"), None);
+    }
+}
@@ -1,12 +1,13 @@
 use crate::commands::cd::CdArgs;
 use crate::commands::command::EvaluatedWholeStreamCommandArgs;
 use crate::commands::cp::CopyArgs;
+use crate::commands::ln::LinkArgs;
 use crate::commands::ls::LsArgs;
 use crate::commands::mkdir::MkdirArgs;
 use crate::commands::mv::MoveArgs;
-use crate::commands::rm::RemoveArgs;
+use crate::commands::rm::{always_trash, RemoveArgs};
 use crate::data::dir_entry_dict;
-use crate::path::canonicalize;
+use crate::path::{absolutize, canonicalize, relative};
 use crate::prelude::*;
 use crate::shell::completer::NuCompleter;
 use crate::shell::shell::Shell;
@@ -22,7 +23,7 @@ use std::os::unix::fs::PermissionsExt;
 
 use nu_errors::ShellError;
 use nu_parser::expand_ndots;
-use nu_protocol::{Primitive, ReturnSuccess, UntaggedValue};
+use nu_protocol::{Primitive, ReturnSuccess, TaggedDictBuilder, UntaggedValue, Value};
 use nu_source::Tagged;
 
 pub struct FilesystemShell {
@@ -108,6 +109,7 @@ impl Shell for FilesystemShell {
             short_names,
             with_symlink_targets,
             du,
+            long,
         }: LsArgs,
         name_tag: Tag,
         ctrl_c: Arc<AtomicBool>,
@@ -173,7 +175,8 @@ impl Shell for FilesystemShell {
                 &path,
                 metadata.as_ref(),
                 name_tag.clone(),
-                full,
+                full || long,
+                long,
                 short_names,
                 with_symlink_targets,
                 du,
@@ -264,6 +267,11 @@ impl Shell for FilesystemShell {
             src,
             dst,
             recursive,
+            no_dereference,
+            preserve,
+            interactive,
+            no_clobber,
+            progress,
         }: CopyArgs,
         name: Tag,
         path: &str,
@@ -314,32 +322,27 @@ impl Shell for FilesystemShell {
             ));
         }
 
+        let mut steps = vec![];
+        let mut finishing_steps = vec![];
+
         for entry in sources {
             if let Ok(entry) = entry {
-                let mut sources = FileStructure::new();
-                sources.walk_decorate(&entry)?;
-
-                if entry.is_file() {
-                    let sources = sources.paths_applying_with(|(source_file, _depth_level)| {
-                        if destination.is_dir() {
-                            let mut dest = canonicalize(&path, &dst.item)?;
-                            if let Some(name) = entry.file_name() {
-                                dest.push(name);
-                            }
-                            Ok((source_file, dest))
-                        } else {
-                            Ok((source_file, destination.clone()))
+                if !entry.is_dir() || (no_dereference && is_symlink(&entry)) {
+                    let dest = if destination.is_dir() {
+                        let mut dest = canonicalize(path, &dst.item)?;
+                        if let Some(name) = entry.file_name() {
+                            dest.push(name);
                         }
-                    })?;
+                        dest
+                    } else {
+                        destination.clone()
+                    };
+
+                    steps.push(CopyStep::Copy(entry, dest));
+                } else {
+                    let mut sources = FileStructure::new();
+                    sources.walk_decorate(&entry)?;
 
-                    for (src, dst) in sources {
-                        if src.is_file() {
-                            std::fs::copy(src, dst).map_err(|e| {
-                                ShellError::labeled_error(e.to_string(), e.to_string(), &name_tag)
-                            })?;
-                        }
-                    }
-                } else if entry.is_dir() {
                     let destination = if !destination.exists() {
                         destination.clone()
                     } else {
@@ -361,7 +364,11 @@ impl Shell for FilesystemShell {
 
                     let sources = sources.paths_applying_with(|(source_file, depth_level)| {
                         let mut dest = destination.clone();
-                        let path = canonicalize(&path, &source_file)?;
+                        // Only the parent is resolved, so symlinks keep their own names.
+                        let path = match (source_file.parent(), source_file.file_name()) {
+                            (Some(parent), Some(name)) => canonicalize(path, parent)?.join(name),
+                            _ => canonicalize(path, &source_file)?,
+                        };
 
                         let comps: Vec<_> = path
                             .components()
@@ -377,24 +384,154 @@ impl Shell for FilesystemShell {
                         Ok((PathBuf::from(&source_file), dest))
                     })?;
 
-                    let dst_tag = &dst.tag;
+                    // Symlinked directories are copied as links, so nothing inside them is.
+                    let mut links: Vec<PathBuf> = vec![];
+
                     for (src, dst) in sources {
-                        if src.is_dir() && !dst.exists() {
-                            std::fs::create_dir_all(&dst).map_err(|e| {
-                                ShellError::labeled_error(e.to_string(), e.to_string(), dst_tag)
-                            })?;
+                        if links.iter().any(|link| src.starts_with(link)) {
+                            continue;
                         }
 
-                        if src.is_file() {
-                            std::fs::copy(&src, &dst).map_err(|e| {
-                                ShellError::labeled_error(e.to_string(), e.to_string(), &name_tag)
-                            })?;
+                        if no_dereference && is_symlink(&src) {
+                            links.push(src.clone());
+                            steps.push(CopyStep::Copy(src, dst));
+                        } else if src.is_dir() {
+                            if preserve {
+                                finishing_steps.push(CopyStep::Preserve(src.clone(), dst.clone()));
+                            }
+                            steps.push(CopyStep::CreateDir(src, dst));
+                        } else {
+                            steps.push(CopyStep::Copy(src, dst));
                         }
                     }
+
+                    if preserve {
+                        finishing_steps.push(CopyStep::Preserve(entry, destination));
+                    }
                 }
             }
         }
 
+        // Directories get their times back once everything inside them is copied, deepest first.
+        steps.extend(finishing_steps.into_iter().rev());
+
+        let options = CopyOptions {
+            no_dereference,
+            preserve,
+            interactive,
+            no_clobber,
+        };
+
+        if progress {
+            return Ok(
+                futures::stream::iter(steps.into_iter().filter_map(move |step| {
+                    copy_step(step, &options, &name_tag)
+                        .map(|row| row.map(ReturnSuccess::Value))
+                        .transpose()
+                }))
+                .to_output_stream(),
+            );
+        }
+
+        for step in steps {
+            copy_step(step, &options, &name_tag)?;
+        }
+
+        Ok(OutputStream::empty())
+    }
+
+    fn ln(
+        &self,
+        LinkArgs {
+            target,
+            link,
+            hard,
+            relative: make_relative,
+            force,
+            backup,
+        }: LinkArgs,
+        name: Tag,
+        path: &str,
+    ) -> Result<OutputStream, ShellError> {
+        let path = Path::new(path);
+
+        let (link_path, link_tag) = match link {
+            Some(link) => (absolutize(path, &link.item), link.tag),
+            None => (path.to_path_buf(), name.clone()),
+        };
+
+        // Like cp, a link made in a directory takes the target's name.
+        let link_path = if link_path.is_dir() && !is_symlink(&link_path) {
+            match target.item.file_name() {
+                Some(file_name) => link_path.join(file_name),
+                None => {
+                    return Err(ShellError::labeled_error(
+                        "Cannot name the link after the target",
+                        "give the link a name",
+                        target.tag,
+                    ))
+                }
+            }
+        } else {
+            link_path
+        };
+
+        let link_dir = link_path.parent().unwrap_or(path).to_path_buf();
+
+        if hard && make_relative {
+            return Err(ShellError::labeled_error(
+                "Only symbolic links can be relative",
+                "can't be used with --hard",
+                name,
+            ));
+        }
+
+        if std::fs::symlink_metadata(&link_path).is_ok() {
+            if backup {
+                let mut backup_path = link_path.clone().into_os_string();
+                backup_path.push("~");
+                std::fs::rename(&link_path, &backup_path).map_err(|e| {
+                    ShellError::labeled_error(e.to_string(), "could not back up", &link_tag)
+                })?;
+            } else if force {
+                if link_path.is_dir() && !is_symlink(&link_path) {
+                    return Err(ShellError::labeled_error(
+                        "Cannot replace a directory with a link",
+                        "is a directory",
+                        link_tag,
+                    ));
+                }
+                std::fs::remove_file(&link_path).map_err(|e| {
+                    ShellError::labeled_error(e.to_string(), "could not replace", &link_tag)
+                })?;
+            } else {
+                return Err(ShellError::labeled_error(
+                    format!("{:?} already exists", link_path),
+                    "already exists, use --force or --backup to replace it",
+                    link_tag,
+                ));
+            }
+        }
+
+        let result = if hard {
+            std::fs::hard_link(absolutize(path, &target.item), &link_path)
+        } else if make_relative {
+            let target_path =
+                canonicalize(path, &target.item).unwrap_or_else(|_| absolutize(path, &target.item));
+            let link_dir = canonicalize(path, &link_dir).unwrap_or(link_dir);
+            create_symlink(&relative(link_dir, target_path), &link_path)
+        } else {
+            create_symlink(&target.item, &link_path)
+        };
+
+        result.map_err(|e| {
+            ShellError::labeled_error(
+                format!("Could not link {:?} to {:?}. {}", link_path, target.item, e),
+                e.to_string(),
+                target.tag,
+            )
+        })?;
+
         Ok(OutputStream::empty())
     }
 
@@ -496,14 +633,28 @@ impl Shell for FilesystemShell {
         RemoveArgs {
             rest: targets,
             recursive,
-            trash: _trash,
-            permanent: _permanent,
+            trash,
+            permanent,
         }: RemoveArgs,
         name: Tag,
         path: &str,
     ) -> Result<OutputStream, ShellError> {
         let name_tag = name;
 
+        // rm_always_trash only applies when nu can move files to the trash, `rm` warns about it
+        // otherwise.
+        let use_trash =
+            trash.item || (cfg!(feature = "trash-support") && !permanent.item && always_trash());
+
+        // Deleting permanently when the trash was asked for can't be undone, so refuse instead.
+        if use_trash && cfg!(not(feature = "trash-support")) {
+            return Err(ShellError::labeled_error(
+                "Cannot move files to the trash",
+                "this nu is built without the trash-support feature, use --permanent to delete",
+                name_tag,
+            ));
+        }
+
         if targets.is_empty() {
             return Err(ShellError::labeled_error(
                 "rm requires target paths",
@@ -582,33 +733,42 @@ impl Shell for FilesystemShell {
                         let result;
                         #[cfg(feature = "trash-support")]
                         {
-                            let rm_always_trash = config::config(Tag::unknown())?
-                                .get("rm_always_trash")
-                                .map(|val| val.is_true())
-                                .unwrap_or(false);
-                            result = if _trash.item || (rm_always_trash && !_permanent.item) {
-                                trash::remove(&f).map_err(|_| f.to_string_lossy())
+                            result = if use_trash {
+                                trash::remove(&f)
+                                    .map(|_| "moved to the trash")
+                                    .map_err(|_| f.to_string_lossy())
                             } else if metadata.is_file() {
-                                std::fs::remove_file(&f).map_err(|_| f.to_string_lossy())
+                                std::fs::remove_file(&f)
+                                    .map(|_| "deleted")
+                                    .map_err(|_| f.to_string_lossy())
                             } else {
-                                std::fs::remove_dir_all(&f).map_err(|_| f.to_string_lossy())
+                                std::fs::remove_dir_all(&f)
+                                    .map(|_| "deleted")
+                                    .map_err(|_| f.to_string_lossy())
                             };
                         }
                         #[cfg(not(feature = "trash-support"))]
                         {
                             result = if metadata.is_file() {
-                                std::fs::remove_file(&f).map_err(|_| f.to_string_lossy())
+                                std::fs::remove_file(&f)
+                                    .map(|_| "deleted")
+                                    .map_err(|_| f.to_string_lossy())
                             } else {
-                                std::fs::remove_dir_all(&f).map_err(|_| f.to_string_lossy())
+                                std::fs::remove_dir_all(&f)
+                                    .map(|_| "deleted")
+                                    .map_err(|_| f.to_string_lossy())
                             };
                         }
 
-                        if let Err(e) = result {
-                            let msg = format!("Could not delete {:}", e);
-                            Err(ShellError::labeled_error(msg, e, tag))
-                        } else {
-                            let val = format!("deleted {:}", f.to_string_lossy()).into();
-                            Ok(ReturnSuccess::Value(val))
+                        match result {
+                            Err(e) => {
+                                let msg = format!("Could not delete {:}", e);
+                                Err(ShellError::labeled_error(msg, e, tag))
+                            }
+                            Ok(done) => {
+                                let val = format!("{} {:}", done, f.to_string_lossy()).into();
+                                Ok(ReturnSuccess::Value(val))
+                            }
                         }
                     } else {
                         let msg =
@@ -766,6 +926,137 @@ fn move_file(from: TaggedPathBuf, to: TaggedPathBuf) -> Result<(), ShellError> {
         })
 }
 
+enum CopyStep {
+    Copy(PathBuf, PathBuf),
+    CreateDir(PathBuf, PathBuf),
+    Preserve(PathBuf, PathBuf),
+}
+
+struct CopyOptions {
+    no_dereference: bool,
+    preserve: bool,
+    interactive: bool,
+    no_clobber: bool,
+}
+
+/// Carries out a step of a copy, giving a row about each file or link for `cp --progress`.
+fn copy_step(
+    step: CopyStep,
+    options: &CopyOptions,
+    tag: &Tag,
+) -> Result<Option<Value>, ShellError> {
+    let fail = |e: std::io::Error, path: &Path| {
+        ShellError::labeled_error(
+            format!("Could not copy {:?}. {}", path, e),
+            e.to_string(),
+            tag,
+        )
+    };
+
+    let (src, dst) = match step {
+        CopyStep::CreateDir(src, dst) => {
+            if !dst.exists() {
+                std::fs::create_dir_all(&dst).map_err(|e| fail(e, &src))?;
+            }
+            return Ok(None);
+        }
+        CopyStep::Preserve(src, dst) => {
+            preserve_attributes(&src, &dst).map_err(|e| fail(e, &src))?;
+            return Ok(None);
+        }
+        CopyStep::Copy(src, dst) => (src, dst),
+    };
+
+    let link = options.no_dereference && is_symlink(&src);
+
+    if !link && !src.is_file() {
+        return Ok(None);
+    }
+
+    let exists = std::fs::symlink_metadata(&dst).is_ok();
+    let skip = exists
+        && (options.no_clobber
+            || (options.interactive && !confirm(&format!("overwrite {:?}?", dst))));
+
+    let (status, size) = if skip {
+        ("skipped", 0)
+    } else if link {
+        if exists {
+            std::fs::remove_file(&dst).map_err(|e| fail(e, &dst))?;
+        }
+        copy_symlink(&src, &dst).map_err(|e| fail(e, &src))?;
+        ("linked", 0)
+    } else {
+        let size = std::fs::copy(&src, &dst).map_err(|e| fail(e, &src))?;
+        if options.preserve {
+            preserve_attributes(&src, &dst).map_err(|e| fail(e, &src))?;
+        }
+        ("copied", size)
+    };
+
+    let mut row = TaggedDictBuilder::new(tag);
+    row.insert_untagged("source", UntaggedValue::path(src));
+    row.insert_untagged("destination", UntaggedValue::path(dst));
+    row.insert_untagged("size", UntaggedValue::bytes(size));
+    row.insert_untagged("status", UntaggedValue::string(status));
+
+    Ok(Some(row.into_value()))
+}
+
+fn is_symlink(path: &Path) -> bool {
+    std::fs::symlink_metadata(path)
+        .map(|md| md.file_type().is_symlink())
+        .unwrap_or(false)
+}
+
+fn copy_symlink(src: &Path, dst: &Path) -> std::io::Result<()> {
+    create_symlink(&std::fs::read_link(src)?, dst)
+}
+
+/// Creates a symbolic link. The target is relative to the link's directory, as it's kept as is.
+fn create_symlink(target: &Path, link: &Path) -> std::io::Result<()> {
+    cfg_if::cfg_if! {
+        if #[cfg(windows)] {
+            let points_to_dir = link
+                .parent()
+                .map_or_else(|| target.is_dir(), |dir| dir.join(target).is_dir());
+
+            if points_to_dir {
+                std::os::windows::fs::symlink_dir(target, link)
+            } else {
+                std::os::windows::fs::symlink_file(target, link)
+            }
+        } else {
+            std::os::unix::fs::symlink(target, link)
+        }
+    }
+}
+
+/// Gives the copy the permissions and the access and modification times of the original.
+fn preserve_attributes(src: &Path, dst: &Path) -> std::io::Result<()> {
+    let metadata = std::fs::metadata(src)?;
+    let times = std::fs::FileTimes::new()
+        .set_accessed(metadata.accessed()?)
+        .set_modified(metadata.modified()?);
+
+    std::fs::File::open(dst)?.set_times(times)?;
+    std::fs::set_permissions(dst, metadata.permissions())
+}
+
+/// Asks a yes or no question, for `--interactive` commands.
+fn confirm(question: &str) -> bool {
+    use std::io::Write;
+
+    print!("{} (y/n) ", question);
+    let _ = std::io::stdout().flush();
+
+    let mut answer = String::new();
+    match std::io::stdin().read_line(&mut answer) {
+        Ok(_) => matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"),
+        Err(_) => false,
+    }
+}
+
 fn is_empty_dir(dir: impl AsRef<Path>) -> bool {
     match dir.as_ref().read_dir() {
         Err(_) => true,
@@ -1,6 +1,7 @@
 use crate::commands::cd::CdArgs;
 use crate::commands::command::EvaluatedWholeStreamCommandArgs;
 use crate::commands::cp::CopyArgs;
+use crate::commands::ln::LinkArgs;
 use crate::commands::ls::LsArgs;
 use crate::commands::mkdir::MkdirArgs;
 use crate::commands::mv::MoveArgs;
@@ -189,6 +190,10 @@ impl Shell for HelpShell {
         Ok(OutputStream::empty())
     }
 
+    fn ln(&self, _args: LinkArgs, _name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
+        Ok(OutputStream::empty())
+    }
+
     fn mkdir(&self, _args: MkdirArgs, _name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
         Ok(OutputStream::empty())
     }
@@ -1,14 +1,19 @@
+use crate::commands::classified::external::did_find_command;
 use crate::context::Context;
+use crate::data::history;
+use crate::shell::completer::complete_in_context;
 use crate::shell::palette::{DefaultPalette, Palette};
 use ansi_term::{Color, Style};
+use nu_errors::{ArgumentError, ParseError, ParseErrorReason};
 use nu_parser::SignatureRegistry;
 use nu_protocol::hir::FlatShape;
-use nu_source::{Spanned, Tag, Tagged};
+use nu_source::{Span, Spanned, SpannedItem, Tag, Tagged};
 use rustyline::completion::Completer;
 use rustyline::error::ReadlineError;
 use rustyline::highlight::Highlighter;
 use rustyline::hint::Hinter;
 use std::borrow::Cow::{self, Owned};
+use std::path::Path;
 
 pub(crate) struct Helper {
     context: Context,
@@ -32,12 +37,24 @@ impl Completer for Helper {
         pos: usize,
         ctx: &rustyline::Context<'_>,
     ) -> Result<(usize, Vec<rustyline::completion::Pair>), ReadlineError> {
+        if let Some(completions) = complete_in_context(&self.context, line, pos) {
+            return Ok(completions);
+        }
+
         self.context.shell_manager.complete(line, pos, ctx)
     }
 }
 
 impl Hinter for Helper {
     fn hint(&self, line: &str, pos: usize, ctx: &rustyline::Context<'_>) -> Option<String> {
+        // While searching the history, the hint is the command Enter would give
+        if line.contains(history::FUZZY_SEARCH) {
+            return Some(match history::fuzzy_search(ctx.history().iter(), line) {
+                Some(command) => format!("  (history) {}", command),
+                None => "  (no command in the history matches)".to_string(),
+            });
+        }
+
         self.context.shell_manager.hint(line, pos, ctx)
     }
 }
@@ -62,6 +79,10 @@ impl Highlighter for Helper {
     }
 
     fn highlight<'l>(&self, line: &'l str, _pos: usize) -> Cow<'l, str> {
+        if line.contains(history::FUZZY_SEARCH) {
+            return Owned(line.replace(history::FUZZY_SEARCH, ""));
+        }
+
         Painter::paint_string(
             line,
             &self.context.registry().clone_box(),
@@ -106,14 +127,29 @@ impl Painter {
         registry: &dyn SignatureRegistry,
         palette: &P,
     ) -> Cow<'l, str> {
-        let lite_block = nu_parser::lite_parse(line, 0);
+        let lite_block = nu_parser::lite_parse_lines(line, 0);
 
         match lite_block {
             Err(_) => Cow::Borrowed(line),
             Ok(lb) => {
                 let classified = nu_parser::classify_block(&lb, registry);
 
-                let shapes = nu_parser::shapes(&classified.block);
+                let mut shapes = nu_parser::shapes(&classified.block);
+
+                // External commands that can't be found are shown as errors, like they'd fail
+                for shape in shapes.iter_mut() {
+                    if let FlatShape::ExternalCommand = shape.item {
+                        let name = line.get(shape.span.start()..shape.span.end()).unwrap_or("");
+                        if !external_exists(name) {
+                            shape.item = FlatShape::Garbage;
+                        }
+                    }
+                }
+
+                if let Some(span) = classified.failed.as_ref().and_then(error_span) {
+                    shapes.push(FlatShape::Garbage.spanned(span));
+                }
+
                 let mut painter = Painter::new(line);
 
                 for shape in shapes {
@@ -133,7 +169,8 @@ impl Painter {
     }
 
     fn paint(&mut self, styled_span: &Spanned<Style>) {
-        for pos in styled_span.span.start()..styled_span.span.end() {
+        let end = std::cmp::min(styled_span.span.end(), self.styles.len());
+        for pos in styled_span.span.start()..end {
             self.styles[pos] = styled_span.item;
         }
     }
@@ -172,8 +209,80 @@ impl Painter {
     }
 }
 
+/// Whether an external command can be run, as a program on the path, or as a directory to change
+/// to.
+fn external_exists(name: &str) -> bool {
+    let name = name
+        .trim_start_matches('^')
+        .trim_matches(|c| c == '"' || c == '\'' || c == '`');
+    let name = shellexpand::tilde(name);
+
+    did_find_command(&name) || Path::new(name.as_ref()).is_dir()
+}
+
+/// The span of the part of the line a parse error is about, when it points at what was written
+/// rather than at what is missing, which may still be typed.
+fn error_span(error: &ParseError) -> Option<Span> {
+    match error.reason() {
+        ParseErrorReason::ExtraTokens { actual } | ParseErrorReason::Mismatch { actual, .. } => {
+            Some(actual.span)
+        }
+        ParseErrorReason::ArgumentError {
+            error: ArgumentError::UnexpectedArgument(argument),
+            ..
+        }
+        | ParseErrorReason::ArgumentError {
+            error: ArgumentError::UnexpectedFlag(argument),
+            ..
+        } => Some(argument.span),
+        _ => None,
+    }
+}
+
 impl rustyline::Helper for Helper {}
 
 // Use default validator for normal single line behaviour
 // In the future we can implement this for custom multi-line support
 impl rustyline::validate::Validator for Helper {}
+
+#[cfg(test)]
+mod tests {
+    use super::Painter;
+    use crate::shell::palette::DefaultPalette;
+    use ansi_term::{Color, Style};
+
+    fn paint(line: &str) -> String {
+        let context =
+            crate::cli::create_default_context(&mut crate::EnvironmentSyncer::new(), false)
+                .expect("could not make a context");
+        Painter::paint_string(line, context.registry(), &DefaultPalette {}).to_string()
+    }
+
+    fn error(text: &str) -> String {
+        Style::new()
+            .fg(Color::White)
+            .on(Color::Red)
+            .paint(text)
+            .to_string()
+    }
+
+    #[test]
+    fn paints_external_commands_that_cant_be_found_as_errors() {
+        assert!(paint("^nu_no_such_command -a").contains(&error("^nu_no_such_command")));
+        assert!(!paint("^ls -a").contains(&error("^ls")));
+    }
+
+    #[test]
+    fn paints_external_flags_apart_from_words() {
+        let painted = paint("^ls -a --all file");
+
+        assert!(painted.contains(&Color::Blue.bold().paint("--all").to_string()));
+        assert!(painted.contains(&Color::Green.bold().paint("file").to_string()));
+    }
+
+    #[test]
+    fn paints_what_the_parser_rejects_as_errors() {
+        assert!(paint("ls --nope").contains(&error("--nope")));
+        assert!(paint("if $true { echo 1 } { echo 2 }").contains(&error("{ echo 2 }")));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::commands::cd::CdArgs;
 use crate::commands::command::EvaluatedWholeStreamCommandArgs;
 use crate::commands::cp::CopyArgs;
+use crate::commands::ln::LinkArgs;
 use crate::commands::ls::LsArgs;
 use crate::commands::mkdir::MkdirArgs;
 use crate::commands::mv::MoveArgs;
@@ -71,6 +72,11 @@ impl ShellManager {
         self.shells.lock()[self.current_shell()].path()
     }
 
+    /// Whether the current shell is on the filesystem, rather than in a value or a remote place.
+    pub fn is_filesystem(&self) -> bool {
+        self.shells.lock()[self.current_shell()].name() == "filesystem"
+    }
+
     pub fn pwd(&self, args: EvaluatedWholeStreamCommandArgs) -> Result<OutputStream, ShellError> {
         let env = self.shells.lock();
 
@@ -163,6 +169,13 @@ impl ShellManager {
         shells[self.current_shell()].rm(args, name, &path)
     }
 
+    pub fn ln(&self, args: LinkArgs, name: Tag) -> Result<OutputStream, ShellError> {
+        let shells = self.shells.lock();
+
+        let path = shells[self.current_shell()].path();
+        shells[self.current_shell()].ln(args, name, &path)
+    }
+
     pub fn mkdir(&self, args: MkdirArgs, name: Tag) -> Result<OutputStream, ShellError> {
         let shells = self.shells.lock();
 
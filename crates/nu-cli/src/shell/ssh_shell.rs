@@ -0,0 +1,795 @@
+use crate::commands::cd::CdArgs;
+use crate::commands::command::EvaluatedWholeStreamCommandArgs;
+use crate::commands::cp::CopyArgs;
+use crate::commands::ln::LinkArgs;
+use crate::commands::ls::LsArgs;
+use crate::commands::mkdir::MkdirArgs;
+use crate::commands::mv::MoveArgs;
+use crate::commands::rm::RemoveArgs;
+use crate::prelude::*;
+use crate::shell::shell::Shell;
+use chrono::{TimeZone, Utc};
+use glob::Pattern;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::{AnchorLocation, Span};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Lists a directory as `<type> <size> <modified> <name>` lines, with GNU find, or with BSD stat
+/// where find can't format its output.
+const LIST_SCRIPT: &str = r#"if find . -maxdepth 0 -printf '' 2>/dev/null; then
+  find . -mindepth 1 -maxdepth 1 -printf '%y %s %T@ %P\n'
+else
+  find . -mindepth 1 -maxdepth 1 -exec stat -f '%Sp %z %m %N' {} +
+fi"#;
+
+/// A path on a host reached over SSH, like `ssh://user@host:2222/home/user`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SshLocation {
+    user: Option<String>,
+    host: String,
+    port: Option<u16>,
+    /// The absolute path on the host, or empty for the home directory.
+    path: String,
+}
+
+impl SshLocation {
+    pub(crate) fn parse(location: &str) -> Option<SshLocation> {
+        let rest = location
+            .strip_prefix("ssh://")
+            .or_else(|| location.strip_prefix("sftp://"))?;
+
+        let (authority, path) = match rest.find('/') {
+            Some(idx) => (&rest[..idx], &rest[idx..]),
+            None => (rest, ""),
+        };
+
+        let (user, address) = match authority.rfind('@') {
+            Some(idx) => (Some(authority[..idx].to_string()), &authority[idx + 1..]),
+            None => (None, authority),
+        };
+
+        // IPv6 addresses come in brackets, so their colons aren't taken for the port.
+        let (host, port) = match address.rfind(':') {
+            Some(idx) if !address[idx..].contains(']') => {
+                (&address[..idx], Some(address[idx + 1..].parse().ok()?))
+            }
+            _ => (address, None),
+        };
+
+        // ssh would take a host or a user starting with a dash for one of its options, like
+        // -oProxyCommand, which runs commands here
+        if host.is_empty()
+            || host.starts_with('-')
+            || user.as_ref().map_or(false, |user| user.starts_with('-'))
+        {
+            return None;
+        }
+
+        Some(SshLocation {
+            user,
+            host: host.to_string(),
+            port,
+            path: if path.is_empty() {
+                String::new()
+            } else {
+                normalize(path)
+            },
+        })
+    }
+
+    /// Where a path leads from this location, on the same host unless it's a location itself.
+    pub(crate) fn join(&self, path: &str) -> Option<SshLocation> {
+        if path.starts_with("ssh://") || path.starts_with("sftp://") {
+            return SshLocation::parse(path);
+        }
+
+        let path = if path.starts_with('/') {
+            path.to_string()
+        } else {
+            format!("{}/{}", self.path, path)
+        };
+
+        Some(SshLocation {
+            path: normalize(&path),
+            ..self.clone()
+        })
+    }
+
+    /// The location to open or save a path at, when either it or the current directory is remote.
+    pub(crate) fn resolve(cwd: &Path, path: &Path) -> Option<SshLocation> {
+        let path = path.to_string_lossy();
+
+        match SshLocation::parse(&cwd.to_string_lossy()) {
+            Some(cwd) => cwd.join(&path),
+            None => SshLocation::parse(&path),
+        }
+    }
+
+    fn file_name(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or_default()
+    }
+
+    fn parent(&self) -> SshLocation {
+        SshLocation {
+            path: match self.path.rfind('/') {
+                Some(0) | None => "/".to_string(),
+                Some(idx) => self.path[..idx].to_string(),
+            },
+            ..self.clone()
+        }
+    }
+
+    /// The arguments for `ssh` to run a script with `sh` on the host.
+    fn ssh_args(&self, script: &str) -> Vec<String> {
+        let mut args = vec![];
+
+        // Sharing one connection saves logging in again for every command.
+        #[cfg(unix)]
+        {
+            let control_path = std::env::temp_dir().join("nu-ssh-%C");
+            args.extend(vec![
+                "-o".to_string(),
+                "ControlMaster=auto".to_string(),
+                "-o".to_string(),
+                format!("ControlPath={}", control_path.to_string_lossy()),
+                "-o".to_string(),
+                "ControlPersist=60".to_string(),
+            ]);
+        }
+
+        if let Some(port) = self.port {
+            args.push("-p".to_string());
+            args.push(port.to_string());
+        }
+
+        if let Some(user) = &self.user {
+            args.push("-l".to_string());
+            args.push(user.clone());
+        }
+
+        args.push("--".to_string());
+        args.push(self.host.clone());
+        args.push(format!("sh -c {}", quote(script)));
+
+        args
+    }
+
+    /// Runs a script in the directory of the location, giving what it writes out.
+    fn run(&self, script: &str, input: Option<&[u8]>, tag: &Tag) -> Result<Vec<u8>, ShellError> {
+        let script = if self.path.is_empty() {
+            script.to_string()
+        } else {
+            format!("cd {} && {}", quote(&self.path), script)
+        };
+
+        let mut child = Command::new("ssh")
+            .args(self.ssh_args(&script))
+            .stdin(if input.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                ShellError::labeled_error(
+                    format!("Could not run ssh. {}", e),
+                    "could not run ssh",
+                    tag,
+                )
+            })?;
+
+        // The input is sent while the output is read, as ssh stops reading once the pipe of its
+        // output is full
+        let writer = match (input, child.stdin.take()) {
+            (Some(input), Some(mut stdin)) => {
+                let input = input.to_vec();
+                Some(std::thread::spawn(move || stdin.write_all(&input)))
+            }
+            _ => None,
+        };
+
+        let output = child.wait_with_output().map_err(|e| {
+            ShellError::labeled_error(format!("Could not run ssh. {}", e), e.to_string(), tag)
+        })?;
+
+        let written = match writer.map(|writer| writer.join()) {
+            Some(Ok(written)) => written,
+            Some(Err(_)) => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "the data could not be sent",
+            )),
+            None => Ok(()),
+        };
+
+        if output.status.success() {
+            written.map_err(|e| {
+                ShellError::labeled_error(
+                    format!("Could not send the data to {}. {}", self.host, e),
+                    e.to_string(),
+                    tag,
+                )
+            })?;
+
+            Ok(output.stdout)
+        } else {
+            let message = String::from_utf8_lossy(&output.stderr).trim().to_string();
+
+            Err(ShellError::labeled_error(
+                format!("Command failed on {}", self.host),
+                if message.is_empty() {
+                    format!("failed with {}", output.status)
+                } else {
+                    message
+                },
+                tag,
+            ))
+        }
+    }
+
+    /// Reads a file for `open`.
+    pub(crate) fn fetch(
+        &self,
+        span: Span,
+    ) -> Result<(Option<String>, UntaggedValue, Tag), ShellError> {
+        let tag = Tag {
+            span,
+            anchor: Some(AnchorLocation::Url(self.to_string())),
+        };
+
+        let parent = self.parent();
+        let bytes = parent.run(&format!("cat -- {}", quote(&self.path)), None, &tag)?;
+
+        let extension = Path::new(self.file_name())
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_string());
+
+        let contents = match String::from_utf8(bytes) {
+            Ok(text) => UntaggedValue::string(text),
+            Err(e) => UntaggedValue::binary(e.into_bytes()),
+        };
+
+        Ok((extension, contents, tag))
+    }
+
+    /// Writes a file for `save`.
+    pub(crate) fn write(&self, data: &[u8], tag: &Tag) -> Result<(), ShellError> {
+        self.parent()
+            .run(&format!("cat > {}", quote(&self.path)), Some(data), tag)
+            .map(|_| ())
+    }
+}
+
+impl std::fmt::Display for SshLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ssh://")?;
+        if let Some(user) = &self.user {
+            write!(f, "{}@", user)?;
+        }
+        write!(f, "{}", self.host)?;
+        if let Some(port) = self.port {
+            write!(f, ":{}", port)?;
+        }
+        write!(f, "{}", self.path)
+    }
+}
+
+/// Resolves `.` and `..` in an absolute path, and drops empty parts.
+fn normalize(path: &str) -> String {
+    let mut parts = vec![];
+
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            part => parts.push(part),
+        }
+    }
+
+    format!("/{}", parts.join("/"))
+}
+
+/// Quotes text for `sh`, so it's taken as it is.
+fn quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', r#"'\''"#))
+}
+
+/// Quotes a path for `sh`, leaving its wildcards for the remote shell to expand.
+fn quote_pattern(path: &Path) -> String {
+    path.to_string_lossy()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || "*?[]/._-+,=@%:".contains(c) {
+                c.to_string()
+            } else {
+                format!("\\{}", c)
+            }
+        })
+        .collect()
+}
+
+/// Reads the lines of `LIST_SCRIPT` into rows for `ls`.
+fn list_entries(output: &str, all: bool, pattern: Option<&Pattern>, tag: &Tag) -> Vec<Value> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(4, ' ');
+            let kind = fields.next()?;
+            let size = fields.next()?.parse::<u64>().ok();
+            let modified = fields.next()?.parse::<f64>().ok();
+            let name = fields.next()?;
+            let name = name.strip_prefix("./").unwrap_or(name);
+
+            let wanted = match pattern {
+                Some(pattern) => pattern.matches(name),
+                None => all || !name.starts_with('.'),
+            };
+            if !wanted {
+                return None;
+            }
+
+            let file_type = match kind.chars().next() {
+                Some('d') => "Dir",
+                Some('l') => "Symlink",
+                _ => "File",
+            };
+
+            let mut dict = TaggedDictBuilder::new(tag);
+            dict.insert_untagged("name", UntaggedValue::string(name));
+            dict.insert_untagged("type", UntaggedValue::string(file_type));
+            dict.insert_untagged(
+                "size",
+                size.map(UntaggedValue::bytes)
+                    .unwrap_or_else(UntaggedValue::nothing),
+            );
+            dict.insert_untagged(
+                "modified",
+                modified
+                    .map(|secs| UntaggedValue::date(Utc.timestamp(secs as i64, 0)))
+                    .unwrap_or_else(UntaggedValue::nothing),
+            );
+
+            Some(dict.into_value())
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug)]
+pub struct SshShell {
+    location: SshLocation,
+    last_location: SshLocation,
+    home: String,
+}
+
+impl SshShell {
+    pub fn with_location(location: &str) -> Result<SshShell, ShellError> {
+        let tag = Tag::unknown();
+        let location = SshLocation::parse(location).ok_or_else(|| {
+            ShellError::labeled_error(
+                "Invalid SSH location",
+                "expected a location like ssh://user@host/path",
+                &tag,
+            )
+        })?;
+
+        // Logging in once up front checks the host can be reached, and finds the directories.
+        let home = location.parent().run("cd && pwd", None, &tag)?;
+        let home = String::from_utf8_lossy(&home).trim().to_string();
+
+        let path = if location.path.is_empty() {
+            home.clone()
+        } else {
+            let path = location.run("pwd", None, &tag)?;
+            String::from_utf8_lossy(&path).trim().to_string()
+        };
+
+        let location = SshLocation { path, ..location };
+
+        Ok(SshShell {
+            last_location: location.clone(),
+            location,
+            home,
+        })
+    }
+
+    fn location_at(&self, path: &str) -> SshLocation {
+        SshLocation {
+            path: path.to_string(),
+            ..self.location.clone()
+        }
+    }
+}
+
+impl Shell for SshShell {
+    fn name(&self) -> String {
+        format!("ssh {}", self.location.host)
+    }
+
+    fn homedir(&self) -> Option<PathBuf> {
+        Some(PathBuf::from(&self.home))
+    }
+
+    fn ls(
+        &self,
+        LsArgs { path, all, .. }: LsArgs,
+        name_tag: Tag,
+        _ctrl_c: Arc<AtomicBool>,
+    ) -> Result<OutputStream, ShellError> {
+        let tag = path
+            .as_ref()
+            .map(|path| path.tag.clone())
+            .unwrap_or(name_tag);
+
+        let target = match &path {
+            Some(path) => self
+                .location
+                .join(&path.item.to_string_lossy())
+                .ok_or_else(|| {
+                    ShellError::labeled_error(
+                        "Invalid SSH location",
+                        "expected a location like ssh://user@host/path",
+                        &tag,
+                    )
+                })?,
+            None => self.location.clone(),
+        };
+
+        let (dir, pattern) = if target.file_name().contains(&['*', '?', '['][..]) {
+            let pattern = Pattern::new(target.file_name())
+                .map_err(|e| ShellError::labeled_error("Invalid glob pattern", e.msg, &tag))?;
+            (target.parent(), Some(pattern))
+        } else {
+            (target, None)
+        };
+
+        let output = dir.run(LIST_SCRIPT, None, &tag)?;
+        let rows = list_entries(
+            &String::from_utf8_lossy(&output),
+            all,
+            pattern.as_ref(),
+            &tag,
+        );
+
+        Ok(futures::stream::iter(rows.into_iter().map(ReturnSuccess::value)).to_output_stream())
+    }
+
+    fn cd(&self, args: CdArgs, name: Tag) -> Result<OutputStream, ShellError> {
+        let tag = args
+            .path
+            .as_ref()
+            .map(|path| path.tag.clone())
+            .unwrap_or(name);
+
+        let target = match &args.path {
+            None => self.location_at(&self.home),
+            Some(path) if path.item == Path::new("-") => self.last_location.clone(),
+            Some(path) => self
+                .location
+                .join(&path.item.to_string_lossy())
+                .ok_or_else(|| {
+                    ShellError::labeled_error(
+                        "Invalid SSH location",
+                        "expected a location like ssh://user@host/path",
+                        &tag,
+                    )
+                })?,
+        };
+
+        if target.host != self.location.host {
+            return Err(ShellError::labeled_error(
+                "Can not change to another host",
+                "use enter to open a shell on another host",
+                &tag,
+            ));
+        }
+
+        self.location
+            .run(&format!("test -d {}", quote(&target.path)), None, &tag)
+            .map_err(|_| {
+                ShellError::labeled_error(
+                    "Can not change to directory",
+                    "directory not found",
+                    &tag,
+                )
+            })?;
+
+        let mut stream = VecDeque::new();
+        stream.push_back(ReturnSuccess::change_cwd(target.to_string()));
+        Ok(stream.into())
+    }
+
+    fn cp(
+        &self,
+        CopyArgs {
+            src,
+            dst,
+            recursive,
+            preserve,
+            interactive,
+            no_clobber,
+            no_dereference,
+            ..
+        }: CopyArgs,
+        name: Tag,
+        _path: &str,
+    ) -> Result<OutputStream, ShellError> {
+        if interactive {
+            return Err(ShellError::labeled_error(
+                "cp --interactive not currently supported over SSH",
+                "not currently supported",
+                name,
+            ));
+        }
+
+        let mut flags = String::new();
+        if recursive.item {
+            flags.push_str(" -R");
+        }
+        if preserve {
+            flags.push_str(" -p");
+        }
+        if no_clobber {
+            flags.push_str(" -n");
+        }
+        if no_dereference {
+            flags.push_str(" -P");
+        }
+
+        self.location.run(
+            &format!(
+                "cp{} -- {} {}",
+                flags,
+                quote_pattern(&src.item),
+                quote(&dst.item.to_string_lossy())
+            ),
+            None,
+            &src.tag,
+        )?;
+
+        Ok(OutputStream::empty())
+    }
+
+    fn mv(
+        &self,
+        MoveArgs { src, dst }: MoveArgs,
+        _name: Tag,
+        _path: &str,
+    ) -> Result<OutputStream, ShellError> {
+        self.location.run(
+            &format!(
+                "mv -- {} {}",
+                quote_pattern(&src.item),
+                quote(&dst.item.to_string_lossy())
+            ),
+            None,
+            &src.tag,
+        )?;
+
+        Ok(OutputStream::empty())
+    }
+
+    fn ln(&self, _args: LinkArgs, name: Tag, _path: &str) -> Result<OutputStream, ShellError> {
+        Err(ShellError::labeled_error(
+            "ln not currently supported over SSH",
+            "not currently supported",
+            name,
+        ))
+    }
+
+    fn mkdir(
+        &self,
+        MkdirArgs {
+            rest: directories,
+            show_created_paths,
+        }: MkdirArgs,
+        name: Tag,
+        _path: &str,
+    ) -> Result<OutputStream, ShellError> {
+        if directories.is_empty() {
+            return Err(ShellError::labeled_error(
+                "mkdir requires directory paths",
+                "needs parameter",
+                name,
+            ));
+        }
+
+        let paths = directories
+            .iter()
+            .map(|dir| quote(&dir.item.to_string_lossy()))
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.location
+            .run(&format!("mkdir -p -- {}", paths), None, &name)?;
+
+        let mut stream = VecDeque::new();
+        if show_created_paths {
+            for dir in directories {
+                if let Some(created) = self.location.join(&dir.item.to_string_lossy()) {
+                    stream.push_back(ReturnSuccess::value(
+                        UntaggedValue::string(created.path).into_value(&dir.tag),
+                    ));
+                }
+            }
+        }
+
+        Ok(stream.into())
+    }
+
+    fn rm(
+        &self,
+        RemoveArgs {
+            rest: targets,
+            recursive,
+            trash,
+            ..
+        }: RemoveArgs,
+        name: Tag,
+        _path: &str,
+    ) -> Result<OutputStream, ShellError> {
+        if trash.item {
+            return Err(ShellError::labeled_error(
+                "Cannot move files to the trash over SSH",
+                "not currently supported",
+                trash.tag,
+            ));
+        }
+
+        if targets.is_empty() {
+            return Err(ShellError::labeled_error(
+                "rm requires target paths",
+                "needs parameter",
+                name,
+            ));
+        }
+
+        let paths = targets
+            .iter()
+            .map(|target| quote_pattern(&target.item))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let flags = if recursive.item { " -r" } else { "" };
+
+        self.location
+            .run(&format!("rm{} -- {}", flags, paths), None, &name)?;
+
+        Ok(OutputStream::empty())
+    }
+
+    fn path(&self) -> String {
+        self.location.to_string()
+    }
+
+    fn pwd(&self, args: EvaluatedWholeStreamCommandArgs) -> Result<OutputStream, ShellError> {
+        let mut stream = VecDeque::new();
+        stream.push_back(ReturnSuccess::value(
+            UntaggedValue::string(self.path()).into_value(&args.call_info.name_tag),
+        ));
+        Ok(stream.into())
+    }
+
+    fn set_path(&mut self, path: String) {
+        if let Some(location) = SshLocation::parse(&path) {
+            self.last_location = std::mem::replace(&mut self.location, location);
+        }
+    }
+
+    fn complete(
+        &self,
+        _line: &str,
+        pos: usize,
+        _ctx: &rustyline::Context<'_>,
+    ) -> Result<(usize, Vec<rustyline::completion::Pair>), rustyline::error::ReadlineError> {
+        Ok((pos, vec![]))
+    }
+
+    fn hint(&self, _line: &str, _pos: usize, _ctx: &rustyline::Context<'_>) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{list_entries, quote, quote_pattern, SshLocation};
+    use nu_protocol::{Primitive, UntaggedValue};
+    use nu_source::{SpannedItem, Tag};
+    use nu_value_ext::get_data_by_key;
+    use std::path::Path;
+
+    fn location(location: &str) -> SshLocation {
+        SshLocation::parse(location).expect("invalid location")
+    }
+
+    #[test]
+    fn reads_locations() {
+        let parsed = location("ssh://andres@example.com:2222/srv/../var/www/");
+        assert_eq!(parsed.user, Some("andres".to_string()));
+        assert_eq!(parsed.host, "example.com");
+        assert_eq!(parsed.port, Some(2222));
+        assert_eq!(parsed.path, "/var/www");
+        assert_eq!(parsed.to_string(), "ssh://andres@example.com:2222/var/www");
+
+        assert_eq!(location("sftp://example.com").path, "");
+        assert_eq!(location("ssh://[::1]:22/tmp").host, "[::1]");
+        assert_eq!(SshLocation::parse("ssh://host:port/tmp"), None);
+        assert_eq!(SshLocation::parse("/tmp"), None);
+    }
+
+    #[test]
+    fn refuses_hosts_and_users_ssh_would_take_for_options() {
+        assert_eq!(
+            SshLocation::parse("ssh://-oProxyCommand=touch${IFS}pwned/tmp"),
+            None
+        );
+        assert_eq!(
+            SshLocation::parse("ssh://-oProxyCommand=x@example.com/"),
+            None
+        );
+
+        let args = location("ssh://andres@example.com:2222/").ssh_args("ls");
+        let dashes = args.iter().position(|arg| arg == "--");
+        assert_eq!(
+            dashes.map(|idx| &args[idx + 1]),
+            Some(&"example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn moves_around_locations_like_paths() {
+        let home = location("ssh://example.com/home/andres");
+
+        assert_eq!(
+            home.join("../yehuda/./notes.txt"),
+            Some(location("ssh://example.com/home/yehuda/notes.txt"))
+        );
+        assert_eq!(home.join("/etc"), Some(location("ssh://example.com/etc")));
+        assert_eq!(home.parent(), location("ssh://example.com/home"));
+        assert_eq!(
+            location("ssh://example.com/home").parent(),
+            location("ssh://example.com/")
+        );
+    }
+
+    #[test]
+    fn quotes_for_the_remote_shell() {
+        assert_eq!(quote("it's here"), r#"'it'\''s here'"#);
+        assert_eq!(quote_pattern(Path::new("logs/*.json")), "logs/*.json");
+        assert_eq!(
+            quote_pattern(Path::new("my files/$HOME*")),
+            r#"my\ files/\$HOME*"#
+        );
+    }
+
+    #[test]
+    fn reads_listings() {
+        let output = "f 512 1591005600.5 today.json\nd 4096 1591005600 logs\nf 0 1591005600 .hidden\n-rw-r--r-- 12 1591005600 ./notes with spaces.txt\n";
+
+        let names = |entries: Vec<nu_protocol::Value>| {
+            entries
+                .iter()
+                .filter_map(|entry| get_data_by_key(entry, "name".spanned_unknown()))
+                .filter_map(|name| match name.value {
+                    UntaggedValue::Primitive(Primitive::String(name)) => Some(name),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            names(list_entries(output, false, None, &Tag::unknown())),
+            vec!["today.json", "logs", "notes with spaces.txt"]
+        );
+        assert_eq!(list_entries(output, true, None, &Tag::unknown()).len(), 4);
+
+        let pattern = glob::Pattern::new("*.json").expect("invalid pattern");
+        assert_eq!(
+            names(list_entries(output, false, Some(&pattern), &Tag::unknown())),
+            vec!["today.json"]
+        );
+    }
+}
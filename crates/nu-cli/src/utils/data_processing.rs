@@ -9,7 +9,7 @@ use nu_value_ext::{get_data_by_key, ValueExt};
 use num_traits::Zero;
 
 // Re-usable error messages
-const ERR_EMPTY_DATA: &str = "Cannot perform aggregate math operation on empty data";
+pub const ERR_EMPTY_DATA: &str = "Cannot perform aggregate math operation on empty data";
 
 pub fn columns_sorted(
     _group_by_name: Option<Tagged<String>>,
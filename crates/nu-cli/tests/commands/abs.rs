@@ -0,0 +1,69 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn absolute_values_of_numbers() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [-1 2 -3]
+            | math abs
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,2,3]");
+}
+
+#[test]
+fn absolute_value_of_a_decimal() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [-3.5]
+            | math abs
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "3.5");
+}
+
+#[test]
+fn keeps_filesizes() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1kb 2mb]
+            | math abs
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1024,2097152]");
+}
+
+#[test]
+fn absolute_values_of_the_given_columns() {
+    Playground::setup("abs_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "changes.csv",
+            r#"
+                name,change,delta
+                nu,-3,-2
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open changes.csv
+                | math abs change
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"{"name":"nu","change":3,"delta":-2}"#);
+    })
+}
@@ -0,0 +1,60 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn aggregates_columns_per_group() {
+    Playground::setup("aggregate_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_luck,type
+                Andrés,Robalino,1,A
+                Jonathan,Turner,2,B
+                Yehuda,Katz,3,A
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | aggregate --by type --agg { rusty_luck: sum, first_name: concat }
+                | where type == A
+                | format "{rusty_luck} {first_name}"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "4 Andrés, Yehuda");
+    })
+}
+
+#[test]
+fn errors_on_unknown_aggregate_function() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | wrap n
+            | aggregate --agg { n: median }
+        "#
+    ));
+
+    assert!(actual.err.contains("Unknown aggregate function"));
+}
+
+#[test]
+fn aggregates_a_column_with_several_functions() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [3 1 2]
+            | wrap n
+            | aggregate --agg { n: [min max avg] }
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"n":1,"n_max":3,"n_avg":2.0}"#);
+}
@@ -1,3 +1,4 @@
+use nu_test_support::fs::Stub::EmptyFile;
 use nu_test_support::nu;
 use nu_test_support::playground::Playground;
 
@@ -15,3 +16,36 @@ fn alias_args_work() {
         assert_eq!(actual.out, "[1,2]");
     })
 }
+
+#[test]
+fn alias_gives_its_flags_and_the_rest_of_its_arguments_to_its_command() {
+    Playground::setup("alias_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("a.txt"), EmptyFile(".hidden.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                alias files [] { ls }
+                alias say [first] { echo $first }
+                echo (files --all | count) (say 1 2 3 | count) | to json
+            "#
+        );
+
+        assert_eq!(actual.out, "[2,3]");
+    })
+}
+
+#[test]
+fn alias_takes_parameters_like_def() {
+    Playground::setup("alias_test_3", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                alias times [x: int, --by: int = 2] { = $x * $by }
+                echo (times 3) (times 3 --by 3) | to json
+            "#
+        );
+
+        assert_eq!(actual.out, "[6,9]");
+    })
+}
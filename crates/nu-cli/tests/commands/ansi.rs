@@ -0,0 +1,44 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn outputs_escape_codes() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [red bg_light_green bold reset] | each { ansi $it } | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"["\u001b[31m","\u001b[102m","\u001b[1m","\u001b[0m"]"#
+    );
+}
+
+#[test]
+fn errors_on_an_unknown_code() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            ansi orange
+        "#
+    ));
+
+    assert!(actual.err.contains("Unknown ansi code"));
+}
+
+#[test]
+fn strips_escape_codes() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            build-string $(ansi bold) $(ansi red) "nu" $(ansi reset) " shell"
+            | wrap text
+            | ansi strip text
+            | get text
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "nu shell");
+}
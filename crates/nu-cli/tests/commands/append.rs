@@ -28,3 +28,46 @@ fn adds_a_row_to_the_end() {
         assert_eq!(actual.out, "pollo loco");
     })
 }
+
+#[test]
+fn adds_the_rows_of_a_table_to_the_end() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; [a]]
+            | append [[name]; [b] [c]]
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","b","c"]"#);
+}
+
+#[test]
+fn adds_rows_at_a_position() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a d]
+            | append [b c] --at 1
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","b","c","d"]"#);
+}
+
+#[test]
+fn adds_rows_past_the_end_at_the_end() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b]
+            | append c --at 10
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","b","c"]"#);
+}
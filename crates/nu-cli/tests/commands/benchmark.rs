@@ -0,0 +1,55 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn runs_the_block_the_number_of_times_asked() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            benchmark --runs 3 { echo 1 }
+            | get runs
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn gives_the_timings() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            benchmark -n 5 { ls | count }
+            | select "min ms" "mean ms" "max ms" "stddev ms"
+            | pivot
+            | where Column1 >= 0
+            | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "4");
+}
+
+#[test]
+fn errors_from_the_block_stop_it() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            benchmark { open no_such_file.txt }
+        "#
+    ));
+
+    assert!(actual.err.contains("Cannot open"));
+}
+
+#[test]
+fn errors_if_asked_for_no_runs() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            benchmark --runs 0 { echo 1 }
+        "#
+    ));
+
+    assert!(actual.err.contains("at least once"));
+}
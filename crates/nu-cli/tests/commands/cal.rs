@@ -63,3 +63,18 @@ fn cal_sees_pipeline_year() {
 
     assert_eq!(actual.out, "[3,10,17]");
 }
+
+#[test]
+fn cal_weeks_can_start_on_monday() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        cal --full-year 2020 --week-start monday | first | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"{"monday":null,"tuesday":null,"wednesday":1,"thursday":2,"friday":3,"saturday":4,"sunday":5}"#
+    );
+}
@@ -0,0 +1,37 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn outputs_named_characters() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [newline tab pipe left_brace] | each { char $it } | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["\n","\t","|","{"]"#);
+}
+
+#[test]
+fn outputs_unicode_code_points() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            build-string $(char -u 1F600) $(char -u 41)
+        "#
+    ));
+
+    assert_eq!(actual.out, "😀A");
+}
+
+#[test]
+fn errors_on_an_unknown_name() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            char unknown
+        "#
+    ));
+
+    assert!(actual.err.contains("Unknown character name"));
+}
@@ -0,0 +1,112 @@
+use nu_test_support::fs::Stub::EmptyFile;
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+use std::path::Path;
+
+#[cfg(unix)]
+fn mode(path: &Path) -> u32 {
+    use std::os::unix::fs::PermissionsExt;
+
+    std::fs::metadata(path)
+        .expect("the file is missing")
+        .permissions()
+        .mode()
+        & 0o7777
+}
+
+#[cfg(unix)]
+fn set_mode(path: &Path, mode: u32) {
+    use std::os::unix::fs::PermissionsExt;
+
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(mode))
+        .expect("could not set the mode");
+}
+
+#[cfg(unix)]
+#[test]
+fn sets_octal_modes() {
+    Playground::setup("chmod_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("secrets.txt")]);
+
+        nu!(
+            cwd: dirs.test(),
+            "chmod 600 secrets.txt"
+        );
+
+        assert_eq!(mode(&dirs.test().join("secrets.txt")), 0o600);
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn changes_symbolic_modes() {
+    Playground::setup("chmod_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("build.sh")]);
+        set_mode(&dirs.test().join("build.sh"), 0o644);
+
+        nu!(
+            cwd: dirs.test(),
+            "chmod u+x,o-r build.sh"
+        );
+
+        assert_eq!(mode(&dirs.test().join("build.sh")), 0o740);
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn changes_the_paths_from_the_pipeline() {
+    Playground::setup("chmod_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            EmptyFile("a.txt"),
+            EmptyFile("b.txt"),
+            EmptyFile("c.md"),
+        ]);
+        for file in &["a.txt", "b.txt", "c.md"] {
+            set_mode(&dirs.test().join(file), 0o666);
+        }
+
+        nu!(
+            cwd: dirs.test(),
+            "ls | where name =~ txt | chmod go-w"
+        );
+
+        assert_eq!(mode(&dirs.test().join("a.txt")), 0o644);
+        assert_eq!(mode(&dirs.test().join("b.txt")), 0o644);
+        assert_eq!(mode(&dirs.test().join("c.md")), 0o666);
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn changes_everything_inside_directories_when_recursive() {
+    Playground::setup("chmod_test_4", |dirs, sandbox| {
+        sandbox
+            .within("shared")
+            .within("docs")
+            .with_files(vec![EmptyFile("notes.txt")]);
+        set_mode(&dirs.test().join("shared/docs/notes.txt"), 0o600);
+
+        nu!(
+            cwd: dirs.test(),
+            "chmod -r a+rX shared"
+        );
+
+        assert_eq!(mode(&dirs.test().join("shared/docs/notes.txt")), 0o644);
+        assert_eq!(mode(&dirs.test().join("shared/docs")) & 0o555, 0o555);
+    })
+}
+
+#[test]
+fn errors_on_invalid_modes() {
+    Playground::setup("chmod_test_5", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("a.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "chmod u+q a.txt"
+        );
+
+        assert!(actual.err.contains("Invalid mode"));
+    })
+}
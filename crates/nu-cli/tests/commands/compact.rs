@@ -50,3 +50,30 @@ fn discards_empty_rows_by_default() {
         assert_eq!(actual.out, "4");
     });
 }
+
+#[test]
+fn discards_rows_where_given_column_has_empty_cells() {
+    Playground::setup("compact_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "sales.csv",
+            r#"
+                item,amount
+                arepas,3
+                empanadas,
+                bolon,2
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sales.csv
+                | compact amount
+                | get item
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"["arepas","bolon"]"#);
+    });
+}
@@ -0,0 +1,42 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn gives_the_exit_code_of_a_failing_external() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            ^sh -c "exit 3" | complete | get exit_code
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+    assert_eq!(actual.err, "");
+}
+
+#[test]
+fn keeps_stderr_apart_from_stdout() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            ^sh -c "echo out; echo err 1>&2" | complete | echo $it.stdout $it.stderr | str trim | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["out","err"]"#);
+    assert_eq!(actual.err, "");
+}
+
+#[test]
+fn gives_a_zero_exit_code_for_internal_commands() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo hello | complete | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"{"stdout":"hello","stderr":"","exit_code":0}"#
+    );
+}
@@ -0,0 +1,83 @@
+// The config is kept in a directory of the playground, which nu finds from XDG_CONFIG_HOME on Linux
+#![cfg(target_os = "linux")]
+
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+
+#[test]
+fn sets_and_gets_nested_settings() {
+    Playground::setup("config_test_1", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"with-env [XDG_CONFIG_HOME {}] {{ nu -c "config set color_config.header green"; nu -c "config get color_config.header" }}"#,
+            dirs.test()
+        );
+
+        assert_eq!(actual.out, "green");
+    })
+}
+
+#[test]
+fn keeps_the_types_of_settings() {
+    Playground::setup("config_test_2", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"with-env [XDG_CONFIG_HOME {}] {{ nu -c "config set history_size 200; echo [a b] | config set startup; config | to json" }}"#,
+            dirs.test()
+        );
+
+        assert_eq!(actual.out, r#"{"history_size":200,"startup":["a","b"]}"#);
+    })
+}
+
+#[test]
+fn checks_the_settings_nu_reads() {
+    Playground::setup("config_test_3", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"with-env [XDG_CONFIG_HOME {}] {{ nu -c "config set edit_mode vim" }}"#,
+            dirs.test()
+        );
+
+        assert!(actual.err.contains("expected one of: vi, emacs"));
+    })
+}
+
+#[test]
+fn removes_nested_settings() {
+    Playground::setup("config_test_4", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"with-env [XDG_CONFIG_HOME {}] {{ nu -c "config set env.A 1; config set env.B 2; config remove env.A; config get env | to json" }}"#,
+            dirs.test()
+        );
+
+        assert_eq!(actual.out, r#"{"B":2}"#);
+    })
+}
+
+#[test]
+fn makes_the_prompt_with_the_prompt_setting() {
+    Playground::setup("config_test_5", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"with-env [XDG_CONFIG_HOME {}] {{ nu -c "config set prompt 'echo $nu.env.LAST_EXIT_CODE | save --raw code.txt'"; echo "^false" | nu | count; open code.txt }}"#,
+            dirs.test()
+        );
+
+        assert_eq!(actual.out, "1");
+    })
+}
+
+#[test]
+fn saves_aliases_to_the_startup_commands() {
+    Playground::setup("config_test_6", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"with-env [XDG_CONFIG_HOME {}] {{ nu -c "alias l-s [x] {{ ls $x }} --save"; nu -c "alias -s l-s [] {{ ls }}"; nu -c "config get startup | to json" }}"#,
+            dirs.test()
+        );
+
+        assert_eq!(actual.out, r#""alias l-s [] { ls }""#);
+    })
+}
@@ -1,6 +1,6 @@
-use nu_test_support::fs::{files_exist_at, AbsoluteFile, Stub::EmptyFile};
-use nu_test_support::nu;
+use nu_test_support::fs::{files_exist_at, AbsoluteFile, Stub::EmptyFile, Stub::FileWithContent};
 use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
 use std::path::Path;
 
 #[test]
@@ -235,3 +235,118 @@ fn copy_file_and_dir_from_two_parents_up_using_multiple_dots_to_current_dir_recu
         assert!(files_exist_at(vec!["hello_there", "hello_again"], expected));
     })
 }
+
+#[test]
+fn does_not_overwrite_files_with_no_clobber() {
+    Playground::setup("cp_test_11", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![FileWithContent("new.txt", "new")])
+            .within("dst")
+            .with_files(vec![FileWithContent("new.txt", "old")]);
+
+        nu!(
+            cwd: dirs.test(),
+            "cp new.txt dst --no-clobber"
+        );
+
+        let contents = std::fs::read_to_string(dirs.test().join("dst/new.txt"))
+            .expect("can not read the copy");
+
+        assert_eq!(contents, "old");
+    })
+}
+
+#[test]
+fn outputs_a_row_for_every_copied_file_with_progress() {
+    Playground::setup("cp_test_12", |dirs, sandbox| {
+        sandbox
+            .within("originals")
+            .with_files(vec![
+                FileWithContent("jonathan.txt", "abc"),
+                FileWithContent("andres.txt", "abcdef"),
+            ])
+            .mkdir("expected");
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                cp -r originals expected --progress
+                | get size
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, "[6,3]");
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn keeps_modification_times_with_preserve() {
+    Playground::setup("cp_test_13", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("old.txt")]);
+
+        let original = dirs.test().join("old.txt");
+        let long_ago =
+            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_000_000);
+        std::fs::File::open(&original)
+            .and_then(|file| file.set_times(std::fs::FileTimes::new().set_modified(long_ago)))
+            .expect("can not set the modification time");
+
+        nu!(
+            cwd: dirs.test(),
+            "cp old.txt copy.txt --preserve"
+        );
+
+        let modified = std::fs::metadata(dirs.test().join("copy.txt"))
+            .and_then(|metadata| metadata.modified())
+            .expect("can not read the modification time");
+
+        assert_eq!(modified, long_ago);
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn copies_symlinks_as_symlinks_with_no_dereference() {
+    Playground::setup("cp_test_14", |dirs, sandbox| {
+        sandbox
+            .within("originals")
+            .with_files(vec![EmptyFile("target.txt")]);
+
+        std::os::unix::fs::symlink("target.txt", dirs.test().join("originals/link.txt"))
+            .expect("can not create the symlink");
+
+        nu!(
+            cwd: dirs.test(),
+            "cp -r originals copies --no-dereference"
+        );
+
+        let link = std::fs::read_link(dirs.test().join("copies/link.txt"))
+            .expect("the symlink was not copied as a symlink");
+
+        assert_eq!(link, Path::new("target.txt"));
+    })
+}
+
+#[test]
+fn copies_the_files_in_the_pipeline_using_a_block() {
+    Playground::setup("cp_test_15", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("a.csv"), EmptyFile("b.csv")]);
+
+        nu!(
+            cwd: dirs.test(),
+            r#"ls *.csv | cp --using { build-string $it.name ".bak" }"#
+        );
+
+        assert!(files_exist_at(
+            vec![
+                Path::new("a.csv"),
+                Path::new("a.csv.bak"),
+                Path::new("b.csv"),
+                Path::new("b.csv.bak")
+            ],
+            dirs.test()
+        ));
+    })
+}
@@ -0,0 +1,268 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn converts_dates_to_the_given_timezone() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '2020-06-01T22:30:00+00:00'
+            | into date
+            | date to-timezone Asia/Tokyo
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"{"year":2020,"month":6,"day":2,"hour":7,"minute":30,"second":0,"timezone":"JST"}"#
+    );
+}
+
+#[test]
+fn converts_dates_to_fixed_offsets() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"event": "deploy", "at": "2020-06-01T12:00:00+00:00"}]'
+            | from json
+            | into date at
+            | date to-timezone '-05:30' at
+            | get at
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"{"year":2020,"month":6,"day":1,"hour":6,"minute":30,"second":0,"timezone":"-05:30"}"#
+    );
+}
+
+#[test]
+fn reports_unknown_timezones() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '2020-06-01T12:00:00+00:00'
+            | into date
+            | date to-timezone Atlantis/Capital
+        "#
+    ));
+
+    assert!(actual.err.contains("Unknown timezone"));
+}
+
+#[test]
+fn parses_text_in_the_given_timezone() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [
+                '2020-06-01 14:00'
+                '2020-06-01 08:00'
+            ]
+            | str to-datetime --format '%Y-%m-%d %H:%M' --timezone Europe/Paris
+            | date to-timezone --utc
+            | get hour
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[12,6]");
+}
+
+#[test]
+fn offsets_in_the_text_win_over_the_given_timezone() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '2020-06-01 14:00 +0000'
+            | str to-datetime -f '%Y-%m-%d %H:%M %z' -z Europe/Paris
+            | date to-timezone --utc
+            | get hour
+        "#
+    ));
+
+    assert_eq!(actual.out, "14");
+}
+
+#[test]
+fn adds_and_subtracts_durations() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '2020-06-01T12:00:00+00:00'
+            | into date
+            | date add 3wk
+            | date subtract 90sec
+            | format date '%F %T' --utc
+        "#
+    ));
+
+    assert_eq!(actual.out, "2020-06-22 11:58:30");
+}
+
+#[test]
+fn shifts_dates_at_column_paths() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"task": "report", "due": "2020-02-28T09:00:00+00:00"}]'
+            | from json
+            | into date due
+            | date add 2day due
+            | format date '%F' due --utc
+            | get due
+        "#
+    ));
+
+    assert_eq!(actual.out, "2020-03-01");
+}
+
+#[test]
+fn compares_dates_with_arithmetic_on_date_now() {
+    let recent = nu!(
+        cwd: ".", pipeline(
+        r#"
+            date now
+            | date subtract 1day
+            | where $it > ((date now) - 7day)
+            | count
+        "#
+    ));
+
+    let old = nu!(
+        cwd: ".", pipeline(
+        r#"
+            date now
+            | date subtract 30day
+            | where $it > ((date now) - 7day)
+            | count
+        "#
+    ));
+
+    assert_eq!(recent.out, "1");
+    assert_eq!(old.out, "0");
+}
+
+#[test]
+fn compares_durations() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            = 1wk > 6day
+        "#
+    ));
+
+    assert_eq!(actual.out, "true");
+}
+
+#[test]
+fn produces_rows_for_the_dates_in_the_range() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            date range 2020-12-30 2021-01-02
+            | select year quarter week day weekday
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"year":2020,"quarter":4,"week":53,"day":30,"weekday":"wednesday"},{"year":2020,"quarter":4,"week":53,"day":31,"weekday":"thursday"},{"year":2021,"quarter":1,"week":53,"day":1,"weekday":"friday"},{"year":2021,"quarter":1,"week":53,"day":2,"weekday":"saturday"}]"#
+    );
+}
+
+#[test]
+fn leaves_out_weekends_when_asked() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            date range 2020-06-01 2020-06-30 --weekdays-only --step 2day
+            | get day
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,3,5,9,11,15,17,19,23,25,29]");
+}
+
+#[test]
+fn takes_dates_for_the_range() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            date range (date now) ((date now) + 2wk) --step 1wk
+            | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn ends_ranges_with_steps_past_the_last_date_there_can_be() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            date range 2020-01-01 9999-01-01 --step 20000000wk
+            | get year
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "2020");
+}
+
+#[test]
+fn refuses_range_steps_too_long_to_move_a_date_by() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            date range 2020-01-01 9999-01-01 --step 100000000000wk
+        "#
+    ));
+
+    assert!(actual.err.contains("Duration out of range"));
+}
+
+#[test]
+fn gives_the_next_times_a_cron_expression_fires() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            date next-cron '0 9 * * mon-fri' --from 2020-06-05 --timezone UTC --count 3
+            | get day
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[5,8,9]");
+}
+
+#[test]
+fn cron_times_follow_the_timezone() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            date next-cron '30 2 * * *' --from 2020-06-01 --timezone Europe/Paris
+            | select hour minute timezone
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"hour":2,"minute":30,"timezone":"CEST"}"#);
+}
+
+#[test]
+fn rejects_invalid_cron_expressions() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            date next-cron '0 25 * * *'
+        "#
+    ));
+
+    assert!(actual.err.contains("the hour field '25' is invalid"));
+}
@@ -0,0 +1,150 @@
+use nu_test_support::nu;
+
+#[test]
+fn def_gives_arguments_as_variables() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            def greet [greeting, name] { build-string $greeting ' ' $name }
+            greet hello nu
+        "#
+    );
+
+    assert_eq!(actual.out, "hello nu");
+}
+
+#[test]
+fn def_fills_in_defaults_of_optional_parameters() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            def greet [name: string, greeting = hello, nickname?] { echo $greeting $name $nickname | to json }
+            greet nu
+        "#
+    );
+
+    assert_eq!(actual.out, r#"["hello","nu",null]"#);
+}
+
+#[test]
+fn def_gives_the_rest_of_the_arguments_as_a_list() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            def all [first, ...others: int] { echo $others | to json }
+            all a 1 2 3
+        "#
+    );
+
+    assert_eq!(actual.out, "[1,2,3]");
+}
+
+#[test]
+fn def_checks_the_types_of_arguments() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            def double [x: int] { = $x * 2 }
+            double two
+        "#
+    );
+
+    assert!(actual.err.contains("Expected number"));
+}
+
+#[test]
+fn def_takes_switches_and_flags() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            def flags [--verbose(-v), --count: int = 3] { echo $verbose $count | to json }
+            flags
+            flags -v --count 5
+        "#
+    );
+
+    assert_eq!(actual.out, "[false,3][true,5]");
+}
+
+#[test]
+fn def_takes_blocks() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            def twice [f: block] { echo 1 2 | each $f | to json }
+            twice { = $it * 2 }
+        "#
+    );
+
+    assert_eq!(actual.out, "[2,4]");
+}
+
+#[test]
+fn def_gives_help_for_the_command() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            def greet [name: string, --shout(-s)] { echo $name }
+            greet --help
+        "#
+    );
+
+    assert!(actual.out.contains("<name> string"));
+    assert!(actual.out.contains("-s, --shout"));
+}
+
+#[test]
+fn def_rejects_required_parameters_after_optional_ones() {
+    let actual = nu!(cwd: ".", "def bad [x?, y] { echo $x }");
+
+    assert!(actual
+        .err
+        .contains("Required parameter after an optional one"));
+}
+
+#[test]
+fn def_is_documented_by_the_comments_above_it() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            # Greet someone by name.
+            #
+            # Say hello to nu
+            # > greet nu
+            def greet [name] { build-string 'hello ' $name }
+            help greet
+        "#
+    );
+
+    assert!(actual.out.contains("Greet someone by name."));
+    assert!(actual.out.contains("Say hello to nu"));
+    assert!(actual.out.contains("Examples:"));
+}
+
+#[test]
+fn def_takes_the_documentation_as_a_flag() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            def greet [name] { build-string 'hello ' $name } --usage 'Greet someone by name.'
+            help commands | where name == greet | get description
+        "#
+    );
+
+    assert_eq!(actual.out, "Greet someone by name.");
+}
+
+#[test]
+fn comments_only_document_the_def_right_below_them() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            # Not about greet
+
+            def greet [name] { build-string 'hello ' $name }
+            help commands | where name == greet | get description
+        "#
+    );
+
+    assert_eq!(actual.out, "");
+}
@@ -34,3 +34,31 @@ fn adds_row_data_if_column_missing() {
         assert_eq!(actual.out, "2");
     });
 }
+
+#[test]
+fn fills_empty_cells() {
+    Playground::setup("default_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "sales.csv",
+            r#"
+                item,amount
+                arepas,3
+                empanadas,
+                bolon,2
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sales.csv
+                | default amount 10
+                | get amount
+                | sum
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "15");
+    });
+}
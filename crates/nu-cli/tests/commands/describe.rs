@@ -0,0 +1,48 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn describes_a_single_value() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu", "tags": ["shell", 1]}'
+            | from json
+            | describe
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        "row<name: string, tags: table<string | integer>>"
+    );
+}
+
+#[test]
+fn summarizes_the_columns_of_a_table() {
+    Playground::setup("describe_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_luck
+                Andrés,Robalino,1
+                Jonathan,Turner,
+                Yehuda,Katz,3
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | describe
+                | where column == rusty_luck
+                | get max
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "3");
+    })
+}
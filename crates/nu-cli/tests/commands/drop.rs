@@ -1,4 +1,4 @@
-use nu_test_support::nu;
+use nu_test_support::{nu, pipeline};
 
 #[test]
 fn drop_rows() {
@@ -9,3 +9,17 @@ fn drop_rows() {
 
     assert_eq!(actual.out, "3");
 }
+
+#[test]
+fn drops_rows_by_index() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c d]
+            | drop nth 1 3
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","c"]"#);
+}
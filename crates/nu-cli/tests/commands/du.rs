@@ -0,0 +1,50 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn lists_every_directory_in_its_own_row() {
+    Playground::setup("du_test_1", |dirs, sandbox| {
+        sandbox
+            .within("a")
+            .within("b")
+            .within("c")
+            .with_files(vec![FileWithContent("deep.txt", "abcdefghij")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                du --flat
+                | get apparent
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, "[10,10,10]");
+    })
+}
+
+#[test]
+fn counts_the_directories_below_the_max_depth() {
+    Playground::setup("du_test_2", |dirs, sandbox| {
+        sandbox
+            .within("a")
+            .within("b")
+            .within("c")
+            .with_files(vec![FileWithContent("deep.txt", "abcdefghij")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                du --flat --max-depth 1
+                | select path apparent
+                | to json
+            "#
+        ));
+
+        assert_eq!(
+            actual.out,
+            r#"[{"path":"a","apparent":10},{"path":"a/b","apparent":10}]"#
+        );
+    })
+}
@@ -11,3 +11,44 @@ fn each_works_separately() {
 
     assert_eq!(actual.out, "[11,12,13]");
 }
+
+#[test]
+fn each_in_parallel_keeps_the_order_of_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5 6 7 8]
+            | each --parallel { = $it * 2 }
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[2,4,6,8,10,12,14,16]");
+}
+
+#[test]
+fn each_in_parallel_unordered_gives_every_output() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5 6 7 8]
+            | each --threads 3 --unordered { = $it * 2 }
+            | sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "72");
+}
+
+#[test]
+fn each_with_zero_threads_fails() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2]
+            | each --threads 0 { = $it * 2 }
+        "#
+    ));
+
+    assert!(actual.err.contains("can't be zero"));
+}
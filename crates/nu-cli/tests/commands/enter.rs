@@ -1,6 +1,7 @@
 use nu_test_support::fs::{files_exist_at, Stub::EmptyFile};
 use nu_test_support::nu;
 use nu_test_support::playground::Playground;
+use std::io::Write;
 use std::path::Path;
 
 #[test]
@@ -84,3 +85,99 @@ fn errors_if_file_not_found() {
         assert!(actual.err.contains("file not found"));
     })
 }
+
+fn write_zip(path: &Path, files: &[(&str, &str)]) {
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(path).expect("can't create zip"));
+    for (name, contents) in files {
+        zip.start_file(*name, zip::write::FileOptions::default())
+            .expect("can't add file");
+        zip.write_all(contents.as_bytes())
+            .expect("can't write file");
+    }
+    zip.finish().expect("can't finish zip");
+}
+
+fn write_tar_gz(path: &Path, files: &[(&str, &str)]) {
+    let file = std::fs::File::create(path).expect("can't create tar");
+    let mut tar = tar::Builder::new(flate2::write::GzEncoder::new(
+        file,
+        flate2::Compression::default(),
+    ));
+    for (name, contents) in files {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(contents.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        tar.append_data(&mut header, name, contents.as_bytes())
+            .expect("can't add file");
+    }
+    tar.into_inner()
+        .and_then(|gz| gz.finish())
+        .expect("can't finish tar");
+}
+
+#[test]
+fn lists_and_opens_files_inside_zip_archives() {
+    Playground::setup("enter_test_3", |dirs, _| {
+        write_zip(
+            &dirs.test().join("logs.zip"),
+            &[
+                ("2020/today.json", r#"{"level": "error"}"#),
+                ("notes.txt", "hello"),
+            ],
+        );
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "enter logs.zip; ls | get name | to json"
+        );
+        assert_eq!(actual.out, r#"["2020","notes.txt"]"#);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "enter logs.zip; cd 2020; open today.json | get level"
+        );
+        assert_eq!(actual.out, "error");
+    })
+}
+
+#[test]
+fn enters_directories_inside_tar_archives() {
+    Playground::setup("enter_test_4", |dirs, _| {
+        write_tar_gz(
+            &dirs.test().join("src.tar.gz"),
+            &[("src/main.rs", "fn main() {}"), ("README.md", "# hi")],
+        );
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "enter src.tar.gz/src; ls | get name"
+        );
+        assert_eq!(actual.out, "main.rs");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open src.tar.gz/src/main.rs | str ends-with \"main() {}\""
+        );
+        assert_eq!(actual.out, "true");
+    })
+}
+
+#[test]
+fn archives_are_read_only() {
+    Playground::setup("enter_test_5", |dirs, _| {
+        write_zip(&dirs.test().join("logs.zip"), &[("notes.txt", "hello")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "enter logs.zip; rm notes.txt"
+        );
+        assert!(actual.err.contains("archives are read-only"));
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "enter logs.zip; cd .."
+        );
+        assert!(actual.err.contains("use exit to leave the archive"));
+    })
+}
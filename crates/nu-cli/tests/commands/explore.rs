@@ -0,0 +1,11 @@
+use nu_test_support::nu;
+
+#[test]
+fn passes_the_rows_through_without_a_terminal() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "ls | sort-by name | explore | first 1 | get name | echo $it"
+    );
+
+    assert_eq!(actual.out, "appveyor.yml");
+}
@@ -0,0 +1,87 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn finds_rows_with_any_cell_containing_the_text() {
+    Playground::setup("find_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_luck
+                Andrés,Robalino,1
+                Jonathan,Turner,1
+                Yehuda,Katz,1
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | find an ner
+                | get first_name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "Jonathan");
+    })
+}
+
+#[test]
+fn restricts_the_search_to_the_given_columns() {
+    Playground::setup("find_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_luck
+                Andrés,Robalino,1
+                Jonathan,Turner,1
+                Yehuda,Katz,1
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | find --columns last_name --insensitive a
+                | get first_name
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"["Andrés","Yehuda"]"#);
+    })
+}
+
+#[test]
+fn inverts_regular_expression_matches() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "nu", "meta": {"version": "0.16.0"}}, {"name": "git", "meta": {"version": "2.27.0"}}]'
+            | from json
+            | find --invert --regex '^0\.'
+            | get name
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "git");
+}
+
+#[test]
+fn reports_unknown_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "nu"}]'
+            | from json
+            | find --columns nme nu
+        "#
+    ));
+
+    assert!(actual.err.contains("name"));
+}
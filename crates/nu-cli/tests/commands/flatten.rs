@@ -0,0 +1,62 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn flattens_nested_json() {
+    Playground::setup("flatten_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "posts.json",
+            r#"
+                [
+                    {"title": "nu", "meta": {"stars": 9000}, "tags": ["shell", "rust"]},
+                    {"title": "jq", "meta": {"stars": 20000}, "tags": []}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open posts.json
+                | flatten
+                | to json
+            "#
+        ));
+
+        assert_eq!(
+            actual.out,
+            r#"[{"title":"nu","meta.stars":9000,"tags":"shell"},{"title":"nu","meta.stars":9000,"tags":"rust"},{"title":"jq","meta.stars":20000,"tags":null}]"#
+        );
+    })
+}
+
+#[test]
+fn flattens_only_the_given_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"meta": {"stars": 1}, "tags": ["a", "b"]}'
+            | from json
+            | flatten tags
+            | get meta.stars
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,1]");
+}
+
+#[test]
+fn flattens_up_to_the_given_depth() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[1 [2 3]] [4]]
+            | flatten --depth 1
+            | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
@@ -0,0 +1,102 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+
+#[test]
+fn for_loops_over_a_range() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            for x in 1..3 { = $x * 10 } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, "[10,20,30]");
+}
+
+#[test]
+fn for_loops_over_a_list() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            for name in [andres jonathan yehuda] { echo $name } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, r#"["andres","jonathan","yehuda"]"#);
+}
+
+#[test]
+fn for_loops_over_the_rows_of_a_variable() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            let numbers = $(echo [1 2 3])
+            for n in $numbers { = $n + 1 } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, "[2,3,4]");
+}
+
+#[test]
+fn for_loops_can_be_nested() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            for x in 1..2 { for y in [a b] { build-string $x $y } } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, r#"["1a","1b","2a","2b"]"#);
+}
+
+#[test]
+fn for_skips_the_rest_of_a_pass_with_continue() {
+    Playground::setup("for_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            FileWithContent("a.txt", "first"),
+            FileWithContent("c.txt", "third"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                for name in [a b c] { try { open $(build-string $name .txt) } catch { continue } } | to json
+            "#
+        );
+
+        assert_eq!(actual.out, r#"["first","third"]"#);
+    })
+}
+
+#[test]
+fn for_stops_with_break() {
+    Playground::setup("for_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            FileWithContent("a.txt", "first"),
+            FileWithContent("c.txt", "third"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                for name in [a b c] { try { open $(build-string $name .txt) } catch { break } } | to json
+            "#
+        );
+
+        assert_eq!(actual.out, r#""first""#);
+    })
+}
+
+#[test]
+fn for_needs_the_word_in() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            for x on [1 2] { echo $x }
+        "#
+    );
+
+    assert!(actual.err.contains("Expected in"));
+}
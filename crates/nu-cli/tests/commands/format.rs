@@ -1,3 +1,5 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
 use nu_test_support::{nu, pipeline};
 
 #[test]
@@ -42,3 +44,141 @@ fn can_use_variables() {
 
     assert_eq!(actual.out, "nu is a new type of shell");
 }
+
+#[test]
+fn formats_filesizes_in_the_given_unit() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo 1536000b
+            | format filesize --unit MB --precision 3
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "1.536 MB");
+}
+
+#[test]
+fn formats_the_filesizes_of_a_table() {
+    Playground::setup("format_filesize_test_1", |dirs, sandbox| {
+        let content = "x".repeat(3072);
+        sandbox.with_files(vec![FileWithContent("three_kibibytes.txt", &content)]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls
+                | format filesize --binary
+                | get size
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "3.0 KiB");
+    })
+}
+
+#[test]
+fn formats_filesizes_only() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo 42
+            | format filesize
+        "#
+    ));
+
+    assert!(actual.err.contains("Expected a filesize"));
+}
+
+#[test]
+fn formats_dates_with_strftime_patterns() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '2020-06-01 14:30:00'
+            | into date
+            | format date '%Y/%m/%d %H:%M' --utc
+        "#
+    ));
+
+    assert_eq!(actual.out, "2020/06/01 14:30");
+}
+
+#[test]
+fn formats_the_dates_of_a_table() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"name": "release", "when": "2020-06-01"}]'
+            | from json
+            | into date when
+            | date format '%a %e %b' when --utc
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"name":"release","when":"Mon  1 Jun"}"#);
+}
+
+#[test]
+fn formats_dates_in_the_given_locale() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '2020-02-05'
+            | into date
+            | format date '%A %e %B' --locale fr_FR --utc
+        "#
+    ));
+
+    assert_eq!(actual.out, "mercredi  5 février");
+}
+
+#[test]
+fn reports_invalid_date_patterns() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '2020-02-05'
+            | into date
+            | format date '%Y-%Q'
+        "#
+    ));
+
+    assert!(actual.err.contains("Invalid date pattern"));
+}
+
+#[test]
+fn humanizes_durations() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"job": "backup", "took": "2h10m"}, {"job": "sync", "took": "45s"}]'
+            | from json
+            | into duration took
+            | format duration took --humanize
+            | get took
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["2 hours","45 secs"]"#);
+}
+
+#[test]
+fn formats_durations_as_times_in_the_past() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '2020-06-01T12:00:00+00:00'
+            | into date
+            | = (date now) - $it
+            | format duration --ago
+            | str ends-with ' years ago'
+        "#
+    ));
+
+    assert_eq!(actual.out, "true");
+}
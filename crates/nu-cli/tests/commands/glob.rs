@@ -0,0 +1,85 @@
+use nu_test_support::fs::Stub::EmptyFile;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn matches_in_every_subdirectory_with_double_asterisk() {
+    Playground::setup("glob_test_1", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![EmptyFile("top.rs"), EmptyFile("top.txt")])
+            .within("src")
+            .within("commands")
+            .with_files(vec![EmptyFile("glob.rs"), EmptyFile("notes.md")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                glob '**/*.rs'
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"["src/commands/glob.rs","top.rs"]"#);
+    })
+}
+
+#[test]
+fn expands_braces() {
+    Playground::setup("glob_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            EmptyFile("Cargo.toml"),
+            EmptyFile("lib.rs"),
+            EmptyFile("README.md"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                glob '*.{rs,toml}'
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"["Cargo.toml","lib.rs"]"#);
+    })
+}
+
+#[test]
+fn keeps_only_directories_down_to_the_depth() {
+    Playground::setup("glob_test_3", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![EmptyFile("a.txt")])
+            .within("one")
+            .within("two")
+            .within("three")
+            .with_files(vec![EmptyFile("b.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                glob '**' --depth 2 --no-file
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"["one","one/two"]"#);
+    })
+}
+
+#[test]
+fn composes_with_each() {
+    Playground::setup("glob_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("a.txt"), EmptyFile("b.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                glob '[ab].txt' --no-dir
+                | each { open $it }
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"["fake data","fake data"]"#);
+    })
+}
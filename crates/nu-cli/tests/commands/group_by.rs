@@ -54,3 +54,47 @@ fn errors_if_given_unknown_column_name_is_missing() {
         assert!(actual.err.contains("Unknown column"));
     })
 }
+
+#[test]
+fn groups_by_block_result() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5]
+            | group-by { = $it > 2 }
+            | get true
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn groups_by_multiple_columns_into_nested_groups() {
+    Playground::setup("group_by_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_at,type
+                Andrés,Robalino,10/11/2013,A
+                Jonathan,Turner,10/12/2013,B
+                Yehuda,Katz,10/11/2013,A
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | group-by type rusty_at
+                | get A."10/11/2013"
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "2");
+    })
+}
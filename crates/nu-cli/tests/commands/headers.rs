@@ -29,3 +29,51 @@ fn headers_adds_missing_column_name() {
 
     assert_eq!(actual.out, "r1c1r2c1")
 }
+
+#[test]
+fn headers_uses_the_given_row_as_header() {
+    let actual = nu!(
+    cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo "report title|a b c|1 2 3"
+            | split row "|"
+            | split column " "
+            | headers --row 1
+            | get b
+            | echo $it"#
+    ));
+
+    assert_eq!(actual.out, "2")
+}
+
+#[test]
+fn headers_can_keep_the_header_row() {
+    let actual = nu!(
+    cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo "a b c|1 2 3"
+            | split row "|"
+            | split column " "
+            | headers --keep
+            | get a
+            | to json"#
+    ));
+
+    assert_eq!(actual.out, r#"["a","1"]"#)
+}
+
+#[test]
+fn headers_renames_repeated_names() {
+    let actual = nu!(
+    cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo "x y x|1 2 3"
+            | split row "|"
+            | split column " "
+            | headers
+            | get x_2
+            | echo $it"#
+    ));
+
+    assert_eq!(actual.out, "3")
+}
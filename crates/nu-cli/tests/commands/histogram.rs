@@ -72,7 +72,7 @@ fn count() {
             | from json
             | histogram bit
             | sort-by count
-            | reject frequency
+            | reject frequency percentage
             | to json
         "#
     ));
@@ -81,3 +81,89 @@ fn count() {
 
     assert_eq!(actual.out, bit_json);
 }
+
+#[test]
+fn counts_percentages() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b a a]
+            | histogram
+            | where value == a
+            | get percentage
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "75.0");
+}
+
+#[test]
+fn bins_numbers() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 2.5 4 5 9]
+            | histogram --bins 2
+            | reject frequency percentage
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"value":"1 - 5","count":4},{"value":"5 - 9","count":2}]"#
+    );
+}
+
+#[test]
+fn bins_numbers_between_the_given_edges() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "[{"age":3},{"age":15},{"age":42},{"age":67},{"age":120}]"
+            | from json
+            | histogram age --edges [0 18 65 100]
+            | reject frequency percentage
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"age":"0 - 18","count":2},{"age":"18 - 65","count":1},{"age":"65 - 100","count":1}]"#
+    );
+}
+
+#[test]
+fn counts_the_values_given_by_a_block() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5]
+            | histogram { = $it > 3 }
+            | reject frequency percentage
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"value":"false","count":3},{"value":"true","count":2}]"#
+    );
+}
+
+#[test]
+fn draws_empty_bars_when_no_number_is_in_the_edges() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [100 200]
+            | histogram --edges [0 10]
+            | select count frequency
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"count":0,"frequency":""}"#);
+}
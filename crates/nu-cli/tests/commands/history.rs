@@ -0,0 +1,48 @@
+// The history is kept in a directory of the playground, which nu finds from XDG_DATA_HOME on Linux
+#![cfg(target_os = "linux")]
+
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+
+#[test]
+fn gives_the_last_run_of_each_command_with_its_exit_status() {
+    Playground::setup("history_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "lines.txt",
+            "echo hi\n^false\necho hi\n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"with-env [XDG_DATA_HOME {}] {{ open lines.txt | nu | count; nu -c "history | select command exit_status | to json" }}"#,
+            dirs.test()
+        );
+
+        assert_eq!(
+            actual.out,
+            r#"[{"command":"^false","exit_status":1},{"command":"echo hi","exit_status":0}]"#
+        );
+    })
+}
+
+#[test]
+fn searches_for_the_letters_of_the_text_in_order() {
+    Playground::setup("history_test_2", |dirs, sandbox| {
+        sandbox.mkdir("nu").with_files(vec![FileWithContent(
+            "nu/history.txt",
+            "open data.csv\nls | sort-by size\nopen notes.csv\n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"with-env [XDG_DATA_HOME {}] {{ nu -c "history --search ocsv | select index command | to json" }}"#,
+            dirs.test()
+        );
+
+        assert_eq!(
+            actual.out,
+            r#"[{"index":0,"command":"open data.csv"},{"index":2,"command":"open notes.csv"}]"#
+        );
+    })
+}
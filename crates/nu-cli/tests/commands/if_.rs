@@ -0,0 +1,74 @@
+use nu_test_support::nu;
+
+#[test]
+fn if_runs_the_block_when_the_condition_holds() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            if 2 > 1 { echo yes } else { echo no } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, r#""yes""#);
+}
+
+#[test]
+fn if_without_else_outputs_nothing_when_the_condition_does_not_hold() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            if 1 > 2 { echo yes } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, "");
+    assert!(actual.err.is_empty());
+}
+
+#[test]
+fn if_runs_the_block_of_the_first_else_if_that_holds() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            let n = 15; if $n < 10 { echo small } else if $n < 20 { echo medium } else if $n < 30 { echo big } else { echo large } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, r#""medium""#);
+}
+
+#[test]
+fn if_runs_the_else_block_when_no_condition_holds() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            let n = 150; if $n < 10 { echo small } else if $n < 100 { echo medium } else { echo large } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, r#""large""#);
+}
+
+#[test]
+fn if_gives_the_input_to_the_block() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            echo [1 2 3] | if 1 == 1 { math max } else { math min } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn if_needs_a_block_after_else() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            if 1 > 2 { echo yes } else
+        "#
+    );
+
+    assert!(actual.err.contains("requires block parameter"));
+}
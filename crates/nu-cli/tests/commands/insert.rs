@@ -14,3 +14,34 @@ fn insert_plugin() {
 
     assert_eq!(actual.out, "1");
 }
+
+#[test]
+fn insert_keeps_the_type_of_the_value() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; [a]]
+            | insert number 5
+            | insert text "5"
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"name":"a","number":5,"text":"5"}"#);
+}
+
+#[test]
+fn insert_computed_from_each_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2]
+            | wrap size
+            | insert double { = $it.size * 2 }
+            | get double
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[2,4]");
+}
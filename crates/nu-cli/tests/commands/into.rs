@@ -0,0 +1,109 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn converts_columns_into_numbers() {
+    Playground::setup("into_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "stock.csv",
+            r#"
+                item,count,price
+                pencil,"10",1.5
+                binder,"3",4.25
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open stock.csv --raw
+                | from csv
+                | into string count
+                | into int count
+                | get count
+                | sum
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "13");
+    })
+}
+
+#[test]
+fn reports_the_cell_that_cannot_be_converted() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 three]
+            | into int
+            | to json
+        "#
+    ));
+
+    assert!(actual.err.contains("can't convert 'three' into int"));
+}
+
+#[test]
+fn ignores_errors_when_asked() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 three]
+            | into int --ignore-errors
+            | compact
+            | sum
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn converts_text_into_filesizes() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1kb "512 B"]
+            | into filesize
+            | into int
+            | sum
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "1536");
+}
+
+#[test]
+fn converts_text_into_dates() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "01/06/2020"
+            | into date --format "%d/%m/%Y"
+            | into string
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "2020-06-01T00:00:00+00:00");
+}
+
+#[test]
+fn converts_text_into_durations() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo ["1h30m" "90s" "0:45"]
+            | into duration
+            | where $it > 1min
+            | format duration
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["1:30:00","1:30"]"#);
+}
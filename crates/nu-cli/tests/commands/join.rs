@@ -0,0 +1,82 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+fn caballeros_and_countries(sandbox: &mut Playground) {
+    sandbox.with_files(vec![
+        FileWithContentToBeTrimmed(
+            "caballeros.csv",
+            r#"
+                name,country
+                Andrés,EC
+                Jonathan,NZ
+                Yehuda,US
+            "#,
+        ),
+        FileWithContentToBeTrimmed(
+            "countries.csv",
+            r#"
+                country,name
+                EC,Ecuador
+                US,United States
+                DE,Germany
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn inner_joins_on_key_column() {
+    Playground::setup("join_test_1", |dirs, sandbox| {
+        caballeros_and_countries(sandbox);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open caballeros.csv
+                | join { open countries.csv } country
+                | where name == Yehuda
+                | get name_right
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "United States");
+    })
+}
+
+#[test]
+fn outer_join_keeps_every_row() {
+    Playground::setup("join_test_2", |dirs, sandbox| {
+        caballeros_and_countries(sandbox);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open caballeros.csv
+                | join --outer --suffix _country { open countries.csv } country
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "4");
+    })
+}
+
+#[test]
+fn errors_on_conflicting_join_kinds() {
+    Playground::setup("join_test_3", |dirs, sandbox| {
+        caballeros_and_countries(sandbox);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open caballeros.csv
+                | join --left --right { open countries.csv } country
+            "#
+        ));
+
+        assert!(actual.err.contains("Conflicting join kinds"));
+    })
+}
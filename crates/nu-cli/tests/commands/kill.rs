@@ -0,0 +1,73 @@
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+
+#[cfg(unix)]
+fn sleeping() -> std::process::Child {
+    std::process::Command::new("sleep")
+        .arg("60")
+        .spawn()
+        .expect("can't run sleep")
+}
+
+#[cfg(unix)]
+#[test]
+fn kills_pids_from_the_pipeline() {
+    use std::os::unix::process::ExitStatusExt;
+
+    Playground::setup("kill_test_1", |dirs, _| {
+        let mut first = sleeping();
+        let mut second = sleeping();
+
+        let command = format!(
+            r#"echo '[{{"pid": {}}}, {{"pid": {}}}]' | from json | kill"#,
+            first.id(),
+            second.id()
+        );
+        nu!(cwd: dirs.test(), &command);
+
+        assert_eq!(first.wait().expect("sleep didn't end").signal(), Some(15));
+        assert_eq!(second.wait().expect("sleep didn't end").signal(), Some(15));
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn sends_signals_by_name_or_number() {
+    use std::os::unix::process::ExitStatusExt;
+
+    Playground::setup("kill_test_2", |dirs, _| {
+        let mut child = sleeping();
+        let command = format!("kill --signal sigint {}", child.id());
+        nu!(cwd: dirs.test(), &command);
+        assert_eq!(child.wait().expect("sleep didn't end").signal(), Some(2));
+
+        let mut child = sleeping();
+        let command = format!("kill -s 9 {}", child.id());
+        nu!(cwd: dirs.test(), &command);
+        assert_eq!(child.wait().expect("sleep didn't end").signal(), Some(9));
+    })
+}
+
+#[test]
+fn errors_without_pids() {
+    Playground::setup("kill_test_3", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"echo '[{"name": "sleep"}]' | from json | kill"#
+        );
+
+        assert!(actual.err.contains("expected a process id"));
+    })
+}
+
+#[test]
+fn errors_if_given_force_and_signal() {
+    Playground::setup("kill_test_4", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            "kill --force --signal HUP 12345"
+        );
+
+        assert!(actual.err.contains("--force already sends KILL"));
+    })
+}
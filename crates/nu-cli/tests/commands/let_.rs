@@ -0,0 +1,147 @@
+use nu_test_support::nu;
+
+#[test]
+fn let_sets_a_variable_for_the_rest_of_the_line() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            let threshold = 10; echo [1 20 300] | where $it > $threshold | to json
+        "#
+    );
+
+    assert_eq!(actual.out, "[20,300]");
+}
+
+#[test]
+fn let_takes_an_expression() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            let total = 1 + 2 * 3; echo $total
+        "#
+    );
+
+    assert_eq!(actual.out, "7");
+}
+
+#[test]
+fn let_keeps_the_variable_for_the_next_lines() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            let name = nu
+            echo $name
+        "#
+    );
+
+    assert_eq!(actual.out, "nu");
+}
+
+#[test]
+fn let_sets_variables_inside_blocks() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            echo [1 2] | each { let double = $it * 2; = $double + 1 } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, "[3,5]");
+}
+
+#[test]
+fn let_has_to_be_on_its_own() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            echo 1 | let x = 2
+        "#
+    );
+
+    assert!(actual.err.contains("let has to be on its own"));
+}
+
+#[test]
+fn let_needs_an_equals_sign() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            let x 2
+        "#
+    );
+
+    assert!(actual.err.contains("Expected ="));
+}
+
+#[test]
+fn commands_keep_the_variables_they_were_defined_with() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            let greeting = hello
+            def greet [name] { echo $greeting $name | to json }
+            let greeting = bye
+            greet nu
+        "#
+    );
+
+    assert_eq!(actual.out, r#"["hello","nu"]"#);
+}
+
+#[test]
+fn commands_defined_in_commands_keep_their_parameters() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            def outer [x] { def inner [] { echo $x } }
+            outer 3
+            inner
+        "#
+    );
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn commands_do_not_see_the_variables_of_their_caller() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            def show [] { echo $secret | empty? }
+            let secret = 42
+            show
+        "#
+    );
+
+    assert_eq!(actual.out, "true");
+}
+
+#[test]
+fn blocks_keep_the_variables_they_were_made_with() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            let x = 5
+            let b = { echo $x }
+            let x = 6
+            echo 1 | each $b
+        "#
+    );
+
+    assert_eq!(actual.out, "5");
+}
+
+#[test]
+fn parameters_take_the_place_of_the_variables_blocks_were_made_with() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            let name = outer
+            def greet [name] { echo $name }
+            let acc = 100
+            echo (greet inner) (echo [1 2 3] | reduce { = $acc + $it }) | to json
+        "#
+    );
+
+    assert_eq!(actual.out, r#"["inner",6]"#);
+}
@@ -19,3 +19,31 @@ fn lines() {
 
     assert_eq!(actual.out, "rustyline");
 }
+
+#[test]
+fn lines_joins_a_line_given_in_parts() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            ^sh -c "printf ER; sleep 1; echo ROR"
+            | lines
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#""ERROR""#);
+}
+
+#[test]
+fn lines_gives_the_last_line_without_a_line_ending() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "nu"
+            | lines
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#""nu""#);
+}
@@ -0,0 +1,95 @@
+use nu_test_support::fs::Stub::{EmptyFile, FileWithContent};
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+use std::path::Path;
+
+#[cfg(unix)]
+#[test]
+fn creates_a_symbolic_link_in_the_directory() {
+    Playground::setup("ln_test_1", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![EmptyFile("data.csv")])
+            .mkdir("links");
+
+        nu!(
+            cwd: dirs.test(),
+            "ln ../data.csv links"
+        );
+
+        let link = dirs.test().join("links/data.csv");
+        let target = std::fs::read_link(&link).expect("the link was not created");
+
+        assert_eq!(target, Path::new("../data.csv"));
+        assert!(link.exists());
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn points_relative_links_from_the_link_directory() {
+    Playground::setup("ln_test_2", |dirs, sandbox| {
+        sandbox
+            .within("build")
+            .with_files(vec![EmptyFile("nu")])
+            .mkdir("bin");
+
+        nu!(
+            cwd: dirs.test(),
+            "ln --relative build/nu bin/nu"
+        );
+
+        let target =
+            std::fs::read_link(dirs.test().join("bin/nu")).expect("the link was not created");
+
+        assert_eq!(target, Path::new("../build/nu"));
+    })
+}
+
+#[test]
+fn creates_hard_links() {
+    Playground::setup("ln_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("original.txt", "hello")]);
+
+        nu!(
+            cwd: dirs.test(),
+            "ln --hard original.txt copy.txt"
+        );
+
+        let copy = dirs.test().join("copy.txt");
+
+        assert!(!std::fs::symlink_metadata(&copy)
+            .expect("the link was not created")
+            .file_type()
+            .is_symlink());
+        assert_eq!(
+            std::fs::read_to_string(copy).expect("can not read the link"),
+            "hello"
+        );
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn does_not_replace_files_unless_forced() {
+    Playground::setup("ln_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("target.txt"), EmptyFile("taken.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "ln target.txt taken.txt"
+        );
+
+        assert!(actual.err.contains("already exists"));
+
+        nu!(
+            cwd: dirs.test(),
+            "ln target.txt taken.txt --backup"
+        );
+
+        assert!(dirs.test().join("taken.txt~").exists());
+        assert_eq!(
+            std::fs::read_link(dirs.test().join("taken.txt")).expect("the link was not created"),
+            Path::new("target.txt")
+        );
+    })
+}
@@ -0,0 +1,74 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn sets_variables_from_a_row() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo '{"FOO": "bar", "BAZ": 1}' | from json | load-env; echo $nu.env.FOO $nu.env.BAZ | to json"#
+    );
+
+    assert_eq!(actual.out, r#"["bar","1"]"#);
+}
+
+#[test]
+fn sets_variables_from_pairs() {
+    let actual = nu!(
+        cwd: ".",
+        "load-env [FOO bar]; echo $nu.env.FOO"
+    );
+
+    assert_eq!(actual.out, "bar");
+}
+
+#[cfg(unix)]
+#[test]
+fn sets_variables_for_externals() {
+    let actual = nu!(
+        cwd: ".",
+        "load-env [FOO bar]; ^printenv FOO"
+    );
+
+    assert_eq!(actual.out, "bar");
+}
+
+#[test]
+fn loads_a_dotenv_file() {
+    Playground::setup("load_env_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            ".env",
+            r#"
+                DATABASE_URL = "postgres://localhost/nu"
+                WORKERS = 4
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open .env | from toml | load-env;
+                echo $nu.env.DATABASE_URL $nu.env.WORKERS | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"["postgres://localhost/nu","4"]"#);
+    })
+}
+
+#[test]
+fn env_gives_the_scope_of_each_variable() {
+    let actual = nu!(
+        cwd: ".",
+        pipeline(
+        r#"
+            load-env [LOADED yes];
+            with-env [BLOCKED yes] { env | where name == LOADED || name == BLOCKED | to json }
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"name":"BLOCKED","value":"yes","scope":"block"},{"name":"LOADED","value":"yes","scope":"session"}]"#
+    );
+}
@@ -0,0 +1,62 @@
+use nu_test_support::nu;
+
+#[test]
+fn loop_runs_until_break() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            loop { echo looped; break } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, "");
+    assert!(actual.err.is_empty());
+}
+
+#[test]
+fn loop_stops_at_an_error() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            loop { open no_such_file.txt }
+        "#
+    );
+
+    assert!(actual.err.contains("file not found"));
+}
+
+#[test]
+fn break_is_not_caught_by_try() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            for x in [1 2] { try { break } catch { echo caught } } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, "");
+}
+
+#[test]
+fn break_has_to_be_in_a_loop() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            break
+        "#
+    );
+
+    assert!(actual.err.contains("break can only be used in a loop"));
+}
+
+#[test]
+fn continue_has_to_be_in_a_loop() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            echo [1 2] | each { continue }
+        "#
+    );
+
+    assert!(actual.err.contains("continue can only be used in a loop"));
+}
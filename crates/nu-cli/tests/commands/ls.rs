@@ -1,4 +1,4 @@
-use nu_test_support::fs::Stub::EmptyFile;
+use nu_test_support::fs::Stub::{EmptyFile, FileWithContent};
 use nu_test_support::playground::Playground;
 use nu_test_support::{nu, pipeline};
 
@@ -154,3 +154,54 @@ fn list_files_from_two_parents_up_using_multiple_dots() {
         assert_eq!(actual.out, "5");
     })
 }
+
+#[test]
+fn lists_the_total_size_of_directories_with_du() {
+    Playground::setup("ls_test_du", |dirs, sandbox| {
+        sandbox
+            .within("dir")
+            .within("nested")
+            .with_files(vec![FileWithContent("a.txt", "12345")])
+            .with_files(vec![FileWithContent("dir/b.txt", "678")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls --du
+                | select name size
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"{"name":"dir","size":8}"#);
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn lists_octal_permissions_and_links_with_long() {
+    use std::os::unix::fs::PermissionsExt;
+
+    Playground::setup("ls_test_long", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("secret.txt"), EmptyFile("public.txt")]);
+
+        let secret = dirs.test().join("secret.txt");
+        std::fs::set_permissions(&secret, std::fs::Permissions::from_mode(0o600))
+            .expect("can not set permissions");
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls --long
+                | where octal == "0600"
+                | select name mode links
+                | to json
+            "#
+        ));
+
+        assert_eq!(
+            actual.out,
+            r#"{"name":"secret.txt","mode":"rw-------","links":1}"#
+        );
+    })
+}
@@ -0,0 +1,99 @@
+use nu_test_support::nu;
+
+#[test]
+fn match_runs_the_first_arm_that_matches() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            match 1 { 1 => "first", 1 => "second" } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, r#""first""#);
+}
+
+#[test]
+fn match_matches_ranges() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            match 7 { 1 => "one", 2..5 => "a few", 6..10 => "several" } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, r#""several""#);
+}
+
+#[test]
+fn match_matches_types() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            match "hello" { int => "number", string => "text" } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, r#""text""#);
+}
+
+#[test]
+fn match_falls_back_on_the_wildcard() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            match 42 { 1 => "one", "x" => "x", _ => "other" } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, r#""other""#);
+}
+
+#[test]
+fn match_errors_when_no_arm_matches() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            match 42 { 1 => "one" } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, "");
+    assert!(actual.err.contains("No arm matches"));
+}
+
+#[test]
+fn match_runs_blocks_with_the_input() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            echo [1 2 3] | match max { "min" => { math min }, "max" => { math max } } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn match_errors_on_unknown_types() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            match "x" { x => 1 }
+        "#
+    );
+
+    assert!(actual.err.contains("Unknown type"));
+}
+
+#[test]
+fn match_still_filters_by_regex_in_the_deprecated_form() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"
+            ls | match name '.*\.tsv$' | get name | to json
+        "#
+    );
+
+    assert_eq!(actual.out, r#""caco3_plastics.tsv""#);
+    assert!(actual.err.contains("deprecated"));
+}
@@ -179,3 +179,43 @@ fn compound_where_paren() {
 
     assert_eq!(actual.out, r#"[{"a":2,"b":1},{"a":2,"b":2}]"#);
 }
+
+#[test]
+fn multiplies_filesizes() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 3 * 1kb * 2
+            | format filesize --unit B
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "6144 B");
+}
+
+#[test]
+fn divides_filesizes() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 10mb / 4
+            | format filesize --unit MiB
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "2.5 MiB");
+}
+
+#[test]
+fn ratio_of_filesizes() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            = 10mb / 4mb
+        "#
+    ));
+
+    assert_eq!(actual.out, "2.5");
+}
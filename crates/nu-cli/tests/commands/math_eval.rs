@@ -0,0 +1,67 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn evaluates_an_expression() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            math eval "floor(sqrt(17)) * 2 ^ 3"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "32.0");
+}
+
+#[test]
+fn evaluates_the_expressions_in_the_pipeline() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo ["1 + 2" "10 / 4"]
+            | math eval
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[3.0,2.5]");
+}
+
+#[test]
+fn evaluates_with_the_values_in_the_pipeline() {
+    Playground::setup("math_eval_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "triangles.csv",
+            r#"
+                a,b
+                3,4
+                5,12
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open triangles.csv
+                | math eval "sqrt($a^2 + $b^2)"
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, "[5.0,13.0]");
+    })
+}
+
+#[test]
+fn reports_expressions_that_cant_be_parsed() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            math eval "2 *"
+        "#
+    ));
+
+    assert!(actual.err.contains("Could not parse the expression"));
+}
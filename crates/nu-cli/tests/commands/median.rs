@@ -0,0 +1,44 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn median_of_numbers() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [5 1 4 2 3]
+            | math median
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn median_of_the_columns_of_a_table() {
+    Playground::setup("median_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "scores.csv",
+            r#"
+                first,second
+                1,10
+                2,40
+                3,20
+                4,30
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open scores.csv
+                | math median
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"{"first":2.5,"second":25.0}"#);
+    })
+}
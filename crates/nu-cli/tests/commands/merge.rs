@@ -41,3 +41,48 @@ fn row() {
         assert_eq!(actual.out, "2");
     })
 }
+
+#[test]
+fn table_given_directly() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2]
+            | wrap a
+            | merge $(echo [3 4] | wrap b)
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"[{"a":1,"b":3},{"a":2,"b":4}]"#);
+}
+
+#[test]
+fn keeps_rows_past_the_end_of_the_other_table() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | wrap a
+            | merge { echo [3] | wrap b }
+            | get b
+            | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn fails_with_values_that_are_not_tables() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2]
+            | wrap a
+            | merge 3
+        "#
+    ));
+
+    assert!(actual.err.contains("Expected a table or a block"));
+}
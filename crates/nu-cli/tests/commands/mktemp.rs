@@ -0,0 +1,71 @@
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+use std::path::Path;
+
+#[test]
+fn creates_a_file_in_the_temporary_directory() {
+    Playground::setup("mktemp_test_1", |dirs, _sandbox| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            "mktemp --suffix .json"
+        );
+
+        let path = Path::new(&actual.out);
+
+        assert!(path.is_file());
+        assert!(path.starts_with(std::env::temp_dir()));
+        assert!(actual.out.ends_with(".json"));
+
+        std::fs::remove_file(path).expect("could not remove the file");
+    })
+}
+
+#[test]
+fn creates_a_directory_from_a_template() {
+    Playground::setup("mktemp_test_2", |dirs, _sandbox| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            "mktemp -d build.XXXXXX"
+        );
+
+        let path = Path::new(&actual.out);
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        assert!(name.starts_with("build."));
+        assert_eq!(name.len(), "build.XXXXXX".len());
+        assert_eq!(path.parent(), Some(dirs.test().as_path()));
+        assert!(path.is_dir());
+    })
+}
+
+#[test]
+fn creates_it_in_the_given_directory() {
+    Playground::setup("mktemp_test_3", |dirs, sandbox| {
+        sandbox.mkdir("scratch");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "mktemp --tmpdir scratch"
+        );
+
+        let path = Path::new(&actual.out);
+
+        assert_eq!(path.parent(), Some(dirs.test().join("scratch").as_path()));
+        assert!(path.is_file());
+    })
+}
+
+#[test]
+fn errors_on_templates_without_enough_xs() {
+    Playground::setup("mktemp_test_4", |dirs, _sandbox| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            "mktemp build.XX"
+        );
+
+        assert!(actual.err.contains("Invalid template"));
+    })
+}
@@ -1,55 +1,112 @@
+mod abs;
+mod aggregate;
 mod alias;
+mod ansi;
 mod append;
 mod average;
+mod benchmark;
 mod cal;
 mod calc;
 mod cd;
+mod char_;
+mod chmod;
 mod compact;
+mod complete;
+mod config;
 mod cp;
+mod date;
+mod def;
 mod default;
+mod describe;
 mod drop;
+mod du;
 mod each;
 mod enter;
 mod every;
+mod explore;
+mod find;
 mod first;
+mod flatten;
+mod for_;
 mod format;
 mod get;
+mod glob;
 mod group_by;
 mod headers;
 mod histogram;
+mod history;
+mod if_;
 mod insert;
+mod into;
 mod is_empty;
+mod join;
 mod keep;
 mod keep_until;
 mod keep_while;
+mod kill;
 mod last;
+mod let_;
 mod lines;
+mod ln;
+mod load_env;
+mod loop_;
 mod ls;
+mod match_;
 mod math;
+mod math_eval;
+mod median;
 mod merge;
 mod mkdir;
+mod mktemp;
+mod mode;
+mod module;
+mod move_;
+mod mut_;
 mod mv;
+#[cfg(target_os = "linux")]
+mod netstat;
 mod open;
 mod parse;
+mod pathvar;
+mod percentile;
+mod pivot;
+mod poll;
+mod predicates;
 mod prepend;
+mod product;
+mod random;
 mod range;
+mod reduce;
 mod rename;
 mod reverse;
 mod rm;
+mod rotate;
+mod round;
+mod sample;
 mod save;
 mod select;
 mod semicolon;
+mod seq;
 mod skip_until;
 mod sort_by;
+mod source;
 mod split_by;
 mod split_column;
 mod split_row;
 mod str_;
 mod sum;
 mod touch;
+mod tree;
 mod trim;
+mod try_;
 mod uniq;
 mod update;
+mod use_;
+mod variance;
 mod where_;
+mod while_;
+mod window;
 mod with_env;
 mod wrap;
+mod z;
+mod zip;
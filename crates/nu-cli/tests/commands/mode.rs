@@ -0,0 +1,15 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn mode_of_values() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [b a c a b d]
+            | math mode
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","b"]"#);
+}
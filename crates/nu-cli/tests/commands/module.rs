@@ -0,0 +1,78 @@
+use nu_test_support::nu;
+
+#[test]
+fn module_commands_are_called_with_the_module_name() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            module greetings { def hello [name] { build-string 'hello ' $name } }
+            greetings hello nu
+        "#
+    );
+
+    assert_eq!(actual.out, "hello nu");
+}
+
+#[test]
+fn module_commands_are_not_defined_outside_of_the_module() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            module greetings { def hello [name] { build-string 'hello ' $name } }
+            hello nu
+        "#
+    );
+
+    assert!(actual.err.contains("Command not found"));
+}
+
+#[test]
+fn module_commands_call_each_other_without_the_module_name() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            module arith { def double [x: int] { = $x * 2 }; def quadruple [x: int] { double $x | double $it } }
+            arith quadruple 3
+        "#
+    );
+
+    assert_eq!(actual.out, "12");
+}
+
+#[test]
+fn module_exports_aliases() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            module shortcuts { alias say [word] { echo $word } }
+            shortcuts say hi
+        "#
+    );
+
+    assert_eq!(actual.out, "hi");
+}
+
+#[test]
+fn module_env_is_only_set_when_used() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            module editor { load-env [MODULE_EDITOR vim] }
+            env | where name == MODULE_EDITOR | count
+        "#
+    );
+
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn module_cannot_take_the_name_of_a_command() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            module ls { def hello [] { echo hi } }
+        "#
+    );
+
+    assert!(actual.err.contains("there is already a command named ls"));
+}
@@ -0,0 +1,74 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn moves_columns_before_another() {
+    Playground::setup("move_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_at,type
+                Andrés,Robalino,10/11/2013,A
+                Jonathan,Turner,10/12/2013,B
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | move type rusty_at --before first_name
+                | to csv
+                | lines
+                | first 1
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "type,rusty_at,first_name,last_name");
+    })
+}
+
+#[test]
+fn moves_columns_last() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"a": 1, "b": 2, "c": 3}'
+            | from json
+            | move a --last
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"b":2,"c":3,"a":1}"#);
+}
+
+#[test]
+fn requires_one_destination() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"a": 1, "b": 2, "c": 3}'
+            | from json
+            | move a --first --last
+        "#
+    ));
+
+    assert!(actual.err.contains("Expected one destination"));
+}
+
+#[test]
+fn suggests_columns_that_exist() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": 1, "size": 2}'
+            | from json
+            | move sise --first
+        "#
+    ));
+
+    assert!(actual.err.contains("did you mean 'size'?"));
+}
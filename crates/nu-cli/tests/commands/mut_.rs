@@ -0,0 +1,109 @@
+use nu_test_support::nu;
+
+#[test]
+fn mut_variables_can_be_set_again() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            mut greeting = hello; $greeting = hi; echo $greeting
+        "#
+    );
+
+    assert_eq!(actual.out, "hi");
+}
+
+#[test]
+fn mut_variables_can_be_added_to_and_taken_from() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            mut n = 10; $n += 5; $n -= 3; echo $n
+        "#
+    );
+
+    assert_eq!(actual.out, "12");
+}
+
+#[test]
+fn mut_variables_keep_their_value_across_the_passes_of_a_loop() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            mut total = 0; for x in 1..4 { $total += $x }; echo $total
+        "#
+    );
+
+    assert_eq!(actual.out, "10");
+}
+
+#[test]
+fn mut_variables_can_be_the_condition_of_a_while() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            mut n = 0; while $n < 3 { $n += 1; echo $n } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, "[1,2,3]");
+}
+
+#[test]
+fn mut_variables_can_be_set_in_if_blocks() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            mut size = small; if 2 > 1 { $size = big }; echo $size
+        "#
+    );
+
+    assert_eq!(actual.out, "big");
+}
+
+#[test]
+fn mut_variables_give_way_to_loop_variables_of_the_same_name() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            mut x = 1; for x in [5 6] { echo $x } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, "[5,6]");
+}
+
+#[test]
+fn let_variables_cannot_be_set_again() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            let greeting = hello; $greeting = hi
+        "#
+    );
+
+    assert!(actual.err.contains("was not made with mut"));
+}
+
+#[test]
+fn assignments_have_to_be_on_their_own() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            mut n = 1; $n = 2 | echo
+        "#
+    );
+
+    assert!(actual.err.contains("assignment on its own"));
+}
+
+#[test]
+fn compound_assignments_need_values_of_a_matching_type() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            mut n = 1; $n += abc
+        "#
+    );
+
+    assert!(actual.err.contains("Coercion error"));
+}
@@ -1,6 +1,7 @@
-use nu_test_support::fs::{files_exist_at, Stub::EmptyFile};
+use nu_test_support::fs::{files_exist_at, Stub::EmptyFile, Stub::FileWithContent};
 use nu_test_support::nu;
 use nu_test_support::playground::Playground;
+use std::path::Path;
 
 #[test]
 fn moves_a_file() {
@@ -331,3 +332,66 @@ fn move_file_from_two_parents_up_using_multiple_dots_to_current_dir() {
         assert!(!original.exists());
     })
 }
+
+#[test]
+fn renames_the_files_in_the_pipeline_using_a_block() {
+    Playground::setup("mv_test_14", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            EmptyFile("andres.jpeg"),
+            EmptyFile("yehuda.jpeg"),
+            EmptyFile("jonathan.txt"),
+        ]);
+
+        nu!(
+            cwd: dirs.test(),
+            "ls *.jpeg | mv --using { echo $it.name | str find-replace jpeg jpg }"
+        );
+
+        assert!(files_exist_at(
+            vec![
+                Path::new("andres.jpg"),
+                Path::new("yehuda.jpg"),
+                Path::new("jonathan.txt")
+            ],
+            dirs.test()
+        ));
+        assert!(!dirs.test().join("andres.jpeg").exists());
+    })
+}
+
+#[test]
+fn moves_the_files_in_a_table_of_from_and_to_columns() {
+    Playground::setup("mv_test_15", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![
+                EmptyFile("a.txt"),
+                EmptyFile("b.txt"),
+                FileWithContent("renames.csv", "from,to\na.txt,first.txt\nb.txt,done\n"),
+            ])
+            .mkdir("done");
+
+        nu!(
+            cwd: dirs.test(),
+            "open renames.csv | mv"
+        );
+
+        assert!(dirs.test().join("first.txt").exists());
+        assert!(dirs.test().join("done/b.txt").exists());
+        assert!(!dirs.test().join("a.txt").exists());
+    })
+}
+
+#[test]
+fn errors_without_a_destination() {
+    Playground::setup("mv_test_16", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("a.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "mv a.txt"
+        );
+
+        assert!(actual.err.contains("Expected a destination"));
+        assert!(dirs.test().join("a.txt").exists());
+    })
+}
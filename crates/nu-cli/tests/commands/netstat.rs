@@ -0,0 +1,39 @@
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+
+#[test]
+fn lists_listening_sockets_with_their_process() {
+    Playground::setup("netstat_test_1", |dirs, _| {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("can't listen");
+        let port = listener.local_addr().expect("no address").port();
+
+        let command = format!(
+            r#"netstat --tcp --listening | where "local port" == {} | get pid | echo $it"#,
+            port
+        );
+        let actual = nu!(cwd: dirs.test(), &command);
+
+        assert_eq!(actual.out, std::process::id().to_string());
+    })
+}
+
+#[test]
+fn lists_udp_sockets_only_when_asked() {
+    Playground::setup("netstat_test_2", |dirs, _| {
+        let socket = std::net::UdpSocket::bind("127.0.0.1:0").expect("can't bind");
+        let port = socket.local_addr().expect("no address").port();
+
+        let command = format!(
+            r#"netstat --udp | where "local port" == {} | get state | echo $it"#,
+            port
+        );
+        let actual = nu!(cwd: dirs.test(), &command);
+
+        assert_eq!(actual.out, "UNCONN");
+
+        let command = format!(r#"netstat --tcp | where "local port" == {} | count"#, port);
+        let actual = nu!(cwd: dirs.test(), &command);
+
+        assert_eq!(actual.out, "0");
+    })
+}
@@ -0,0 +1,89 @@
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn adds_to_the_start_of_the_path() {
+    Playground::setup("pathvar_test_1", |dirs, sandbox| {
+        sandbox.mkdir("bin");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "pathvar add bin; pathvar | first | get path | echo $it"
+        );
+
+        assert_eq!(actual.out, dirs.test().join("bin").to_string_lossy());
+    })
+}
+
+#[test]
+fn appends_to_the_end_of_the_path() {
+    Playground::setup("pathvar_test_2", |dirs, sandbox| {
+        sandbox.mkdir("bin");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "pathvar append bin; pathvar | last | get path | echo $it"
+        );
+
+        assert_eq!(actual.out, dirs.test().join("bin").to_string_lossy());
+    })
+}
+
+#[test]
+fn keeps_one_of_each_directory() {
+    Playground::setup("pathvar_test_3", |dirs, sandbox| {
+        sandbox.mkdir("bin");
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                pathvar add bin;
+                pathvar append bin;
+                pathvar add bin;
+                pathvar | get path | to json
+            "#
+        ));
+
+        let bin = format!("{:?}", dirs.test().join("bin"));
+        assert_eq!(actual.out.matches(&bin).count(), 1);
+    })
+}
+
+#[test]
+fn removes_from_the_path() {
+    Playground::setup("pathvar_test_4", |dirs, sandbox| {
+        sandbox.mkdir("bin");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "pathvar add bin; pathvar remove bin; pathvar | first | get path | echo $it"
+        );
+
+        assert_ne!(actual.out, dirs.test().join("bin").to_string_lossy());
+    })
+}
+
+#[test]
+fn errors_if_the_directory_does_not_exist() {
+    Playground::setup("pathvar_test_5", |dirs, _| {
+        let actual = nu!(cwd: dirs.test(), "pathvar add not_here");
+
+        assert!(actual.err.contains("Not a directory"));
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "pathvar add --force not_here; pathvar | first | get exists | echo $it"
+        );
+
+        assert_eq!(actual.out, "false");
+    })
+}
+
+#[test]
+fn errors_if_removing_a_directory_not_in_the_path() {
+    Playground::setup("pathvar_test_6", |dirs, _| {
+        let actual = nu!(cwd: dirs.test(), "pathvar remove not_here");
+
+        assert!(actual.err.contains("Not in the PATH"));
+    })
+}
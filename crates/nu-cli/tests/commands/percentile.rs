@@ -0,0 +1,73 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn percentile_of_numbers() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [15 20 35 40 50]
+            | math percentile --nth 40
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "29.0");
+}
+
+#[test]
+fn percentile_of_the_columns_of_a_table() {
+    Playground::setup("percentile_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "requests.csv",
+            r#"
+                path,latency,bytes
+                /,120,512
+                /about,80,2048
+                /,100,1024
+                /blog,300,4096
+                /,90,256
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open requests.csv
+                | select latency bytes
+                | math percentile -n 75
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"{"latency":120,"bytes":2048}"#);
+    })
+}
+
+#[test]
+fn quartiles_of_numbers() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [7 1 3 5]
+            | math quartiles
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"q1":2.5,"median":4.0,"q3":5.5}"#);
+}
+
+#[test]
+fn percentile_out_of_range() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | math percentile --nth 101
+        "#
+    ));
+
+    assert!(actual.err.contains("Expected a percentile from 0 to 100"));
+}
@@ -0,0 +1,74 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+fn cpu_measurements(sandbox: &mut Playground) {
+    sandbox.with_files(vec![FileWithContentToBeTrimmed(
+        "cpu.csv",
+        r#"
+            date,host,cpu
+            2020-06-01,web,10
+            2020-06-01,db,50
+            2020-06-02,web,20
+            2020-06-02,web,30
+        "#,
+    )]);
+}
+
+#[test]
+fn reshapes_long_to_wide_aggregating_duplicates() {
+    Playground::setup("pivot_test_1", |dirs, sandbox| {
+        cpu_measurements(sandbox);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open cpu.csv
+                | pivot --index date --columns host --values cpu --agg sum
+                | where date == 2020-06-02
+                | get web
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "50");
+    })
+}
+
+#[test]
+fn errors_on_duplicate_cells_without_aggregation() {
+    Playground::setup("pivot_test_2", |dirs, sandbox| {
+        cpu_measurements(sandbox);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open cpu.csv
+                | pivot --index date --columns host --values cpu
+            "#
+        ));
+
+        assert!(actual.err.contains("use --agg"));
+    })
+}
+
+#[test]
+fn unpivot_reverses_pivot() {
+    Playground::setup("pivot_test_3", |dirs, sandbox| {
+        cpu_measurements(sandbox);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open cpu.csv
+                | pivot --index date --columns host --values cpu --agg max
+                | unpivot date --key-name host --value-name cpu
+                | where host == db
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "2");
+    })
+}
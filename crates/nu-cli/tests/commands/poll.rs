@@ -0,0 +1,54 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn runs_the_block_the_number_of_times_asked() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            poll 1sec --count 2 { echo a b }
+            | get value
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","b","a","b"]"#);
+}
+
+#[test]
+fn adds_the_time_to_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            poll -c 1 { echo '{"name": "nu"}' | from json }
+            | get time
+            | describe
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "date");
+}
+
+#[test]
+fn stops_at_the_first_error() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            poll { open no_such_file.txt }
+        "#
+    ));
+
+    assert!(actual.err.contains("Cannot open"));
+}
+
+#[test]
+fn errors_without_a_block() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            poll 1sec
+        "#
+    ));
+
+    assert!(actual.err.contains("Expected a block"));
+}
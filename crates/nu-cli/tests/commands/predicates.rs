@@ -0,0 +1,61 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn is_empty_checks_the_value_given() {
+    let actual = nu!(
+        cwd: ".",
+        r#"is-empty """#
+    );
+
+    assert_eq!(actual.out, "true");
+}
+
+#[test]
+fn is_empty_checks_the_input() {
+    let actual = nu!(
+        cwd: ".",
+        "echo [1 2] | is-empty"
+    );
+
+    assert_eq!(actual.out, "false");
+}
+
+#[test]
+fn is_empty_in_where() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; [Ada] [""] [Grace]]
+            | where (is-empty $it.name) == $false
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["Ada","Grace"]"#);
+}
+
+#[test]
+fn is_null_checks_missing_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name size]; [a 1]]
+            | append {name: b}
+            | where (is-null $it.size?)
+            | get name
+        "#
+    ));
+
+    assert_eq!(actual.out, "b");
+}
+
+#[test]
+fn is_null_is_false_for_values() {
+    let actual = nu!(
+        cwd: ".",
+        "is-null 0"
+    );
+
+    assert_eq!(actual.out, "false");
+}
@@ -28,3 +28,17 @@ fn adds_a_row_to_the_beginning() {
         assert_eq!(actual.out, "pollo loco");
     })
 }
+
+#[test]
+fn adds_the_rows_of_a_list_to_the_beginning() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [c d]
+            | prepend [a b]
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","b","c","d"]"#);
+}
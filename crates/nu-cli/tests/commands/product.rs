@@ -0,0 +1,15 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn product_of_numbers() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [2 3 1.5]
+            | math product
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "9.0");
+}
@@ -0,0 +1,103 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn generates_an_integer_in_the_range() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5 6 7 8 9 10]
+            | each { random integer 3..5 }
+            | where $it < 3 || $it > 5
+            | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn generates_an_integer_from_a_single_value_range() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            random integer 42..42
+        "#
+    ));
+
+    assert_eq!(actual.out, "42");
+}
+
+#[test]
+fn errors_on_a_descending_range() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            random integer 5..2
+        "#
+    ));
+
+    assert!(actual.err.contains("smaller to a larger integer"));
+}
+
+#[test]
+fn generates_a_decimal_in_the_range() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5 6 7 8 9 10]
+            | each { random decimal 2..3 }
+            | where $it < 2 || $it >= 3
+            | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn generates_a_version_4_uuid() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            random uuid | split row "-" | each { size | get chars } | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[8,4,4,4,12]");
+}
+
+#[test]
+fn generates_booleans_with_a_bias() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5] | each { random bool --bias 0 } | where $it | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn errors_on_a_bias_out_of_range() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            random bool --bias 1.5
+        "#
+    ));
+
+    assert!(actual.err.contains("Expected a bias from 0 to 1"));
+}
+
+#[test]
+fn generates_chars_of_the_given_length() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            random chars --length 12 | size | get chars
+        "#
+    ));
+
+    assert_eq!(actual.out, "12");
+}
@@ -45,3 +45,121 @@ fn selects_some_rows() {
         assert_eq!(actual.out, "2");
     });
 }
+
+#[test]
+fn leaves_out_an_exclusive_end() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c d e]
+            | range 1..<3
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["b","c"]"#);
+}
+
+#[test]
+fn selects_rows_by_a_step() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c d e]
+            | range 0..2..4
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","c","e"]"#);
+}
+
+#[test]
+fn range_literal_gives_its_values() {
+    let actual = nu!(
+        cwd: ".",
+        "1..3 | to json"
+    );
+
+    assert_eq!(actual.out, "[1,2,3]");
+}
+
+#[test]
+fn range_literal_with_an_exclusive_end() {
+    let actual = nu!(
+        cwd: ".",
+        "1..<4 | to json"
+    );
+
+    assert_eq!(actual.out, "[1,2,3]");
+}
+
+#[test]
+fn range_literal_counts_by_a_step() {
+    let actual = nu!(
+        cwd: ".",
+        "echo 1..3..9 | to json"
+    );
+
+    assert_eq!(actual.out, "[1,3,5,7,9]");
+}
+
+#[test]
+fn range_literal_counts_down() {
+    let actual = nu!(
+        cwd: ".",
+        "echo 3..1 | to json"
+    );
+
+    assert_eq!(actual.out, "[3,2,1]");
+}
+
+#[test]
+fn range_literal_is_lazy() {
+    let actual = nu!(
+        cwd: ".",
+        "1..1000000000 | first 2 | to json"
+    );
+
+    assert_eq!(actual.out, "[1,2]");
+}
+
+#[test]
+fn range_literal_with_variables() {
+    let actual = nu!(
+        cwd: ".",
+        "let a = 2; let b = 5; echo $a..<$b | to json"
+    );
+
+    assert_eq!(actual.out, "[2,3,4]");
+}
+
+#[test]
+fn where_checks_membership_in_a_range() {
+    let actual = nu!(
+        cwd: ".",
+        "echo [1 5 12 10] | where $it in 1..<10 | to json"
+    );
+
+    assert_eq!(actual.out, "[1,5]");
+}
+
+#[test]
+fn where_checks_membership_in_a_stepped_range() {
+    let actual = nu!(
+        cwd: ".",
+        "echo [1 2 3 4 5] | where $it not-in 1..3..5 | to json"
+    );
+
+    assert_eq!(actual.out, "[2,4]");
+}
+
+#[test]
+fn three_dots_are_not_a_range() {
+    let actual = nu!(
+        cwd: ".",
+        "echo 1...3"
+    );
+
+    assert_eq!(actual.out, "1...3");
+}
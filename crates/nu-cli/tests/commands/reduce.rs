@@ -0,0 +1,53 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn reduces_with_the_first_row_as_initial_value() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4]
+            | reduce { = $acc * $it }
+        "#
+    ));
+
+    assert_eq!(actual.out, "24");
+}
+
+#[test]
+fn reduces_from_the_given_initial_value() {
+    Playground::setup("reduce_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "notes.txt",
+            r#"
+                nu is a shell
+                with tables
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open notes.txt
+                | lines
+                | reduce --fold 0 { = $acc + $(echo $it | split row " " | count) }
+            "#
+        ));
+
+        assert_eq!(actual.out, "6");
+    })
+}
+
+#[test]
+fn fails_without_rows_or_initial_value() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo []
+            | reduce { = $acc + $it }
+        "#
+    ));
+
+    assert!(actual.err.contains("Expected rows to reduce"));
+}
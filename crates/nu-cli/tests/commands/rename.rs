@@ -95,3 +95,90 @@ fn errors_if_no_columns_present() {
         );
     })
 }
+
+#[test]
+fn renames_columns_by_name() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"a": 1, "b": 2, "c": 3}'
+            | from json
+            | rename { echo '{"c": "spam", "a": "eggs"}' | from json }
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"eggs":1,"b":2,"spam":3}"#);
+}
+
+#[test]
+fn renames_all_columns_of_headerless_csv() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "1,2"
+            | from csv --headerless
+            | rename x y
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"x":1,"y":2}"#);
+}
+
+#[test]
+fn fails_to_rename_columns_that_do_not_exist() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": 1}'
+            | from json
+            | rename $(echo '{"nmae": "title"}' | from json)
+        "#
+    ));
+
+    assert!(actual.err.contains("did you mean 'name'?"));
+}
+
+#[test]
+fn fails_to_give_two_columns_the_same_name() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"a": 1, "b": 2}'
+            | from json
+            | rename b
+        "#
+    ));
+
+    assert!(actual.err.contains("Duplicate column name"));
+}
+
+#[test]
+fn fails_to_map_a_column_to_the_name_of_another() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"a": 1, "b": 2}'
+            | from json
+            | rename $(echo '{"a": "b"}' | from json)
+        "#
+    ));
+
+    assert!(actual.err.contains("two columns named b"));
+}
+
+#[test]
+fn swaps_the_names_of_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"a": 1, "b": 2}'
+            | from json
+            | rename b a
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"b":1,"a":2}"#);
+}
@@ -1,4 +1,4 @@
-use nu_test_support::fs::{files_exist_at, Stub::EmptyFile};
+use nu_test_support::fs::{files_exist_at, Stub::EmptyFile, Stub::FileWithContent};
 use nu_test_support::nu;
 use nu_test_support::playground::Playground;
 
@@ -265,3 +265,43 @@ fn remove_files_from_two_parents_up_using_multiple_dots_and_glob() {
         ));
     })
 }
+
+#[cfg(not(feature = "trash-support"))]
+#[test]
+fn does_not_delete_permanently_when_asked_to_trash_without_trash_support() {
+    Playground::setup("rm_test_trash", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("keep_me.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "rm --trash keep_me.txt"
+        );
+
+        assert!(actual.err.contains("Cannot move files to the trash"));
+        assert!(dirs.test().join("keep_me.txt").exists());
+    })
+}
+
+// nu finds the config in the playground from XDG_CONFIG_HOME on Linux
+#[cfg(all(target_os = "linux", not(feature = "trash-support")))]
+#[test]
+fn deletes_with_a_warning_when_always_trashing_without_trash_support() {
+    Playground::setup("rm_test_always_trash", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![EmptyFile("i_will_be_deleted.txt")])
+            .within("nu")
+            .with_files(vec![FileWithContent(
+                "config.toml",
+                "rm_always_trash = true",
+            )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"with-env [XDG_CONFIG_HOME {}] {{ nu -c "rm i_will_be_deleted.txt" }}"#,
+            dirs.test()
+        );
+
+        assert!(actual.err.contains("rm_always_trash is ignored"));
+        assert!(!dirs.test().join("i_will_be_deleted.txt").exists());
+    })
+}
@@ -0,0 +1,48 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn rotates_a_record_into_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu", "stars": 9000}'
+            | from json
+            | rotate
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"Column1":"nu","Column2":"name"},{"Column1":9000,"Column2":"stars"}]"#
+    );
+}
+
+#[test]
+fn rotates_counter_clockwise() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu", "stars": 9000}'
+            | from json
+            | rotate --ccw
+            | get Column1
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["stars","name"]"#);
+}
+
+#[test]
+fn fails_with_values_that_are_not_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2]
+            | rotate
+        "#
+    ));
+
+    assert!(actual.err.contains("requires table input"));
+}
@@ -0,0 +1,84 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn rounds_numbers_to_integers() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1.5 2.4 -2.5 7]
+            | math round
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[2,2,-3,7]");
+}
+
+#[test]
+fn rounds_every_number_of_a_table() {
+    Playground::setup("round_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "prices.csv",
+            r#"
+                item,price,tax
+                tea,3.14159,0.271
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open prices.csv
+                | math round --precision 2
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"{"item":"tea","price":3.14,"tax":0.27}"#);
+    })
+}
+
+#[test]
+fn rounds_up() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1.2 -1.2 3]
+            | math ceil
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[2,-1,3]");
+}
+
+#[test]
+fn rounds_down() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1.2 -1.2 3]
+            | math floor
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,-2,3]");
+}
+
+#[test]
+fn square_roots_of_numbers() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [16 2.25]
+            | math sqrt
+            | sum
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "5.5");
+}
@@ -0,0 +1,65 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn picks_the_number_of_rows_asked() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5 6 7 8 9 10]
+            | sample 4
+            | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "4");
+}
+
+#[test]
+fn picks_every_row_when_asked_for_more() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | sample 1000000000000
+            | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn picks_the_same_rows_given_the_same_seed() {
+    let first = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5 6 7 8 9 10]
+            | sample --fraction 0.5 --seed 42
+            | to json
+        "#
+    ));
+
+    let second = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5 6 7 8 9 10]
+            | sample --fraction 0.5 --seed 42
+            | to json
+        "#
+    ));
+
+    assert_eq!(first.out, second.out);
+}
+
+#[test]
+fn fails_with_fractions_out_of_range() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | sample --fraction 1.5
+        "#
+    ));
+
+    assert!(actual.err.contains("requires a number between 0 and 1"));
+}
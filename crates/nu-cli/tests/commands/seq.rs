@@ -0,0 +1,107 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn counts_up_to_the_given_number() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq 4
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,2,3,4]");
+}
+
+#[test]
+fn counts_down_with_a_step() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq 9 1 --step -4
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[9,5,1]");
+}
+
+#[test]
+fn counts_with_decimal_steps() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq 1 2 --step 0.5
+            | sum
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "4.5");
+}
+
+#[test]
+fn rejects_a_zero_step() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq 1 2 --step 0
+        "#
+    ));
+
+    assert!(actual.err.contains("zero"));
+}
+
+#[test]
+fn produces_dates_between_two_days() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq date --from 2020-06-28 --to 2020-07-02
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "5");
+}
+
+#[test]
+fn produces_the_given_number_of_dates() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq date --from 2020-06-01 --step 1day --count 3
+            | last 1
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#""2020-06-03 00:00:00 UTC""#);
+}
+
+#[test]
+fn stops_dates_at_the_last_date_there_can_be() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq date --from 2020-01-01 --step 100000d --count 10000
+            | count
+        "#
+    ));
+
+    assert!(actual.err.is_empty());
+    assert!(actual.out.parse::<usize>().expect("a count") < 10000);
+}
+
+#[test]
+fn refuses_steps_too_long_to_move_a_date_by() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq date --from 2020-01-01 --step 100000000000wk --count 2
+        "#
+    ));
+
+    assert!(actual.err.contains("Duration out of range"));
+}
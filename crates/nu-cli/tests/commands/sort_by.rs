@@ -62,3 +62,63 @@ fn sort_primitive_values() {
 
     assert_eq!(actual.out, "authors = [\"The Nu Project Contributors\"]");
 }
+
+#[test]
+fn by_column_descending() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"kind": "a", "size": 1}, {"kind": "b", "size": 3}, {"kind": "a", "size": 2}]'
+            | from json
+            | sort-by kind size:desc
+            | get size
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[2,1,3]");
+}
+
+#[test]
+fn reversed() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [2 3 1]
+            | sort-by --reverse
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[3,2,1]");
+}
+
+#[test]
+fn naturally_and_case_insensitively() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [file10 File9 file1 file2]
+            | sort-by --natural --insensitive
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["file1","file2","File9","file10"]"#);
+}
+
+#[test]
+fn with_a_comparator_block() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"name": "a", "size": 1}, {"name": "b", "size": 3}, {"name": "c", "size": 2}]'
+            | from json
+            | sort-by { = $a.size > $b.size }
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["b","c","a"]"#);
+}
@@ -0,0 +1,117 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+
+#[test]
+fn source_keeps_the_commands_and_environment_of_the_file() {
+    Playground::setup("source_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "greetings.nu",
+            r#"
+                def hello [name] { build-string 'hello ' $name }
+                load-env [SOURCED_GREETING hi]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                source greetings.nu
+                echo (hello nu) $nu.env.SOURCED_GREETING | to json
+            "#
+        );
+
+        assert_eq!(actual.out, r#"["hello nu","hi"]"#);
+    })
+}
+
+#[test]
+fn source_finds_files_from_the_directory_of_the_file_sourcing_them() {
+    Playground::setup("source_test_2", |dirs, sandbox| {
+        sandbox.within("lib").with_files(vec![
+            FileWithContentToBeTrimmed(
+                "main.nu",
+                r#"
+                    source helpers.nu
+                    def hello [name] { build-string (greeting) ' ' $name }
+                "#,
+            ),
+            FileWithContentToBeTrimmed(
+                "helpers.nu",
+                r#"
+                    def greeting [] { echo hi }
+                "#,
+            ),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                source lib/main.nu
+                hello nu
+            "#
+        );
+
+        assert_eq!(actual.out, "hi nu");
+    })
+}
+
+#[test]
+fn source_runs_blocks_and_pipelines_written_over_several_lines() {
+    Playground::setup("source_test_5", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "tens.nu",
+            r#"
+                def tens [] {
+                    echo [1 2 3] |
+                    each { = $it * 10 }
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                source tens.nu
+                tens | to json
+            "#
+        );
+
+        assert_eq!(actual.out, "[10,20,30]");
+    })
+}
+
+#[test]
+fn source_shows_errors_in_the_file_they_are_in() {
+    Playground::setup("source_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "broken.nu",
+            r#"
+                def hello [] { echo hello }
+                echo 1 | no-such-command
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                source broken.nu
+            "#
+        );
+
+        assert!(actual.err.contains("broken.nu:2:10"));
+        assert!(actual.err.contains("there is an error in this file"));
+    })
+}
+
+#[test]
+fn source_errors_on_missing_files() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            source no_such_file.nu
+        "#
+    );
+
+    assert!(actual.err.contains("could not read this file"));
+}
@@ -28,3 +28,44 @@ fn to_column() {
         assert!(actual.out.contains("shipper"));
     })
 }
+
+#[test]
+fn to_column_on_a_regex_with_max_splits() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "2020-08-31  INFO   server started on port 8080"
+            | split column --regex '\s+' --max-splits 2 date level
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"{"date":"2020-08-31","level":"INFO","Column3":"server started on port 8080"}"#
+    );
+}
+
+#[test]
+fn to_column_names_the_columns_without_a_given_name() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "a,b,c" | split column "," first | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"first":"a","Column2":"b","Column3":"c"}"#);
+}
+
+#[test]
+fn to_column_errors_on_an_invalid_regex() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "a,b,c" | split column --regex "("
+        "#
+    ));
+
+    assert!(actual.err.contains("Invalid regular expression"));
+}
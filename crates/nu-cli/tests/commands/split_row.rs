@@ -27,3 +27,15 @@ fn to_row() {
         assert!(actual.out.contains('5'));
     })
 }
+
+#[test]
+fn to_row_on_a_regex() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "4, 0;2 ,7" | split row --regex '\s*[,;]\s*' | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["4","0","2","7"]"#);
+}
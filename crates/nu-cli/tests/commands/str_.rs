@@ -190,6 +190,55 @@ fn find_and_replaces_without_passing_field() {
     })
 }
 
+#[test]
+fn find_and_replaces_all_matches_ignoring_case() {
+    Playground::setup("str_test_19", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                     [fortune.teller]
+                     phone = "katz-1-800-KATZ"
+                 "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                 open sample.toml
+                 | str find-replace --all --insensitive katz "5289" fortune.teller.phone
+                 | get fortune.teller.phone
+                 | echo $it
+             "#
+        ));
+
+        assert_eq!(actual.out, "5289-1-800-5289");
+    })
+}
+
+#[test]
+fn find_and_replaces_with_named_capture_groups() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "1-800-5289" | str find-replace '(?P<area>\d+)-(?P<rest>.+)' '$rest ($area)'
+        "#
+    ));
+
+    assert_eq!(actual.out, "800-5289 (1)");
+}
+
+#[test]
+fn find_and_replace_errors_on_an_invalid_regex() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "1-800-5289" | str find-replace '(' x
+        "#
+    ));
+
+    assert!(actual.err.contains("Invalid regular expression"));
+}
+
 #[test]
 fn substrings_the_input() {
     Playground::setup("str_test_8", |dirs, sandbox| {
@@ -340,3 +389,247 @@ fn substrings_the_input_and_treats_end_index_as_length_if_blank_end_index_given(
         assert_eq!(actual.out, "arepas");
     })
 }
+
+#[test]
+fn converts_to_camel_case() {
+    Playground::setup("str_test_14", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                    [dependency]
+                    name = "nu-shell_command"
+                "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open sample.toml | str camel-case dependency.name | get dependency.name | echo $it"
+        );
+
+        assert_eq!(actual.out, "nuShellCommand");
+    })
+}
+
+#[test]
+fn converts_to_pascal_case() {
+    Playground::setup("str_test_15", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                    [dependency]
+                    name = "nu-shell_command"
+                "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open sample.toml | str pascal-case dependency.name | get dependency.name | echo $it"
+        );
+
+        assert_eq!(actual.out, "NuShellCommand");
+    })
+}
+
+#[test]
+fn converts_to_snake_case() {
+    Playground::setup("str_test_16", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                    [dependency]
+                    name = "NuShell Command"
+                "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open sample.toml | str snake-case dependency.name | get dependency.name | echo $it"
+        );
+
+        assert_eq!(actual.out, "nu_shell_command");
+    })
+}
+
+#[test]
+fn converts_to_kebab_case() {
+    Playground::setup("str_test_17", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                    [dependency]
+                    name = "NuShell_command"
+                "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open sample.toml | str kebab-case dependency.name | get dependency.name | echo $it"
+        );
+
+        assert_eq!(actual.out, "nu-shell-command");
+    })
+}
+
+#[test]
+fn converts_to_title_case() {
+    Playground::setup("str_test_18", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                    [dependency]
+                    name = "nu-shell_command"
+                "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open sample.toml | str title-case dependency.name | get dependency.name | echo $it"
+        );
+
+        assert_eq!(actual.out, "Nu Shell Command");
+    })
+}
+
+#[test]
+fn converts_each_string_to_snake_case() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [fooBar "Baz Qux"] | str snake-case | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["foo_bar","baz_qux"]"#);
+}
+
+#[test]
+fn left_pads_a_column_with_zeros() {
+    Playground::setup("str_test_20", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                    [dependency]
+                    id = "42"
+                "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open sample.toml | str lpad --length 6 --character 0 dependency.id | get dependency.id | echo $it"
+        );
+
+        assert_eq!(actual.out, "000042");
+    })
+}
+
+#[test]
+fn right_pads_and_centers_text() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [nu shell] | str rpad -l 6 -c "." | str pad -l 8 -c "|" | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["|nu....|","|shell.|"]"#);
+}
+
+#[test]
+fn pad_errors_on_a_fill_of_several_characters() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo nu | str lpad -l 6 -c ab
+        "#
+    ));
+
+    assert!(actual.err.contains("Expected a single fill character"));
+}
+
+#[test]
+fn checks_starts_with_and_ends_with() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [Cargo.toml Cargo.lock README.md]
+            | wrap name
+            | insert cargo { get name | str starts-with Cargo }
+            | insert toml { get name | str ends-with .toml }
+            | where cargo
+            | where toml
+            | get name
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "Cargo.toml");
+}
+
+#[test]
+fn checks_if_columns_contain_a_pattern() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [Cargo.toml Cargo.lock README.md]
+            | wrap name
+            | str contains --insensitive CARGO name
+            | where name
+            | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn finds_the_index_of_a_pattern() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [Cargo.toml README.md LICENSE] | str index-of "." | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[5,6,-1]");
+}
+
+#[test]
+fn counts_the_characters_of_a_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; [Cargo.toml] [LICENSE]] | str length name | get name | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[10,7]");
+}
+
+#[test]
+fn filters_rows_within_an_edit_distance() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [nushell nutshell bash zsh NuShel]
+            | wrap name
+            | insert distance { get name | str distance -i nushell }
+            | where distance <= 2
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["nushell","nutshell","NuShel"]"#);
+}
+
+#[test]
+fn scores_the_similarity_of_a_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [nush abcd] | wrap name | str similarity nash name | get name | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[0.75,0.0]");
+}
@@ -1,5 +1,7 @@
+use nu_test_support::fs::Stub::EmptyFile;
 use nu_test_support::nu;
 use nu_test_support::playground::Playground;
+use std::time::{Duration, UNIX_EPOCH};
 
 #[test]
 fn creates_a_file_when_it_doesnt_exist() {
@@ -14,3 +16,41 @@ fn creates_a_file_when_it_doesnt_exist() {
         assert!(path.exists());
     })
 }
+
+#[test]
+fn sets_the_modification_time() {
+    Playground::setup("create_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("fixture.json")]);
+
+        nu!(
+            cwd: dirs.test(),
+            "touch fixture.json --modified 2020-01-01T12:00:00+00:00"
+        );
+
+        let modified = std::fs::metadata(dirs.test().join("fixture.json"))
+            .and_then(|metadata| metadata.modified())
+            .expect("could not read the modification time");
+
+        assert_eq!(modified, UNIX_EPOCH + Duration::from_secs(1_577_880_000));
+    })
+}
+
+#[test]
+fn leaves_the_modification_time_when_only_setting_the_access_time() {
+    Playground::setup("create_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("fixture.json")]);
+
+        nu!(
+            cwd: dirs.test(),
+            "touch fixture.json --modified 2020-01-01T12:00:00+00:00; touch fixture.json --accessed 2021-01-01"
+        );
+
+        let metadata = std::fs::metadata(dirs.test().join("fixture.json"))
+            .expect("could not read the metadata");
+
+        assert_eq!(
+            metadata.modified().expect("no modification time"),
+            UNIX_EPOCH + Duration::from_secs(1_577_880_000)
+        );
+    })
+}
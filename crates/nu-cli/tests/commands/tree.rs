@@ -0,0 +1,47 @@
+use nu_test_support::fs::Stub::EmptyFile;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn renders_nested_data() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu", "pkg": {"version": "0.16.0"}}'
+            | from json
+            | tree
+            | lines
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"["├── name: nu","└── pkg","    └── version: 0.16.0"]"#
+    );
+}
+
+#[test]
+fn renders_paths_of_a_column() {
+    Playground::setup("tree_test_1", |dirs, sandbox| {
+        sandbox
+            .within("src")
+            .with_files(vec![EmptyFile("cli.rs"), EmptyFile("lib.rs")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls src/*.rs
+                | sort-by name
+                | tree --path name
+                | lines
+                | to json
+            "#
+        ));
+
+        assert_eq!(
+            actual.out,
+            r#"["└── src","    ├── cli.rs","    └── lib.rs"]"#
+        );
+    })
+}
@@ -0,0 +1,77 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn gives_the_output_when_nothing_fails() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            try { echo 1 2 3 } catch { echo 0 }
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,2,3]");
+}
+
+#[test]
+fn runs_the_catch_block_with_the_error() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            try { open no_such_file.txt } catch { echo $err.label }
+        "#
+    ));
+
+    assert_eq!(actual.out, "file not found");
+    assert_eq!(actual.err, "");
+}
+
+#[test]
+fn gives_the_span_of_the_error() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            try { open no_such_file.txt } catch { = $err.span.end - $err.span.start }
+        "#
+    ));
+
+    assert_eq!(actual.out, "16");
+}
+
+#[test]
+fn ignores_the_error_without_a_catch_block() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            try { open no_such_file.txt }; echo recovered
+        "#
+    ));
+
+    assert_eq!(actual.out, "recovered");
+    assert_eq!(actual.err, "");
+}
+
+#[cfg(unix)]
+#[test]
+fn gives_the_exit_code_of_externals() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            try { ^sh -c "exit 3" } catch { echo $err.exit_code }
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn errors_without_the_word_catch() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            try { echo 1 } { echo 2 }
+        "#
+    ));
+
+    assert!(actual.err.contains("Expected catch and a block"));
+}
@@ -140,3 +140,99 @@ fn uniq_when_keys_out_of_order() {
 
     assert_eq!(actual.out, "1");
 }
+
+#[test]
+fn uniq_ignores_where_values_come_from() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 1 2]
+            | uniq
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,2]");
+}
+
+#[test]
+fn uniq_counts_occurrences() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b a a]
+            | uniq --count
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"value":"a","count":3},{"value":"b","count":1}]"#
+    );
+}
+
+#[test]
+fn uniq_by_columns() {
+    Playground::setup("uniq_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_at,type
+                Andrés,Robalino,10/11/2013,A
+                Jonathan,Turner,10/12/2013,B
+                Yehuda,Katz,10/11/2013,A
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | uniq --by type --count
+                | get count
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, "[2,1]");
+    })
+}
+
+#[test]
+fn uniq_filters_repeated_and_unique_rows() {
+    let repeated = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 2 3 3 3]
+            | uniq --repeated
+            | to json
+        "#
+    ));
+
+    let unique_only = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 2 3 3 3]
+            | uniq --unique-only
+            | to json
+        "#
+    ));
+
+    assert_eq!(repeated.out, "[2,3]");
+    assert_eq!(unique_only.out, "1");
+}
+
+#[test]
+fn uniq_refuses_to_replace_a_count_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"count": 7}, {"count": 7}]'
+            | from json
+            | uniq --count
+        "#
+    ));
+
+    assert!(actual.err.contains("Column count already exists"));
+}
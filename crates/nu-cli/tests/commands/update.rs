@@ -29,3 +29,19 @@ fn sets_the_column_from_a_block_run_output() {
 
     assert_eq!(actual.out, "0.7.0");
 }
+
+#[test]
+fn sets_the_column_computed_from_each_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2]
+            | wrap size
+            | update size { = $it.size * 2 }
+            | get size
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[2,4]");
+}
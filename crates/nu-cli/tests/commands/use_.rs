@@ -0,0 +1,85 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+
+#[test]
+fn use_gives_all_the_commands_of_a_module() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            module greetings { def hello [] { echo hello }; def bye [] { echo bye } }
+            use greetings
+            echo (hello) (bye) | to json
+        "#
+    );
+
+    assert_eq!(actual.out, r#"["hello","bye"]"#);
+}
+
+#[test]
+fn use_gives_only_the_commands_asked_for() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            module greetings { def hello [] { echo hello }; def bye [] { echo bye } }
+            use greetings hello
+            bye
+        "#
+    );
+
+    assert!(actual.err.contains("Command not found"));
+}
+
+#[test]
+fn use_errors_on_commands_not_in_the_module() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            module greetings { def hello [] { echo hello } }
+            use greetings goodbye
+        "#
+    );
+
+    assert!(actual
+        .err
+        .contains("greetings has no command named goodbye"));
+}
+
+#[test]
+fn use_sets_the_environment_of_the_module() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            module editor { load-env [MODULE_EDITOR vim] }
+            use editor
+            echo $nu.env.MODULE_EDITOR
+        "#
+    );
+
+    assert_eq!(actual.out, "vim");
+}
+
+#[test]
+fn use_takes_a_file_as_a_module() {
+    Playground::setup("use_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "greetings.nu",
+            r#"
+                # Greet someone
+                def hello [name] { build-string 'hello ' $name }
+
+                def shout [name] { hello $name | str upcase }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                use greetings.nu shout
+                echo (shout nu) (greetings hello nu) | to json
+            "#
+        );
+
+        assert_eq!(actual.out, r#"["HELLO NU","hello nu"]"#);
+    })
+}
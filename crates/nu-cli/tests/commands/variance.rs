@@ -0,0 +1,56 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn variance_of_numbers() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [2 4 4 4 5 5 7 9]
+            | math variance
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "4.0");
+}
+
+#[test]
+fn sample_variance_of_numbers() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5]
+            | math variance --sample
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "2.5");
+}
+
+#[test]
+fn standard_deviation_of_numbers() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [2 4 4 4 5 5 7 9]
+            | math stddev
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "2.0");
+}
+
+#[test]
+fn variance_of_values_that_are_not_numbers() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 a 3]
+            | math variance
+        "#
+    ));
+
+    assert!(actual.err.contains("value that is not a number"));
+}
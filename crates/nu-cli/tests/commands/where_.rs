@@ -166,3 +166,109 @@ fn contains_operator() {
 
     assert_eq!(actual.out, "2");
 }
+
+#[test]
+fn contains_operator_matches_regular_expressions() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.db
+            | where table_name == strings
+            | get table_values
+            | where x =~ "^w.r+ld$"
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn filters_on_nested_paths_with_compound_conditions() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"pkg": {"name": "a", "version": "1.2.0"}, "size": 3000}, {"pkg": {"name": "b", "version": "2.0.1"}, "size": 5000}, {"pkg": {"name": "c", "version": "1.0.0"}, "size": 100}]'
+            | from json
+            | where pkg.version =~ "^1\." && size > 2kb
+            | get pkg.name
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "a");
+}
+
+#[test]
+fn in_and_not_in_operators() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"name": "a"}, {"name": "b"}, {"name": "c"}]'
+            | from json
+            | where name in [a c]
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","c"]"#);
+
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"name": "a"}, {"name": "b"}, {"name": "c"}]'
+            | from json
+            | where name not-in [a c]
+            | get name
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "b");
+}
+
+#[test]
+fn contains_operator_patterns_are_regular_expressions_not_substrings() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"name": "cars"}, {"name": "main.rs"}, {"name": "notes.txt"}]'
+            | from json
+            | where name =~ .rs
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["cars","main.rs"]"#);
+
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"name": "cars"}, {"name": "main.rs"}, {"name": "notes.txt"}]'
+            | from json
+            | where name =~ '\.rs'
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#""main.rs""#);
+}
+
+#[test]
+fn contains_operator_errors_on_invalid_regular_expressions() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"name": "a(b"}]'
+            | from json
+            | where name =~ "a(b"
+            | count
+        "#
+    ));
+
+    assert!(actual.err.contains("Invalid regular expression"));
+}
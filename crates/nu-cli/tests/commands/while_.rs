@@ -0,0 +1,38 @@
+use nu_test_support::nu;
+
+#[test]
+fn while_skips_the_block_when_the_condition_does_not_hold() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            let n = 3; while $n < 2 { echo never } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, "");
+    assert!(actual.err.is_empty());
+}
+
+#[test]
+fn while_runs_the_block_while_the_condition_holds() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            with-env [DONE no] { while $nu.env.DONE == no { load-env [DONE yes]; echo ran } | to json }
+        "#
+    );
+
+    assert_eq!(actual.out, r#""ran""#);
+}
+
+#[test]
+fn while_needs_a_boolean_condition() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            while $(echo 3) { echo x }
+        "#
+    );
+
+    assert!(actual.err.contains("Expected boolean"));
+}
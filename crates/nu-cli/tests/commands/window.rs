@@ -0,0 +1,99 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn computes_moving_averages() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5]
+            | window 3
+            | each { echo $it | math average }
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[2.0,3.0,4.0]");
+}
+
+#[test]
+fn skips_windows_by_stride() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5]
+            | window 2 --stride 2
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[[1,2],[3,4]]");
+}
+
+#[test]
+fn yields_nothing_with_fewer_rows_than_the_window() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2]
+            | window 3
+            | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn takes_windows_far_larger_than_the_input() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2]
+            | window 1000000000000
+            | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn splits_rows_into_chunks() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5]
+            | chunks 2
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[[1,2],[3,4],[5]]");
+}
+
+#[test]
+fn takes_chunks_far_larger_than_the_input() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2]
+            | chunks 1000000000000
+            | count
+        "#
+    ));
+
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn chunks_fails_with_zero_size() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | chunks 0
+        "#
+    ));
+
+    assert!(actual.err.contains("can't be zero"));
+}
@@ -64,3 +64,43 @@ fn with_env_shorthand_nested_quotes() {
 
     assert_eq!(actual.out, "-arg \"hello world\"");
 }
+
+#[test]
+fn with_env_sets_several_variables() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "with-env [FOO BARRRR BAZ 1] {echo $nu.env} | echo $it.FOO $it.BAZ | to json"
+    );
+
+    assert_eq!(actual.out, r#"["BARRRR","1"]"#);
+}
+
+#[test]
+fn with_env_sets_variables_from_a_row() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"with-env $(echo '{"FOO": "BARRRR"}' | from json) {echo $nu.env} | get FOO"#
+    );
+
+    assert_eq!(actual.out, "BARRRR");
+}
+
+#[test]
+fn with_env_shorthand_sets_several_variables() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "FOO=BARRRR BAZ=a=b echo $nu.env | echo $it.FOO $it.BAZ | to json"
+    );
+
+    assert_eq!(actual.out, r#"["BARRRR","a=b"]"#);
+}
+
+#[test]
+fn with_env_shorthand_is_only_for_the_command() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "FOO=BARRRR echo done; echo $nu.env | get FOO"
+    );
+
+    assert!(actual.err.contains("Unknown column"));
+}
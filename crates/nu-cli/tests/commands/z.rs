@@ -0,0 +1,61 @@
+// The visits are kept in a directory of the playground, which nu finds from XDG_DATA_HOME on Linux
+#![cfg(target_os = "linux")]
+
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+
+#[test]
+fn changes_to_the_directory_visited_the_most() {
+    Playground::setup("z_test_1", |dirs, sandbox| {
+        sandbox
+            .mkdir("projects/nushell")
+            .mkdir("projects/notes")
+            .with_files(vec![FileWithContent(
+            "lines.txt",
+            "cd projects/notes\ncd ../nushell\ncd ../notes\ncd ../nushell\ncd ../notes\ncd ../..\n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"with-env [XDG_DATA_HOME {}] {{ open lines.txt | nu | count; nu -c "z proj; pwd" }}"#,
+            dirs.test()
+        );
+
+        assert!(actual.out.ends_with("notes"));
+    })
+}
+
+#[test]
+fn lists_the_matching_directories_by_how_often_they_were_visited() {
+    Playground::setup("z_test_2", |dirs, sandbox| {
+        sandbox
+            .mkdir("projects/nushell")
+            .mkdir("projects/notes")
+            .with_files(vec![FileWithContent(
+                "lines.txt",
+                "cd projects/notes\ncd ../nushell\ncd ../notes\ncd ../..\n",
+            )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"with-env [XDG_DATA_HOME {}] {{ open lines.txt | nu | count; nu -c "z --list proj | get visits | to json" }}"#,
+            dirs.test()
+        );
+
+        assert_eq!(actual.out, "[2.0,1.0]");
+    })
+}
+
+#[test]
+fn errors_when_no_visited_directory_matches() {
+    Playground::setup("z_test_3", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"with-env [XDG_DATA_HOME {}] {{ nu -c "z nowhere" }}"#,
+            dirs.test()
+        );
+
+        assert!(actual.err.contains("no visited directory matches"));
+    })
+}
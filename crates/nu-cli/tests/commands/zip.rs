@@ -0,0 +1,33 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn pairs_rows_with_the_output_of_a_block() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2]
+            | zip { echo [a b] }
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"Column1":1,"Column2":"a"},{"Column1":2,"Column2":"b"}]"#
+    );
+}
+
+#[test]
+fn stops_at_the_end_of_the_shortest() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | zip $(echo [10 20])
+            | each { = $it.Column1 + $it.Column2 }
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[11,22]");
+}
@@ -101,3 +101,22 @@ fn table_to_json_text() {
         assert_eq!(actual.out, "JonAndrehudaTZ");
     })
 }
+
+#[test]
+fn hyphenated_conversion_names_still_work_with_a_warning() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sgml_description.json
+            | to-json
+            | from-json
+            | get glossary.GlossDiv.GlossList.GlossEntry.GlossSee
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "markup");
+    assert!(actual
+        .err
+        .contains("`to-json` is deprecated, use `to json` instead"));
+}
@@ -0,0 +1,126 @@
+use futures::executor::block_on;
+use indexmap::IndexMap;
+use nu_cli::{
+    create_embedded_context, evaluate_pipeline, whole_stream_command, Context, WholeStreamCommand,
+};
+use nu_errors::ShellError;
+use nu_protocol::Value;
+use std::error::Error;
+
+/// An embeddable evaluator for nu pipelines.
+///
+/// The engine owns a command registry with all of the internal commands registered, but it
+/// never touches the user's configuration, history or plugins. Pipelines are run over the
+/// values handed to it and whatever the pipeline produces is given back as values.
+///
+/// ```ignore
+/// let mut engine = Engine::new()?;
+/// let sizes = engine.evaluate_blocking("where size > 10 | get name", files)?;
+/// ```
+pub struct Engine {
+    context: Context,
+    vars: IndexMap<String, Value>,
+    env: IndexMap<String, String>,
+}
+
+impl Engine {
+    /// Creates an engine with every internal command available.
+    pub fn new() -> Result<Engine, Box<dyn Error>> {
+        Ok(Engine {
+            context: create_embedded_context()?,
+            vars: IndexMap::new(),
+            env: IndexMap::new(),
+        })
+    }
+
+    /// Registers an additional command, replacing any command with the same name.
+    pub fn add_command(&mut self, command: impl WholeStreamCommand + 'static) {
+        self.context
+            .add_commands(vec![whole_stream_command(command)]);
+    }
+
+    /// Makes `$name` available to the pipelines evaluated by this engine.
+    pub fn set_var(&mut self, name: impl Into<String>, value: Value) {
+        let name = name.into();
+        let name = if name.starts_with('$') {
+            name
+        } else {
+            format!("${}", name)
+        };
+
+        self.vars.insert(name, value);
+    }
+
+    /// Sets an environment variable visible to the pipelines (and any external commands they run).
+    pub fn set_env(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.env.insert(name.into(), value.into());
+    }
+
+    /// Parses `pipeline` and runs it with `input` as its input stream, collecting the output.
+    pub async fn evaluate(
+        &mut self,
+        pipeline: &str,
+        input: Vec<Value>,
+    ) -> Result<Vec<Value>, ShellError> {
+        evaluate_pipeline(pipeline, &mut self.context, input, &self.vars, &self.env).await
+    }
+
+    /// Like [`evaluate`](#method.evaluate), but blocks the current thread until the pipeline
+    /// finishes.
+    pub fn evaluate_blocking(
+        &mut self,
+        pipeline: &str,
+        input: Vec<Value>,
+    ) -> Result<Vec<Value>, ShellError> {
+        block_on(self.evaluate(pipeline, input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Engine;
+    use nu_errors::ShellError;
+    use nu_protocol::{UntaggedValue, Value};
+
+    fn int(n: i64) -> Value {
+        UntaggedValue::int(n).into_untagged_value()
+    }
+
+    fn untagged(values: Vec<Value>) -> Vec<UntaggedValue> {
+        values.into_iter().map(|v| v.value).collect()
+    }
+
+    #[test]
+    fn evaluates_pipelines_over_given_values() -> Result<(), ShellError> {
+        let mut engine = Engine::new().expect("could not create engine");
+
+        let actual =
+            engine.evaluate_blocking("where $it > 1 | sum", vec![int(1), int(2), int(3)])?;
+
+        assert_eq!(untagged(actual), vec![UntaggedValue::int(5)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn exposes_variables_to_pipelines() -> Result<(), ShellError> {
+        let mut engine = Engine::new().expect("could not create engine");
+        engine.set_var("limit", int(2));
+
+        let actual =
+            engine.evaluate_blocking("where $it < $limit", vec![int(1), int(2), int(3)])?;
+
+        assert_eq!(untagged(actual), vec![UntaggedValue::int(1)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reports_errors_from_pipelines() {
+        let mut engine = Engine::new().expect("could not create engine");
+
+        assert!(engine
+            .evaluate_blocking("first --not-a-flag", vec![])
+            .is_err());
+    }
+}
@@ -303,9 +303,12 @@ impl PrettyDebug for ShellError {
             ProximateShellError::UntaggedRuntimeError { reason } => {
                 b::error("Unknown Error") + b::delimit("(", b::description(reason), ")")
             }
-            ProximateShellError::ExternalPlaceholderError => {
+            ProximateShellError::ExternalPlaceholderError { .. } => {
                 b::error("non-zero external exit code")
             }
+            ProximateShellError::LoopControl { control, .. } => {
+                b::error("loop control") + b::space() + b::description(control.name())
+            }
         }
     }
 }
@@ -418,8 +421,33 @@ impl ShellError {
         ProximateShellError::Diagnostic(ShellDiagnostic { diagnostic }).start()
     }
 
-    pub fn external_non_zero() -> ShellError {
-        ProximateShellError::ExternalPlaceholderError.start()
+    pub fn external_non_zero(exit_code: Option<i32>) -> ShellError {
+        ProximateShellError::ExternalPlaceholderError { exit_code }.start()
+    }
+
+    /// The exit code of the external command, when the error is about one that failed.
+    pub fn exit_code(&self) -> Option<i32> {
+        match self.error {
+            ProximateShellError::ExternalPlaceholderError { exit_code } => exit_code,
+            _ => None,
+        }
+    }
+
+    /// A `break` or `continue`, which goes up to the loop it is in like an error does.
+    pub fn loop_control(control: LoopControl, span: impl Into<Span>) -> ShellError {
+        ProximateShellError::LoopControl {
+            control,
+            span: span.into(),
+        }
+        .start()
+    }
+
+    /// Whether the error is a `break` or `continue` on its way to its loop.
+    pub fn as_loop_control(&self) -> Option<LoopControl> {
+        match self.error {
+            ProximateShellError::LoopControl { control, .. } => Some(control),
+            _ => None,
+        }
     }
 
     pub fn into_diagnostic(self) -> Option<Diagnostic<usize>> {
@@ -587,7 +615,9 @@ impl ShellError {
             }
 
             ProximateShellError::UntaggedRuntimeError { reason } => Some(Diagnostic::error().with_message(format!("Error: {}", reason))),
-            ProximateShellError::ExternalPlaceholderError => None,
+            ProximateShellError::ExternalPlaceholderError { .. } => None,
+            ProximateShellError::LoopControl { control, span } => Some(Diagnostic::error().with_message(format!("{} can only be used in a loop", control.name()))
+                .with_labels(vec![Label::primary(0, span).with_message("not in a for, while or loop")])),
         }
     }
 
@@ -631,6 +661,23 @@ impl ShellError {
     }
 }
 
+/// `LoopControl` is what `break` and `continue` tell the loop they are in to do.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum LoopControl {
+    Break,
+    Continue,
+}
+
+impl LoopControl {
+    /// The command that gives this control.
+    pub fn name(self) -> &'static str {
+        match self {
+            LoopControl::Break => "break",
+            LoopControl::Continue => "continue",
+        }
+    }
+}
+
 /// `ExpectedRange` describes a range of values that was expected by a command. In addition
 /// to typical ranges, this enum allows an error to specify that the range of allowed values
 /// corresponds to a particular numeric type (which is a dominant use-case for the
@@ -734,7 +781,13 @@ pub enum ProximateShellError {
     UntaggedRuntimeError {
         reason: String,
     },
-    ExternalPlaceholderError,
+    ExternalPlaceholderError {
+        exit_code: Option<i32>,
+    },
+    LoopControl {
+        control: LoopControl,
+        span: Span,
+    },
 }
 
 impl ProximateShellError {
@@ -766,7 +819,8 @@ impl HasFallibleSpan for ProximateShellError {
             ProximateShellError::Diagnostic(_) => return None,
             ProximateShellError::CoerceError { left, right } => left.span.until(right.span),
             ProximateShellError::UntaggedRuntimeError { .. } => return None,
-            ProximateShellError::ExternalPlaceholderError => return None,
+            ProximateShellError::ExternalPlaceholderError { .. } => return None,
+            ProximateShellError::LoopControl { span, .. } => *span,
         })
     }
 }
@@ -4,7 +4,7 @@ mod path;
 mod shapes;
 mod signature;
 
-pub use crate::lite_parse::{lite_parse, LiteBlock};
+pub use crate::lite_parse::{is_incomplete, lite_parse, lite_parse_lines, LiteBlock};
 pub use crate::parse::{classify_block, garbage, parse_full_column_path};
 pub use crate::path::expand_ndots;
 pub use crate::shapes::shapes;
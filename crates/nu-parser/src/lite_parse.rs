@@ -49,6 +49,17 @@ fn skip_whitespace(src: &mut Input) {
     }
 }
 
+/// Skips the whitespace up to the end of the line, leaving the newline, if there is one.
+fn skip_line_whitespace(src: &mut Input) {
+    while let Some((_, x)) = src.peek() {
+        if x.is_whitespace() && *x != '\n' {
+            let _ = src.next();
+        } else {
+            break;
+        }
+    }
+}
+
 fn bare(src: &mut Input, span_offset: usize) -> Result<Spanned<String>, ParseError> {
     skip_whitespace(src);
 
@@ -65,6 +76,11 @@ fn bare(src: &mut Input, span_offset: usize) -> Result<Spanned<String>, ParseErr
 
     while let Some((_, c)) = src.peek() {
         let c = *c;
+        if !inside_quote && c == '"' && bare == "$" {
+            interpolated_string(src, &mut bare);
+            continue;
+        }
+
         if inside_quote {
             if c == delimiter {
                 inside_quote = false;
@@ -104,6 +120,38 @@ fn bare(src: &mut Input, span_offset: usize) -> Result<Spanned<String>, ParseErr
     Ok(bare.spanned(span))
 }
 
+/// Reads the rest of a `$"..."` string, whose expressions in parentheses can have quotes of
+/// their own
+fn interpolated_string(src: &mut Input, bare: &mut String) {
+    let mut depth = 0;
+    let mut inner_quote = None;
+
+    if let Some((_, c)) = src.next() {
+        bare.push(c);
+    }
+
+    while let Some((_, c)) = src.next() {
+        bare.push(c);
+
+        match inner_quote {
+            Some(q) if c == q => inner_quote = None,
+            Some(_) => {}
+            // An escaped paren is text, which the parser reads the escape of
+            None if depth == 0 && c == '\\' => {
+                if let Some((_, escaped @ '(')) | Some((_, escaped @ ')')) = src.peek().copied() {
+                    bare.push(escaped);
+                    let _ = src.next();
+                }
+            }
+            None if depth > 0 && (c == '\'' || c == '"' || c == '`') => inner_quote = Some(c),
+            None if c == '(' => depth += 1,
+            None if c == ')' && depth > 0 => depth -= 1,
+            None if c == '"' && depth == 0 => break,
+            None => {}
+        }
+    }
+}
+
 fn command(src: &mut Input, span_offset: usize) -> Result<LiteCommand, ParseError> {
     let command = bare(src, span_offset)?;
     if command.item.is_empty() {
@@ -113,7 +161,14 @@ fn command(src: &mut Input, span_offset: usize) -> Result<LiteCommand, ParseErro
     }
 }
 
-fn pipeline(src: &mut Input, span_offset: usize) -> Result<LiteBlock, ParseError> {
+/// Reads the pipelines of `src`. When `lines` is set, a new line ends a pipeline like `;` does,
+/// unless the line ends with a `|`.
+fn pipeline(src: &mut Input, span_offset: usize, lines: bool) -> Result<LiteBlock, ParseError> {
+    let skip_space = if lines {
+        skip_line_whitespace
+    } else {
+        skip_whitespace
+    };
     let mut block = vec![];
     let mut commands = vec![];
 
@@ -127,13 +182,14 @@ fn pipeline(src: &mut Input, span_offset: usize) -> Result<LiteBlock, ParseError
             Err(e) => return Err(e),
         };
 
+        let mut after_pipe = false;
         loop {
-            skip_whitespace(src);
+            skip_space(src);
 
             if let Some((_, c)) = src.peek() {
                 // The first character tells us a lot about each argument
                 match c {
-                    ';' => {
+                    ';' | '\n' => {
                         // this is the end of the command and the end of the pipeline
                         break;
                     }
@@ -147,6 +203,7 @@ fn pipeline(src: &mut Input, span_offset: usize) -> Result<LiteBlock, ParseError
                                 let _ = src.next();
                             } else {
                                 // this is the end of this command
+                                after_pipe = true;
                                 break;
                             }
                         } else {
@@ -165,9 +222,15 @@ fn pipeline(src: &mut Input, span_offset: usize) -> Result<LiteBlock, ParseError
             }
         }
         commands.push(cmd);
-        skip_whitespace(src);
 
-        if let Some((_, ';')) = src.peek() {
+        // A pipeline goes on to the next line after a `|`
+        if after_pipe {
+            skip_whitespace(src);
+            continue;
+        }
+        skip_space(src);
+
+        if let Some((_, ';')) | Some((_, '\n')) = src.peek() {
             let _ = src.next();
 
             if !commands.is_empty() {
@@ -185,7 +248,50 @@ fn pipeline(src: &mut Input, span_offset: usize) -> Result<LiteBlock, ParseError
 }
 
 pub fn lite_parse(src: &str, span_offset: usize) -> Result<LiteBlock, ParseError> {
-    pipeline(&mut src.char_indices().peekable(), span_offset)
+    pipeline(&mut src.char_indices().peekable(), span_offset, false)
+}
+
+/// Like `lite_parse`, for the lines of a block or of what is typed in, where each line is a
+/// pipeline of its own, unless it ends with a `|`.
+pub fn lite_parse_lines(src: &str, span_offset: usize) -> Result<LiteBlock, ParseError> {
+    pipeline(&mut src.char_indices().peekable(), span_offset, true)
+}
+
+/// Whether `src` needs more lines to be complete, as it leaves a string, a block, a list or
+/// parentheses open, or ends with a `|` for the pipeline to go on.
+pub fn is_incomplete(src: &str) -> bool {
+    let mut quote = None;
+    let mut delimiters = vec![];
+    let mut last = None;
+
+    for c in src.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '\'' | '"' | '`' => quote = Some(c),
+                '[' | '{' | '(' => delimiters.push(c),
+                ']' | '}' | ')' => {
+                    let open = match c {
+                        ']' => '[',
+                        '}' => '{',
+                        _ => '(',
+                    };
+                    if delimiters.last() == Some(&open) {
+                        let _ = delimiters.pop();
+                    }
+                }
+                _ => {}
+            },
+        }
+
+        if !c.is_whitespace() {
+            last = Some(c);
+        }
+    }
+
+    let ends_with_pipe = last == Some('|') && !src.trim_end().ends_with("||");
+    quote.is_some() || !delimiters.is_empty() || ends_with_pipe
 }
 
 #[test]
@@ -209,3 +315,45 @@ fn lite_simple_offset() -> Result<(), ParseError> {
 
     Ok(())
 }
+
+#[test]
+fn lite_lines_are_pipelines() -> Result<(), ParseError> {
+    let result = lite_parse_lines("echo 1\necho 2 |\n  str length", 0)?;
+    assert_eq!(result.block.len(), 2);
+    assert_eq!(result.block[1].commands.len(), 2);
+    assert_eq!(result.block[1].commands[1].name.item, "str");
+
+    let result = lite_parse("echo 1\necho 2", 0)?;
+    assert_eq!(result.block.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn lite_lines_keep_blocks_together() -> Result<(), ParseError> {
+    let result = lite_parse_lines("each {\n  echo $it\n}\necho done", 0)?;
+    assert_eq!(result.block.len(), 2);
+    assert_eq!(result.block[0].commands[0].args[0].item, "{\n  echo $it\n}");
+
+    Ok(())
+}
+
+#[test]
+fn lite_interpolated_strings_end_after_escaped_parens() -> Result<(), ParseError> {
+    let result = lite_parse(r#"echo $"a \( b" | echo hi"#, 0)?;
+    assert_eq!(result.block.len(), 1);
+    assert_eq!(result.block[0].commands.len(), 2);
+    assert_eq!(result.block[0].commands[0].args[0].item, r#"$"a \( b""#);
+    Ok(())
+}
+
+#[test]
+fn lite_incomplete_input() {
+    assert!(is_incomplete("def greet [name] {"));
+    assert!(is_incomplete("ls |"));
+    assert!(is_incomplete("echo [1 2"));
+    assert!(is_incomplete("echo \"abc"));
+    assert!(!is_incomplete("ls | where size > 1kb"));
+    assert!(!is_incomplete("echo \"{\""));
+    assert!(!is_incomplete("= $true || $false"));
+}
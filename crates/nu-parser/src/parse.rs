@@ -1,6 +1,7 @@
+use std::collections::BTreeMap;
 use std::path::Path;
 
-use crate::lite_parse::{lite_parse, LiteBlock, LiteCommand, LitePipeline};
+use crate::lite_parse::{lite_parse, lite_parse_lines, LiteBlock, LiteCommand, LitePipeline};
 use crate::path::expand_path;
 use crate::signature::SignatureRegistry;
 use log::trace;
@@ -10,7 +11,10 @@ use nu_protocol::hir::{
     Expression, Flag, FlagKind, InternalCommand, Member, NamedArguments, Operator,
     SpannedExpression, Unit,
 };
-use nu_protocol::{NamedType, PositionalType, Signature, SyntaxShape, UnspannedPathMember};
+use nu_protocol::{
+    NamedType, PathMember, PositionalType, RangeInclusion, Signature, SyntaxShape,
+    UnspannedPathMember,
+};
 use nu_source::{Span, Spanned, SpannedItem};
 use num_bigint::BigInt;
 
@@ -129,15 +133,8 @@ pub fn parse_full_column_path(
             } else if head.is_none() && current_part.starts_with('$') {
                 // We have the variable head
                 head = Some(Expression::variable(current_part.clone(), part_span))
-            } else if let Ok(row_number) = current_part.parse::<u64>() {
-                output.push(
-                    UnspannedPathMember::Int(BigInt::from(row_number)).into_path_member(part_span),
-                );
             } else {
-                let current_part = trim_quotes(&current_part);
-                output.push(
-                    UnspannedPathMember::String(current_part.clone()).into_path_member(part_span),
-                );
+                output.push(path_member(&current_part, part_span));
             }
             current_part.clear();
             // Note: I believe this is safe because of the delimiter we're using, but if we get fancy with
@@ -179,47 +176,46 @@ pub fn parse_full_column_path(
             } else if current_part.starts_with('$') {
                 // We have the variable head
                 head = Some(Expression::variable(current_part, lite_arg.span));
-            } else if let Ok(row_number) = current_part.parse::<u64>() {
-                output.push(
-                    UnspannedPathMember::Int(BigInt::from(row_number)).into_path_member(part_span),
-                );
             } else {
-                let current_part = trim_quotes(&current_part);
-                output.push(UnspannedPathMember::String(current_part).into_path_member(part_span));
+                output.push(path_member(&current_part, part_span));
             }
-        } else if let Ok(row_number) = current_part.parse::<u64>() {
-            output.push(
-                UnspannedPathMember::Int(BigInt::from(row_number)).into_path_member(part_span),
-            );
         } else {
-            let current_part = trim_quotes(&current_part);
-            output.push(UnspannedPathMember::String(current_part).into_path_member(part_span));
+            output.push(path_member(&current_part, part_span));
         }
     }
 
-    if let Some(head) = head {
-        (
-            SpannedExpression::new(
-                Expression::path(SpannedExpression::new(head, lite_arg.span), output),
-                lite_arg.span,
-            ),
-            error,
-        )
+    let head = head.unwrap_or_else(|| Expression::variable("$it".into(), lite_arg.span));
+    let mut path = hir::Path::new(
+        SpannedExpression::new(head, lite_arg.span),
+        output.iter().map(|(member, _)| member.clone()).collect(),
+    );
+    path.optional = output
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, optional))| *optional)
+        .map(|(idx, _)| idx)
+        .collect();
+
+    (
+        SpannedExpression::new(Expression::Path(Box::new(path)), lite_arg.span),
+        error,
+    )
+}
+
+/// A member of a column path, and whether it's optional, like `foo` in `$it.foo?`.
+fn path_member(part: &str, span: Span) -> (PathMember, bool) {
+    let (part, optional, span) = match part.strip_suffix('?') {
+        Some(part) if !part.is_empty() => (part, true, Span::new(span.start(), span.end() - 1)),
+        _ => (part, false, span),
+    };
+
+    let member = if let Ok(row_number) = part.parse::<u64>() {
+        UnspannedPathMember::Int(BigInt::from(row_number))
     } else {
-        (
-            SpannedExpression::new(
-                Expression::path(
-                    SpannedExpression::new(
-                        Expression::variable("$it".into(), lite_arg.span),
-                        lite_arg.span,
-                    ),
-                    output,
-                ),
-                lite_arg.span,
-            ),
-            error,
-        )
-    }
+        UnspannedPathMember::String(trim_quotes(part))
+    };
+
+    (member.into_path_member(span), optional)
 }
 
 fn trim_quotes(input: &str) -> String {
@@ -233,40 +229,81 @@ fn trim_quotes(input: &str) -> String {
     }
 }
 
-/// Parse a numeric range
-fn parse_range(lite_arg: &Spanned<String>) -> (SpannedExpression, Option<ParseError>) {
-    let numbers: Vec<_> = lite_arg.item.split("..").collect();
-
-    if numbers.len() != 2 {
+/// Parses a range, like `1..10`, `1..<10` to leave out the end, or `1..3..10` to count by the
+/// difference between the first two values. The values can be numbers or variables.
+fn parse_range(
+    lite_arg: &Spanned<String>,
+    registry: &dyn SignatureRegistry,
+) -> (SpannedExpression, Option<ParseError>) {
+    let mismatch = || {
         (
             garbage(lite_arg.span),
             Some(ParseError::mismatch("range", lite_arg.clone())),
         )
-    } else if let Ok(lhs) = numbers[0].parse::<i64>() {
-        if let Ok(rhs) = numbers[1].parse::<i64>() {
-            (
-                SpannedExpression::new(
-                    Expression::range(
-                        SpannedExpression::new(Expression::integer(lhs), lite_arg.span),
-                        lite_arg.span,
-                        SpannedExpression::new(Expression::integer(rhs), lite_arg.span),
-                    ),
-                    lite_arg.span,
-                ),
-                None,
-            )
-        } else {
-            (
-                garbage(lite_arg.span),
-                Some(ParseError::mismatch("range", lite_arg.clone())),
-            )
+    };
+
+    // Each part, with the span it has in the argument
+    let mut parts = vec![];
+    let mut offset = lite_arg.span.start();
+    for part in lite_arg.item.split("..") {
+        parts.push(
+            part.to_string()
+                .spanned(Span::new(offset, offset + part.len())),
+        );
+        offset += part.len() + 2;
+    }
+
+    let (left, next, right) = match &parts[..] {
+        [left, right] => (left, None, right),
+        [left, next, right] => (left, Some(next), right),
+        _ => return mismatch(),
+    };
+
+    // Dots that are left over, like in `1...3`, aren't a decimal point
+    if parts.iter().any(|part| part.item.starts_with('.')) {
+        return mismatch();
+    }
+
+    let (right, inclusion) = match right.item.strip_prefix('<') {
+        Some(end) => (
+            end.to_string()
+                .spanned(Span::new(right.span.start() + 1, right.span.end())),
+            RangeInclusion::Exclusive,
+        ),
+        None => (right.clone(), RangeInclusion::Inclusive),
+    };
+
+    let parse_value = |part: &Spanned<String>| parse_arg(SyntaxShape::Number, registry, part);
+
+    let (left, left_err) = parse_value(left);
+    let (right, right_err) = parse_value(&right);
+    let (next, next_err) = match next {
+        Some(next) => {
+            let (next, err) = parse_value(next);
+            (Some(next), err)
         }
-    } else {
-        (
-            garbage(lite_arg.span),
-            Some(ParseError::mismatch("range", lite_arg.clone())),
-        )
+        None => (None, None),
+    };
+
+    if left_err.is_some() || right_err.is_some() || next_err.is_some() {
+        return mismatch();
     }
+
+    let dotdot = Span::new(left.span.end(), left.span.end() + 2);
+
+    (
+        SpannedExpression::new(
+            Expression::Range(Box::new(hir::Range {
+                left,
+                next,
+                dotdot,
+                right,
+                inclusion,
+            })),
+            lite_arg.span,
+        ),
+        None,
+    )
 }
 
 /// Parse any allowed operator, including word-based operators
@@ -284,10 +321,11 @@ fn parse_operator(lite_arg: &Spanned<String>) -> (SpannedExpression, Option<Pars
         "-" => Operator::Minus,
         "*" => Operator::Multiply,
         "/" => Operator::Divide,
-        "in:" => Operator::In,
-        "not-in:" => Operator::NotIn,
+        "in:" | "in" => Operator::In,
+        "not-in:" | "not-in" => Operator::NotIn,
         "&&" => Operator::And,
         "||" => Operator::Or,
+        "??" => Operator::Coalesce,
         _ => {
             return (
                 garbage(lite_arg.span),
@@ -311,13 +349,13 @@ fn parse_unit(lite_arg: &Spanned<String>) -> (SpannedExpression, Option<ParseErr
         (Unit::Gigabyte, vec!["gb", "GB", "Gb", "gB"]),
         (Unit::Terabyte, vec!["tb", "TB", "Tb", "tB"]),
         (Unit::Petabyte, vec!["pb", "PB", "Pb", "pB"]),
-        (Unit::Second, vec!["s"]),
-        (Unit::Minute, vec!["m"]),
-        (Unit::Hour, vec!["h"]),
-        (Unit::Day, vec!["d"]),
-        (Unit::Week, vec!["w"]),
-        (Unit::Month, vec!["M"]),
-        (Unit::Year, vec!["y"]),
+        (Unit::Second, vec!["s", "sec"]),
+        (Unit::Minute, vec!["m", "min"]),
+        (Unit::Hour, vec!["h", "hr"]),
+        (Unit::Day, vec!["d", "day"]),
+        (Unit::Week, vec!["w", "wk"]),
+        (Unit::Month, vec!["M", "mon"]),
+        (Unit::Year, vec!["y", "yr"]),
     ];
 
     for unit_group in unit_groups.iter() {
@@ -483,33 +521,173 @@ fn parse_interpolated_string(
         }
     }
 
+    (build_string(output, lite_arg.span), error)
+}
+
+/// Parses a string with `$"..."` interpolation. The expressions in parentheses are evaluated
+/// and joined with the text around them, `\(` and `\)` being literal parentheses.
+fn parse_string_interpolation(
+    registry: &dyn SignatureRegistry,
+    lite_arg: &Spanned<String>,
+) -> (SpannedExpression, Option<ParseError>) {
+    let inner_string = &lite_arg.item[2..lite_arg.item.len() - 1];
+    let offset = lite_arg.span.start() + 2;
+    let mut error = None;
+    let mut output = vec![];
+
+    let mut text = String::new();
+    let mut text_start = offset;
+    let mut chars = inner_string.char_indices().peekable();
+
+    while let Some((idx, c)) = chars.next() {
+        match c {
+            '\\' if matches!(chars.peek(), Some((_, '(')) | Some((_, ')'))) => {
+                if let Some((_, paren)) = chars.next() {
+                    text.push(paren);
+                }
+            }
+            '(' => {
+                if !text.is_empty() {
+                    output.push(SpannedExpression::new(
+                        Expression::string(std::mem::take(&mut text)),
+                        Span::new(text_start, offset + idx),
+                    ));
+                }
+
+                let mut depth = 1;
+                let mut quote = None;
+                let mut end = None;
+
+                for (inner_idx, c) in &mut chars {
+                    match quote {
+                        Some(q) if c == q => quote = None,
+                        Some(_) => {}
+                        None if c == '\'' || c == '"' || c == '`' => quote = Some(c),
+                        None if c == '(' => depth += 1,
+                        None if c == ')' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                end = Some(inner_idx);
+                                break;
+                            }
+                        }
+                        None => {}
+                    }
+                }
+
+                match end {
+                    Some(end) => {
+                        let expression = inner_string[idx + 1..end]
+                            .to_string()
+                            .spanned(Span::new(offset + idx + 1, offset + end));
+                        let (expression, err) =
+                            parse_interpolated_expression(registry, &expression);
+                        if error.is_none() {
+                            error = err;
+                        }
+                        output.push(expression);
+                        text_start = offset + end + 1;
+                    }
+                    None => {
+                        if error.is_none() {
+                            error = Some(ParseError::unexpected_eof(
+                                "closing )",
+                                Span::new(offset + idx, lite_arg.span.end()),
+                            ));
+                        }
+                        break;
+                    }
+                }
+            }
+            c => {
+                if text.is_empty() {
+                    text_start = offset + idx;
+                }
+                text.push(c);
+            }
+        }
+    }
+
+    if !text.is_empty() {
+        output.push(SpannedExpression::new(
+            Expression::string(text),
+            Span::new(text_start, lite_arg.span.end() - 1),
+        ));
+    }
+
+    (build_string(output, lite_arg.span), error)
+}
+
+/// Parses an expression inside of an interpolated string, either a variable with its column
+/// path, like `$it.name`, or a pipeline, like `echo $it.name | str upcase`
+fn parse_interpolated_expression(
+    registry: &dyn SignatureRegistry,
+    lite_arg: &Spanned<String>,
+) -> (SpannedExpression, Option<ParseError>) {
+    let trimmed = lite_arg.item.trim();
+
+    if trimmed.is_empty() {
+        return (
+            garbage(lite_arg.span),
+            Some(ParseError::mismatch("expression", lite_arg.clone())),
+        );
+    }
+
+    if trimmed.starts_with('$') && !trimmed.contains(char::is_whitespace) {
+        let start =
+            lite_arg.span.start() + (lite_arg.item.len() - lite_arg.item.trim_start().len());
+        let variable = trimmed
+            .to_string()
+            .spanned(Span::new(start, start + trimmed.len()));
+
+        return parse_full_column_path(&variable, registry);
+    }
+
+    let lite_block = match lite_parse(&lite_arg.item, lite_arg.span.start()) {
+        Ok(lb) => lb,
+        Err(e) => return (garbage(lite_arg.span), Some(e)),
+    };
+
+    let classified_block = classify_block(&lite_block, registry);
+
+    (
+        SpannedExpression::new(
+            Expression::Invocation(classified_block.block),
+            lite_arg.span,
+        ),
+        classified_block.failed,
+    )
+}
+
+/// An invocation of `build-string` joining the parts of an interpolated string
+fn build_string(parts: Vec<SpannedExpression>, span: Span) -> SpannedExpression {
     let block = vec![Commands {
-        span: lite_arg.span,
+        span,
         list: vec![ClassifiedCommand::Internal(InternalCommand {
             name: "build-string".to_owned(),
-            name_span: lite_arg.span,
+            name_span: span,
             args: hir::Call {
                 head: Box::new(SpannedExpression {
                     expr: Expression::Synthetic(hir::Synthetic::String("build-string".to_owned())),
-                    span: lite_arg.span,
+                    span,
                 }),
                 is_last: false,
+                capture_stderr: false,
                 named: None,
-                positional: Some(output),
-                span: lite_arg.span,
+                positional: Some(parts),
+                span,
             },
         })],
     }];
 
-    let call = SpannedExpression {
+    SpannedExpression {
         expr: Expression::Invocation(Block {
             block,
-            span: lite_arg.span,
+            span,
+            captures: BTreeMap::new(),
         }),
-        span: lite_arg.span,
-    };
-
-    (call, error)
+        span,
+    }
 }
 
 /// Parses the given argument using the shape as a guide for how to correctly parse the argument
@@ -518,7 +696,18 @@ fn parse_arg(
     registry: &dyn SignatureRegistry,
     lite_arg: &Spanned<String>,
 ) -> (SpannedExpression, Option<ParseError>) {
+    if lite_arg.item.starts_with("$\"") && lite_arg.item.len() > 2 && lite_arg.item.ends_with('"') {
+        return parse_string_interpolation(registry, lite_arg);
+    }
+
     if lite_arg.item.starts_with('$') {
+        // A range can start with a variable, like `$start..10`
+        if lite_arg.item.contains("..") {
+            if let (range, None) = parse_range(lite_arg, registry) {
+                return (range, None);
+            }
+        }
+
         return parse_full_column_path(&lite_arg, registry);
     }
 
@@ -578,7 +767,7 @@ fn parse_arg(
             )
         }
 
-        SyntaxShape::Range => parse_range(&lite_arg),
+        SyntaxShape::Range => parse_range(&lite_arg, registry),
         SyntaxShape::Operator => parse_operator(&lite_arg),
         SyntaxShape::Unit => parse_unit(&lite_arg),
         SyntaxShape::Path => {
@@ -593,6 +782,24 @@ fn parse_arg(
         SyntaxShape::ColumnPath => parse_simple_column_path(lite_arg),
         SyntaxShape::FullColumnPath => parse_full_column_path(lite_arg, registry),
         SyntaxShape::Any => {
+            if lite_arg.item.starts_with('(') && lite_arg.item.ends_with(')') {
+                // Parentheses holding an invocation or math, like `(date now)` or `(1 + 2)`
+                if let (expr, None) = parse_parenthesized_expression(lite_arg, registry, false) {
+                    if let Expression::Invocation(_) | Expression::Binary(_) = expr.expr {
+                        return (expr, None);
+                    }
+                }
+            }
+
+            if let Some(row) = parse_row(lite_arg, registry) {
+                return row;
+            }
+
+            // Lists and tables are given with their errors, like a row missing a cell
+            if lite_arg.item.starts_with('[') && lite_arg.item.ends_with(']') {
+                return parse_arg(SyntaxShape::Table, registry, lite_arg);
+            }
+
             let shapes = vec![
                 SyntaxShape::Int,
                 SyntaxShape::Number,
@@ -617,9 +824,8 @@ fn parse_arg(
 
             match (chars.next(), chars.next_back()) {
                 (Some('['), Some(']')) => {
-                    // We have a literal row
+                    // We have a literal list, or a table if it has headers, like `[[a b]; [1 2]]`
                     let string: String = chars.collect();
-                    let mut error = None;
 
                     // We haven't done much with the inner string, so let's go ahead and work with it
                     let lite_block = match lite_parse(&string, lite_arg.span.start() + 1) {
@@ -627,43 +833,31 @@ fn parse_arg(
                         Err(e) => return (garbage(lite_arg.span), Some(e)),
                     };
 
-                    if lite_block.block.is_empty() {
-                        return (
+                    match &lite_block.block[..] {
+                        [] => (
                             SpannedExpression::new(Expression::List(vec![]), lite_arg.span),
-                            error,
-                        );
-                    }
-                    if lite_block.block.len() > 1 {
-                        return (
-                            garbage(lite_arg.span),
-                            Some(ParseError::mismatch("table", lite_arg.clone())),
-                        );
-                    }
-
-                    let lite_pipeline = lite_block.block[0].clone();
-                    let mut output = vec![];
-                    for lite_inner in &lite_pipeline.commands {
-                        let (arg, err) = parse_arg(SyntaxShape::Any, registry, &lite_inner.name);
-
-                        output.push(arg);
-                        if error.is_none() {
-                            error = err;
-                        }
-
-                        for arg in &lite_inner.args {
-                            let (arg, err) = parse_arg(SyntaxShape::Any, registry, &arg);
-                            output.push(arg);
-
-                            if error.is_none() {
-                                error = err;
+                            None,
+                        ),
+                        [items] => {
+                            let mut output = vec![];
+                            let mut error = None;
+                            for item in list_items(items) {
+                                let (arg, err) = parse_item(&item, registry);
+                                output.push(arg);
+                                error = error.or(err);
                             }
+
+                            (
+                                SpannedExpression::new(Expression::List(output), lite_arg.span),
+                                error,
+                            )
                         }
+                        [headers, rows] => parse_table(headers, rows, lite_arg, registry),
+                        _ => (
+                            garbage(lite_arg.span),
+                            Some(ParseError::mismatch("table", lite_arg.clone())),
+                        ),
                     }
-
-                    (
-                        SpannedExpression::new(Expression::List(output), lite_arg.span),
-                        error,
-                    )
                 }
                 _ => (
                     garbage(lite_arg.span),
@@ -682,7 +876,7 @@ fn parse_arg(
                     let string: String = chars.collect();
 
                     // We haven't done much with the inner string, so let's go ahead and work with it
-                    let lite_block = match lite_parse(&string, lite_arg.span.start() + 1) {
+                    let lite_block = match lite_parse_lines(&string, lite_arg.span.start() + 1) {
                         Ok(lp) => lp,
                         Err(e) => return (garbage(lite_arg.span), Some(e)),
                     };
@@ -711,6 +905,233 @@ fn parse_arg(
     }
 }
 
+/// The items of a list, apart by spaces or commas, like in `[a b c]` or `[a, b, c]`.
+fn list_items(lite_pipeline: &LitePipeline) -> Vec<Spanned<String>> {
+    lite_pipeline
+        .commands
+        .iter()
+        .flat_map(|lite_cmd| std::iter::once(&lite_cmd.name).chain(lite_cmd.args.iter()))
+        .flat_map(|item| split_outside_quotes(item, ','))
+        .filter(|item| !item.item.is_empty())
+        .collect()
+}
+
+/// Whether the item is spread into a list or a row, like `...$rows`. Only values can be spread,
+/// so words like `...rest` in the parameters of `def` are left as they are.
+fn is_spread(item: &Spanned<String>) -> bool {
+    match item
+        .item
+        .strip_prefix("...")
+        .and_then(|value| value.chars().next())
+    {
+        Some(c) => c == '$' || c == '(' || c == '[' || c == '{' || c.is_ascii_digit(),
+        None => false,
+    }
+}
+
+/// Parses an item of a list or a row, which can be another one spread into it, like `...$rows`.
+fn parse_item(
+    item: &Spanned<String>,
+    registry: &dyn SignatureRegistry,
+) -> (SpannedExpression, Option<ParseError>) {
+    if !is_spread(item) {
+        return parse_arg(SyntaxShape::Any, registry, item);
+    }
+
+    let value = slice_arg(item, 3, item.item.len());
+    let (value, err) = parse_arg(SyntaxShape::Any, registry, &value);
+
+    (
+        SpannedExpression::new(Expression::Spread(Box::new(value)), item.span),
+        err,
+    )
+}
+
+/// Splits the argument at each `separator` that isn't in quotes or brackets.
+fn split_outside_quotes(lite_arg: &Spanned<String>, separator: char) -> Vec<Spanned<String>> {
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut quote = None;
+    let mut depth = 0;
+
+    for (idx, c) in lite_arg.item.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' || c == '`' => quote = Some(c),
+            None if c == '[' || c == '{' || c == '(' => depth += 1,
+            None if c == ']' || c == '}' || c == ')' => depth -= 1,
+            None if c == separator && depth == 0 => {
+                parts.push(slice_arg(lite_arg, start, idx));
+                start = idx + c.len_utf8();
+            }
+            None => {}
+        }
+    }
+    parts.push(slice_arg(lite_arg, start, lite_arg.item.len()));
+
+    parts
+}
+
+/// The part of the argument from `start` to `end`, with its own span.
+fn slice_arg(lite_arg: &Spanned<String>, start: usize, end: usize) -> Spanned<String> {
+    let offset = lite_arg.span.start();
+
+    lite_arg.item[start..end]
+        .to_string()
+        .spanned(Span::new(offset + start, offset + end))
+}
+
+/// Parses a table literal, from the list of its headers and its rows, which are lists of the
+/// same length, like `[[name, size]; [a, 1] [b, 2]]`.
+fn parse_table(
+    headers: &LitePipeline,
+    rows: &LitePipeline,
+    lite_arg: &Spanned<String>,
+    registry: &dyn SignatureRegistry,
+) -> (SpannedExpression, Option<ParseError>) {
+    let mut error = None;
+
+    let headers = match &list_items(headers)[..] {
+        [headers] => match parse_arg(SyntaxShape::Table, registry, headers) {
+            (
+                SpannedExpression {
+                    expr: Expression::List(headers),
+                    ..
+                },
+                err,
+            ) => {
+                error = error.or(err);
+                headers
+            }
+            (_, err) => return (garbage(lite_arg.span), err),
+        },
+        _ => {
+            return (
+                garbage(lite_arg.span),
+                Some(ParseError::mismatch(
+                    "a list of headers before the ;",
+                    lite_arg.clone(),
+                )),
+            )
+        }
+    };
+
+    let mut output = vec![];
+    for row in list_items(rows) {
+        match parse_arg(SyntaxShape::Table, registry, &row) {
+            (
+                SpannedExpression {
+                    expr: Expression::List(cells),
+                    ..
+                },
+                err,
+            ) => {
+                if cells.len() != headers.len() {
+                    error = error.or_else(|| {
+                        Some(ParseError::mismatch(
+                            format!("a row with {} columns", headers.len()),
+                            row.clone(),
+                        ))
+                    });
+                }
+
+                error = error.or(err);
+                output.push(cells);
+            }
+            (_, err) => {
+                error = error.or(err);
+            }
+        }
+    }
+
+    (
+        SpannedExpression::new(Expression::Table(headers, output), lite_arg.span),
+        error,
+    )
+}
+
+/// Parses a row literal, like `{name: "x", size: 3}`, whose columns are written `<name>: <value>`
+/// and apart by commas or spaces. This is `None` if the argument looks like a block instead.
+fn parse_row(
+    lite_arg: &Spanned<String>,
+    registry: &dyn SignatureRegistry,
+) -> Option<(SpannedExpression, Option<ParseError>)> {
+    if !lite_arg.item.starts_with('{') || !lite_arg.item.ends_with('}') || lite_arg.item.len() < 2 {
+        return None;
+    }
+
+    let inner = &lite_arg.item[1..lite_arg.item.len() - 1];
+    let lite_block = lite_parse(inner, lite_arg.span.start() + 1).ok()?;
+    let items: Vec<Spanned<String>> = match &lite_block.block[..] {
+        [items] => list_items(items),
+        _ => return None,
+    };
+
+    // A row starts with the name of a column and a colon, which a block doesn't
+    let mut items = items.into_iter().peekable();
+    let mut entries = vec![];
+    let mut error = None;
+    while let Some(item) = items.next() {
+        if is_spread(&item) {
+            let (value, err) = parse_item(&item, registry);
+            error = error.or(err);
+            entries.push((None, value));
+            continue;
+        }
+
+        let (column, value) = match &split_outside_quotes(&item, ':')[..] {
+            [column, value] if !column.item.is_empty() => {
+                if value.item.is_empty() {
+                    (column.clone(), items.next())
+                } else if entries.is_empty()
+                    && (value.item.starts_with('\\') || value.item.starts_with('/'))
+                {
+                    // A path with a drive, like `C:\`, or a URL
+                    return None;
+                } else {
+                    (column.clone(), Some(value.clone()))
+                }
+            }
+            [column] if items.peek().map_or(false, |colon| colon.item == ":") => {
+                let _ = items.next();
+                (column.clone(), items.next())
+            }
+            _ if entries.is_empty() => return None,
+            _ => {
+                error = error.or_else(|| Some(ParseError::mismatch("<column>: <value>", item)));
+                break;
+            }
+        };
+
+        let column =
+            SpannedExpression::new(Expression::string(trim_quotes(&column.item)), column.span);
+        let value = match value {
+            Some(value) => {
+                let (value, err) = parse_arg(SyntaxShape::Any, registry, &value);
+                error = error.or(err);
+                value
+            }
+            None => {
+                error = error.or_else(|| {
+                    Some(ParseError::argument_error(
+                        lite_arg.clone(),
+                        ArgumentError::MissingMandatoryPositional("a value".into()),
+                    ))
+                });
+                garbage(lite_arg.span)
+            }
+        };
+
+        entries.push((Some(column), value));
+    }
+
+    Some((
+        SpannedExpression::new(Expression::Row(entries), lite_arg.span),
+        error,
+    ))
+}
+
 /// Match the available flags in a signature with what the user provided. This will check both long-form flags (--full) and shorthand flags (-f)
 /// This also allows users to provide a group of shorthand flags (-af) that correspond to multiple shorthand flags at once.
 fn get_flags_from_flag(
@@ -820,6 +1241,19 @@ fn parse_parenthesized_expression(
 
             let mut lite_pipeline = lite_block.block[0].clone();
 
+            if is_invocation(&lite_pipeline, registry) {
+                // We have a command invocation, like `(date now)`
+                let classified_block = classify_block(&lite_block, registry);
+
+                return (
+                    SpannedExpression::new(
+                        Expression::Invocation(classified_block.block),
+                        lite_arg.span,
+                    ),
+                    classified_block.failed,
+                );
+            }
+
             let mut collection = vec![];
             for lite_cmd in lite_pipeline.commands.iter_mut() {
                 collection.push(lite_cmd.name.clone());
@@ -836,6 +1270,19 @@ fn parse_parenthesized_expression(
     }
 }
 
+/// Whether the contents of parentheses are a pipeline rather than a math expression: they contain
+/// a pipe, or start with a command that isn't followed by an operator
+fn is_invocation(lite_pipeline: &LitePipeline, registry: &dyn SignatureRegistry) -> bool {
+    match &lite_pipeline.commands[..] {
+        [] => false,
+        [lite_cmd] => match lite_cmd.args.first() {
+            Some(arg) => registry.has(&lite_cmd.name.item) && parse_operator(arg).1.is_some(),
+            None => registry.has(&lite_cmd.name.item),
+        },
+        _ => true,
+    }
+}
+
 fn parse_possibly_parenthesized(
     lite_arg: &Spanned<String>,
     registry: &dyn SignatureRegistry,
@@ -995,10 +1442,12 @@ fn parse_math_expression(
 
 /// Handles parsing the positional arguments as a batch
 /// This allows us to check for times where multiple arguments are treated as one shape, as is the case with SyntaxShape::Math
+/// A math expression leaves the last `remaining` arguments for the mandatory positionals after it, as in `while <cond> <block>`
 fn parse_positional_argument(
     idx: usize,
     lite_cmd: &LiteCommand,
     positional_type: &PositionalType,
+    remaining: usize,
     registry: &dyn SignatureRegistry,
 ) -> (usize, SpannedExpression, Option<ParseError>) {
     let mut idx = idx;
@@ -1009,6 +1458,8 @@ fn parse_positional_argument(
             // A condition can take up multiple arguments, as we build the operation as <arg> <operator> <arg>
             // We need to do this here because in parse_arg, we have access to only one arg at a time
 
+            let end = lite_cmd.args.len().saturating_sub(remaining).max(idx + 1);
+
             if idx < lite_cmd.args.len() {
                 if lite_cmd.args[idx].item.starts_with('{') {
                     // It's an explicit math expression, so parse it deeper in
@@ -1019,17 +1470,10 @@ fn parse_positional_argument(
                     arg
                 } else {
                     let (new_idx, arg, err) =
-                        parse_math_expression(idx, &lite_cmd.args[idx..], registry, true);
-
-                    let span = arg.span;
-                    let mut commands = hir::Commands::new(span);
-                    commands.push(ClassifiedCommand::Expr(Box::new(arg)));
-                    let mut block = hir::Block::new(span);
-                    block.push(commands);
-
-                    let arg = SpannedExpression::new(Expression::Block(block), span);
+                        parse_condition(idx, &lite_cmd.args[idx..end], registry, true);
 
-                    idx = new_idx;
+                    // The caller moves past the last argument of the expression
+                    idx = new_idx - 1;
                     if error.is_none() {
                         error = err;
                     }
@@ -1057,6 +1501,383 @@ fn parse_positional_argument(
     (idx, arg, error)
 }
 
+/// Parses the words of a condition as a math expression, wrapped in a block so it can be run
+/// again, like on each pass of a loop.
+fn parse_condition(
+    idx: usize,
+    lite_args: &[Spanned<String>],
+    registry: &dyn SignatureRegistry,
+    shorthand_mode: bool,
+) -> (usize, SpannedExpression, Option<ParseError>) {
+    let (new_idx, arg, err) = parse_math_expression(idx, lite_args, registry, shorthand_mode);
+
+    let span = arg.span;
+    let mut commands = hir::Commands::new(span);
+    commands.push(ClassifiedCommand::Expr(Box::new(arg)));
+    let mut block = hir::Block::new(span);
+    block.push(commands);
+
+    (
+        new_idx,
+        SpannedExpression::new(Expression::Block(block), span),
+        err,
+    )
+}
+
+/// Parses `let <name> = <value>`, where the value can take up the rest of the words, like in
+/// `let total = $x + 1`. The name and the value are given as the two positional arguments.
+/// `mut <name> = <value>` is parsed the same way.
+fn parse_let(
+    lite_cmd: &LiteCommand,
+    registry: &dyn SignatureRegistry,
+) -> (InternalCommand, Option<ParseError>) {
+    let mut internal_command = InternalCommand::new(
+        lite_cmd.name.item.clone(),
+        lite_cmd.name.span,
+        lite_cmd.span(),
+    );
+
+    match &lite_cmd.args[..] {
+        [name, equals, value @ ..] if equals.item == "=" && !value.is_empty() => {
+            let variable = name.item.trim_start_matches('$').to_string();
+            let (_, expr, err) = parse_math_expression(0, value, registry, false);
+
+            internal_command.args.positional = Some(vec![
+                SpannedExpression::new(Expression::string(variable), name.span),
+                expr,
+            ]);
+
+            let err = err.or_else(|| match name.item.trim_start_matches('$') {
+                "" | "it" | "nu" | "true" | "false" => {
+                    Some(ParseError::mismatch("variable name", name.clone()))
+                }
+                _ => None,
+            });
+
+            (internal_command, err)
+        }
+        [name, equals, ..] if equals.item == "=" => (
+            internal_command,
+            Some(ParseError::argument_error(
+                name.clone(),
+                ArgumentError::MissingMandatoryPositional("value".into()),
+            )),
+        ),
+        [_, other, ..] => (
+            internal_command,
+            Some(ParseError::mismatch("=", other.clone())),
+        ),
+        _ => (
+            internal_command,
+            Some(ParseError::argument_error(
+                lite_cmd.name.clone(),
+                ArgumentError::MissingMandatoryPositional("name".into()),
+            )),
+        ),
+    }
+}
+
+/// Parses `$<name> = <value>`, or with `+=` or `-=`, as a command named after the operator. Like
+/// with `let`, the name and the value are given as the two positional arguments.
+fn parse_assignment(
+    lite_cmd: &LiteCommand,
+    registry: &dyn SignatureRegistry,
+) -> (InternalCommand, Option<ParseError>) {
+    let operator = &lite_cmd.args[0];
+    let mut internal_command =
+        InternalCommand::new(operator.item.clone(), operator.span, lite_cmd.span());
+
+    let name = &lite_cmd.name;
+    let variable = name.item.trim_start_matches('$').to_string();
+    let value = &lite_cmd.args[1..];
+
+    if value.is_empty() {
+        return (
+            internal_command,
+            Some(ParseError::argument_error(
+                operator.clone(),
+                ArgumentError::MissingMandatoryPositional("value".into()),
+            )),
+        );
+    }
+
+    let (_, expr, err) = parse_math_expression(0, value, registry, false);
+
+    internal_command.args.positional = Some(vec![
+        SpannedExpression::new(Expression::string(variable), name.span),
+        expr,
+    ]);
+
+    (internal_command, err)
+}
+
+/// Parses `if <cond> { ... } else if <cond> { ... } else { ... }`. The positional arguments are
+/// each condition followed by its block, then the else block if there is one.
+fn parse_if(
+    lite_cmd: &LiteCommand,
+    registry: &dyn SignatureRegistry,
+) -> (InternalCommand, Option<ParseError>) {
+    let mut internal_command = InternalCommand::new(
+        lite_cmd.name.item.clone(),
+        lite_cmd.name.span,
+        lite_cmd.span(),
+    );
+
+    let mut positional = vec![];
+    let mut error = None;
+    let mut rest = &lite_cmd.args[..];
+
+    loop {
+        // The condition goes up to the first block after it, which is the one it runs
+        let block = rest
+            .iter()
+            .skip(1)
+            .position(|arg| arg.item.starts_with('{'))
+            .map(|idx| idx + 1);
+
+        let block = match block {
+            Some(block) => block,
+            None => {
+                error = error.or_else(|| {
+                    Some(ParseError::argument_error(
+                        lite_cmd.name.clone(),
+                        ArgumentError::MissingMandatoryPositional("a condition and a block".into()),
+                    ))
+                });
+                break;
+            }
+        };
+
+        // Unlike in `where`, there's no row to take a bare word on the left as a column of
+        let (expr, err) = if rest[0].item.starts_with('{') {
+            parse_arg(SyntaxShape::Math, registry, &rest[0])
+        } else {
+            let (_, expr, err) = parse_condition(0, &rest[..block], registry, false);
+            (expr, err)
+        };
+        positional.push(expr);
+        error = error.or(err);
+
+        let (expr, err) = parse_arg(SyntaxShape::Block, registry, &rest[block]);
+        positional.push(expr);
+        error = error.or(err);
+
+        rest = &rest[block + 1..];
+        match rest {
+            [] => break,
+            [word, next, tail @ ..] if word.item == "else" && next.item == "if" => {
+                if tail.is_empty() {
+                    error = error.or_else(|| {
+                        Some(ParseError::argument_error(
+                            next.clone(),
+                            ArgumentError::MissingMandatoryPositional("condition".into()),
+                        ))
+                    });
+                    break;
+                }
+                rest = tail;
+            }
+            [word, block] if word.item == "else" => {
+                let (expr, err) = parse_arg(SyntaxShape::Block, registry, block);
+                positional.push(expr);
+                error = error.or(err);
+                break;
+            }
+            [word] if word.item == "else" => {
+                error = error.or_else(|| {
+                    Some(ParseError::argument_error(
+                        word.clone(),
+                        ArgumentError::MissingMandatoryPositional("block".into()),
+                    ))
+                });
+                break;
+            }
+            [word, ..] if word.item == "else" => {
+                error = error.or_else(|| {
+                    Some(ParseError::argument_error(
+                        lite_cmd.name.clone(),
+                        ArgumentError::UnexpectedArgument(rest[2].clone()),
+                    ))
+                });
+                break;
+            }
+            [word, ..] => {
+                error = error.or_else(|| Some(ParseError::mismatch("else", word.clone())));
+                break;
+            }
+        }
+    }
+
+    internal_command.args.positional = Some(positional);
+    (internal_command, error)
+}
+
+/// Parses `match <value> { <pattern> => <body>, ... }`. The positional arguments are the value,
+/// then each pattern followed by its body as a block. Patterns are values, ranges, variables,
+/// the names of types as bare words, or `_` for anything.
+fn parse_match(
+    lite_cmd: &LiteCommand,
+    registry: &dyn SignatureRegistry,
+) -> (InternalCommand, Option<ParseError>) {
+    let mut internal_command = InternalCommand::new(
+        lite_cmd.name.item.clone(),
+        lite_cmd.name.span,
+        lite_cmd.span(),
+    );
+
+    let (value, arms) = match &lite_cmd.args[..] {
+        [value, arms] if arms.item.starts_with('{') && arms.item.ends_with('}') => (value, arms),
+        [_, arms] => {
+            return (
+                internal_command,
+                Some(ParseError::mismatch("the arms of the match", arms.clone())),
+            )
+        }
+        [_, _, extra, ..] => {
+            return (
+                internal_command,
+                Some(ParseError::argument_error(
+                    lite_cmd.name.clone(),
+                    ArgumentError::UnexpectedArgument(extra.clone()),
+                )),
+            )
+        }
+        _ => {
+            return (
+                internal_command,
+                Some(ParseError::argument_error(
+                    lite_cmd.name.clone(),
+                    ArgumentError::MissingMandatoryPositional("a value and its arms".into()),
+                )),
+            )
+        }
+    };
+
+    let (value, mut error) = parse_arg(SyntaxShape::Any, registry, value);
+    let mut positional = vec![value];
+
+    let inner = &arms.item[1..arms.item.len() - 1];
+    let lite_block = match lite_parse(inner, arms.span.start() + 1) {
+        Ok(lite_block) => lite_block,
+        Err(err) => return (internal_command, Some(err)),
+    };
+
+    // Arms are split by commas, or by semicolons as they end a pipeline
+    let mut arms: Vec<Vec<Spanned<String>>> = vec![];
+    for pipeline in lite_block.block {
+        if let [_, second, ..] = &pipeline.commands[..] {
+            error = error.or_else(|| {
+                Some(ParseError::mismatch(
+                    "an arm, with the pipeline of its body in a block",
+                    second.name.clone(),
+                ))
+            });
+        }
+
+        let mut arm = vec![];
+        for command in pipeline.commands {
+            for word in std::iter::once(command.name).chain(command.args) {
+                match word.item.strip_suffix(',') {
+                    Some(item) => {
+                        if !item.is_empty() {
+                            let span = Span::new(word.span.start(), word.span.end() - 1);
+                            arm.push(item.to_string().spanned(span));
+                        }
+                        arms.push(std::mem::take(&mut arm));
+                    }
+                    None => arm.push(word),
+                }
+            }
+        }
+        arms.push(arm);
+    }
+
+    for arm in arms.into_iter().filter(|arm| !arm.is_empty()) {
+        match &arm[..] {
+            [pattern, arrow, body @ ..] if arrow.item == "=>" && !body.is_empty() => {
+                let (pattern, err) = parse_pattern(pattern, registry);
+                positional.push(pattern);
+                error = error.or(err);
+
+                let (body, err) = match body {
+                    [block] if block.item.starts_with('{') => {
+                        parse_arg(SyntaxShape::Block, registry, block)
+                    }
+                    _ => {
+                        let (_, expr, err) = parse_math_expression(0, body, registry, false);
+
+                        let span = expr.span;
+                        let mut commands = hir::Commands::new(span);
+                        commands.push(ClassifiedCommand::Expr(Box::new(expr)));
+                        let mut block = hir::Block::new(span);
+                        block.push(commands);
+
+                        (SpannedExpression::new(Expression::Block(block), span), err)
+                    }
+                };
+                positional.push(body);
+                error = error.or(err);
+            }
+            [pattern, arrow] if arrow.item == "=>" => {
+                error = error.or_else(|| {
+                    Some(ParseError::argument_error(
+                        pattern.clone(),
+                        ArgumentError::MissingMandatoryPositional("the body of the arm".into()),
+                    ))
+                });
+            }
+            [_, other, ..] => {
+                error = error.or_else(|| Some(ParseError::mismatch("=>", other.clone())));
+            }
+            [pattern] => {
+                error = error.or_else(|| {
+                    Some(ParseError::argument_error(
+                        pattern.clone(),
+                        ArgumentError::MissingMandatoryPositional("=>".into()),
+                    ))
+                });
+            }
+            [] => {}
+        }
+    }
+
+    internal_command.args.positional = Some(positional);
+    (internal_command, error)
+}
+
+/// Parses the pattern of an arm of `match`. Bare words are kept as they are, as they name a type,
+/// or are `_`.
+fn parse_pattern(
+    lite_arg: &Spanned<String>,
+    registry: &dyn SignatureRegistry,
+) -> (SpannedExpression, Option<ParseError>) {
+    let shapes = [
+        SyntaxShape::Int,
+        SyntaxShape::Number,
+        SyntaxShape::Range,
+        SyntaxShape::Unit,
+    ];
+
+    for shape in shapes.iter() {
+        if let (expr, None) = parse_arg(*shape, registry, lite_arg) {
+            return (expr, None);
+        }
+    }
+
+    match lite_arg.item.chars().next() {
+        Some('$') | Some('"') | Some('\'') | Some('`') | Some('(') => {
+            parse_arg(SyntaxShape::Any, registry, lite_arg)
+        }
+        _ => (
+            SpannedExpression::new(
+                Expression::Literal(hir::Literal::Bare(lite_arg.item.clone())),
+                lite_arg.span,
+            ),
+            None,
+        ),
+    }
+}
+
 /// Does a full parse of an internal command using the lite-ly parse command as a starting point
 /// This main focus at this level is to understand what flags were passed in, what positional arguments were passed in, what rest arguments were passed in
 /// and to ensure that the basic requirements in terms of number of each were met.
@@ -1132,6 +1953,11 @@ fn parse_internal_command(
                         }
                     }
                 }
+            } else if signature.allows_unknown_flags {
+                positional.push(SpannedExpression::new(
+                    Expression::string(lite_cmd.args[idx].item.clone()),
+                    lite_cmd.args[idx].span,
+                ));
             } else {
                 positional.push(garbage(lite_cmd.args[idx].span));
 
@@ -1141,10 +1967,15 @@ fn parse_internal_command(
             }
         } else if signature.positional.len() > current_positional {
             let arg = {
+                let remaining = signature.positional[current_positional + 1..]
+                    .iter()
+                    .filter(|(positional, _)| matches!(positional, PositionalType::Mandatory(..)))
+                    .count();
                 let (new_idx, expr, err) = parse_positional_argument(
                     idx,
                     &lite_cmd,
                     &signature.positional[current_positional].0,
+                    remaining,
                     registry,
                 );
                 idx = new_idx;
@@ -1256,6 +2087,9 @@ fn classify_pipeline(
                     named: None,
                     span: Span::unknown(),
                     is_last: iter.peek().is_none(),
+                    capture_stderr: iter
+                        .peek()
+                        .map_or(false, |next| next.name.item == "complete"),
                 },
             }))
         } else if lite_cmd.name.item == "=" {
@@ -1273,6 +2107,74 @@ fn classify_pipeline(
                 garbage(lite_cmd.span())
             };
             commands.push(ClassifiedCommand::Expr(Box::new(expr)))
+        } else if lite_cmd.args.is_empty()
+            && matches!(parse_range(&lite_cmd.name, registry), (_, None))
+        {
+            // A range on its own gives its values, like in `1..10 | each { ... }`
+            let (range, _) = parse_range(&lite_cmd.name, registry);
+            commands.push(ClassifiedCommand::Expr(Box::new(range)))
+        } else if lite_cmd.args.is_empty() && parse_row(&lite_cmd.name, registry).is_some() {
+            // A row on its own gives itself, like in `{name: "x", size: 3} | to json`
+            if let Some((row, err)) = parse_row(&lite_cmd.name, registry) {
+                error = error.or(err);
+                commands.push(ClassifiedCommand::Expr(Box::new(row)))
+            }
+        } else if lite_cmd.args.is_empty()
+            && lite_cmd.name.item.starts_with('[')
+            && lite_cmd.name.item.ends_with(']')
+        {
+            // A list or a table on its own gives its rows, like in `[1 2 3] | each { ... }`
+            let (table, err) = parse_arg(SyntaxShape::Table, registry, &lite_cmd.name);
+
+            error = error.or(err);
+            commands.push(ClassifiedCommand::Expr(Box::new(table)))
+        } else if lite_cmd.name.item == "let" || lite_cmd.name.item == "mut" {
+            let (internal_command, err) = parse_let(&lite_cmd, registry);
+
+            error = error.or(err);
+            commands.push(ClassifiedCommand::Internal(internal_command));
+        } else if lite_cmd.name.item.starts_with('$')
+            && lite_cmd.args.first().map_or(false, |operator| {
+                ["=", "+=", "-="].contains(&&*operator.item)
+            })
+        {
+            let (internal_command, err) = parse_assignment(&lite_cmd, registry);
+
+            // The block runs an assignment, so there's no command to run it in a pipeline
+            if lite_pipeline.commands.len() > 1 {
+                error = error.or_else(|| {
+                    Some(ParseError::mismatch(
+                        "an assignment on its own, not part of a pipeline",
+                        lite_cmd.name.clone(),
+                    ))
+                });
+            }
+
+            error = error.or(err);
+            commands.push(ClassifiedCommand::Internal(internal_command));
+        } else if lite_cmd.name.item.starts_with('$') {
+            // An expression, like `$in * 2` in `echo abc | str length | $in * 2`
+            let mut args = vec![lite_cmd.name.clone()];
+            args.extend(lite_cmd.args.iter().cloned());
+
+            let (_, expr, err) = parse_math_expression(0, &args, registry, false);
+            error = error.or(err);
+            commands.push(ClassifiedCommand::Expr(Box::new(expr)))
+        } else if lite_cmd.name.item == "if" {
+            let (internal_command, err) = parse_if(&lite_cmd, registry);
+
+            error = error.or(err);
+            commands.push(ClassifiedCommand::Internal(internal_command));
+        } else if lite_cmd.name.item == "match"
+            && lite_cmd.args.len() > 1
+            && lite_cmd.args[1].item.starts_with('{')
+        {
+            // Only the form with a block of arms. The deprecated `match <column> <regex>` goes
+            // through the signature of match, which takes its regex in place of the arms
+            let (internal_command, err) = parse_match(&lite_cmd, registry);
+
+            error = error.or(err);
+            commands.push(ClassifiedCommand::Internal(internal_command));
         } else {
             if !lite_cmd.args.is_empty() {
                 // Check if it's a sub-command
@@ -1332,6 +2234,9 @@ fn classify_pipeline(
                     named: None,
                     span: Span::unknown(),
                     is_last: iter.peek().is_none(),
+                    capture_stderr: iter
+                        .peek()
+                        .map_or(false, |next| next.name.item == "complete"),
                 },
             }))
         }
@@ -1342,62 +2247,76 @@ fn classify_pipeline(
 
 type SpannedKeyValue = (Spanned<String>, Spanned<String>);
 
+/// Splits an environment variable assignment, like `NAME=value`, into its name and value
+fn env_assignment(word: &Spanned<String>) -> Option<SpannedKeyValue> {
+    let mut assignment = word.item.splitn(2, '=');
+    let name = assignment.next()?;
+    let value = assignment.next()?;
+
+    if name.is_empty()
+        || name.starts_with(|c: char| c.is_ascii_digit())
+        || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return None;
+    }
+
+    Some((
+        name.to_string().spanned(word.span),
+        trim_quotes(value).spanned(word.span),
+    ))
+}
+
 fn expand_shorthand_forms(
     lite_pipeline: &LitePipeline,
-) -> (LitePipeline, Option<SpannedKeyValue>, Option<ParseError>) {
+) -> (LitePipeline, Vec<SpannedKeyValue>, Option<ParseError>) {
     if !lite_pipeline.commands.is_empty() {
         if lite_pipeline.commands[0].name.item == "=" {
-            (lite_pipeline.clone(), None, None)
+            (lite_pipeline.clone(), vec![], None)
         } else if lite_pipeline.commands[0].name.contains('=') {
-            let assignment: Vec<_> = lite_pipeline.commands[0].name.split('=').collect();
-            if assignment.len() != 2 {
-                (
-                    lite_pipeline.clone(),
-                    None,
-                    Some(ParseError::mismatch(
-                        "environment variable assignment",
-                        lite_pipeline.commands[0].name.clone(),
-                    )),
-                )
-            } else {
-                let original_span = lite_pipeline.commands[0].name.span;
-                let env_value = trim_quotes(assignment[1]);
-
-                let (variable_name, value) = (assignment[0], env_value);
-                let mut lite_pipeline = lite_pipeline.clone();
-
-                if !lite_pipeline.commands[0].args.is_empty() {
-                    let new_lite_command_name = lite_pipeline.commands[0].args[0].clone();
-                    let mut new_lite_command_args = lite_pipeline.commands[0].args.clone();
-                    new_lite_command_args.remove(0);
+            let first = &lite_pipeline.commands[0];
+            let mut words = std::iter::once(&first.name).chain(first.args.iter());
+            let mut vars = vec![];
+
+            // Every assignment before the command is set for it, like `A=1 B=2 cmd`
+            let command_name = loop {
+                match words.next() {
+                    Some(word) if word.contains('=') => match env_assignment(word) {
+                        Some(var) => vars.push(var),
+                        None => {
+                            return (
+                                lite_pipeline.clone(),
+                                vec![],
+                                Some(ParseError::mismatch(
+                                    "environment variable assignment",
+                                    word.clone(),
+                                )),
+                            )
+                        }
+                    },
+                    Some(word) => break word.clone(),
+                    None => {
+                        return (
+                            lite_pipeline.clone(),
+                            vec![],
+                            Some(ParseError::mismatch(
+                                "a command following variable",
+                                first.name.clone(),
+                            )),
+                        )
+                    }
+                }
+            };
 
-                    lite_pipeline.commands[0].name = new_lite_command_name;
-                    lite_pipeline.commands[0].args = new_lite_command_args;
+            let mut lite_pipeline = lite_pipeline.clone();
+            lite_pipeline.commands[0].name = command_name;
+            lite_pipeline.commands[0].args = words.cloned().collect();
 
-                    (
-                        lite_pipeline,
-                        Some((
-                            variable_name.to_string().spanned(original_span),
-                            value.spanned(original_span),
-                        )),
-                        None,
-                    )
-                } else {
-                    (
-                        lite_pipeline.clone(),
-                        None,
-                        Some(ParseError::mismatch(
-                            "a command following variable",
-                            lite_pipeline.commands[0].name.clone(),
-                        )),
-                    )
-                }
-            }
+            (lite_pipeline, vars, None)
         } else {
-            (lite_pipeline.clone(), None, None)
+            (lite_pipeline.clone(), vec![], None)
         }
     } else {
-        (lite_pipeline.clone(), None, None)
+        (lite_pipeline.clone(), vec![], None)
     }
 }
 
@@ -1414,12 +2333,14 @@ pub fn classify_block(lite_block: &LiteBlock, registry: &dyn SignatureRegistry)
 
         let (pipeline, err) = classify_pipeline(&lite_pipeline, registry);
 
-        let pipeline = if let Some(vars) = vars {
+        let pipeline = if !vars.is_empty() {
             let span = pipeline.commands.span;
             let block = hir::Block {
                 block: vec![pipeline.commands.clone()],
                 span,
+                captures: BTreeMap::new(),
             };
+            let vars_span = Span::new(vars[0].0.span.start(), vars[vars.len() - 1].1.span.end());
             let mut call = hir::Call::new(
                 Box::new(SpannedExpression {
                     expr: Expression::string("with-env".to_string()),
@@ -1429,17 +2350,23 @@ pub fn classify_block(lite_block: &LiteBlock, registry: &dyn SignatureRegistry)
             );
             call.positional = Some(vec![
                 SpannedExpression {
-                    expr: Expression::List(vec![
-                        SpannedExpression {
-                            expr: Expression::string(vars.0.item),
-                            span: vars.0.span,
-                        },
-                        SpannedExpression {
-                            expr: Expression::string(vars.1.item),
-                            span: vars.1.span,
-                        },
-                    ]),
-                    span: Span::new(vars.0.span.start(), vars.1.span.end()),
+                    expr: Expression::List(
+                        vars.into_iter()
+                            .flat_map(|(name, value)| {
+                                vec![
+                                    SpannedExpression {
+                                        expr: Expression::string(name.item),
+                                        span: name.span,
+                                    },
+                                    SpannedExpression {
+                                        expr: Expression::string(value.item),
+                                        span: value.span,
+                                    },
+                                ]
+                            })
+                            .collect(),
+                    ),
+                    span: vars_span,
                 },
                 SpannedExpression {
                     expr: Expression::Block(block),
@@ -16,6 +16,24 @@ pub fn expression_to_flat_shape(e: &SpannedExpression) -> Vec<Spanned<FlatShape>
             }
             output
         }
+        Expression::Table(headers, rows) => {
+            let mut output = vec![];
+            for expr in headers.iter().chain(rows.iter().flatten()) {
+                output.append(&mut expression_to_flat_shape(expr));
+            }
+            output
+        }
+        Expression::Row(entries) => {
+            let mut output = vec![];
+            for (column, value) in entries.iter() {
+                if let Some(column) = column {
+                    output.append(&mut expression_to_flat_shape(column));
+                }
+                output.append(&mut expression_to_flat_shape(value));
+            }
+            output
+        }
+        Expression::Spread(value) => expression_to_flat_shape(value),
         Expression::Path(exprs) => {
             let mut output = vec![];
             output.append(&mut expression_to_flat_shape(&exprs.head));
@@ -55,6 +73,9 @@ pub fn expression_to_flat_shape(e: &SpannedExpression) -> Vec<Spanned<FlatShape>
             let mut output = vec![];
             output.append(&mut expression_to_flat_shape(&range.left));
             output.push(FlatShape::DotDot.spanned(range.dotdot));
+            if let Some(next) = &range.next {
+                output.append(&mut expression_to_flat_shape(next));
+            }
             output.append(&mut expression_to_flat_shape(&range.right));
             output
         }
@@ -69,6 +90,11 @@ pub fn shapes(commands: &Block) -> Vec<Spanned<FlatShape>> {
     for pipeline in &commands.block {
         for command in &pipeline.list {
             match command {
+                ClassifiedCommand::Internal(internal) if internal.name == "run_external" => {
+                    if let Some(positionals) = &internal.args.positional {
+                        output.append(&mut external_shapes(positionals));
+                    }
+                }
                 ClassifiedCommand::Internal(internal) => {
                     output.append(&mut expression_to_flat_shape(&internal.args.head));
 
@@ -101,3 +127,31 @@ pub fn shapes(commands: &Block) -> Vec<Spanned<FlatShape>> {
 
     output
 }
+
+/// The shapes of the name and the arguments of an external command. The name comes first, and
+/// the words given to it are told apart from its flags, as they are passed on as they are.
+fn external_shapes(args: &[SpannedExpression]) -> Vec<Spanned<FlatShape>> {
+    let mut output = vec![];
+    let mut args = args.iter();
+
+    if let Some(name) = args.next() {
+        output.push(FlatShape::ExternalCommand.spanned(name.span));
+    }
+
+    for arg in args {
+        match &arg.expr {
+            Expression::Literal(Literal::String(word)) if word.starts_with("--") => {
+                output.push(FlatShape::Flag.spanned(arg.span))
+            }
+            Expression::Literal(Literal::String(word)) if word.starts_with('-') => {
+                output.push(FlatShape::ShorthandFlag.spanned(arg.span))
+            }
+            Expression::Literal(Literal::String(_)) => {
+                output.push(FlatShape::ExternalWord.spanned(arg.span))
+            }
+            _ => output.append(&mut expression_to_flat_shape(arg)),
+        }
+    }
+
+    output
+}
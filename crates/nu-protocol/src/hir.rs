@@ -1,11 +1,12 @@
 use std::cmp::{Ord, Ordering, PartialOrd};
+use std::collections::BTreeMap;
 use std::convert::From;
 use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 
-use crate::{hir, Primitive, UntaggedValue};
+use crate::{hir, Primitive, RangeInclusion, UntaggedValue, Value};
 use crate::{PathMember, ShellTypeName};
 use derive_new::new;
 use num_traits::ToPrimitive;
@@ -107,6 +108,28 @@ impl ClassifiedCommand {
         }
     }
 
+    /// Whether the command uses `$in`, the input it is given, including in its blocks
+    pub fn has_in_usage(&self) -> bool {
+        match self {
+            ClassifiedCommand::Internal(command) => {
+                let positional = command.args.positional.iter().flatten();
+                let named = command
+                    .args
+                    .named
+                    .iter()
+                    .flat_map(|named| named.iter())
+                    .filter_map(|(_, value)| match value {
+                        NamedValue::Value(_, value) => Some(value),
+                        _ => None,
+                    });
+
+                positional.chain(named).any(|arg| arg.has_in_usage())
+            }
+            ClassifiedCommand::Expr(expr) => expr.has_in_usage(),
+            _ => false,
+        }
+    }
+
     pub fn expand_it_usage(&mut self) {
         match self {
             ClassifiedCommand::Internal(command) => {
@@ -183,10 +206,12 @@ impl Commands {
                                     span: self.span,
                                 }],
                                 span: self.span,
+                                captures: BTreeMap::new(),
                             }),
                             span: self.span,
                         }]),
                         is_last: false, // FIXME
+                        capture_stderr: false,
                     },
                 })
             }
@@ -198,6 +223,9 @@ impl Commands {
 pub struct Block {
     pub block: Vec<Commands>,
     pub span: Span,
+    /// The variables of the scope the block was made in, kept as they were then, for whenever
+    /// the block is run.
+    pub captures: BTreeMap<String, Value>,
 }
 
 impl Block {
@@ -205,6 +233,7 @@ impl Block {
         Block {
             block: vec![],
             span,
+            captures: BTreeMap::new(),
         }
     }
 
@@ -212,6 +241,14 @@ impl Block {
         self.block.push(commands);
     }
 
+    /// Whether `$in` is used in the block
+    pub fn has_in_usage(&self) -> bool {
+        self.block
+            .iter()
+            .flat_map(|commands| commands.list.iter())
+            .any(ClassifiedCommand::has_in_usage)
+    }
+
     /// Convert all shallow uses of $it to `each { use of $it }`, converting each to a per-row command
     pub fn expand_it_usage(&mut self) {
         for commands in &mut self.block {
@@ -552,6 +589,8 @@ impl SpannedExpression {
                     | Operator::NotEqual
                     | Operator::In
                     | Operator::NotIn => 80,
+                    // So `$it.size? ?? 0 > 10` compares the size, or 0 if there's none
+                    Operator::Coalesce => 85,
                     Operator::And => 50,
                     Operator::Or => 40, // TODO: should we have And and Or be different precedence?
                 }
@@ -560,6 +599,32 @@ impl SpannedExpression {
         }
     }
 
+    /// Whether `$in` is used in the expression, including in its blocks
+    pub fn has_in_usage(&self) -> bool {
+        match &self.expr {
+            Expression::Variable(Variable::Other(name, _)) => name == "$in",
+            Expression::Path(path) => path.head.has_in_usage(),
+            Expression::Binary(binary) => binary.left.has_in_usage() || binary.right.has_in_usage(),
+            Expression::Range(range) => {
+                range.left.has_in_usage()
+                    || range.right.has_in_usage()
+                    || range
+                        .next
+                        .as_ref()
+                        .map_or(false, |next| next.has_in_usage())
+            }
+            Expression::List(list) => list.iter().any(|item| item.has_in_usage()),
+            Expression::Table(headers, rows) => headers
+                .iter()
+                .chain(rows.iter().flatten())
+                .any(|cell| cell.has_in_usage()),
+            Expression::Row(entries) => entries.iter().any(|(_, value)| value.has_in_usage()),
+            Expression::Spread(value) => value.has_in_usage(),
+            Expression::Block(block) | Expression::Invocation(block) => block.has_in_usage(),
+            _ => false,
+        }
+    }
+
     pub fn has_shallow_it_usage(&self) -> bool {
         match &self.expr {
             Expression::Binary(binary) => {
@@ -575,6 +640,17 @@ impl SpannedExpression {
                 }
                 false
             }
+            Expression::Table(headers, rows) => headers
+                .iter()
+                .chain(rows.iter().flatten())
+                .any(|cell| cell.has_shallow_it_usage()),
+            Expression::Row(entries) => entries.iter().any(|(column, value)| {
+                column
+                    .as_ref()
+                    .map_or(false, |column| column.has_shallow_it_usage())
+                    || value.has_shallow_it_usage()
+            }),
+            Expression::Spread(value) => value.has_shallow_it_usage(),
             Expression::Invocation(block) => {
                 for commands in block.block.iter() {
                     for command in commands.list.iter() {
@@ -643,6 +719,43 @@ impl PrettyDebugWithSource for SpannedExpression {
                     ),
                     "]",
                 ),
+                Expression::Table(headers, rows) => b::delimit(
+                    "[",
+                    b::intersperse(
+                        std::iter::once(headers).chain(rows.iter()).map(|row| {
+                            b::delimit(
+                                "[",
+                                b::intersperse(
+                                    row.iter()
+                                        .map(|item| item.refined_pretty_debug(refine, source)),
+                                    b::space(),
+                                ),
+                                "]",
+                            )
+                        }),
+                        b::space(),
+                    ),
+                    "]",
+                ),
+                Expression::Row(entries) => b::delimit(
+                    "{",
+                    b::intersperse(
+                        entries.iter().map(|(column, value)| match column {
+                            Some(column) => {
+                                column.refined_pretty_debug(refine, source)
+                                    + b::operator(":")
+                                    + b::space()
+                                    + value.refined_pretty_debug(refine, source)
+                            }
+                            None => value.refined_pretty_debug(refine, source),
+                        }),
+                        b::space(),
+                    ),
+                    "}",
+                ),
+                Expression::Spread(value) => {
+                    b::operator("...") + value.refined_pretty_debug(refine, source)
+                }
                 Expression::Path(path) => path.pretty_debug(source),
                 Expression::FilePath(path) => b::typed("path", b::primitive(path.display())),
                 Expression::ExternalCommand(external) => {
@@ -683,6 +796,40 @@ impl PrettyDebugWithSource for SpannedExpression {
                 ),
                 "]",
             ),
+            Expression::Table(headers, rows) => b::delimit(
+                "[",
+                b::intersperse(
+                    std::iter::once(headers).chain(rows.iter()).map(|row| {
+                        b::delimit(
+                            "[",
+                            b::intersperse(
+                                row.iter().map(|item| item.pretty_debug(source)),
+                                b::space(),
+                            ),
+                            "]",
+                        )
+                    }),
+                    b::space(),
+                ),
+                "]",
+            ),
+            Expression::Row(entries) => b::delimit(
+                "{",
+                b::intersperse(
+                    entries.iter().map(|(column, value)| match column {
+                        Some(column) => {
+                            column.pretty_debug(source)
+                                + b::operator(":")
+                                + b::space()
+                                + value.pretty_debug(source)
+                        }
+                        None => value.pretty_debug(source),
+                    }),
+                    b::space(),
+                ),
+                "}",
+            ),
+            Expression::Spread(value) => b::operator("...") + value.pretty_debug(source),
             Expression::Path(path) => path.pretty_debug(source),
             Expression::FilePath(path) => b::typed("path", b::primitive(path.display())),
             Expression::ExternalCommand(external) => b::typed(
@@ -724,6 +871,7 @@ pub enum Operator {
     NotIn,
     And,
     Or,
+    Coalesce,
 }
 
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Clone, Hash, Deserialize, Serialize, new)]
@@ -764,18 +912,28 @@ impl ShellTypeName for Synthetic {
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Clone, Hash, Deserialize, Serialize)]
 pub struct Range {
     pub left: SpannedExpression,
+    /// The value after the left end, for ranges that count by more than one, like `1..3..9`
+    pub next: Option<SpannedExpression>,
     pub dotdot: Span,
     pub right: SpannedExpression,
+    /// Whether the right end is in the range, which it isn't when written like `1..<10`
+    pub inclusion: RangeInclusion,
 }
 
 impl PrettyDebugWithSource for Range {
     fn pretty_debug(&self, source: &str) -> DebugDocBuilder {
+        let next = match &self.next {
+            Some(next) => next.pretty_debug(source) + b::space() + b::keyword(".."),
+            None => b::blank(),
+        };
+
         b::delimit(
             "<",
             self.left.pretty_debug(source)
                 + b::space()
                 + b::keyword(self.dotdot.slice(source))
                 + b::space()
+                + next
                 + self.right.pretty_debug(source),
             ">",
         )
@@ -869,6 +1027,10 @@ impl PrettyDebugWithSource for SpannedLiteral {
 pub struct Path {
     pub head: SpannedExpression,
     pub tail: Vec<PathMember>,
+    /// The positions in the tail of the optional members, like `foo` in `$it.foo?`, which give
+    /// nothing instead of an error when they're missing
+    #[new(default)]
+    pub optional: Vec<usize>,
 }
 
 impl PrettyDebugWithSource for Path {
@@ -889,6 +1051,13 @@ pub enum Expression {
     Range(Box<Range>),
     Block(hir::Block),
     List(Vec<SpannedExpression>),
+    /// A table literal, like `[[name, size]; [a, 1] [b, 2]]`, with its headers and its rows
+    Table(Vec<SpannedExpression>, Vec<Vec<SpannedExpression>>),
+    /// A row literal, like `{name: "x", size: 3}`, with its columns and their values. A value
+    /// without a column is a spread row, like `...$row`, whose columns are all put in
+    Row(Vec<(Option<SpannedExpression>, SpannedExpression)>),
+    /// A list, table or row spread into a literal, like `...$rows` in `[...$rows 4]`
+    Spread(Box<SpannedExpression>),
     Path(Box<Path>),
 
     FilePath(PathBuf),
@@ -914,6 +1083,9 @@ impl ShellTypeName for Expression {
             Expression::FilePath(..) => "file path",
             Expression::Variable(..) => "variable",
             Expression::List(..) => "list",
+            Expression::Table(..) => "table",
+            Expression::Row(..) => "row",
+            Expression::Spread(..) => "spread",
             Expression::Binary(..) => "binary",
             Expression::Range(..) => "range",
             Expression::Block(..) => "block",
@@ -957,8 +1129,10 @@ impl Expression {
     pub fn range(left: SpannedExpression, dotdot: Span, right: SpannedExpression) -> Expression {
         Expression::Range(Box::new(Range {
             left,
+            next: None,
             dotdot,
             right,
+            inclusion: RangeInclusion::Inclusive,
         }))
     }
 
@@ -1033,6 +1207,9 @@ pub struct Call {
     pub named: Option<NamedArguments>,
     pub span: Span,
     pub is_last: bool,
+    /// Whether an external command gives its stderr and exit code to the next command, instead
+    /// of to the terminal, as when it's piped into `complete`.
+    pub capture_stderr: bool,
 }
 
 impl Call {
@@ -1106,6 +1283,7 @@ impl Call {
             named: None,
             span,
             is_last: false,
+            capture_stderr: false,
         }
     }
 }
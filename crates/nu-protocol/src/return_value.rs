@@ -21,8 +21,11 @@ pub enum CommandAction {
     EnterValueShell(Value),
     /// Enter the help shell, which allows exploring the help system
     EnterHelpShell(Value),
-    /// Enter the help shell, which allows exploring the help system
+    /// Add an alias, with its name, the parameters as written, and its body
     AddAlias(String, Vec<String>, Block),
+    /// Add a command defined by the user, with its name, the parameters as written, its
+    /// documentation, and its body
+    AddCommand(String, Vec<String>, String, Block),
     /// Go to the previous shell in the shell ring buffer
     PreviousShell,
     /// Go to the next shell in the shell ring buffer
@@ -45,6 +48,7 @@ impl PrettyDebug for CommandAction {
             CommandAction::EnterValueShell(v) => b::typed("enter value shell", v.pretty()),
             CommandAction::EnterHelpShell(v) => b::typed("enter help shell", v.pretty()),
             CommandAction::AddAlias(..) => b::description("add alias"),
+            CommandAction::AddCommand(..) => b::description("add command"),
             CommandAction::PreviousShell => b::description("previous shell"),
             CommandAction::NextShell => b::description("next shell"),
             CommandAction::LeaveShell => b::description("leave shell"),
@@ -116,6 +116,10 @@ pub struct Signature {
     pub input: Option<Type>,
     /// If the command is expected to filter data, or to consume it (as a sink)
     pub is_filter: bool,
+    /// If flags that aren't in the signature are given to the command as positional arguments,
+    /// as they are written, instead of being an error
+    #[serde(default)]
+    pub allows_unknown_flags: bool,
 }
 
 impl Signature {
@@ -179,6 +183,7 @@ impl Signature {
             rest_positional: None,
             named: indexmap::indexmap! {"help".into() => (NamedType::Switch(Some('h')), "Display this help message".into())},
             is_filter: false,
+            allows_unknown_flags: false,
             yields: None,
             input: None,
         }
@@ -289,6 +294,12 @@ impl Signature {
         self
     }
 
+    /// Give the flags that aren't in the signature to the command as positional arguments
+    pub fn allow_unknown_flags(mut self) -> Signature {
+        self.allows_unknown_flags = true;
+        self
+    }
+
     /// Set the type for the "rest" of the positional arguments
     pub fn rest(mut self, ty: SyntaxShape, desc: impl Into<String>) -> Signature {
         self.rest_positional = Some((ty, desc.into()));
@@ -34,4 +34,8 @@ impl RangeInclusion {
 pub struct Range {
     pub from: (Spanned<Primitive>, RangeInclusion),
     pub to: (Spanned<Primitive>, RangeInclusion),
+    /// The value after the start, for ranges that count by more than one (eg 1..3..9 counts by 2)
+    #[new(default)]
+    #[serde(default)]
+    pub next: Option<Spanned<Primitive>>,
 }
@@ -1,4 +0,0 @@
-mod match_;
-mod nu;
-
-pub use match_::Match;
@@ -14,11 +14,19 @@ impl Plugin for Ps {
                 "list all available columns for each entry",
                 Some('f'),
             )
+            .switch(
+                "tree",
+                "nest each process under its parent, in a children column",
+                Some('t'),
+            )
             .filter())
     }
 
     fn begin_filter(&mut self, callinfo: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
-        Ok(block_on(ps(callinfo.name_tag, callinfo.args.has("full")))?
+        let full = callinfo.args.has("full");
+        let tree = callinfo.args.has("tree");
+
+        Ok(block_on(ps(callinfo.name_tag, full, tree))?
             .into_iter()
             .map(ReturnSuccess::value)
             .collect())
@@ -1,13 +1,14 @@
 use futures::{StreamExt, TryStreamExt};
-use heim::process::{self as process, Process, ProcessResult};
-use heim::units::{information, ratio, Ratio};
+use heim::process::{self as process, Pid, Process, ProcessResult};
+use heim::units::{information, ratio, time, Ratio};
+use std::collections::HashMap;
 use std::usize;
 
 use nu_errors::ShellError;
 use nu_protocol::{TaggedDictBuilder, UntaggedValue, Value};
 use nu_source::Tag;
 
-use std::time::Duration;
+use std::time::{Duration, UNIX_EPOCH};
 
 #[derive(Default)]
 pub struct Ps;
@@ -28,7 +29,7 @@ async fn usage(process: Process) -> ProcessResult<(process::Process, Ratio, proc
     Ok((process, usage_2 - usage_1, memory))
 }
 
-pub async fn ps(tag: Tag, full: bool) -> Result<Vec<Value>, ShellError> {
+pub async fn ps(tag: Tag, full: bool, tree: bool) -> Result<Vec<Value>, ShellError> {
     let processes = process::processes()
         .await
         .map_err(|_| {
@@ -48,8 +49,15 @@ pub async fn ps(tag: Tag, full: bool) -> Result<Vec<Value>, ShellError> {
     futures::pin_mut!(processes);
 
     let mut output = vec![];
+    let mut parents = vec![];
     while let Some(res) = processes.next().await {
         if let Ok((process, usage, memory)) = res {
+            let parent_pid = if full || tree {
+                process.parent_pid().await.ok()
+            } else {
+                None
+            };
+
             let mut dict = TaggedDictBuilder::new(&tag);
             dict.insert_untagged("pid", UntaggedValue::int(process.pid()));
             if let Ok(name) = process.name().await {
@@ -68,10 +76,18 @@ pub async fn ps(tag: Tag, full: bool) -> Result<Vec<Value>, ShellError> {
                 UntaggedValue::bytes(memory.vms().get::<information::byte>()),
             );
             if full {
-                if let Ok(parent_pid) = process.parent_pid().await {
+                if let Some(parent_pid) = parent_pid {
                     dict.insert_untagged("parent", UntaggedValue::int(parent_pid))
                 }
 
+                if let Ok(start) = process.create_time().await {
+                    let secs = start.get::<time::second>();
+                    if secs.is_finite() && secs >= 0.0 {
+                        let start = UNIX_EPOCH + Duration::from_secs_f64(secs);
+                        dict.insert_untagged("start", UntaggedValue::system_date(start))
+                    }
+                }
+
                 if let Ok(exe) = process.exe().await {
                     dict.insert_untagged("exe", UntaggedValue::string(exe.to_string_lossy()))
                 }
@@ -83,12 +99,92 @@ pub async fn ps(tag: Tag, full: bool) -> Result<Vec<Value>, ShellError> {
                             "command",
                             UntaggedValue::string(command.to_os_string().to_string_lossy()),
                         );
+                        dict.insert_untagged(
+                            "args",
+                            UntaggedValue::Table(
+                                command
+                                    .into_iter()
+                                    .skip(1)
+                                    .map(|arg| {
+                                        UntaggedValue::string(arg.to_string_lossy())
+                                            .into_value(&tag)
+                                    })
+                                    .collect(),
+                            ),
+                        );
                     }
                 }
             }
-            output.push(dict.into_value());
+            output.push((process.pid(), dict));
+            parents.push(parent_pid);
         }
     }
 
-    Ok(output)
+    if tree {
+        Ok(into_tree(output, parents, &tag))
+    } else {
+        Ok(output
+            .into_iter()
+            .map(|(_, dict)| dict.into_value())
+            .collect())
+    }
+}
+
+/// Nests each process under its parent, in a `children` column, leaving the processes without a
+/// parent in the list at the top.
+fn into_tree(
+    processes: Vec<(Pid, TaggedDictBuilder)>,
+    parents: Vec<Option<Pid>>,
+    tag: &Tag,
+) -> Vec<Value> {
+    let pids = processes.iter().map(|(pid, _)| *pid).collect::<Vec<_>>();
+    let mut children: HashMap<Pid, Vec<Pid>> = HashMap::new();
+    let mut roots = vec![];
+
+    for (pid, parent) in pids.iter().zip(parents) {
+        match parent {
+            Some(parent) if parent != *pid && pids.contains(&parent) => {
+                children.entry(parent).or_default().push(*pid)
+            }
+            _ => roots.push(*pid),
+        }
+    }
+
+    let mut rows = processes.into_iter().collect::<HashMap<_, _>>();
+    roots.sort();
+
+    let mut tree = roots
+        .into_iter()
+        .filter_map(|pid| with_children(pid, &mut rows, &children, tag))
+        .collect::<Vec<_>>();
+
+    // Processes whose parents loop back to them aren't under any root, so they go at the top too.
+    let mut rest = rows.keys().copied().collect::<Vec<_>>();
+    rest.sort();
+    for pid in rest {
+        tree.extend(with_children(pid, &mut rows, &children, tag));
+    }
+
+    tree
+}
+
+fn with_children(
+    pid: Pid,
+    rows: &mut HashMap<Pid, TaggedDictBuilder>,
+    children: &HashMap<Pid, Vec<Pid>>,
+    tag: &Tag,
+) -> Option<Value> {
+    // Taking the row out means a process is only listed once.
+    let mut dict = rows.remove(&pid)?;
+
+    let mut pids = children.get(&pid).cloned().unwrap_or_default();
+    pids.sort();
+
+    let nested = pids
+        .into_iter()
+        .filter_map(|pid| with_children(pid, rows, children, tag))
+        .collect();
+    dict.insert_untagged("children", UntaggedValue::Table(nested));
+
+    Some(dict.into_value())
 }
@@ -9,11 +9,18 @@ impl Plugin for Sys {
     fn config(&mut self) -> Result<Signature, ShellError> {
         Ok(Signature::build("sys")
             .desc("View information about the current system.")
+            .switch(
+                "all",
+                "list every mounted filesystem in disks, not just the physical ones",
+                Some('a'),
+            )
             .filter())
     }
 
     fn begin_filter(&mut self, callinfo: CallInfo) -> Result<Vec<ReturnValue>, ShellError> {
-        Ok(block_on(sysinfo(callinfo.name_tag))
+        let all_disks = callinfo.args.has("all");
+
+        Ok(block_on(sysinfo(callinfo.name_tag, all_disks))
             .into_iter()
             .map(ReturnSuccess::value)
             .collect())
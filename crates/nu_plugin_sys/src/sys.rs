@@ -126,9 +126,61 @@ async fn host(tag: Tag) -> Result<Value, ShellError> {
     Ok(dict.into_value())
 }
 
-async fn disks(tag: Tag) -> Result<Option<UntaggedValue>, ShellError> {
+/// The options a filesystem is mounted with, like `ro,nosuid`, from the flags `statvfs` knows.
+#[cfg(unix)]
+fn mount_options(usage: &disk::Usage) -> String {
+    use heim::disk::os::unix::{Flags, UsageExt};
+
+    let flags = usage.flags();
+    let mut options = vec![if flags.contains(Flags::RDONLY) {
+        "ro"
+    } else {
+        "rw"
+    }];
+
+    if flags.contains(Flags::NOSUID) {
+        options.push("nosuid");
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let known = [
+            (Flags::NODEV, "nodev"),
+            (Flags::NOEXEC, "noexec"),
+            (Flags::SYNCHRONOUS, "sync"),
+            (Flags::MANDLOCK, "mand"),
+            (Flags::NOATIME, "noatime"),
+            (Flags::NODIRATIME, "nodiratime"),
+        ];
+
+        options.extend(
+            known
+                .iter()
+                .filter(|(flag, _)| flags.contains(*flag))
+                .map(|(_, name)| *name),
+        );
+    }
+
+    #[cfg(not(any(target_os = "macos", target_env = "musl")))]
+    {
+        if flags.contains(Flags::RELATIME) {
+            options.push("relatime");
+        }
+    }
+
+    options.join(",")
+}
+
+async fn disks(tag: Tag, all: bool) -> Result<Option<UntaggedValue>, ShellError> {
     let mut output = vec![];
-    let partitions = disk::partitions_physical().await.map_err(|_| {
+    let partitions = if all {
+        disk::partitions().await.map(StreamExt::boxed_local)
+    } else {
+        disk::partitions_physical()
+            .await
+            .map(StreamExt::boxed_local)
+    }
+    .map_err(|_| {
         ShellError::labeled_error(
             "Unabled to get disk list",
             "could not load disk list",
@@ -169,6 +221,9 @@ async fn disks(tag: Tag) -> Result<Option<UntaggedValue>, ShellError> {
                     "free",
                     UntaggedValue::bytes(usage.free().get::<information::byte>()),
                 );
+
+                #[cfg(unix)]
+                dict.insert_untagged("options", UntaggedValue::string(mount_options(&usage)));
             }
 
             output.push(dict.into_value());
@@ -290,7 +345,7 @@ async fn net(tag: Tag) -> Result<Option<UntaggedValue>, ShellError> {
 
     while let Some(nic) = io_counters.next().await {
         if let Ok(nic) = nic {
-            let mut network_idx = TaggedDictBuilder::with_capacity(&tag, 3);
+            let mut network_idx = TaggedDictBuilder::with_capacity(&tag, 8);
             network_idx.insert_untagged("name", UntaggedValue::string(nic.interface()));
             network_idx.insert_untagged(
                 "sent",
@@ -300,6 +355,11 @@ async fn net(tag: Tag) -> Result<Option<UntaggedValue>, ShellError> {
                 "recv",
                 UntaggedValue::bytes(nic.bytes_recv().get::<information::byte>()),
             );
+            network_idx.insert_untagged("packets sent", UntaggedValue::int(nic.packets_sent()));
+            network_idx.insert_untagged("packets recv", UntaggedValue::int(nic.packets_recv()));
+            network_idx.insert_untagged("errors sent", UntaggedValue::int(nic.errors_sent()));
+            network_idx.insert_untagged("errors recv", UntaggedValue::int(nic.errors_recv()));
+            network_idx.insert_untagged("dropped recv", UntaggedValue::int(nic.drop_recv()));
             output.push(network_idx.into_value());
         }
     }
@@ -310,13 +370,13 @@ async fn net(tag: Tag) -> Result<Option<UntaggedValue>, ShellError> {
     }
 }
 
-pub async fn sysinfo(tag: Tag) -> Vec<Value> {
+pub async fn sysinfo(tag: Tag, all_disks: bool) -> Vec<Value> {
     let mut sysinfo = TaggedDictBuilder::with_capacity(&tag, 7);
 
     let (host, cpu, disks, memory, temp) = futures::future::join5(
         host(tag.clone()),
         cpu(tag.clone()),
-        disks(tag.clone()),
+        disks(tag.clone(), all_disks),
         mem(tag.clone()),
         temp(tag.clone()),
     )
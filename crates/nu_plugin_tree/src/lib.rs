@@ -1,4 +0,0 @@
-mod nu;
-mod tree;
-
-pub use tree::TreeViewer;
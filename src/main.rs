@@ -1,14 +1,13 @@
-use clap::{App, Arg};
+use clap::{App, AppSettings, Arg};
 use log::LevelFilter;
 use nu_cli::utils::test_bins as binaries;
 use nu_cli::{create_default_context, EnvironmentSyncer};
 use std::error::Error;
-use std::fs::File;
-use std::io::{prelude::*, BufReader};
 
 fn main() -> Result<(), Box<dyn Error>> {
     let matches = App::new("nushell")
         .version(clap::crate_version!())
+        .setting(AppSettings::TrailingVarArg)
         .arg(
             Arg::with_name("loglevel")
                 .short("l")
@@ -50,6 +49,13 @@ fn main() -> Result<(), Box<dyn Error>> {
                 .multiple(false)
                 .takes_value(false),
         )
+        .arg(
+            Arg::with_name("no-config")
+                .help("don't load the config file, so a run doesn't depend on it")
+                .long("no-config")
+                .multiple(false)
+                .takes_value(false),
+        )
         .arg(
             Arg::with_name("script")
                 .help("the nu script to run")
@@ -57,9 +63,10 @@ fn main() -> Result<(), Box<dyn Error>> {
         )
         .arg(
             Arg::with_name("args")
-                .help("positional args (used by --testbin)")
+                .help("the arguments given to the script, or to --testbin")
                 .index(2)
-                .multiple(true),
+                .multiple(true)
+                .allow_hyphen_values(true),
         )
         .get_matches();
 
@@ -114,6 +121,10 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     builder.try_init()?;
 
+    if matches.is_present("no-config") {
+        nu_cli::disable_config();
+    }
+
     match matches.values_of("commands") {
         None => {}
         Some(values) => {
@@ -128,26 +139,18 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     match matches.value_of("script") {
         Some(script) => {
-            let file = File::open(script)?;
-            let reader = BufReader::new(file);
-            let pipelines: Vec<String> = reader
-                .lines()
-                .filter_map(|x| {
-                    if let Ok(x) = x {
-                        if !x.starts_with('#') {
-                            Some(x)
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-
-            futures::executor::block_on(nu_cli::run_vec_of_pipelines(
-                pipelines,
-                matches.is_present("stdin"),
+            let args = match matches.values_of("args") {
+                Some(args) => args.map(String::from).collect(),
+                None => vec![],
+            };
+
+            // A script is given what is piped into it, like when it is run with a #!/usr/bin/env nu line
+            let redirect_stdin = matches.is_present("stdin") || !atty::is(atty::Stream::Stdin);
+
+            futures::executor::block_on(nu_cli::run_script_file(
+                script.to_string(),
+                args,
+                redirect_stdin,
             ))?;
             return Ok(());
         }
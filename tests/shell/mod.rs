@@ -1 +1,2 @@
 mod pipeline;
+mod scripts;
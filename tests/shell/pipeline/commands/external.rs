@@ -169,6 +169,63 @@ mod stdin_evaluation {
 
         assert_eq!(stdout, "y");
     }
+
+    #[test]
+    fn gives_lines_one_per_line() {
+        let actual = nu!(
+            cwd: ".",
+            pipeline(r#"
+                ^sh -c "echo nu; echo shell"
+                | lines
+                | ^wc -l
+                | str trim
+            "#
+        ));
+
+        assert_eq!(actual.out, "2");
+    }
+}
+
+mod binary_piping {
+    use super::nu;
+    use nu_test_support::{pipeline, playground::Playground};
+
+    #[test]
+    fn pipes_binary_to_and_from_externals_as_is() {
+        Playground::setup("binary_piping_test_1", |dirs, _| {
+            let bytes: Vec<u8> = (0..=255).rev().collect();
+            std::fs::write(dirs.test().join("data.bin"), &bytes).expect("can't write test file");
+
+            nu!(
+                cwd: dirs.test(), pipeline(
+                r#"
+                    open data.bin --binary
+                    | ^cat
+                    | save copy.bin
+                "#
+            ));
+
+            let copied = std::fs::read(dirs.test().join("copy.bin")).expect("can't read copy");
+            assert_eq!(copied, bytes);
+        })
+    }
+
+    #[test]
+    fn opens_text_files_as_binary_without_decoding_them() {
+        let actual = nu!(
+            cwd: "tests/fixtures/formats", pipeline(
+            r#"
+                open utf16.ini --binary
+                | ^wc -c
+                | str trim
+            "#
+        ));
+
+        let expected = std::fs::metadata("tests/fixtures/formats/utf16.ini")
+            .expect("can't find fixture")
+            .len();
+        assert_eq!(actual.out, expected.to_string());
+    }
 }
 
 mod external_words {
@@ -173,6 +173,78 @@ fn string_interpolation_with_it_column_path() {
     assert_eq!(actual.out, "sammie");
 }
 
+#[test]
+fn parenthesized_invocations_in_math() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            = (echo [1 2 3] | count) * (echo 2) + (1 + 1)
+        "#
+    );
+
+    assert_eq!(actual.out, "8");
+}
+
+#[test]
+fn dollar_string_interpolation_with_it() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            echo '{"name": "sammie"}' | from json | echo $"Hello ($it.name)!"
+        "#
+    );
+
+    assert_eq!(actual.out, "Hello sammie!");
+}
+
+#[test]
+fn dollar_string_interpolation_with_pipelines() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            echo $"(echo "nu shell" | str upcase) is (= 2 * 21) \(really\)"
+        "#
+    );
+
+    assert_eq!(actual.out, "NU SHELL is 42 (really)");
+}
+
+#[test]
+fn dollar_string_interpolation_with_an_escaped_paren_before_a_pipe() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            echo $"a \( b" "c" | to json
+        "#
+    );
+
+    assert_eq!(actual.out, r#"["a ( b","c"]"#);
+}
+
+#[test]
+fn dollar_string_interpolation_in_blocks() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            echo [1 2 3] | each { echo $"n=($it)" } | to json
+        "#
+    );
+
+    assert_eq!(actual.out, r#"["n=1","n=2","n=3"]"#);
+}
+
+#[test]
+fn dollar_string_interpolation_reports_unclosed_parens() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            echo $"Hello (echo world"
+        "#
+    );
+
+    assert!(actual.err.contains("Expected closing )"));
+}
+
 #[test]
 fn argument_invocation_reports_errors() {
     let actual = nu!(
@@ -205,6 +277,206 @@ fn echoing_ranges() {
     assert_eq!(actual.out, "6");
 }
 
+#[test]
+fn list_literals_with_commas() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo [a, "b, c" d] | to json"#
+    );
+
+    assert_eq!(actual.out, r#"["a","b, c","d"]"#);
+}
+
+#[test]
+fn list_literal_gives_its_rows() {
+    let actual = nu!(
+        cwd: ".",
+        r#"[1 2 3] | each { = $it * 2 } | to json"#
+    );
+
+    assert_eq!(actual.out, "[2,4,6]");
+}
+
+#[test]
+fn table_literals() {
+    let actual = nu!(
+        cwd: ".",
+        r#"[[name, size]; [a, 1] [b, 2]] | where size > 1 | get name"#
+    );
+
+    assert_eq!(actual.out, "b");
+}
+
+#[test]
+fn table_literal_rows_need_a_cell_for_each_header() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo [[name, size]; [a, 1] [b]]"#
+    );
+
+    assert!(actual.err.contains("a row with 2 columns"));
+}
+
+#[test]
+fn row_literals() {
+    let actual = nu!(
+        cwd: ".",
+        r#"{name: "x", size: (1 + 2), tags: [a b]} | to json"#
+    );
+
+    assert_eq!(actual.out, r#"{"name":"x","size":3,"tags":["a","b"]}"#);
+}
+
+#[test]
+fn row_literals_in_variables() {
+    let actual = nu!(
+        cwd: ".",
+        r#"let row = {"first name": Ada, inner: {year: 1815}}; echo $row.inner.year"#
+    );
+
+    assert_eq!(actual.out, "1815");
+}
+
+#[test]
+fn row_literals_need_a_value_for_each_column() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo {name: "x", size:}"#
+    );
+
+    assert!(actual.err.contains("requires a value"));
+}
+
+#[test]
+fn spreads_lists_into_list_literals() {
+    let actual = nu!(
+        cwd: ".",
+        r#"let a = [1 2]; echo [...$a 3 ...$a ...4..5] | to json"#
+    );
+
+    assert_eq!(actual.out, "[1,2,3,1,2,4,5]");
+}
+
+#[test]
+fn spreads_rows_into_row_literals() {
+    let actual = nu!(
+        cwd: ".",
+        r#"let row = {name: a, size: 1}; echo {...$row, size: 2, kind: file} | to json"#
+    );
+
+    assert_eq!(actual.out, r#"{"name":"a","size":2,"kind":"file"}"#);
+}
+
+#[test]
+fn only_rows_are_spread_into_rows() {
+    let actual = nu!(
+        cwd: ".",
+        r#"let a = [1 2]; echo {...$a}"#
+    );
+
+    assert!(actual.err.contains("only rows can be spread into a row"));
+}
+
+#[test]
+fn optional_columns_give_nothing_when_missing() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo [{name: a, size: 1} {name: b}] | each { echo $it.size? } | to json"#
+    );
+
+    assert_eq!(actual.out, "[1,null]");
+}
+
+#[test]
+fn optional_columns_skip_the_rest_of_the_path() {
+    let actual = nu!(
+        cwd: ".",
+        r#"let row = {name: a}; echo $row.meta?.size | to json"#
+    );
+
+    assert_eq!(actual.out, "null");
+}
+
+#[test]
+fn missing_columns_are_still_errors() {
+    let actual = nu!(
+        cwd: ".",
+        r#"let row = {name: a}; echo $row.size"#
+    );
+
+    assert!(actual.err.contains("Unknown column"));
+}
+
+#[test]
+fn coalescing_gives_the_right_side_for_nothing() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo [{name: a, size: 1} {name: b}] | each { = $it.size? ?? 0 } | to json"#
+    );
+
+    assert_eq!(actual.out, "[1,0]");
+}
+
+#[test]
+fn coalescing_in_where() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo [{name: a, size: 10} {name: b} {name: c, size: 1}] | where size? ?? 0 > 5 | get name"#
+    );
+
+    assert_eq!(actual.out, "a");
+}
+
+#[test]
+fn blocks_are_not_row_literals() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo [1 2] | each { echo $it } | to json"#
+    );
+
+    assert_eq!(actual.out, "[1,2]");
+}
+
+#[test]
+fn in_is_the_input_of_an_expression() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo "abc" | str length | $in * 2"#
+    );
+
+    assert_eq!(actual.out, "6");
+}
+
+#[test]
+fn in_is_the_input_of_each_run_of_a_block() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo [1 2 3] | each { = $in * 2 } | to json"#
+    );
+
+    assert_eq!(actual.out, "[2,4,6]");
+}
+
+#[test]
+fn in_is_the_input_in_conditions() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo 5 | if $in > 3 { echo big } else { echo small }"#
+    );
+
+    assert_eq!(actual.out, "big");
+}
+
+#[test]
+fn in_is_a_table_for_several_values() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo [[a]; [1] [2]] | echo $in.a | to json"#
+    );
+
+    assert_eq!(actual.out, "[1,2]");
+}
+
 mod parse {
     use nu_test_support::nu;
 
@@ -0,0 +1,235 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+
+#[test]
+fn scripts_are_given_their_arguments_as_args() {
+    Playground::setup("scripts_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "args.nu",
+            r#"
+                echo $args | to json
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"nu args.nu one "two words" --flag"#
+        );
+
+        assert_eq!(actual.out, r#"["one","two words","--flag"]"#);
+    })
+}
+
+#[test]
+fn scripts_run_their_main_command_with_their_arguments() {
+    Playground::setup("scripts_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "greet.nu",
+            r#"
+                def main [name, --times: int = 1, --loud(-l)] { seq $times | each { if $loud { build-string HELLO ' ' $name } else { build-string hello ' ' $name } } | to json }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"nu greet.nu "big world" --times 2 -l"#
+        );
+
+        assert_eq!(actual.out, r#"["HELLO big world","HELLO big world"]"#);
+    })
+}
+
+#[test]
+fn scripts_show_errors_for_arguments_their_main_command_does_not_take() {
+    Playground::setup("scripts_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "greet.nu",
+            r#"
+                def main [name] { build-string hello ' ' $name }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"nu greet.nu nu --loud"#
+        );
+
+        assert!(actual.err.contains("unexpected flag"));
+    })
+}
+
+#[test]
+fn scripts_exit_with_the_exit_code_of_a_failing_external() {
+    Playground::setup("scripts_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "fail.nu",
+            r#"
+                echo before
+                ^sh -c "exit 3"
+                echo after
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"nu fail.nu | complete | get exit_code"#
+        );
+
+        assert_eq!(actual.out, "3");
+    })
+}
+
+#[test]
+fn scripts_are_given_what_is_piped_into_them() {
+    Playground::setup("scripts_test_5", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "upcase.nu",
+            r#"
+                #!/usr/bin/env nu
+                lines | str upcase | to json
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"echo "one" | nu upcase.nu"#
+        );
+
+        assert_eq!(actual.out, r#""ONE""#);
+    })
+}
+
+#[test]
+fn commands_run_each_of_their_lines() {
+    Playground::setup("scripts_test_6", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "commands.txt",
+            r#"
+                let x = 5
+                = $x * 2
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"nu -c $(open commands.txt)"#
+        );
+
+        assert_eq!(actual.out, "10");
+    })
+}
+
+#[test]
+fn no_config_leaves_the_config_file_alone() {
+    let actual = nu!(
+        cwd: ".",
+        r#"nu --no-config -c "config | to json""#
+    );
+
+    assert_eq!(actual.out, "{}");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn env_nu_is_run_before_commands_and_login_nu_is_not() {
+    Playground::setup("scripts_test_7", |dirs, sandbox| {
+        sandbox.mkdir("config").within("config/nu").with_files(vec![
+            FileWithContentToBeTrimmed(
+                "env.nu",
+                r#"
+                    load-env [GREETING hi]
+                "#,
+            ),
+            FileWithContentToBeTrimmed(
+                "login.nu",
+                r#"
+                    load-env [GREETING bye]
+                "#,
+            ),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"with-env [XDG_CONFIG_HOME {}] {{ nu -c "echo $nu.env.GREETING" }}"#,
+            dirs.test().join("config")
+        );
+
+        assert_eq!(actual.out, "hi");
+    })
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn no_config_skips_the_startup_scripts() {
+    Playground::setup("scripts_test_8", |dirs, sandbox| {
+        sandbox
+            .mkdir("config")
+            .within("config/nu")
+            .with_files(vec![FileWithContentToBeTrimmed(
+                "env.nu",
+                r#"
+                load-env [GREETING hi]
+            "#,
+            )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"with-env [XDG_CONFIG_HOME {}] {{ nu --no-config -c "echo $nu.env.GREETING? | to json" }}"#,
+            dirs.test().join("config")
+        );
+
+        assert_eq!(actual.out, "null");
+    })
+}
+
+#[test]
+fn commands_run_blocks_and_strings_written_over_several_lines() {
+    Playground::setup("scripts_test_9", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            FileWithContentToBeTrimmed(
+                "block.txt",
+                r#"
+                    echo [1 2] | each {
+                        = $it + 1
+                    } | to json
+                "#,
+            ),
+            FileWithContentToBeTrimmed(
+                "string.txt",
+                r#"
+                    echo "a
+                    b" | lines | count
+                "#,
+            ),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"nu -c $(open block.txt); nu -c $(open string.txt)"#
+        );
+
+        assert_eq!(actual.out, "[2,3]2");
+    })
+}
+
+#[test]
+fn scripts_run_blocks_written_over_several_lines() {
+    Playground::setup("scripts_test_10", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "each.nu",
+            r#"
+                echo [1 2] | each {
+                    = $it + 1
+                } | to json
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"nu each.nu"#
+        );
+
+        assert_eq!(actual.out, "[2,3]");
+    })
+}